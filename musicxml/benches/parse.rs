@@ -0,0 +1,218 @@
+//! Measures the time needed to parse the test scores, a large synthetic orchestral score, and the datatypes that
+//! require validation.
+//!
+//! Run with `cargo bench -p musicxml`. Each benchmark reports the median time and the number of heap allocations
+//! per iteration. Every datatype is also validated by the regular expressions that the crate used before its
+//! hand-written parsers, and the speedup of the current parser over this regex baseline is reported alongside it.
+
+use musicxml::datatypes::{
+  Color, Date, EndingNumber, SmuflAccidentalGlyphName, SmuflCodaGlyphName, SmuflGlyphName, SmuflLyricsGlyphName,
+  SmuflPictogramGlyphName, SmuflSegnoGlyphName, SmuflWavyLineGlyphName, YyyyMmDd,
+};
+use musicxml::read_score_data_partwise;
+use musicxml_internal::DatatypeDeserializer;
+use regex::Regex;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Global allocator that counts every allocation made through the system allocator.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    unsafe { System.alloc(layout) }
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    unsafe { System.dealloc(ptr, layout) }
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    unsafe { System.realloc(ptr, layout, new_size) }
+  }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const SCORES: [&str; 4] = [
+  "Grande Valse Brillante.musicxml",
+  "Grande Valse Brillante - Timewise.musicxml",
+  "Grande Valse Brillante.mxl",
+  "MozaChloSample.musicxml",
+];
+
+/// Runs a benchmark, prints its median time and allocation count, and returns the median time in seconds.
+fn bench(name: &str, iterations: u32, mut run: impl FnMut()) -> f64 {
+  let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+  run();
+  let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+  let mut timings: Vec<f64> = (0..iterations)
+    .map(|_| {
+      let start = Instant::now();
+      run();
+      start.elapsed().as_secs_f64()
+    })
+    .collect();
+  timings.sort_by(f64::total_cmp);
+  let median = timings[timings.len() / 2];
+  println!("{name:<48} {:>12.3} µs {allocations:>12} allocations", median * 1e6);
+  median
+}
+
+/// Validation of each datatype exactly as performed by the regular expressions the crate used previously.
+mod regex_baseline {
+  use super::*;
+
+  fn hex(value: &str) -> Option<u8> {
+    u8::from_str_radix(value, 16).ok()
+  }
+
+  pub fn color(value: &str) -> bool {
+    let regex = Regex::new(r"#([A-Fa-f0-9]{2})([A-Fa-f0-9]{2})([A-Fa-f0-9]{2})([A-Fa-f0-9]{2})?$").unwrap();
+    regex.captures(value).is_some_and(|captures| {
+      (1..=4)
+        .filter_map(|index| captures.get(index))
+        .all(|group| hex(group.as_str()).is_some())
+    })
+  }
+
+  pub fn date(value: &str) -> bool {
+    let regex = Regex::new(r"([0-9]{4})-([0-9]{2})-([0-9]{2})[T|\ ]?((([\+\-][0-2][0-9]):([0-5][0-9]))|Z)?").unwrap();
+    regex.captures(value).is_some_and(|captures| {
+      let number = |index: usize| {
+        captures
+          .get(index)
+          .map_or(0, |group| group.as_str().parse::<i32>().unwrap())
+      };
+      let (month, date, hours, minutes) = (number(2), number(3), number(6), number(7));
+      number(1) >= 0 && (1..13).contains(&month) && (1..32).contains(&date) && hours.abs() < 24 && minutes < 61
+    })
+  }
+
+  pub fn yyyy_mm_dd(value: &str) -> bool {
+    let regex = Regex::new(r"([0-9]{4})-([0-9]{2})-([0-9]{2})$").unwrap();
+    regex.captures(value).is_some_and(|captures| {
+      let number = |index: usize| captures[index].parse::<u16>().unwrap();
+      number(1) > 0 && (1..13).contains(&number(2)) && (1..32).contains(&number(3))
+    })
+  }
+
+  pub fn ending_number(value: &str) -> bool {
+    let space_regex = Regex::new(r"^[ ]*$").unwrap();
+    let integer_regex = Regex::new(r"^[1-9][0-9]*(, ?[1-9][0-9]*)*$").unwrap();
+    space_regex.is_match(value) || integer_regex.is_match(value)
+  }
+
+  pub fn glyph_name(pattern: &str) -> impl Fn(&str) -> bool + '_ {
+    move |value| SmuflGlyphName::deserialize(value).is_ok_and(|token| Regex::new(pattern).unwrap().is_match(&token))
+  }
+}
+
+/// Builds an orchestral-sized score by duplicating the single part of a test score under new part IDs.
+fn orchestral_score(data: &str, parts: usize) -> String {
+  let part_list = data.find("<score-part ").unwrap()..data.find("</part-list>").unwrap();
+  let part = data.find("<part id=").unwrap()..data.rfind("</score-partwise>").unwrap();
+  let (score_part, part_content) = (&data[part_list.clone()], &data[part.clone()]);
+  let mut score = String::from(&data[..part_list.start]);
+  (1..=parts).for_each(|index| score.push_str(&score_part.replace("\"P1", &format!("\"P{index}"))));
+  score.push_str(&data[part_list.end..part.start]);
+  (1..=parts).for_each(|index| score.push_str(&part_content.replace("\"P1\"", &format!("\"P{index}\""))));
+  score.push_str(&data[part.end..]);
+  score
+}
+
+fn bench_datatype<T: DatatypeDeserializer>(name: &str, values: &[&str], baseline: impl Fn(&str) -> bool) {
+  for value in values {
+    assert_eq!(
+      T::deserialize(value).is_ok(),
+      baseline(value),
+      "{name} disagrees with the regex baseline"
+    );
+  }
+  let parser = bench(name, 1_000, || {
+    for _ in 0..100 {
+      for value in values {
+        let _ = black_box(T::deserialize(black_box(value)));
+      }
+    }
+  });
+  let regex = bench(&format!("{name} (regex baseline)"), 20, || {
+    for _ in 0..100 {
+      for value in values {
+        black_box(baseline(black_box(value)));
+      }
+    }
+  });
+  println!("{:<48} {:>12.1}x faster than regex", "", regex / parser);
+}
+
+fn main() {
+  for score in SCORES {
+    let data = std::fs::read(format!("{}/tests/{score}", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    bench(score, 50, || {
+      black_box(read_score_data_partwise(black_box(data.clone())).unwrap());
+    });
+  }
+  let data = std::fs::read_to_string(format!("{}/tests/{}", env!("CARGO_MANIFEST_DIR"), SCORES[0])).unwrap();
+  let orchestral = orchestral_score(&data, 24).into_bytes();
+  bench("Orchestral score (24 parts)", 10, || {
+    black_box(read_score_data_partwise(black_box(orchestral.clone())).unwrap());
+  });
+  bench_datatype::<Color>(
+    "Color",
+    &["#A123E5", "#40800080", "#FFFFFF", "#12345G"],
+    regex_baseline::color,
+  );
+  bench_datatype::<Date>(
+    "Date",
+    &["2024-01-23", "2024-01-23-02:30", "1971-12-01Z", "2024-13-01"],
+    regex_baseline::date,
+  );
+  bench_datatype::<YyyyMmDd>(
+    "YyyyMmDd",
+    &["2024-01-23", "1999-12-31", "2024-1-3"],
+    regex_baseline::yyyy_mm_dd,
+  );
+  bench_datatype::<EndingNumber>(
+    "EndingNumber",
+    &["1", "1, 2", "1,2,3", "  ", "0"],
+    regex_baseline::ending_number,
+  );
+  bench_datatype::<SmuflAccidentalGlyphName>(
+    "SmuflAccidentalGlyphName",
+    &["accidentalFlat", "medRenFlatSoftB"],
+    regex_baseline::glyph_name(r"^(acc|medRenFla|medRenNatura|medRenShar|kievanAccidental).*$"),
+  );
+  bench_datatype::<SmuflCodaGlyphName>(
+    "SmuflCodaGlyphName",
+    &["coda", "codaSquare"],
+    regex_baseline::glyph_name(r"^coda.*$"),
+  );
+  bench_datatype::<SmuflLyricsGlyphName>(
+    "SmuflLyricsGlyphName",
+    &["lyricsElision", "lyricsHyphenBaseline"],
+    regex_baseline::glyph_name(r"^lyrics.*$"),
+  );
+  bench_datatype::<SmuflPictogramGlyphName>(
+    "SmuflPictogramGlyphName",
+    &["pictGlsp", "pictTimpani"],
+    regex_baseline::glyph_name(r"^pict.*$"),
+  );
+  bench_datatype::<SmuflSegnoGlyphName>(
+    "SmuflSegnoGlyphName",
+    &["segno", "segnoSerpent1"],
+    regex_baseline::glyph_name(r"^segno.*$"),
+  );
+  bench_datatype::<SmuflWavyLineGlyphName>(
+    "SmuflWavyLineGlyphName",
+    &["wiggleTrill", "guitarWideVibratoStroke"],
+    regex_baseline::glyph_name(r"(^wiggle[a-zA-Z0-9]*$)|(^guitar[a-zA-Z0-9]*VibratoStroke$)"),
+  );
+}
@@ -0,0 +1,889 @@
+//! Command-line interface for inspecting, validating, and converting MusicXML scores.
+//!
+//! Every subcommand writes its results to standard output and its diagnostics to standard error, and reports its
+//! outcome through the process exit code: `0` on success, `1` if the input could not be processed or is invalid,
+//! and `2` if the command line itself could not be understood. The `info` and `validate` subcommands accept a
+//! `--json` flag for machine-readable output, and any input or output path may be given as `-` to use standard
+//! input or standard output, so that the tool can be used in shell pipelines.
+
+use musicxml::convert::{kern, mei, mnx};
+use musicxml::datatypes::{AccidentalValue, Octave, Semitones, Step};
+use musicxml::elements::{
+  AudibleType, KeyContents, Measure, MeasureElement, Note, NoteType, Part, PartElement, PartListElement, Pitch,
+  ScorePartwise,
+};
+use musicxml::parser;
+use musicxml::summary::ScoreSummary;
+use musicxml_internal::{ElementSerializer, XmlElement};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: musicxml <command> [options]
+
+Commands:
+  info <input> [--json]
+      Print the title, composer, parts, measure count, key, time, and duration of a score.
+  convert <input> <output> [--format <format>] [--timewise | --partwise] [--compact]
+      Convert a score into another format. The output format is taken from the extension of the
+      output path unless specified: musicxml (.musicxml, .xml), mxl (.mxl), mei (.mei), kern (.krn),
+      or mnx (.mnx, .json).
+  validate <input> [--json]
+      Check that a score can be parsed and that its parts and measures are consistent.
+  extract-part <input> <part-id> <output> [--compact]
+      Write a score containing only the specified part.
+  transpose <input> <semitones> <output> [--compact]
+      Transpose all pitches and key signatures by the specified number of semitones.
+  dump-json <input> [--compact]
+      Print the element tree of a score as JSON.
+
+Inputs may be MusicXML, compressed MXL, MEI, Humdrum **kern, or MNX, and are detected by their contents.
+Use '-' as a path to read from standard input or write to standard output.
+
+Exit codes: 0 on success, 1 if the input is invalid or cannot be processed, 2 on usage errors.";
+
+const DEFAULT_TEMPO: f64 = 120.0;
+const STEP_SEMITONES: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Number of diatonic steps and change in key signature fifths for each chromatic interval within an octave.
+const INTERVALS: [(i32, i32); 12] = [
+  (0, 0),
+  (1, -5),
+  (1, 2),
+  (2, -3),
+  (2, 4),
+  (3, -1),
+  (3, 6),
+  (4, 1),
+  (5, -4),
+  (5, 3),
+  (6, -2),
+  (6, 5),
+];
+
+/// Reasons for which a command can fail, each of which maps onto a distinct exit code.
+#[derive(Debug, PartialEq)]
+enum Failure {
+  Usage(String),
+  Error(String),
+}
+
+impl From<String> for Failure {
+  fn from(message: String) -> Self {
+    Failure::Error(message)
+  }
+}
+
+/// Result of a successfully executed command: the bytes to write to standard output and the exit code.
+#[derive(Debug, Default)]
+struct Output {
+  data: Vec<u8>,
+  code: u8,
+}
+
+impl Output {
+  fn text(text: String, code: u8) -> Self {
+    Output {
+      data: text.into_bytes(),
+      code,
+    }
+  }
+}
+
+/// Supported score formats for reading and writing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+  MusicXml,
+  Mxl,
+  Mei,
+  Kern,
+  Mnx,
+}
+
+impl Format {
+  fn from_name(name: &str) -> Option<Self> {
+    match name.to_ascii_lowercase().as_str() {
+      "musicxml" | "xml" => Some(Format::MusicXml),
+      "mxl" => Some(Format::Mxl),
+      "mei" => Some(Format::Mei),
+      "kern" | "krn" => Some(Format::Kern),
+      "mnx" | "json" => Some(Format::Mnx),
+      _ => None,
+    }
+  }
+
+  fn from_path(path: &str) -> Option<Self> {
+    if path == "-" {
+      Some(Format::MusicXml)
+    } else {
+      path
+        .rsplit_once('.')
+        .and_then(|(_, extension)| Format::from_name(extension))
+    }
+  }
+
+  fn detect(data: &[u8]) -> Self {
+    if data.starts_with(b"PK") {
+      return Format::Mxl;
+    }
+    let head = String::from_utf8_lossy(&data[..data.len().min(4096)]);
+    if head.trim_start().starts_with('{') {
+      Format::Mnx
+    } else if head.contains("<mei") {
+      Format::Mei
+    } else if head.contains("**kern") {
+      Format::Kern
+    } else {
+      Format::MusicXml
+    }
+  }
+}
+
+/// Command-line arguments split into positional arguments and options.
+struct Arguments {
+  positional: Vec<String>,
+  flags: Vec<String>,
+  format: Option<String>,
+}
+
+impl Arguments {
+  fn parse(args: &[String], allowed: &[&str]) -> Result<Self, Failure> {
+    let mut arguments = Arguments {
+      positional: Vec::new(),
+      flags: Vec::new(),
+      format: None,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+      if arg.len() > 2 && arg.starts_with("--") {
+        if !allowed.contains(&arg.as_str()) {
+          return Err(Failure::Usage(format!("Unknown option '{arg}'")));
+        } else if arg == "--format" {
+          let value = args
+            .next()
+            .ok_or(Failure::Usage(String::from("Missing value for '--format'")))?;
+          arguments.format = Some(value.clone());
+        } else {
+          arguments.flags.push(arg.clone());
+        }
+      } else {
+        arguments.positional.push(arg.clone());
+      }
+    }
+    Ok(arguments)
+  }
+
+  fn flag(&self, name: &str) -> bool {
+    self.flags.iter().any(|flag| flag == name)
+  }
+
+  fn expect(&self, names: &[&str]) -> Result<(), Failure> {
+    if self.positional.len() < names.len() {
+      Err(Failure::Usage(format!(
+        "Missing <{}> argument",
+        names[self.positional.len()]
+      )))
+    } else if self.positional.len() > names.len() {
+      Err(Failure::Usage(format!(
+        "Unexpected argument '{}'",
+        self.positional[names.len()]
+      )))
+    } else {
+      Ok(())
+    }
+  }
+}
+
+fn read_input(path: &str) -> Result<Vec<u8>, Failure> {
+  if path == "-" {
+    let mut data = Vec::new();
+    std::io::stdin()
+      .read_to_end(&mut data)
+      .map_err(|err| format!("Unable to read from standard input: {err}"))?;
+    Ok(data)
+  } else {
+    Ok(std::fs::read(path).map_err(|err| format!("Unable to read '{path}': {err}"))?)
+  }
+}
+
+fn read_score(path: &str) -> Result<ScorePartwise, Failure> {
+  let data = read_input(path)?;
+  let text = || musicxml_internal::bytes_to_string(&data);
+  Ok(match Format::detect(&data) {
+    Format::MusicXml | Format::Mxl => parser::parse_score_partwise_from_data(data.clone()),
+    Format::Mei => text().and_then(|text| mei::mei_to_score_partwise(&text)),
+    Format::Kern => text().and_then(|text| kern::kern_to_score_partwise(&text)),
+    Format::Mnx => text().and_then(|text| mnx::mnx_to_score_partwise(&text)),
+  }?)
+}
+
+fn write_score(score: &ScorePartwise, format: Format, pretty_print: bool, timewise: bool) -> Result<Vec<u8>, Failure> {
+  Ok(match format {
+    Format::MusicXml => parser::parse_score_partwise_to_data(score, false, pretty_print, timewise)?,
+    Format::Mxl => parser::parse_score_partwise_to_data(score, true, pretty_print, timewise)?,
+    Format::Mei => mei::score_partwise_to_mei(score)?.into_bytes(),
+    Format::Kern => kern::score_partwise_to_kern(score)?.into_bytes(),
+    Format::Mnx => mnx::score_partwise_to_mnx(score)?.into_bytes(),
+  })
+}
+
+/// Returns the output for the specified path, which is only non-empty when writing to standard output.
+fn write_output(path: &str, data: Vec<u8>) -> Result<Output, Failure> {
+  if path == "-" {
+    Ok(Output { data, code: 0 })
+  } else {
+    std::fs::write(path, data).map_err(|err| format!("Unable to write '{path}': {err}"))?;
+    Ok(Output::default())
+  }
+}
+
+fn json_string(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len() + 2);
+  escaped.push('"');
+  for character in value.chars() {
+    match character {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      character if (character as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", character as u32)),
+      character => escaped.push(character),
+    }
+  }
+  escaped.push('"');
+  escaped
+}
+
+fn json_number(value: f64) -> String {
+  let rounded = (value * 1000.0).round() / 1000.0;
+  format!("{rounded}")
+}
+
+fn part_measures(part: &Part) -> Vec<&Measure> {
+  part
+    .content
+    .iter()
+    .filter_map(|element| match element {
+      PartElement::Measure(measure) => Some(measure),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Returns the duration of a note in divisions and whether the note belongs to a chord with its predecessor.
+fn note_timing(note: &Note) -> (u32, bool) {
+  match &note.content.info {
+    NoteType::Normal(info) => (info.duration.content.0, info.chord.is_some()),
+    NoteType::Cue(info) => (info.duration.content.0, info.chord.is_some()),
+    NoteType::Grace(_) => (0, true),
+  }
+}
+
+fn note_pitch_mut(note: &mut Note) -> Option<&mut Pitch> {
+  let audible = match &mut note.content.info {
+    NoteType::Normal(info) => &mut info.audible,
+    NoteType::Cue(info) => &mut info.audible,
+    NoteType::Grace(info) => match &mut info.info {
+      musicxml::elements::GraceType::Cue(info) => &mut info.audible,
+      musicxml::elements::GraceType::Normal(info) => &mut info.audible,
+    },
+  };
+  match audible {
+    AudibleType::Pitch(pitch) => Some(pitch),
+    _ => None,
+  }
+}
+
+/// Checks the timing of every measure in a part, adding an error message for any inconsistency found.
+fn check_measure_timings(part: &Part, errors: &mut Vec<String>) {
+  let mut divisions: Option<u32> = None;
+  for measure in part_measures(part) {
+    let mut position = 0_i64;
+    let mut undivided = false;
+    for element in &measure.content {
+      match element {
+        MeasureElement::Attributes(attributes) => {
+          if let Some(value) = &attributes.content.divisions {
+            divisions = Some(value.content.0);
+          }
+        }
+        MeasureElement::Note(note) => {
+          let (duration, chord) = note_timing(note);
+          undivided |= divisions.is_none() && duration > 0;
+          if !chord {
+            position += i64::from(duration);
+          }
+        }
+        MeasureElement::Backup(backup) => {
+          undivided |= divisions.is_none();
+          position -= i64::from(backup.content.duration.content.0);
+          if position < 0 {
+            errors.push(format!(
+              "Part '{}' measure {}: <backup> moves before the start of the measure",
+              *part.attributes.id, *measure.attributes.number
+            ));
+            position = 0;
+          }
+        }
+        MeasureElement::Forward(forward) => {
+          undivided |= divisions.is_none();
+          position += i64::from(forward.content.duration.content.0);
+        }
+        _ => {}
+      }
+    }
+    if undivided {
+      errors.push(format!(
+        "Part '{}' measure {}: durations appear before <divisions> is defined",
+        *part.attributes.id, *measure.attributes.number
+      ));
+    }
+  }
+}
+
+fn info(args: &[String]) -> Result<Output, Failure> {
+  let args = Arguments::parse(args, &["--json"])?;
+  args.expect(&["input"])?;
+  let summary = ScoreSummary::from(&read_score(&args.positional[0])?);
+  let parts: Vec<(&str, &str, usize)> = summary
+    .parts
+    .iter()
+    .map(|part| (part.id.as_str(), part.name.as_str(), part.measures))
+    .collect();
+  let measures = summary.measures;
+  let (title, composer) = (summary.title.as_deref(), summary.composers.first().map(String::as_str));
+  let key = summary.key_changes.first().map(|key| (key.fifths, key.mode.clone()));
+  let time = summary.time_changes.first().map(|time| time.signature.clone());
+  let quarters = summary.duration_quarters;
+  let seconds = summary.duration_seconds.unwrap_or(quarters * 60.0 / DEFAULT_TEMPO);
+  let output = if args.flag("--json") {
+    let optional = |value: Option<&str>| value.map_or(String::from("null"), json_string);
+    let parts: Vec<String> = parts
+      .iter()
+      .map(|(id, name, measures)| {
+        format!(
+          "{{\"id\":{},\"name\":{},\"measures\":{measures}}}",
+          json_string(id),
+          json_string(name)
+        )
+      })
+      .collect();
+    let key = key.as_ref().map_or(String::from("null"), |(fifths, mode)| {
+      format!("{{\"fifths\":{fifths},\"mode\":{}}}", optional(mode.as_deref()))
+    });
+    format!(
+      "{{\"title\":{},\"composer\":{},\"parts\":[{}],\"measures\":{measures},\"key\":{key},\"time\":{},\"duration\":{{\"quarters\":{},\"seconds\":{}}}}}\n",
+      optional(title),
+      optional(composer),
+      parts.join(","),
+      optional(time.as_deref()),
+      json_number(quarters),
+      json_number(seconds)
+    )
+  } else {
+    let mut output = format!(
+      "title: {}\ncomposer: {}\nparts: {}\n",
+      title.unwrap_or(""),
+      composer.unwrap_or(""),
+      parts.len()
+    );
+    for (id, name, measures) in &parts {
+      output += &format!("part: {id}\t{name}\t{measures}\n");
+    }
+    let key = key.map_or(String::new(), |(fifths, mode)| match mode {
+      Some(mode) => format!("{fifths} {mode}"),
+      None => format!("{fifths}"),
+    });
+    output += &format!(
+      "measures: {measures}\nkey: {key}\ntime: {}\nduration-quarters: {}\nduration-seconds: {}\n",
+      time.unwrap_or_default(),
+      json_number(quarters),
+      json_number(seconds)
+    );
+    output
+  };
+  Ok(Output::text(output, 0))
+}
+
+fn convert(args: &[String]) -> Result<Output, Failure> {
+  let args = Arguments::parse(args, &["--format", "--timewise", "--partwise", "--compact"])?;
+  args.expect(&["input", "output"])?;
+  if args.flag("--timewise") && args.flag("--partwise") {
+    return Err(Failure::Usage(String::from(
+      "Options '--timewise' and '--partwise' are mutually exclusive",
+    )));
+  }
+  let output = &args.positional[1];
+  let format = match &args.format {
+    Some(name) => Format::from_name(name).ok_or(Failure::Usage(format!("Unknown format '{name}'")))?,
+    None => Format::from_path(output).ok_or(Failure::Usage(format!(
+      "Unable to determine the format of '{output}'; use '--format'"
+    )))?,
+  };
+  let score = read_score(&args.positional[0])?;
+  let data = write_score(&score, format, !args.flag("--compact"), args.flag("--timewise"))?;
+  write_output(output, data)
+}
+
+/// Checks the structural consistency of a score, returning a list of problems found.
+fn validation_errors(score: &ScorePartwise) -> Vec<String> {
+  let mut errors = Vec::new();
+  let mut declared: Vec<&str> = Vec::new();
+  for element in &score.content.part_list.content.content {
+    if let PartListElement::ScorePart(part) = element {
+      if declared.contains(&part.attributes.id.0.as_str()) {
+        errors.push(format!(
+          "Part '{}' is declared more than once in <part-list>",
+          *part.attributes.id
+        ));
+      }
+      declared.push(&part.attributes.id.0);
+    }
+  }
+  let used: Vec<&str> = score
+    .content
+    .part
+    .iter()
+    .map(|part| part.attributes.id.0.as_str())
+    .collect();
+  for id in &used {
+    if !declared.contains(id) {
+      errors.push(format!("Part '{id}' is not declared in <part-list>"));
+    }
+  }
+  for id in &declared {
+    if !used.contains(id) {
+      errors.push(format!("Part '{id}' is declared in <part-list> but has no <part>"));
+    }
+  }
+  let reference = score.content.part.first().map(part_measures).unwrap_or_default();
+  for part in &score.content.part {
+    let measures = part_measures(part);
+    if measures.len() != reference.len() {
+      errors.push(format!(
+        "Part '{}' has {} measures but part '{}' has {}",
+        *part.attributes.id,
+        measures.len(),
+        *score.content.part[0].attributes.id,
+        reference.len()
+      ));
+    }
+    for (measure, expected) in measures.iter().zip(reference.iter()) {
+      if measure.attributes.number.0 != expected.attributes.number.0 {
+        errors.push(format!(
+          "Part '{}' has measure {} where measure {} was expected",
+          *part.attributes.id, *measure.attributes.number, *expected.attributes.number
+        ));
+      }
+    }
+    check_measure_timings(part, &mut errors);
+  }
+  errors
+}
+
+fn validate(args: &[String]) -> Result<Output, Failure> {
+  let args = Arguments::parse(args, &["--json"])?;
+  args.expect(&["input"])?;
+  let errors = match read_score(&args.positional[0]) {
+    Ok(score) => validation_errors(&score),
+    Err(Failure::Error(message)) => vec![message],
+    Err(failure) => return Err(failure),
+  };
+  let code = u8::from(!errors.is_empty());
+  let output = if args.flag("--json") {
+    let errors: Vec<String> = errors.iter().map(|error| json_string(error)).collect();
+    format!("{{\"valid\":{},\"errors\":[{}]}}\n", code == 0, errors.join(","))
+  } else if errors.is_empty() {
+    String::from("valid\n")
+  } else {
+    errors.iter().map(|error| format!("invalid: {error}\n")).collect()
+  };
+  Ok(Output::text(output, code))
+}
+
+fn extract_part(args: &[String]) -> Result<Output, Failure> {
+  let args = Arguments::parse(args, &["--compact"])?;
+  args.expect(&["input", "part-id", "output"])?;
+  let mut score = read_score(&args.positional[0])?;
+  let id = &args.positional[1];
+  if !score.content.part.iter().any(|part| part.attributes.id.0 == *id) {
+    return Err(Failure::Error(format!("Part '{id}' does not exist in the score")));
+  }
+  score.content.part.retain(|part| part.attributes.id.0 == *id);
+  score.content.part_list.content.content.retain(|element| match element {
+    PartListElement::ScorePart(part) => part.attributes.id.0 == *id,
+    PartListElement::PartGroup(_) => false,
+  });
+  let output = &args.positional[2];
+  let format = Format::from_path(output).unwrap_or(Format::MusicXml);
+  write_output(output, write_score(&score, format, !args.flag("--compact"), false)?)
+}
+
+/// Respells a pitch after moving it by the specified number of diatonic steps and semitones.
+fn transpose_pitch(pitch: &mut Pitch, steps: i32, semitones: i32) -> Result<(), String> {
+  let index = match pitch.content.step.content {
+    Step::C => 0,
+    Step::D => 1,
+    Step::E => 2,
+    Step::F => 3,
+    Step::G => 4,
+    Step::A => 5,
+    Step::B => 6,
+  };
+  let alter = pitch
+    .content
+    .alter
+    .as_ref()
+    .map_or(0, |alter| i32::from(alter.content.0));
+  let octave = i32::from(pitch.content.octave.content.0);
+  let key = octave * 12 + STEP_SEMITONES[index as usize] + alter + semitones;
+  let position = octave * 7 + index + steps;
+  let (octave, index) = (position.div_euclid(7), position.rem_euclid(7));
+  if !(0..=9).contains(&octave) {
+    return Err(format!(
+      "Transposed pitch is outside of the supported octave range (octave {octave})"
+    ));
+  }
+  let alter = key - octave * 12 - STEP_SEMITONES[index as usize];
+  pitch.content.step.content = match index {
+    0 => Step::C,
+    1 => Step::D,
+    2 => Step::E,
+    3 => Step::F,
+    4 => Step::G,
+    5 => Step::A,
+    _ => Step::B,
+  };
+  pitch.content.octave.content = Octave(octave as u8);
+  match (&mut pitch.content.alter, alter) {
+    (Some(_), 0) | (None, 0) => pitch.content.alter = None,
+    (Some(value), alter) => value.content = Semitones(alter as i16),
+    (None, alter) => {
+      pitch.content.alter = Some(musicxml::elements::Alter {
+        attributes: (),
+        content: Semitones(alter as i16),
+      })
+    }
+  }
+  Ok(())
+}
+
+fn accidental_value(alter: i32) -> Option<AccidentalValue> {
+  match alter {
+    -2 => Some(AccidentalValue::FlatFlat),
+    -1 => Some(AccidentalValue::Flat),
+    0 => Some(AccidentalValue::Natural),
+    1 => Some(AccidentalValue::Sharp),
+    2 => Some(AccidentalValue::DoubleSharp),
+    _ => None,
+  }
+}
+
+/// Returns the number of diatonic steps and the change in fifths used to transpose by the specified number of
+/// semitones within a key signature, spelling the interval so that the transposed key stays within seven sharps or
+/// flats.
+fn key_interval(semitones: i32, key_fifths: i32) -> (i32, i32) {
+  let (mut steps, fifths) = INTERVALS[semitones.rem_euclid(12) as usize];
+  steps += 7 * semitones.div_euclid(12);
+  if key_fifths + fifths > 7 {
+    (steps + 1, fifths - 12)
+  } else if key_fifths + fifths < -7 {
+    (steps - 1, fifths + 12)
+  } else {
+    (steps, fifths)
+  }
+}
+
+/// Transposes every pitch and key signature in a score by the specified number of semitones.
+///
+/// The interval is spelled separately for each key signature so that the transposed key stays within seven sharps or
+/// flats, and the notes that follow a key signature are spelled to match it. Notes before the first key signature of
+/// a part are spelled according to the first key signature of the first part.
+fn transpose_score(score: &mut ScorePartwise, semitones: i32) -> Result<(), String> {
+  let fifths = ScoreSummary::from(&*score)
+    .key_changes
+    .first()
+    .map_or(0, |key| key.fifths);
+  let (initial, _) = key_interval(semitones, i32::from(fifths));
+  for part in &mut score.content.part {
+    // Diatonic steps to transpose by for each staff, where staff 0 applies to every staff without its own key
+    let mut spellings = BTreeMap::from([(0, initial)]);
+    for element in &mut part.content {
+      let PartElement::Measure(measure) = element else {
+        continue;
+      };
+      for element in &mut measure.content {
+        match element {
+          MeasureElement::Attributes(attributes) => {
+            for key in &mut attributes.content.key {
+              if let KeyContents::Explicit(content) = &mut key.content {
+                let original = i32::from(content.fifths.content.0);
+                let (steps, fifths) = key_interval(semitones, original);
+                content.fifths.content.0 = (original + fifths) as i8;
+                match &key.attributes.number {
+                  Some(number) => {
+                    spellings.insert(u32::from(number.0), steps);
+                  }
+                  None => spellings = BTreeMap::from([(0, steps)]),
+                }
+              }
+            }
+          }
+          MeasureElement::Note(note) => {
+            let staff = note.content.staff.as_ref().map_or(1, |staff| *staff.content);
+            let steps = spellings.get(&staff).or(spellings.get(&0)).copied().unwrap_or(initial);
+            if let Some(pitch) = note_pitch_mut(note) {
+              transpose_pitch(pitch, steps, semitones)?;
+              let alter = pitch
+                .content
+                .alter
+                .as_ref()
+                .map_or(0, |alter| i32::from(alter.content.0));
+              if let (Some(accidental), Some(value)) = (&mut note.content.accidental, accidental_value(alter)) {
+                accidental.content = value;
+                accidental.attributes.smufl = None;
+              }
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
+fn transpose(args: &[String]) -> Result<Output, Failure> {
+  let args = Arguments::parse(args, &["--compact"])?;
+  args.expect(&["input", "semitones", "output"])?;
+  let semitones: i32 = args.positional[1]
+    .parse()
+    .map_err(|_| Failure::Usage(format!("Invalid number of semitones '{}'", args.positional[1])))?;
+  let mut score = read_score(&args.positional[0])?;
+  transpose_score(&mut score, semitones)?;
+  let output = &args.positional[2];
+  let format = Format::from_path(output).unwrap_or(Format::MusicXml);
+  write_output(output, write_score(&score, format, !args.flag("--compact"), false)?)
+}
+
+fn element_json(element: &XmlElement, output: &mut String, depth: Option<usize>) {
+  let (indent, inner, separator) = match depth {
+    Some(depth) => (
+      format!("\n{}", "  ".repeat(depth)),
+      format!("\n{}", "  ".repeat(depth + 1)),
+      ": ",
+    ),
+    None => (String::new(), String::new(), ":"),
+  };
+  let attributes: Vec<String> = element
+    .attributes
+    .iter()
+    .map(|(name, value)| format!("{}{separator}{}", json_string(name), json_string(value)))
+    .collect();
+  output.push('{');
+  output.push_str(&format!("{inner}\"name\"{separator}{}", json_string(&element.name)));
+  output.push_str(&format!(
+    ",{inner}\"attributes\"{separator}{{{}}}",
+    attributes.join(", ")
+  ));
+  if !element.text.is_empty() {
+    output.push_str(&format!(",{inner}\"text\"{separator}{}", json_string(&element.text)));
+  }
+  if !element.elements.is_empty() {
+    output.push_str(&format!(",{inner}\"children\"{separator}["));
+    for (index, child) in element.elements.iter().enumerate() {
+      if index > 0 {
+        output.push(',');
+      }
+      if let Some(depth) = depth {
+        output.push_str(&format!("\n{}", "  ".repeat(depth + 2)));
+      }
+      element_json(child, output, depth.map(|depth| depth + 2));
+    }
+    output.push_str(&format!("{inner}]"));
+  }
+  output.push_str(&format!("{indent}}}"));
+}
+
+fn dump_json(args: &[String]) -> Result<Output, Failure> {
+  let args = Arguments::parse(args, &["--compact"])?;
+  args.expect(&["input"])?;
+  let score = read_score(&args.positional[0])?;
+  let mut output = String::new();
+  element_json(
+    &ScorePartwise::serialize(&score),
+    &mut output,
+    (!args.flag("--compact")).then_some(0),
+  );
+  output.push('\n');
+  Ok(Output::text(output, 0))
+}
+
+fn run(args: &[String]) -> Result<Output, Failure> {
+  let (command, args) = args
+    .split_first()
+    .ok_or(Failure::Usage(String::from("Missing command")))?;
+  match command.as_str() {
+    "info" => info(args),
+    "convert" => convert(args),
+    "validate" => validate(args),
+    "extract-part" => extract_part(args),
+    "transpose" => transpose(args),
+    "dump-json" => dump_json(args),
+    "help" | "-h" | "--help" => Ok(Output::text(format!("{USAGE}\n"), 0)),
+    _ => Err(Failure::Usage(format!("Unknown command '{command}'"))),
+  }
+}
+
+fn main() -> ExitCode {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  match run(&args) {
+    Ok(output) => {
+      let mut stdout = std::io::stdout().lock();
+      if let Err(err) = stdout.write_all(&output.data).and_then(|()| stdout.flush()) {
+        eprintln!("error: Unable to write to standard output: {err}");
+        return ExitCode::from(1);
+      }
+      ExitCode::from(output.code)
+    }
+    Err(Failure::Usage(message)) => {
+      eprintln!("error: {message}\n\n{USAGE}");
+      ExitCode::from(2)
+    }
+    Err(Failure::Error(message)) => {
+      eprintln!("error: {message}");
+      ExitCode::from(1)
+    }
+  }
+}
+
+#[cfg(test)]
+mod musicxml_tests {
+  use super::*;
+
+  fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| String::from(*arg)).collect()
+  }
+
+  #[test]
+  fn run_usage() {
+    assert!(matches!(run(&args(&[])), Err(Failure::Usage(_))));
+    assert!(matches!(run(&args(&["play"])), Err(Failure::Usage(_))));
+    assert!(matches!(run(&args(&["info"])), Err(Failure::Usage(_))));
+    assert!(matches!(run(&args(&["info", "a", "b"])), Err(Failure::Usage(_))));
+    assert!(matches!(run(&args(&["info", "a", "--pretty"])), Err(Failure::Usage(_))));
+    assert!(matches!(run(&args(&["convert", "a", "b.pdf"])), Err(Failure::Usage(_))));
+    assert!(matches!(
+      run(&args(&["transpose", "a", "x", "b"])),
+      Err(Failure::Usage(_))
+    ));
+    assert!(matches!(
+      run(&args(&["info", "missing.musicxml"])),
+      Err(Failure::Error(_))
+    ));
+  }
+
+  #[test]
+  fn run_info() {
+    let output = run(&args(&["info", "tests/MozaChloSample.musicxml", "--json"])).unwrap();
+    let text = String::from_utf8(output.data).unwrap();
+    assert_eq!(output.code, 0);
+    assert!(text.starts_with("{\"title\":\"An Chloe (Page 1)\",\"composer\":\"Wolfgang Amadeus Mozart\""));
+    assert!(text.contains("\"measures\":"));
+    assert!(text.contains("\"duration\":{\"quarters\":"));
+    let output = run(&args(&["info", "tests/MozaChloSample.musicxml"])).unwrap();
+    assert!(String::from_utf8(output.data)
+      .unwrap()
+      .contains("\ncomposer: Wolfgang Amadeus Mozart\n"));
+  }
+
+  #[test]
+  fn run_validate() {
+    let output = run(&args(&["validate", "tests/MozaChloSample.musicxml", "--json"])).unwrap();
+    assert_eq!(output.code, 0);
+    assert_eq!(
+      String::from_utf8(output.data).unwrap(),
+      "{\"valid\":true,\"errors\":[]}\n"
+    );
+    let mut score = musicxml::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    score.content.part.truncate(1);
+    score.content.part[0].attributes.id.0 = String::from("X");
+    let errors = validation_errors(&score);
+    assert!(errors.iter().any(|error| error.contains("'X' is not declared")));
+    let output = run(&args(&["validate", "missing.musicxml"])).unwrap();
+    assert_eq!(output.code, 1);
+  }
+
+  #[test]
+  fn run_transpose() {
+    let mut score = musicxml::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    let fifths = |score: &ScorePartwise| ScoreSummary::from(score).key_changes[0].fifths;
+    let original = fifths(&score);
+    transpose_score(&mut score, 2).unwrap();
+    assert_eq!(fifths(&score), original + 2);
+    transpose_score(&mut score, -2).unwrap();
+    assert_eq!(
+      score,
+      musicxml::read_score_partwise("tests/MozaChloSample.musicxml").unwrap()
+    );
+    let mut score: ScorePartwise = parser::parse_from_xml_str(
+      "<score-partwise>
+        <part-list><score-part id=\"P1\"><part-name>Flute</part-name></score-part></part-list>
+        <part id=\"P1\">
+          <measure number=\"1\">
+            <attributes><divisions>1</divisions><key><fifths>0</fifths></key></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>4</duration><type>whole</type></note>
+          </measure>
+          <measure number=\"2\">
+            <attributes><key><fifths>4</fifths></key></attributes>
+            <note><pitch><step>E</step><octave>4</octave></pitch><duration>4</duration><type>whole</type></note>
+          </measure>
+        </part>
+      </score-partwise>",
+    )
+    .unwrap();
+    transpose_score(&mut score, 6).unwrap();
+    let xml = parser::parse_to_xml_str(&score, false);
+    assert!(xml.contains("<key><fifths>6</fifths></key>"));
+    assert!(xml.contains("<pitch><step>F</step><alter>1</alter><octave>4</octave></pitch>"));
+    assert!(xml.contains("<key><fifths>-2</fifths></key>"));
+    assert!(xml.contains("<pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch>"));
+    let mut pitch: Pitch = parser::parse_from_xml_str("<pitch><step>B</step><octave>3</octave></pitch>").unwrap();
+    transpose_pitch(&mut pitch, 1, 1).unwrap();
+    assert_eq!(
+      parser::parse_to_xml_str(&pitch, false),
+      "<pitch><step>C</step><octave>4</octave></pitch>"
+    );
+    transpose_pitch(&mut pitch, -2, -4).unwrap();
+    assert_eq!(
+      parser::parse_to_xml_str(&pitch, false),
+      "<pitch><step>A</step><alter>-1</alter><octave>3</octave></pitch>"
+    );
+  }
+
+  #[test]
+  fn run_dump_json() {
+    let xml: XmlElement = XmlElement {
+      name: "a".into(),
+      attributes: vec![("b".into(), "\"c\"".into())],
+      elements: vec![XmlElement {
+        name: "d".into(),
+        text: "e\n".into(),
+        ..Default::default()
+      }],
+      text: "".into(),
+    };
+    let mut output = String::new();
+    element_json(&xml, &mut output, None);
+    assert_eq!(
+      output,
+      "{\"name\":\"a\",\"attributes\":{\"b\":\"\\\"c\\\"\"},\"children\":[{\"name\":\"d\",\"attributes\":{},\"text\":\"e\\n\"}]}"
+    );
+    let output = run(&args(&["dump-json", "tests/MozaChloSample.musicxml"])).unwrap();
+    assert!(String::from_utf8(output.data)
+      .unwrap()
+      .starts_with("{\n  \"name\": \"score-partwise\""));
+  }
+}
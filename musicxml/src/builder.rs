@@ -0,0 +1,746 @@
+use crate::datatypes::{
+  self, ClefSign, Id, IdRef, NonNegativeInteger, NoteTypeValue, PositiveDivisions, Semitones, StaffLinePosition, Token,
+};
+use crate::elements::{
+  ActualNotes, Alter, Attributes, AttributesContents, AudibleType, BeatType, Beats, Chord, Clef, ClefAttributes,
+  ClefContents, Creator, CreatorAttributes, Divisions, Dot, DotAttributes, Duration, ExplicitKeyContents, Fifths,
+  Identification, IdentificationContents, Key, KeyAttributes, KeyContents, Line, Measure, MeasureAttributes,
+  MeasureElement, NormalInfo, NormalNotes, Note, NoteAttributes, NoteContents, NoteType, Octave, Part, PartAttributes,
+  PartElement, PartList, PartListContents, PartListElement, PartName, PartNameAttributes, Pitch, PitchContents, Rest,
+  RestAttributes, RestContents, ScorePart, ScorePartAttributes, ScorePartContents, ScorePartwise,
+  ScorePartwiseAttributes, ScorePartwiseContents, Sign, Step, Time, TimeAttributes, TimeBeatContents, TimeContents,
+  TimeModification, TimeModificationContents, Type, TypeAttributes, Work, WorkContents, WorkTitle,
+};
+use crate::util::{dotted_length, lcm, length_to_note_type, step_from_letter, Fraction};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Notated length of a note or rest, including any augmentation dots and tuplet ratio.
+///
+/// Lengths are usually created using the [w], [h], [q], [e], and [s] shorthand functions and then modified using
+/// [NoteLength::dot] and [NoteLength::tuplet]. The resulting `<duration>`, `<type>`, `<dot>`, and
+/// `<time-modification>` elements are all computed automatically from the length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteLength {
+  base: Fraction,
+  dots: u8,
+  actual_notes: u32,
+  normal_notes: u32,
+}
+
+impl NoteLength {
+  /// Creates an undotted length for the specified note type.
+  #[must_use]
+  pub fn new(note_type: &NoteTypeValue) -> Self {
+    Self {
+      base: dotted_length(note_type, 0),
+      dots: 0,
+      actual_notes: 1,
+      normal_notes: 1,
+    }
+  }
+
+  /// Adds an augmentation dot to the length. May be called repeatedly for double- or triple-dotted notes.
+  #[must_use]
+  pub fn dot(mut self) -> Self {
+    self.dots += 1;
+    self
+  }
+
+  /// Places the length within a tuplet, where `actual_notes` notes occupy the time normally taken by
+  /// `normal_notes` notes. For example, an eighth-note triplet is written as `e().tuplet(3, 2)`.
+  #[must_use]
+  pub fn tuplet(mut self, actual_notes: u32, normal_notes: u32) -> Self {
+    self.actual_notes = actual_notes;
+    self.normal_notes = normal_notes;
+    self
+  }
+
+  fn note_type(&self) -> Option<NoteTypeValue> {
+    length_to_note_type(self.base).map(|(note_type, _)| note_type)
+  }
+
+  fn length(&self) -> Fraction {
+    self
+      .note_type()
+      .map_or(self.base, |note_type| dotted_length(&note_type, self.dots))
+      * Fraction::new(i64::from(self.normal_notes), i64::from(self.actual_notes))
+  }
+
+  fn validate(&self) -> Result<(), String> {
+    if self.actual_notes == 0 || self.normal_notes == 0 {
+      Err(format!(
+        "Invalid tuplet ratio {}:{}: both note counts must be positive",
+        self.actual_notes, self.normal_notes
+      ))
+    } else {
+      Ok(())
+    }
+  }
+
+  fn to_note(self, audible: AudibleType, chord: bool, divisions: i64) -> Result<Note, String> {
+    let length = self.length();
+    let duration = divisions_value((length.num * divisions / length.den).max(1))?;
+    Ok(Note {
+      attributes: NoteAttributes::default(),
+      content: NoteContents {
+        info: NoteType::Normal(NormalInfo {
+          chord: chord.then_some(Chord {
+            attributes: (),
+            content: (),
+          }),
+          audible,
+          duration: Duration {
+            attributes: (),
+            content: PositiveDivisions(duration),
+          },
+          tie: Vec::new(),
+        }),
+        instrument: Vec::new(),
+        footnote: None,
+        level: None,
+        voice: None,
+        r#type: self.note_type().map(|note_type| Type {
+          attributes: TypeAttributes::default(),
+          content: note_type,
+        }),
+        dot: (0..self.dots)
+          .map(|_| Dot {
+            attributes: DotAttributes::default(),
+            content: (),
+          })
+          .collect(),
+        accidental: None,
+        time_modification: (self.actual_notes != self.normal_notes).then(|| TimeModification {
+          attributes: (),
+          content: TimeModificationContents {
+            actual_notes: ActualNotes {
+              attributes: (),
+              content: NonNegativeInteger(self.actual_notes),
+            },
+            normal_notes: NormalNotes {
+              attributes: (),
+              content: NonNegativeInteger(self.normal_notes),
+            },
+            normal_type: None,
+            normal_dot: Vec::new(),
+          },
+        }),
+        stem: None,
+        notehead: None,
+        notehead_text: None,
+        staff: None,
+        beam: Vec::new(),
+        notations: Vec::new(),
+        lyric: Vec::new(),
+        play: None,
+        listen: None,
+      },
+    })
+  }
+}
+
+/// Converts a computed number of divisions into the range supported by `<divisions>` and `<duration>`.
+fn divisions_value(value: i64) -> Result<u32, String> {
+  u32::try_from(value).map_err(|_| format!("Note lengths require {value} divisions, which is too many to represent"))
+}
+
+/// Returns the length of a whole note.
+#[must_use]
+pub fn w() -> NoteLength {
+  NoteLength::new(&NoteTypeValue::Whole)
+}
+
+/// Returns the length of a half note.
+#[must_use]
+pub fn h() -> NoteLength {
+  NoteLength::new(&NoteTypeValue::Half)
+}
+
+/// Returns the length of a quarter note.
+#[must_use]
+pub fn q() -> NoteLength {
+  NoteLength::new(&NoteTypeValue::Quarter)
+}
+
+/// Returns the length of an eighth note.
+#[must_use]
+pub fn e() -> NoteLength {
+  NoteLength::new(&NoteTypeValue::Eighth)
+}
+
+/// Returns the length of a sixteenth note.
+#[must_use]
+pub fn s() -> NoteLength {
+  NoteLength::new(&NoteTypeValue::Sixteenth)
+}
+
+/// Parses a pitch written in scientific pitch notation, such as `"C4"`, `"Eb4"`, `"F#5"`, or `"Bbb2"`.
+///
+/// Sharps may be written as `#` and double sharps as `##` or `x`, while flats are written as `b`.
+fn parse_pitch(pitch: &str) -> Result<Pitch, String> {
+  let error = || {
+    format!("Invalid pitch \"{pitch}\": expected a step from A to G, optional accidentals, and an octave from 0 to 9")
+  };
+  let mut chars = pitch.chars();
+  let step = chars
+    .next()
+    .and_then(|letter| step_from_letter(letter.to_ascii_uppercase()))
+    .ok_or_else(error)?;
+  let remaining = chars.as_str();
+  let octave_start = remaining.find(|ch: char| ch.is_ascii_digit()).ok_or_else(error)?;
+  let (accidentals, octave) = remaining.split_at(octave_start);
+  let alter = accidentals.chars().try_fold(0_i16, |alter, ch| match ch {
+    '#' => Ok(alter + 1),
+    'x' => Ok(alter + 2),
+    'b' => Ok(alter - 1),
+    _ => Err(error()),
+  })?;
+  let octave = octave
+    .parse::<u8>()
+    .ok()
+    .filter(|octave| *octave <= 9)
+    .ok_or_else(error)?;
+  Ok(Pitch {
+    attributes: (),
+    content: PitchContents {
+      step: Step {
+        attributes: (),
+        content: step,
+      },
+      alter: (alter != 0).then_some(Alter {
+        attributes: (),
+        content: Semitones(alter),
+      }),
+      octave: Octave {
+        attributes: (),
+        content: datatypes::Octave(octave),
+      },
+    },
+  })
+}
+
+/// A note or rest waiting for the score's divisions to be known.
+#[derive(Debug)]
+struct PendingNote {
+  pitch: Option<Pitch>,
+  length: NoteLength,
+  chord: bool,
+}
+
+/// Builds the contents of a single measure.
+///
+/// Attribute changes such as [MeasureBuilder::key], [MeasureBuilder::time], and [MeasureBuilder::clef] are always
+/// written at the start of the measure in the order required by MusicXML, regardless of the order in which they are
+/// specified. Notes, chords, and rests are written in the order in which they are added.
+#[derive(Debug, Default)]
+pub struct MeasureBuilder {
+  key: Option<i8>,
+  time: Option<(u32, u32)>,
+  clef: Option<(ClefSign, u8)>,
+  notes: Vec<PendingNote>,
+  last_length: Option<NoteLength>,
+  error: Option<String>,
+}
+
+impl MeasureBuilder {
+  fn fail(mut self, error: String) -> Self {
+    self.error.get_or_insert(error);
+    self
+  }
+
+  fn push_note(mut self, pitch: Option<&str>, length: NoteLength, chord: bool) -> Self {
+    if let Err(error) = length.validate() {
+      return self.fail(error);
+    }
+    match pitch.map(parse_pitch).transpose() {
+      Ok(pitch) => self.notes.push(PendingNote { pitch, length, chord }),
+      Err(error) => return self.fail(error),
+    }
+    self.last_length = Some(length);
+    self
+  }
+
+  /// Sets the key signature to the specified number of sharps (positive) or flats (negative).
+  #[must_use]
+  pub fn key(mut self, fifths: i32) -> Self {
+    match i8::try_from(fifths) {
+      Ok(fifths) if (-7..=7).contains(&fifths) => self.key = Some(fifths),
+      _ => {
+        return self.fail(format!(
+          "Invalid key signature with {fifths} fifths: expected a value from -7 to 7"
+        ))
+      }
+    }
+    self
+  }
+
+  /// Sets the time signature, such as `time(3, 4)` for three quarter notes per measure.
+  #[must_use]
+  pub fn time(mut self, beats: u32, beat_type: u32) -> Self {
+    if beats == 0 || beat_type == 0 {
+      return self.fail(format!(
+        "Invalid time signature {beats}/{beat_type}: both values must be positive"
+      ));
+    }
+    self.time = Some((beats, beat_type));
+    self
+  }
+
+  /// Sets the clef using its sign and the staff line on which it is centered, counting from the bottom line.
+  #[must_use]
+  pub fn clef(mut self, sign: ClefSign, line: u8) -> Self {
+    self.clef = Some((sign, line));
+    self
+  }
+
+  /// Adds a note with the specified pitch and length, such as `note("Eb4", q())`.
+  #[must_use]
+  pub fn note(self, pitch: &str, length: NoteLength) -> Self {
+    self.push_note(Some(pitch), length, false)
+  }
+
+  /// Adds a note with the specified pitch to the chord started by the previous note, using the same length.
+  #[must_use]
+  pub fn chord(self, pitch: &str) -> Self {
+    match self.last_length {
+      Some(length) => self.push_note(Some(pitch), length, true),
+      None => self.fail(format!("Chord note \"{pitch}\" must follow another note")),
+    }
+  }
+
+  /// Adds a rest of the specified length.
+  #[must_use]
+  pub fn rest(mut self, length: NoteLength) -> Self {
+    self = self.push_note(None, length, false);
+    self.last_length = None;
+    self
+  }
+
+  fn attributes(&mut self, divisions: Option<u32>) -> Option<Attributes> {
+    let content = AttributesContents {
+      divisions: divisions.map(|divisions| Divisions {
+        attributes: (),
+        content: PositiveDivisions(divisions),
+      }),
+      key: self
+        .key
+        .map(|fifths| Key {
+          attributes: KeyAttributes::default(),
+          content: KeyContents::Explicit(ExplicitKeyContents {
+            cancel: None,
+            fifths: Fifths {
+              attributes: (),
+              content: datatypes::Fifths(fifths),
+            },
+            mode: None,
+            key_octave: Vec::new(),
+          }),
+        })
+        .into_iter()
+        .collect(),
+      time: self
+        .time
+        .map(|(beats, beat_type)| Time {
+          attributes: TimeAttributes::default(),
+          content: TimeContents {
+            beats: vec![TimeBeatContents {
+              beats: Beats {
+                attributes: (),
+                content: beats.to_string(),
+              },
+              beat_type: BeatType {
+                attributes: (),
+                content: beat_type.to_string(),
+              },
+            }],
+            ..Default::default()
+          },
+        })
+        .into_iter()
+        .collect(),
+      clef: self
+        .clef
+        .take()
+        .map(|(sign, line)| Clef {
+          attributes: ClefAttributes::default(),
+          content: ClefContents {
+            sign: Sign {
+              attributes: (),
+              content: sign,
+            },
+            line: Some(Line {
+              attributes: (),
+              content: StaffLinePosition(i16::from(line)),
+            }),
+            clef_octave_change: None,
+          },
+        })
+        .into_iter()
+        .collect(),
+      ..Default::default()
+    };
+    (content != AttributesContents::default()).then_some(Attributes {
+      attributes: (),
+      content,
+    })
+  }
+
+  fn lengths(&self) -> impl Iterator<Item = Fraction> + '_ {
+    self.notes.iter().map(|note| note.length.length())
+  }
+
+  fn into_measure(mut self, number: usize, divisions: i64, first: bool) -> Result<Measure, String> {
+    let mut content = Vec::new();
+    if let Some(attributes) = self.attributes(if first { Some(divisions_value(divisions)?) } else { None }) {
+      content.push(MeasureElement::Attributes(attributes));
+    }
+    for note in self.notes {
+      let audible = match note.pitch {
+        Some(pitch) => AudibleType::Pitch(pitch),
+        None => AudibleType::Rest(Rest {
+          attributes: RestAttributes::default(),
+          content: RestContents {
+            display_step: None,
+            display_octave: None,
+          },
+        }),
+      };
+      content.push(MeasureElement::Note(
+        note.length.to_note(audible, note.chord, divisions)?,
+      ));
+    }
+    Ok(Measure {
+      attributes: MeasureAttributes {
+        number: Token(number.to_string()),
+        id: None,
+        implicit: None,
+        non_controlling: None,
+        text: None,
+        width: None,
+      },
+      content,
+    })
+  }
+}
+
+#[derive(Debug)]
+struct PartBuilder {
+  name: String,
+  measures: Vec<MeasureBuilder>,
+}
+
+/// Builds a complete [ScorePartwise] programmatically.
+///
+/// Parts are added using [ScoreBuilder::part], after which each call to [ScoreBuilder::measure] appends a measure to
+/// the most recently added part. The number of divisions per quarter note is computed automatically so that every
+/// note length can be represented exactly, and all `<duration>`, `<type>`, `<dot>`, and `<time-modification>`
+/// elements are derived from the [NoteLength] of each note.
+///
+/// ```rust
+/// use musicxml::builder::{e, h, q, ScoreBuilder};
+/// use musicxml::datatypes::ClefSign;
+///
+/// let score = ScoreBuilder::new()
+///   .title("Waltz in E♭ Major")
+///   .composer("Frédéric Chopin")
+///   .part("Piano")
+///   .measure(|m| m.time(3, 4).key(-3).clef(ClefSign::G, 2).note("Eb4", q()).chord("G4").rest(h()))
+///   .measure(|m| m.note("Bb4", h().dot()))
+///   .measure(|m| m.note("C5", e().tuplet(3, 2)).note("D5", e().tuplet(3, 2)).note("Eb5", e().tuplet(3, 2)).rest(h()))
+///   .build()
+///   .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct ScoreBuilder {
+  title: Option<String>,
+  composer: Option<String>,
+  parts: Vec<PartBuilder>,
+  error: Option<String>,
+}
+
+impl ScoreBuilder {
+  /// Creates an empty score builder.
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the title of the work.
+  #[must_use]
+  pub fn title(mut self, title: &str) -> Self {
+    self.title = Some(String::from(title));
+    self
+  }
+
+  /// Sets the composer of the work.
+  #[must_use]
+  pub fn composer(mut self, composer: &str) -> Self {
+    self.composer = Some(String::from(composer));
+    self
+  }
+
+  /// Adds a new part with the specified name. Subsequent measures are added to this part.
+  #[must_use]
+  pub fn part(mut self, name: &str) -> Self {
+    self.parts.push(PartBuilder {
+      name: String::from(name),
+      measures: Vec::new(),
+    });
+    self
+  }
+
+  /// Appends a measure to the most recently added part, using the specified function to fill in its contents.
+  #[must_use]
+  pub fn measure(mut self, build: impl FnOnce(MeasureBuilder) -> MeasureBuilder) -> Self {
+    match self.parts.last_mut() {
+      Some(part) => part.measures.push(build(MeasureBuilder::default())),
+      None => {
+        self
+          .error
+          .get_or_insert_with(|| String::from("A part must be added before any measures"));
+      }
+    }
+    self
+  }
+
+  /// Builds the score.
+  ///
+  /// # Errors
+  ///
+  /// If any part was missing when a measure was added, or any measure contains an invalid pitch, key signature,
+  /// time signature, tuplet ratio, or a chord note without a preceding note, an error message will be returned.
+  pub fn build(self) -> Result<ScorePartwise, String> {
+    if let Some(error) = self.error {
+      return Err(error);
+    }
+    let divisions = self
+      .parts
+      .iter()
+      .flat_map(|part| part.measures.iter())
+      .flat_map(MeasureBuilder::lengths)
+      .fold(1, |divisions, length| lcm(divisions, length.den));
+    let mut part_list = Vec::new();
+    let mut parts = Vec::new();
+    for (index, part) in self.parts.into_iter().enumerate() {
+      let id = format!("P{}", index + 1);
+      part_list.push(PartListElement::ScorePart(ScorePart {
+        attributes: ScorePartAttributes { id: Id(id.clone()) },
+        content: ScorePartContents {
+          identification: None,
+          part_link: Vec::new(),
+          part_name: PartName {
+            attributes: PartNameAttributes::default(),
+            content: part.name,
+          },
+          part_name_display: None,
+          part_abbreviation: None,
+          part_abbreviation_display: None,
+          group: Vec::new(),
+          score_instrument: Vec::new(),
+          player: Vec::new(),
+          midi_device: Vec::new(),
+          midi_instrument: Vec::new(),
+        },
+      }));
+      let mut measures = Vec::new();
+      for (index, measure) in part.measures.into_iter().enumerate() {
+        let measure = match measure.error {
+          Some(error) => Err(error),
+          None => measure.into_measure(index + 1, divisions, index == 0),
+        };
+        let measure = measure.map_err(|error| format!("Invalid measure {} in part {id}: {error}", index + 1))?;
+        measures.push(PartElement::Measure(measure));
+      }
+      parts.push(Part {
+        attributes: PartAttributes { id: IdRef(id) },
+        content: measures,
+      });
+    }
+    Ok(ScorePartwise {
+      attributes: ScorePartwiseAttributes {
+        version: Some(Token(String::from("4.0"))),
+      },
+      content: ScorePartwiseContents {
+        work: self.title.map(|title| Work {
+          attributes: (),
+          content: WorkContents {
+            work_title: Some(WorkTitle {
+              attributes: (),
+              content: title,
+            }),
+            ..Default::default()
+          },
+        }),
+        movement_number: None,
+        movement_title: None,
+        identification: self.composer.map(|composer| Identification {
+          attributes: (),
+          content: IdentificationContents {
+            creator: vec![Creator {
+              attributes: CreatorAttributes {
+                r#type: Some(Token(String::from("composer"))),
+              },
+              content: composer,
+            }],
+            ..Default::default()
+          },
+        }),
+        defaults: None,
+        credit: Vec::new(),
+        part_list: PartList {
+          attributes: (),
+          content: PartListContents { content: part_list },
+        },
+        part: parts,
+      },
+    })
+  }
+}
+
+#[cfg(test)]
+mod builder_tests {
+  use super::*;
+  use crate::elements::{MeasureElement, Note};
+  use crate::parser::parse_to_xml_str;
+  use crate::util::{
+    note_dots, note_duration, note_is_chord, note_is_rest, note_pitch, note_time_modification, part_measures,
+  };
+
+  fn notes(score: &ScorePartwise, part: usize) -> Vec<&Note> {
+    part_measures(&score.content.part[part])
+      .flat_map(|measure| measure.content.iter())
+      .filter_map(|element| match element {
+        MeasureElement::Note(note) => Some(note),
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn build_score() {
+    let score = ScoreBuilder::new()
+      .title("Waltz")
+      .composer("Frédéric Chopin")
+      .part("Piano")
+      .measure(|m| m.note("Eb4", q()).chord("G4").rest(h()))
+      .build()
+      .unwrap();
+    let xml = parse_to_xml_str(&score, false);
+    assert!(xml.contains("<work><work-title>Waltz</work-title></work>"));
+    assert!(xml.contains("<creator type=\"composer\">Frédéric Chopin</creator>"));
+    assert!(xml.contains("<score-part id=\"P1\"><part-name>Piano</part-name></score-part>"));
+    assert!(xml.contains(
+      "<note><pitch><step>E</step><alter>-1</alter><octave>4</octave></pitch><duration>1</duration><type>quarter</type></note>"
+    ));
+    assert!(xml.contains(
+      "<note><chord/><pitch><step>G</step><octave>4</octave></pitch><duration>1</duration><type>quarter</type></note>"
+    ));
+    assert!(xml.contains("<note><rest/><duration>2</duration><type>half</type></note>"));
+    let notes = notes(&score, 0);
+    assert_eq!(notes.len(), 3);
+    assert!(note_is_chord(notes[1]) && note_is_rest(notes[2]));
+  }
+
+  #[test]
+  fn build_attributes() {
+    let score = ScoreBuilder::new()
+      .part("Violin")
+      .measure(|m| m.clef(ClefSign::G, 2).time(3, 4).key(-3).note("C5", h().dot()))
+      .measure(|m| m.time(2, 4).note("D5", h()))
+      .build()
+      .unwrap();
+    let xml = parse_to_xml_str(&score, false);
+    assert!(xml.contains(
+      "<measure number=\"1\"><attributes><divisions>1</divisions><key><fifths>-3</fifths></key><time><beats>3</beats><beat-type>4</beat-type></time><clef><sign>G</sign><line>2</line></clef></attributes>"
+    ));
+    assert!(xml.contains(
+      "<measure number=\"2\"><attributes><time><beats>2</beats><beat-type>4</beat-type></time></attributes>"
+    ));
+  }
+
+  #[test]
+  fn build_computed_durations() {
+    let score = ScoreBuilder::new()
+      .part("Flute")
+      .measure(|m| {
+        m.note("C4", e().tuplet(3, 2))
+          .note("D4", e().tuplet(3, 2))
+          .note("E4", e().tuplet(3, 2))
+          .note("F4", q().dot().dot())
+          .note("G4", s())
+      })
+      .build()
+      .unwrap();
+    assert!(parse_to_xml_str(&score, false).contains("<divisions>12</divisions>"));
+    let notes = notes(&score, 0);
+    let durations: Vec<u32> = notes.iter().map(|note| note_duration(note)).collect();
+    assert_eq!(durations, [4, 4, 4, 21, 3]);
+    assert_eq!(note_time_modification(notes[0]), (3, 2));
+    assert_eq!(note_time_modification(notes[3]), (1, 1));
+    assert_eq!(note_dots(notes[3]), 2);
+  }
+
+  #[test]
+  fn build_pitches_and_parts() {
+    let score = ScoreBuilder::new()
+      .part("Soprano")
+      .measure(|m| m.note("F#5", q()).note("Bbb2", q()).note("Cx4", q()).note("a0", q()))
+      .part("Alto")
+      .measure(|m| m.rest(w()))
+      .build()
+      .unwrap();
+    let pitches: Vec<(i32, u8)> = notes(&score, 0)
+      .iter()
+      .filter_map(|note| note_pitch(note))
+      .map(|pitch| {
+        (
+          pitch.content.alter.as_ref().map_or(0, |alter| *alter.content as i32),
+          *pitch.content.octave.content,
+        )
+      })
+      .collect();
+    assert_eq!(pitches, [(1, 5), (-2, 2), (2, 4), (0, 0)]);
+    assert_eq!(score.content.part.len(), 2);
+    assert_eq!(*score.content.part[1].attributes.id, "P2");
+    assert!(note_is_rest(notes(&score, 1)[0]));
+  }
+
+  #[test]
+  fn build_invalid() {
+    let errors = [
+      ScoreBuilder::new().measure(|m| m.rest(w())).build(),
+      ScoreBuilder::new().part("Piano").measure(|m| m.note("H4", q())).build(),
+      ScoreBuilder::new().part("Piano").measure(|m| m.note("C#", q())).build(),
+      ScoreBuilder::new()
+        .part("Piano")
+        .measure(|m| m.rest(q()).chord("C4"))
+        .build(),
+      ScoreBuilder::new().part("Piano").measure(|m| m.key(8)).build(),
+      ScoreBuilder::new().part("Piano").measure(|m| m.time(0, 4)).build(),
+      ScoreBuilder::new()
+        .part("Piano")
+        .measure(|m| m.note("C4", e().tuplet(0, 2)))
+        .build(),
+      ScoreBuilder::new()
+        .part("Piano")
+        .measure(|m| m.note("C4", e().tuplet(u32::MAX, 1)))
+        .build(),
+    ];
+    let errors: Vec<String> = errors.into_iter().map(Result::unwrap_err).collect();
+    assert_eq!(errors[0], "A part must be added before any measures");
+    assert_eq!(
+      errors[1],
+      "Invalid measure 1 in part P1: Invalid pitch \"H4\": expected a step from A to G, optional accidentals, and an octave from 0 to 9"
+    );
+    assert!(errors[2].contains("Invalid pitch \"C#\""));
+    assert_eq!(
+      errors[3],
+      "Invalid measure 1 in part P1: Chord note \"C4\" must follow another note"
+    );
+    assert!(errors[4].contains("Invalid key signature with 8 fifths"));
+    assert!(errors[5].contains("Invalid time signature 0/4"));
+    assert!(errors[6].contains("Invalid tuplet ratio 0:2"));
+    assert!(errors[7].contains("too many to represent"));
+  }
+}
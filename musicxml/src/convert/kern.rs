@@ -0,0 +1,2367 @@
+//! Converts between [ScorePartwise][crate::elements::ScorePartwise] scores and Humdrum `**kern` files.
+//!
+//! The exporter writes one `**kern` spine per staff, ordered from the lowest staff on the left to the highest staff
+//! on the right as is customary in Humdrum. Every voice on a staff is written to its own sub-spine, and staves with
+//! lyrics, chord symbols, or dynamics receive additional `**text`, `**harm`, and `**dynam` spines to the right of
+//! their `**kern` spine. Durations are written as exact reciprocal values, so tuplets are represented by their
+//! sounding rhythm (such as `12` for a triplet eighth note).
+//!
+//! The importer understands the commonly used subset of the `**kern` representation, including spine splits and
+//! merges, chords, ties, slurs, beams, articulations, grace notes, and the interpretations written by the exporter.
+
+use crate::datatypes::{
+  AccidentalValue, BackwardForward, BarStyle, BeamValue, ClefSign, Mode, NoteTypeValue, RightLeftMiddle,
+  StartStopContinue, StemValue, Syllabic, TimeSymbol, WedgeType, YesNo,
+};
+use crate::elements::{
+  Articulations, Attributes, AudibleType, Clef, Direction, DirectionTypeContents, Dynamics, KeyContents, LyricContents,
+  Measure, MeasureElement, NotationContentTypes, Note, NoteType, PartListElement, ScorePart, ScorePartwise, Time,
+};
+use crate::util::{
+  chord_symbol_to_xml, direction_xml, dotted_length, format_fraction, harmony_element_text, length_to_note_type,
+  note_audible, note_dots, note_is_grace, note_staff, note_tied, note_type_length, note_voice, part_measures,
+  part_staves, pitch_alter, resolve_fractional_durations, split_length, step_letter, Fraction, MeasureEvent,
+  PartTimeline, XmlElementExt, DYNAMICS, NOTE_TYPES,
+};
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use musicxml_internal::{DatatypeSerializer, ElementDeserializer, ElementSerializer, XmlElement};
+
+/// MusicXML articulation element names and their `**kern` signifiers, ordered so that longer signifiers are matched
+/// before any signifier they contain.
+const ARTICULATIONS: [(&str, &str); 5] = [
+  ("strong-accent", "^^"),
+  ("accent", "^"),
+  ("staccatissimo", "`"),
+  ("staccato", "'"),
+  ("tenuto", "~"),
+];
+
+/// Humdrum reference record keys and the MusicXML creator types they correspond to.
+const CREATORS: [(&str, &str); 3] = [("COM", "composer"), ("LYR", "lyricist"), ("ARR", "arranger")];
+
+/// Major keys ordered by their number of fifths, starting at seven flats.
+const MAJOR_KEYS: [&str; 15] = [
+  "C-", "G-", "D-", "A-", "E-", "B-", "F", "C", "G", "D", "A", "E", "B", "F#", "C#",
+];
+
+/// Returns the `**kern` rhythm of a length in quarter notes, using augmentation dots where possible and an exact
+/// rational reciprocal otherwise.
+fn kern_recip(length: Fraction) -> String {
+  if !length.is_positive() {
+    return String::from("0");
+  }
+  for dots in 0..=3usize {
+    let base = length / Fraction::new((1 << (dots + 1)) - 1, 1 << dots);
+    let recip = Fraction::from_int(4) / base;
+    let rhythm = match (recip.num, recip.den) {
+      (num, 1) => Some(num.to_string()),
+      (1, 2) => Some(String::from("0")),
+      (1, 4) => Some(String::from("00")),
+      (1, 8) => Some(String::from("000")),
+      _ => None,
+    };
+    if let Some(rhythm) = rhythm {
+      return rhythm + &".".repeat(dots);
+    }
+  }
+  let recip = Fraction::from_int(4) / length;
+  format!("{}%{}", recip.num, recip.den)
+}
+
+/// Returns the `**kern` pitch name of a step and octave, where `c` is middle C.
+fn kern_pitch(step: char, octave: i32) -> String {
+  if octave >= 4 {
+    step
+      .to_ascii_lowercase()
+      .to_string()
+      .repeat((octave - 3).unsigned_abs() as usize)
+  } else {
+    step
+      .to_ascii_uppercase()
+      .to_string()
+      .repeat((4 - octave).unsigned_abs() as usize)
+  }
+}
+
+fn kern_clef(clef: &Clef) -> Option<String> {
+  let sign = match clef.content.sign.content {
+    ClefSign::G => "G",
+    ClefSign::F => "F",
+    ClefSign::C => "C",
+    ClefSign::Percussion => return Some(String::from("*clefX")),
+    _ => return None,
+  };
+  let octave = match clef
+    .content
+    .clef_octave_change
+    .as_ref()
+    .map_or(0, |change| change.content)
+  {
+    change if change < 0 => "v".repeat(change.unsigned_abs() as usize),
+    change => "^".repeat(change.unsigned_abs() as usize),
+  };
+  let line = clef.content.line.as_ref().map_or(
+    match sign {
+      "G" => 2,
+      "F" => 4,
+      _ => 3,
+    },
+    |line| *line.content,
+  );
+  Some(format!("*clef{sign}{octave}{line}"))
+}
+
+fn kern_key_signature(fifths: i32) -> String {
+  let steps = if fifths >= 0 {
+    ["f#", "c#", "g#", "d#", "a#", "e#", "b#"]
+  } else {
+    ["b-", "e-", "a-", "d-", "g-", "c-", "f-"]
+  };
+  format!("*k[{}]", steps[..fifths.unsigned_abs().min(7) as usize].concat())
+}
+
+fn kern_key_designation(fifths: i32, mode: &str) -> Option<String> {
+  let index = usize::try_from(fifths + 7)
+    .ok()
+    .filter(|index| *index < MAJOR_KEYS.len())?;
+  match mode {
+    "major" => Some(format!("*{}:", MAJOR_KEYS[index])),
+    "minor" => MAJOR_KEYS
+      .get(index + 3)
+      .map(|tonic| format!("*{}:", tonic.to_ascii_lowercase())),
+    _ => None,
+  }
+}
+
+fn kern_meter(time: &Time) -> Option<String> {
+  let count = time
+    .content
+    .beats
+    .iter()
+    .map(|beats| beats.beats.content.as_str())
+    .collect::<Vec<_>>()
+    .join("+");
+  let unit = &time.content.beats.first()?.beat_type.content;
+  Some(format!("*M{count}/{unit}"))
+}
+
+fn kern_meter_symbol(time: &Time) -> Option<&'static str> {
+  match time.attributes.symbol {
+    Some(TimeSymbol::Common) => Some("*met(c)"),
+    Some(TimeSymbol::Cut) => Some("*met(c|)"),
+    _ => None,
+  }
+}
+
+/// Replaces characters which would break the tab-separated layout of a Humdrum file.
+fn spine_text(text: &str) -> String {
+  text.replace(['\t', '\n', '\r'], " ")
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// EXPORT -------------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+#[derive(PartialEq, Eq)]
+enum SpineKind {
+  Kern,
+  Text(String),
+  Harm,
+  Dynam,
+}
+
+/// A top-level spine of the exported file, which is split into one column per voice for `**kern` spines.
+struct ExportSpine {
+  kind: SpineKind,
+  part: usize,
+  staff: u32,
+  voices: Vec<String>,
+}
+
+/// Per-part state that carries across measures during export.
+struct PartExport<'a> {
+  measures: Vec<&'a Measure>,
+  staves: u32,
+  first_staff: u32,
+  timeline: PartTimeline,
+  open_wedges: BTreeMap<u8, bool>,
+}
+
+/// The position of a line within a measure: interpretations come first, followed by grace notes in order, followed
+/// by the line containing regularly timed events.
+type LineKey = (Fraction, u8, u32);
+
+const INTERPRETATION_LINE: u8 = 0;
+const GRACE_LINE: u8 = 1;
+const DATA_LINE: u8 = 2;
+
+struct KernExporter<'a> {
+  parts: Vec<PartExport<'a>>,
+  score_parts: Vec<&'a ScorePart>,
+  spines: Vec<ExportSpine>,
+  columns: Vec<(usize, Option<String>)>,
+  lines: Vec<String>,
+}
+
+impl<'a> KernExporter<'a> {
+  fn new(score: &'a ScorePartwise) -> Result<Self, String> {
+    let score_parts: Vec<&ScorePart> = score
+      .content
+      .part_list
+      .content
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        PartListElement::ScorePart(score_part) => Some(score_part),
+        PartListElement::PartGroup(_) => None,
+      })
+      .collect();
+    if let Some(part) = score.content.part.iter().find(|part| {
+      !score_parts
+        .iter()
+        .any(|score_part| score_part.attributes.id.0 == part.attributes.id.0)
+    }) {
+      return Err(format!(
+        "Part '{}' is not declared in the part list",
+        part.attributes.id.0
+      ));
+    }
+    if score_parts.is_empty() {
+      return Err(String::from("Score does not contain any parts"));
+    }
+    let mut parts = Vec::new();
+    let mut first_staff = 1;
+    for score_part in &score_parts {
+      let (measures, staves) = match score
+        .content
+        .part
+        .iter()
+        .find(|part| part.attributes.id.0 == score_part.attributes.id.0)
+      {
+        Some(part) => (part_measures(part).collect(), part_staves(part)),
+        None => (Vec::new(), 1),
+      };
+      parts.push(PartExport {
+        measures,
+        staves,
+        first_staff,
+        timeline: PartTimeline::default(),
+        open_wedges: BTreeMap::new(),
+      });
+      first_staff += staves;
+    }
+    let mut exporter = KernExporter {
+      parts,
+      score_parts,
+      spines: Vec::new(),
+      columns: Vec::new(),
+      lines: Vec::new(),
+    };
+    exporter.create_spines();
+    Ok(exporter)
+  }
+
+  /// Determines the spines required by every staff, along with the voices each `**kern` spine must hold.
+  fn create_spines(&mut self) {
+    for (index, part) in self.parts.iter().enumerate().rev() {
+      let mut voices: BTreeMap<u32, BTreeSet<(u32, String)>> = BTreeMap::new();
+      let mut verses: BTreeMap<u32, BTreeSet<(u32, String)>> = BTreeMap::new();
+      let (mut harmonies, mut dynamics) = (BTreeSet::new(), BTreeSet::new());
+      for element in part.measures.iter().flat_map(|measure| measure.content.iter()) {
+        match element {
+          MeasureElement::Note(note) => {
+            let voice = note_voice(note);
+            voices
+              .entry(note_staff(note))
+              .or_default()
+              .insert((voice.parse().unwrap_or(u32::MAX), String::from(voice)));
+            for (index, lyric) in note.content.lyric.iter().enumerate() {
+              let number = lyric
+                .attributes
+                .number
+                .as_ref()
+                .map_or_else(|| (index + 1).to_string(), |number| number.0.clone());
+              verses
+                .entry(note_staff(note))
+                .or_default()
+                .insert((number.parse().unwrap_or(u32::MAX), number));
+            }
+          }
+          MeasureElement::Direction(direction)
+            if direction.content.direction_type.iter().any(|direction_type| {
+              matches!(
+                direction_type.content,
+                DirectionTypeContents::Dynamics(_) | DirectionTypeContents::Wedge(_)
+              )
+            }) =>
+          {
+            dynamics.insert(direction.content.staff.as_ref().map_or(1, |staff| *staff.content));
+          }
+          MeasureElement::Harmony(harmony) => {
+            harmonies.insert(harmony.content.staff.as_ref().map_or(1, |staff| *staff.content));
+          }
+          _ => {}
+        }
+      }
+      for staff in (1..=part.staves).rev() {
+        let staff_voices: Vec<String> = voices.remove(&staff).map_or_else(
+          || vec![String::from("1")],
+          |voices| voices.into_iter().map(|(_, voice)| voice).collect(),
+        );
+        let spine = |kind| ExportSpine {
+          kind,
+          part: index,
+          staff,
+          voices: Vec::new(),
+        };
+        self.spines.push(ExportSpine {
+          voices: staff_voices,
+          ..spine(SpineKind::Kern)
+        });
+        for (_, verse) in verses.remove(&staff).unwrap_or_default() {
+          self.spines.push(spine(SpineKind::Text(verse)));
+        }
+        if harmonies.contains(&staff) {
+          self.spines.push(spine(SpineKind::Harm));
+        }
+        if dynamics.contains(&staff) {
+          self.spines.push(spine(SpineKind::Dynam));
+        }
+      }
+    }
+    for (index, spine) in self.spines.iter().enumerate() {
+      if spine.kind == SpineKind::Kern {
+        self
+          .columns
+          .extend(spine.voices.iter().map(|voice| (index, Some(voice.clone()))));
+      } else {
+        self.columns.push((index, None));
+      }
+    }
+  }
+
+  fn find_column(&self, part: usize, staff: u32, kind: &SpineKind, voice: Option<&str>) -> Option<usize> {
+    self.columns.iter().position(|(spine, column_voice)| {
+      let spine = &self.spines[*spine];
+      spine.part == part
+        && spine.staff == staff
+        && spine.kind == *kind
+        && (voice.is_none() || column_voice.as_deref() == voice)
+    })
+  }
+
+  fn kern_columns(&self, part: usize, staff: Option<u32>) -> impl Iterator<Item = usize> + '_ {
+    self.columns.iter().enumerate().filter_map(move |(index, (spine, _))| {
+      let spine = &self.spines[*spine];
+      (spine.kind == SpineKind::Kern && spine.part == part && staff.is_none_or(|staff| spine.staff == staff))
+        .then_some(index)
+    })
+  }
+
+  /// Writes a line containing one token per top-level spine, omitting it if every token would be empty.
+  fn spine_line(&mut self, token: impl Fn(&ExportSpine) -> Option<String>) {
+    let tokens: Vec<Option<String>> = self.spines.iter().map(token).collect();
+    if tokens.iter().any(Option::is_some) {
+      let tokens: Vec<String> = tokens
+        .into_iter()
+        .map(|token| token.unwrap_or_else(|| String::from("*")))
+        .collect();
+      self.lines.push(tokens.join("\t"));
+    }
+  }
+
+  /// Returns the attributes that apply at the very beginning of a part.
+  fn initial_attributes(&self, part: usize) -> impl Iterator<Item = &'a Attributes> + use<'a> {
+    let first: Option<&'a Measure> = self.parts[part].measures.first().copied();
+    first.into_iter().flat_map(|measure| {
+      measure
+        .content
+        .iter()
+        .take_while(|element| !matches!(element, MeasureElement::Note(_) | MeasureElement::Forward(_)))
+        .filter_map(|element| match element {
+          MeasureElement::Attributes(attributes) => Some(attributes),
+          _ => None,
+        })
+    })
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn export_header(&mut self) {
+    let line: Vec<&str> = self
+      .spines
+      .iter()
+      .map(|spine| match spine.kind {
+        SpineKind::Kern => "**kern",
+        SpineKind::Text(_) => "**text",
+        SpineKind::Harm => "**harm",
+        SpineKind::Dynam => "**dynam",
+      })
+      .collect();
+    self.lines.push(line.join("\t"));
+    self.spine_line(|spine| Some(format!("*part{}", spine.part + 1)));
+    let first_staves: Vec<u32> = self.parts.iter().map(|part| part.first_staff).collect();
+    self.spine_line(|spine| Some(format!("*staff{}", first_staves[spine.part] + spine.staff - 1)));
+    let names: Vec<(String, Option<String>)> = self
+      .score_parts
+      .iter()
+      .map(|score_part| {
+        (
+          spine_text(&score_part.content.part_name.content),
+          score_part
+            .content
+            .part_abbreviation
+            .as_ref()
+            .map(|abbreviation| spine_text(&abbreviation.content)),
+        )
+      })
+      .collect();
+    self.spine_line(|spine| {
+      (spine.kind == SpineKind::Kern && !names[spine.part].0.is_empty()).then(|| format!("*I\"{}", names[spine.part].0))
+    });
+    self.spine_line(|spine| {
+      names[spine.part]
+        .1
+        .as_ref()
+        .filter(|_| spine.kind == SpineKind::Kern)
+        .map(|abbreviation| format!("*I'{abbreviation}"))
+    });
+    let mut clefs: BTreeMap<(usize, u32), String> = BTreeMap::new();
+    let mut keys: BTreeMap<usize, (i32, Option<String>)> = BTreeMap::new();
+    let mut times: BTreeMap<usize, (String, Option<&str>)> = BTreeMap::new();
+    for part in 0..self.parts.len() {
+      for attributes in self.initial_attributes(part) {
+        for clef in &attributes.content.clef {
+          let staff = clef.attributes.number.as_ref().map_or(1, |number| u32::from(number.0));
+          if let Some(clef) = kern_clef(clef) {
+            clefs.entry((part, staff)).or_insert(clef);
+          }
+        }
+        if let Some(KeyContents::Explicit(key)) = attributes.content.key.first().map(|key| &key.content) {
+          let mode = key.mode.as_ref().map(|mode| Mode::serialize(&mode.content));
+          keys.entry(part).or_insert((i32::from(*key.fifths.content), mode));
+        }
+        if let Some(time) = attributes.content.time.first() {
+          if let Some(meter) = kern_meter(time) {
+            times.entry(part).or_insert((meter, kern_meter_symbol(time)));
+          }
+        }
+      }
+    }
+    let is_kern = |spine: &ExportSpine| spine.kind == SpineKind::Kern;
+    self.spine_line(|spine| {
+      clefs
+        .get(&(spine.part, spine.staff))
+        .filter(|_| is_kern(spine))
+        .cloned()
+    });
+    self.spine_line(|spine| {
+      keys
+        .get(&spine.part)
+        .filter(|_| is_kern(spine))
+        .map(|(fifths, _)| kern_key_signature(*fifths))
+    });
+    self.spine_line(|spine| {
+      keys
+        .get(&spine.part)
+        .filter(|_| is_kern(spine))
+        .and_then(|(fifths, mode)| kern_key_designation(*fifths, mode.as_deref()?))
+    });
+    self.spine_line(|spine| {
+      times
+        .get(&spine.part)
+        .filter(|_| is_kern(spine))
+        .map(|(meter, _)| meter.clone())
+    });
+    self.spine_line(|spine| {
+      times
+        .get(&spine.part)
+        .filter(|_| is_kern(spine))
+        .and_then(|(_, symbol)| symbol.map(String::from))
+    });
+    let mut widths: Vec<usize> = vec![1; self.spines.len()];
+    while self
+      .spines
+      .iter()
+      .zip(&widths)
+      .any(|(spine, width)| spine.voices.len() > *width)
+    {
+      let mut tokens = Vec::new();
+      for (spine, width) in self.spines.iter().zip(widths.iter_mut()) {
+        for index in 0..*width {
+          tokens.push(if index + 1 == *width && spine.voices.len() > *width {
+            "*^"
+          } else {
+            "*"
+          });
+        }
+        if spine.voices.len() > *width {
+          *width += 1;
+        }
+      }
+      self.lines.push(tokens.join("\t"));
+    }
+  }
+
+  fn export_footer(&mut self) {
+    if self.spines.iter().any(|spine| spine.voices.len() > 1) {
+      let tokens: Vec<&str> = self
+        .columns
+        .iter()
+        .map(|(spine, _)| {
+          if self.spines[*spine].voices.len() > 1 {
+            "*v"
+          } else {
+            "*"
+          }
+        })
+        .collect();
+      self.lines.push(tokens.join("\t"));
+    }
+    self.lines.push(vec!["*-"; self.spines.len()].join("\t"));
+  }
+
+  fn barline(&mut self, number: Option<&str>, previous: Option<&Measure>, next: Option<&Measure>, invisible: bool) {
+    let mut backward = false;
+    let mut style = "";
+    for barline in previous
+      .into_iter()
+      .flat_map(|measure| measure.content.iter())
+      .filter_map(|element| match element {
+        MeasureElement::Barline(barline) => Some(barline),
+        _ => None,
+      })
+      .filter(|barline| matches!(barline.attributes.location, None | Some(RightLeftMiddle::Right)))
+    {
+      backward |= barline
+        .content
+        .repeat
+        .as_ref()
+        .is_some_and(|repeat| repeat.attributes.direction == BackwardForward::Backward);
+      if let Some(bar_style) = &barline.content.bar_style {
+        style = match BarStyle::serialize(&bar_style.content).as_str() {
+          "light-light" => "||",
+          "light-heavy" => "|!",
+          "heavy-light" => "!|",
+          "heavy-heavy" => "!!",
+          _ => "",
+        };
+      }
+    }
+    let forward = next
+      .into_iter()
+      .flat_map(|measure| measure.content.iter())
+      .any(|element| {
+        matches!(element, MeasureElement::Barline(barline) if barline.attributes.location == Some(RightLeftMiddle::Left)
+          && barline.content.repeat.as_ref().is_some_and(|repeat| repeat.attributes.direction == BackwardForward::Forward))
+      });
+    let style = match (backward, forward) {
+      (true, true) => ":|!|:",
+      (true, false) => ":|!",
+      (false, true) => "!|:",
+      (false, false) => style,
+    };
+    let token = match number {
+      Some(number) => format!("={number}{style}{}", if invisible { "-" } else { "" }),
+      None => format!("=={}", if backward { ":|!" } else { "" }),
+    };
+    self.lines.push(vec![token.as_str(); self.columns.len()].join("\t"));
+  }
+
+  fn note_token(note: &Note, length: Fraction, first: bool) -> String {
+    let mut prefix = String::new();
+    let mut suffix = String::new();
+    let (tie_start, tie_stop) = note_tied(note);
+    let mut slur_stops = 0;
+    let mut marks = String::new();
+    for notation in note
+      .content
+      .notations
+      .iter()
+      .flat_map(|notations| &notations.content.notations)
+    {
+      match notation {
+        NotationContentTypes::Slur(slur) if first => match slur.attributes.r#type {
+          StartStopContinue::Start => prefix.push('('),
+          StartStopContinue::Stop => slur_stops += 1,
+          StartStopContinue::Continue => {}
+        },
+        NotationContentTypes::Articulations(articulations) => {
+          let serialized = Articulations::serialize(articulations);
+          for child in &serialized.elements {
+            if let Some((_, signifier)) = ARTICULATIONS.iter().find(|(name, _)| *name == child.name) {
+              marks += signifier;
+            }
+          }
+        }
+        NotationContentTypes::Fermata(_) => marks.push(';'),
+        _ => {}
+      }
+    }
+    if tie_start && !tie_stop {
+      prefix.push('[');
+    }
+    let rhythm = if note_is_grace(note) {
+      let notated = note.content.r#type.as_ref().map_or(Fraction::new(1, 2), |note_type| {
+        dotted_length(&note_type.content, note_dots(note))
+      });
+      let slash = matches!(&note.content.info, NoteType::Grace(grace)
+        if grace.grace.attributes.slash == Some(YesNo::Yes));
+      kern_recip(notated) + if slash { "q" } else { "Q" }
+    } else {
+      kern_recip(length)
+    };
+    let pitch = match note_audible(note) {
+      AudibleType::Pitch(pitch) => {
+        let alter = pitch_alter(pitch);
+        let accidental = if alter > 0 {
+          "#".repeat(alter.unsigned_abs() as usize)
+        } else if alter < 0 {
+          "-".repeat(alter.unsigned_abs() as usize)
+        } else if note
+          .content
+          .accidental
+          .as_ref()
+          .is_some_and(|accidental| accidental.content == AccidentalValue::Natural)
+        {
+          String::from("n")
+        } else {
+          String::new()
+        };
+        kern_pitch(
+          step_letter(&pitch.content.step.content),
+          i32::from(*pitch.content.octave.content),
+        ) + &accidental
+      }
+      AudibleType::Unpitched(unpitched) => kern_pitch(
+        step_letter(&unpitched.content.display_step.content),
+        i32::from(*unpitched.content.display_octave.content),
+      ),
+      AudibleType::Rest(_) => String::from("r"),
+    };
+    if first {
+      match note.content.stem.as_ref().map(|stem| &stem.content) {
+        Some(StemValue::Up) => marks.push('/'),
+        Some(StemValue::Down) => marks.push('\\'),
+        _ => {}
+      }
+      for beam in &note.content.beam {
+        match beam.content {
+          BeamValue::Begin => suffix.push('L'),
+          BeamValue::End => suffix.push('J'),
+          BeamValue::ForwardHook => suffix.push('K'),
+          BeamValue::BackwardHook => suffix.push('k'),
+          BeamValue::Continue => {}
+        }
+      }
+    }
+    if note.attributes.print_object == Some(YesNo::No) {
+      suffix += "yy";
+    }
+    match (tie_start, tie_stop) {
+      (true, true) => suffix.push('_'),
+      (false, true) => suffix.push(']'),
+      _ => {}
+    }
+    suffix += &")".repeat(slur_stops);
+    format!("{prefix}{rhythm}{pitch}{marks}{suffix}")
+  }
+
+  fn lyric_token(note: &Note, verse: &str) -> Option<String> {
+    let (_, lyric) = note.content.lyric.iter().enumerate().find(|(index, lyric)| {
+      lyric
+        .attributes
+        .number
+        .as_ref()
+        .map_or_else(|| (index + 1).to_string(), |number| number.0.clone())
+        == verse
+    })?;
+    let LyricContents::Text(text) = &lyric.content else {
+      return None;
+    };
+    let mut syllables = vec![(text.syllabic.as_ref(), text.text.content.as_str())];
+    syllables.extend(
+      text
+        .additional
+        .iter()
+        .map(|additional| (additional.syllabic.as_ref(), additional.text.content.as_str())),
+    );
+    let words: Vec<String> = syllables
+      .into_iter()
+      .map(|(syllabic, text)| match syllabic.map(|syllabic| &syllabic.content) {
+        Some(Syllabic::Begin) => format!("{text}-"),
+        Some(Syllabic::Middle) => format!("-{text}-"),
+        Some(Syllabic::End) => format!("-{text}"),
+        Some(Syllabic::Single) | None => String::from(text),
+      })
+      .collect();
+    let token = spine_text(&words.join(" "));
+    (!token.trim().is_empty()).then_some(token)
+  }
+
+  fn dynamic_token(&mut self, part: usize, direction: &Direction) -> Option<String> {
+    let mut tokens = Vec::new();
+    for direction_type in &direction.content.direction_type {
+      match &direction_type.content {
+        DirectionTypeContents::Dynamics(dynamics) => {
+          for dynamics in dynamics {
+            let serialized = Dynamics::serialize(dynamics);
+            let text: String = serialized
+              .elements
+              .iter()
+              .map(|child| {
+                if child.name == "other-dynamics" {
+                  child.text.as_ref()
+                } else {
+                  child.name.as_ref()
+                }
+              })
+              .collect();
+            if !text.is_empty() {
+              tokens.push(spine_text(&text));
+            }
+          }
+        }
+        DirectionTypeContents::Wedge(wedge) => {
+          let number = wedge.attributes.number.as_ref().map_or(1, |number| number.0);
+          let open_wedges = &mut self.parts[part].open_wedges;
+          match wedge.attributes.r#type {
+            WedgeType::Crescendo => {
+              open_wedges.insert(number, true);
+              tokens.push(String::from("<"));
+            }
+            WedgeType::Diminuendo => {
+              open_wedges.insert(number, false);
+              tokens.push(String::from(">"));
+            }
+            WedgeType::Stop => {
+              if let Some(crescendo) = open_wedges.remove(&number) {
+                tokens.push(String::from(if crescendo { "[" } else { "]" }));
+              }
+            }
+            WedgeType::Continue => {}
+          }
+        }
+        _ => {}
+      }
+    }
+    (!tokens.is_empty()).then(|| tokens.join(" "))
+  }
+
+  fn attribute_changes(&self, part: usize, attributes: &Attributes, line: &mut BTreeMap<u32, Vec<(usize, String)>>) {
+    for clef in &attributes.content.clef {
+      let staff = clef.attributes.number.as_ref().map_or(1, |number| u32::from(number.0));
+      if let Some(token) = kern_clef(clef) {
+        line.entry(0).or_default().extend(
+          self
+            .kern_columns(part, Some(staff))
+            .map(|column| (column, token.clone())),
+        );
+      }
+    }
+    if let Some(KeyContents::Explicit(key)) = attributes.content.key.first().map(|key| &key.content) {
+      let token = kern_key_signature(i32::from(*key.fifths.content));
+      line
+        .entry(1)
+        .or_default()
+        .extend(self.kern_columns(part, None).map(|column| (column, token.clone())));
+    }
+    if let Some(time) = attributes.content.time.first() {
+      if let Some(token) = kern_meter(time) {
+        line
+          .entry(2)
+          .or_default()
+          .extend(self.kern_columns(part, None).map(|column| (column, token.clone())));
+      }
+      if let Some(token) = kern_meter_symbol(time) {
+        line.entry(3).or_default().extend(
+          self
+            .kern_columns(part, None)
+            .map(|column| (column, String::from(token))),
+        );
+      }
+    }
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn export_measure(&mut self, index: usize) {
+    let width = self.columns.len();
+    let mut lines: BTreeMap<LineKey, Vec<Option<String>>> = BTreeMap::new();
+    let mut ends = vec![Fraction::ZERO; width];
+    let mut length = Fraction::ZERO;
+    let mut pending: Vec<(usize, Fraction, String)> = Vec::new();
+    let set = |lines: &mut BTreeMap<LineKey, Vec<Option<String>>>, key: LineKey, column: usize, token: String| {
+      let tokens = lines.entry(key).or_insert_with(|| vec![None; width]);
+      tokens[column] = Some(match tokens[column].take() {
+        Some(existing) => existing + " " + &token,
+        None => token,
+      });
+    };
+    for part in 0..self.parts.len() {
+      let Some(measure) = self.parts[part].measures.get(index).copied() else {
+        continue;
+      };
+      let mut timeline = self.parts[part].timeline;
+      let measure_timeline = timeline.measure(measure);
+      self.parts[part].timeline = timeline;
+      let mut grace_counts: BTreeMap<usize, u32> = BTreeMap::new();
+      for event in &measure_timeline.events {
+        match &event.event {
+          MeasureEvent::Notes(notes) => {
+            let first = notes[0];
+            let staff = note_staff(first);
+            let Some(column) = self.find_column(part, staff, &SpineKind::Kern, Some(note_voice(first))) else {
+              continue;
+            };
+            let key = if note_is_grace(first) {
+              let count = grace_counts.entry(column).or_default();
+              *count += 1;
+              (event.position, GRACE_LINE, *count)
+            } else {
+              grace_counts.remove(&column);
+              if event.position > ends[column] {
+                for (position, token) in invisible_rests(ends[column], event.position) {
+                  set(&mut lines, (position, DATA_LINE, 0), column, token);
+                }
+              }
+              ends[column] = ends[column].max(event.position + event.duration);
+              length = length.max(event.position + event.duration);
+              (event.position, DATA_LINE, 0)
+            };
+            let tokens: Vec<String> = notes
+              .iter()
+              .enumerate()
+              .map(|(index, note)| Self::note_token(note, event.duration, index == 0))
+              .collect();
+            set(&mut lines, key, column, tokens.join(" "));
+            for (text_column, (spine, _)) in self.columns.iter().enumerate() {
+              let spine = &self.spines[*spine];
+              if let (SpineKind::Text(verse), true) = (&spine.kind, spine.part == part && spine.staff == staff) {
+                if let Some(token) = notes.iter().find_map(|note| Self::lyric_token(note, verse)) {
+                  set(&mut lines, key, text_column, token);
+                }
+              }
+            }
+          }
+          MeasureEvent::Attributes(attributes) => {
+            if index > 0 || event.position.is_positive() {
+              let mut changes = BTreeMap::new();
+              self.attribute_changes(part, attributes, &mut changes);
+              for (kind, tokens) in changes {
+                for (column, token) in tokens {
+                  let tokens = lines
+                    .entry((event.position, INTERPRETATION_LINE, kind))
+                    .or_insert_with(|| vec![None; width]);
+                  tokens[column] = Some(token);
+                }
+              }
+            }
+          }
+          MeasureEvent::Direction(direction) => {
+            let staff = direction.content.staff.as_ref().map_or(1, |staff| *staff.content);
+            if let Some(token) = self.dynamic_token(part, direction) {
+              if let Some(column) = self.find_column(part, staff, &SpineKind::Dynam, None) {
+                pending.push((column, event.position, token));
+              }
+            }
+          }
+          MeasureEvent::Harmony(harmony) => {
+            let staff = harmony.content.staff.as_ref().map_or(1, |staff| *staff.content);
+            let text = spine_text(&harmony_element_text(harmony));
+            if let (Some(column), false) = (
+              self.find_column(part, staff, &SpineKind::Harm, None),
+              text.trim().is_empty(),
+            ) {
+              pending.push((column, event.position, text));
+            }
+          }
+          MeasureEvent::Sound(_) => {}
+        }
+      }
+    }
+    for (column, (spine, _)) in self.columns.iter().enumerate() {
+      if self.spines[*spine].kind == SpineKind::Kern && length > ends[column] {
+        for (position, token) in invisible_rests(ends[column], length) {
+          set(&mut lines, (position, DATA_LINE, 0), column, token);
+        }
+      }
+    }
+    for (column, position, token) in pending {
+      let position = lines
+        .keys()
+        .filter(|(line_position, kind, _)| *kind == DATA_LINE && *line_position <= position)
+        .map(|(line_position, _, _)| *line_position)
+        .next_back()
+        .unwrap_or(Fraction::ZERO);
+      set(&mut lines, (position, DATA_LINE, 0), column, token);
+    }
+    for ((_, kind, _), tokens) in lines {
+      let empty = if kind == INTERPRETATION_LINE { "*" } else { "." };
+      let tokens: Vec<String> = tokens
+        .into_iter()
+        .map(|token| token.unwrap_or_else(|| String::from(empty)))
+        .collect();
+      self.lines.push(tokens.join("\t"));
+    }
+  }
+
+  fn export_measures(&mut self) {
+    let count = self.parts.iter().map(|part| part.measures.len()).max().unwrap_or(0);
+    let reference =
+      |exporter: &Self, index: usize| exporter.parts.iter().find_map(|part| part.measures.get(index).copied());
+    for index in 0..count {
+      let measure = reference(self, index);
+      let number = measure.map_or_else(
+        || (index + 1).to_string(),
+        |measure| measure.attributes.number.0.clone(),
+      );
+      if index > 0 {
+        self.barline(Some(&number), reference(self, index - 1), measure, false);
+      } else if measure.is_none_or(|measure| measure.attributes.implicit != Some(YesNo::Yes)) {
+        self.barline(Some(&number), None, measure, true);
+      }
+      self.export_measure(index);
+    }
+    self.barline(
+      None,
+      count.checked_sub(1).and_then(|index| reference(self, index)),
+      None,
+      false,
+    );
+  }
+}
+
+/// Returns the invisible rests which fill the gap between two positions in a `**kern` sub-spine.
+fn invisible_rests(start: Fraction, end: Fraction) -> Vec<(Fraction, String)> {
+  let lengths: Vec<Fraction> = split_length(end - start)
+    .iter()
+    .map(|(note_type, dots)| dotted_length(note_type, *dots))
+    .collect();
+  let lengths = if lengths.iter().fold(Fraction::ZERO, |total, length| total + *length) == end - start {
+    lengths
+  } else {
+    vec![end - start]
+  };
+  let mut position = start;
+  lengths
+    .into_iter()
+    .map(|length| {
+      let rest = (position, kern_recip(length) + "ryy");
+      position += length;
+      rest
+    })
+    .collect()
+}
+
+fn export_references(score: &ScorePartwise) -> Vec<String> {
+  let mut references = Vec::new();
+  if let Some(identification) = &score.content.identification {
+    for creator in &identification.content.creator {
+      let role = creator.attributes.r#type.as_ref().map(|role| role.0.as_str());
+      if let Some((key, _)) = CREATORS.iter().find(|(_, name)| Some(*name) == role) {
+        references.push(format!("!!!{key}: {}", spine_text(&creator.content)));
+      }
+    }
+  }
+  if let Some(title) = score
+    .content
+    .work
+    .as_ref()
+    .and_then(|work| work.content.work_title.as_ref())
+  {
+    references.push(format!("!!!OTL: {}", spine_text(&title.content)));
+  }
+  if let Some(number) = &score.content.movement_number {
+    references.push(format!("!!!OMV: {}", spine_text(&number.content)));
+  }
+  if let Some(title) = &score.content.movement_title {
+    references.push(format!("!!!OMD: {}", spine_text(&title.content)));
+  }
+  if let Some(identification) = &score.content.identification {
+    for rights in &identification.content.rights {
+      references.push(format!("!!!YEC: {}", spine_text(&rights.content)));
+    }
+  }
+  references
+}
+
+/// Converts a [ScorePartwise] into a Humdrum file containing `**kern` spines.
+///
+/// Each staff of each [ScorePart] is written as a separate `**kern` spine, and the voices on a staff are written to
+/// sub-spines which are split at the beginning of the file and merged at its end. Positions at which a voice does not
+/// contain any notes are filled with invisible rests so that every sub-spine remains rhythmically complete.
+///
+/// # Errors
+///
+/// Returns an error if the score does not contain any parts or contains a part that is not declared in its part list.
+pub fn score_partwise_to_kern(score: &ScorePartwise) -> Result<String, String> {
+  let mut exporter = KernExporter::new(score)?;
+  exporter.lines = export_references(score);
+  exporter.export_header();
+  exporter.export_measures();
+  exporter.export_footer();
+  Ok(exporter.lines.join("\n") + "\n")
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// IMPORT -------------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TrackKind {
+  Kern,
+  Text,
+  Harm,
+  Dynam,
+  Other,
+}
+
+/// A top-level spine of the imported file along with the interpretations that apply to it.
+struct Track {
+  kind: TrackKind,
+  owner: usize,
+  verse: u32,
+  part: Option<String>,
+  name: Option<String>,
+  abbreviation: Option<String>,
+  clef: Option<XmlElement<'static>>,
+  key: Option<i32>,
+  mode: Option<&'static str>,
+  time: Option<XmlElement<'static>>,
+}
+
+/// A column of the imported file, which is either a top-level spine or one of its sub-spines.
+#[derive(Clone)]
+struct Column {
+  track: usize,
+  position: Fraction,
+  beams: usize,
+  slurs: u8,
+}
+
+/// The contents of a single `**kern` token or chord member.
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct KernToken {
+  length: Option<Fraction>,
+  base: Fraction,
+  dots: u8,
+  grace: Option<bool>,
+  pitch: Option<(char, i32)>,
+  alter: i32,
+  natural: bool,
+  rest: bool,
+  invisible: bool,
+  tie_start: bool,
+  tie_stop: bool,
+  slur_starts: u8,
+  slur_stops: u8,
+  beam_starts: usize,
+  beam_ends: usize,
+  articulations: Vec<&'static str>,
+  fermata: bool,
+  stem: Option<&'static str>,
+}
+
+impl KernToken {
+  fn parse(token: &str) -> Option<Self> {
+    let mut parsed = KernToken::default();
+    let mut chars = token.chars().peekable();
+    let mut rhythm = String::new();
+    let mut rhythm_done = false;
+    while let Some(ch) = chars.next() {
+      match ch {
+        '0'..='9' | '%' if !rhythm_done => rhythm.push(ch),
+        '.' => parsed.dots += 1,
+        'a'..='g' | 'A'..='G' if parsed.pitch.is_none() => {
+          let mut count = 1;
+          while chars.next_if_eq(&ch).is_some() {
+            count += 1;
+          }
+          let octave = if ch.is_ascii_lowercase() { 3 + count } else { 4 - count };
+          parsed.pitch = Some((ch.to_ascii_uppercase(), octave));
+        }
+        'r' => parsed.rest = true,
+        '#' => parsed.alter += 1,
+        '-' => parsed.alter -= 1,
+        'n' => parsed.natural = true,
+        'q' => parsed.grace = Some(true),
+        'Q' => parsed.grace = Some(false),
+        '[' => parsed.tie_start = true,
+        ']' => parsed.tie_stop = true,
+        '_' => (parsed.tie_start, parsed.tie_stop) = (true, true),
+        '(' => parsed.slur_starts += 1,
+        ')' => parsed.slur_stops += 1,
+        'L' => parsed.beam_starts += 1,
+        'J' => parsed.beam_ends += 1,
+        'y' => parsed.invisible = true,
+        ';' => parsed.fermata = true,
+        '/' => parsed.stem = Some("up"),
+        '\\' => parsed.stem = Some("down"),
+        '^' => {
+          parsed.articulations.push(if chars.next_if_eq(&'^').is_some() {
+            "strong-accent"
+          } else {
+            "accent"
+          });
+        }
+        '`' => parsed.articulations.push("staccatissimo"),
+        '\'' => parsed.articulations.push("staccato"),
+        '~' => parsed.articulations.push("tenuto"),
+        _ => {}
+      }
+      if !rhythm.is_empty() && !matches!(chars.peek(), Some('0'..='9' | '%')) {
+        rhythm_done = true;
+      }
+    }
+    if parsed.pitch.is_none() && !parsed.rest {
+      return None;
+    }
+    if !rhythm.is_empty() {
+      let base = if rhythm.chars().all(|ch| ch == '0') {
+        Fraction::from_int(4 << rhythm.len())
+      } else {
+        let (recip, den) = rhythm.split_once('%').unwrap_or((&rhythm, "1"));
+        let (recip, den): (i64, i64) = (recip.parse().ok()?, den.parse().ok()?);
+        if recip == 0 {
+          return None;
+        }
+        Fraction::new(4 * den, recip)
+      };
+      let mut length = base;
+      let mut addition = base;
+      for _ in 0..parsed.dots {
+        addition = addition * Fraction::new(1, 2);
+        length += addition;
+      }
+      parsed.base = base;
+      parsed.length = Some(length);
+    } else if parsed.grace.is_none() {
+      return None;
+    }
+    Some(parsed)
+  }
+}
+
+/// A MusicXML note under construction, kept separate from its XML form until tuplets and lyrics have been applied.
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct ImportedNote {
+  position: Fraction,
+  duration: Fraction,
+  grace: Option<bool>,
+  chord: bool,
+  pitch: Option<(char, i32, i32)>,
+  invisible: bool,
+  accidental: Option<&'static str>,
+  note_type: Option<(NoteTypeValue, u8)>,
+  time_modification: Option<(i64, i64)>,
+  tie_start: bool,
+  tie_stop: bool,
+  slur_starts: Vec<u8>,
+  slur_stops: Vec<u8>,
+  beam: Option<&'static str>,
+  tuplet: Vec<&'static str>,
+  articulations: Vec<&'static str>,
+  fermata: bool,
+  stem: Option<&'static str>,
+  lyrics: Vec<XmlElement<'static>>,
+}
+
+impl ImportedNote {
+  fn new(token: &KernToken, position: Fraction) -> Self {
+    let mut note = ImportedNote {
+      position,
+      grace: token.grace,
+      pitch: token.pitch.map(|(step, octave)| (step, token.alter, octave)),
+      invisible: token.invisible,
+      accidental: token.natural.then_some("natural"),
+      tie_start: token.tie_start,
+      tie_stop: token.tie_stop,
+      articulations: token.articulations.clone(),
+      fermata: token.fermata,
+      stem: token.stem,
+      ..Default::default()
+    };
+    if note.grace.is_none() {
+      note.duration = token.length.unwrap_or_default();
+    }
+    let base = if token.length.is_some() {
+      token.base
+    } else {
+      Fraction::new(1, 2)
+    };
+    if let Some((note_type, _)) = length_to_note_type(base) {
+      note.note_type = Some((note_type, token.dots));
+    } else if let Some(note_type) = NOTE_TYPES
+      .into_iter()
+      .rev()
+      .find(|note_type| note_type_length(note_type) >= base)
+    {
+      let ratio = note_type_length(&note_type) / base;
+      note.note_type = Some((note_type, token.dots));
+      note.time_modification = Some((ratio.num, ratio.den));
+    }
+    note
+  }
+
+  fn to_xml(&self, voice: &str, staff: Option<u32>) -> XmlElement<'static> {
+    let mut note = XmlElement::named("note");
+    if self.invisible {
+      note = note.with_attribute("print-object", "no");
+    }
+    if let Some(slash) = self.grace {
+      let grace = XmlElement::named("grace");
+      note = note.with_child(if slash {
+        grace.with_attribute("slash", "yes")
+      } else {
+        grace
+      });
+    }
+    if self.chord {
+      note = note.with_child(XmlElement::named("chord"));
+    }
+    note = note.with_child(match self.pitch {
+      Some((step, alter, octave)) => {
+        let mut pitch = XmlElement::named("pitch").with_child(XmlElement::named("step").with_text(step.to_string()));
+        if alter != 0 {
+          pitch = pitch.with_child(XmlElement::named("alter").with_text(alter.to_string()));
+        }
+        pitch.with_child(XmlElement::named("octave").with_text(octave.to_string()))
+      }
+      None => XmlElement::named("rest"),
+    });
+    if self.grace.is_none() {
+      note = note.with_child(XmlElement::named("duration").with_text(format_fraction(self.duration)));
+    }
+    if self.tie_stop {
+      note = note.with_child(XmlElement::named("tie").with_attribute("type", "stop"));
+    }
+    if self.tie_start {
+      note = note.with_child(XmlElement::named("tie").with_attribute("type", "start"));
+    }
+    note = note.with_child(XmlElement::named("voice").with_text(voice));
+    if let Some((note_type, dots)) = &self.note_type {
+      note = note.with_child(XmlElement::named("type").with_text(NoteTypeValue::serialize(note_type)));
+      for _ in 0..*dots {
+        note = note.with_child(XmlElement::named("dot"));
+      }
+    }
+    if let Some(accidental) = self.accidental {
+      note = note.with_child(XmlElement::named("accidental").with_text(accidental));
+    }
+    if let Some((actual, normal)) = self.time_modification {
+      note = note.with_child(
+        XmlElement::named("time-modification")
+          .with_child(XmlElement::named("actual-notes").with_text(actual.to_string()))
+          .with_child(XmlElement::named("normal-notes").with_text(normal.to_string())),
+      );
+    }
+    if let Some(stem) = self.stem {
+      note = note.with_child(XmlElement::named("stem").with_text(stem));
+    }
+    if let Some(staff) = staff {
+      note = note.with_child(XmlElement::named("staff").with_text(staff.to_string()));
+    }
+    if let Some(beam) = self.beam {
+      note = note.with_child(XmlElement::named("beam").with_attribute("number", "1").with_text(beam));
+    }
+    let mut notations = XmlElement::named("notations");
+    if self.tie_stop {
+      notations = notations.with_child(XmlElement::named("tied").with_attribute("type", "stop"));
+    }
+    if self.tie_start {
+      notations = notations.with_child(XmlElement::named("tied").with_attribute("type", "start"));
+    }
+    for number in &self.slur_stops {
+      notations = notations.with_child(
+        XmlElement::named("slur")
+          .with_attribute("type", "stop")
+          .with_attribute("number", number.to_string()),
+      );
+    }
+    for number in &self.slur_starts {
+      notations = notations.with_child(
+        XmlElement::named("slur")
+          .with_attribute("type", "start")
+          .with_attribute("number", number.to_string()),
+      );
+    }
+    for tuplet in &self.tuplet {
+      notations = notations.with_child(XmlElement::named("tuplet").with_attribute("type", *tuplet));
+    }
+    if !self.articulations.is_empty() {
+      let mut articulations = XmlElement::named("articulations");
+      for articulation in &self.articulations {
+        articulations = articulations.with_child(XmlElement::named(articulation));
+      }
+      notations = notations.with_child(articulations);
+    }
+    if self.fermata {
+      notations = notations.with_child(XmlElement::named("fermata"));
+    }
+    if !notations.elements.is_empty() {
+      note = note.with_child(notations);
+    }
+    note.elements.extend(self.lyrics.iter().cloned());
+    note
+  }
+}
+
+/// The contents of a single measure, collected across every spine of the imported file.
+#[derive(Default)]
+struct ImportedMeasure {
+  number: String,
+  forward_repeat: bool,
+  backward_repeat: bool,
+  bar_style: Option<&'static str>,
+  notes: BTreeMap<(usize, u32), Vec<ImportedNote>>,
+  changes: Vec<(usize, Fraction, XmlElement<'static>)>,
+  events: Vec<(usize, Fraction, XmlElement<'static>)>,
+}
+
+/// The key, time, and clef changes which occur at a single position within an imported measure.
+type AttributeChanges = (
+  Option<XmlElement<'static>>,
+  Option<XmlElement<'static>>,
+  Vec<XmlElement<'static>>,
+);
+
+#[derive(Default)]
+struct KernImporter {
+  tracks: Vec<Track>,
+  columns: Vec<Column>,
+  measures: Vec<ImportedMeasure>,
+  references: Vec<(String, String)>,
+  time: Fraction,
+  started: bool,
+  measure_started: bool,
+}
+
+/// Returns how many more times `up` than `down` appears in a token, such as the octave marks of a clef.
+fn count_difference(text: &str, up: char, down: char) -> i32 {
+  let count = |symbol| i32::try_from(text.matches(symbol).count()).unwrap_or(i32::MAX);
+  count(up) - count(down)
+}
+
+fn clef_from_kern(clef: &str) -> Option<XmlElement<'static>> {
+  let mut chars = clef.chars();
+  let sign = match chars.next()? {
+    'X' => return Some(XmlElement::named("clef").with_child(XmlElement::named("sign").with_text("percussion"))),
+    sign @ ('G' | 'F' | 'C') => sign,
+    _ => return None,
+  };
+  let rest = chars.as_str();
+  let octave_change = count_difference(rest, '^', 'v');
+  let line = rest
+    .trim_start_matches(['^', 'v'])
+    .parse::<u32>()
+    .ok()
+    .unwrap_or(match sign {
+      'G' => 2,
+      'F' => 4,
+      _ => 3,
+    });
+  let mut element = XmlElement::named("clef")
+    .with_child(XmlElement::named("sign").with_text(sign.to_string()))
+    .with_child(XmlElement::named("line").with_text(line.to_string()));
+  if octave_change != 0 {
+    element = element.with_child(XmlElement::named("clef-octave-change").with_text(octave_change.to_string()));
+  }
+  Some(element)
+}
+
+fn key_xml(fifths: i32, mode: Option<&str>) -> XmlElement<'static> {
+  let key = XmlElement::named("key").with_child(XmlElement::named("fifths").with_text(fifths.to_string()));
+  match mode {
+    Some(mode) => key.with_child(XmlElement::named("mode").with_text(mode)),
+    None => key,
+  }
+}
+
+fn time_from_kern(meter: &str) -> Option<XmlElement<'static>> {
+  let (count, unit) = meter.split_once('/')?;
+  if count.is_empty() || !unit.chars().all(|ch| ch.is_ascii_digit()) || unit.is_empty() {
+    return None;
+  }
+  Some(
+    XmlElement::named("time")
+      .with_child(XmlElement::named("beats").with_text(count))
+      .with_child(XmlElement::named("beat-type").with_text(unit)),
+  )
+}
+
+fn move_cursor(measure: &mut XmlElement, cursor: &mut Fraction, position: Fraction) {
+  if position < *cursor {
+    measure.elements.push(
+      XmlElement::named("backup")
+        .with_child(XmlElement::named("duration").with_text(format_fraction(*cursor - position))),
+    );
+  } else if position > *cursor {
+    measure.elements.push(
+      XmlElement::named("forward")
+        .with_child(XmlElement::named("duration").with_text(format_fraction(position - *cursor))),
+    );
+  }
+  *cursor = position;
+}
+
+/// Marks the first and last notes of each run of tuplet notes which together fill a complete tuplet group.
+fn apply_tuplets(notes: &mut [ImportedNote]) {
+  let mut open: Option<(usize, (i64, i64), Fraction)> = None;
+  let mut previous: Option<usize> = None;
+  for index in 0..notes.len() {
+    let note = &notes[index];
+    if note.chord || note.grace.is_some() {
+      continue;
+    }
+    let ratio = note.time_modification;
+    if let Some((start, open_ratio, _)) = open {
+      if ratio != Some(open_ratio) {
+        notes[start].tuplet.push("start");
+        if let Some(previous) = previous {
+          notes[previous].tuplet.push("stop");
+        }
+        open = None;
+      }
+    }
+    let note = &notes[index];
+    if let Some((actual, normal)) = note.time_modification {
+      let (start, _, filled) = open.get_or_insert((index, (actual, normal), Fraction::ZERO));
+      *filled += note.duration;
+      let group = note
+        .note_type
+        .as_ref()
+        .map_or(note.duration, |(note_type, _)| note_type_length(note_type))
+        * Fraction::from_int(normal);
+      if *filled >= group {
+        let start = *start;
+        notes[start].tuplet.push("start");
+        notes[index].tuplet.push("stop");
+        open = None;
+      }
+    }
+    previous = Some(index);
+  }
+  if let (Some((start, _, _)), Some(previous)) = (open, previous) {
+    notes[start].tuplet.push("start");
+    notes[previous].tuplet.push("stop");
+  }
+}
+
+fn lyric_xml(token: &str, verse: u32) -> Option<XmlElement<'static>> {
+  let token = token.trim();
+  if token.is_empty() || token == "_" {
+    return None;
+  }
+  let (begins, ends) = (
+    token.len() > 1 && token.starts_with('-'),
+    token.len() > 1 && token.ends_with('-'),
+  );
+  let syllabic = match (begins, ends) {
+    (true, true) => "middle",
+    (false, true) => "begin",
+    (true, false) => "end",
+    (false, false) => "single",
+  };
+  let text = token.strip_prefix('-').filter(|_| begins).unwrap_or(token);
+  let text = text.strip_suffix('-').filter(|_| ends).unwrap_or(text);
+  Some(
+    XmlElement::named("lyric")
+      .with_attribute("number", verse.to_string())
+      .with_child(XmlElement::named("syllabic").with_text(syllabic))
+      .with_child(XmlElement::named("text").with_text(text)),
+  )
+}
+
+fn dynamics_xml(token: &str, staff: Option<u32>) -> Vec<XmlElement<'static>> {
+  let mut directions = Vec::new();
+  for part in token.split(' ') {
+    let direction_type = match part {
+      "<" => XmlElement::named("wedge").with_attribute("type", "crescendo"),
+      ">" => XmlElement::named("wedge").with_attribute("type", "diminuendo"),
+      "[" | "]" => XmlElement::named("wedge").with_attribute("type", "stop"),
+      dynamic if DYNAMICS.contains(&dynamic) => XmlElement::named("dynamics").with_child(XmlElement::named(dynamic)),
+      dynamic if !dynamic.is_empty() && dynamic.chars().all(char::is_alphabetic) => {
+        XmlElement::named("dynamics").with_child(XmlElement::named("other-dynamics").with_text(dynamic))
+      }
+      _ => continue,
+    };
+    directions.push(direction_xml(direction_type, Some("below"), staff));
+  }
+  directions
+}
+
+impl KernImporter {
+  fn current(&mut self) -> &mut ImportedMeasure {
+    if self.measures.is_empty() {
+      self.measures.push(ImportedMeasure {
+        number: String::from("0"),
+        ..Default::default()
+      });
+    }
+    let index = self.measures.len() - 1;
+    &mut self.measures[index]
+  }
+
+  fn read_exclusive_interpretations(&mut self, tokens: &[&str]) {
+    let mut owner = None;
+    for (index, token) in tokens.iter().enumerate() {
+      let kind = match *token {
+        "**kern" => TrackKind::Kern,
+        "**text" | "**silbe" => TrackKind::Text,
+        "**harm" | "**mxhm" => TrackKind::Harm,
+        "**dynam" | "**dyn" => TrackKind::Dynam,
+        _ => TrackKind::Other,
+      };
+      if kind == TrackKind::Kern {
+        owner = Some(index);
+      }
+      let verse = if kind == TrackKind::Text {
+        let earlier = self
+          .tracks
+          .iter()
+          .filter(|track| track.kind == TrackKind::Text && Some(track.owner) == owner)
+          .count();
+        u32::try_from(earlier).map_or(u32::MAX, |earlier| earlier + 1)
+      } else {
+        0
+      };
+      self.tracks.push(Track {
+        kind,
+        owner: owner.unwrap_or(usize::MAX),
+        verse,
+        part: None,
+        name: None,
+        abbreviation: None,
+        clef: None,
+        key: None,
+        mode: None,
+        time: None,
+      });
+      self.columns.push(Column {
+        track: index,
+        position: Fraction::ZERO,
+        beams: 0,
+        slurs: 0,
+      });
+    }
+    let first_kern = self.tracks.iter().position(|track| track.kind == TrackKind::Kern);
+    for track in &mut self.tracks {
+      if track.owner == usize::MAX {
+        track.owner = first_kern.unwrap_or(usize::MAX);
+      }
+    }
+  }
+
+  fn record_change(&mut self, track: usize, element: XmlElement<'static>) {
+    let time = self.time;
+    let measure = self.current();
+    if !measure.changes.iter().any(|(change_track, position, change)| {
+      *change_track == track && *position == time && change.name == element.name
+    }) {
+      measure.changes.push((track, time, element));
+    }
+  }
+
+  fn read_interpretation(&mut self, track: usize, token: &str) -> Result<(), String> {
+    let Some(interpretation) = token.strip_prefix('*') else {
+      return Ok(());
+    };
+    if matches!(interpretation, "+" | "x") {
+      return Err(format!("Unsupported spine manipulator '{token}'"));
+    }
+    if self.tracks[track].kind != TrackKind::Kern {
+      return Ok(());
+    }
+    if let Some(clef) = interpretation.strip_prefix("clef") {
+      if let Some(clef) = clef_from_kern(clef) {
+        if self.started {
+          self.record_change(track, clef);
+        } else {
+          self.tracks[track].clef = Some(clef);
+        }
+      }
+    } else if let Some(signature) = interpretation.strip_prefix("k[").and_then(|key| key.strip_suffix(']')) {
+      let fifths = count_difference(signature, '#', '-');
+      if self.started {
+        self.record_change(track, key_xml(fifths, None));
+      } else {
+        self.tracks[track].key = Some(fifths);
+      }
+    } else if let Some(time) = interpretation.strip_prefix('M').and_then(time_from_kern) {
+      if self.started {
+        self.record_change(track, time);
+      } else {
+        self.tracks[track].time = Some(time);
+      }
+    } else if let Some(symbol) = interpretation
+      .strip_prefix("met(")
+      .and_then(|symbol| symbol.strip_suffix(')'))
+    {
+      let symbol = match symbol {
+        "c" => "common",
+        "c|" => "cut",
+        _ => return Ok(()),
+      };
+      if let Some(time) = &mut self.tracks[track].time {
+        if !self.started && time.attribute("symbol").is_none() {
+          time
+            .attributes
+            .push((Cow::Borrowed("symbol"), Cow::Owned(String::from(symbol))));
+        }
+      }
+    } else if let Some(part) = interpretation.strip_prefix("part") {
+      self.tracks[track].part = Some(String::from(part));
+    } else if let Some(name) = interpretation.strip_prefix("I\"") {
+      self.tracks[track].name = Some(String::from(name));
+    } else if let Some(abbreviation) = interpretation.strip_prefix("I'") {
+      self.tracks[track].abbreviation = Some(String::from(abbreviation));
+    } else if let Some(tonic) = interpretation.strip_suffix(':') {
+      if let Some(first) = tonic
+        .chars()
+        .next()
+        .filter(|ch| matches!(ch.to_ascii_lowercase(), 'a'..='g'))
+      {
+        if !self.started {
+          self.tracks[track].mode = Some(if first.is_ascii_uppercase() { "major" } else { "minor" });
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn read_manipulators(&mut self, tokens: &[&str]) -> Result<(), String> {
+    let mut columns = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+      let column = self.columns[index].clone();
+      match tokens[index] {
+        "*^" => {
+          columns.push(column.clone());
+          columns.push(Column {
+            beams: 0,
+            slurs: 0,
+            ..column
+          });
+        }
+        "*v" => {
+          let mut merged = column.clone();
+          while index + 1 < tokens.len() && tokens[index + 1] == "*v" && self.columns[index + 1].track == column.track {
+            index += 1;
+            merged.position = merged.position.max(self.columns[index].position);
+          }
+          columns.push(merged);
+        }
+        "*-" => {}
+        token => {
+          self.read_interpretation(column.track, token)?;
+          columns.push(column);
+        }
+      }
+      index += 1;
+    }
+    self.columns = columns;
+    Ok(())
+  }
+
+  fn read_barline(&mut self, token: &str) {
+    let marks = token.trim_start_matches('=');
+    let number: String = marks.chars().take_while(char::is_ascii_digit).collect();
+    let backward = marks.contains(":|") || marks.contains(":!");
+    let forward = marks.contains("|:") || marks.contains("!:");
+    let style = if token.starts_with("==") {
+      Some("light-heavy")
+    } else if marks.contains("||") {
+      Some("light-light")
+    } else if marks.contains("|!") {
+      Some("light-heavy")
+    } else if marks.contains("!|") {
+      Some("heavy-light")
+    } else if marks.contains("!!") {
+      Some("heavy-heavy")
+    } else {
+      None
+    };
+    self.time = Fraction::ZERO;
+    for column in &mut self.columns {
+      column.position = Fraction::ZERO;
+    }
+    if !self.measure_started {
+      let measure = self.current();
+      if !number.is_empty() {
+        measure.number = number;
+      }
+      measure.forward_repeat |= forward;
+      return;
+    }
+    let measure = self.current();
+    measure.backward_repeat |= backward;
+    measure.bar_style = if backward { Some("light-heavy") } else { style };
+    let number = if number.is_empty() {
+      measure.number.parse::<u32>().map_or_else(
+        |_| (self.measures.len() + 1).to_string(),
+        |number| (number + 1).to_string(),
+      )
+    } else {
+      number
+    };
+    self.measures.push(ImportedMeasure {
+      number,
+      forward_repeat: forward,
+      ..Default::default()
+    });
+    self.measure_started = false;
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn read_data(&mut self, tokens: &[&str]) {
+    let time = self.time;
+    let mut advanced = false;
+    let mut voices: BTreeMap<usize, u32> = BTreeMap::new();
+    let mut heads: BTreeMap<usize, (u32, usize)> = BTreeMap::new();
+    for (index, token) in tokens.iter().enumerate() {
+      let track = self.columns[index].track;
+      let voice = {
+        let count = voices.entry(track).or_default();
+        *count += 1;
+        *count - 1
+      };
+      if self.tracks[track].kind != TrackKind::Kern || *token == "." {
+        continue;
+      }
+      let mut first = true;
+      for subtoken in token.split(' ') {
+        let Some(parsed) = KernToken::parse(subtoken) else {
+          continue;
+        };
+        let column = &mut self.columns[index];
+        if parsed.grace.is_none() {
+          column.position = time + parsed.length.unwrap_or_default();
+          advanced = true;
+        }
+        if parsed.rest && parsed.invisible {
+          continue;
+        }
+        let mut note = ImportedNote::new(&parsed, time);
+        note.chord = !first;
+        if first {
+          let open = column.beams > 0;
+          column.beams = (column.beams + parsed.beam_starts).saturating_sub(parsed.beam_ends);
+          note.beam = if parsed.beam_starts > 0 && !open {
+            Some("begin")
+          } else if parsed.beam_ends > 0 && column.beams == 0 {
+            Some("end")
+          } else if open || column.beams > 0 {
+            Some("continue")
+          } else {
+            None
+          };
+          for _ in 0..parsed.slur_stops {
+            note.slur_stops.push(column.slurs.max(1));
+            column.slurs = column.slurs.saturating_sub(1);
+          }
+          for _ in 0..parsed.slur_starts {
+            column.slurs += 1;
+            note.slur_starts.push(column.slurs);
+          }
+        }
+        first = false;
+        let is_head = note.grace.is_none() && !note.chord;
+        let notes = self.current().notes.entry((track, voice)).or_default();
+        notes.push(note);
+        if is_head {
+          heads.entry(track).or_insert((voice, notes.len() - 1));
+        }
+      }
+    }
+    for (index, token) in tokens.iter().enumerate() {
+      let track = &self.tracks[self.columns[index].track];
+      if *token == "." || track.kind == TrackKind::Kern || track.kind == TrackKind::Other {
+        continue;
+      }
+      let (kind, owner, verse) = (track.kind, track.owner, track.verse);
+      let measure = self.current();
+      match kind {
+        TrackKind::Text => {
+          if let (Some(lyric), Some((voice, note))) = (lyric_xml(token, verse), heads.get(&owner)) {
+            if let Some(note) = measure
+              .notes
+              .get_mut(&(owner, *voice))
+              .and_then(|notes| notes.get_mut(*note))
+            {
+              note.lyrics.push(lyric);
+            }
+          }
+        }
+        TrackKind::Dynam => {
+          for direction in dynamics_xml(token, None) {
+            measure.events.push((owner, time, direction));
+          }
+        }
+        TrackKind::Harm => {
+          if let Some(harmony) = chord_symbol_to_xml(token) {
+            measure.events.push((owner, time, harmony));
+          }
+        }
+        TrackKind::Kern | TrackKind::Other => {}
+      }
+    }
+    self.started = true;
+    self.measure_started = true;
+    if advanced {
+      if let Some(next) = self
+        .columns
+        .iter()
+        .filter(|column| self.tracks[column.track].kind == TrackKind::Kern && column.position > time)
+        .map(|column| column.position)
+        .min()
+      {
+        self.time = next;
+      }
+    }
+  }
+
+  fn read(&mut self, kern: &str) -> Result<(), String> {
+    for (number, line) in kern.lines().enumerate() {
+      let line = line.trim_end_matches('\r');
+      if line.is_empty() {
+        continue;
+      }
+      if let Some(record) = line.strip_prefix("!!!") {
+        if let Some((key, value)) = record.split_once(':') {
+          self
+            .references
+            .push((String::from(key.trim()), String::from(value.trim())));
+        }
+        continue;
+      }
+      if line.starts_with('!') {
+        continue;
+      }
+      let tokens: Vec<&str> = line.split('\t').collect();
+      if self.tracks.is_empty() {
+        if tokens.iter().all(|token| token.starts_with("**")) {
+          self.read_exclusive_interpretations(&tokens);
+          continue;
+        }
+        return Err(format!(
+          "Line {} appears before any exclusive interpretations",
+          number + 1
+        ));
+      }
+      if tokens.len() != self.columns.len() {
+        return Err(format!(
+          "Line {} contains {} spines, but {} are active",
+          number + 1,
+          tokens.len(),
+          self.columns.len()
+        ));
+      }
+      if tokens[0].starts_with('*') {
+        self.read_manipulators(&tokens)?;
+        if self.columns.is_empty() {
+          break;
+        }
+      } else if tokens[0].starts_with('=') {
+        self.read_barline(tokens[0]);
+      } else {
+        self.read_data(&tokens);
+      }
+    }
+    if self.tracks.is_empty() {
+      return Err(String::from(
+        "Humdrum data does not contain any exclusive interpretations",
+      ));
+    }
+    Ok(())
+  }
+
+  /// Groups the `**kern` tracks into parts, ordered from the top of the score, with each part's tracks ordered from
+  /// its top staff.
+  fn part_tracks(&self) -> Vec<Vec<usize>> {
+    let kern: Vec<usize> = (0..self.tracks.len())
+      .filter(|track| self.tracks[*track].kind == TrackKind::Kern)
+      .collect();
+    let mut parts: Vec<(Option<&String>, Vec<usize>)> = Vec::new();
+    for track in kern {
+      let label = self.tracks[track].part.as_ref();
+      match parts.iter_mut().find(|(part, _)| label.is_some() && *part == label) {
+        Some((_, tracks)) => tracks.insert(0, track),
+        None => parts.push((label, vec![track])),
+      }
+    }
+    parts.into_iter().rev().map(|(_, tracks)| tracks).collect()
+  }
+
+  fn initial_attributes(&self, tracks: &[usize]) -> XmlElement<'static> {
+    let mut attributes = XmlElement::named("attributes").with_child(XmlElement::named("divisions").with_text("1"));
+    if let Some(track) = tracks
+      .iter()
+      .map(|track| &self.tracks[*track])
+      .find(|track| track.key.is_some())
+    {
+      attributes = attributes.with_child(key_xml(track.key.unwrap_or_default(), track.mode));
+    }
+    if let Some(time) = tracks.iter().find_map(|track| self.tracks[*track].time.as_ref()) {
+      attributes = attributes.with_child(time.clone());
+    }
+    if tracks.len() > 1 {
+      attributes = attributes.with_child(XmlElement::named("staves").with_text(tracks.len().to_string()));
+    }
+    for (index, track) in tracks.iter().enumerate() {
+      if let Some(mut clef) = self.tracks[*track].clef.clone() {
+        if tracks.len() > 1 {
+          clef = clef.with_attribute("number", (index + 1).to_string());
+        }
+        attributes = attributes.with_child(clef);
+      }
+    }
+    attributes
+  }
+
+  fn build_part(&mut self, id: &str, tracks: &[usize]) -> XmlElement<'static> {
+    let mut part = XmlElement::named("part").with_attribute("id", id);
+    let multi_staff = tracks.len() > 1;
+    let first_attributes = self.initial_attributes(tracks);
+    let last = self.measures.len().saturating_sub(1);
+    for (index, measure) in self.measures.iter_mut().enumerate() {
+      let mut element = XmlElement::named("measure").with_attribute("number", measure.number.as_str());
+      if index == 0 && measure.number == "0" {
+        element = element.with_attribute("implicit", "yes");
+      }
+      if measure.forward_repeat {
+        element = element.with_child(
+          XmlElement::named("barline")
+            .with_attribute("location", "left")
+            .with_child(XmlElement::named("bar-style").with_text("heavy-light"))
+            .with_child(XmlElement::named("repeat").with_attribute("direction", "forward")),
+        );
+      }
+      if index == 0 {
+        element = element.with_child(first_attributes.clone());
+      }
+      let mut cursor = Fraction::ZERO;
+      for (staff, track) in (1..).zip(tracks.iter()) {
+        for ((_, voice), notes) in measure.notes.range_mut((*track, 0)..=(*track, u32::MAX)) {
+          apply_tuplets(notes);
+          let number = if multi_staff {
+            (staff - 1) * 4 + *voice + 1
+          } else {
+            *voice + 1
+          }
+          .to_string();
+          for note in notes.iter() {
+            if !note.chord {
+              move_cursor(&mut element, &mut cursor, note.position);
+            }
+            element
+              .elements
+              .push(note.to_xml(&number, multi_staff.then_some(staff)));
+            if !note.chord && note.grace.is_none() {
+              cursor = note.position + note.duration;
+            }
+          }
+        }
+      }
+      let mut changes: BTreeMap<Fraction, AttributeChanges> = BTreeMap::new();
+      for (track, position, change) in &measure.changes {
+        let Some(staff) = tracks.iter().position(|part_track| part_track == track) else {
+          continue;
+        };
+        let (key, time, clefs) = changes.entry(*position).or_default();
+        match change.name.as_ref() {
+          "key" => {
+            key.get_or_insert_with(|| change.clone());
+          }
+          "time" => {
+            time.get_or_insert_with(|| change.clone());
+          }
+          _ => clefs.push(if multi_staff {
+            change.clone().with_attribute("number", (staff + 1).to_string())
+          } else {
+            change.clone()
+          }),
+        }
+      }
+      for (position, (key, time, clefs)) in changes {
+        move_cursor(&mut element, &mut cursor, position);
+        let mut attributes = XmlElement::named("attributes");
+        attributes.elements.extend(key.into_iter().chain(time).chain(clefs));
+        element.elements.push(attributes);
+      }
+      let mut events: Vec<(Fraction, u32, &XmlElement)> = measure
+        .events
+        .iter()
+        .filter_map(|(track, position, event)| {
+          let (staff, _) = (1..).zip(tracks.iter()).find(|(_, part_track)| *part_track == track)?;
+          Some((*position, staff, event))
+        })
+        .collect();
+      events.sort_by_key(|(position, _, _)| *position);
+      for (position, staff, event) in events {
+        move_cursor(&mut element, &mut cursor, position);
+        let mut event = event.clone();
+        if multi_staff {
+          event = event.with_child(XmlElement::named("staff").with_text(staff.to_string()));
+        }
+        element.elements.push(event);
+      }
+      if measure.backward_repeat || measure.bar_style.is_some() || index == last {
+        let mut barline = XmlElement::named("barline")
+          .with_attribute("location", "right")
+          .with_child(XmlElement::named("bar-style").with_text(measure.bar_style.unwrap_or("light-heavy")));
+        if measure.backward_repeat {
+          barline = barline.with_child(XmlElement::named("repeat").with_attribute("direction", "backward"));
+        }
+        element.elements.push(barline);
+      }
+      part.elements.push(element);
+    }
+    part
+  }
+
+  fn header(&self) -> Vec<XmlElement<'static>> {
+    let reference = |key: &str| {
+      self
+        .references
+        .iter()
+        .find(|(reference, _)| reference == key)
+        .map(|(_, value)| value.as_str())
+    };
+    let mut elements = Vec::new();
+    if let Some(title) = reference("OTL") {
+      elements.push(XmlElement::named("work").with_child(XmlElement::named("work-title").with_text(title)));
+    }
+    if let Some(number) = reference("OMV") {
+      elements.push(XmlElement::named("movement-number").with_text(number));
+    }
+    if let Some(title) = reference("OMD") {
+      elements.push(XmlElement::named("movement-title").with_text(title));
+    }
+    let mut identification = XmlElement::named("identification");
+    for (key, value) in &self.references {
+      if let Some((_, role)) = CREATORS.iter().find(|(creator, _)| creator == key) {
+        identification = identification.with_child(
+          XmlElement::named("creator")
+            .with_attribute("type", *role)
+            .with_text(value.as_str()),
+        );
+      }
+    }
+    for (key, value) in &self.references {
+      if key == "YEC" {
+        identification = identification.with_child(XmlElement::named("rights").with_text(value.as_str()));
+      }
+    }
+    if !identification.elements.is_empty() {
+      elements.push(identification);
+    }
+    elements
+  }
+}
+
+/// Converts a Humdrum file containing `**kern` spines into a [ScorePartwise].
+///
+/// Every `**kern` spine becomes a separate part unless spines are grouped using `*part` interpretations, in which case
+/// each group becomes a single multi-staff part. Sub-spines become separate voices, and `**text`, `**harm`, and
+/// `**dynam` spines are attached to the nearest `**kern` spine on their left as lyrics, harmonies, and dynamics.
+/// Invisible rests (`ryy`) are treated as gaps in their voice rather than as notes.
+///
+/// # Errors
+///
+/// Returns an error if the data does not start with exclusive interpretations, does not contain any `**kern` spines,
+/// has lines whose number of tokens does not match the number of active spines, uses unsupported spine manipulators,
+/// or cannot be represented as a valid MusicXML score.
+pub fn kern_to_score_partwise(kern: &str) -> Result<ScorePartwise, String> {
+  let mut importer = KernImporter::default();
+  importer.read(kern)?;
+  let parts = importer.part_tracks();
+  if parts.is_empty() {
+    return Err(String::from("Humdrum data does not contain any **kern spines"));
+  }
+  if importer.measures.len() > 1 {
+    if let Some(last) = importer.measures.last() {
+      if last.notes.is_empty() && last.events.is_empty() && last.changes.is_empty() {
+        importer.measures.pop();
+      }
+    }
+  }
+  importer.current();
+  let mut xml = XmlElement::named("score-partwise").with_attribute("version", "4.0");
+  xml.elements = importer.header();
+  let mut part_list = XmlElement::named("part-list");
+  let mut part_elements = Vec::new();
+  for (index, tracks) in parts.iter().enumerate() {
+    let id = format!("P{}", index + 1);
+    let name = tracks.iter().find_map(|track| importer.tracks[*track].name.clone());
+    let abbreviation = tracks
+      .iter()
+      .find_map(|track| importer.tracks[*track].abbreviation.clone());
+    let mut score_part = XmlElement::named("score-part")
+      .with_attribute("id", id.as_str())
+      .with_child(XmlElement::named("part-name").with_text(name.unwrap_or_default()));
+    if let Some(abbreviation) = abbreviation {
+      score_part = score_part.with_child(XmlElement::named("part-abbreviation").with_text(abbreviation));
+    }
+    part_list.elements.push(score_part);
+    part_elements.push(importer.build_part(&id, tracks));
+  }
+  xml.elements.push(part_list);
+  xml.elements.extend(part_elements);
+  resolve_fractional_durations(&mut xml);
+  ScorePartwise::deserialize(&xml)
+}
+
+#[cfg(test)]
+mod kern_tests {
+  use super::*;
+  use crate::elements::PartElement;
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_is_chord, note_pitch, note_ties, note_time_modification};
+
+  const SCORE: &str = "<score-partwise version=\"4.0\">
+    <work><work-title>Test Song</work-title></work>
+    <identification><creator type=\"composer\">Jane Doe</creator></identification>
+    <part-list>
+      <score-part id=\"P1\"><part-name>Voice</part-name></score-part>
+      <score-part id=\"P2\"><part-name>Piano</part-name><part-abbreviation>Pno.</part-abbreviation></score-part>
+    </part-list>
+    <part id=\"P1\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>6</divisions>
+          <key><fifths>1</fifths><mode>major</mode></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <clef><sign>G</sign><line>2</line></clef>
+        </attributes>
+        <direction placement=\"below\"><direction-type><dynamics><mf/></dynamics></direction-type></direction>
+        <harmony><root><root-step>G</root-step></root><kind text=\"\">major</kind></harmony>
+        <note>
+          <pitch><step>F</step><alter>1</alter><octave>4</octave></pitch>
+          <duration>12</duration><tie type=\"start\"/><voice>1</voice><type>half</type>
+          <notations><tied type=\"start\"/><slur type=\"start\" number=\"1\"/></notations>
+          <lyric number=\"1\"><syllabic>begin</syllabic><text>Hel</text></lyric>
+        </note>
+        <note>
+          <pitch><step>G</step><octave>4</octave></pitch>
+          <duration>2</duration><voice>1</voice><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+          <beam number=\"1\">begin</beam>
+          <notations><tuplet type=\"start\"/><articulations><staccato/></articulations></notations>
+          <lyric number=\"1\"><syllabic>end</syllabic><text>lo</text></lyric>
+        </note>
+        <note>
+          <pitch><step>A</step><octave>4</octave></pitch>
+          <duration>2</duration><voice>1</voice><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+          <beam number=\"1\">continue</beam>
+        </note>
+        <note>
+          <pitch><step>B</step><octave>4</octave></pitch>
+          <duration>2</duration><voice>1</voice><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+          <beam number=\"1\">end</beam>
+          <notations><tuplet type=\"stop\"/><slur type=\"stop\" number=\"1\"/></notations>
+        </note>
+      </measure>
+      <measure number=\"2\">
+        <note>
+          <pitch><step>F</step><alter>1</alter><octave>4</octave></pitch>
+          <duration>18</duration><tie type=\"stop\"/><voice>1</voice><type>half</type><dot/>
+          <notations><tied type=\"stop\"/></notations>
+        </note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+    <part id=\"P2\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>1</divisions>
+          <key><fifths>1</fifths><mode>major</mode></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <staves>2</staves>
+          <clef number=\"1\"><sign>G</sign><line>2</line></clef>
+          <clef number=\"2\"><sign>F</sign><line>4</line></clef>
+        </attributes>
+        <note>
+          <pitch><step>G</step><octave>4</octave></pitch>
+          <duration>3</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+        <note>
+          <chord/><pitch><step>B</step><octave>4</octave></pitch>
+          <duration>3</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+        <backup><duration>3</duration></backup>
+        <note>
+          <pitch><step>D</step><octave>4</octave></pitch>
+          <duration>1</duration><voice>2</voice><type>quarter</type><stem>down</stem><staff>1</staff>
+        </note>
+        <backup><duration>1</duration></backup>
+        <note>
+          <pitch><step>G</step><octave>2</octave></pitch>
+          <duration>3</duration><voice>5</voice><type>half</type><dot/><staff>2</staff>
+        </note>
+      </measure>
+      <measure number=\"2\">
+        <note>
+          <rest measure=\"yes\"/><duration>3</duration><voice>1</voice><staff>1</staff>
+        </note>
+        <backup><duration>3</duration></backup>
+        <note>
+          <rest measure=\"yes\"/><duration>3</duration><voice>5</voice><staff>2</staff>
+        </note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+  </score-partwise>";
+
+  fn part_notes(score: &ScorePartwise, part: usize) -> Vec<&Note> {
+    score.content.part[part]
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        PartElement::Measure(measure) => Some(measure),
+        _ => None,
+      })
+      .flat_map(|measure| measure.content.iter())
+      .filter_map(|element| match element {
+        MeasureElement::Note(note) => Some(note),
+        _ => None,
+      })
+      .collect()
+  }
+
+  #[test]
+  fn export_structure() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let kern = score_partwise_to_kern(&score).unwrap();
+    let lines: Vec<&str> = kern.lines().collect();
+    assert_eq!(lines[0], "!!!COM: Jane Doe");
+    assert_eq!(lines[1], "!!!OTL: Test Song");
+    assert_eq!(lines[2], "**kern\t**kern\t**kern\t**text\t**harm\t**dynam");
+    assert!(lines.contains(&"*clefF4\t*clefG2\t*clefG2\t*\t*\t*"));
+    assert!(lines.contains(&"*k[f#]\t*k[f#]\t*k[f#]\t*\t*\t*"));
+    assert!(lines.contains(&"*G:\t*G:\t*G:\t*\t*\t*"));
+    assert!(lines.contains(&"*M3/4\t*M3/4\t*M3/4\t*\t*\t*"));
+    assert!(lines.contains(&"*\t*^\t*\t*\t*\t*"));
+    assert!(lines.contains(&"2.GG\t2.g 2.b\t4d\\\t([2f#\tHel-\tG\tmf"));
+    assert!(lines.contains(&".\t.\t.\t12g'L\t-lo\t.\t."));
+    assert!(lines.contains(&".\t.\t.\t12bJ)\t.\t.\t."));
+    assert!(lines.contains(&"2.r\t2.r\t2.ryy\t2.f#]\t.\t.\t."));
+    assert!(lines.contains(&"*\t*v\t*v\t*\t*\t*\t*"));
+    assert_eq!(lines.last(), Some(&"*-\t*-\t*-\t*-\t*-\t*-"));
+  }
+
+  #[test]
+  fn round_trip() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let imported = kern_to_score_partwise(&score_partwise_to_kern(&score).unwrap()).unwrap();
+    assert_eq!(imported.content.part.len(), 2);
+    for part in 0..2 {
+      let (original, converted) = (part_notes(&score, part), part_notes(&imported, part));
+      assert_eq!(original.len(), converted.len());
+      for (original, converted) in original.iter().zip(converted.iter()) {
+        assert_eq!(note_pitch(original), note_pitch(converted));
+        if original.content.r#type.is_some() {
+          assert_eq!(original.content.r#type, converted.content.r#type);
+          assert_eq!(original.content.dot.len(), converted.content.dot.len());
+        }
+        assert_eq!(note_ties(original), note_ties(converted));
+        assert_eq!(note_time_modification(original), note_time_modification(converted));
+        assert_eq!(original.content.beam, converted.content.beam);
+        assert_eq!(original.content.lyric.len(), converted.content.lyric.len());
+        assert_eq!(original.content.staff, converted.content.staff);
+      }
+    }
+    assert_eq!(
+      score_partwise_to_kern(&imported).unwrap(),
+      score_partwise_to_kern(&score).unwrap()
+    );
+  }
+
+  #[test]
+  fn import_kern() {
+    let kern = "!!!OTL: Voices & Tuplets
+**kern\t**dynam\t**kern\t**text
+*part1\t*part1\t*part2\t*part2
+*clefF4\t*\t*clefG2\t*
+*k[b-]\t*\t*k[b-]\t*
+*M2/4\t*\t*M2/4\t*
+*met(c|)\t*\t*\t*
+=1-\t=1-\t=1-\t=1-
+4C\tp\t8qf\t.
+.\t.\t(6gL\tLa
+.\t.\t6a\t.
+.\t.\t6bnJ)\t.
+*\t*\t*^\t*
+4E 4G\t<\t4cc;\t4e/\tla
+=2:|!\t=2:|!\t=2:|!\t=2:|!\t=2:|!
+*\t*\t*v\t*v\t*
+2C\t[\t[2dd-^\t.
+==\t==\t==\t==
+*-\t*-\t*-\t*-
+";
+    let score = kern_to_score_partwise(kern).unwrap();
+    assert_eq!(score.content.part.len(), 2);
+    let notes = part_notes(&score, 0);
+    assert_eq!(notes.len(), 7);
+    assert!(note_is_grace(notes[0]));
+    assert_eq!(note_time_modification(notes[1]), (3, 2));
+    assert_eq!(notes[1].content.lyric.len(), 1);
+    assert_eq!(notes[1].content.beam.len(), 1);
+    assert_eq!(
+      notes[3].content.accidental.as_ref().unwrap().content,
+      AccidentalValue::Natural
+    );
+    assert_eq!(notes[4].content.lyric.len(), 1);
+    assert_eq!(note_voice(notes[5]), "2");
+    assert_eq!(notes[5].content.lyric.len(), 0);
+    assert_eq!(note_tied(notes[6]), (true, false));
+    assert_eq!(pitch_alter(note_pitch(notes[6]).unwrap()), -1);
+    let notes = part_notes(&score, 1);
+    assert_eq!(notes.len(), 4);
+    assert!(note_is_chord(notes[2]));
+    let measures: Vec<&Measure> = part_measures(&score.content.part[1]).collect();
+    assert_eq!(measures.len(), 2);
+    assert!(measures[0]
+      .content
+      .iter()
+      .any(|element| matches!(element, MeasureElement::Direction(_))));
+    assert!(measures[0]
+      .content
+      .iter()
+      .any(|element| matches!(element, MeasureElement::Barline(barline)
+      if barline.content.repeat.is_some())));
+    let kern = score_partwise_to_kern(&score).unwrap();
+    assert!(kern.contains("*met(c|)"));
+    assert!(kern.contains("=2:|!"));
+  }
+
+  #[test]
+  fn invalid_kern() {
+    assert!(kern_to_score_partwise("").is_err());
+    assert!(kern_to_score_partwise("4c\n").is_err());
+    assert!(kern_to_score_partwise("**text\nla\n*-\n").is_err());
+    assert!(kern_to_score_partwise("**kern\t**kern\n4c\n*-\t*-\n").is_err());
+    assert!(kern_to_score_partwise("**kern\t**kern\n*+\t*\n*-\t*-\t*-\n").is_err());
+  }
+
+  #[test]
+  fn round_trip_file() {
+    let score = crate::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    let imported = kern_to_score_partwise(&score_partwise_to_kern(&score).unwrap()).unwrap();
+    assert_eq!(score.content.part.len(), imported.content.part.len());
+    for part in 0..score.content.part.len() {
+      let pitches = |score| {
+        part_notes(score, part)
+          .into_iter()
+          .filter_map(note_pitch)
+          .collect::<Vec<_>>()
+      };
+      assert_eq!(pitches(&score), pitches(&imported));
+    }
+  }
+}
@@ -0,0 +1,3019 @@
+//! Converts between [ScorePartwise][crate::elements::ScorePartwise] scores and MEI 5 documents.
+//!
+//! The exporter produces a `<mei>` document containing a header built from the score's
+//! [Identification][crate::elements::Identification] and [Work][crate::elements::Work] metadata, followed by a single
+//! `<mdiv>/<score>` whose `<scoreDef>` mirrors the score's part list. Every MusicXML voice becomes an MEI `<layer>`,
+//! while ties, slurs, fermatas, dynamics, directions, and harmonies are written as control events referencing notes
+//! by `xml:id` or by `tstamp`.
+//!
+//! The importer understands the same subset of MEI and rebuilds a partwise score from it, so documents written by
+//! the exporter can be read back without loss of the converted content.
+
+use crate::datatypes::{
+  AboveBelow, BackwardForward, BeamValue, ClefSign, GroupBarlineValue, GroupSymbolValue, NoteTypeValue,
+  RightLeftMiddle, StartStop, StartStopContinue, StartStopDiscontinue, StemValue, Syllabic, TimeSymbol,
+  UprightInverted, WedgeType, YesNo,
+};
+use crate::elements::{
+  BeatEquation, Clef, Direction, DirectionTypeContents, Harmony, Key, KeyContents, LyricContents, Measure,
+  MeasureElement, MetronomeContents, NotationContentTypes, Note, PartListElement, ScorePart, ScorePartwise, Time,
+};
+use crate::parser::xml_parser;
+use crate::util::{
+  chord_symbol_to_xml, direction_xml, dotted_length, format_decimal, format_fraction, harmony_element_text,
+  length_to_note_type, note_dots, note_is_cue, note_is_rest, note_pitch, note_staff, note_tied, note_time_modification,
+  note_voice, parse_decimal, part_measures, part_staves, pitch_alter, pitch_to_midi, resolve_fractional_durations,
+  split_length, step_letter, xml_escape_tree, xml_unescape_tree, Fraction, MeasureEvent, PartTimeline, TimedEvent,
+  XmlElementExt, DYNAMICS,
+};
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer, ElementDeserializer, ElementSerializer, XmlElement};
+
+const MEI_NAMESPACE: &str = "http://www.music-encoding.org/ns/mei";
+const MEI_VERSION: &str = "5.0";
+const MEI_SCHEMA: &str = "https://music-encoding.org/schema/5.0/mei-all.rng";
+
+/// MusicXML accidental values and their MEI `@accid` equivalents.
+const ACCIDENTALS: [(&str, &str); 14] = [
+  ("sharp", "s"),
+  ("flat", "f"),
+  ("natural", "n"),
+  ("double-sharp", "x"),
+  ("sharp-sharp", "ss"),
+  ("flat-flat", "ff"),
+  ("natural-sharp", "ns"),
+  ("natural-flat", "nf"),
+  ("triple-sharp", "ts"),
+  ("triple-flat", "tf"),
+  ("quarter-flat", "1qf"),
+  ("quarter-sharp", "1qs"),
+  ("three-quarters-flat", "3qf"),
+  ("three-quarters-sharp", "3qs"),
+];
+
+/// MusicXML articulation element names and their MEI `@artic` equivalents.
+const ARTICULATIONS: [(&str, &str); 13] = [
+  ("accent", "acc"),
+  ("strong-accent", "marc"),
+  ("staccato", "stacc"),
+  ("tenuto", "ten"),
+  ("detached-legato", "ten-stacc"),
+  ("staccatissimo", "stacciss"),
+  ("spiccato", "spicc"),
+  ("stress", "stress"),
+  ("unstress", "unstress"),
+  ("scoop", "scoop"),
+  ("plop", "plop"),
+  ("doit", "doit"),
+  ("falloff", "fall"),
+];
+
+/// MusicXML bar styles and their MEI `@left`/`@right` barline renditions.
+const BAR_STYLES: [(&str, &str); 7] = [
+  ("light-light", "dbl"),
+  ("light-heavy", "end"),
+  ("heavy-heavy", "dblheavy"),
+  ("heavy", "heavy"),
+  ("dashed", "dashed"),
+  ("dotted", "dotted"),
+  ("none", "invis"),
+];
+
+fn lookup<'a>(table: &[(&'a str, &'a str)], key: &str) -> Option<&'a str> {
+  table.iter().find(|(from, _)| *from == key).map(|(_, to)| *to)
+}
+
+fn reverse_lookup<'a>(table: &[(&'a str, &'a str)], key: &str) -> Option<&'a str> {
+  table.iter().find(|(_, to)| *to == key).map(|(from, _)| *from)
+}
+
+fn mei_duration(note_type: &NoteTypeValue) -> String {
+  match note_type {
+    NoteTypeValue::Whole => String::from("1"),
+    NoteTypeValue::Half => String::from("2"),
+    NoteTypeValue::Quarter => String::from("4"),
+    NoteTypeValue::Eighth => String::from("8"),
+    other => {
+      let value = NoteTypeValue::serialize(other);
+      String::from(value.trim_end_matches("th").trim_end_matches("nd"))
+    }
+  }
+}
+
+/// The MEI duration and dot count of a note, inferred from its length when it has no written type.
+fn note_mei_duration(note: &Note, length: Fraction) -> Option<(String, u8)> {
+  if let Some(note_type) = &note.content.r#type {
+    return Some((mei_duration(&note_type.content), note_dots(note)));
+  }
+  let (actual, normal) = note_time_modification(note);
+  length_to_note_type(length * Fraction::new(i64::from(actual), i64::from(normal)))
+    .map(|(note_type, dots)| (mei_duration(&note_type), dots))
+}
+
+fn note_type_from_mei(duration: &str) -> Option<NoteTypeValue> {
+  let value = match duration {
+    "1" => String::from("whole"),
+    "2" => String::from("half"),
+    "4" => String::from("quarter"),
+    "8" => String::from("eighth"),
+    "32" => String::from("32nd"),
+    "maxima" | "long" | "breve" => String::from(duration),
+    other => format!("{other}th"),
+  };
+  NoteTypeValue::deserialize(&value).ok()
+}
+
+fn accid_alter(accid: &str) -> Option<i16> {
+  match accid {
+    "s" | "ns" => Some(1),
+    "f" | "nf" => Some(-1),
+    "ss" | "x" => Some(2),
+    "ff" => Some(-2),
+    "ts" | "xs" | "sx" => Some(3),
+    "tf" => Some(-3),
+    "n" | "1qf" | "1qs" | "3qf" | "3qs" => Some(0),
+    _ => None,
+  }
+}
+
+fn gestural_accid(alter: i32) -> Option<&'static str> {
+  match alter {
+    1 => Some("s"),
+    -1 => Some("f"),
+    2 => Some("ss"),
+    -2 => Some("ff"),
+    3 => Some("ts"),
+    -3 => Some("tf"),
+    _ => None,
+  }
+}
+
+/// Returns the alteration that a key signature with the given number of fifths applies to a step.
+fn key_signature_alter(fifths: i32, step: char) -> i16 {
+  let altered = if fifths > 0 { "FCGDAEB" } else { "BEADGCF" };
+  if !altered
+    .chars()
+    .take(fifths.unsigned_abs() as usize)
+    .any(|letter| letter == step)
+  {
+    0
+  } else if fifths > 0 {
+    1
+  } else {
+    -1
+  }
+}
+
+fn time_stamp(position: Fraction, meter_unit: i64) -> String {
+  format_decimal(Fraction::from_int(1) + position * Fraction::new(meter_unit, 4))
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// EXPORT -------------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Generates unique `xml:id` values for elements that do not carry an explicit MusicXML `id`.
+#[derive(Default)]
+struct IdGenerator {
+  used: BTreeSet<String>,
+  next: usize,
+}
+
+impl IdGenerator {
+  fn reserve(&mut self, id: &str) -> String {
+    self.used.insert(String::from(id));
+    String::from(id)
+  }
+
+  fn generate(&mut self, prefix: &str) -> String {
+    loop {
+      self.next += 1;
+      let id = format!("{prefix}{}", self.next);
+      if self.used.insert(id.clone()) {
+        return id;
+      }
+    }
+  }
+}
+
+/// Per-part state that carries across measures during export.
+struct PartExport<'a> {
+  measures: Vec<&'a Measure>,
+  first_staff: u32,
+  staves: u32,
+  timeline: PartTimeline,
+  meter_unit: i64,
+  meter_length: Fraction,
+  open_ties: Vec<(u32, i32, String, usize)>,
+  open_slurs: BTreeMap<u8, (String, usize, u32)>,
+  open_wedges: BTreeMap<u8, (usize, XmlElement<'static>)>,
+}
+
+struct MeiExporter<'a> {
+  score: &'a ScorePartwise,
+  parts: Vec<PartExport<'a>>,
+  score_parts: Vec<&'a ScorePart>,
+  ids: IdGenerator,
+  control_events: Vec<Vec<XmlElement<'static>>>,
+}
+
+fn key_signature(key: &Key) -> Option<XmlElement<'static>> {
+  let KeyContents::Explicit(contents) = &key.content else {
+    return None;
+  };
+  let fifths = i32::from(*contents.fifths.content);
+  let sig = match fifths {
+    0 => String::from("0"),
+    n if n > 0 => format!("{n}s"),
+    n => format!("{}f", -n),
+  };
+  let mut key_sig = XmlElement::named("keySig").with_attribute("sig", sig);
+  if let Some(mode) = &contents.mode {
+    let mode = crate::datatypes::Mode::serialize(&mode.content);
+    if mode != "none" {
+      key_sig = key_sig.with_attribute("mode", mode);
+    }
+  }
+  Some(key_sig)
+}
+
+fn meter_signature(time: &Time) -> Option<XmlElement<'static>> {
+  let count = time
+    .content
+    .beats
+    .iter()
+    .map(|beats| beats.beats.content.as_str())
+    .collect::<Vec<_>>()
+    .join("+");
+  let unit = time.content.beats.first()?.beat_type.content.clone();
+  let mut meter_sig = XmlElement::named("meterSig")
+    .with_attribute("count", count)
+    .with_attribute("unit", unit);
+  match time.attributes.symbol {
+    Some(TimeSymbol::Common) => meter_sig = meter_sig.with_attribute("sym", "common"),
+    Some(TimeSymbol::Cut) => meter_sig = meter_sig.with_attribute("sym", "cut"),
+    _ => {}
+  }
+  Some(meter_sig)
+}
+
+fn meter_unit(time: &Time) -> Option<i64> {
+  time.content.beats.first()?.beat_type.content.trim().parse().ok()
+}
+
+/// Returns the length of a measure in quarter notes as an MEI reader computes it from the exported `<meterSig>`.
+fn meter_length(time: &Time) -> Option<Fraction> {
+  let unit = meter_unit(time).filter(|unit| *unit > 0)?;
+  let beats: i64 = time
+    .content
+    .beats
+    .iter()
+    .flat_map(|beats| beats.beats.content.split('+'))
+    .filter_map(|count| count.trim().parse::<i64>().ok())
+    .sum();
+  (beats > 0).then(|| Fraction::new(beats * 4, unit))
+}
+
+fn clef_element(clef: &Clef) -> Option<XmlElement<'static>> {
+  let (shape, default_line) = match clef.content.sign.content {
+    ClefSign::G => ("G", 2),
+    ClefSign::F => ("F", 4),
+    ClefSign::C => ("C", 3),
+    ClefSign::Percussion => ("perc", 3),
+    ClefSign::TAB => ("TAB", 5),
+    ClefSign::Jianpu | ClefSign::None => return None,
+  };
+  let line = clef.content.line.as_ref().map_or(default_line, |line| *line.content);
+  let mut element = XmlElement::named("clef")
+    .with_attribute("shape", shape)
+    .with_attribute("line", line.to_string());
+  if let Some(change) = &clef.content.clef_octave_change {
+    if change.content != 0 {
+      let displacement = match change.content.unsigned_abs() {
+        1 => "8",
+        2 => "15",
+        _ => "22",
+      };
+      element = element
+        .with_attribute("dis", displacement)
+        .with_attribute("dis.place", if change.content > 0 { "above" } else { "below" });
+    }
+  }
+  Some(element)
+}
+
+fn placement(placement: Option<&AboveBelow>) -> Option<&'static str> {
+  placement.map(|placement| match placement {
+    AboveBelow::Above => "above",
+    AboveBelow::Below => "below",
+  })
+}
+
+fn export_header(score: &ScorePartwise) -> XmlElement<'static> {
+  let work = score.content.work.as_ref();
+  let work_title = work
+    .and_then(|work| work.content.work_title.as_ref())
+    .map(|title| title.content.as_str());
+  let movement_title = score
+    .content
+    .movement_title
+    .as_ref()
+    .map(|title| title.content.as_str());
+  let mut title_stmt = XmlElement::named("titleStmt")
+    .with_child(XmlElement::named("title").with_text(work_title.or(movement_title).unwrap_or("")));
+  if let (Some(_), Some(movement_title)) = (work_title, movement_title) {
+    title_stmt = title_stmt.with_child(
+      XmlElement::named("title")
+        .with_attribute("type", "subordinate")
+        .with_text(movement_title),
+    );
+  }
+  let mut pub_stmt = XmlElement::named("pubStmt");
+  let mut encoding_desc = None;
+  if let Some(identification) = &score.content.identification {
+    let mut resp_stmt = XmlElement::named("respStmt");
+    for creator in &identification.content.creator {
+      let role = creator
+        .attributes
+        .r#type
+        .as_ref()
+        .map_or("creator", |role| role.0.as_str());
+      let name = XmlElement::named("persName").with_text(creator.content.as_str());
+      match role {
+        "composer" | "lyricist" | "arranger" => {
+          title_stmt = title_stmt.with_child(XmlElement::named(role).with_child(name));
+        }
+        _ => resp_stmt = resp_stmt.with_child(name.with_attribute("role", role)),
+      }
+    }
+    if !resp_stmt.elements.is_empty() {
+      title_stmt = title_stmt.with_child(resp_stmt);
+    }
+    if !identification.content.rights.is_empty() {
+      let mut availability = XmlElement::named("availability");
+      for rights in &identification.content.rights {
+        availability = availability.with_child(XmlElement::named("useRestrict").with_text(rights.content.as_str()));
+      }
+      pub_stmt = pub_stmt.with_child(availability);
+    }
+    let software: Vec<_> = identification
+      .content
+      .encoding
+      .iter()
+      .flat_map(|encoding| encoding.content.iter())
+      .filter_map(|contents| match contents {
+        crate::elements::EncodingContents::Software(software) => Some(software.content.as_str()),
+        _ => None,
+      })
+      .collect();
+    if !software.is_empty() {
+      let mut app_info = XmlElement::named("appInfo");
+      for name in software {
+        app_info =
+          app_info.with_child(XmlElement::named("application").with_child(XmlElement::named("name").with_text(name)));
+      }
+      encoding_desc = Some(XmlElement::named("encodingDesc").with_child(app_info));
+    }
+  }
+  let mut header = XmlElement::named("meiHead").with_child(
+    XmlElement::named("fileDesc")
+      .with_child(title_stmt)
+      .with_child(pub_stmt),
+  );
+  if let Some(encoding_desc) = encoding_desc {
+    header = header.with_child(encoding_desc);
+  }
+  if let Some(work) = work {
+    let mut mei_work = XmlElement::named("work");
+    if let Some(title) = &work.content.work_title {
+      mei_work = mei_work.with_child(XmlElement::named("title").with_text(title.content.as_str()));
+    }
+    if let Some(number) = &work.content.work_number {
+      mei_work = mei_work.with_child(XmlElement::named("identifier").with_text(number.content.as_str()));
+    }
+    if !mei_work.elements.is_empty() {
+      header = header.with_child(XmlElement::named("workList").with_child(mei_work));
+    }
+  }
+  header
+}
+
+impl<'a> MeiExporter<'a> {
+  fn new(score: &'a ScorePartwise) -> Result<Self, String> {
+    let score_parts: Vec<&ScorePart> = score
+      .content
+      .part_list
+      .content
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        PartListElement::ScorePart(score_part) => Some(score_part),
+        PartListElement::PartGroup(_) => None,
+      })
+      .collect();
+    if let Some(part) = score.content.part.iter().find(|part| {
+      !score_parts
+        .iter()
+        .any(|score_part| score_part.attributes.id.0 == part.attributes.id.0)
+    }) {
+      return Err(format!(
+        "Part '{}' is not declared in the part list",
+        part.attributes.id.0
+      ));
+    }
+    let mut parts = Vec::new();
+    let mut first_staff = 1;
+    for score_part in &score_parts {
+      let (measures, staves) = match score
+        .content
+        .part
+        .iter()
+        .find(|part| part.attributes.id.0 == score_part.attributes.id.0)
+      {
+        Some(part) => (part_measures(part).collect(), part_staves(part)),
+        None => (Vec::new(), 1),
+      };
+      parts.push(PartExport {
+        measures,
+        first_staff,
+        staves,
+        timeline: PartTimeline::default(),
+        meter_unit: 4,
+        meter_length: Fraction::from_int(4),
+        open_ties: Vec::new(),
+        open_slurs: BTreeMap::new(),
+        open_wedges: BTreeMap::new(),
+      });
+      first_staff += staves;
+    }
+    let mut ids = IdGenerator::default();
+    for part in &score.content.part {
+      for measure in part_measures(part) {
+        for element in &measure.content {
+          if let MeasureElement::Note(note) = element {
+            if let Some(id) = &note.attributes.id {
+              ids.reserve(&id.0);
+            }
+          }
+        }
+      }
+    }
+    let measure_count = parts.iter().map(|part| part.measures.len()).max().unwrap_or(0);
+    Ok(MeiExporter {
+      score,
+      parts,
+      score_parts,
+      ids,
+      control_events: (0..measure_count).map(|_| Vec::new()).collect(),
+    })
+  }
+
+  /// Returns the attributes that apply at the very beginning of a part.
+  fn initial_attributes(&self, part: usize) -> impl Iterator<Item = &'a crate::elements::Attributes> + use<'a> {
+    let first: Option<&'a Measure> = self.parts[part].measures.first().copied();
+    first.into_iter().flat_map(|measure| {
+      measure
+        .content
+        .iter()
+        .take_while(|element| !matches!(element, MeasureElement::Note(_) | MeasureElement::Forward(_)))
+        .filter_map(|element| match element {
+          MeasureElement::Attributes(attributes) => Some(attributes),
+          _ => None,
+        })
+    })
+  }
+
+  fn staff_def(&self, part: usize, staff: u32, score_key: Option<&XmlElement>) -> XmlElement<'static> {
+    let info = &self.parts[part];
+    let score_part = self.score_parts[part];
+    let mut staff_def = XmlElement::named("staffDef").with_attribute("n", (info.first_staff + staff - 1).to_string());
+    let (mut lines, mut clef, mut key, mut transpose) = (5, None, None, None);
+    for attributes in self.initial_attributes(part) {
+      for details in &attributes.content.staff_details {
+        if details
+          .attributes
+          .number
+          .as_ref()
+          .map_or(1, |number| u32::from(number.0))
+          == staff
+        {
+          if let Some(staff_lines) = &details.content.staff_lines {
+            lines = *staff_lines.content;
+          }
+        }
+      }
+      if let Some(found) = attributes
+        .content
+        .clef
+        .iter()
+        .find(|clef| clef.attributes.number.as_ref().map_or(1, |number| u32::from(number.0)) == staff)
+      {
+        clef = clef_element(found);
+      }
+      if let Some(found) = attributes.content.key.iter().find(|key| {
+        key.attributes.number.is_none()
+          || key
+            .attributes
+            .number
+            .as_ref()
+            .is_some_and(|number| u32::from(number.0) == staff)
+      }) {
+        key = key_signature(found);
+      }
+      if let Some(found) = attributes.content.transpose.iter().find(|transpose| {
+        transpose.attributes.number.is_none()
+          || transpose
+            .attributes
+            .number
+            .as_ref()
+            .is_some_and(|number| u32::from(number.0) == staff)
+      }) {
+        transpose = Some(found);
+      }
+    }
+    staff_def = staff_def.with_attribute("lines", lines.to_string());
+    if let Some(transpose) = transpose {
+      staff_def = staff_def.with_attribute("trans.semi", transpose.content.chromatic.content.0.to_string());
+      if let Some(diatonic) = &transpose.content.diatonic {
+        staff_def = staff_def.with_attribute("trans.diat", diatonic.content.to_string());
+      }
+    }
+    if info.staves == 1 {
+      staff_def =
+        staff_def.with_child(XmlElement::named("label").with_text(score_part.content.part_name.content.as_str()));
+      if let Some(abbreviation) = &score_part.content.part_abbreviation {
+        staff_def = staff_def.with_child(XmlElement::named("labelAbbr").with_text(abbreviation.content.as_str()));
+      }
+    }
+    if let Some(clef) = clef {
+      staff_def = staff_def.with_child(clef);
+    }
+    if let Some(key) = key {
+      if score_key.is_none_or(|score_key| score_key.attributes != key.attributes) {
+        staff_def = staff_def.with_child(key);
+      }
+    }
+    staff_def
+  }
+
+  fn part_staff_defs(&self, part: usize, score_key: Option<&XmlElement>) -> XmlElement<'static> {
+    let info = &self.parts[part];
+    if info.staves == 1 {
+      return self.staff_def(part, 1, score_key);
+    }
+    let score_part = self.score_parts[part];
+    let mut group = XmlElement::named("staffGrp")
+      .with_attribute("symbol", "brace")
+      .with_attribute("bar.thru", "true")
+      .with_child(XmlElement::named("label").with_text(score_part.content.part_name.content.as_str()));
+    if let Some(abbreviation) = &score_part.content.part_abbreviation {
+      group = group.with_child(XmlElement::named("labelAbbr").with_text(abbreviation.content.as_str()));
+    }
+    for staff in 1..=info.staves {
+      group = group.with_child(self.staff_def(part, staff, score_key));
+    }
+    group
+  }
+
+  fn score_def(&self) -> XmlElement<'static> {
+    let mut score_def = XmlElement::named("scoreDef");
+    let mut score_key = None;
+    if !self.parts.is_empty() {
+      for attributes in self.initial_attributes(0) {
+        if let Some(key) = attributes.content.key.first().and_then(key_signature) {
+          score_key = Some(key);
+        }
+        if let Some(time) = attributes.content.time.first().and_then(meter_signature) {
+          score_def.elements.retain(|element| element.name != "meterSig");
+          score_def = score_def.with_child(time);
+        }
+      }
+    }
+    if let Some(key) = &score_key {
+      score_def.elements.insert(0, key.clone());
+    }
+    let mut groups: Vec<(Option<String>, XmlElement<'static>)> = vec![(None, XmlElement::named("staffGrp"))];
+    let mut part = 0;
+    for element in &self.score.content.part_list.content.content {
+      match element {
+        PartListElement::ScorePart(_) => {
+          let staff_defs = self.part_staff_defs(part, score_key.as_ref());
+          if let Some((_, group)) = groups.last_mut() {
+            group.elements.push(staff_defs);
+          }
+          part += 1;
+        }
+        PartListElement::PartGroup(part_group) => {
+          let number = part_group.attributes.number.as_ref().map(|number| number.0.clone());
+          if part_group.attributes.r#type == StartStop::Start {
+            let mut group = XmlElement::named("staffGrp");
+            if let Some(symbol) = &part_group.content.group_symbol {
+              group = group.with_attribute(
+                "symbol",
+                match symbol.content {
+                  GroupSymbolValue::Brace => "brace",
+                  GroupSymbolValue::Bracket => "bracket",
+                  GroupSymbolValue::Square => "bracketsq",
+                  GroupSymbolValue::Line => "line",
+                  GroupSymbolValue::None => "none",
+                },
+              );
+            }
+            if let Some(barline) = &part_group.content.group_barline {
+              group = group.with_attribute(
+                "bar.thru",
+                if barline.content == GroupBarlineValue::No {
+                  "false"
+                } else {
+                  "true"
+                },
+              );
+            }
+            if let Some(name) = &part_group.content.group_name {
+              group = group.with_child(XmlElement::named("label").with_text(name.content.as_str()));
+            }
+            if let Some(abbreviation) = &part_group.content.group_abbreviation {
+              group = group.with_child(XmlElement::named("labelAbbr").with_text(abbreviation.content.as_str()));
+            }
+            groups.push((number, group));
+          } else if let Some(index) = groups
+            .iter()
+            .rposition(|(open, _)| index_matches(open.as_ref(), number.as_ref()))
+          {
+            while groups.len() > index.max(1) {
+              if let Some((_, group)) = groups.pop() {
+                if let Some((_, parent)) = groups.last_mut() {
+                  parent.elements.push(group);
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+    while groups.len() > 1 {
+      if let Some((_, group)) = groups.pop() {
+        if let Some((_, parent)) = groups.last_mut() {
+          parent.elements.push(group);
+        }
+      }
+    }
+    if let Some((_, root)) = groups.pop() {
+      score_def = score_def.with_child(root);
+    }
+    score_def
+  }
+
+  fn note_id(&mut self, note: &Note) -> String {
+    match &note.attributes.id {
+      Some(id) => id.0.clone(),
+      None => self.ids.generate(if note_is_rest(note) { "rest" } else { "note" }),
+    }
+  }
+
+  fn export_lyrics(note: &Note, element: &mut XmlElement) {
+    for (index, lyric) in note.content.lyric.iter().enumerate() {
+      let LyricContents::Text(text) = &lyric.content else {
+        continue;
+      };
+      let number = lyric
+        .attributes
+        .number
+        .as_ref()
+        .and_then(|number| number.0.parse::<u32>().ok())
+        .or_else(|| u32::try_from(index + 1).ok())
+        .unwrap_or(1);
+      let mut syllables = vec![(
+        text.syllabic.as_ref().map(|syllabic| &syllabic.content),
+        text.text.content.as_str(),
+      )];
+      for additional in &text.additional {
+        syllables.push((
+          additional.syllabic.as_ref().map(|syllabic| &syllabic.content),
+          additional.text.content.as_str(),
+        ));
+      }
+      let mut verse = XmlElement::named("verse").with_attribute("n", number.to_string());
+      let count = syllables.len();
+      for (index, (syllabic, text_content)) in syllables.into_iter().enumerate() {
+        let mut syl = XmlElement::named("syl");
+        match syllabic {
+          Some(Syllabic::Begin) => syl = syl.with_attribute("wordpos", "i").with_attribute("con", "d"),
+          Some(Syllabic::Middle) => syl = syl.with_attribute("wordpos", "m").with_attribute("con", "d"),
+          Some(Syllabic::End) => syl = syl.with_attribute("wordpos", "t"),
+          Some(Syllabic::Single) | None => {}
+        }
+        if index + 1 < count {
+          syl.attributes.retain(|(name, _)| name != "con");
+          syl = syl.with_attribute("con", "b");
+        } else if text.extend.is_some() {
+          syl.attributes.retain(|(name, _)| name != "con");
+          syl = syl.with_attribute("con", "u");
+        }
+        verse = verse.with_child(syl.with_text(text_content));
+      }
+      element.elements.push(verse);
+    }
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn export_note(
+    &mut self,
+    part: usize,
+    measure: usize,
+    note: &Note,
+    length: Fraction,
+    in_chord: bool,
+  ) -> XmlElement<'static> {
+    let id = self.note_id(note);
+    let staff = self.parts[part].first_staff + note_staff(note) - 1;
+    let duration = note_mei_duration(note, length);
+    let mut element = if note_is_rest(note) {
+      let measure_rest = matches!(
+        crate::util::note_audible(note),
+        crate::elements::AudibleType::Rest(rest) if rest.attributes.measure == Some(YesNo::Yes)
+      ) || note.content.r#type.is_none();
+      // A measure rest in an incomplete measure, such as a pickup, is written with its actual duration
+      let full_measure = length >= self.parts[part].meter_length || duration.is_none();
+      if measure_rest && full_measure && !in_chord {
+        XmlElement::named("mRest").with_attribute("xml:id", id.as_str())
+      } else {
+        XmlElement::named("rest").with_attribute("xml:id", id.as_str())
+      }
+    } else {
+      XmlElement::named("note").with_attribute("xml:id", id.as_str())
+    };
+    if element.name != "mRest" && !in_chord {
+      if let Some((duration, dots)) = duration {
+        element = element.with_attribute("dur", duration);
+        if dots > 0 {
+          element = element.with_attribute("dots", dots.to_string());
+        }
+      }
+    }
+    if element.name == "note" {
+      match crate::util::note_audible(note) {
+        crate::elements::AudibleType::Pitch(pitch) => {
+          element = element
+            .with_attribute(
+              "pname",
+              step_letter(&pitch.content.step.content)
+                .to_ascii_lowercase()
+                .to_string(),
+            )
+            .with_attribute("oct", pitch.content.octave.content.0.to_string());
+          let written = note.content.accidental.as_ref().and_then(|accidental| {
+            lookup(
+              &ACCIDENTALS,
+              &crate::datatypes::AccidentalValue::serialize(&accidental.content),
+            )
+          });
+          if let Some(written) = written {
+            element = element.with_attribute("accid", written);
+          }
+          if let Some(gestural) = gestural_accid(pitch_alter(pitch)) {
+            if written.and_then(accid_alter).map(i32::from) != Some(pitch_alter(pitch)) {
+              element = element.with_attribute("accid.ges", gestural);
+            }
+          }
+        }
+        crate::elements::AudibleType::Unpitched(unpitched) => {
+          element = element
+            .with_attribute(
+              "pname",
+              step_letter(&unpitched.content.display_step.content)
+                .to_ascii_lowercase()
+                .to_string(),
+            )
+            .with_attribute("oct", unpitched.content.display_octave.content.0.to_string());
+        }
+        crate::elements::AudibleType::Rest(_) => {}
+      }
+      if !in_chord {
+        element = Self::export_note_flags(note, element);
+      }
+    }
+    for notations in &note.content.notations {
+      for notation in &notations.content.notations {
+        match notation {
+          NotationContentTypes::Articulations(articulations) => {
+            let serialized = crate::elements::Articulations::serialize(articulations);
+            let artic: Vec<_> = serialized
+              .elements
+              .iter()
+              .filter_map(|child| lookup(&ARTICULATIONS, &child.name))
+              .collect();
+            if !artic.is_empty() && element.name == "note" {
+              element = element.with_child(XmlElement::named("artic").with_attribute("artic", artic.join(" ")));
+            }
+          }
+          NotationContentTypes::Fermata(fermata) => {
+            let mut event = XmlElement::named("fermata")
+              .with_attribute("staff", staff.to_string())
+              .with_attribute("startid", format!("#{id}"));
+            if fermata.attributes.r#type == Some(UprightInverted::Inverted) {
+              event = event.with_attribute("form", "inv");
+            }
+            self.control_events[measure].push(event);
+          }
+          NotationContentTypes::Slur(slur) => {
+            let number = slur.attributes.number.as_ref().map_or(1, |number| number.0);
+            match slur.attributes.r#type {
+              StartStopContinue::Start => {
+                self.parts[part].open_slurs.insert(number, (id.clone(), measure, staff));
+              }
+              StartStopContinue::Stop => {
+                if let Some((start, start_measure, start_staff)) = self.parts[part].open_slurs.remove(&number) {
+                  self.control_events[start_measure].push(
+                    XmlElement::named("slur")
+                      .with_attribute("staff", start_staff.to_string())
+                      .with_attribute("startid", format!("#{start}"))
+                      .with_attribute("endid", format!("#{id}")),
+                  );
+                }
+              }
+              StartStopContinue::Continue => {}
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+    if let Some(pitch) = note_pitch(note) {
+      let (tie_start, tie_stop) = note_tied(note);
+      let midi = pitch_to_midi(pitch);
+      if tie_stop {
+        let open_ties = &mut self.parts[part].open_ties;
+        if let Some(index) = open_ties
+          .iter()
+          .position(|(tie_staff, tie_midi, _, _)| *tie_midi == midi && *tie_staff == staff)
+          .or_else(|| open_ties.iter().position(|(_, tie_midi, _, _)| *tie_midi == midi))
+        {
+          let (start_staff, _, start, start_measure) = open_ties.remove(index);
+          self.control_events[start_measure].push(
+            XmlElement::named("tie")
+              .with_attribute("staff", start_staff.to_string())
+              .with_attribute("startid", format!("#{start}"))
+              .with_attribute("endid", format!("#{id}")),
+          );
+        }
+      }
+      if tie_start {
+        self.parts[part].open_ties.push((staff, midi, id.clone(), measure));
+      }
+    }
+    Self::export_lyrics(note, &mut element);
+    element
+  }
+
+  fn export_note_flags(note: &Note, mut element: XmlElement<'static>) -> XmlElement<'static> {
+    if let Some(stem) = &note.content.stem {
+      match stem.content {
+        StemValue::Up => element = element.with_attribute("stem.dir", "up"),
+        StemValue::Down => element = element.with_attribute("stem.dir", "down"),
+        StemValue::Double | StemValue::None => {}
+      }
+    }
+    if let crate::elements::NoteType::Grace(grace) = &note.content.info {
+      element = element.with_attribute(
+        "grace",
+        if grace.grace.attributes.slash == Some(YesNo::Yes) {
+          "unacc"
+        } else {
+          "acc"
+        },
+      );
+    }
+    if note_is_cue(note) {
+      element = element.with_attribute("cue", "true");
+    }
+    element
+  }
+
+  fn export_chord(&mut self, part: usize, measure: usize, notes: &[&Note], length: Fraction) -> XmlElement<'static> {
+    let first = notes[0];
+    let mut chord = XmlElement::named("chord").with_attribute("xml:id", self.ids.generate("chord"));
+    let duration = note_mei_duration(first, length);
+    if let Some((duration, dots)) = duration {
+      chord = chord.with_attribute("dur", duration);
+      if dots > 0 {
+        chord = chord.with_attribute("dots", dots.to_string());
+      }
+    }
+    chord = Self::export_note_flags(first, chord);
+    for note in notes {
+      let element = self.export_note(part, measure, note, length, true);
+      chord.elements.push(element);
+    }
+    chord
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn export_direction(&mut self, part: usize, measure: usize, position: Fraction, direction: &Direction) {
+    let info = &self.parts[part];
+    let staff = info.first_staff + direction.content.staff.as_ref().map_or(1, |staff| *staff.content) - 1;
+    let tstamp = time_stamp(position, info.meter_unit);
+    let place = placement(direction.attributes.placement.as_ref());
+    let event = |name: &str| {
+      let event = XmlElement::named(name)
+        .with_attribute("staff", staff.to_string())
+        .with_attribute("tstamp", tstamp.as_str());
+      match place {
+        Some(place) => event.with_attribute("place", place),
+        None => event,
+      }
+    };
+    let (mut words, mut tempo) = (String::new(), None);
+    for direction_type in &direction.content.direction_type {
+      match &direction_type.content {
+        DirectionTypeContents::Dynamics(dynamics) => {
+          for dynamics in dynamics {
+            let serialized = crate::elements::Dynamics::serialize(dynamics);
+            let text: String = serialized
+              .elements
+              .iter()
+              .map(|child| {
+                if child.name == "other-dynamics" {
+                  child.text.as_ref()
+                } else {
+                  child.name.as_ref()
+                }
+              })
+              .collect();
+            self.control_events[measure].push(event("dynam").with_text(text));
+          }
+        }
+        DirectionTypeContents::Words(words_list) => {
+          for text in words_list {
+            words += &text.content;
+          }
+        }
+        DirectionTypeContents::Rehearsal(rehearsals) => {
+          for rehearsal in rehearsals {
+            self.control_events[measure].push(event("reh").with_text(rehearsal.content.as_str()));
+          }
+        }
+        DirectionTypeContents::Metronome(metronome) => {
+          let mut element = event("tempo");
+          if let MetronomeContents::BeatBased(beat_based) = &metronome.content {
+            if let BeatEquation::BPM(per_minute) = &beat_based.equals {
+              if let Some(mm) = parse_decimal(&per_minute.content) {
+                element = element
+                  .with_attribute("mm", format_decimal(mm))
+                  .with_attribute("mm.unit", mei_duration(&beat_based.beat_unit.content));
+                if !beat_based.beat_unit_dot.is_empty() {
+                  element = element.with_attribute("mm.dots", beat_based.beat_unit_dot.len().to_string());
+                }
+              }
+            }
+          }
+          tempo = Some(element);
+        }
+        DirectionTypeContents::Wedge(wedge) => {
+          let number = wedge.attributes.number.as_ref().map_or(1, |number| number.0);
+          match wedge.attributes.r#type {
+            WedgeType::Crescendo | WedgeType::Diminuendo => {
+              let form = if wedge.attributes.r#type == WedgeType::Crescendo {
+                "cres"
+              } else {
+                "dim"
+              };
+              self.parts[part]
+                .open_wedges
+                .insert(number, (measure, event("hairpin").with_attribute("form", form)));
+            }
+            WedgeType::Stop => {
+              if let Some((start_measure, hairpin)) = self.parts[part].open_wedges.remove(&number) {
+                let hairpin = hairpin.with_attribute("tstamp2", format!("{}m+{tstamp}", measure - start_measure));
+                self.control_events[start_measure].push(hairpin);
+              }
+            }
+            WedgeType::Continue => {}
+          }
+        }
+        _ => {}
+      }
+    }
+    if let Some(bpm) = direction
+      .content
+      .sound
+      .as_ref()
+      .and_then(|sound| sound.attributes.tempo.as_ref())
+    {
+      tempo = Some(
+        tempo
+          .unwrap_or_else(|| event("tempo"))
+          .with_attribute("midi.bpm", format!("{}", bpm.0)),
+      );
+    }
+    if let Some(tempo) = tempo {
+      self.control_events[measure].push(tempo.with_text(words));
+    } else if !words.is_empty() {
+      self.control_events[measure].push(event("dir").with_text(words));
+    }
+  }
+
+  fn export_harmony(&mut self, part: usize, measure: usize, position: Fraction, harmony: &Harmony) {
+    let info = &self.parts[part];
+    let staff = info.first_staff + harmony.content.staff.as_ref().map_or(1, |staff| *staff.content) - 1;
+    let mut element = XmlElement::named("harm")
+      .with_attribute("staff", staff.to_string())
+      .with_attribute("tstamp", time_stamp(position, info.meter_unit));
+    if let Some(place) = placement(harmony.attributes.placement.as_ref()) {
+      element = element.with_attribute("place", place);
+    }
+    self.control_events[measure].push(element.with_text(harmony_element_text(harmony)));
+  }
+
+  fn export_layer(
+    &mut self,
+    part: usize,
+    measure: usize,
+    number: u32,
+    events: &[&TimedEvent<'_>],
+    clefs: &[(Fraction, XmlElement<'static>)],
+  ) -> XmlElement<'static> {
+    let mut stack = vec![XmlElement::named("layer").with_attribute("n", number.to_string())];
+    let mut cursor = Fraction::ZERO;
+    let mut clefs = clefs.iter().peekable();
+    for event in events {
+      while let Some((_, clef)) = clefs.next_if(|(position, _)| *position <= event.position) {
+        push_child(&mut stack, clef.clone());
+      }
+      let MeasureEvent::Notes(notes) = &event.event else {
+        continue;
+      };
+      if event.position > cursor {
+        for (note_type, dots) in split_length(event.position - cursor) {
+          let mut space = XmlElement::named("space").with_attribute("dur", mei_duration(&note_type));
+          if dots > 0 {
+            space = space.with_attribute("dots", dots.to_string());
+          }
+          push_child(&mut stack, space);
+        }
+      }
+      cursor = cursor.max(event.position + event.duration);
+      let first = notes[0];
+      let tuplet = first
+        .content
+        .notations
+        .iter()
+        .flat_map(|notations| &notations.content.notations)
+        .find_map(|notation| match notation {
+          NotationContentTypes::Tuplet(tuplet) => Some(&tuplet.attributes.r#type),
+          _ => None,
+        });
+      let beam = first
+        .content
+        .beam
+        .iter()
+        .find(|beam| beam.attributes.number.as_ref().is_none_or(|number| number.0 == 1))
+        .map(|beam| &beam.content);
+      if tuplet == Some(&StartStop::Start) {
+        let (actual, normal) = note_time_modification(first);
+        stack.push(
+          XmlElement::named("tuplet")
+            .with_attribute("num", actual.to_string())
+            .with_attribute("numbase", normal.to_string()),
+        );
+      }
+      if beam == Some(&BeamValue::Begin) {
+        stack.push(XmlElement::named("beam"));
+      }
+      let element = if notes.len() == 1 {
+        self.export_note(part, measure, first, event.duration, false)
+      } else {
+        self.export_chord(part, measure, notes, event.duration)
+      };
+      push_child(&mut stack, element);
+      if beam == Some(&BeamValue::End) {
+        close_container(&mut stack, "beam");
+      }
+      if tuplet == Some(&StartStop::Stop) {
+        close_container(&mut stack, "tuplet");
+      }
+    }
+    for (_, clef) in clefs {
+      push_child(&mut stack, clef.clone());
+    }
+    while stack.len() > 1 {
+      if let Some(element) = stack.pop() {
+        push_child(&mut stack, element);
+      }
+    }
+    stack.pop().unwrap_or_else(|| XmlElement::named("layer"))
+  }
+
+  fn export_part_measure(&mut self, part: usize, index: usize) -> Vec<XmlElement<'static>> {
+    let Some(measure) = self.parts[part].measures.get(index).copied() else {
+      return Vec::new();
+    };
+    let mut timeline = self.parts[part].timeline;
+    let measure_timeline = timeline.measure(measure);
+    self.parts[part].timeline = timeline;
+    let mut voices: BTreeMap<u32, Vec<(String, Vec<&TimedEvent<'_>>)>> = BTreeMap::new();
+    let mut clefs: BTreeMap<u32, Vec<(Fraction, XmlElement<'static>)>> = BTreeMap::new();
+    for event in &measure_timeline.events {
+      match &event.event {
+        MeasureEvent::Notes(notes) => {
+          let (staff, voice) = (note_staff(notes[0]), note_voice(notes[0]));
+          let staff_voices = voices.entry(staff).or_default();
+          match staff_voices.iter_mut().find(|(name, _)| name == voice) {
+            Some((_, events)) => events.push(event),
+            None => staff_voices.push((String::from(voice), vec![event])),
+          }
+        }
+        MeasureEvent::Attributes(attributes) => {
+          if let Some(time) = attributes.content.time.first() {
+            if let Some(unit) = meter_unit(time) {
+              self.parts[part].meter_unit = unit;
+            }
+            if let Some(length) = meter_length(time) {
+              self.parts[part].meter_length = length;
+            }
+          }
+          if index > 0 || event.position.is_positive() {
+            for clef in &attributes.content.clef {
+              if let Some(element) = clef_element(clef) {
+                let staff = clef.attributes.number.as_ref().map_or(1, |number| u32::from(number.0));
+                clefs.entry(staff).or_default().push((event.position, element));
+              }
+            }
+          }
+        }
+        MeasureEvent::Direction(direction) => self.export_direction(part, index, event.position, direction),
+        MeasureEvent::Harmony(harmony) => self.export_harmony(part, index, event.position, harmony),
+        MeasureEvent::Sound(_) => {}
+      }
+    }
+    let mut staves = Vec::new();
+    for staff in 1..=self.parts[part].staves {
+      let mut element =
+        XmlElement::named("staff").with_attribute("n", (self.parts[part].first_staff + staff - 1).to_string());
+      let staff_clefs = clefs.remove(&staff).unwrap_or_default();
+      let staff_voices = voices.remove(&staff).unwrap_or_default();
+      if staff_voices.is_empty() {
+        let mut layer = XmlElement::named("layer").with_attribute("n", "1");
+        layer.elements.extend(staff_clefs.iter().map(|(_, clef)| clef.clone()));
+        element.elements.push(layer.with_child(XmlElement::named("mSpace")));
+      }
+      let mut used_numbers = BTreeSet::new();
+      for (voice_index, (voice, events)) in staff_voices.iter().enumerate() {
+        let number = voice
+          .parse::<u32>()
+          .ok()
+          .filter(|number| *number > 0 && !used_numbers.contains(number))
+          .unwrap_or_else(|| {
+            (1..=u32::MAX)
+              .find(|number| !used_numbers.contains(number))
+              .unwrap_or(1)
+          });
+        used_numbers.insert(number);
+        let layer_clefs = if voice_index == 0 { staff_clefs.as_slice() } else { &[] };
+        let layer = self.export_layer(part, index, number, events, layer_clefs);
+        element.elements.push(layer);
+      }
+      staves.push(element);
+    }
+    staves
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn export_section(&mut self) -> XmlElement<'static> {
+    let mut section = XmlElement::named("section");
+    let mut ending: Option<XmlElement<'static>> = None;
+    let measure_count = self.control_events.len();
+    for index in 0..measure_count {
+      let Some(reference) = self.parts.iter().find_map(|part| part.measures.get(index).copied()) else {
+        continue;
+      };
+      let mut element = XmlElement::named("measure").with_attribute("n", reference.attributes.number.0.as_str());
+      if let Some(id) = &reference.attributes.id {
+        element = element.with_attribute("xml:id", id.0.as_str());
+      }
+      if reference.attributes.implicit == Some(YesNo::Yes) {
+        element = element.with_attribute("metcon", "false");
+      }
+      let (mut left, mut right, mut ending_start, mut ending_close) = (None, None, None, false);
+      for barline in reference.content.iter().filter_map(|element| match element {
+        MeasureElement::Barline(barline) => Some(barline),
+        _ => None,
+      }) {
+        let style = barline
+          .content
+          .bar_style
+          .as_ref()
+          .and_then(|style| lookup(&BAR_STYLES, &crate::datatypes::BarStyle::serialize(&style.content)));
+        let repeat = barline
+          .content
+          .repeat
+          .as_ref()
+          .map(|repeat| &repeat.attributes.direction);
+        if barline.attributes.location == Some(RightLeftMiddle::Left) {
+          left = if repeat == Some(&BackwardForward::Forward) {
+            Some("rptstart")
+          } else {
+            style
+          };
+          if let Some(mei_ending) = &barline.content.ending {
+            if mei_ending.attributes.r#type == StartStopDiscontinue::Start {
+              ending_start = Some((mei_ending.attributes.number.0.clone(), mei_ending.content.clone()));
+            }
+          }
+        } else if barline.attributes.location != Some(RightLeftMiddle::Middle) {
+          right = if repeat == Some(&BackwardForward::Backward) {
+            Some("rptend")
+          } else {
+            style
+          };
+          if let Some(mei_ending) = &barline.content.ending {
+            ending_close |= mei_ending.attributes.r#type != StartStopDiscontinue::Start;
+          }
+        }
+      }
+      if let Some(left) = left {
+        element = element.with_attribute("left", left);
+      }
+      if let Some(right) = right {
+        element = element.with_attribute("right", right);
+      }
+      let mut changes = XmlElement::named("scoreDef");
+      if index > 0 {
+        for part in &self.parts {
+          let Some(measure) = part.measures.get(index) else {
+            continue;
+          };
+          for element in &measure.content {
+            if let MeasureElement::Attributes(attributes) = element {
+              if changes.child("keySig").is_none() {
+                if let Some(key) = attributes.content.key.first().and_then(key_signature) {
+                  changes.elements.insert(0, key);
+                }
+              }
+              if changes.child("meterSig").is_none() {
+                if let Some(time) = attributes.content.time.first().and_then(meter_signature) {
+                  changes.elements.push(time);
+                }
+              }
+            }
+          }
+        }
+      }
+      for part in 0..self.parts.len() {
+        let staves = self.export_part_measure(part, index);
+        element.elements.extend(staves);
+      }
+      if let Some((number, label)) = ending_start {
+        if let Some(ending) = ending.take() {
+          section.elements.push(ending);
+        }
+        let mut new_ending = XmlElement::named("ending").with_attribute("n", number);
+        if !label.is_empty() {
+          new_ending = new_ending.with_attribute("label", label);
+        }
+        ending = Some(new_ending);
+      }
+      let container = ending.as_mut().unwrap_or(&mut section);
+      if !changes.elements.is_empty() {
+        container.elements.push(changes);
+      }
+      container.elements.push(element);
+      if ending_close {
+        if let Some(ending) = ending.take() {
+          section.elements.push(ending);
+        }
+      }
+    }
+    if let Some(ending) = ending.take() {
+      section.elements.push(ending);
+    }
+    let control_events = core::mem::take(&mut self.control_events);
+    let measures = section.elements.iter_mut().flat_map(|element| {
+      if element.name == "ending" {
+        element.elements.iter_mut().collect::<Vec<_>>()
+      } else {
+        vec![element]
+      }
+    });
+    for (measure, events) in measures.filter(|element| element.name == "measure").zip(control_events) {
+      measure.elements.extend(events);
+    }
+    section
+  }
+}
+
+fn index_matches(open: Option<&String>, number: Option<&String>) -> bool {
+  open.map_or("1", String::as_str) == number.map_or("1", String::as_str)
+}
+
+fn push_child(stack: &mut [XmlElement], child: XmlElement<'static>) {
+  if let Some(parent) = stack.last_mut() {
+    parent.elements.push(child);
+  }
+}
+
+fn close_container(stack: &mut Vec<XmlElement<'static>>, name: &str) {
+  if let Some(index) = stack.iter().skip(1).rposition(|element| element.name == name) {
+    while stack.len() > index + 1 {
+      if let Some(element) = stack.pop() {
+        push_child(stack, element);
+      }
+    }
+  }
+}
+
+/// Converts a [ScorePartwise] into an MEI 5 document.
+///
+/// Each [ScorePart] is written as an MEI `<staffDef>`, or as a braced `<staffGrp>` when the part spans multiple
+/// staves, and each [PartGroup][crate::elements::PartGroup] becomes an enclosing `<staffGrp>`. Notes without an
+/// explicit `id` receive a generated `xml:id` so that ties and slurs can reference them.
+///
+/// # Errors
+///
+/// Returns an error if the score contains a part that is not declared in its part list.
+pub fn score_partwise_to_mei(score: &ScorePartwise) -> Result<String, String> {
+  let mut exporter = MeiExporter::new(score)?;
+  let score_def = exporter.score_def();
+  let section = exporter.export_section();
+  let mut mdiv = XmlElement::named("mdiv");
+  if let Some(number) = &score.content.movement_number {
+    mdiv = mdiv.with_attribute("n", number.content.as_str());
+  }
+  if let Some(title) = &score.content.movement_title {
+    mdiv = mdiv.with_attribute("label", title.content.as_str());
+  }
+  let mut mei = XmlElement::named("mei")
+    .with_attribute("xmlns", MEI_NAMESPACE)
+    .with_attribute("meiversion", MEI_VERSION)
+    .with_child(export_header(score))
+    .with_child(
+      XmlElement::named("music").with_child(
+        XmlElement::named("body")
+          .with_child(mdiv.with_child(XmlElement::named("score").with_child(score_def).with_child(section))),
+      ),
+    );
+  xml_escape_tree(&mut mei);
+  Ok(format!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<?xml-model href=\"{MEI_SCHEMA}\" type=\"application/xml\" schematypens=\"http://relaxng.org/ns/structure/1.0\"?>\n{}\n",
+    xml_parser::parse_to_string(&mei, 0)
+  ))
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// IMPORT -------------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// A notation attached to a note by an MEI control event or attribute.
+enum NoteMark {
+  TieStart,
+  TieStop,
+  SlurStart(u8),
+  SlurStop(u8),
+  Fermata(bool),
+}
+
+/// A MusicXML note under construction, kept separate from its XML form until beams and tuplets have been applied.
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct ImportedNote {
+  id: Option<String>,
+  grace: Option<bool>,
+  cue: bool,
+  chord: bool,
+  pitch: Option<(char, i16, String)>,
+  measure_rest: bool,
+  duration: Fraction,
+  tie_start: bool,
+  tie_stop: bool,
+  voice: String,
+  note_type: Option<NoteTypeValue>,
+  dots: u8,
+  accidental: Option<&'static str>,
+  time_modification: Option<(i64, i64)>,
+  stem: Option<String>,
+  staff: Option<u32>,
+  beam: Option<&'static str>,
+  tuplet: Vec<&'static str>,
+  notations: Vec<XmlElement<'static>>,
+  lyrics: Vec<XmlElement<'static>>,
+}
+
+impl ImportedNote {
+  fn to_xml(&self) -> XmlElement<'static> {
+    let mut note = XmlElement::named("note");
+    if let Some(id) = &self.id {
+      note = note.with_attribute("id", id.as_str());
+    }
+    if let Some(slash) = self.grace {
+      let grace = XmlElement::named("grace");
+      note = note.with_child(if slash {
+        grace.with_attribute("slash", "yes")
+      } else {
+        grace
+      });
+    }
+    if self.cue {
+      note = note.with_child(XmlElement::named("cue"));
+    }
+    if self.chord {
+      note = note.with_child(XmlElement::named("chord"));
+    }
+    if let Some((step, alter, octave)) = &self.pitch {
+      let mut pitch = XmlElement::named("pitch").with_child(XmlElement::named("step").with_text(step.to_string()));
+      if *alter != 0 {
+        pitch = pitch.with_child(XmlElement::named("alter").with_text(alter.to_string()));
+      }
+      note = note.with_child(pitch.with_child(XmlElement::named("octave").with_text(octave.as_str())));
+    } else {
+      let rest = XmlElement::named("rest");
+      note = note.with_child(if self.measure_rest {
+        rest.with_attribute("measure", "yes")
+      } else {
+        rest
+      });
+    }
+    if self.grace.is_none() {
+      note = note.with_child(XmlElement::named("duration").with_text(format_fraction(self.duration)));
+    }
+    if self.tie_stop {
+      note = note.with_child(XmlElement::named("tie").with_attribute("type", "stop"));
+    }
+    if self.tie_start {
+      note = note.with_child(XmlElement::named("tie").with_attribute("type", "start"));
+    }
+    note = note.with_child(XmlElement::named("voice").with_text(self.voice.as_str()));
+    if let Some(note_type) = &self.note_type {
+      note = note.with_child(XmlElement::named("type").with_text(NoteTypeValue::serialize(note_type)));
+    }
+    for _ in 0..self.dots {
+      note = note.with_child(XmlElement::named("dot"));
+    }
+    if let Some(accidental) = self.accidental {
+      note = note.with_child(XmlElement::named("accidental").with_text(accidental));
+    }
+    if let Some((actual, normal)) = self.time_modification {
+      note = note.with_child(
+        XmlElement::named("time-modification")
+          .with_child(XmlElement::named("actual-notes").with_text(actual.to_string()))
+          .with_child(XmlElement::named("normal-notes").with_text(normal.to_string())),
+      );
+    }
+    if let Some(stem) = &self.stem {
+      note = note.with_child(XmlElement::named("stem").with_text(stem.as_str()));
+    }
+    if let Some(staff) = self.staff {
+      note = note.with_child(XmlElement::named("staff").with_text(staff.to_string()));
+    }
+    if let Some(beam) = self.beam {
+      note = note.with_child(XmlElement::named("beam").with_attribute("number", "1").with_text(beam));
+    }
+    let mut notations = XmlElement::named("notations");
+    if self.tie_stop {
+      notations = notations.with_child(XmlElement::named("tied").with_attribute("type", "stop"));
+    }
+    if self.tie_start {
+      notations = notations.with_child(XmlElement::named("tied").with_attribute("type", "start"));
+    }
+    notations.elements.extend(self.notations.iter().cloned());
+    for tuplet in &self.tuplet {
+      notations = notations.with_child(XmlElement::named("tuplet").with_attribute("type", *tuplet));
+    }
+    if !notations.elements.is_empty() {
+      note = note.with_child(notations);
+    }
+    note.elements.extend(self.lyrics.iter().cloned());
+    note
+  }
+}
+
+/// An item produced while reading an MEI `<layer>`.
+enum LayerItem {
+  Note(ImportedNote),
+  Element(XmlElement<'static>),
+}
+
+/// The state used while reading the contents of a single MEI `<layer>`.
+struct LayerContext {
+  position: Fraction,
+  ratio: (i64, i64),
+  grace: Option<bool>,
+  voice: String,
+  staff: u32,
+  global_staff: u32,
+  multi_staff: bool,
+}
+
+/// Describes how an MEI staff maps onto a MusicXML part.
+struct ImportedStaff {
+  part: usize,
+  local: u32,
+  clef: Option<XmlElement<'static>>,
+  key: Option<(i32, Option<String>)>,
+  lines: Option<String>,
+  transpose: Option<(String, Option<String>)>,
+}
+
+struct ImportedPart {
+  id: String,
+  staves: u32,
+  measures: Vec<XmlElement<'static>>,
+  cursor: Fraction,
+}
+
+/// An event waiting to be inserted into a part at a specific measure position.
+struct PlacedEvent {
+  measure: usize,
+  staff: u32,
+  position: Fraction,
+  element: XmlElement<'static>,
+}
+
+#[derive(Default)]
+struct MeiImporter {
+  parts: Vec<ImportedPart>,
+  part_list: Vec<XmlElement<'static>>,
+  staves: BTreeMap<u32, ImportedStaff>,
+  key: Option<(i32, Option<String>)>,
+  meter: Option<(String, String, Option<String>)>,
+  marks: BTreeMap<String, Vec<NoteMark>>,
+  note_positions: BTreeMap<String, (u32, Fraction)>,
+  pending: Vec<PlacedEvent>,
+  accidentals: BTreeMap<(u32, char, String), i16>,
+  measure_count: usize,
+  group_count: usize,
+  changes: Option<XmlElement<'static>>,
+  ending_start: Option<(String, String)>,
+  repeat_start: bool,
+}
+
+/// Returns the concatenated text content of an element and all of its descendants.
+fn text_content(element: &XmlElement) -> String {
+  let mut text = String::from(element.text.as_ref());
+  for child in &element.elements {
+    text += &text_content(child);
+  }
+  text
+}
+
+fn label_text(element: &XmlElement, name: &str) -> Option<String> {
+  element
+    .child(name)
+    .map(text_content)
+    .or_else(|| element.attribute(name).map(String::from))
+    .filter(|text| !text.is_empty())
+}
+
+fn key_from_mei(element: &XmlElement) -> Option<(i32, Option<String>)> {
+  let (sig, mode) = match element.child("keySig") {
+    Some(key_sig) => (key_sig.attribute("sig")?, key_sig.attribute("mode")),
+    None => (element.attribute("key.sig")?, element.attribute("key.mode")),
+  };
+  let fifths = match sig.split_at(sig.len().saturating_sub(1)) {
+    (count, "s") => count.parse().ok()?,
+    (count, "f") => -count.parse::<i32>().ok()?,
+    _ => 0,
+  };
+  Some((fifths, mode.map(String::from)))
+}
+
+fn meter_from_mei(element: &XmlElement) -> Option<(String, String, Option<String>)> {
+  let (count, unit, sym) = match element.child("meterSig") {
+    Some(meter_sig) => (
+      meter_sig.attribute("count"),
+      meter_sig.attribute("unit"),
+      meter_sig.attribute("sym"),
+    ),
+    None => (
+      element.attribute("meter.count"),
+      element.attribute("meter.unit"),
+      element.attribute("meter.sym"),
+    ),
+  };
+  let (count, unit) = match sym {
+    Some("common") => (count.unwrap_or("4"), unit.unwrap_or("4")),
+    Some("cut") => (count.unwrap_or("2"), unit.unwrap_or("2")),
+    _ => (count?, unit?),
+  };
+  Some((String::from(count), String::from(unit), sym.map(String::from)))
+}
+
+fn clef_from_mei(element: &XmlElement, number: Option<u32>) -> Option<XmlElement<'static>> {
+  let (shape, line, dis, place) = match element.name.as_ref() {
+    "clef" => (
+      element.attribute("shape")?,
+      element.attribute("line"),
+      element.attribute("dis"),
+      element.attribute("dis.place"),
+    ),
+    _ => match element.child("clef") {
+      Some(clef) => return clef_from_mei(clef, number),
+      None => (
+        element.attribute("clef.shape")?,
+        element.attribute("clef.line"),
+        element.attribute("clef.dis"),
+        element.attribute("clef.dis.place"),
+      ),
+    },
+  };
+  let sign = match shape {
+    "G" | "GG" => "G",
+    "F" => "F",
+    "C" => "C",
+    "perc" => "percussion",
+    "TAB" => "TAB",
+    _ => return None,
+  };
+  let mut clef = XmlElement::named("clef");
+  if let Some(number) = number {
+    clef = clef.with_attribute("number", number.to_string());
+  }
+  clef = clef.with_child(XmlElement::named("sign").with_text(sign));
+  if let Some(line) = line {
+    clef = clef.with_child(XmlElement::named("line").with_text(line));
+  }
+  let octaves = match dis {
+    Some("8") => 1,
+    Some("15") => 2,
+    Some("22") => 3,
+    _ => 0,
+  };
+  if octaves != 0 {
+    let change = if place == Some("below") { -octaves } else { octaves };
+    clef = clef.with_child(XmlElement::named("clef-octave-change").with_text(change.to_string()));
+  }
+  Some(clef)
+}
+
+fn key_xml((fifths, mode): &(i32, Option<String>)) -> XmlElement<'static> {
+  let key = XmlElement::named("key").with_child(XmlElement::named("fifths").with_text(fifths.to_string()));
+  match mode {
+    Some(mode) => key.with_child(XmlElement::named("mode").with_text(mode.as_str())),
+    None => key,
+  }
+}
+
+fn time_xml((count, unit, sym): &(String, String, Option<String>)) -> XmlElement<'static> {
+  let time = XmlElement::named("time");
+  let time = match sym.as_deref() {
+    Some("common" | "cut") => time.with_attribute("symbol", sym.as_deref().unwrap_or_default()),
+    _ => time,
+  };
+  time
+    .with_child(XmlElement::named("beats").with_text(count.as_str()))
+    .with_child(XmlElement::named("beat-type").with_text(unit.as_str()))
+}
+
+/// Returns the position of a `tstamp` value in quarter notes from the start of the measure.
+fn position_from_tstamp(tstamp: &str, meter_unit: i64) -> Option<Fraction> {
+  let position = (parse_decimal(tstamp)? - Fraction::from_int(1)) * Fraction::new(4, meter_unit.max(1));
+  Some(position.rounded(Fraction::new(1, 96)).max(Fraction::ZERO))
+}
+
+fn set_attribute(element: &mut XmlElement, name: &str, value: &str) {
+  element.attributes.retain(|(key, _)| key != name);
+  element
+    .attributes
+    .push((Cow::Owned(String::from(name)), Cow::Owned(String::from(value))));
+}
+
+impl MeiImporter {
+  fn meter_unit(&self) -> i64 {
+    self
+      .meter
+      .as_ref()
+      .and_then(|(_, unit, _)| unit.parse().ok())
+      .unwrap_or(4)
+  }
+
+  fn meter_length(&self) -> Fraction {
+    match &self.meter {
+      Some((count, unit, _)) => {
+        let beats: i64 = count
+          .split('+')
+          .filter_map(|count| count.trim().parse::<i64>().ok())
+          .sum();
+        Fraction::new(beats.max(1) * 4, unit.parse::<i64>().unwrap_or(4).max(1))
+      }
+      None => Fraction::from_int(4),
+    }
+  }
+
+  fn read_staff_def(&mut self, staff_def: &XmlElement, part: usize, local: u32) {
+    let Some(number) = staff_def.attribute("n").and_then(|n| n.parse::<u32>().ok()) else {
+      return;
+    };
+    let transpose = staff_def
+      .attribute("trans.semi")
+      .map(|semi| (String::from(semi), staff_def.attribute("trans.diat").map(String::from)));
+    self.staves.insert(
+      number,
+      ImportedStaff {
+        part,
+        local,
+        clef: clef_from_mei(staff_def, None),
+        key: key_from_mei(staff_def),
+        lines: staff_def.attribute("lines").map(String::from),
+        transpose,
+      },
+    );
+  }
+
+  fn add_part(&mut self, name: Option<String>, abbreviation: Option<String>, staff_defs: &[&XmlElement]) {
+    let part = self.parts.len();
+    let id = format!("P{}", part + 1);
+    for (number, staff_def) in (1..).zip(staff_defs) {
+      self.read_staff_def(staff_def, part, number);
+    }
+    let mut score_part = XmlElement::named("score-part")
+      .with_attribute("id", id.as_str())
+      .with_child(XmlElement::named("part-name").with_text(name.unwrap_or_default()));
+    if let Some(abbreviation) = abbreviation {
+      score_part = score_part.with_child(XmlElement::named("part-abbreviation").with_text(abbreviation));
+    }
+    self.part_list.push(score_part);
+    self.parts.push(ImportedPart {
+      id,
+      staves: u32::try_from(staff_defs.len().max(1)).unwrap_or(u32::MAX),
+      measures: Vec::new(),
+      cursor: Fraction::ZERO,
+    });
+  }
+
+  fn read_staff_grp(&mut self, group: &XmlElement, is_root: bool) {
+    let staff_defs: Vec<&XmlElement> = group.children_named("staffDef").collect();
+    let is_single_part = !is_root
+      && staff_defs.len() > 1
+      && group.children_named("staffGrp").next().is_none()
+      && label_text(group, "label").is_some()
+      && staff_defs
+        .iter()
+        .all(|staff_def| label_text(staff_def, "label").is_none());
+    if is_single_part {
+      self.add_part(label_text(group, "label"), label_text(group, "labelAbbr"), &staff_defs);
+      return;
+    }
+    let mut part_group = None;
+    if !is_root {
+      self.group_count += 1;
+      let number = self.group_count.to_string();
+      let mut start = XmlElement::named("part-group")
+        .with_attribute("type", "start")
+        .with_attribute("number", number.as_str());
+      if let Some(label) = label_text(group, "label") {
+        start = start.with_child(XmlElement::named("group-name").with_text(label));
+      }
+      if let Some(label) = label_text(group, "labelAbbr") {
+        start = start.with_child(XmlElement::named("group-abbreviation").with_text(label));
+      }
+      if let Some(symbol) = group.attribute("symbol") {
+        let symbol = match symbol {
+          "bracketsq" => "square",
+          "brace" | "bracket" | "line" => symbol,
+          _ => "none",
+        };
+        start = start.with_child(XmlElement::named("group-symbol").with_text(symbol));
+      }
+      if let Some(bar_thru) = group.attribute("bar.thru") {
+        start =
+          start.with_child(XmlElement::named("group-barline").with_text(if bar_thru == "true" { "yes" } else { "no" }));
+      }
+      self.part_list.push(start);
+      part_group = Some(number);
+    }
+    for child in &group.elements {
+      match child.name.as_ref() {
+        "staffDef" => self.add_part(label_text(child, "label"), label_text(child, "labelAbbr"), &[child]),
+        "staffGrp" => self.read_staff_grp(child, false),
+        _ => {}
+      }
+    }
+    if let Some(number) = part_group {
+      self.part_list.push(
+        XmlElement::named("part-group")
+          .with_attribute("type", "stop")
+          .with_attribute("number", number),
+      );
+    }
+  }
+
+  fn read_score_def(&mut self, score_def: &XmlElement) {
+    self.key = key_from_mei(score_def);
+    self.meter = meter_from_mei(score_def);
+    if let Some(group) = score_def.child("staffGrp") {
+      self.read_staff_grp(group, true);
+    }
+  }
+
+  fn collect_marks(&mut self, element: &XmlElement, slur_count: &mut u8) {
+    let reference = |name: &str| {
+      element
+        .attribute(name)
+        .map(|id| String::from(id.trim_start_matches('#')))
+    };
+    match element.name.as_ref() {
+      "tie" => {
+        if let (Some(start), Some(end)) = (reference("startid"), reference("endid")) {
+          self.marks.entry(start).or_default().push(NoteMark::TieStart);
+          self.marks.entry(end).or_default().push(NoteMark::TieStop);
+        }
+      }
+      "slur" => {
+        if let (Some(start), Some(end)) = (reference("startid"), reference("endid")) {
+          let number = *slur_count + 1;
+          *slur_count = (*slur_count + 1) % 16;
+          self.marks.entry(start).or_default().push(NoteMark::SlurStart(number));
+          self.marks.entry(end).or_default().push(NoteMark::SlurStop(number));
+        }
+      }
+      "fermata" => {
+        if let Some(start) = reference("startid") {
+          let inverted = element.attribute("form") == Some("inv") || element.attribute("place") == Some("below");
+          self.marks.entry(start).or_default().push(NoteMark::Fermata(inverted));
+        }
+      }
+      _ => {
+        for child in &element.elements {
+          self.collect_marks(child, slur_count);
+        }
+      }
+    }
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn read_note(&mut self, element: &XmlElement, chord: Option<&XmlElement>, context: &LayerContext) -> ImportedNote {
+    let attribute = |name: &str| {
+      element
+        .attribute(name)
+        .or_else(|| chord.and_then(|chord| chord.attribute(name)))
+    };
+    let accid = element.child("accid");
+    let mut note = ImportedNote {
+      id: element.attribute("xml:id").map(String::from),
+      voice: context.voice.clone(),
+      staff: context.multi_staff.then_some(context.staff),
+      chord: chord.is_some_and(|chord| {
+        chord
+          .elements
+          .iter()
+          .find(|child| child.name == "note")
+          .is_some_and(|first| !core::ptr::eq(first, element))
+      }),
+      ..Default::default()
+    };
+    note.grace = attribute("grace").map(|grace| grace == "unacc").or(context.grace);
+    note.cue = attribute("cue") == Some("true");
+    note.stem = attribute("stem.dir")
+      .filter(|stem| *stem == "up" || *stem == "down")
+      .map(String::from);
+    note.dots = attribute("dots")
+      .and_then(|dots| dots.parse().ok())
+      .unwrap_or_else(|| u8::try_from(element.children_named("dot").count()).unwrap_or(u8::MAX));
+    note.note_type = attribute("dur").and_then(note_type_from_mei);
+    let (num, numbase) = context.ratio;
+    if num != numbase {
+      note.time_modification = Some((num, numbase));
+    }
+    note.duration = match &note.note_type {
+      Some(note_type) => dotted_length(note_type, note.dots) * Fraction::new(numbase, num),
+      None => Fraction::from_int(1),
+    };
+    match element.name.as_ref() {
+      "mRest" => {
+        note.measure_rest = true;
+        note.duration = self.meter_length();
+      }
+      "note" => {
+        let step = element
+          .attribute("pname")
+          .and_then(|pname| pname.chars().next())
+          .map_or('C', |pname| pname.to_ascii_uppercase());
+        let octave = String::from(element.attribute("oct").unwrap_or("4"));
+        let written = element
+          .attribute("accid")
+          .or_else(|| accid.and_then(|accid| accid.attribute("accid")));
+        let gestural = element
+          .attribute("accid.ges")
+          .or_else(|| accid.and_then(|accid| accid.attribute("accid.ges")));
+        note.accidental = written.and_then(|written| reverse_lookup(&ACCIDENTALS, written));
+        let carried = (context.global_staff, step, octave.clone());
+        let alter = gestural
+          .and_then(accid_alter)
+          .or_else(|| written.and_then(accid_alter))
+          .or_else(|| self.accidentals.get(&carried).copied())
+          .unwrap_or_else(|| {
+            let fifths = self
+              .staves
+              .get(&context.global_staff)
+              .and_then(|staff| staff.key.as_ref())
+              .or(self.key.as_ref())
+              .map_or(0, |(fifths, _)| *fifths);
+            key_signature_alter(fifths, step)
+          });
+        if written.is_some() {
+          self.accidentals.insert(carried, alter);
+        }
+        note.pitch = Some((step, alter, octave));
+      }
+      _ => {}
+    }
+    if let Some(id) = &note.id {
+      self
+        .note_positions
+        .insert(id.clone(), (context.global_staff, context.position));
+      for mark in self.marks.remove(id).unwrap_or_default() {
+        match mark {
+          NoteMark::TieStart => note.tie_start = true,
+          NoteMark::TieStop => note.tie_stop = true,
+          NoteMark::SlurStart(number) => note.notations.push(
+            XmlElement::named("slur")
+              .with_attribute("type", "start")
+              .with_attribute("number", number.to_string()),
+          ),
+          NoteMark::SlurStop(number) => note.notations.push(
+            XmlElement::named("slur")
+              .with_attribute("type", "stop")
+              .with_attribute("number", number.to_string()),
+          ),
+          NoteMark::Fermata(inverted) => note
+            .notations
+            .push(XmlElement::named("fermata").with_attribute("type", if inverted { "inverted" } else { "upright" })),
+        }
+      }
+    }
+    match element.attribute("tie") {
+      Some("i") => note.tie_start = true,
+      Some("m") => (note.tie_start, note.tie_stop) = (true, true),
+      Some("t") => note.tie_stop = true,
+      _ => {}
+    }
+    let mut articulations = XmlElement::named("articulations");
+    let artic_values = element.attribute("artic").into_iter().chain(
+      element
+        .children_named("artic")
+        .filter_map(|artic| artic.attribute("artic")),
+    );
+    for value in artic_values.flat_map(str::split_whitespace) {
+      if let Some(name) = reverse_lookup(&ARTICULATIONS, value) {
+        articulations = articulations.with_child(XmlElement::named(name));
+      }
+    }
+    if !articulations.elements.is_empty() {
+      note.notations.push(articulations);
+    }
+    let verses = element.children_named("verse").chain(
+      chord
+        .filter(|_| !note.chord)
+        .into_iter()
+        .flat_map(|chord| chord.children_named("verse")),
+    );
+    for (index, verse) in verses.enumerate() {
+      let number = verse
+        .attribute("n")
+        .map_or_else(|| (index + 1).to_string(), String::from);
+      let mut lyric = XmlElement::named("lyric").with_attribute("number", number);
+      let syllables: Vec<&XmlElement> = verse.children_named("syl").collect();
+      for (index, syl) in syllables.iter().enumerate() {
+        let syllabic = match syl.attribute("wordpos") {
+          Some("i") => "begin",
+          Some("m") => "middle",
+          Some("t") => "end",
+          _ => "single",
+        };
+        if index > 0 {
+          lyric = lyric.with_child(XmlElement::named("elision").with_text(" "));
+        }
+        lyric = lyric
+          .with_child(XmlElement::named("syllabic").with_text(syllabic))
+          .with_child(XmlElement::named("text").with_text(text_content(syl)));
+      }
+      if syllables.last().and_then(|syl| syl.attribute("con")) == Some("u") {
+        lyric = lyric.with_child(XmlElement::named("extend"));
+      }
+      if !syllables.is_empty() {
+        note.lyrics.push(lyric);
+      }
+    }
+    note
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn read_layer_element(&mut self, element: &XmlElement, context: &mut LayerContext, items: &mut Vec<LayerItem>) {
+    match element.name.as_ref() {
+      "note" | "rest" | "mRest" => {
+        let note = self.read_note(element, None, context);
+        if note.grace.is_none() {
+          context.position += note.duration;
+        }
+        items.push(LayerItem::Note(note));
+      }
+      "chord" => {
+        let mut duration = Fraction::ZERO;
+        for child in element.children_named("note") {
+          let note = self.read_note(child, Some(element), context);
+          if note.grace.is_none() && !note.chord {
+            duration = note.duration;
+          }
+          items.push(LayerItem::Note(note));
+        }
+        context.position += duration;
+      }
+      "space" => {
+        let note_type = element.attribute("dur").and_then(note_type_from_mei);
+        let dots = element
+          .attribute("dots")
+          .and_then(|dots| dots.parse().ok())
+          .unwrap_or(0);
+        if let Some(note_type) = note_type {
+          let duration = dotted_length(&note_type, dots) * Fraction::new(context.ratio.1, context.ratio.0);
+          context.position += duration;
+          let mut forward = XmlElement::named("forward")
+            .with_child(XmlElement::named("duration").with_text(format_fraction(duration)))
+            .with_child(XmlElement::named("voice").with_text(context.voice.as_str()));
+          if context.multi_staff {
+            forward = forward.with_child(XmlElement::named("staff").with_text(context.staff.to_string()));
+          }
+          items.push(LayerItem::Element(forward));
+        }
+      }
+      "clef" => {
+        if let Some(clef) = clef_from_mei(element, context.multi_staff.then_some(context.staff)) {
+          items.push(LayerItem::Element(XmlElement::named("attributes").with_child(clef)));
+        }
+      }
+      "beam" => {
+        let start = items.len();
+        for child in &element.elements {
+          self.read_layer_element(child, context, items);
+        }
+        let mut heads: Vec<&mut ImportedNote> = items[start..]
+          .iter_mut()
+          .filter_map(|item| match item {
+            LayerItem::Note(note) if !note.chord && note.pitch.is_some() => Some(note),
+            _ => None,
+          })
+          .collect();
+        let count = heads.len();
+        if count > 1 {
+          for (index, note) in heads.iter_mut().enumerate() {
+            note.beam = Some(match index {
+              0 => "begin",
+              index if index + 1 == count => "end",
+              _ => "continue",
+            });
+          }
+        }
+      }
+      "tuplet" => {
+        let num: i64 = element.attribute("num").and_then(|num| num.parse().ok()).unwrap_or(3);
+        let numbase: i64 = element
+          .attribute("numbase")
+          .and_then(|numbase| numbase.parse().ok())
+          .unwrap_or(2);
+        let previous = context.ratio;
+        context.ratio = (previous.0 * num, previous.1 * numbase);
+        let start = items.len();
+        for child in &element.elements {
+          self.read_layer_element(child, context, items);
+        }
+        context.ratio = previous;
+        let mut heads = items[start..].iter_mut().filter_map(|item| match item {
+          LayerItem::Note(note) if !note.chord => Some(note),
+          _ => None,
+        });
+        if let Some(first) = heads.next() {
+          first.tuplet.push("start");
+          match heads.last() {
+            Some(last) => last.tuplet.push("stop"),
+            None => first.tuplet.push("stop"),
+          }
+        }
+      }
+      "graceGrp" => {
+        let previous = context.grace;
+        context.grace = Some(element.attribute("grace") == Some("unacc"));
+        for child in &element.elements {
+          self.read_layer_element(child, context, items);
+        }
+        context.grace = previous;
+      }
+      "mSpace" | "keySig" | "meterSig" | "barLine" | "verse" | "syl" => {}
+      _ => {
+        for child in &element.elements {
+          self.read_layer_element(child, context, items);
+        }
+      }
+    }
+  }
+
+  fn control_event(element: &XmlElement, staff: u32, multi_staff: bool) -> Option<XmlElement<'static>> {
+    let place = element
+      .attribute("place")
+      .filter(|place| *place == "above" || *place == "below");
+    let staff = multi_staff.then_some(staff);
+    let text = text_content(element);
+    match element.name.as_ref() {
+      "dynam" => {
+        let dynamic = if DYNAMICS.contains(&text.trim()) {
+          XmlElement::named(text.trim())
+        } else {
+          XmlElement::named("other-dynamics").with_text(text.trim())
+        };
+        Some(direction_xml(
+          XmlElement::named("dynamics").with_child(dynamic),
+          place,
+          staff,
+        ))
+      }
+      "dir" => Some(direction_xml(XmlElement::named("words").with_text(text), place, staff)),
+      "reh" => Some(direction_xml(
+        XmlElement::named("rehearsal").with_text(text),
+        place,
+        staff,
+      )),
+      "tempo" => {
+        let mut direction = XmlElement::named("direction");
+        if let Some(place) = place {
+          direction = direction.with_attribute("placement", place);
+        }
+        if !text.is_empty() {
+          direction = direction
+            .with_child(XmlElement::named("direction-type").with_child(XmlElement::named("words").with_text(text)));
+        }
+        if let (Some(mm), Some(unit)) = (
+          element.attribute("mm"),
+          element.attribute("mm.unit").and_then(note_type_from_mei),
+        ) {
+          let mut metronome = XmlElement::named("metronome")
+            .with_child(XmlElement::named("beat-unit").with_text(NoteTypeValue::serialize(&unit)));
+          for _ in 0..element
+            .attribute("mm.dots")
+            .and_then(|dots| dots.parse::<u8>().ok())
+            .unwrap_or(0)
+          {
+            metronome = metronome.with_child(XmlElement::named("beat-unit-dot"));
+          }
+          metronome = metronome.with_child(XmlElement::named("per-minute").with_text(mm));
+          direction = direction.with_child(XmlElement::named("direction-type").with_child(metronome));
+        }
+        if direction.elements.is_empty() {
+          return None;
+        }
+        if let Some(staff) = staff {
+          direction = direction.with_child(XmlElement::named("staff").with_text(staff.to_string()));
+        }
+        if let Some(bpm) = element.attribute("midi.bpm") {
+          direction = direction.with_child(XmlElement::named("sound").with_attribute("tempo", bpm));
+        }
+        Some(direction)
+      }
+      "harm" => {
+        let mut harmony = chord_symbol_to_xml(&text)?;
+        if let Some(place) = place {
+          harmony = harmony.with_attribute("placement", place);
+        }
+        if let Some(staff) = staff {
+          harmony = harmony.with_child(XmlElement::named("staff").with_text(staff.to_string()));
+        }
+        Some(harmony)
+      }
+      "hairpin" => {
+        let form = if element.attribute("form") == Some("dim") {
+          "diminuendo"
+        } else {
+          "crescendo"
+        };
+        Some(direction_xml(
+          XmlElement::named("wedge").with_attribute("type", form),
+          place,
+          staff,
+        ))
+      }
+      _ => None,
+    }
+  }
+
+  fn read_control_events(&mut self, measure: &XmlElement) {
+    let index = self.measure_count - 1;
+    for element in &measure.elements {
+      if matches!(element.name.as_ref(), "staff" | "tie" | "slur" | "fermata") {
+        continue;
+      }
+      let start = element
+        .attribute("startid")
+        .and_then(|id| self.note_positions.get(id.trim_start_matches('#')))
+        .copied();
+      let staff = element
+        .attribute("staff")
+        .and_then(|staff| staff.split_whitespace().next())
+        .and_then(|staff| staff.parse::<u32>().ok())
+        .or(start.map(|(staff, _)| staff))
+        .unwrap_or(1);
+      let Some(info) = self.staves.get(&staff) else {
+        continue;
+      };
+      let multi_staff = self.parts[info.part].staves > 1;
+      let local = info.local;
+      let position = start.map(|(_, position)| position).or_else(|| {
+        element
+          .attribute("tstamp")
+          .and_then(|tstamp| position_from_tstamp(tstamp, self.meter_unit()))
+      });
+      let Some(event) = Self::control_event(element, local, multi_staff) else {
+        continue;
+      };
+      self.pending.push(PlacedEvent {
+        measure: index,
+        staff,
+        position: position.unwrap_or(Fraction::ZERO),
+        element: event,
+      });
+      if element.name == "hairpin" {
+        if let Some((measures, beat)) = element.attribute("tstamp2").and_then(|tstamp| tstamp.split_once("m+")) {
+          let target = index + measures.trim().parse::<usize>().unwrap_or(0);
+          let position = position_from_tstamp(beat, self.meter_unit()).unwrap_or_default();
+          self.pending.push(PlacedEvent {
+            measure: target,
+            staff,
+            position,
+            element: direction_xml(
+              XmlElement::named("wedge").with_attribute("type", "stop"),
+              None,
+              multi_staff.then_some(local),
+            ),
+          });
+        }
+      }
+    }
+  }
+
+  fn move_cursor(part: &mut ImportedPart, position: Fraction) {
+    let Some(measure) = part.measures.last_mut() else {
+      return;
+    };
+    if position < part.cursor {
+      measure.elements.push(
+        XmlElement::named("backup")
+          .with_child(XmlElement::named("duration").with_text(format_fraction(part.cursor - position))),
+      );
+    } else if position > part.cursor {
+      measure.elements.push(
+        XmlElement::named("forward")
+          .with_child(XmlElement::named("duration").with_text(format_fraction(position - part.cursor))),
+      );
+    }
+    part.cursor = position;
+  }
+
+  fn initial_attributes(&self, part: usize) -> XmlElement<'static> {
+    let mut attributes = XmlElement::named("attributes").with_child(XmlElement::named("divisions").with_text("1"));
+    let staves: Vec<(&u32, &ImportedStaff)> = self.staves.iter().filter(|(_, staff)| staff.part == part).collect();
+    if let Some(key) = staves
+      .first()
+      .and_then(|(_, staff)| staff.key.as_ref())
+      .or(self.key.as_ref())
+    {
+      attributes = attributes.with_child(key_xml(key));
+    }
+    if let Some(meter) = &self.meter {
+      attributes = attributes.with_child(time_xml(meter));
+    }
+    let multi_staff = staves.len() > 1;
+    if multi_staff {
+      attributes = attributes.with_child(XmlElement::named("staves").with_text(staves.len().to_string()));
+    }
+    for (_, staff) in &staves {
+      if let Some(mut clef) = staff.clef.clone() {
+        if multi_staff {
+          set_attribute(&mut clef, "number", &staff.local.to_string());
+        }
+        attributes = attributes.with_child(clef);
+      }
+    }
+    for (_, staff) in &staves {
+      if let Some(lines) = staff.lines.as_deref().filter(|lines| *lines != "5") {
+        let mut details = XmlElement::named("staff-details");
+        if multi_staff {
+          details = details.with_attribute("number", staff.local.to_string());
+        }
+        attributes = attributes.with_child(details.with_child(XmlElement::named("staff-lines").with_text(lines)));
+      }
+    }
+    if let Some((chromatic, diatonic)) = staves.first().and_then(|(_, staff)| staff.transpose.as_ref()) {
+      let mut transpose = XmlElement::named("transpose");
+      if let Some(diatonic) = diatonic {
+        transpose = transpose.with_child(XmlElement::named("diatonic").with_text(diatonic.as_str()));
+      }
+      attributes =
+        attributes.with_child(transpose.with_child(XmlElement::named("chromatic").with_text(chromatic.as_str())));
+    }
+    attributes
+  }
+
+  /// Shortens the measure rests of an incomplete measure to the length of its longest layer of notes, since an
+  /// `<mRest>` otherwise fills the whole meter.
+  fn fit_measure_rests(layers: &mut [(usize, Vec<LayerItem>, Fraction)]) {
+    let is_measure_rest = |item: &LayerItem| matches!(item, LayerItem::Note(note) if note.measure_rest);
+    let Some(longest) = layers
+      .iter()
+      .filter(|(_, items, _)| {
+        items
+          .iter()
+          .any(|item| matches!(item, LayerItem::Note(_)) && !is_measure_rest(item))
+      })
+      .map(|(_, _, position)| *position)
+      .max()
+    else {
+      return;
+    };
+    for (_, items, position) in layers.iter_mut() {
+      for item in items.iter_mut() {
+        if let LayerItem::Note(note) = item {
+          if note.measure_rest {
+            *position = *position - note.duration + longest;
+            note.duration = longest;
+          }
+        }
+      }
+    }
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn read_measure(&mut self, measure: &XmlElement) {
+    let index = self.measure_count;
+    self.measure_count += 1;
+    self.accidentals.clear();
+    let number = measure
+      .attribute("n")
+      .map_or_else(|| (index + 1).to_string(), String::from);
+    let changes = self.changes.take();
+    if let Some(changes) = &changes {
+      if let Some(key) = key_from_mei(changes) {
+        self.key = Some(key);
+        self.staves.values_mut().for_each(|staff| staff.key = None);
+      }
+      if let Some(meter) = meter_from_mei(changes) {
+        self.meter = Some(meter);
+      }
+    }
+    let repeat_start = core::mem::take(&mut self.repeat_start);
+    let ending_start = self.ending_start.take();
+    for part in 0..self.parts.len() {
+      let mut element = XmlElement::named("measure").with_attribute("number", number.as_str());
+      if let Some(id) = measure.attribute("xml:id") {
+        element = element.with_attribute("id", id);
+      }
+      if measure.attribute("metcon") == Some("false") {
+        element = element.with_attribute("implicit", "yes");
+      }
+      if index == 0 {
+        element = element.with_child(self.initial_attributes(part));
+      } else if let Some(changes) = &changes {
+        let mut attributes = XmlElement::named("attributes");
+        if let Some(key) = key_from_mei(changes) {
+          attributes = attributes.with_child(key_xml(&key));
+        }
+        if let Some(meter) = meter_from_mei(changes) {
+          attributes = attributes.with_child(time_xml(&meter));
+        }
+        if !attributes.elements.is_empty() {
+          element = element.with_child(attributes);
+        }
+      }
+      let left = measure.attribute("left");
+      if repeat_start
+        || ending_start.is_some()
+        || left.is_some_and(|left| reverse_lookup(&BAR_STYLES, left).is_some() || left == "rptstart")
+      {
+        let mut barline = XmlElement::named("barline").with_attribute("location", "left");
+        let forward = repeat_start || left == Some("rptstart");
+        if forward {
+          barline = barline.with_child(XmlElement::named("bar-style").with_text("heavy-light"));
+        } else if let Some(style) = left.and_then(|left| reverse_lookup(&BAR_STYLES, left)) {
+          barline = barline.with_child(XmlElement::named("bar-style").with_text(style));
+        }
+        if let Some((number, label)) = &ending_start {
+          barline = barline.with_child(
+            XmlElement::named("ending")
+              .with_attribute("number", number.as_str())
+              .with_attribute("type", "start")
+              .with_text(label.as_str()),
+          );
+        }
+        if forward {
+          barline = barline.with_child(XmlElement::named("repeat").with_attribute("direction", "forward"));
+        }
+        element = element.with_child(barline);
+      }
+      self.parts[part].measures.push(element);
+      self.parts[part].cursor = Fraction::ZERO;
+    }
+    let mut layers = Vec::new();
+    for staff in measure.children_named("staff") {
+      let Some(global_staff) = staff.attribute("n").and_then(|n| n.parse::<u32>().ok()) else {
+        continue;
+      };
+      let Some(info) = self.staves.get(&global_staff) else {
+        continue;
+      };
+      let (part, local) = (info.part, info.local);
+      let multi_staff = self.parts[part].staves > 1;
+      for (layer_index, layer) in staff.children_named("layer").enumerate() {
+        let layer_number = layer
+          .attribute("n")
+          .and_then(|n| n.parse::<u32>().ok())
+          .or_else(|| u32::try_from(layer_index + 1).ok())
+          .unwrap_or(1);
+        let voice = if multi_staff {
+          (local - 1) * 4 + layer_number
+        } else {
+          layer_number
+        };
+        let mut context = LayerContext {
+          position: Fraction::ZERO,
+          ratio: (1, 1),
+          grace: None,
+          voice: voice.to_string(),
+          staff: local,
+          global_staff,
+          multi_staff,
+        };
+        let mut items = Vec::new();
+        for child in &layer.elements {
+          self.read_layer_element(child, &mut context, &mut items);
+        }
+        if !items.is_empty() {
+          layers.push((part, items, context.position));
+        }
+      }
+    }
+    if measure.attribute("metcon") == Some("false") {
+      Self::fit_measure_rests(&mut layers);
+    }
+    for (part, items, position) in layers {
+      Self::move_cursor(&mut self.parts[part], Fraction::ZERO);
+      if let Some(element) = self.parts[part].measures.last_mut() {
+        element.elements.extend(items.into_iter().map(|item| match item {
+          LayerItem::Note(note) => note.to_xml(),
+          LayerItem::Element(element) => element,
+        }));
+      }
+      self.parts[part].cursor = position;
+    }
+    self.read_control_events(measure);
+    let (mut events, pending): (Vec<_>, Vec<_>) = core::mem::take(&mut self.pending)
+      .into_iter()
+      .partition(|event| event.measure == index);
+    self.pending = pending;
+    events.sort_by_key(|event| event.position);
+    for event in events {
+      if let Some(part) = self.staves.get(&event.staff).map(|staff| staff.part) {
+        Self::move_cursor(&mut self.parts[part], event.position);
+        if let Some(measure) = self.parts[part].measures.last_mut() {
+          measure.elements.push(event.element);
+        }
+      }
+    }
+    let right = measure.attribute("right");
+    for part in &mut self.parts {
+      let Some(element) = part.measures.last_mut() else {
+        continue;
+      };
+      if matches!(element.elements.last(), Some(last) if last.name == "backup" || last.name == "forward") {
+        element.elements.pop();
+      }
+      let style = match right {
+        Some("rptend" | "rptboth") => Some("light-heavy"),
+        Some(right) => reverse_lookup(&BAR_STYLES, right),
+        None => None,
+      };
+      if let Some(style) = style {
+        let mut barline = XmlElement::named("barline")
+          .with_attribute("location", "right")
+          .with_child(XmlElement::named("bar-style").with_text(style));
+        if matches!(right, Some("rptend" | "rptboth")) {
+          barline = barline.with_child(XmlElement::named("repeat").with_attribute("direction", "backward"));
+        }
+        element.elements.push(barline);
+      }
+    }
+    self.repeat_start = right == Some("rptboth");
+  }
+
+  fn close_ending(&mut self, number: &str) {
+    for part in &mut self.parts {
+      let Some(measure) = part.measures.last_mut() else {
+        continue;
+      };
+      let existing = measure
+        .elements
+        .iter()
+        .rposition(|element| element.name == "barline" && element.attribute("location") == Some("right"));
+      let barline_index = if let Some(index) = existing {
+        index
+      } else {
+        measure
+          .elements
+          .push(XmlElement::named("barline").with_attribute("location", "right"));
+        measure.elements.len() - 1
+      };
+      let barline = &mut measure.elements[barline_index];
+      let repeated = barline.child("repeat").is_some();
+      let ending = XmlElement::named("ending")
+        .with_attribute("number", number)
+        .with_attribute("type", if repeated { "stop" } else { "discontinue" });
+      match barline.elements.iter().position(|element| element.name == "repeat") {
+        Some(position) => barline.elements.insert(position, ending),
+        None => barline.elements.push(ending),
+      }
+    }
+  }
+
+  fn read_section(&mut self, section: &XmlElement) {
+    for child in &section.elements {
+      match child.name.as_ref() {
+        "section" => self.read_section(child),
+        "ending" => {
+          let number = child.attribute("n").map_or_else(|| String::from("1"), String::from);
+          self.ending_start = Some((
+            number.clone(),
+            child.attribute("label").map(String::from).unwrap_or_default(),
+          ));
+          let before = self.measure_count;
+          self.read_section(child);
+          if self.measure_count > before {
+            self.close_ending(&number);
+          } else {
+            self.ending_start = None;
+          }
+        }
+        "measure" => self.read_measure(child),
+        "scoreDef" => self.changes = Some(child.clone().into_owned()),
+        _ => {}
+      }
+    }
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn read_header(header: Option<&XmlElement>, mdiv: Option<&XmlElement>) -> Vec<XmlElement<'static>> {
+    let mut elements = Vec::new();
+    let title_stmt = header
+      .and_then(|header| header.child("fileDesc"))
+      .and_then(|desc| desc.child("titleStmt"));
+    let main_title = title_stmt
+      .and_then(|stmt| {
+        stmt
+          .children_named("title")
+          .find(|title| title.attribute("type").is_none_or(|kind| kind == "main"))
+      })
+      .map(text_content)
+      .filter(|title| !title.is_empty());
+    let movement_title = mdiv
+      .and_then(|mdiv| mdiv.attribute("label").map(String::from))
+      .or_else(|| {
+        title_stmt
+          .and_then(|stmt| {
+            stmt
+              .children_named("title")
+              .find(|title| title.attribute("type") == Some("subordinate"))
+          })
+          .map(text_content)
+      });
+    let work = header
+      .and_then(|header| header.child("workList"))
+      .and_then(|list| list.child("work"));
+    let work_title = work
+      .and_then(|work| work.child("title"))
+      .map(text_content)
+      .or(main_title.filter(|title| Some(title) != movement_title.as_ref()));
+    let work_number = work.and_then(|work| work.child("identifier")).map(text_content);
+    if work_title.is_some() || work_number.is_some() {
+      let mut work = XmlElement::named("work");
+      if let Some(number) = work_number {
+        work = work.with_child(XmlElement::named("work-number").with_text(number));
+      }
+      if let Some(title) = work_title {
+        work = work.with_child(XmlElement::named("work-title").with_text(title));
+      }
+      elements.push(work);
+    }
+    if let Some(number) = mdiv.and_then(|mdiv| mdiv.attribute("n")) {
+      elements.push(XmlElement::named("movement-number").with_text(number));
+    }
+    if let Some(title) = movement_title {
+      elements.push(XmlElement::named("movement-title").with_text(title));
+    }
+    let mut identification = XmlElement::named("identification");
+    if let Some(stmt) = title_stmt {
+      for child in &stmt.elements {
+        match child.name.as_ref() {
+          "composer" | "lyricist" | "arranger" => identification.elements.push(
+            XmlElement::named("creator")
+              .with_attribute("type", child.name.as_ref())
+              .with_text(text_content(child)),
+          ),
+          "respStmt" => {
+            for person in child
+              .elements
+              .iter()
+              .filter(|person| person.name == "persName" || person.name == "corpName")
+            {
+              let mut creator = XmlElement::named("creator").with_text(text_content(person));
+              if let Some(role) = person.attribute("role") {
+                creator = creator.with_attribute("type", role);
+              }
+              identification.elements.push(creator);
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+    if let Some(availability) = header
+      .and_then(|header| header.child("fileDesc"))
+      .and_then(|desc| desc.child("pubStmt"))
+      .and_then(|stmt| stmt.child("availability"))
+    {
+      for restriction in availability.children_named("useRestrict") {
+        identification
+          .elements
+          .push(XmlElement::named("rights").with_text(text_content(restriction)));
+      }
+    }
+    if let Some(app_info) = header
+      .and_then(|header| header.child("encodingDesc"))
+      .and_then(|desc| desc.child("appInfo"))
+    {
+      let mut encoding = XmlElement::named("encoding");
+      for application in app_info.children_named("application") {
+        if let Some(name) = application.child("name") {
+          encoding = encoding.with_child(XmlElement::named("software").with_text(text_content(name)));
+        }
+      }
+      if !encoding.elements.is_empty() {
+        identification.elements.push(encoding);
+      }
+    }
+    if !identification.elements.is_empty() {
+      elements.push(identification);
+    }
+    elements
+  }
+}
+
+/// Converts an MEI document into a [ScorePartwise].
+///
+/// Each `<staffDef>` becomes a [ScorePart], except that a labelled `<staffGrp>` containing only unlabelled staves is
+/// treated as a single multi-staff part (such as a piano). Every `<layer>` becomes a voice, and supported control
+/// events are attached to the notes they reference by `startid` or placed according to their `tstamp`.
+///
+/// # Errors
+///
+/// Returns an error if the document is not well-formed XML, does not contain a `<score>` with a `<scoreDef>`, or
+/// cannot be represented as a valid MusicXML score.
+pub fn mei_to_score_partwise(mei: &str) -> Result<ScorePartwise, String> {
+  let mut root = xml_parser::parse_from_string(mei)?;
+  xml_unescape_tree(&mut root);
+  let mdiv = root.descendant("mdiv");
+  let score = mdiv
+    .and_then(|mdiv| mdiv.descendant("score"))
+    .or_else(|| root.descendant("score"))
+    .ok_or_else(|| String::from("MEI document does not contain a <score> element"))?;
+  let score_def = score
+    .child("scoreDef")
+    .ok_or_else(|| String::from("MEI <score> element does not contain a <scoreDef>"))?;
+  let mut importer = MeiImporter::default();
+  importer.read_score_def(score_def);
+  if importer.parts.is_empty() {
+    return Err(String::from("MEI <scoreDef> does not define any staves"));
+  }
+  importer.collect_marks(score, &mut 0);
+  importer.read_section(score);
+  let mut xml = XmlElement::named("score-partwise").with_attribute("version", "4.0");
+  xml.elements = MeiImporter::read_header(root.child("meiHead"), mdiv);
+  let mut part_list = XmlElement::named("part-list");
+  part_list.elements = core::mem::take(&mut importer.part_list);
+  xml.elements.push(part_list);
+  for part in importer.parts {
+    let mut element = XmlElement::named("part").with_attribute("id", part.id);
+    element.elements = part.measures;
+    xml.elements.push(element);
+  }
+  resolve_fractional_durations(&mut xml);
+  ScorePartwise::deserialize(&xml)
+}
+
+#[cfg(test)]
+mod mei_tests {
+  use super::*;
+  use crate::elements::{AudibleType, PartElement};
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_audible, note_ties};
+
+  const SCORE: &str = "<score-partwise version=\"4.0\">
+    <work><work-title>Test Song</work-title></work>
+    <identification>
+      <creator type=\"composer\">Jane Doe</creator>
+      <rights>Public Domain</rights>
+      <encoding><software>Test Suite</software></encoding>
+    </identification>
+    <part-list>
+      <part-group type=\"start\" number=\"1\"><group-symbol>bracket</group-symbol></part-group>
+      <score-part id=\"P1\"><part-name>Voice</part-name></score-part>
+      <score-part id=\"P2\"><part-name>Piano</part-name></score-part>
+      <part-group type=\"stop\" number=\"1\"/>
+    </part-list>
+    <part id=\"P1\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>2</divisions>
+          <key><fifths>1</fifths><mode>major</mode></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <clef><sign>G</sign><line>2</line></clef>
+        </attributes>
+        <direction placement=\"below\"><direction-type><dynamics><mf/></dynamics></direction-type></direction>
+        <harmony><root><root-step>G</root-step></root><kind text=\"\">major</kind></harmony>
+        <note id=\"n1\">
+          <pitch><step>F</step><alter>1</alter><octave>4</octave></pitch>
+          <duration>4</duration><tie type=\"start\"/><voice>1</voice><type>half</type>
+          <notations><tied type=\"start\"/></notations>
+          <lyric number=\"1\"><syllabic>begin</syllabic><text>Hel</text></lyric>
+        </note>
+        <note>
+          <pitch><step>G</step><octave>4</octave></pitch>
+          <duration>1</duration><voice>1</voice><type>eighth</type><beam number=\"1\">begin</beam>
+          <lyric number=\"1\"><syllabic>end</syllabic><text>lo</text></lyric>
+        </note>
+        <note>
+          <pitch><step>A</step><octave>4</octave></pitch>
+          <duration>1</duration><voice>1</voice><type>eighth</type><beam number=\"1\">end</beam>
+        </note>
+      </measure>
+      <measure number=\"2\">
+        <note id=\"n2\">
+          <pitch><step>F</step><alter>1</alter><octave>4</octave></pitch>
+          <duration>6</duration><tie type=\"stop\"/><voice>1</voice><type>half</type><dot/>
+          <notations><tied type=\"stop\"/></notations>
+        </note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+    <part id=\"P2\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>1</divisions>
+          <key><fifths>1</fifths><mode>major</mode></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <staves>2</staves>
+          <clef number=\"1\"><sign>G</sign><line>2</line></clef>
+          <clef number=\"2\"><sign>F</sign><line>4</line></clef>
+        </attributes>
+        <note>
+          <pitch><step>G</step><octave>4</octave></pitch>
+          <duration>3</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+        <note>
+          <chord/><pitch><step>B</step><octave>4</octave></pitch>
+          <duration>3</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+        <backup><duration>3</duration></backup>
+        <note>
+          <pitch><step>G</step><octave>2</octave></pitch>
+          <duration>3</duration><voice>5</voice><type>half</type><dot/><staff>2</staff>
+        </note>
+      </measure>
+      <measure number=\"2\">
+        <note>
+          <rest measure=\"yes\"/><duration>3</duration><voice>1</voice><staff>1</staff>
+        </note>
+        <backup><duration>3</duration></backup>
+        <note>
+          <rest measure=\"yes\"/><duration>3</duration><voice>5</voice><staff>2</staff>
+        </note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+  </score-partwise>";
+
+  fn part_notes(score: &ScorePartwise, part: usize) -> Vec<&Note> {
+    score.content.part[part]
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        PartElement::Measure(measure) => Some(measure),
+        _ => None,
+      })
+      .flat_map(|measure| measure.content.iter())
+      .filter_map(|element| match element {
+        MeasureElement::Note(note) => Some(note),
+        _ => None,
+      })
+      .collect()
+  }
+
+  fn part_length(score: &ScorePartwise, part: usize) -> Fraction {
+    let mut timeline = PartTimeline::default();
+    part_measures(&score.content.part[part])
+      .map(|measure| {
+        timeline
+          .measure(measure)
+          .events
+          .iter()
+          .map(|event| event.position + event.duration)
+          .max()
+          .unwrap_or(Fraction::ZERO)
+      })
+      .fold(Fraction::ZERO, |total, length| total + length)
+  }
+
+  #[test]
+  fn export_structure() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let mei = score_partwise_to_mei(&score).unwrap();
+    assert!(mei.contains("<mei xmlns=\"http://www.music-encoding.org/ns/mei\" meiversion=\"5.0\">"));
+    assert!(mei.contains("<composer>"));
+    assert!(mei.contains("<useRestrict>Public Domain</useRestrict>"));
+    assert!(mei.contains("<keySig sig=\"1s\" mode=\"major\"/>"));
+    assert!(mei.contains("<meterSig count=\"3\" unit=\"4\"/>"));
+    assert!(mei.contains("<staffGrp symbol=\"bracket\">"));
+    assert!(mei.contains("<staffGrp symbol=\"brace\" bar.thru=\"true\">"));
+    assert!(mei.contains("<note xml:id=\"n1\" dur=\"2\" pname=\"f\" oct=\"4\" accid.ges=\"s\">"));
+    assert!(mei.contains("<tie staff=\"1\" startid=\"#n1\" endid=\"#n2\"/>"));
+    assert!(mei.contains("<syl wordpos=\"i\" con=\"d\">Hel</syl>"));
+    assert!(mei.contains("<dynam staff=\"1\" tstamp=\"1\" place=\"below\">mf</dynam>"));
+    assert!(mei.contains("<harm staff=\"1\" tstamp=\"1\">G</harm>"));
+    assert!(mei.contains("<beam>"));
+    assert!(mei.contains("<chord "));
+    assert!(mei.contains("<mRest "));
+    assert!(mei.contains("right=\"end\""));
+  }
+
+  #[test]
+  fn round_trip() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let imported = mei_to_score_partwise(&score_partwise_to_mei(&score).unwrap()).unwrap();
+    assert_eq!(imported.content.part.len(), 2);
+    assert_eq!(
+      imported
+        .content
+        .work
+        .as_ref()
+        .unwrap()
+        .content
+        .work_title
+        .as_ref()
+        .unwrap()
+        .content,
+      "Test Song"
+    );
+    for part in 0..2 {
+      let (original, converted) = (part_notes(&score, part), part_notes(&imported, part));
+      assert_eq!(original.len(), converted.len());
+      for (original, converted) in original.iter().zip(converted.iter()) {
+        assert_eq!(note_audible(original), note_audible(converted));
+        assert_eq!(original.content.r#type, converted.content.r#type);
+        assert_eq!(original.content.dot.len(), converted.content.dot.len());
+        assert_eq!(note_ties(original), note_ties(converted));
+        assert_eq!(original.content.lyric.len(), converted.content.lyric.len());
+        assert_eq!(original.content.staff, converted.content.staff);
+      }
+    }
+    let mei = score_partwise_to_mei(&imported).unwrap();
+    assert!(mei.contains("<tie staff=\"1\" startid=\"#n1\" endid=\"#n2\"/>"));
+    assert!(mei.contains("<dynam staff=\"1\" tstamp=\"1\" place=\"below\">mf</dynam>"));
+    assert!(mei.contains("<harm staff=\"1\" tstamp=\"1\">G</harm>"));
+  }
+
+  #[test]
+  fn import_mei() {
+    let mei = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+      <mei xmlns=\"http://www.music-encoding.org/ns/mei\" meiversion=\"5.0\">
+        <meiHead><fileDesc><titleStmt><title>Tuplets &amp; Chords</title></titleStmt><pubStmt/></fileDesc></meiHead>
+        <music><body><mdiv><score>
+          <scoreDef meter.count=\"2\" meter.unit=\"4\" key.sig=\"2f\">
+            <staffGrp><staffDef n=\"1\" lines=\"5\" clef.shape=\"F\" clef.line=\"4\" label=\"Cello\"/></staffGrp>
+          </scoreDef>
+          <section>
+            <measure n=\"1\">
+              <staff n=\"1\"><layer n=\"1\">
+                <tuplet num=\"3\" numbase=\"2\">
+                  <beam><note xml:id=\"a\" dur=\"8\" pname=\"b\" oct=\"3\"/><note dur=\"8\" pname=\"c\" oct=\"4\"/><note dur=\"8\" pname=\"e\" oct=\"4\" accid=\"n\"/></beam>
+                </tuplet>
+                <chord dur=\"4\"><note pname=\"e\" oct=\"3\"/><note pname=\"g\" oct=\"3\"/></chord>
+              </layer></staff>
+              <slur staff=\"1\" startid=\"#a\" endid=\"#b\"/>
+            </measure>
+            <measure n=\"2\" right=\"end\">
+              <staff n=\"1\"><layer n=\"1\"><note xml:id=\"b\" dur=\"2\" pname=\"b\" oct=\"3\"/></layer></staff>
+              <dir staff=\"1\" tstamp=\"2\">rit.</dir>
+            </measure>
+          </section>
+        </score></mdiv></body></music>
+      </mei>";
+    let score = mei_to_score_partwise(mei).unwrap();
+    assert_eq!(
+      score
+        .content
+        .work
+        .as_ref()
+        .unwrap()
+        .content
+        .work_title
+        .as_ref()
+        .unwrap()
+        .content,
+      "Tuplets & Chords"
+    );
+    let notes = part_notes(&score, 0);
+    assert_eq!(notes.len(), 6);
+    let alters: Vec<i32> = notes
+      .iter()
+      .map(|note| match note_audible(note) {
+        AudibleType::Pitch(pitch) => pitch_alter(pitch),
+        _ => 99,
+      })
+      .collect();
+    assert_eq!(alters, vec![-1, 0, 0, -1, 0, -1]);
+    assert_eq!(note_time_modification(notes[0]), (3, 2));
+    assert!(crate::util::note_is_chord(notes[4]));
+    assert_eq!(
+      crate::util::note_duration(notes[0]) * 3,
+      crate::util::note_duration(notes[3])
+    );
+    assert_eq!(notes[0].content.beam.len(), 1);
+    assert_eq!(notes[0].content.notations.len(), 1);
+    assert_eq!(notes[5].content.notations.len(), 1);
+  }
+
+  #[test]
+  fn invalid_mei() {
+    assert!(mei_to_score_partwise("<mei><music/></mei>").is_err());
+    assert!(mei_to_score_partwise("<mei><music><body><mdiv><score/></mdiv></body></music></mei>").is_err());
+  }
+
+  #[test]
+  fn round_trip_file() {
+    let score = crate::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    let imported = mei_to_score_partwise(&score_partwise_to_mei(&score).unwrap()).unwrap();
+    assert_eq!(score.content.part.len(), imported.content.part.len());
+    for part in 0..score.content.part.len() {
+      let (original, converted) = (part_notes(&score, part), part_notes(&imported, part));
+      assert_eq!(original.len(), converted.len());
+      for (original, converted) in original.iter().zip(converted.iter()) {
+        assert_eq!(note_pitch(original), note_pitch(converted));
+        assert_eq!(original.content.lyric.len(), converted.content.lyric.len());
+      }
+      assert_eq!(part_length(&score, part), part_length(&imported, part));
+    }
+  }
+
+  #[test]
+  fn round_trip_pickup() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      "<score-partwise version=\"4.0\">
+        <part-list>
+          <score-part id=\"P1\"><part-name>Voice</part-name></score-part>
+          <score-part id=\"P2\"><part-name>Violin</part-name></score-part>
+        </part-list>
+        <part id=\"P1\">
+          <measure number=\"0\" implicit=\"yes\">
+            <attributes><divisions>1</divisions><time><beats>3</beats><beat-type>4</beat-type></time></attributes>
+            <note><rest measure=\"yes\"/><duration>1</duration><voice>1</voice></note>
+          </measure>
+          <measure number=\"1\">
+            <note>
+              <pitch><step>C</step><octave>5</octave></pitch><duration>3</duration><voice>1</voice>
+              <type>half</type><dot/>
+            </note>
+          </measure>
+        </part>
+        <part id=\"P2\">
+          <measure number=\"0\" implicit=\"yes\">
+            <attributes><divisions>1</divisions><time><beats>3</beats><beat-type>4</beat-type></time></attributes>
+            <note>
+              <pitch><step>G</step><octave>4</octave></pitch><duration>1</duration><voice>1</voice><type>quarter</type>
+            </note>
+          </measure>
+          <measure number=\"1\">
+            <note><rest measure=\"yes\"/><duration>3</duration><voice>1</voice></note>
+          </measure>
+        </part>
+      </score-partwise>",
+    )
+    .unwrap();
+    let mei = score_partwise_to_mei(&score).unwrap();
+    assert!(mei.contains("<measure n=\"0\" metcon=\"false\">"));
+    assert!(mei.contains("<rest xml:id=\"rest1\" dur=\"4\"/>"));
+    assert!(mei.contains("<mRest "));
+    let imported = mei_to_score_partwise(&mei).unwrap();
+    for part in 0..2 {
+      assert_eq!(part_length(&imported, part), Fraction::from_int(4));
+    }
+    let imported = mei_to_score_partwise(&mei.replace("<rest xml:id=\"rest1\" dur=\"4\"/>", "<mRest/>")).unwrap();
+    for part in 0..2 {
+      assert_eq!(part_length(&imported, part), Fraction::from_int(4));
+    }
+  }
+}
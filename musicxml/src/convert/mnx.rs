@@ -0,0 +1,2022 @@
+//! Converts between [ScorePartwise][crate::elements::ScorePartwise] scores and MNX documents in their JSON
+//! representation.
+//!
+//! The exporter writes the time signatures, key signatures, barlines, and repeats shared by all parts into the MNX
+//! `global` measures, and writes every part's notes into `sequences` of events, one sequence per MusicXML voice.
+//! Note durations are derived from each note's [Type][crate::elements::Type] and [Dot][crate::elements::Dot]
+//! elements, tuplets are written as nested `tuplet` containers, and beams, ties, and slurs reference events and notes
+//! by their `id`. MusicXML positions, which are measured in `<divisions>`, are written as rational fractions of a
+//! whole note.
+//!
+//! The importer understands the same subset of MNX and rebuilds a partwise score from it, computing the smallest
+//! `<divisions>` value able to represent every event exactly.
+//!
+//! Playback tempos given by `<sound tempo>` are written as global MNX `tempos` in quarter notes per minute, and are
+//! imported into the first part as metronome marks carrying the same playback tempo. Any text shown with a tempo, such
+//! as "Allegretto", is not converted. MNX has no fields for score metadata, so the [Work][crate::elements::Work],
+//! [MovementTitle][crate::elements::MovementTitle], and [Identification][crate::elements::Identification] elements
+//! of a score, including its work title and its composer and lyricist creators, are not exported and are absent from
+//! imported scores.
+
+use crate::datatypes::{
+  AboveBelow, BackwardForward, BarStyle, BeamValue, ClefSign, NonNegativeDecimal, NoteTypeValue, RightLeftMiddle,
+  StartStopContinue, StemValue, Syllabic, YesNo,
+};
+use crate::elements::{
+  Articulations, AudibleType, Clef, KeyContents, LyricContents, Measure, MeasureElement, NotationContentTypes, Note,
+  NoteType, Part, PartListElement, ScorePart, ScorePartwise, Time,
+};
+use crate::parser::json_parser::{self, JsonValue};
+use crate::util::{
+  dotted_length, format_fraction, gcd, length_to_note_type, note_audible, note_dots, note_is_grace, note_staff,
+  note_tied, note_time_modification, note_type_length, note_voice, part_measures, part_staves, pitch_alter,
+  resolve_fractional_durations, split_length, step_letter, Fraction, MeasureEvent, PartTimeline, XmlElementExt,
+  NOTE_TYPES,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer, ElementDeserializer, ElementSerializer, XmlElement};
+
+const MNX_VERSION: i64 = 1;
+
+/// MusicXML bar styles and their MNX barline types.
+const BAR_STYLES: [(&str, &str); 11] = [
+  ("regular", "regular"),
+  ("dotted", "dotted"),
+  ("dashed", "dashed"),
+  ("heavy", "heavy"),
+  ("light-light", "double"),
+  ("light-heavy", "final"),
+  ("heavy-light", "heavyLight"),
+  ("heavy-heavy", "heavyHeavy"),
+  ("tick", "tick"),
+  ("short", "short"),
+  ("none", "noBarline"),
+];
+
+/// MusicXML articulation element names and their MNX marking names.
+const MARKINGS: [(&str, &str); 10] = [
+  ("accent", "accent"),
+  ("strong-accent", "strongAccent"),
+  ("staccato", "staccato"),
+  ("tenuto", "tenuto"),
+  ("staccatissimo", "staccatissimo"),
+  ("spiccato", "spiccato"),
+  ("stress", "stress"),
+  ("unstress", "unstress"),
+  ("soft-accent", "softAccent"),
+  ("breath-mark", "breath"),
+];
+
+/// MusicXML syllabic values and their MNX lyric syllable types.
+const SYLLABLES: [(&str, &str); 4] = [
+  ("begin", "start"),
+  ("middle", "middle"),
+  ("end", "end"),
+  ("single", "whole"),
+];
+
+/// MusicXML accidental names for each chromatic alteration from a double flat to a double sharp.
+const ACCIDENTALS: [&str; 5] = ["flat-flat", "flat", "natural", "sharp", "double-sharp"];
+
+fn lookup<'a>(table: &[(&'a str, &'a str)], key: &str) -> Option<&'a str> {
+  table.iter().find(|(from, _)| *from == key).map(|(_, to)| *to)
+}
+
+fn reverse_lookup<'a>(table: &[(&'a str, &'a str)], key: &str) -> Option<&'a str> {
+  table.iter().find(|(_, to)| *to == key).map(|(from, _)| *from)
+}
+
+fn object<'a>(members: impl IntoIterator<Item = (&'a str, JsonValue)>) -> JsonValue {
+  JsonValue::Object(
+    members
+      .into_iter()
+      .map(|(key, value)| (String::from(key), value))
+      .collect(),
+  )
+}
+
+fn string(value: &str) -> JsonValue {
+  JsonValue::String(String::from(value))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn integer(value: i64) -> JsonValue {
+  JsonValue::Number(value as f64)
+}
+
+/// Returns an MNX rhythmic position for a position in quarter notes.
+fn position_json(position: Fraction) -> JsonValue {
+  let whole = position / Fraction::from_int(4);
+  object([(
+    "fraction",
+    JsonValue::Array(vec![integer(whole.num), integer(whole.den)]),
+  )])
+}
+
+fn position_from_json(position: Option<&JsonValue>) -> Result<Fraction, String> {
+  let Some(position) = position else {
+    return Ok(Fraction::ZERO);
+  };
+  match position.get("fraction").map(JsonValue::as_array) {
+    Some([num, den]) => match (num.as_i64(), den.as_i64()) {
+      (Some(num), Some(den)) if den > 0 => Ok(Fraction::new(num * 4, den)),
+      _ => Err(String::from("Invalid MNX rhythmic position")),
+    },
+    _ => Err(String::from("Invalid MNX rhythmic position")),
+  }
+}
+
+fn note_value_base(note_type: &NoteTypeValue) -> String {
+  match NoteTypeValue::serialize(note_type).as_str() {
+    "long" => String::from("longa"),
+    base => String::from(base),
+  }
+}
+
+/// Returns an MNX note value for a note type and number of dots.
+fn note_value_json(note_type: &NoteTypeValue, dots: u8) -> JsonValue {
+  let mut members = vec![("base", JsonValue::String(note_value_base(note_type)))];
+  if dots > 0 {
+    members.push(("dots", integer(i64::from(dots))));
+  }
+  object(members)
+}
+
+fn note_value_from_json(value: &JsonValue) -> Result<(NoteTypeValue, u8), String> {
+  let base = value
+    .get("base")
+    .and_then(JsonValue::as_str)
+    .ok_or_else(|| String::from("MNX note value is missing its base"))?;
+  let note_type = NoteTypeValue::deserialize(if base == "longa" { "long" } else { base })
+    .map_err(|_| format!("Unsupported MNX note value base '{base}'"))?;
+  let dots = value.get("dots").and_then(JsonValue::as_i64).unwrap_or(0);
+  Ok((note_type, u8::try_from(dots).unwrap_or(0)))
+}
+
+/// Returns the length in quarter notes of an MNX note value quantity, which may also be written as a plain note value.
+fn quantity_length(value: Option<&JsonValue>) -> Result<Fraction, String> {
+  let value = value.ok_or_else(|| String::from("MNX note value quantity is missing"))?;
+  let (note_value, multiple) = match value.get("duration") {
+    Some(note_value) => (
+      note_value,
+      value.get("multiple").and_then(JsonValue::as_i64).unwrap_or(1),
+    ),
+    None => (value, 1),
+  };
+  let (note_type, dots) = note_value_from_json(note_value)?;
+  Ok(dotted_length(&note_type, dots) * Fraction::from_int(multiple))
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// EXPORT -------------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// A note or chord located within a specific measure of an exported part.
+struct ExportEvent<'a> {
+  measure: usize,
+  offset: Fraction,
+  position: Fraction,
+  duration: Fraction,
+  notes: Vec<&'a Note>,
+}
+
+/// A tuplet container whose content is still being collected.
+struct OpenTuplet {
+  ratio: (u32, u32),
+  base: String,
+  length: Fraction,
+  filled: Fraction,
+  content: Vec<JsonValue>,
+}
+
+impl OpenTuplet {
+  fn new(note: &Note, ratio: (u32, u32)) -> Self {
+    let normal_type = note
+      .content
+      .time_modification
+      .as_ref()
+      .and_then(|modification| modification.content.normal_type.as_ref())
+      .map(|normal_type| &normal_type.content)
+      .or(note.content.r#type.as_ref().map(|note_type| &note_type.content));
+    let (base, length) = match normal_type {
+      Some(note_type) => (note_value_base(note_type), note_type_length(note_type)),
+      None => (String::from("eighth"), Fraction::new(1, 2)),
+    };
+    OpenTuplet {
+      ratio,
+      base,
+      length: length * Fraction::from_int(i64::from(ratio.1)),
+      filled: Fraction::ZERO,
+      content: Vec::new(),
+    }
+  }
+
+  fn into_json(self) -> JsonValue {
+    let quantity = |multiple: u32| {
+      object([
+        ("multiple", integer(i64::from(multiple))),
+        ("duration", object([("base", JsonValue::String(self.base.clone()))])),
+      ])
+    };
+    object([
+      ("type", string("tuplet")),
+      ("inner", quantity(self.ratio.0)),
+      ("outer", quantity(self.ratio.1)),
+      ("content", JsonValue::Array(self.content)),
+    ])
+  }
+}
+
+struct PartExporter<'a> {
+  id: &'a str,
+  measures: Vec<&'a Measure>,
+  staves: u32,
+  events: Vec<ExportEvent<'a>>,
+  meter_lengths: Vec<Fraction>,
+  clefs: Vec<Vec<(Fraction, &'a Clef)>>,
+  tempos: Vec<Vec<(Fraction, f64)>>,
+  ties: BTreeMap<(usize, usize), (usize, usize)>,
+  slurs: BTreeMap<usize, Vec<(usize, Option<&'a AboveBelow>)>>,
+}
+
+fn rest_is_measure(note: &Note) -> bool {
+  matches!(note_audible(note), AudibleType::Rest(rest) if rest.attributes.measure == Some(YesNo::Yes))
+}
+
+fn clef_json(clef: &Clef) -> Option<JsonValue> {
+  let (sign, default_line) = match clef.content.sign.content {
+    ClefSign::G => ("G", 2),
+    ClefSign::F => ("F", 4),
+    ClefSign::C => ("C", 3),
+    _ => return None,
+  };
+  let line = clef
+    .content
+    .line
+    .as_ref()
+    .map_or(default_line, |line| i64::from(*line.content));
+  let mut members = vec![("sign", string(sign)), ("staffPosition", integer((line - 3) * 2))];
+  match clef
+    .content
+    .clef_octave_change
+    .as_ref()
+    .map(|change| i64::from(change.content))
+  {
+    Some(change) if change != 0 => members.push(("octave", integer(change))),
+    _ => {}
+  }
+  Some(object(members))
+}
+
+/// Returns the count and unit of the MNX time signature written for a MusicXML time signature.
+fn time_signature(time: &Time) -> Option<(i64, i64)> {
+  let beats = time.content.beats.first()?;
+  let count = beats
+    .beats
+    .content
+    .split('+')
+    .map(|count| count.trim().parse::<i64>().ok())
+    .sum::<Option<i64>>()?;
+  let unit = beats.beat_type.content.trim().parse::<i64>().ok()?;
+  Some((count, unit))
+}
+
+fn time_json(time: &Time) -> Option<JsonValue> {
+  let (count, unit) = time_signature(time)?;
+  Some(object([("count", integer(count)), ("unit", integer(unit))]))
+}
+
+impl<'a> PartExporter<'a> {
+  fn new(part: &'a Part) -> Self {
+    let measures: Vec<&Measure> = part_measures(part).collect();
+    let mut events = Vec::new();
+    let mut clefs = Vec::new();
+    let mut tempos = Vec::new();
+    let mut meter_lengths = Vec::new();
+    let mut meter_length = Fraction::from_int(4);
+    let mut timeline = PartTimeline::default();
+    let mut offset = Fraction::ZERO;
+    for (index, measure) in measures.iter().enumerate() {
+      let mut measure_clefs = Vec::new();
+      let mut measure_tempos = Vec::new();
+      let mut length = Fraction::ZERO;
+      for event in timeline.measure(measure).events {
+        length = length.max(event.position + event.duration);
+        match event.event {
+          MeasureEvent::Notes(notes) => events.push(ExportEvent {
+            measure: index,
+            offset,
+            position: event.position,
+            duration: event.duration,
+            notes,
+          }),
+          MeasureEvent::Attributes(attributes) => {
+            measure_clefs.extend(attributes.content.clef.iter().map(|clef| (event.position, clef)));
+            if let Some((count, unit)) = attributes.content.time.first().and_then(time_signature) {
+              if count > 0 && unit > 0 {
+                meter_length = Fraction::new(count * 4, unit);
+              }
+            }
+          }
+          MeasureEvent::Direction(direction) => {
+            if let Some(tempo) = direction
+              .content
+              .sound
+              .as_ref()
+              .and_then(|sound| sound.attributes.tempo.as_ref())
+            {
+              measure_tempos.push((event.position, tempo.0));
+            }
+          }
+          MeasureEvent::Sound(sound) => {
+            if let Some(tempo) = &sound.attributes.tempo {
+              measure_tempos.push((event.position, tempo.0));
+            }
+          }
+          MeasureEvent::Harmony(_) => {}
+        }
+      }
+      clefs.push(measure_clefs);
+      tempos.push(measure_tempos);
+      meter_lengths.push(meter_length);
+      offset += length;
+    }
+    let mut exporter = PartExporter {
+      id: &part.attributes.id.0,
+      measures,
+      staves: part_staves(part),
+      events,
+      meter_lengths,
+      clefs,
+      tempos,
+      ties: BTreeMap::new(),
+      slurs: BTreeMap::new(),
+    };
+    exporter.link_ties();
+    exporter.link_slurs();
+    exporter
+  }
+
+  /// Connects every note that starts a tie to the matching note in the next event of the same voice.
+  fn link_ties(&mut self) {
+    for (index, event) in self.events.iter().enumerate() {
+      let first = event.notes[0];
+      let end = event.offset + event.position + event.duration;
+      let Some((next, next_event)) = self.events.iter().enumerate().skip(index + 1).find(|(_, other)| {
+        note_staff(other.notes[0]) == note_staff(first)
+          && note_voice(other.notes[0]) == note_voice(first)
+          && !note_is_grace(other.notes[0])
+          && other.offset + other.position >= end
+      }) else {
+        continue;
+      };
+      for (note_index, note) in event.notes.iter().enumerate() {
+        if !note_tied(note).0 {
+          continue;
+        }
+        if let Some(target) = next_event
+          .notes
+          .iter()
+          .position(|other| note_audible(other) == note_audible(note))
+        {
+          self.ties.insert((index, note_index), (next, target));
+        }
+      }
+    }
+  }
+
+  /// Connects every event that starts a slur to the event at which the slur stops.
+  fn link_slurs(&mut self) {
+    let mut open: BTreeMap<u8, (usize, Option<&'a AboveBelow>)> = BTreeMap::new();
+    for (index, event) in self.events.iter().enumerate() {
+      for notation in event
+        .notes
+        .iter()
+        .flat_map(|note| note.content.notations.iter())
+        .flat_map(|notations| notations.content.notations.iter())
+      {
+        if let NotationContentTypes::Slur(slur) = notation {
+          let number = slur.attributes.number.as_ref().map_or(1, |number| number.0);
+          match slur.attributes.r#type {
+            StartStopContinue::Start => {
+              open.insert(number, (index, slur.attributes.placement.as_ref()));
+            }
+            StartStopContinue::Stop => {
+              if let Some((start, placement)) = open.remove(&number) {
+                self.slurs.entry(start).or_default().push((index, placement));
+              }
+            }
+            StartStopContinue::Continue => {}
+          }
+        }
+      }
+    }
+  }
+
+  fn event_id(&self, index: usize) -> String {
+    format!("{}e{}", self.id, index + 1)
+  }
+
+  fn note_id(&self, (index, note): (usize, usize)) -> String {
+    format!("{}e{}n{}", self.id, index + 1, note + 1)
+  }
+
+  fn note_json(&self, index: usize, note_index: usize, targets: &BTreeSet<(usize, usize)>) -> JsonValue {
+    let note = self.events[index].notes[note_index];
+    let mut members = Vec::new();
+    if targets.contains(&(index, note_index)) {
+      members.push(("id", JsonValue::String(self.note_id((index, note_index)))));
+    }
+    let (step, octave, alter) = match note_audible(note) {
+      AudibleType::Pitch(pitch) => (
+        step_letter(&pitch.content.step.content),
+        i64::from(*pitch.content.octave.content),
+        pitch_alter(pitch),
+      ),
+      AudibleType::Unpitched(unpitched) => (
+        step_letter(&unpitched.content.display_step.content),
+        i64::from(*unpitched.content.display_octave.content),
+        0,
+      ),
+      AudibleType::Rest(_) => ('C', 4, 0),
+    };
+    let mut pitch = vec![
+      ("step", JsonValue::String(step.to_string())),
+      ("octave", integer(octave)),
+    ];
+    if alter != 0 {
+      pitch.push(("alter", integer(i64::from(alter))));
+    }
+    members.push(("pitch", object(pitch)));
+    if note.content.accidental.is_some() {
+      members.push(("accidentalDisplay", object([("show", JsonValue::Bool(true))])));
+    }
+    if let Some(target) = self.ties.get(&(index, note_index)) {
+      members.push((
+        "ties",
+        JsonValue::Array(vec![object([("target", JsonValue::String(self.note_id(*target)))])]),
+      ));
+    }
+    object(members)
+  }
+
+  fn event_json(&self, index: usize, targets: &BTreeSet<(usize, usize)>) -> JsonValue {
+    let event = &self.events[index];
+    let first = event.notes[0];
+    let mut members = vec![
+      ("type", string("event")),
+      ("id", JsonValue::String(self.event_id(index))),
+    ];
+    if let Some(note_type) = &first.content.r#type {
+      members.push(("duration", note_value_json(&note_type.content, note_dots(first))));
+    } else {
+      let (actual, normal) = note_time_modification(first);
+      let notated = event.duration * Fraction::new(i64::from(actual), i64::from(normal));
+      // A measure rest in an incomplete measure, such as a pickup, is written with its actual duration
+      let full_measure = rest_is_measure(first) && event.duration >= self.meter_lengths[event.measure];
+      match length_to_note_type(notated).filter(|_| !full_measure) {
+        Some((note_type, dots)) => members.push(("duration", note_value_json(&note_type, dots))),
+        None => members.push(("measure", JsonValue::Bool(true))),
+      }
+    }
+    if matches!(note_audible(first), AudibleType::Rest(_)) {
+      members.push(("rest", object([])));
+    } else {
+      let notes = (0..event.notes.len())
+        .map(|note_index| self.note_json(index, note_index, targets))
+        .collect();
+      members.push(("notes", JsonValue::Array(notes)));
+    }
+    let mut markings = Vec::new();
+    for articulations in event
+      .notes
+      .iter()
+      .flat_map(|note| note.content.notations.iter())
+      .flat_map(|notations| notations.content.notations.iter())
+      .filter_map(|notation| match notation {
+        NotationContentTypes::Articulations(articulations) => Some(articulations),
+        _ => None,
+      })
+    {
+      for child in Articulations::serialize(articulations).elements {
+        if let Some(marking) = lookup(&MARKINGS, &child.name) {
+          if !markings.iter().any(|(name, _)| *name == marking) {
+            markings.push((marking, object([])));
+          }
+        }
+      }
+    }
+    if !markings.is_empty() {
+      members.push(("markings", object(markings)));
+    }
+    if let Some(slurs) = self.slurs.get(&index) {
+      let slurs = slurs
+        .iter()
+        .map(|(target, placement)| {
+          let mut slur = vec![("target", JsonValue::String(self.event_id(*target)))];
+          match placement {
+            Some(AboveBelow::Above) => slur.push(("side", string("up"))),
+            Some(AboveBelow::Below) => slur.push(("side", string("down"))),
+            None => {}
+          }
+          object(slur)
+        })
+        .collect();
+      members.push(("slurs", JsonValue::Array(slurs)));
+    }
+    if let Some(note) = event.notes.iter().find(|note| !note.content.lyric.is_empty()) {
+      let mut lines = Vec::new();
+      for (line, lyric) in note.content.lyric.iter().enumerate() {
+        let LyricContents::Text(text) = &lyric.content else {
+          continue;
+        };
+        let number = lyric
+          .attributes
+          .number
+          .as_ref()
+          .map_or_else(|| (line + 1).to_string(), |number| number.0.clone());
+        let mut words = String::from(text.text.content.as_str());
+        for additional in &text.additional {
+          words.push(' ');
+          words += additional.text.content.as_str();
+        }
+        let mut syllable = vec![("text", JsonValue::String(words))];
+        if let Some(syllabic) = &text.syllabic {
+          if let Some(kind) = lookup(&SYLLABLES, &Syllabic::serialize(&syllabic.content)) {
+            syllable.push(("type", string(kind)));
+          }
+        }
+        lines.push((number, object(syllable)));
+      }
+      if !lines.is_empty() {
+        let lines = JsonValue::Object(lines);
+        members.push(("lyrics", object([("lines", lines)])));
+      }
+    }
+    match first.content.stem.as_ref().map(|stem| &stem.content) {
+      Some(StemValue::Up) => members.push(("stemDirection", string("up"))),
+      Some(StemValue::Down) => members.push(("stemDirection", string("down"))),
+      _ => {}
+    }
+    object(members)
+  }
+
+  /// Builds the content of a sequence, wrapping grace notes and tuplets in containers and filling gaps with spaces.
+  fn sequence_content(&self, indices: &[usize], targets: &BTreeSet<(usize, usize)>) -> Vec<JsonValue> {
+    let mut content = Vec::new();
+    let mut cursor = Fraction::ZERO;
+    let mut graces: Vec<JsonValue> = Vec::new();
+    let mut grace_slash = false;
+    let mut tuplet: Option<OpenTuplet> = None;
+    let grace_json = |graces: Vec<JsonValue>, slash: bool| {
+      let mut members = vec![("type", string("grace"))];
+      if slash {
+        members.push(("slash", JsonValue::Bool(true)));
+      }
+      members.push(("content", JsonValue::Array(graces)));
+      object(members)
+    };
+    for &index in indices {
+      let event = &self.events[index];
+      let first = event.notes[0];
+      if note_is_grace(first) {
+        if graces.is_empty() {
+          grace_slash = matches!(&first.content.info, NoteType::Grace(grace)
+            if grace.grace.attributes.slash == Some(YesNo::Yes));
+        }
+        graces.push(self.event_json(index, targets));
+        continue;
+      }
+      let ratio = note_time_modification(first);
+      if event.position > cursor || tuplet.as_ref().is_some_and(|open| open.ratio != ratio) {
+        if let Some(open) = tuplet.take() {
+          content.push(open.into_json());
+        }
+      }
+      if event.position > cursor {
+        content.extend(spaces(event.position - cursor));
+      }
+      if ratio != (1, 1) && tuplet.is_none() {
+        tuplet = Some(OpenTuplet::new(first, ratio));
+      }
+      let container = match &mut tuplet {
+        Some(open) => &mut open.content,
+        None => &mut content,
+      };
+      if !graces.is_empty() {
+        container.push(grace_json(core::mem::take(&mut graces), grace_slash));
+      }
+      container.push(self.event_json(index, targets));
+      cursor = event.position + event.duration;
+      if let Some(open) = &mut tuplet {
+        open.filled += event.duration;
+        if open.filled >= open.length {
+          if let Some(open) = tuplet.take() {
+            content.push(open.into_json());
+          }
+        }
+      }
+    }
+    if let Some(open) = tuplet.take() {
+      content.push(open.into_json());
+    }
+    if !graces.is_empty() {
+      content.push(grace_json(graces, grace_slash));
+    }
+    content
+  }
+
+  /// Builds the beams formed by a sequence of events, along with any beams nested within them.
+  fn beams_json(&self, indices: &[usize], level: u8) -> Vec<JsonValue> {
+    let beam_value = |index: usize, level: u8| {
+      self.events[index].notes[0]
+        .content
+        .beam
+        .iter()
+        .find(|beam| beam.attributes.number.as_ref().map_or(1, |number| number.0) == level)
+        .map(|beam| &beam.content)
+    };
+    let mut beams = Vec::new();
+    let mut group: Vec<usize> = Vec::new();
+    for &index in indices {
+      match beam_value(index, level) {
+        Some(BeamValue::Begin) => group = vec![index],
+        Some(BeamValue::Continue) if !group.is_empty() => group.push(index),
+        Some(BeamValue::End) if !group.is_empty() => {
+          group.push(index);
+          let mut members = vec![(
+            "events",
+            JsonValue::Array(
+              group
+                .iter()
+                .map(|index| JsonValue::String(self.event_id(*index)))
+                .collect(),
+            ),
+          )];
+          let hooks: Vec<JsonValue> = group
+            .iter()
+            .filter_map(|index| {
+              let direction = match beam_value(*index, level + 1) {
+                Some(BeamValue::ForwardHook) => "right",
+                Some(BeamValue::BackwardHook) => "left",
+                _ => return None,
+              };
+              Some(object([
+                ("event", JsonValue::String(self.event_id(*index))),
+                ("direction", string(direction)),
+              ]))
+            })
+            .collect();
+          if !hooks.is_empty() {
+            members.push(("hooks", JsonValue::Array(hooks)));
+          }
+          let inner = if level < 8 {
+            self.beams_json(&group, level + 1)
+          } else {
+            Vec::new()
+          };
+          if !inner.is_empty() {
+            members.push(("inner", JsonValue::Array(inner)));
+          }
+          beams.push(object(members));
+          group.clear();
+        }
+        _ => group.clear(),
+      }
+    }
+    beams
+  }
+
+  fn measure_json(&self, measure: usize, targets: &BTreeSet<(usize, usize)>) -> JsonValue {
+    let mut members = Vec::new();
+    let clefs: Vec<JsonValue> = self.clefs[measure]
+      .iter()
+      .filter_map(|(position, clef)| {
+        let mut members = vec![("clef", clef_json(clef)?)];
+        if position.is_positive() {
+          members.push(("position", position_json(*position)));
+        }
+        if let Some(number) = &clef.attributes.number {
+          members.push(("staff", integer(i64::from(number.0))));
+        }
+        Some(object(members))
+      })
+      .collect();
+    if !clefs.is_empty() {
+      members.push(("clefs", JsonValue::Array(clefs)));
+    }
+    let mut sequences: BTreeMap<(u32, u32, &str), Vec<usize>> = BTreeMap::new();
+    for (index, event) in self
+      .events
+      .iter()
+      .enumerate()
+      .filter(|(_, event)| event.measure == measure)
+    {
+      let voice = note_voice(event.notes[0]);
+      sequences
+        .entry((note_staff(event.notes[0]), voice.parse().unwrap_or(u32::MAX), voice))
+        .or_default()
+        .push(index);
+    }
+    let mut beams = Vec::new();
+    let sequences = sequences
+      .iter()
+      .map(|((staff, _, voice), indices)| {
+        beams.extend(self.beams_json(indices, 1));
+        let mut members = Vec::new();
+        if self.staves > 1 {
+          members.push(("staff", integer(i64::from(*staff))));
+        }
+        members.push(("voice", string(voice)));
+        members.push(("content", JsonValue::Array(self.sequence_content(indices, targets))));
+        object(members)
+      })
+      .collect();
+    members.push(("sequences", JsonValue::Array(sequences)));
+    if !beams.is_empty() {
+      members.push(("beams", JsonValue::Array(beams)));
+    }
+    object(members)
+  }
+
+  fn part_json(&self, score_part: Option<&ScorePart>) -> JsonValue {
+    let mut members = vec![("id", string(self.id))];
+    if let Some(score_part) = score_part {
+      members.push(("name", string(&score_part.content.part_name.content)));
+      if let Some(abbreviation) = &score_part.content.part_abbreviation {
+        members.push(("shortName", string(&abbreviation.content)));
+      }
+    }
+    if self.staves > 1 {
+      members.push(("staves", integer(i64::from(self.staves))));
+    }
+    let targets: BTreeSet<(usize, usize)> = self.ties.values().copied().collect();
+    let measures = (0..self.measures.len())
+      .map(|measure| self.measure_json(measure, &targets))
+      .collect();
+    members.push(("measures", JsonValue::Array(measures)));
+    object(members)
+  }
+}
+
+/// Returns the spaces which fill a gap within a sequence.
+fn spaces(length: Fraction) -> Vec<JsonValue> {
+  let quantity = NOTE_TYPES.iter().find_map(|note_type| {
+    let multiple = length / note_type_length(note_type);
+    (multiple.den == 1).then_some((note_type, multiple.num))
+  });
+  match quantity {
+    Some((note_type, multiple)) => vec![object([
+      ("type", string("space")),
+      (
+        "duration",
+        object([
+          ("multiple", integer(multiple)),
+          ("duration", note_value_json(note_type, 0)),
+        ]),
+      ),
+    ])],
+    None => split_length(length)
+      .iter()
+      .map(|(note_type, dots)| {
+        object([
+          ("type", string("space")),
+          ("duration", note_value_json(note_type, *dots)),
+        ])
+      })
+      .collect(),
+  }
+}
+
+/// Returns the MNX tempos of a measure, which are taken from the first part that specifies a tempo in that measure.
+fn tempos_json(parts: &[PartExporter], measure: usize) -> Option<JsonValue> {
+  let tempos = parts
+    .iter()
+    .filter_map(|part| part.tempos.get(measure))
+    .find(|tempos| !tempos.is_empty())?;
+  Some(JsonValue::Array(
+    tempos
+      .iter()
+      .map(|(position, tempo)| {
+        object([
+          ("bpm", JsonValue::Number(*tempo)),
+          ("value", note_value_json(&NoteTypeValue::Quarter, 0)),
+          ("location", position_json(*position)),
+        ])
+      })
+      .collect(),
+  ))
+}
+
+fn global_json(parts: &[PartExporter]) -> JsonValue {
+  let count = parts.iter().map(|part| part.measures.len()).max().unwrap_or(0);
+  let mut lyric_lines: Vec<String> = Vec::new();
+  let mut measures = Vec::new();
+  for index in 0..count {
+    let reference: Vec<&Measure> = parts
+      .iter()
+      .filter_map(|part| part.measures.get(index).copied())
+      .collect();
+    let elements = || reference.iter().flat_map(|measure| measure.content.iter());
+    let mut members = Vec::new();
+    if let Some(number) = reference
+      .first()
+      .and_then(|measure| measure.attributes.number.0.parse::<i64>().ok())
+    {
+      members.push(("number", integer(number)));
+    }
+    for element in elements() {
+      match element {
+        MeasureElement::Attributes(attributes) => {
+          if !members.iter().any(|(name, _)| *name == "key") {
+            if let Some(KeyContents::Explicit(key)) = attributes.content.key.first().map(|key| &key.content) {
+              members.push(("key", object([("fifths", integer(i64::from(*key.fifths.content)))])));
+            }
+          }
+          if !members.iter().any(|(name, _)| *name == "time") {
+            if let Some(time) = attributes.content.time.first().and_then(time_json) {
+              members.push(("time", time));
+            }
+          }
+        }
+        MeasureElement::Note(note) => {
+          for (line, lyric) in note.content.lyric.iter().enumerate() {
+            let number = lyric
+              .attributes
+              .number
+              .as_ref()
+              .map_or_else(|| (line + 1).to_string(), |number| number.0.clone());
+            if !lyric_lines.contains(&number) {
+              lyric_lines.push(number);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+    let barlines = || {
+      elements().filter_map(|element| match element {
+        MeasureElement::Barline(barline) => Some(barline),
+        _ => None,
+      })
+    };
+    if let Some(tempos) = tempos_json(parts, index) {
+      members.push(("tempos", tempos));
+    }
+    if barlines().any(|barline| {
+      barline.attributes.location == Some(RightLeftMiddle::Left)
+        && barline
+          .content
+          .repeat
+          .as_ref()
+          .is_some_and(|repeat| repeat.attributes.direction == BackwardForward::Forward)
+    }) {
+      members.push(("repeatStart", object([])));
+    }
+    let right =
+      || barlines().filter(|barline| matches!(barline.attributes.location, None | Some(RightLeftMiddle::Right)));
+    if let Some(repeat) = right()
+      .filter_map(|barline| barline.content.repeat.as_ref())
+      .find(|repeat| repeat.attributes.direction == BackwardForward::Backward)
+    {
+      members.push((
+        "repeatEnd",
+        object(
+          repeat
+            .attributes
+            .times
+            .as_ref()
+            .map(|times| ("times", integer(i64::from(times.0)))),
+        ),
+      ));
+    }
+    if let Some(style) = right()
+      .filter_map(|barline| barline.content.bar_style.as_ref())
+      .find_map(|bar_style| lookup(&BAR_STYLES, &BarStyle::serialize(&bar_style.content)))
+      .filter(|style| *style != "regular")
+    {
+      members.push(("barline", object([("type", string(style))])));
+    }
+    measures.push(object(members));
+  }
+  let mut members = vec![("measures", JsonValue::Array(measures))];
+  if !lyric_lines.is_empty() {
+    let order = lyric_lines.into_iter().map(JsonValue::String).collect();
+    members.push(("lyrics", object([("lineOrder", JsonValue::Array(order))])));
+  }
+  object(members)
+}
+
+/// Converts a [ScorePartwise] into an MNX document in its JSON representation.
+///
+/// Time signatures, key signatures, tempos, barlines, and repeats are written to the global measures, while every
+/// voice of every part is written to its own sequence. Notes without a [Type][crate::elements::Type] element are
+/// written using the note value that matches their duration, and whole-measure rests are written as `measure` events.
+///
+/// # Errors
+///
+/// Returns an error if the score does not contain any parts or contains a part that is not declared in its part list.
+pub fn score_partwise_to_mnx(score: &ScorePartwise) -> Result<String, String> {
+  let score_parts: Vec<&ScorePart> = score
+    .content
+    .part_list
+    .content
+    .content
+    .iter()
+    .filter_map(|element| match element {
+      PartListElement::ScorePart(score_part) => Some(score_part),
+      PartListElement::PartGroup(_) => None,
+    })
+    .collect();
+  if score.content.part.is_empty() {
+    return Err(String::from("Score does not contain any parts"));
+  }
+  let mut parts = Vec::new();
+  let mut parts_json = Vec::new();
+  for part in &score.content.part {
+    let score_part = score_parts
+      .iter()
+      .find(|score_part| score_part.attributes.id.0 == part.attributes.id.0)
+      .ok_or_else(|| format!("Part '{}' is not declared in the part list", part.attributes.id.0))?;
+    let exporter = PartExporter::new(part);
+    parts_json.push(exporter.part_json(Some(score_part)));
+    parts.push(exporter);
+  }
+  let mnx = object([
+    ("mnx", object([("version", integer(MNX_VERSION))])),
+    ("global", global_json(&parts)),
+    ("parts", JsonValue::Array(parts_json)),
+  ]);
+  Ok(json_parser::parse_to_string(&mnx, 0))
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// IMPORT -------------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The location of a `<note>` element within the measures of an imported part.
+type NoteLocation = (usize, usize);
+
+/// The sequence and tuplet context in which an imported event is read.
+#[derive(Clone, Copy)]
+struct EventContext<'a> {
+  voice: &'a str,
+  staff: Option<u32>,
+  ratio: (i64, i64),
+  grace: Option<bool>,
+  measure_length: Fraction,
+}
+
+#[derive(Default)]
+struct PartImporter {
+  measures: Vec<XmlElement<'static>>,
+  notes: BTreeMap<String, NoteLocation>,
+  events: BTreeMap<String, NoteLocation>,
+  ties: Vec<(NoteLocation, String)>,
+  slurs: Vec<(NoteLocation, String, Option<&'static str>)>,
+  cursor: Fraction,
+}
+
+fn clef_from_json(clef: &JsonValue, number: Option<u32>) -> Option<XmlElement<'static>> {
+  let sign = clef.get("clef")?.get("sign")?.as_str()?;
+  let position = clef
+    .get("clef")?
+    .get("staffPosition")
+    .and_then(JsonValue::as_i64)
+    .unwrap_or(0);
+  let mut element = XmlElement::named("clef");
+  if let Some(number) = clef
+    .get("staff")
+    .and_then(JsonValue::as_i64)
+    .and_then(|staff| u32::try_from(staff).ok())
+    .or(number)
+  {
+    element = element.with_attribute("number", number.to_string());
+  }
+  element = element
+    .with_child(XmlElement::named("sign").with_text(sign))
+    .with_child(XmlElement::named("line").with_text((position / 2 + 3).to_string()));
+  match clef.get("clef")?.get("octave").and_then(JsonValue::as_i64) {
+    Some(octave) if octave != 0 => {
+      Some(element.with_child(XmlElement::named("clef-octave-change").with_text(octave.to_string())))
+    }
+    _ => Some(element),
+  }
+}
+
+fn notations<'a>(note: &'a mut XmlElement<'static>) -> &'a mut XmlElement<'static> {
+  let index = if let Some(index) = note.elements.iter().position(|element| element.name == "notations") {
+    index
+  } else {
+    note.elements.push(XmlElement::named("notations"));
+    note.elements.len() - 1
+  };
+  &mut note.elements[index]
+}
+
+impl PartImporter {
+  fn note_mut(&mut self, (measure, element): NoteLocation) -> &mut XmlElement<'static> {
+    &mut self.measures[measure].elements[element]
+  }
+
+  fn move_cursor(&mut self, position: Fraction) {
+    let Some(measure) = self.measures.last_mut() else {
+      return;
+    };
+    if position < self.cursor {
+      measure.elements.push(
+        XmlElement::named("backup")
+          .with_child(XmlElement::named("duration").with_text(format_fraction(self.cursor - position))),
+      );
+    } else if position > self.cursor {
+      measure.elements.push(
+        XmlElement::named("forward")
+          .with_child(XmlElement::named("duration").with_text(format_fraction(position - self.cursor))),
+      );
+    }
+    self.cursor = position;
+  }
+
+  #[allow(clippy::too_many_lines)]
+  fn read_event(&mut self, event: &JsonValue, context: EventContext) -> Result<(), String> {
+    let measure_rest = event.get("measure").and_then(JsonValue::as_bool) == Some(true);
+    let (note_value, length) = match event.get("duration") {
+      Some(duration) => {
+        let (note_type, dots) = note_value_from_json(duration)?;
+        let length = dotted_length(&note_type, dots) * Fraction::new(context.ratio.1, context.ratio.0);
+        (Some((note_type, dots)), length)
+      }
+      None if measure_rest => (None, context.measure_length),
+      None => return Err(String::from("MNX event does not specify a duration")),
+    };
+    let notes: Vec<Option<&JsonValue>> = match event.get("notes").map(JsonValue::as_array) {
+      Some(notes) if !notes.is_empty() => notes.iter().map(Some).collect(),
+      _ => vec![None],
+    };
+    let measure = self.measures.len() - 1;
+    for (index, note) in notes.into_iter().enumerate() {
+      let mut element = XmlElement::named("note");
+      if let Some(slash) = context.grace {
+        let grace = XmlElement::named("grace");
+        element = element.with_child(if slash {
+          grace.with_attribute("slash", "yes")
+        } else {
+          grace
+        });
+      }
+      if index > 0 {
+        element = element.with_child(XmlElement::named("chord"));
+      }
+      let pitch = note.and_then(|note| note.get("pitch"));
+      let alter = pitch
+        .and_then(|pitch| pitch.get("alter"))
+        .and_then(JsonValue::as_i64)
+        .unwrap_or(0);
+      element = element.with_child(match pitch {
+        Some(pitch) => {
+          let step = pitch
+            .get("step")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| String::from("MNX pitch is missing its step"))?;
+          let octave = pitch
+            .get("octave")
+            .and_then(JsonValue::as_i64)
+            .ok_or_else(|| String::from("MNX pitch is missing its octave"))?;
+          let mut element = XmlElement::named("pitch").with_child(XmlElement::named("step").with_text(step));
+          if alter != 0 {
+            element = element.with_child(XmlElement::named("alter").with_text(alter.to_string()));
+          }
+          element.with_child(XmlElement::named("octave").with_text(octave.to_string()))
+        }
+        None if measure_rest => XmlElement::named("rest").with_attribute("measure", "yes"),
+        None => XmlElement::named("rest"),
+      });
+      if context.grace.is_none() {
+        element = element.with_child(XmlElement::named("duration").with_text(format_fraction(length)));
+      }
+      element = element.with_child(XmlElement::named("voice").with_text(context.voice));
+      if let Some((note_type, dots)) = &note_value {
+        element = element.with_child(XmlElement::named("type").with_text(NoteTypeValue::serialize(note_type)));
+        for _ in 0..*dots {
+          element = element.with_child(XmlElement::named("dot"));
+        }
+      }
+      let show_accidental = note
+        .and_then(|note| note.get("accidentalDisplay"))
+        .and_then(|display| display.get("show"))
+        .and_then(JsonValue::as_bool)
+        == Some(true);
+      if let Some(accidental) = usize::try_from(alter + 2)
+        .ok()
+        .and_then(|index| ACCIDENTALS.get(index))
+        .filter(|_| show_accidental)
+      {
+        element = element.with_child(XmlElement::named("accidental").with_text(*accidental));
+      }
+      if context.ratio != (1, 1) {
+        element = element.with_child(
+          XmlElement::named("time-modification")
+            .with_child(XmlElement::named("actual-notes").with_text(context.ratio.0.to_string()))
+            .with_child(XmlElement::named("normal-notes").with_text(context.ratio.1.to_string())),
+        );
+      }
+      if index == 0 {
+        if let Some(stem) = event.get("stemDirection").and_then(JsonValue::as_str) {
+          element = element.with_child(XmlElement::named("stem").with_text(stem));
+        }
+      }
+      if let Some(staff) = context.staff {
+        element = element.with_child(XmlElement::named("staff").with_text(staff.to_string()));
+      }
+      if index == 0 {
+        let markings: Vec<&str> = event
+          .get("markings")
+          .map(JsonValue::as_object)
+          .unwrap_or_default()
+          .iter()
+          .filter_map(|(marking, _)| reverse_lookup(&MARKINGS, marking))
+          .collect();
+        if !markings.is_empty() {
+          let mut articulations = XmlElement::named("articulations");
+          for marking in markings {
+            articulations = articulations.with_child(XmlElement::named(marking));
+          }
+          element = element.with_child(XmlElement::named("notations").with_child(articulations));
+        }
+        for (number, syllable) in event
+          .get("lyrics")
+          .and_then(|lyrics| lyrics.get("lines"))
+          .map(JsonValue::as_object)
+          .unwrap_or_default()
+        {
+          let mut lyric = XmlElement::named("lyric").with_attribute("number", number.as_str());
+          if let Some(syllabic) = syllable
+            .get("type")
+            .and_then(JsonValue::as_str)
+            .and_then(|kind| reverse_lookup(&SYLLABLES, kind))
+          {
+            lyric = lyric.with_child(XmlElement::named("syllabic").with_text(syllabic));
+          }
+          let text = syllable.get("text").and_then(JsonValue::as_str).unwrap_or_default();
+          element = element.with_child(lyric.with_child(XmlElement::named("text").with_text(text)));
+        }
+      }
+      self.measures[measure].elements.push(element);
+      let location = (measure, self.measures[measure].elements.len() - 1);
+      if let Some(note) = note {
+        if let Some(id) = note.get("id").and_then(JsonValue::as_str) {
+          self.notes.insert(String::from(id), location);
+        }
+        for tie in note.get("ties").map(JsonValue::as_array).unwrap_or_default() {
+          if let Some(target) = tie.get("target").and_then(JsonValue::as_str) {
+            self.ties.push((location, String::from(target)));
+          }
+        }
+      }
+      if index == 0 {
+        if let Some(id) = event.get("id").and_then(JsonValue::as_str) {
+          self.events.insert(String::from(id), location);
+        }
+        for slur in event.get("slurs").map(JsonValue::as_array).unwrap_or_default() {
+          if let Some(target) = slur.get("target").and_then(JsonValue::as_str) {
+            let placement = match slur.get("side").and_then(JsonValue::as_str) {
+              Some("up") => Some("above"),
+              Some("down") => Some("below"),
+              _ => None,
+            };
+            self.slurs.push((location, String::from(target), placement));
+          }
+        }
+      }
+    }
+    if context.grace.is_none() {
+      self.cursor += length;
+    }
+    Ok(())
+  }
+
+  fn read_content(&mut self, items: &[JsonValue], context: EventContext) -> Result<(), String> {
+    for item in items {
+      match item.get("type").and_then(JsonValue::as_str).unwrap_or("event") {
+        "event" => self.read_event(item, context)?,
+        "space" => {
+          let length = quantity_length(item.get("duration"))? * Fraction::new(context.ratio.1, context.ratio.0);
+          self.move_cursor(self.cursor + length);
+        }
+        "grace" => {
+          let slash = item.get("slash").and_then(JsonValue::as_bool) == Some(true);
+          for event in item.get("content").map(JsonValue::as_array).unwrap_or_default() {
+            self.read_event(
+              event,
+              EventContext {
+                grace: Some(slash),
+                ..context
+              },
+            )?;
+          }
+        }
+        "tuplet" => {
+          let ratio = quantity_length(item.get("inner"))? / quantity_length(item.get("outer"))?;
+          if !ratio.is_positive() {
+            return Err(String::from("Invalid MNX tuplet ratio"));
+          }
+          let (actual, normal) = (context.ratio.0 * ratio.num, context.ratio.1 * ratio.den);
+          let divisor = gcd(actual, normal).max(1);
+          let measure = self.measures.len() - 1;
+          let start = self.measures[measure].elements.len();
+          self.read_content(
+            item.get("content").map(JsonValue::as_array).unwrap_or_default(),
+            EventContext {
+              ratio: (actual / divisor, normal / divisor),
+              ..context
+            },
+          )?;
+          let timed: Vec<usize> = (start..self.measures[measure].elements.len())
+            .filter(|index| {
+              let element = &self.measures[measure].elements[*index];
+              element.name == "note" && element.child("chord").is_none() && element.child("grace").is_none()
+            })
+            .collect();
+          if let (Some(first), Some(last)) = (timed.first(), timed.last()) {
+            notations(self.note_mut((measure, *first)))
+              .elements
+              .push(XmlElement::named("tuplet").with_attribute("type", "start"));
+            notations(self.note_mut((measure, *last)))
+              .elements
+              .push(XmlElement::named("tuplet").with_attribute("type", "stop"));
+          }
+        }
+        other => return Err(format!("Unsupported MNX sequence content type '{other}'")),
+      }
+    }
+    Ok(())
+  }
+
+  fn read_beams(&mut self, beams: &[JsonValue], level: u8) {
+    for beam in beams {
+      let locations: Vec<NoteLocation> = beam
+        .get("events")
+        .map(JsonValue::as_array)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|event| self.events.get(event.as_str()?).copied())
+        .collect();
+      for (index, location) in locations.iter().enumerate() {
+        let value = match index {
+          0 => "begin",
+          index if index + 1 == locations.len() => "end",
+          _ => "continue",
+        };
+        self.note_mut(*location).elements.push(
+          XmlElement::named("beam")
+            .with_attribute("number", level.to_string())
+            .with_text(value),
+        );
+      }
+      for hook in beam.get("hooks").map(JsonValue::as_array).unwrap_or_default() {
+        let Some(location) = hook
+          .get("event")
+          .and_then(JsonValue::as_str)
+          .and_then(|event| self.events.get(event).copied())
+        else {
+          continue;
+        };
+        let value = match hook.get("direction").and_then(JsonValue::as_str) {
+          Some("left") => "backward hook",
+          _ => "forward hook",
+        };
+        self.note_mut(location).elements.push(
+          XmlElement::named("beam")
+            .with_attribute("number", (level + 1).to_string())
+            .with_text(value),
+        );
+      }
+      self.read_beams(
+        beam.get("inner").map(JsonValue::as_array).unwrap_or_default(),
+        level + 1,
+      );
+    }
+  }
+
+  /// Resolves the tie and slur targets collected while reading the part into MusicXML start and stop notations.
+  fn link(&mut self) {
+    for (source, target) in core::mem::take(&mut self.ties) {
+      let Some(target) = self.notes.get(&target).copied() else {
+        continue;
+      };
+      for (location, kind) in [(source, "start"), (target, "stop")] {
+        let note = self.note_mut(location);
+        note
+          .elements
+          .push(XmlElement::named("tie").with_attribute("type", kind));
+        notations(note)
+          .elements
+          .push(XmlElement::named("tied").with_attribute("type", kind));
+      }
+    }
+    for (index, (source, target, placement)) in core::mem::take(&mut self.slurs).into_iter().enumerate() {
+      let Some(target) = self.events.get(&target).copied() else {
+        continue;
+      };
+      let number = (index % 6 + 1).to_string();
+      let mut start = XmlElement::named("slur")
+        .with_attribute("type", "start")
+        .with_attribute("number", number.as_str());
+      if let Some(placement) = placement {
+        start = start.with_attribute("placement", placement);
+      }
+      notations(self.note_mut(source)).elements.push(start);
+      notations(self.note_mut(target)).elements.push(
+        XmlElement::named("slur")
+          .with_attribute("type", "stop")
+          .with_attribute("number", number.as_str()),
+      );
+    }
+  }
+}
+
+/// Returns a `<direction>` element containing a metronome mark and the playback tempo of an MNX tempo.
+#[allow(clippy::cast_precision_loss)]
+fn tempo_from_json(tempo: &JsonValue) -> Result<XmlElement<'static>, String> {
+  let bpm = match tempo.get("bpm") {
+    Some(JsonValue::Number(bpm)) if *bpm > 0.0 => *bpm,
+    _ => return Err(String::from("Invalid MNX tempo")),
+  };
+  let (note_type, dots) = tempo
+    .get("value")
+    .map_or(Ok((NoteTypeValue::Quarter, 0)), note_value_from_json)?;
+  let length = dotted_length(&note_type, dots);
+  let quarters_per_minute = bpm * length.num as f64 / length.den as f64;
+  let mut metronome = XmlElement::named("metronome")
+    .with_child(XmlElement::named("beat-unit").with_text(NoteTypeValue::serialize(&note_type)));
+  for _ in 0..dots {
+    metronome = metronome.with_child(XmlElement::named("beat-unit-dot"));
+  }
+  metronome = metronome
+    .with_child(XmlElement::named("per-minute").with_text(NonNegativeDecimal::serialize(&NonNegativeDecimal(bpm))));
+  Ok(
+    XmlElement::named("direction")
+      .with_attribute("placement", "above")
+      .with_child(XmlElement::named("direction-type").with_child(metronome))
+      .with_child(XmlElement::named("sound").with_attribute(
+        "tempo",
+        NonNegativeDecimal::serialize(&NonNegativeDecimal(quarters_per_minute)),
+      )),
+  )
+}
+
+#[allow(clippy::too_many_lines)]
+fn read_part(part: &JsonValue, global: &[JsonValue], id: &str, tempos: bool) -> Result<XmlElement<'static>, String> {
+  let staves = part
+    .get("staves")
+    .and_then(JsonValue::as_i64)
+    .and_then(|staves| u32::try_from(staves).ok())
+    .unwrap_or(1)
+    .max(1);
+  let part_measures = part.get("measures").map(JsonValue::as_array).unwrap_or_default();
+  let mut importer = PartImporter::default();
+  let mut measure_length = Fraction::from_int(4);
+  for (index, global_measure) in global.iter().enumerate() {
+    let part_measure = part_measures.get(index);
+    let number = global_measure
+      .get("number")
+      .and_then(JsonValue::as_i64)
+      .or_else(|| i64::try_from(index + 1).ok())
+      .unwrap_or(1);
+    let mut measure = XmlElement::named("measure").with_attribute("number", number.to_string());
+    if index == 0 && number == 0 {
+      measure = measure.with_attribute("implicit", "yes");
+    }
+    if global_measure.get("repeatStart").is_some() {
+      measure = measure.with_child(
+        XmlElement::named("barline")
+          .with_attribute("location", "left")
+          .with_child(XmlElement::named("bar-style").with_text("heavy-light"))
+          .with_child(XmlElement::named("repeat").with_attribute("direction", "forward")),
+      );
+    }
+    let mut attributes = XmlElement::named("attributes");
+    if index == 0 {
+      attributes = attributes.with_child(XmlElement::named("divisions").with_text("1"));
+    }
+    if let Some(fifths) = global_measure
+      .get("key")
+      .and_then(|key| key.get("fifths"))
+      .and_then(JsonValue::as_i64)
+    {
+      attributes = attributes
+        .with_child(XmlElement::named("key").with_child(XmlElement::named("fifths").with_text(fifths.to_string())));
+    }
+    if let Some(time) = global_measure.get("time") {
+      let (Some(count), Some(unit)) = (
+        time.get("count").and_then(JsonValue::as_i64),
+        time.get("unit").and_then(JsonValue::as_i64),
+      ) else {
+        return Err(String::from("Invalid MNX time signature"));
+      };
+      if count <= 0 || unit <= 0 {
+        return Err(String::from("Invalid MNX time signature"));
+      }
+      measure_length = Fraction::new(count * 4, unit);
+      attributes = attributes.with_child(
+        XmlElement::named("time")
+          .with_child(XmlElement::named("beats").with_text(count.to_string()))
+          .with_child(XmlElement::named("beat-type").with_text(unit.to_string())),
+      );
+    }
+    if index == 0 && staves > 1 {
+      attributes = attributes.with_child(XmlElement::named("staves").with_text(staves.to_string()));
+    }
+    let default_number = (staves > 1).then_some(1);
+    let mut later_elements = Vec::new();
+    for clef in part_measure
+      .and_then(|measure| measure.get("clefs"))
+      .map(JsonValue::as_array)
+      .unwrap_or_default()
+    {
+      let position = position_from_json(clef.get("position"))?;
+      if let Some(element) = clef_from_json(clef, default_number) {
+        if position.is_positive() {
+          later_elements.push((position, XmlElement::named("attributes").with_child(element)));
+        } else {
+          attributes = attributes.with_child(element);
+        }
+      }
+    }
+    if !attributes.elements.is_empty() {
+      measure = measure.with_child(attributes);
+    }
+    for tempo in global_measure
+      .get("tempos")
+      .filter(|_| tempos)
+      .map(JsonValue::as_array)
+      .unwrap_or_default()
+    {
+      let position = position_from_json(tempo.get("location"))?;
+      let direction = tempo_from_json(tempo)?;
+      if position.is_positive() {
+        later_elements.push((position, direction));
+      } else {
+        measure = measure.with_child(direction);
+      }
+    }
+    importer.measures.push(measure);
+    importer.cursor = Fraction::ZERO;
+    let sequences = part_measure
+      .and_then(|measure| measure.get("sequences"))
+      .map(JsonValue::as_array)
+      .unwrap_or_default();
+    let mut staff_sequences: BTreeMap<i64, i64> = BTreeMap::new();
+    for sequence in sequences {
+      let staff = sequence.get("staff").and_then(JsonValue::as_i64).unwrap_or(1);
+      let count = staff_sequences.entry(staff).or_default();
+      *count += 1;
+      let voice = sequence
+        .get("voice")
+        .and_then(JsonValue::as_str)
+        .map_or_else(|| ((staff - 1) * 4 + *count).to_string(), String::from);
+      importer.move_cursor(Fraction::ZERO);
+      importer.read_content(
+        sequence.get("content").map(JsonValue::as_array).unwrap_or_default(),
+        EventContext {
+          voice: &voice,
+          staff: (staves > 1).then(|| u32::try_from(staff).unwrap_or(1)),
+          ratio: (1, 1),
+          grace: None,
+          measure_length,
+        },
+      )?;
+    }
+    later_elements.sort_by_key(|(position, _)| *position);
+    for (position, element) in later_elements {
+      importer.move_cursor(position);
+      if let Some(measure) = importer.measures.last_mut() {
+        measure.elements.push(element);
+      }
+    }
+    let bar_style = global_measure
+      .get("barline")
+      .and_then(|barline| barline.get("type"))
+      .and_then(JsonValue::as_str)
+      .and_then(|style| reverse_lookup(&BAR_STYLES, style));
+    let repeat_end = global_measure.get("repeatEnd");
+    if bar_style.is_some() || repeat_end.is_some() {
+      let mut barline = XmlElement::named("barline")
+        .with_attribute("location", "right")
+        .with_child(XmlElement::named("bar-style").with_text(bar_style.unwrap_or("light-heavy")));
+      if let Some(repeat_end) = repeat_end {
+        let mut repeat = XmlElement::named("repeat").with_attribute("direction", "backward");
+        if let Some(times) = repeat_end.get("times").and_then(JsonValue::as_i64) {
+          repeat = repeat.with_attribute("times", times.to_string());
+        }
+        barline = barline.with_child(repeat);
+      }
+      if let Some(measure) = importer.measures.last_mut() {
+        measure.elements.push(barline);
+      }
+    }
+    let beams = part_measure
+      .and_then(|measure| measure.get("beams"))
+      .map(JsonValue::as_array)
+      .unwrap_or_default();
+    importer.read_beams(beams, 1);
+  }
+  importer.link();
+  let mut element = XmlElement::named("part").with_attribute("id", id);
+  element.elements = importer.measures;
+  Ok(element)
+}
+
+/// Converts an MNX document in its JSON representation into a [ScorePartwise].
+///
+/// Every MNX part becomes a MusicXML part whose measures follow the document's global measures. Sequences become
+/// voices, `tuplet` and `grace` containers are expanded into notes carrying the corresponding
+/// [TimeModification][crate::elements::TimeModification] and [Grace][crate::elements::Grace] elements, and beams,
+/// ties, and slurs are resolved from the `id` values they reference.
+///
+/// # Errors
+///
+/// Returns an error if the document is not valid JSON, is not an MNX document, does not contain any global measures
+/// or parts, contains an invalid tempo, uses note values or sequence content that cannot be represented in MusicXML,
+/// or cannot be represented as a valid MusicXML score.
+pub fn mnx_to_score_partwise(mnx: &str) -> Result<ScorePartwise, String> {
+  let document = json_parser::parse_from_string(mnx)?;
+  if document.get("mnx").is_none() {
+    return Err(String::from("Document is not an MNX score"));
+  }
+  let global = document
+    .get("global")
+    .and_then(|global| global.get("measures"))
+    .map(JsonValue::as_array)
+    .unwrap_or_default();
+  if global.is_empty() {
+    return Err(String::from("MNX document does not contain any global measures"));
+  }
+  let parts = document.get("parts").map(JsonValue::as_array).unwrap_or_default();
+  if parts.is_empty() {
+    return Err(String::from("MNX document does not contain any parts"));
+  }
+  let mut xml = XmlElement::named("score-partwise").with_attribute("version", "4.0");
+  let mut part_list = XmlElement::named("part-list");
+  let mut part_elements = Vec::new();
+  for (index, part) in parts.iter().enumerate() {
+    let id = match part.get("id").and_then(JsonValue::as_str) {
+      Some(id) => String::from(id),
+      None => format!("P{}", index + 1),
+    };
+    let name = part.get("name").and_then(JsonValue::as_str).unwrap_or_default();
+    let mut score_part = XmlElement::named("score-part")
+      .with_attribute("id", id.as_str())
+      .with_child(XmlElement::named("part-name").with_text(name));
+    if let Some(short_name) = part.get("shortName").and_then(JsonValue::as_str) {
+      score_part = score_part.with_child(XmlElement::named("part-abbreviation").with_text(short_name));
+    }
+    part_list.elements.push(score_part);
+    part_elements.push(read_part(part, global, &id, index == 0)?);
+  }
+  xml.elements.push(part_list);
+  xml.elements.extend(part_elements);
+  resolve_fractional_durations(&mut xml);
+  ScorePartwise::deserialize(&xml)
+}
+
+#[cfg(test)]
+mod mnx_tests {
+  use super::*;
+  use crate::elements::PartElement;
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_is_chord, note_pitch, note_ties};
+
+  const SCORE: &str = "<score-partwise version=\"4.0\">
+    <part-list>
+      <score-part id=\"P1\"><part-name>Voice</part-name></score-part>
+      <score-part id=\"P2\"><part-name>Piano</part-name><part-abbreviation>Pno.</part-abbreviation></score-part>
+    </part-list>
+    <part id=\"P1\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>6</divisions>
+          <key><fifths>-2</fifths></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <clef><sign>G</sign><line>2</line></clef>
+        </attributes>
+        <note>
+          <pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch>
+          <duration>12</duration><voice>1</voice><type>half</type>
+          <lyric number=\"1\"><syllabic>begin</syllabic><text>Hel</text></lyric>
+        </note>
+        <note>
+          <pitch><step>G</step><octave>4</octave></pitch>
+          <duration>2</duration><voice>1</voice><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+          <beam number=\"1\">begin</beam>
+          <notations><tuplet type=\"start\"/><slur type=\"start\" number=\"1\"/><articulations><staccato/></articulations></notations>
+          <lyric number=\"1\"><syllabic>end</syllabic><text>lo</text></lyric>
+        </note>
+        <note>
+          <pitch><step>A</step><octave>4</octave></pitch>
+          <duration>2</duration><voice>1</voice><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+          <beam number=\"1\">continue</beam>
+        </note>
+        <note>
+          <pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch>
+          <duration>2</duration><tie type=\"start\"/><voice>1</voice><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+          <beam number=\"1\">end</beam>
+          <notations><tied type=\"start\"/><tuplet type=\"stop\"/><slur type=\"stop\" number=\"1\"/></notations>
+        </note>
+      </measure>
+      <measure number=\"2\">
+        <note>
+          <pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch>
+          <duration>18</duration><tie type=\"stop\"/><voice>1</voice><type>half</type><dot/>
+          <notations><tied type=\"stop\"/></notations>
+        </note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+    <part id=\"P2\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>2</divisions>
+          <key><fifths>-2</fifths></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <staves>2</staves>
+          <clef number=\"1\"><sign>G</sign><line>2</line></clef>
+          <clef number=\"2\"><sign>F</sign><line>4</line></clef>
+        </attributes>
+        <note>
+          <pitch><step>D</step><octave>4</octave></pitch>
+          <duration>6</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+        <note>
+          <chord/><pitch><step>F</step><octave>4</octave></pitch>
+          <duration>6</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+        <backup><duration>6</duration></backup>
+        <forward><duration>2</duration></forward>
+        <note>
+          <pitch><step>B</step><alter>-1</alter><octave>2</octave></pitch>
+          <duration>1</duration><voice>5</voice><type>eighth</type><staff>2</staff><beam number=\"1\">begin</beam>
+          <beam number=\"2\">forward hook</beam>
+        </note>
+        <note>
+          <pitch><step>F</step><octave>3</octave></pitch>
+          <duration>1</duration><voice>5</voice><type>eighth</type><staff>2</staff><beam number=\"1\">end</beam>
+        </note>
+        <note>
+          <pitch><step>D</step><octave>3</octave></pitch>
+          <duration>2</duration><voice>5</voice><type>quarter</type><staff>2</staff>
+        </note>
+      </measure>
+      <measure number=\"2\">
+        <attributes><clef number=\"2\"><sign>G</sign><line>2</line></clef></attributes>
+        <note>
+          <rest measure=\"yes\"/><duration>6</duration><voice>1</voice><staff>1</staff>
+        </note>
+        <backup><duration>6</duration></backup>
+        <note>
+          <rest measure=\"yes\"/><duration>6</duration><voice>5</voice><staff>2</staff>
+        </note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+  </score-partwise>";
+
+  fn part_notes(score: &ScorePartwise, part: usize) -> Vec<&Note> {
+    score.content.part[part]
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        PartElement::Measure(measure) => Some(measure),
+        _ => None,
+      })
+      .flat_map(|measure| measure.content.iter())
+      .filter_map(|element| match element {
+        MeasureElement::Note(note) => Some(note),
+        _ => None,
+      })
+      .collect()
+  }
+
+  fn part_length(score: &ScorePartwise, part: usize) -> Fraction {
+    let mut timeline = PartTimeline::default();
+    part_measures(&score.content.part[part])
+      .map(|measure| {
+        timeline
+          .measure(measure)
+          .events
+          .iter()
+          .map(|event| event.position + event.duration)
+          .max()
+          .unwrap_or(Fraction::ZERO)
+      })
+      .fold(Fraction::ZERO, |total, length| total + length)
+  }
+
+  #[test]
+  fn export_structure() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let document = json_parser::parse_from_string(&score_partwise_to_mnx(&score).unwrap()).unwrap();
+    assert_eq!(document.get("mnx").unwrap().get("version").unwrap().as_i64(), Some(1));
+    let global = document.get("global").unwrap().get("measures").unwrap().as_array();
+    assert_eq!(global.len(), 2);
+    assert_eq!(global[0].get("key").unwrap().get("fifths").unwrap().as_i64(), Some(-2));
+    assert_eq!(global[0].get("time").unwrap().get("count").unwrap().as_i64(), Some(3));
+    assert_eq!(
+      global[1].get("barline").unwrap().get("type").unwrap().as_str(),
+      Some("final")
+    );
+    let parts = document.get("parts").unwrap().as_array();
+    assert_eq!(parts[1].get("shortName").unwrap().as_str(), Some("Pno."));
+    assert_eq!(parts[1].get("staves").unwrap().as_i64(), Some(2));
+    let measure = &parts[0].get("measures").unwrap().as_array()[0];
+    let content = measure.get("sequences").unwrap().as_array()[0]
+      .get("content")
+      .unwrap()
+      .as_array();
+    assert_eq!(content.len(), 2);
+    let event = &content[0];
+    assert_eq!(
+      event.get("duration").unwrap().get("base").unwrap().as_str(),
+      Some("half")
+    );
+    let note = &event.get("notes").unwrap().as_array()[0];
+    assert_eq!(note.get("pitch").unwrap().get("alter").unwrap().as_i64(), Some(-1));
+    let lines = event.get("lyrics").unwrap().get("lines").unwrap();
+    assert_eq!(lines.get("1").unwrap().get("type").unwrap().as_str(), Some("start"));
+    let tuplet = &content[1];
+    assert_eq!(tuplet.get("type").unwrap().as_str(), Some("tuplet"));
+    assert_eq!(tuplet.get("inner").unwrap().get("multiple").unwrap().as_i64(), Some(3));
+    assert_eq!(tuplet.get("content").unwrap().as_array().len(), 3);
+    let first = &tuplet.get("content").unwrap().as_array()[0];
+    assert!(first.get("markings").unwrap().get("staccato").is_some());
+    let last = &tuplet.get("content").unwrap().as_array()[2];
+    let tie = &last.get("notes").unwrap().as_array()[0].get("ties").unwrap().as_array()[0];
+    assert_eq!(tie.get("target").unwrap().as_str(), Some("P1e5n1"));
+    assert_eq!(
+      first.get("slurs").unwrap().as_array()[0]
+        .get("target")
+        .unwrap()
+        .as_str(),
+      Some("P1e4")
+    );
+    assert_eq!(
+      measure.get("beams").unwrap().as_array()[0]
+        .get("events")
+        .unwrap()
+        .as_array()
+        .len(),
+      3
+    );
+    let piano = &parts[1].get("measures").unwrap().as_array()[0];
+    let bass = &piano.get("sequences").unwrap().as_array()[1];
+    assert_eq!(bass.get("staff").unwrap().as_i64(), Some(2));
+    assert_eq!(
+      bass.get("content").unwrap().as_array()[0].get("type").unwrap().as_str(),
+      Some("space")
+    );
+    assert!(piano.get("beams").unwrap().as_array()[0].get("hooks").is_some());
+    let rest_measure = &parts[1].get("measures").unwrap().as_array()[1];
+    let clef = &rest_measure.get("clefs").unwrap().as_array()[0];
+    assert_eq!(clef.get("staff").unwrap().as_i64(), Some(2));
+    assert_eq!(
+      clef.get("clef").unwrap().get("staffPosition").unwrap().as_i64(),
+      Some(-2)
+    );
+    let rest = &rest_measure.get("sequences").unwrap().as_array()[0]
+      .get("content")
+      .unwrap()
+      .as_array()[0];
+    assert_eq!(rest.get("measure").unwrap().as_bool(), Some(true));
+  }
+
+  #[test]
+  fn round_trip() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let mnx = score_partwise_to_mnx(&score).unwrap();
+    let imported = mnx_to_score_partwise(&mnx).unwrap();
+    assert_eq!(imported.content.part.len(), 2);
+    for part in 0..2 {
+      let (original, converted) = (part_notes(&score, part), part_notes(&imported, part));
+      assert_eq!(original.len(), converted.len());
+      for (original, converted) in original.iter().zip(converted.iter()) {
+        assert_eq!(note_audible(original), note_audible(converted));
+        assert_eq!(original.content.r#type, converted.content.r#type);
+        assert_eq!(original.content.dot.len(), converted.content.dot.len());
+        assert_eq!(note_ties(original), note_ties(converted));
+        assert_eq!(note_time_modification(original), note_time_modification(converted));
+        assert_eq!(original.content.beam, converted.content.beam);
+        assert_eq!(original.content.lyric.len(), converted.content.lyric.len());
+        assert_eq!(original.content.staff, converted.content.staff);
+        assert_eq!(note_voice(original), note_voice(converted));
+      }
+    }
+    assert_eq!(score_partwise_to_mnx(&imported).unwrap(), mnx);
+  }
+
+  #[test]
+  fn import_mnx() {
+    let mnx = r#"{
+      "mnx": {"version": 1},
+      "global": {"measures": [
+        {"time": {"count": 2, "unit": 4}, "key": {"fifths": 1}, "repeatStart": {}},
+        {"repeatEnd": {"times": 3}}
+      ]},
+      "parts": [{
+        "name": "Flute",
+        "measures": [
+          {
+            "clefs": [{"clef": {"sign": "G", "staffPosition": -2}}],
+            "sequences": [{"content": [
+              {"type": "grace", "slash": true, "content": [
+                {"type": "event", "duration": {"base": "eighth"}, "notes": [{"pitch": {"step": "E", "octave": 5}}]}
+              ]},
+              {"type": "event", "id": "a", "duration": {"base": "quarter"}, "slurs": [{"target": "c", "side": "up"}],
+                "notes": [{"pitch": {"step": "D", "octave": 5}}, {"pitch": {"step": "F", "octave": 5, "alter": 1},
+                "accidentalDisplay": {"show": true}}]},
+              {"type": "tuplet", "inner": {"multiple": 3, "duration": {"base": "eighth"}},
+                "outer": {"multiple": 1, "duration": {"base": "quarter"}}, "content": [
+                {"type": "event", "id": "b1", "duration": {"base": "eighth"}, "notes": [{"id": "t1", "pitch": {"step": "A", "octave": 4}, "ties": [{"target": "t2"}]}]},
+                {"type": "event", "id": "b2", "duration": {"base": "eighth"}, "notes": [{"id": "t2", "pitch": {"step": "A", "octave": 4}}]},
+                {"type": "event", "id": "c", "duration": {"base": "eighth"}, "rest": {}}
+              ]}
+            ]}],
+            "beams": [{"events": ["b1", "b2", "c"]}]
+          },
+          {"sequences": [{"content": [
+            {"type": "space", "duration": {"multiple": 1, "duration": {"base": "quarter"}}},
+            {"type": "event", "duration": {"base": "quarter"}, "markings": {"accent": {}},
+              "lyrics": {"lines": {"1": {"text": "la", "type": "whole"}}}, "notes": [{"pitch": {"step": "G", "octave": 4}}]}
+          ]}]}
+        ]
+      }]
+    }"#;
+    let score = mnx_to_score_partwise(mnx).unwrap();
+    let notes = part_notes(&score, 0);
+    assert_eq!(notes.len(), 7);
+    assert!(note_is_grace(notes[0]));
+    assert!(note_is_chord(notes[2]));
+    assert_eq!(
+      notes[2].content.accidental.as_ref().unwrap().content,
+      crate::datatypes::AccidentalValue::Sharp
+    );
+    assert_eq!(pitch_alter(note_pitch(notes[2]).unwrap()), 1);
+    assert_eq!(note_time_modification(notes[3]), (3, 2));
+    assert_eq!(note_tied(notes[3]), (true, false));
+    assert_eq!(note_tied(notes[4]), (false, true));
+    assert_eq!(notes[3].content.beam.len(), 1);
+    assert_eq!(notes[5].content.beam[0].content, BeamValue::End);
+    assert_eq!(notes[1].content.notations.len(), 1);
+    assert_eq!(notes[6].content.lyric.len(), 1);
+    let measures: Vec<&Measure> = part_measures(&score.content.part[0]).collect();
+    assert!(measures[1]
+      .content
+      .iter()
+      .any(|element| matches!(element, MeasureElement::Forward(_))));
+    assert!(measures[1]
+      .content
+      .iter()
+      .any(|element| matches!(element, MeasureElement::Barline(barline) if barline.content.repeat.is_some())));
+    let divisions = measures[0].content.iter().find_map(|element| match element {
+      MeasureElement::Attributes(attributes) => attributes
+        .content
+        .divisions
+        .as_ref()
+        .map(|divisions| *divisions.content),
+      _ => None,
+    });
+    assert_eq!(divisions, Some(3));
+  }
+
+  #[test]
+  fn invalid_mnx() {
+    assert!(mnx_to_score_partwise("").is_err());
+    assert!(mnx_to_score_partwise("{\"parts\": []}").is_err());
+    assert!(mnx_to_score_partwise("{\"mnx\": {}, \"global\": {\"measures\": []}, \"parts\": []}").is_err());
+    assert!(mnx_to_score_partwise(
+      "{\"mnx\": {}, \"global\": {\"measures\": [{}]}, \"parts\": [{\"measures\": [{\"sequences\": [{\"content\": [{\"type\": \"event\", \"duration\": {\"base\": \"4096th\"}}]}]}]}]}"
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn round_trip_file() {
+    let score = crate::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    let imported = mnx_to_score_partwise(&score_partwise_to_mnx(&score).unwrap()).unwrap();
+    assert_eq!(score.content.part.len(), imported.content.part.len());
+    for part in 0..score.content.part.len() {
+      let (original, converted) = (part_notes(&score, part), part_notes(&imported, part));
+      assert_eq!(original.len(), converted.len());
+      for (original, converted) in original.iter().zip(converted.iter()) {
+        assert_eq!(note_pitch(original), note_pitch(converted));
+        assert_eq!(original.content.lyric.len(), converted.content.lyric.len());
+      }
+      assert_eq!(part_length(&score, part), part_length(&imported, part));
+    }
+  }
+
+  #[test]
+  fn round_trip_tempo_and_metadata() {
+    let score = crate::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    assert!(score.content.work.is_some() && score.content.identification.is_some());
+    let mnx = score_partwise_to_mnx(&score).unwrap();
+    let document = json_parser::parse_from_string(&mnx).unwrap();
+    let global = document.get("global").unwrap().get("measures").unwrap().as_array();
+    let tempos: Vec<(usize, Option<i64>)> = global
+      .iter()
+      .enumerate()
+      .flat_map(|(index, measure)| {
+        let tempos = measure.get("tempos").map(JsonValue::as_array).unwrap_or_default();
+        tempos
+          .iter()
+          .map(move |tempo| (index, tempo.get("bpm").and_then(JsonValue::as_i64)))
+      })
+      .collect();
+    assert_eq!(tempos, vec![(0, Some(126))]);
+
+    let imported = mnx_to_score_partwise(&mnx).unwrap();
+    let sound_tempos = |part: &Part| -> Vec<f64> {
+      part_measures(part)
+        .flat_map(|measure| measure.content.iter())
+        .filter_map(|element| match element {
+          MeasureElement::Direction(direction) => direction.content.sound.as_ref()?.attributes.tempo.as_ref(),
+          _ => None,
+        })
+        .map(|tempo| tempo.0)
+        .collect()
+    };
+    assert_eq!(sound_tempos(&imported.content.part[0]), vec![126.0]);
+    assert!(imported.content.part[1..]
+      .iter()
+      .all(|part| sound_tempos(part).is_empty()));
+
+    // MNX has no score metadata, so the work title and the composer and lyricist are not converted
+    assert!(imported.content.work.is_none());
+    assert!(imported.content.movement_title.is_none());
+    assert!(imported.content.identification.is_none());
+  }
+
+  #[test]
+  fn round_trip_pickup() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      "<score-partwise version=\"4.0\">
+        <part-list>
+          <score-part id=\"P1\"><part-name>Voice</part-name></score-part>
+          <score-part id=\"P2\"><part-name>Violin</part-name></score-part>
+        </part-list>
+        <part id=\"P1\">
+          <measure number=\"0\" implicit=\"yes\">
+            <attributes><divisions>1</divisions><time><beats>3</beats><beat-type>4</beat-type></time></attributes>
+            <note><rest measure=\"yes\"/><duration>1</duration><voice>1</voice></note>
+          </measure>
+          <measure number=\"1\">
+            <note>
+              <pitch><step>C</step><octave>5</octave></pitch><duration>3</duration><voice>1</voice>
+              <type>half</type><dot/>
+            </note>
+          </measure>
+        </part>
+        <part id=\"P2\">
+          <measure number=\"0\" implicit=\"yes\">
+            <attributes><divisions>1</divisions><time><beats>3</beats><beat-type>4</beat-type></time></attributes>
+            <note>
+              <pitch><step>G</step><octave>4</octave></pitch><duration>1</duration><voice>1</voice><type>quarter</type>
+            </note>
+          </measure>
+          <measure number=\"1\">
+            <note><rest measure=\"yes\"/><duration>3</duration><voice>1</voice></note>
+          </measure>
+        </part>
+      </score-partwise>",
+    )
+    .unwrap();
+    let mnx = score_partwise_to_mnx(&score).unwrap();
+    let document = json_parser::parse_from_string(&mnx).unwrap();
+    let parts = document.get("parts").unwrap().as_array();
+    let rest = |part: usize, measure: usize| {
+      parts[part].get("measures").unwrap().as_array()[measure]
+        .get("sequences")
+        .unwrap()
+        .as_array()[0]
+        .get("content")
+        .unwrap()
+        .as_array()[0]
+        .clone()
+    };
+    let pickup = rest(0, 0);
+    assert!(pickup.get("measure").is_none());
+    assert_eq!(
+      pickup.get("duration").unwrap().get("base").unwrap().as_str(),
+      Some("quarter")
+    );
+    assert!(rest(1, 1).get("measure").is_some());
+    let imported = mnx_to_score_partwise(&mnx).unwrap();
+    for part in 0..2 {
+      assert_eq!(part_length(&imported, part), Fraction::from_int(4));
+    }
+  }
+}
@@ -0,0 +1,8 @@
+/// Conversion between MusicXML and the Music Encoding Initiative (MEI) format.
+pub mod mei;
+
+/// Conversion between MusicXML and the Humdrum `**kern` format.
+pub mod kern;
+
+/// Conversion between MusicXML and the JSON representation of the W3C MNX format.
+pub mod mnx;
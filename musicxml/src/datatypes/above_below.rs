@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Used to indicate whether one element appears above or below another element.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum AboveBelow {
   /// This element appears above the reference element.
   Above,
@@ -13,7 +13,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// `smufl` attribute to specify a particular Standard Music Font Layout (SMuFL) accidental.
 ///
 /// The `smufl` attribute may be used with any accidental value to help specify the appearance of symbols that share the same MusicXML semantics.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum AccidentalValue {
   /// <span class="smufl">&#xE262;</span>
   Sharp,
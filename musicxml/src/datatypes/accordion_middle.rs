@@ -12,7 +12,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum allowed value:** 1
 ///
 /// **Maximum allowed value:** 3
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct AccordionMiddle(pub u8);
 
 impl Deref for AccordionMiddle {
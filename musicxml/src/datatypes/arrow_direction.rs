@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Represents the direction in which an arrow points, using Unicode arrow terminology.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum ArrowDirection {
   /// ![Down](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/arrow-direction-down.png)
   Down,
@@ -8,7 +8,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// [Paired][ArrowStyle::Paired] arrows are duplicate [Single][ArrowStyle::Single] arrows in the same direction.
 /// [Combined][ArrowStyle::Combined] arrows apply to [Double][ArrowStyle::Double] direction arrows like left right,
 /// indicating that an arrow in one direction should be combined with an arrow in the other direction.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum ArrowStyle {
   /// ![Combined](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/arrow-style-combined.png)
   Combined,
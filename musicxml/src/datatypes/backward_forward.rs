@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// The start of the repeat has a [Forward][BackwardForward::Forward] direction while
 /// the end of the repeat has a [Backward][BackwardForward::Backward] direction.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum BackwardForward {
   /// ![Backward](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/backward-forward-backward.png)
   Backward,
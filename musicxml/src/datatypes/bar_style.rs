@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Represents barline style information.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum BarStyle {
   /// ![Dashed](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/bar-style-dashed.png)
   Dashed,
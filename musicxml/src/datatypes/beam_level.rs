@@ -9,7 +9,7 @@ use musicxml_macros::DatatypeSerialize;
 /// It does not distinguish overlapping beams, such as grace notes within regular notes, or beams used in different voices.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct BeamLevel(pub u8);
 
 impl Deref for BeamLevel {
@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Represents the type of beam associated with each of 8 beam levels (up to 1024th notes) available for each note.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum BeamValue {
   /// ![BackwardHook](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/beam-value-backward-hook.png)
   #[rename("backward hook")]
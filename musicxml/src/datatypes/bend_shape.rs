@@ -4,7 +4,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Distinguishes between the [Angled][BendShape::Angled] bend symbols commonly used in standard notation
 /// and the [Curved][BendShape::Curved] bend symbols commonly used in both tablature and standard notation.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum BendShape {
   /// ![Angled](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/bend-shape-angled.png)
   Angled,
@@ -2,7 +2,7 @@ use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 
 /// Represents the shape of the caesura sign.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum CaesuraValue {
   /// <span class="smufl">&#xE4D1;</span>
   Normal,
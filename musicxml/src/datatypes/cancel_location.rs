@@ -8,7 +8,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// [BeforeBarline][CancelLocation::BeforeBarline] and to the left. It is [Left][CancelLocation::Left] if not specified.
 ///
 /// For mid-measure key elements, [BeforeBarline][CancelLocation::BeforeBarline] should be treated like [Left][CancelLocation::Left].
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum CancelLocation {
   /// ![Left](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/cancel-location-left.png)
   Left,
@@ -1,6 +1,5 @@
 use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
-use regex::Regex;
 
 /// Indicates the color of an element.
 ///
@@ -14,7 +13,7 @@ use regex::Regex;
 ///
 /// As in [SVG 1.1](https://www.w3.org/TR/SVG11/color.html),
 /// colors are defined in terms of the [sRGB](https://www.color.org/srgb04.xalter) color space (IEC 61966).
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Color {
   /// Amount of red present in the color (0-127)
   pub r: u8,
@@ -50,28 +49,30 @@ impl DatatypeSerializer for Color {
   }
 }
 
+fn parse_color(value: &str) -> Result<Color, &'static str> {
+  let digits = value.strip_prefix('#').ok_or("expected a leading '#' character")?;
+  let mut components = [0_u8; 4];
+  let count = match digits.len() {
+    6 => 3,
+    8 => 4,
+    _ => return Err("expected six or eight hexadecimal digits"),
+  };
+  for (component, pair) in components.iter_mut().zip(digits.as_bytes().chunks_exact(2)) {
+    let value = match (char::from(pair[0]).to_digit(16), char::from(pair[1]).to_digit(16)) {
+      (Some(high), Some(low)) => u8::try_from(high * 16 + low).ok(),
+      _ => None,
+    };
+    *component = value.ok_or("expected only hexadecimal digits")?;
+  }
+  Ok(match count {
+    3 => Color::new(components[0], components[1], components[2], 0),
+    _ => Color::new(components[1], components[2], components[3], components[0]),
+  })
+}
+
 impl DatatypeDeserializer for Color {
   fn deserialize(value: &str) -> Result<Self, String> {
-    let regex = Regex::new(r"#([A-Fa-f0-9]{2})([A-Fa-f0-9]{2})([A-Fa-f0-9]{2})([A-Fa-f0-9]{2})?$").unwrap();
-    if let Some(captures) = regex.captures(value) {
-      if let Some(b) = captures.get(4) {
-        Ok(Color::new(
-          u8::from_str_radix(captures.get(2).unwrap().as_str(), 16).unwrap(),
-          u8::from_str_radix(captures.get(3).unwrap().as_str(), 16).unwrap(),
-          u8::from_str_radix(b.as_str(), 16).unwrap(),
-          u8::from_str_radix(captures.get(1).unwrap().as_str(), 16).unwrap(),
-        ))
-      } else {
-        Ok(Color::new(
-          u8::from_str_radix(captures.get(1).unwrap().as_str(), 16).unwrap(),
-          u8::from_str_radix(captures.get(2).unwrap().as_str(), 16).unwrap(),
-          u8::from_str_radix(captures.get(3).unwrap().as_str(), 16).unwrap(),
-          0,
-        ))
-      }
-    } else {
-      Err(format!("Value {value} is invalid for the <color> data type"))
-    }
+    parse_color(value).map_err(|reason| format!("Value {value} is invalid for the <color> data type: {reason}"))
   }
 }
 
@@ -162,4 +163,16 @@ mod color_tests {
     let result = Color::deserialize("#G123e567");
     assert!(result.is_err());
   }
+
+  #[test]
+  fn deserialize_invalid8() {
+    assert_eq!(
+      Color::deserialize("#A123+5").unwrap_err(),
+      "Value #A123+5 is invalid for the <color> data type: expected only hexadecimal digits"
+    );
+    assert_eq!(
+      Color::deserialize("A123E5").unwrap_err(),
+      "Value A123E5 is invalid for the <color> data type: expected a leading '#' character"
+    );
+  }
 }
@@ -5,7 +5,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 /// Used to specify a comma-separated list of text elements, as is used by the `font_family` attribute.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct CommaSeparatedText(pub Vec<String>);
 
 impl Deref for CommaSeparatedText {
@@ -7,7 +7,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// In CSS, these refer to an entry in a table of font sizes computed and kept by the user agent.
 /// The scaling is relative to the reference value of [Medium][CssFontSize::Medium].
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum CssFontSize {
   /// Scaling factor guideline: 3/5 of [Medium][CssFontSize::Medium].
   #[rename("xx-small")]
@@ -1,9 +1,9 @@
+use super::yyyy_mm_dd::{parse_digits, parse_year_month_day};
 use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
-use regex::Regex;
 
 /// See the definition in the [W3C XML Schema standard](https://www.w3.org/TR/xmlschema-2/#date).
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Date {
   /// The year of the date.
   pub year: u16,
@@ -50,42 +50,40 @@ impl DatatypeSerializer for Date {
   }
 }
 
+fn parse_date(value: &[u8]) -> Result<Date, &'static str> {
+  let (date, timezone) = value
+    .split_at_checked(10)
+    .ok_or("expected a date in YYYY-MM-DD format")?;
+  let (year, month, date) = parse_year_month_day(date)?;
+  let timezone = match timezone {
+    [b'T' | b' ', rest @ ..] => rest,
+    _ => timezone,
+  };
+  let (timezone_hours, timezone_minutes) = match timezone {
+    [] | [b'Z'] => (0, 0),
+    [sign @ (b'+' | b'-'), hours @ .., b':', minute_tens, minute_ones] if hours.len() == 2 => {
+      let hours = parse_digits(hours).ok_or("the timezone hours must consist of two digits")?;
+      let minutes =
+        parse_digits(&[*minute_tens, *minute_ones]).ok_or("the timezone minutes must consist of two digits")?;
+      let hours = i8::try_from(hours)
+        .ok()
+        .filter(|hours| *hours <= 23)
+        .ok_or("the timezone hours must be between 00 and 23")?;
+      let minutes = u8::try_from(minutes)
+        .ok()
+        .filter(|minutes| *minutes <= 59)
+        .ok_or("the timezone minutes must be between 00 and 59")?;
+      (if *sign == b'-' { -hours } else { hours }, minutes)
+    }
+    _ => return Err("expected a timezone in Z, +HH:MM, or -HH:MM format"),
+  };
+  Ok(Date::new(year, month, date, timezone_hours, timezone_minutes))
+}
+
 impl DatatypeDeserializer for Date {
   fn deserialize(value: &str) -> Result<Self, String> {
-    let regex = Regex::new(r"([0-9]{4})-([0-9]{2})-([0-9]{2})[T|\ ]?((([\+\-][0-2][0-9]):([0-5][0-9]))|Z)?").unwrap();
-    if let Some(captures) = regex.captures(value) {
-      let date = if let Some(capture) = captures.get(6) {
-        Date::new(
-          captures.get(1).unwrap().as_str().parse().unwrap(),
-          captures.get(2).unwrap().as_str().parse().unwrap(),
-          captures.get(3).unwrap().as_str().parse().unwrap(),
-          capture.as_str().parse().unwrap(),
-          captures.get(7).unwrap().as_str().parse().unwrap(),
-        )
-      } else {
-        Date::new(
-          captures.get(1).unwrap().as_str().parse().unwrap(),
-          captures.get(2).unwrap().as_str().parse().unwrap(),
-          captures.get(3).unwrap().as_str().parse().unwrap(),
-          0,
-          0,
-        )
-      };
-      if date.month > 0
-        && date.month < 13
-        && date.date > 0
-        && date.date < 32
-        && date.timezone_hours > -24
-        && date.timezone_hours < 24
-        && date.timezone_minutes < 61
-      {
-        Ok(date)
-      } else {
-        Err(format!("Value {value} is invalid for the <date> data type"))
-      }
-    } else {
-      Err(format!("Value {value} is invalid for the <date> data type"))
-    }
+    parse_date(value.as_bytes())
+      .map_err(|reason| format!("Value {value} is invalid for the <date> data type: {reason}"))
   }
 }
 
@@ -97,7 +95,7 @@ mod date_tests {
   fn serialize_valid1() {
     let test = Date {
       year: 2024,
-      month: 01,
+      month: 1,
       date: 23,
       timezone_hours: -2,
       timezone_minutes: 30,
@@ -114,7 +112,7 @@ mod date_tests {
       result.unwrap(),
       Date {
         year: 2024,
-        month: 01,
+        month: 1,
         date: 23,
         timezone_hours: -2,
         timezone_minutes: 30
@@ -131,7 +129,7 @@ mod date_tests {
       Date {
         year: 1971,
         month: 12,
-        date: 01,
+        date: 1,
         timezone_hours: 3,
         timezone_minutes: 0
       }
@@ -147,7 +145,7 @@ mod date_tests {
       Date {
         year: 1971,
         month: 12,
-        date: 01,
+        date: 1,
         timezone_hours: 0,
         timezone_minutes: 0
       }
@@ -162,8 +160,8 @@ mod date_tests {
       result.unwrap(),
       Date {
         year: 2100,
-        month: 09,
-        date: 02,
+        month: 9,
+        date: 2,
         timezone_hours: 0,
         timezone_minutes: 0
       }
@@ -179,7 +177,7 @@ mod date_tests {
       Date {
         year: 1971,
         month: 12,
-        date: 01,
+        date: 1,
         timezone_hours: 0,
         timezone_minutes: 0
       }
@@ -226,4 +224,22 @@ mod date_tests {
     let result = Date::deserialize("2024-12-41 -2:00");
     assert!(result.is_err());
   }
+
+  #[test]
+  fn deserialize_invalid8() {
+    let result = Date::deserialize("2024-02-01T+24:00");
+    assert_eq!(
+      result.unwrap_err(),
+      "Value 2024-02-01T+24:00 is invalid for the <date> data type: the timezone hours must be between 00 and 23"
+    );
+  }
+
+  #[test]
+  fn deserialize_invalid9() {
+    let result = Date::deserialize("2024-02-01 12:00");
+    assert_eq!(
+      result.unwrap_err(),
+      "Value 2024-02-01 12:00 is invalid for the <date> data type: expected a timezone in Z, +HH:MM, or -HH:MM format"
+    );
+  }
 }
@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// See the definition in the [W3C XML Schema standard](https://www.w3.org/TR/xmlschema-2/#decimal).
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub struct Decimal(pub f64);
 
 impl Eq for Decimal {}
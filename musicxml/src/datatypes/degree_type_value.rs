@@ -4,7 +4,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Indicates whether the current degree element is an addition, alteration, or subtraction
 /// to the kind of the current chord in the harmony element.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum DegreeTypeValue {
   /// The degree is an addition to the kind of the current chord.
   Add,
@@ -8,7 +8,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 /// - [Beam][DistanceType::Beam]: The distance between beams.
 /// - [Hyphen][DistanceType::Hyphen]: The distance between hyphens in lyrics.
 /// - [Other][DistanceType::Other]: Left as a string so that other application-specific types can be defined.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum DistanceType {
   /// The distance between beams.
   Beam,
@@ -8,7 +8,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// It is preferred that these be integer values both for MIDI interoperability and to avoid roundoff errors.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub struct Divisions(pub i32);
 
 impl Deref for Divisions {
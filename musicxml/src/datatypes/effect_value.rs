@@ -5,7 +5,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// Represents pictograms for sound effect percussion instruments.
 ///
 /// The cannon, lotus flute, and megaphone values are in addition to Stone's list.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum EffectValue {
   /// <span class="smufl">&#xE701;</span>
   Anvil,
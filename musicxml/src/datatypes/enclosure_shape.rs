@@ -7,7 +7,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// A [Bracket][EnclosureShape::Bracket] enclosure is similar to a rectangle with the bottom line missing,
 /// as is common in jazz notation. An [InvertedBracket][EnclosureShape::InvertedBracket] enclosure is
 /// similar to a rectangle with the top line missing.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum EnclosureShape {
   /// ![Rectangle](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/enclosure-shape-rectangle.png)
   Rectangle,
@@ -2,7 +2,6 @@ use alloc::{string::String, string::ToString, vec::Vec};
 use core::ops::Deref;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::DatatypeSerialize;
-use regex::Regex;
 
 /// Used to specify either a comma-separated list of positive integers without leading zeros, or a string of zero or more spaces.
 ///
@@ -11,7 +10,7 @@ use regex::Regex;
 /// The "zero or more spaces" version is used when software knows that an ending is present, but cannot determine the type of the ending.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct EndingNumber(pub String);
 
 impl Deref for EndingNumber {
@@ -21,16 +20,26 @@ impl Deref for EndingNumber {
   }
 }
 
+fn is_positive_integer(value: &str) -> bool {
+  value.starts_with(|digit: char| ('1'..='9').contains(&digit)) && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
 impl DatatypeDeserializer for EndingNumber {
   fn deserialize(value: &str) -> Result<Self, String> {
-    let space_regex = Regex::new(r"^[ ]*$").unwrap();
-    let integer_regex = Regex::new(r"^[1-9][0-9]*(, ?[1-9][0-9]*)*$").unwrap();
-    if space_regex.is_match(value) {
+    if value.bytes().all(|byte| byte == b' ') {
       Ok(EndingNumber(String::from(value)))
-    } else if integer_regex.is_match(value) {
+    } else if value.split(',').enumerate().all(|(index, number)| {
+      is_positive_integer(if index > 0 {
+        number.strip_prefix(' ').unwrap_or(number)
+      } else {
+        number
+      })
+    }) {
       Ok(EndingNumber(value.split(' ').collect::<Vec<_>>().join("")))
     } else {
-      Err(format!("Value {value} is invalid for the <ending-number> data type"))
+      Err(format!(
+        "Value {value} is invalid for the <ending-number> data type: expected a comma-separated list of positive integers"
+      ))
     }
   }
 }
@@ -96,4 +105,10 @@ mod ending_number_tests {
     let result = EndingNumber::deserialize("23,33,  2");
     assert!(result.is_err());
   }
+
+  #[test]
+  fn deserialize_invalid6() {
+    let result = EndingNumber::deserialize(" 1, 2");
+    assert!(result.is_err());
+  }
 }
@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Represents the type of beam fanning present on a note, used to represent accelerandos and ritardandos.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum Fan {
   /// ![Accelerando](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/fan-accel.png)
   Accel,
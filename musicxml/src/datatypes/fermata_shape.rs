@@ -2,7 +2,7 @@ use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 
 /// Represents the shape of the fermata sign.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum FermataShape {
   /// <span class="smufl">&#xE4C0;</span>
   Normal,
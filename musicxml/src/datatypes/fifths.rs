@@ -9,7 +9,7 @@ use musicxml_macros::DatatypeSerialize;
 /// key's placement within the circle of fifths (hence the type name).
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct Fifths(pub i8);
 
 impl Deref for Fifths {
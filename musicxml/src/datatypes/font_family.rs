@@ -12,7 +12,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 /// The fantasy style refers to decorative text such as found in older German-style printing.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct FontFamily(pub Vec<String>);
 
 impl Deref for FontFamily {
@@ -2,7 +2,7 @@ use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 
 /// Defines what type of glyph is being defined in a [Glyph][crate::elements::Glyph] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum GlyphType {
   /// Specifies the glyph to use when a note has a [Rest][crate::elements::Rest] element and a `type` value of quarter.
   QuarterRest,
@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Indicates how the symbol for a group or multi-staff part is indicated in the score.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum GroupSymbolValue {
   /// ![Brace](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/group-symbol-value-brace.png)
   Brace,
@@ -4,7 +4,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Indicates which portion of the symbol is filled in when the corresponding
 /// [HarmonClosedValue][super::HarmonClosedValue] is [Half][super::HarmonClosedValue::Half].
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum HarmonClosedLocation {
   /// ![Bottom](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/harmon-closed-location-bottom.png)
   Bottom,
@@ -8,7 +8,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// appears to the right of the first. The [Vertical][HarmonyArrangement::Vertical] value specifies
 /// that the second element appears below the first. The [Diagonal][HarmonyArrangement::Diagonal] value
 /// specifies that the second element appears both below and to the right of the first.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum HarmonyArrangement {
   /// ![Horizontal](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/harmony-arrangement-horizontal.png)
   Horizontal,
@@ -4,7 +4,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Indicates which portion of the hole is filled in when the corresponding
 /// [HoleClosedValue][super::HoleClosedValue] is [Half][super::HoleClosedValue::Half].
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum HoleClosedLocation {
   /// ![Bottom](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/hole-closed-location-bottom.png)
   Bottom,
@@ -7,7 +7,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// [Degree][crate::elements::Degree] elements can then add, subtract, or alter from these starting points.
 ///
 /// The 11th and 13th values are usually used as a basis for alteration.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum KindValue {
   /// Triad: major third, augmented fifth.
   Augmented,
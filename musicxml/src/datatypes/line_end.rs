@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Specifies if there is a jog up or down (or both), an arrow, or nothing at the start or end of a bracket.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum LineEnd {
   /// ![Up](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/line-end-up.png)
   Up,
@@ -5,7 +5,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// Distinguishes between different line lengths for [Doit][crate::elements::Doit],
 /// [Falloff][crate::elements::Falloff], [Plop][crate::elements::Plop],
 /// and [Scoop][crate::elements::Scoop] articulations.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum LineLength {
   /// <span class="smufl">&#xE5DD;</span>
   Short,
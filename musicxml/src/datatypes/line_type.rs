@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Distinguishes between solid, dashed, dotted, and wavy lines.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum LineType {
   /// ![Dashed](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/line-type-dashed.png)
   Dashed,
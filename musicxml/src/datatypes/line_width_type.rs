@@ -2,7 +2,7 @@ use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 
 /// Defines what type of line is being defined in a [LineWidth][crate::elements::LineWidth] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum LineWidthType {
   /// A beam line.
   Beam,
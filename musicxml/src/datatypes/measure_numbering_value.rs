@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Describes how measure numbers are displayed on this part: no numbers, numbers every measure, or numbers every system.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum MeasureNumberingValue {
   /// ![None](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/measure-numbering-value-none.png)
   None,
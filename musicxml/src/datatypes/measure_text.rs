@@ -11,7 +11,7 @@ use musicxml_macros::DatatypeSerialize;
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
 ///
 /// **Minimum Length**: 1
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct MeasureText(pub String);
 
 impl Deref for MeasureText {
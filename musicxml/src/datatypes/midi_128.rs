@@ -12,7 +12,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum Value**: 1
 ///
 /// **Maximum Value**: 128
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct Midi128(pub u8);
 
 impl Deref for Midi128 {
@@ -10,7 +10,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum Value**: 1
 ///
 /// **Maximum Value**: 16
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct Midi16(pub u8);
 
 impl Deref for Midi16 {
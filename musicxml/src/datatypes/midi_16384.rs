@@ -12,7 +12,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum Value**: 1
 ///
 /// **Maximum Value**: 16384
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct Midi16384(pub u16);
 
 impl Deref for Midi16384 {
@@ -8,7 +8,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// This is used in the [Scaling][crate::elements::Scaling] element to provide a default scaling from tenths to physical units.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub struct Millimeters(pub f64);
 
 impl Eq for Millimeters {}
@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// The [On][Mute::On] and [Off][Mute::Off] values are used for undifferentiated mutes.
 /// The remaining values represent specific mutes.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum Mute {
   /// Represents an on-mute.
   On,
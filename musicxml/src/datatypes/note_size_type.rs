@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Indicates the type of note size being defined by a [NoteSize][crate::elements::NoteSize] element.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum NoteSizeType {
   /// Used for notes of cue size that do not include a [Grace][crate::elements::Grace] element, whether defined explicitly or implicitly via a [Cue][crate::elements::Cue] element.
   Cue,
@@ -4,7 +4,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Used for the MusicXML [Type][crate::elements::Type] element and represents the graphic note type,
 /// from 1024th (shortest) to maxima (longest).
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum NoteTypeValue {
   /// ![Maxima](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/note-type-maxima.png)
   Maxima,
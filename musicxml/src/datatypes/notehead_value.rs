@@ -19,7 +19,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// that share the same MusicXML semantics. Noteheads in the SMuFL Note name noteheads and Note name noteheads
 /// supplement ranges (U+E150–U+E1AF and U+EEE0–U+EEFF) should not use the `smufl` attribute or the "other" value,
 /// but instead use the [NoteheadText][crate::elements::NoteheadText] element.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum NoteheadValue {
   /// ![ArrowDown](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/notehead-value-arrow-down.png)
   #[rename("arrow down")]
@@ -34,7 +34,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum value**: 1
 ///
 /// **Maximum value**: 16
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct NumberLevel(pub u8);
 
 impl Deref for NumberLevel {
@@ -4,7 +4,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 /// Can be either a decimal number or the string "normal".
 ///
 /// This is used by the `line_height` and `letter_spacing` attributes.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum NumberOrNormal {
   /// Represents the string "normal".
   Normal,
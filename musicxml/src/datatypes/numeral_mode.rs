@@ -9,7 +9,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// melodic minor value sharpens both 6 and 7. If a minor mode is used without
 /// qualification, either in the [Mode][crate::elements::Mode] or [NumeralMode][crate::elements::NumeralMode]
 /// elements, natural minor is used.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum NumeralMode {
   /// Numerals are interpreted relative to a harmonic minor scale with a raised 7th degree.
   #[rename("harmonic minor")]
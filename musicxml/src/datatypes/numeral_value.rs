@@ -10,7 +10,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum value**: 1
 ///
 /// **Maximum value**: 7
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct NumeralValue(pub u8);
 
 impl Deref for NumeralValue {
@@ -6,7 +6,7 @@ use musicxml_macros::DatatypeSerialize;
 /// Represents octaves by the numbers 0 to 9, where 4 indicates the octave started by middle C.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct Octave(pub u8);
 
 impl Deref for Octave {
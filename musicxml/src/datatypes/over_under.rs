@@ -4,7 +4,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Used to indicate whether the tips of curved lines such as slurs and ties
 /// are overhand (tips down) or underhand (tips up).
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum OverUnder {
   /// Tips of curved lines are overhand (tips down).
   Over,
@@ -10,7 +10,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum value:** 0.0
 ///
 /// **Maximum value:** 100.0
-#[derive(Debug, PartialEq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Clone, DatatypeSerialize)]
 pub struct Percent(pub f64);
 
 impl Eq for Percent {}
@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// The [Chimes][PitchedValue::Chimes] and [TubularChimes][PitchedValue::TubularChimes] values
 /// distinguish the single-line and double-line versions of the pictogram.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum PitchedValue {
   /// <span class="smufl">&#xE6B0;</span>
   Celesta,
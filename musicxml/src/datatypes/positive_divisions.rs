@@ -8,7 +8,7 @@ use musicxml_macros::DatatypeSerialize;
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
 ///
 /// **Minimum value**: 1
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct PositiveDivisions(pub u32);
 
 impl Deref for PositiveDivisions {
@@ -2,7 +2,7 @@ use alloc::string::{String, ToString};
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 
 /// Represents a positive integer or an empty value.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum PositiveIntegerOrEmpty {
   /// Represents an empty value.
   Empty,
@@ -12,7 +12,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum value:** -180.0
 ///
 /// **Maximum value:** 180.0
-#[derive(Debug, PartialEq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Clone, DatatypeSerialize)]
 pub struct RotationDegrees(pub f32);
 
 impl Eq for RotationDegrees {}
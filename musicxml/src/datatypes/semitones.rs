@@ -9,7 +9,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// Decimal values like 0.5 (quarter tone sharp) are used for microtones.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub struct Semitones(pub i16);
 
 impl Deref for Semitones {
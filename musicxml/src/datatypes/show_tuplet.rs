@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// [TupletActual][crate::elements::TupletActual] element, both the
 /// [TupletActual][crate::elements::TupletActual] or [TupletNormal][crate::elements::TupletNormal]
 /// elements, or neither.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum ShowTuplet {
   /// Show only the [TupletActual][crate::elements::TupletActual] element.
   Actual,
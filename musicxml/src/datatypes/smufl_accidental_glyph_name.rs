@@ -3,7 +3,6 @@ use alloc::string::{String, ToString};
 use core::ops::Deref;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::DatatypeSerialize;
-use regex::Regex;
 
 /// Used to reference a specific Standard Music Font Layout (SMuFL) accidental character.
 ///
@@ -11,7 +10,7 @@ use regex::Regex;
 /// used at the start of glyph names for SMuFL accidentals.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct SmuflAccidentalGlyphName(pub String);
 
 impl Deref for SmuflAccidentalGlyphName {
@@ -21,17 +20,16 @@ impl Deref for SmuflAccidentalGlyphName {
   }
 }
 
+const ACCIDENTAL_PREFIXES: [&str; 5] = ["acc", "medRenFla", "medRenNatura", "medRenShar", "kievanAccidental"];
+
 impl DatatypeDeserializer for SmuflAccidentalGlyphName {
   fn deserialize(value: &str) -> Result<Self, String> {
     if let Ok(token) = SmuflGlyphName::deserialize(value) {
-      if Regex::new(r"^(acc|medRenFla|medRenNatura|medRenShar|kievanAccidental).*$")
-        .unwrap()
-        .is_match(&token)
-      {
+      if ACCIDENTAL_PREFIXES.iter().any(|prefix| token.starts_with(prefix)) {
         Ok(SmuflAccidentalGlyphName((*token).clone()))
       } else {
         Err(format!(
-          "Value {value} is invalid for the <smufl-accidental-glyph-name> data type"
+          "Value {value} is invalid for the <smufl-accidental-glyph-name> data type: expected a name beginning with one of {ACCIDENTAL_PREFIXES:?}"
         ))
       }
     } else {
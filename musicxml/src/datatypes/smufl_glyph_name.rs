@@ -9,7 +9,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// standard piano pedal mark would be "keyboardPedalPed", not U+E650.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub struct SmuflGlyphName(pub String);
 
 impl Deref for SmuflGlyphName {
@@ -3,14 +3,13 @@ use alloc::string::{String, ToString};
 use core::ops::Deref;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::DatatypeSerialize;
-use regex::Regex;
 
 /// Used to reference a specific Standard Music Font Layout (SMuFL) lyrics elision character.
 ///
 /// The value is a SMuFL canonical glyph name that starts with lyrics.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct SmuflLyricsGlyphName(pub String);
 
 impl Deref for SmuflLyricsGlyphName {
@@ -23,11 +22,11 @@ impl Deref for SmuflLyricsGlyphName {
 impl DatatypeDeserializer for SmuflLyricsGlyphName {
   fn deserialize(value: &str) -> Result<Self, String> {
     if let Ok(token) = SmuflGlyphName::deserialize(value) {
-      if Regex::new(r"^lyrics.*$").unwrap().is_match(&token) {
+      if token.starts_with("lyrics") {
         Ok(SmuflLyricsGlyphName((*token).clone()))
       } else {
         Err(format!(
-          "Value {value} is invalid for the <smufl-lyrics-glyph-name> data type"
+          "Value {value} is invalid for the <smufl-lyrics-glyph-name> data type: expected a name beginning with \"lyrics\""
         ))
       }
     } else {
@@ -3,14 +3,13 @@ use alloc::string::{String, ToString};
 use core::ops::Deref;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::DatatypeSerialize;
-use regex::Regex;
 
 /// Used to reference a specific Standard Music Font Layout (SMuFL) percussion pictogram character.
 ///
 /// The value is a SMuFL canonical glyph name that starts with pict.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct SmuflPictogramGlyphName(pub String);
 
 impl Deref for SmuflPictogramGlyphName {
@@ -23,11 +22,11 @@ impl Deref for SmuflPictogramGlyphName {
 impl DatatypeDeserializer for SmuflPictogramGlyphName {
   fn deserialize(value: &str) -> Result<Self, String> {
     if let Ok(token) = SmuflGlyphName::deserialize(value) {
-      if Regex::new(r"^pict.*$").unwrap().is_match(&token) {
+      if token.starts_with("pict") {
         Ok(SmuflPictogramGlyphName((*token).clone()))
       } else {
         Err(format!(
-          "Value {value} is invalid for the <smufl-pictogram-glyph-name>e data type"
+          "Value {value} is invalid for the <smufl-pictogram-glyph-name> data type: expected a name beginning with \"pict\""
         ))
       }
     } else {
@@ -59,4 +58,13 @@ mod smufl_pictogram_glyph_name_tests {
     let result = SmuflPictogramGlyphName::deserialize("pica");
     assert!(result.is_err());
   }
+
+  #[test]
+  fn deserialize_invalid2() {
+    let result = SmuflPictogramGlyphName::deserialize("drumPict");
+    assert_eq!(
+      result.unwrap_err(),
+      "Value drumPict is invalid for the <smufl-pictogram-glyph-name> data type: expected a name beginning with \"pict\""
+    );
+  }
 }
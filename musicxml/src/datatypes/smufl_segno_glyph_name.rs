@@ -3,14 +3,13 @@ use alloc::string::{String, ToString};
 use core::ops::Deref;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::DatatypeSerialize;
-use regex::Regex;
 
 /// Used to reference a specific Standard Music Font Layout (SMuFL) segno character.
 ///
 /// The value is a SMuFL canonical glyph name that starts with segno.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct SmuflSegnoGlyphName(pub String);
 
 impl Deref for SmuflSegnoGlyphName {
@@ -23,11 +22,11 @@ impl Deref for SmuflSegnoGlyphName {
 impl DatatypeDeserializer for SmuflSegnoGlyphName {
   fn deserialize(value: &str) -> Result<Self, String> {
     if let Ok(token) = SmuflGlyphName::deserialize(value) {
-      if Regex::new(r"^segno.*$").unwrap().is_match(&token) {
+      if token.starts_with("segno") {
         Ok(SmuflSegnoGlyphName((*token).clone()))
       } else {
         Err(format!(
-          "Value {value} is invalid for the <smufl-segno-glyph-name> data type"
+          "Value {value} is invalid for the <smufl-segno-glyph-name> data type: expected a name beginning with \"segno\""
         ))
       }
     } else {
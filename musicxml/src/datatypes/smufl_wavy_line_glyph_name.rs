@@ -3,7 +3,6 @@ use alloc::string::{String, ToString};
 use core::ops::Deref;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::DatatypeSerialize;
-use regex::Regex;
 
 /// Used to reference a specific Standard Music Font Layout (SMuFL) wavy line character.
 ///
@@ -12,7 +11,7 @@ use regex::Regex;
 /// lines range, excluding the beam glyphs.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct SmuflWavyLineGlyphName(pub String);
 
 impl Deref for SmuflWavyLineGlyphName {
@@ -22,17 +21,28 @@ impl Deref for SmuflWavyLineGlyphName {
   }
 }
 
+fn is_wavy_line_name(name: &str) -> bool {
+  let is_alphanumeric = |rest: &str| rest.bytes().all(|byte| byte.is_ascii_alphanumeric());
+  if let Some(rest) = name.strip_prefix("wiggle") {
+    is_alphanumeric(rest)
+  } else if let Some(rest) = name
+    .strip_prefix("guitar")
+    .and_then(|rest| rest.strip_suffix("VibratoStroke"))
+  {
+    is_alphanumeric(rest)
+  } else {
+    false
+  }
+}
+
 impl DatatypeDeserializer for SmuflWavyLineGlyphName {
   fn deserialize(value: &str) -> Result<Self, String> {
     if let Ok(token) = SmuflGlyphName::deserialize(value) {
-      if Regex::new(r"(^wiggle[a-zA-Z0-9]*$)|(^guitar[a-zA-Z0-9]*VibratoStroke$)")
-        .unwrap()
-        .is_match(&token)
-      {
+      if is_wavy_line_name(&token) {
         Ok(SmuflWavyLineGlyphName((*token).clone()))
       } else {
         Err(format!(
-          "Value {value} is invalid for the <smufl-wavy-glyph-name> data type"
+          "Value {value} is invalid for the <smufl-wavy-glyph-name> data type: expected a \"wiggle\" name or a \"guitar\" name ending in \"VibratoStroke\""
         ))
       }
     } else {
@@ -9,7 +9,7 @@ use musicxml_macros::DatatypeSerialize;
 /// Staff lines are numbered from bottom to top, with 1 being the bottom line on a staff.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct StaffLine(pub u8);
 
 impl Deref for StaffLine {
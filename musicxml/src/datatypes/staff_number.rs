@@ -9,7 +9,7 @@ use musicxml_macros::DatatypeSerialize;
 /// Staves are numbered from top to bottom, with 1 being the top staff on a part.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct StaffNumber(pub u8);
 
 impl Deref for StaffNumber {
@@ -12,7 +12,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// When multiple elements with the same tag are used within the same note, their order within the MusicXML
 /// document should match the musical score order.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum StartStop {
   /// Starting point of an element.
   Start,
@@ -15,7 +15,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// match the musical score order. For example, a note that marks both the end of one slur and the start of a new slur
 /// should have the incoming slur element with a type of [Stop][StartStopContinue::Stop] precede the outgoing slur element
 /// with a type of [Start][StartStopContinue::Start].
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum StartStopContinue {
   /// Starting point of an element.
   Start,
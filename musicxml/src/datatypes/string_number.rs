@@ -9,7 +9,7 @@ use musicxml_macros::DatatypeSerialize;
 /// Strings are numbered from high to low, with 1 being the highest pitched full-length string.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct StringNumber(pub u8);
 
 impl Deref for StringNumber {
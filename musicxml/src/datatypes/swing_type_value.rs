@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Specifies the note type, either eighth or 16th, to which the ratio defined in the [Swing][crate::elements::Swing] element is applied.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum SwingTypeValue {
   /// The [Swing][crate::elements::Swing] ratio applies to 16th notes.
   #[rename("16th")]
@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// The single, begin, end, and middle values represent single-syllable words,
 /// word-beginning syllables, word-ending syllables, and mid-word syllables, respectively.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum Syllabic {
   /// ![Begin](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/syllabic-begin.png)
   Begin,
@@ -17,7 +17,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// [StaffSize][crate::elements::StaffSize] element.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub struct Tenths(pub f64);
 
 impl Eq for Tenths {}
@@ -14,7 +14,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// In start-stop cases, ties can add more elements using a [Continue][TiedType::Continue] type. This is
 /// typically used to specify the formatting of cross-system ties.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum TiedType {
   /// Start of a tie.
   Start,
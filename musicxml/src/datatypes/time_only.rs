@@ -13,7 +13,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 /// through the repeated section that the element applies.
 ///
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct TimeOnly(pub Vec<u8>);
 
 impl Deref for TimeOnly {
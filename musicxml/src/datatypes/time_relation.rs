@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Indicates the symbol used to represent the interchangeable aspect of dual time signatures.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum TimeRelation {
   /// ![Bracket](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/time-relation-bracket.png)
   Bracket,
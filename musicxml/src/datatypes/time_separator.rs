@@ -12,7 +12,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// side of the separator line. The [None][TimeSeparator::None] value represents no separator with the beats
 /// and beat-type arranged vertically. The [Adjacent][TimeSeparator::Adjacent] value represents no
 /// separator with the beats and beat-type arranged horizontally.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum TimeSeparator {
   /// ![Adjacent](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/time-separator-adjacent.png)
   Adjacent,
@@ -13,7 +13,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// should be represented with a dotted downstem note that corresponds to three times the
 /// [BeatType][crate::elements::BeatType] value, and a numerator that is one third the
 /// [Beats][crate::elements::Beats] value.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum TimeSymbol {
   /// ![Common](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/time-symbol-common.png)
   Common,
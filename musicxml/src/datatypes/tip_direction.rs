@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Represents the direction in which the tip of a stick or beater points, using Unicode arrow terminology.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum TipDirection {
   /// ![Down](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/tip-direction-down.png)
   Down,
@@ -10,7 +10,7 @@ use musicxml_macros::DatatypeSerialize;
 /// **Minimum value:** 0
 ///
 /// **Maximum value:** 8
-#[derive(Debug, PartialEq, Eq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeSerialize)]
 pub struct TremoloMarks(pub u8);
 
 impl Deref for TremoloMarks {
@@ -10,7 +10,7 @@ use musicxml_macros::DatatypeSerialize;
 /// The value of an instance of this type may be accessed by dereferencing the struct: `*datatype_val`.
 ///
 /// **Minimum value**: 2.0
-#[derive(Debug, PartialEq, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Clone, DatatypeSerialize)]
 pub struct TrillBeats(pub f64);
 
 impl Eq for TrillBeats {}
@@ -3,7 +3,7 @@ use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 
 /// Used for octave-shift elements, indicating the direction of the shift from their true pitched values because of printing difficulty.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum UpDownStopContinue {
   /// Start of an [OctaveShift][crate::elements::OctaveShift] up, such as 8va bassa.
   Up,
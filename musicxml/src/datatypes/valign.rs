@@ -5,7 +5,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// Used to indicate vertical alignment to the top, middle, bottom, or baseline of the text.
 ///
 /// Defaults are implementation-dependent.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum Valign {
   /// Aligned to the top of the text.
   Top,
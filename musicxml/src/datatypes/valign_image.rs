@@ -5,7 +5,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// Used to indicate vertical alignment for images and graphics, so it does not include a baseline value.
 ///
 /// Defaults are implementation-dependent.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum ValignImage {
   /// Aligned to the top of the image.
   Top,
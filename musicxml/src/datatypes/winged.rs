@@ -7,7 +7,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 /// The [Straight][Winged::Straight] and [Curved][Winged::Curved] values represent single wings, while the
 /// [DoubleStraight][Winged::DoubleStraight] and [DoubleCurved][Winged::DoubleCurved] values represent
 /// double wings. The [None][Winged::None] value indicates no wings and is the default.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum Winged {
   /// ![None](https://hedgetechllc.github.io/musicxml/musicxml/datatypes/winged-none.png)
   None,
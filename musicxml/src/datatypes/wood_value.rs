@@ -6,7 +6,7 @@ use musicxml_macros::{DatatypeDeserialize, DatatypeSerialize};
 ///
 /// The [Maraca][WoodValue::Maraca] and [Maracas][WoodValue::Maracas] values distinguish
 /// the one- and two-maraca versions of the pictogram.
-#[derive(Debug, PartialEq, Eq, DatatypeDeserialize, DatatypeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, DatatypeDeserialize, DatatypeSerialize)]
 pub enum WoodValue {
   /// <span class="smufl">&#xE6FB;</span>
   #[rename("bamboo scraper")]
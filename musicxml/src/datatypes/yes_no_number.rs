@@ -3,7 +3,7 @@ use alloc::string::{String, ToString};
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
 
 /// Used for attributes that can be either boolean or numeric values.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum YesNoNumber {
   /// The value is `yes`.
   Yes,
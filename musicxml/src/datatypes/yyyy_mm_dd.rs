@@ -1,11 +1,10 @@
 use alloc::string::String;
 use musicxml_internal::{DatatypeDeserializer, DatatypeSerializer};
-use regex::Regex;
 
 /// Represents dates in the yyyy-mm-dd format, following ISO 8601.
 ///
 /// This is a W3C XML Schema date type, but without the optional timezone data.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct YyyyMmDd {
   /// The year.
   pub year: u16,
@@ -35,23 +34,38 @@ impl DatatypeSerializer for YyyyMmDd {
   }
 }
 
+pub(super) fn parse_digits(digits: &[u8]) -> Option<u16> {
+  digits.iter().try_fold(0_u16, |total, &digit| {
+    digit.is_ascii_digit().then(|| total * 10 + u16::from(digit - b'0'))
+  })
+}
+
+pub(super) fn parse_year_month_day(value: &[u8]) -> Result<(u16, u8, u8), &'static str> {
+  let [year @ .., b'-', month1, month2, b'-', day1, day2] = value else {
+    return Err("expected a date in YYYY-MM-DD format");
+  };
+  let year = match year {
+    [_, _, _, _] => parse_digits(year).ok_or("the year must consist of four digits")?,
+    _ => return Err("expected a date in YYYY-MM-DD format"),
+  };
+  let month = parse_digits(&[*month1, *month2]).ok_or("the month must consist of two digits")?;
+  let day = parse_digits(&[*day1, *day2]).ok_or("the day must consist of two digits")?;
+  let month = u8::try_from(month)
+    .ok()
+    .filter(|month| (1..=12).contains(month))
+    .ok_or("the month must be between 01 and 12")?;
+  let day = u8::try_from(day)
+    .ok()
+    .filter(|day| (1..=31).contains(day))
+    .ok_or("the day must be between 01 and 31")?;
+  Ok((year, month, day))
+}
+
 impl DatatypeDeserializer for YyyyMmDd {
   fn deserialize(value: &str) -> Result<Self, String> {
-    let regex = Regex::new(r"([0-9]{4})-([0-9]{2})-([0-9]{2})$").unwrap();
-    if let Some(captures) = regex.captures(value) {
-      let date = YyyyMmDd::new(
-        captures.get(1).unwrap().as_str().parse::<u16>().unwrap(),
-        captures.get(2).unwrap().as_str().parse::<u8>().unwrap(),
-        captures.get(3).unwrap().as_str().parse::<u8>().unwrap(),
-      );
-      if date.month > 0 && date.month < 13 && date.date > 0 && date.date < 32 {
-        Ok(date)
-      } else {
-        Err(format!("Value {value} is invalid for the <yyyy-mm-dd> data type"))
-      }
-    } else {
-      Err(format!("Value {value} is invalid for the <yyyy-mm-dd> data type"))
-    }
+    parse_year_month_day(value.as_bytes())
+      .map(|(year, month, date)| YyyyMmDd::new(year, month, date))
+      .map_err(|reason| format!("Value {value} is invalid for the <yyyy-mm-dd> data type: {reason}"))
   }
 }
 
@@ -67,7 +81,7 @@ mod yyyy_mm_dd_tests {
       result.unwrap(),
       YyyyMmDd {
         year: 2024,
-        month: 01,
+        month: 1,
         date: 23
       }
     );
@@ -82,7 +96,7 @@ mod yyyy_mm_dd_tests {
       YyyyMmDd {
         year: 1971,
         month: 12,
-        date: 01
+        date: 1
       }
     );
   }
@@ -110,4 +124,14 @@ mod yyyy_mm_dd_tests {
     let result = YyyyMmDd::deserialize("2024-12-41");
     assert!(result.is_err());
   }
+
+  #[test]
+  fn deserialize_invalid_reason() {
+    assert_eq!(
+      YyyyMmDd::deserialize("2024-00-10").unwrap_err(),
+      "Value 2024-00-10 is invalid for the <yyyy-mm-dd> data type: the month must be between 01 and 12"
+    );
+    assert!(YyyyMmDd::deserialize("x2024-01-10").is_err());
+    assert!(YyyyMmDd::deserialize("2024-01-1é").is_err());
+  }
 }
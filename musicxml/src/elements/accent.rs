@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Accent] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AccentAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct AccentAttributes {
 /// The [Accent] element indicates a regular horizontal accent mark.
 ///
 /// ![Accent](https://hedgetechllc.github.io/musicxml/musicxml/elements/accent.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Accent {
   /// Element-specific attributes
   pub attributes: AccentAttributes,
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [AccidentalAttributes] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AccidentalAttributes {
   /// Specifies whether or not brackets are put around a symbol for an editorial indication. If not specified, it is left to application defaults.
   pub bracket: Option<YesNo>,
@@ -56,7 +56,7 @@ pub struct AccidentalAttributes {
 /// The [Accidental] element represents actual notated accidentals.
 ///
 /// ![Accidental](https://hedgetechllc.github.io/musicxml/musicxml/elements/accidental.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Accidental {
   /// Element-specific attributes
   pub attributes: AccidentalAttributes,
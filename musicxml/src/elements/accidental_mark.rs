@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [AccidentalMark] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AccidentalMarkAttributes {
   /// Specifies whether or not brackets are put around a symbol for an editorial indication. If not specified, it is left to application defaults.
   pub bracket: Option<YesNo>,
@@ -58,7 +58,7 @@ pub struct AccidentalMarkAttributes {
 /// ![AccidentalMark](https://hedgetechllc.github.io/musicxml/musicxml/elements/accidental-mark.png)
 ///
 /// When used in an ornament, position and placement are relative to the ornament, not relative to the note.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("accidental-mark")]
 pub struct AccidentalMark {
   /// Element-specific attributes
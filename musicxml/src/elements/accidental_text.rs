@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [AccidentalText] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AccidentalTextAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -91,7 +91,7 @@ pub struct AccidentalTextAttributes {
 /// The [AccidentalText] element is used for exact formatting of accidentals in display elements such as [PartNameDisplay][super::PartNameDisplay].
 ///
 /// The` enclosure` attribute is none if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("accidental-text")]
 pub struct AccidentalText {
   /// Element-specific attributes
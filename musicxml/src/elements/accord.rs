@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Accord] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AccordAttributes {
   /// Strings are numbered from high to low.
   pub string: Option<StringNumber>,
 }
 
 /// Contents of the [Accord] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct AccordContents {
   /// Step on a diatonic scale, represented using the English letters A through G.
   pub tuning_step: TuningStep,
@@ -25,7 +25,7 @@ pub struct AccordContents {
 /// The [Accord] element represents the tuning of a single string in the [Scordatura][super::Scordatura] element.
 ///
 /// It uses the same group of elements as the [StaffTuning][super::StaffTuning] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Accord {
   /// Element-specific attributes
   pub attributes: AccordAttributes,
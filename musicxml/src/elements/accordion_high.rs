@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// ![AccordionHigh](https://hedgetechllc.github.io/musicxml/musicxml/elements/accordion-high.png)
 ///
 /// This element is omitted if no dot is present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("accordion-high")]
 pub struct AccordionHigh {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// ![AccordionLow](https://hedgetechllc.github.io/musicxml/musicxml/elements/accordion-low.png)
 ///
 /// This element is omitted if no dot is present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("accordion-low")]
 pub struct AccordionLow {
   /// Element-specific attributes
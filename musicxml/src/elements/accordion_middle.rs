@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// ![AccordionMiddle](https://hedgetechllc.github.io/musicxml/musicxml/elements/accordion-middle.png)
 ///
 /// This element is omitted if no dots are present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("accordion-middle")]
 pub struct AccordionMiddle {
   /// Element-specific attributes
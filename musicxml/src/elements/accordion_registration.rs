@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [AccordionRegistration] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AccordionRegistrationAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -56,7 +56,7 @@ pub struct AccordionRegistrationAttributes {
 }
 
 /// Contents of the [AccordionRegistration] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct AccordionRegistrationContents {
   /// The [AccordionHigh] element represents the presence of one or more dots in the high section of the registration symbol.
   pub accordion_high: Option<AccordionHigh>,
@@ -71,7 +71,7 @@ pub struct AccordionRegistrationContents {
 /// These are circular symbols divided horizontally into high, middle, and low sections that correspond to 4', 8', and 16' pipes.
 /// Each [AccordionHigh], [AccordionLow], and [AccordionMiddle] element represents the presence of one or more dots in the registration diagram.
 /// An [AccordionRegistration] element needs to have at least one of the child elements present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("accordion-registration")]
 pub struct AccordionRegistration {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [ActualNotes] element describes how many notes are played in the time usually occupied by the number in the [NormalNotes][super::NormalNotes] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("actual-notes")]
 pub struct ActualNotes {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// The [Alter] element represents chromatic alteration in number of semitones (e.g., -1 for flat, 1 for sharp).
 ///
 /// Decimal values like 0.5 (quarter tone sharp) are used for microtones.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Alter {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Appearance] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct AppearanceContents {
   /// The [LineWidth] element specifies the width of a line type in tenths.
   pub line_width: Vec<LineWidth>,
@@ -22,7 +22,7 @@ pub struct AppearanceContents {
 ///
 /// This includes support for line widths, definitions for note sizes, standard distances between notation elements, and Standard Music Font Layout (SMuFL) glyphs,
 /// plus an extension element for other aspects of appearance.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Appearance {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Arpeggiate] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ArpeggiateAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -46,7 +46,7 @@ pub struct ArpeggiateAttributes {
 ///
 /// The length of the sign can be determined from the position attributes for the [Arpeggiate] elements used with the
 /// top and bottom notes of the arpeggiated chord.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Arpeggiate {
   /// Element-specific attributes
   pub attributes: ArpeggiateAttributes,
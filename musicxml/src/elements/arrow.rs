@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Arrow] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ArrowAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct ArrowAttributes {
 }
 
 /// Contents of the [StraightArrowContents] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct StraightArrowContents {
   /// The [ArrowDirection] element specifies the direction of the arrow.
   pub arrow_direction: ArrowDirection,
@@ -55,7 +55,7 @@ pub struct StraightArrowContents {
 }
 
 /// Contents of the [CircularArrowContents] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct CircularArrowContents {
   /// The [CircularArrow] element specifies the direction of the circular arrow.
   pub circular_arrow: CircularArrow,
@@ -64,7 +64,7 @@ pub struct CircularArrowContents {
 /// Contents of the [Arrow] element.
 ///
 /// The [Arrow] element may contain either [StraightArrowContents] or [CircularArrowContents].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum ArrowContents {
   /// The [StraightArrowContents] element specifies the direction, style, and arrowhead of a straight arrow.
   Straight(StraightArrowContents),
@@ -73,7 +73,7 @@ pub enum ArrowContents {
 }
 
 impl ContentDeserializer for ArrowContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     if elements.iter().any(|el| el.name == "circular-arrow") {
       Ok(ArrowContents::Circular(CircularArrowContents::deserialize(elements)?))
     } else {
@@ -83,7 +83,7 @@ impl ContentDeserializer for ArrowContents {
 }
 
 impl ContentSerializer for ArrowContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     match element {
       ArrowContents::Circular(contents) => CircularArrowContents::serialize(contents),
       ArrowContents::Straight(contents) => StraightArrowContents::serialize(contents),
@@ -98,7 +98,7 @@ impl ContentSerializer for ArrowContents {
 /// It can represent both Unicode and Standard Music Font Layout (SMuFL) arrows.
 /// The `smufl` attribute distinguishes different SMuFL glyphs that have an arrow appearance such as "arrowBlackUp,"
 /// "guitarStrumUp," or "handbellsSwingUp." The specified glyph should match the descriptive representation.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Arrow {
   /// Element-specific attributes
   pub attributes: ArrowAttributes,
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [ArrowDirection] element represents the direction in which an arrow points, using Unicode arrow terminology.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("arrow-direction")]
 pub struct ArrowDirection {
   /// Element-specific attributes
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [ArrowStyle] element represents the style of an arrow, using Unicode arrow terminology.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("arrow-style")]
 pub struct ArrowStyle {
   /// Element-specific attributes
@@ -8,14 +8,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Articulations] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ArticulationsAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
 }
 
 /// The [ArticulationsType] element specifies all possible articulations and accents available for use in an [Articulations] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum ArticulationsType {
   /// The [Accent] element indicates a regular accent mark.
   Accent(Accent),
@@ -61,7 +61,7 @@ pub enum ArticulationsType {
 /// The [Articulations] element groups together articulations and accents.
 ///
 /// ![Articulations](https://hedgetechllc.github.io/musicxml/musicxml/elements/articulations.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Articulations {
   /// Element-specific attributes
   pub attributes: ArticulationsAttributes,
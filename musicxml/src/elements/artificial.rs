@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Artificial] element indicates that this is an artificial harmonic.
 ///
 /// ![Artificial](https://hedgetechllc.github.io/musicxml/musicxml/elements/artificial.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Artificial {
   /// Element-specific attributes
   pub attributes: (),
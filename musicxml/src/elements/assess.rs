@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Assess] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct AssessAttributes {
   /// If yes, the note should be assessed; if no, it should not be assessed.
   /// If not specified, it is no for notes with a [Cue][super::Cue] child element and yes otherwise.
@@ -32,7 +32,7 @@ impl Default for AssessAttributes {
 ///
 /// By default, an assessment application should assess all notes without a [Cue][super::Cue] child element,
 /// and not assess any note with a [Cue][super::Cue] child element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Assess {
   /// Element-specific attributes
   pub attributes: AssessAttributes,
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Attributes] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct AttributesContents {
   /// The [Footnote] element specifies editorial information or lyrics content.
   pub footnote: Option<Footnote>,
@@ -45,7 +45,7 @@ pub struct AttributesContents {
 ///
 /// This includes key and time signatures, clefs, transpositions, and staving. When attributes are changed mid-measure,
 /// it affects the music in score order, not in MusicXML document order.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Attributes {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Backup] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct BackupContents {
   /// The [Duration] element specifies the number of divisions to move back.
   pub duration: Duration,
@@ -20,7 +20,7 @@ pub struct BackupContents {
 ///
 /// The [Backup] element is generally used to move between voices and staves. Thus it does not include [Voice][super::Voice] or [Staff][super::Staff] elements.
 /// Duration values should always be positive, and should not cross measure boundaries or mid-measure changes in the [Divisions][super::Divisions] value.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Backup {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BarStyle] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BarStyleAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -13,7 +13,7 @@ pub struct BarStyleAttributes {
 /// The [BarStyle] element contains barline style and color information.
 ///
 /// ![BarStyle](https://hedgetechllc.github.io/musicxml/musicxml/elements/bar-style.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("bar-style")]
 pub struct BarStyle {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Barline] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BarlineAttributes {
   /// Used for playback when there is a [Coda] child element.
   /// Indicates the end point for a forward jump to a coda sign.
@@ -29,7 +29,7 @@ pub struct BarlineAttributes {
 }
 
 /// Contents of the [Barline] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct BarlineContents {
   /// The [BarStyle] element indicates the style of the barline.
   pub bar_style: Option<BarStyle>,
@@ -58,7 +58,7 @@ pub struct BarlineContents {
 /// This includes information about repeats and multiple endings, as well as line style. Barline data is on the same level as the other musical data
 /// in a score - a child of a measure in a partwise score, or a part in a timewise score. This allows for barlines within measures, as in dotted barlines
 /// that subdivide measures in complex meters. The two [Fermata] elements allow for fermatas on both sides of the barline (the lower one inverted).
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Barline {
   /// Element-specific attributes
   pub attributes: BarlineAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Barre] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BarreAttributes {
   /// The start value indicates the lowest pitched string (e.g., the string with the highest MusicXML number).
   /// The stop value indicates the highest pitched string.
@@ -16,7 +16,7 @@ pub struct BarreAttributes {
 /// The [Barre] element indicates placing a finger over multiple strings on a single fret.
 ///
 /// ![Barre](https://hedgetechllc.github.io/musicxml/musicxml/elements/barre.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Barre {
   /// Element-specific attributes
   pub attributes: BarreAttributes,
@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Bass] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BassAttributes {
   /// Specifies where the bass is displayed relative to what precedes it.
   pub arrangement: Option<HarmonyArrangement>,
 }
 
 /// Contents of the [Bass] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct BassContents {
   /// The [BassSeparator] element specifies the symbol used to indicate a bass note in popular music chord symbols.
   pub bass_separator: Option<BassSeparator>,
@@ -26,7 +26,7 @@ pub struct BassContents {
 ///
 /// It is generally not used in functional harmony, as inversion is generally not used in pop chord symbols.
 /// As with [Root][super::Root], it is divided into step and alter elements, similar to pitches.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Bass {
   /// Element-specific attributes
   pub attributes: BassAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BassAlter] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BassAlterAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -45,7 +45,7 @@ pub struct BassAlterAttributes {
 ///
 /// In some chord styles, the text for the [BassStep][super::BassStep] element may include [BassAlter] information. In that case, the `print_object` attribute
 /// of the [BassAlter] element can be set to "no".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("bass-alter")]
 pub struct BassAlter {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BassSeparator] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BassSeparatorAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -40,7 +40,7 @@ pub struct BassSeparatorAttributes {
 /// The [BassSeparator] element indicates that text, rather than a line or slash, separates the bass from what precedes it.
 ///
 /// ![BassSeparator](https://hedgetechllc.github.io/musicxml/musicxml/elements/bass-separator.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("bass-separator")]
 pub struct BassSeparator {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BassStep] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BassStepAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -40,7 +40,7 @@ pub struct BassStepAttributes {
 }
 
 /// The [BassStep] element represents the pitch step of the bass of the current chord within the [Harmony][super::Harmony] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("bass-step")]
 pub struct BassStep {
   /// Element-specific attributes
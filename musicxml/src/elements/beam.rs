@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Beam] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BeamAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -29,7 +29,7 @@ pub struct BeamAttributes {
 /// starting with the eighth note beam using a value of 1:
 ///
 /// ![Beam](https://hedgetechllc.github.io/musicxml/musicxml/elements/beam.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Beam {
   /// Element-specific attributes
   pub attributes: BeamAttributes,
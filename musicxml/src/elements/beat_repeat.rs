@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BeatRepeat] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BeatRepeatAttributes {
   /// Indicates the starting or stopping point of the section displaying the beat repeat symbols.
   pub r#type: StartStop,
@@ -17,7 +17,7 @@ pub struct BeatRepeatAttributes {
 }
 
 /// Contents of the [BeatRepeat] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct BeatRepeatContents {
   /// The [SlashType] element is used to indicate the notation style for the slashes.
   pub slash_type: Option<SlashType>,
@@ -36,7 +36,7 @@ pub struct BeatRepeatContents {
 ///
 /// The [BeatRepeat] element specifies a notation style for repetitions. The actual music being repeated needs to be repeated within the MusicXML file.
 /// This element specifies the notation that indicates the repeat.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("beat-repeat")]
 pub struct BeatRepeat {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [BeatType] element indicates the beat unit, as found in the denominator of a time signature.
 ///
 /// ![BeatType](https://hedgetechllc.github.io/musicxml/musicxml/elements/beat-type.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("beat-type")]
 pub struct BeatType {
   /// Element-specific attributes
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [BeatUnitDot] element is used to specify any augmentation dots for a metronome mark note.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("beat-unit-dot")]
 pub struct BeatUnitDot {
   /// Element-specific attributes
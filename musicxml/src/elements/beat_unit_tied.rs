@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [BeatUnitTied] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct BeatUnitTiedContents {
   /// The [BeatUnit] element specifies the beat unit for a metronome mark.
   pub beat_unit: BeatUnit,
@@ -16,7 +16,7 @@ pub struct BeatUnitTiedContents {
 ///
 /// This allows two or more tied notes to be associated with a [PerMinute][super::PerMinute] value in a metronome mark, whereas the [MetronomeTied][super::MetronomeTied] element
 /// is restricted to metric relationship marks.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("beat-unit-tied")]
 pub struct BeatUnitTied {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Beater] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BeaterAttributes {
   /// Indicates the direction in which the tip of the beater points.
   pub tip: Option<TipDirection>,
@@ -13,7 +13,7 @@ pub struct BeaterAttributes {
 /// The [Beater] element represents pictograms for beaters, mallets, and sticks that do not have different materials represented in the pictogram.
 ///
 /// ![Beater](https://hedgetechllc.github.io/musicxml/musicxml/elements/beater.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Beater {
   /// Element-specific attributes
   pub attributes: BeaterAttributes,
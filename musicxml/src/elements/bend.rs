@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Bend] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BendAttributes {
   /// Does the bend accelerate during playback? Default is "no".
   pub accelerate: Option<YesNo>,
@@ -51,7 +51,7 @@ pub struct BendAttributes {
 }
 
 /// Contents of the [Bend] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct BendContents {
   /// The [BendAlter] element specifies the alteration of the bend.
   pub bend_alter: BendAlter,
@@ -66,7 +66,7 @@ pub struct BendContents {
 /// The [Bend] element is used in guitar notation and tablature.
 ///
 /// A single note with a bend and release will contain two [Bend] elements: the first to represent the bend and the second to represent the release.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Bend {
   /// Element-specific attributes
   pub attributes: BendAttributes,
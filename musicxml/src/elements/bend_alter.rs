@@ -8,7 +8,7 @@ use musicxml_macros::*;
 /// As with the [Alter][super::Alter] element, numbers like 0.5 can be used to indicate microtones. Negative values indicate pre-bends or releases.
 /// The [PreBend][super::PreBend] and [Release][super::Release] elements are used to distinguish what is intended.
 /// Because the [BendAlter] element represents the number of steps in the bend, a release after a bend has a negative [BendAlter] value, not a zero value.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("bend-alter")]
 pub struct BendAlter {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Bookmark] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BookmarkAttributes {
   /// The identifier for this bookmark, unique within this document.
   pub id: Id,
@@ -24,7 +24,7 @@ pub struct BookmarkAttributes {
 }
 
 /// The [Bookmark] element serves as a well-defined target for an incoming simple XLink.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Bookmark {
   /// Element-specific attributes
   pub attributes: BookmarkAttributes,
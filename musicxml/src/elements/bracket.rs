@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Bracket] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BracketAttributes {
   /// Specifies if there is a jog up or down (or both), an arrow, or nothing at the start or end of the bracket.
   pub line_end: LineEnd,
@@ -50,7 +50,7 @@ pub struct BracketAttributes {
 /// ![Bracket](https://hedgetechllc.github.io/musicxml/musicxml/elements/bracket.png)
 ///
 /// The `line_type` is solid if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Bracket {
   /// Element-specific attributes
   pub attributes: BracketAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BrassBend] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BrassBendAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct BrassBendAttributes {
 /// The [BrassBend] element represents the u-shaped bend symbol used in brass notation, distinct from the [Bend][super::Bend] element used in guitar music.
 ///
 /// ![BrassBend](https://hedgetechllc.github.io/musicxml/musicxml/elements/brass-bend.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("brass-bend")]
 pub struct BrassBend {
   /// Element-specific attributes
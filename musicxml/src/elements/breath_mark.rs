@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [BreathMark] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct BreathMarkAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct BreathMarkAttributes {
 /// The [BreathMark] element indicates a place to take a breath.
 ///
 /// ![BreathMark](https://hedgetechllc.github.io/musicxml/musicxml/elements/breath-mark.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("breath-mark")]
 pub struct BreathMark {
   /// Element-specific attributes
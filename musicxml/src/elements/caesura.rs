@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Caesura] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CaesuraAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct CaesuraAttributes {
 /// The [Caesura] element indicates a slight pause.
 ///
 /// It is notated using a "railroad tracks" symbol or other variations specified in the element content.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Caesura {
   /// Element-specific attributes
   pub attributes: CaesuraAttributes,
@@ -16,7 +16,7 @@ use musicxml_macros::*;
 /// ![MozartConcertNo3](https://hedgetechllc.github.io/musicxml/musicxml/elements/chord-multiple-stop.png)
 ///
 /// If these first three notes are represented as a chord, the quarter notes must be the ones with the [Chord] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Chord {
   /// Element-specific attributes
   pub attributes: (),
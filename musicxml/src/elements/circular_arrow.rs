@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [CircularArrow] element represents a circular arrow, using Unicode arrow terminology to specify the arrow direction.
 ///
 /// ![CircularArrow](https://hedgetechllc.github.io/musicxml/musicxml/elements/circular-arrow.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("circular-arrow")]
 pub struct CircularArrow {
   /// Element-specific attributes
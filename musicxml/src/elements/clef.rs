@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Clef] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ClefAttributes {
   /// Sometimes clefs are added to the staff in non-standard line positions, either to indicate cue passages,
   /// or when there are multiple clefs present simultaneously on one staff. In this situation,
@@ -57,7 +57,7 @@ pub struct ClefAttributes {
 }
 
 /// Contents of the [Clef] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ClefContents {
   /// The [Sign] element represents the clef symbol.
   pub sign: Sign,
@@ -70,7 +70,7 @@ pub struct ClefContents {
 /// Clefs are represented by a combination of [Sign], [Line], and [ClefOctaveChange] elements.
 ///
 /// Clefs appear at the start of each system unless the `print_object` attribute has been set to "no" or the `additional` attribute has been set to "yes".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Clef {
   /// Element-specific attributes
   pub attributes: ClefAttributes,
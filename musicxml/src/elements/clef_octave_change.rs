@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [ClefOctaveChange] element is used for transposing clefs.
 ///
 /// A treble clef for tenors would have a value of -1.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("clef-octave-change")]
 pub struct ClefOctaveChange {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Coda] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CodaAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -63,7 +63,7 @@ pub struct CodaAttributes {
 /// A [Sound][super::Sound] element is also needed to guide playback applications reliably.
 ///
 /// ![Coda](https://hedgetechllc.github.io/musicxml/musicxml/elements/coda.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Coda {
   /// Element-specific attributes
   pub attributes: CodaAttributes,
@@ -10,7 +10,7 @@ use musicxml_macros::*;
 /// either the [Diatonic][super::Diatonic] or [Chromatic][super::Chromatic] elements. Concert scores may include octave transpositions,
 /// so [Transpose][super::Transpose] elements with a [Double][super::Double] element or a non-zero [OctaveChange][super::OctaveChange]
 /// element value are permitted.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("concert-score")]
 pub struct ConcertScore {
   /// Element-specific attributes
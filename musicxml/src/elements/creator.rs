@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Creator] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CreatorAttributes {
   /// Distinguishes different creative contributions. Thus there can be multiple [Creator] elements
   /// within an [Identification][super::Identification] element. Standard values are composer, lyricist, and arranger.
@@ -16,7 +16,7 @@ pub struct CreatorAttributes {
 /// The [Creator] element describes the creators of the score.
 ///
 /// This is similar to the Dublin Core creator element. The MusicXML format does not use the creator / contributor distinction from Dublin Core.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Creator {
   /// Element-specific attributes
   pub attributes: CreatorAttributes,
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Credit] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CreditAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
@@ -17,14 +17,14 @@ pub struct CreditAttributes {
 }
 
 /// Contents of the [CreditImage] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct CreditImageContents {
   /// The [CreditImage] element specifies the appearance of an image within a credit.
   pub credit_image: CreditImage,
 }
 
 /// Contents of the [CreditTextSubcontents] element.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct CreditTextSubcontents {
   /// The [Link] element specifies a hyperlink to a URL.
   pub link: Vec<Link>,
@@ -37,7 +37,7 @@ pub struct CreditTextSubcontents {
 }
 
 /// Contents of the [CreditTextContents] element.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct CreditTextContents {
   /// The [CreditWords] element specifies the text of a credit.
   pub credit_words: Option<CreditWords>,
@@ -48,11 +48,11 @@ pub struct CreditTextContents {
 }
 
 impl ContentDeserializer for CreditTextContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut subcontents: Option<CreditTextSubcontents> = None;
     let mut contents = CreditTextContents::default();
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "link" => {
           if let Some(content) = subcontents.as_mut() {
             content.link.push(Link::deserialize(element)?);
@@ -92,7 +92,7 @@ impl ContentDeserializer for CreditTextContents {
 
 /// Contents of the [CreditSubcontents] element.
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum CreditSubcontents {
   /// The [CreditImageContents] element specifies the appearance of an image within a credit.
   Image(CreditImageContents),
@@ -101,7 +101,7 @@ pub enum CreditSubcontents {
 }
 
 /// Contents of the [Credit] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct CreditContents {
   /// The [CreditType] element specifies the type of text that appears in a credit.
   pub credit_type: Vec<CreditType>,
@@ -115,7 +115,7 @@ pub struct CreditContents {
 }
 
 impl ContentDeserializer for CreditContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut contents = CreditContents {
       credit_type: Vec::new(),
       link: Vec::new(),
@@ -128,7 +128,7 @@ impl ContentDeserializer for CreditContents {
     };
     let mut image_or_words_found = false;
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "credit-type" => contents.credit_type.push(CreditType::deserialize(element)?),
         "link" => {
           if !image_or_words_found {
@@ -150,7 +150,7 @@ impl ContentDeserializer for CreditContents {
 }
 
 impl ContentSerializer for CreditContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     for el in &element.credit_type {
       elements.push(CreditType::serialize(el));
@@ -202,7 +202,7 @@ impl ContentSerializer for CreditContents {
 ///
 /// By default, a series of [CreditWords][super::CreditWords] and [CreditSymbol][super::CreditSymbol] elements within a single [Credit] element follow one another
 /// in sequence visually. Non-positional formatting attributes are carried over from the previous element by default.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Credit {
   /// Element-specific attributes
   pub attributes: CreditAttributes,
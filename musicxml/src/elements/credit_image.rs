@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [CreditImage] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CreditImageAttributes {
   /// Changes the computation of the default horizontal position.
   /// The origin is changed relative to the left-hand side of the note or the musical position within the bar.
@@ -58,7 +58,7 @@ pub struct CreditImageAttributes {
 ///
 /// However, since the [Credit][super::Credit] is not part of a measure, the `default_x` and `default_y` attributes adjust the origin relative
 /// to the bottom left-hand corner of the page.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("credit-image")]
 pub struct CreditImage {
   /// Element-specific attributes
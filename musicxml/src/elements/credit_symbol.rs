@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [CreditSymbol] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CreditSymbolAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -91,7 +91,7 @@ pub struct CreditSymbolAttributes {
 ///
 /// By default, a series of [CreditWords][super::CreditWords] and [CreditSymbol] elements within a single [Credit][super::Credit] element follow one another
 /// in sequence visually. Non-positional formatting attributes are carried over from the previous element by default.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("credit-symbol")]
 pub struct CreditSymbol {
   /// Element-specific attributes
@@ -16,7 +16,7 @@ use musicxml_macros::*;
 /// - part name
 ///
 /// Other values may also be used.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("credit-type")]
 pub struct CreditType {
   /// Element-specific attributes
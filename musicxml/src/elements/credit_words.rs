@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [CreditWords] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct CreditWordsAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -95,7 +95,7 @@ pub struct CreditWordsAttributes {
 ///
 /// By default, a series of [CreditWords] and [CreditSymbol][super::CreditSymbol] elements within a single [Credit][super::Credit] element follow one another
 /// in sequence visually. Non-positional formatting attributes are carried over from the previous element by default.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("credit-words")]
 pub struct CreditWords {
   /// Element-specific attributes
@@ -8,7 +8,7 @@ use musicxml_macros::*;
 ///
 /// In MusicXML, a cue note is a silent note with no playback. Normal notes that play can be specified as cue size using the [Type][super::Type] element.
 /// A cue note that is specified as full size using the [Type][super::Type] element will still remain silent.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Cue {
   /// Element-specific attributes
   pub attributes: (),
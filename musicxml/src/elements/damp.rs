@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Damp] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DampAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -57,7 +57,7 @@ pub struct DampAttributes {
 /// The [Damp] element specifies a harp damping mark.
 ///
 /// ![Damp](https://hedgetechllc.github.io/musicxml/musicxml/elements/damp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Damp {
   /// Element-specific attributes
   pub attributes: DampAttributes,
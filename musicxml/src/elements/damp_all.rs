@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DampAll] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DampAllAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -57,7 +57,7 @@ pub struct DampAllAttributes {
 /// The [DampAll] element specifies a harp damping mark for all strings.
 ///
 /// ![DampAll](https://hedgetechllc.github.io/musicxml/musicxml/elements/damp-all.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("damp-all")]
 pub struct DampAll {
   /// Element-specific attributes
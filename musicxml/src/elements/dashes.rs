@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Dashes] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DashesAttributes {
   /// Indicates if this is the start, stop, or continuation of the dashes.
   pub r#type: StartStopContinue,
@@ -42,7 +42,7 @@ pub struct DashesAttributes {
 /// The [Dashes] element represents dashes, used for instance with cresc. and dim. marks.
 ///
 /// ![Dashes](https://hedgetechllc.github.io/musicxml/musicxml/elements/dashes.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Dashes {
   /// Element-specific attributes
   pub attributes: DashesAttributes,
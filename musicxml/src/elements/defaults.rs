@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Defaults] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct DefaultsContents {
   /// The [Scaling] element specifies the percentage of the regular scaling to use for music notation.
   pub scaling: Option<Scaling>,
@@ -35,7 +35,7 @@ pub struct DefaultsContents {
 /// layout, and default values for the music font, word font, lyric font, and lyric language.
 ///
 /// Except for the [ConcertScore] element, if any defaults are missing, the choice of what to use is determined by the application.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Defaults {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Degree] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DegreeAttributes {
   /// Specifies whether or not to print an object. It is yes if not specified.
   pub print_object: Option<YesNo>,
 }
 
 /// Contents of the [Degree] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct DegreeContents {
   /// The [DegreeValue] element specifies the degree value for the degree element.
   pub degree_value: DegreeValue,
@@ -29,7 +29,7 @@ pub struct DegreeContents {
 ///
 /// A [Harmony][super::Harmony] with a [Kind][super::Kind] value of "other" can be spelled explicitly by using a series of [Degree] elements together with
 /// a [Root][super::Root], [Numeral][super::Numeral], or [Function][super::Function] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Degree {
   /// Element-specific attributes
   pub attributes: DegreeAttributes,
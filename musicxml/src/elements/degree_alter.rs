@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DegreeAlter] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DegreeAlterAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct DegreeAlterAttributes {
 /// If the [DegreeType][super::DegreeType] value is alter or subtract, the [DegreeAlter] value is relative to the degree already
 /// in the chord based on its [Kind][super::Kind] element. If the [DegreeType][super::DegreeType] value is add, the [DegreeAlter] is relative
 /// to a dominant chord (major and perfect intervals except for a minor seventh).
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("degree-alter")]
 pub struct DegreeAlter {
   /// Element-specific attributes
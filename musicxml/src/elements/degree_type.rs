@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DegreeType] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DegreeTypeAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct DegreeTypeAttributes {
 /// The [DegreeType] element indicates if this degree is an addition, alteration, or subtraction relative to the [Kind][super::Kind] of the current chord.
 ///
 /// The value of the [DegreeType] element affects the interpretation of the value of the [DegreeAlter][super::DegreeAlter] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("degree-type")]
 pub struct DegreeType {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DegreeValue] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DegreeValueAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct DegreeValueAttributes {
 }
 
 /// The content of the [DegreeValue] element is a number indicating the degree of the chord (1 for the root, 3 for third, etc).
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("degree-value")]
 pub struct DegreeValue {
   /// Element-specific attributes
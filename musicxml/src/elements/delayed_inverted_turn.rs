@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DelayedInvertedTurn] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DelayedInvertedTurnAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -63,7 +63,7 @@ pub struct DelayedInvertedTurnAttributes {
 /// An inverted turn has the shape which goes down and then up.
 ///
 /// ![DelayedInvertedTurn](https://hedgetechllc.github.io/musicxml/musicxml/elements/delayed-inverted-turn.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("delayed-inverted-turn")]
 pub struct DelayedInvertedTurn {
   /// Element-specific attributes
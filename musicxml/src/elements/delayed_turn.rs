@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DelayedTurn] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DelayedTurnAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -61,7 +61,7 @@ pub struct DelayedTurnAttributes {
 /// The [DelayedTurn] element indicates a normal turn that is delayed until the end of the current note.
 ///
 /// ![DelayedTurn](https://hedgetechllc.github.io/musicxml/musicxml/elements/delayed-turn.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("delayed-turn")]
 pub struct DelayedTurn {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DetachedLegato] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DetachedLegatoAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct DetachedLegatoAttributes {
 /// The [DetachedLegato] element indicates the combination of a tenuto line and staccato dot symbol.
 ///
 /// ![DetachedLegato](https://hedgetechllc.github.io/musicxml/musicxml/elements/detached-legato.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("detached-legato")]
 pub struct DetachedLegato {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 ///
 /// This allows for correct spelling of enharmonic transpositions. This value does not include [OctaveChange][super::OctaveChange] values;
 /// the values for both elements need to be added to the written pitch to get the correct sounding pitch.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Diatonic {
   /// Element-specific attributes
   pub attributes: (),
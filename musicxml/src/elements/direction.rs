@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Direction] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DirectionAttributes {
   /// Changes the default_x position of a [Direction]. It indicates that the left-hand side of the direction
   /// is aligned with the left-hand side of the time signature. If no time signature is present, the direction
@@ -21,7 +21,7 @@ pub struct DirectionAttributes {
 }
 
 /// Contents of the [Direction] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct DirectionContents {
   /// The [DirectionType] element specifies the type of the direction.
   pub direction_type: Vec<DirectionType>,
@@ -50,7 +50,7 @@ pub struct DirectionContents {
 /// By default, a series of [DirectionType] elements and a series of child elements of a [DirectionType] within a single [Direction] element follow one
 /// another in sequence visually. For a series of [DirectionType] children, non-positional formatting attributes are carried over from the previous
 /// element by default.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Direction {
   /// Element-specific attributes
   pub attributes: DirectionAttributes,
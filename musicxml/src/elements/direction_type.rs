@@ -9,14 +9,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DirectionType] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DirectionTypeAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
 }
 
 /// The [DirectionTypeContents] element specifies all possible options available for use in a [DirectionType] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum DirectionTypeContents {
   /// The [Rehearsal] element indicates a rehearsal mark.
   Rehearsal(Vec<Rehearsal>),
@@ -70,9 +70,9 @@ pub enum DirectionTypeContents {
 
 impl ContentDeserializer for DirectionTypeContents {
   #[allow(clippy::too_many_lines)]
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     Ok(if let Some(element) = elements.first() {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "rehearsal" => DirectionTypeContents::Rehearsal(
           elements
             .iter()
@@ -185,7 +185,7 @@ impl ContentDeserializer for DirectionTypeContents {
 }
 
 impl ContentSerializer for DirectionTypeContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     match element {
       DirectionTypeContents::Rehearsal(contents) => {
@@ -251,7 +251,7 @@ impl ContentSerializer for DirectionTypeContents {
 ///
 /// The [Dynamics] element may also be used in the [Notations][super::Notations] element. Child element attributes related to print suggestions apply
 /// to the individual [DirectionType], not to the overall [Direction][super::Direction].
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("direction-type")]
 pub struct DirectionType {
   /// Element-specific attributes
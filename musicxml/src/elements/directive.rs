@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Directive] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DirectiveAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -43,7 +43,7 @@ pub struct DirectiveAttributes {
 ///
 /// This is typically used for tempo markings at the beginning of a piece of music. This element was deprecated in Version 2.0 in favor of the
 /// [Direction][super::Direction] element's `directive` attribute.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Directive {
   /// Element-specific attributes
   pub attributes: DirectiveAttributes,
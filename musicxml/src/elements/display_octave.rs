@@ -8,7 +8,7 @@ use musicxml_macros::*;
 ///
 /// Positioning follows the current clef. If percussion clef is used, the [DisplayStep][super::DisplayStep] and [DisplayOctave] elements are interpreted
 /// as if in treble clef, with a G in octave 4 on line 2.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("display-octave")]
 pub struct DisplayOctave {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DisplayText] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DisplayTextAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -89,7 +89,7 @@ pub struct DisplayTextAttributes {
 /// The [DisplayText] element is used for exact formatting of multi-font text in display elements such as [PartNameDisplay][super::PartNameDisplay].
 ///
 /// The `enclosure` attribute is none if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("display-text")]
 pub struct DisplayText {
   /// Element-specific attributes
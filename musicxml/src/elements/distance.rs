@@ -4,14 +4,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Distance] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DistanceAttributes {
   /// The type of distance being defined.
   pub r#type: DistanceType,
 }
 
 /// The [Distance] element represents standard distances between notation elements in tenths.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Distance {
   /// Element-specific attributes
   pub attributes: DistanceAttributes,
@@ -10,7 +10,7 @@ use musicxml_macros::*;
 ///
 /// Using a [Divisions] element lets us use just one number to represent a duration for each note in the score, while retaining the full power of a
 /// fractional representation. If maximum compatibility with Standard MIDI 1.0 files is important, do not have the [Divisions] value exceed 16383.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Divisions {
   /// Element-specific attributes
   pub attributes: (),
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Doit] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DoitAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -56,7 +56,7 @@ pub struct DoitAttributes {
 /// The doit appears after the main note and goes above the main pitch.
 ///
 /// ![Doit](https://hedgetechllc.github.io/musicxml/musicxml/elements/doit.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Doit {
   /// Element-specific attributes
   pub attributes: DoitAttributes,
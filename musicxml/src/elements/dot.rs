@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Dot] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DotAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -40,7 +40,7 @@ pub struct DotAttributes {
 }
 
 /// One [Dot] element is used for each dot of prolongation.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Dot {
   /// Element-specific attributes
   pub attributes: DotAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Double] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DoubleAttributes {
   /// If the `above` attribute is set to yes, the doubling is one octave above what is written,
   /// as for mixed flute / piccolo parts in band literature. Otherwise the doubling is one octave
@@ -13,7 +13,7 @@ pub struct DoubleAttributes {
 }
 
 /// If the [Double] element is present, it indicates that the music is doubled one octave from what is currently written.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Double {
   /// Element-specific attributes
   pub attributes: DoubleAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [DoubleTongue] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DoubleTongueAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct DoubleTongueAttributes {
 /// The [DoubleTongue] element represents the double tongue symbol (two dots arranged horizontally).
 ///
 /// ![DoubleTongue](https://hedgetechllc.github.io/musicxml/musicxml/elements/double-tongue.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("double-tongue")]
 pub struct DoubleTongue {
   /// Element-specific attributes
@@ -9,7 +9,7 @@ use musicxml_macros::*;
 ///
 /// The [Duration] element moves the musical position when used in [Backup][super::Backup] elements, [Forward][super::Forward] elements, and
 /// [Note][super::Note] elements that do not contain a [Chord][super::Chord] child element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Duration {
   /// Element-specific attributes
   pub attributes: (),
@@ -11,7 +11,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Dynamics] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct DynamicsAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -72,7 +72,7 @@ pub struct DynamicsAttributes {
 }
 
 /// The [DynamicsType] element specifies all possible dynamics available for use in a [Dynamics] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum DynamicsType {
   /// The [P] element represents the piano dynamic.
   P(P),
@@ -146,7 +146,7 @@ pub enum DynamicsType {
 ///
 /// The `placement` attribute is used when the dynamics are associated with a [Note][super::Note]. It is ignored when the dynamics are associated with
 /// a [Direction][super::Direction]. In that case the [Direction][super::Direction] element's `placement` attribute is used instead.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Dynamics {
   /// Element-specific attributes
   pub attributes: DynamicsAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Effect] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct EffectAttributes {
   /// Distinguishes different SMuFL stylistic alternates.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct EffectAttributes {
 /// The [Effect] element represents pictograms for sound effect percussion instruments.
 ///
 /// ![Effect](https://hedgetechllc.github.io/musicxml/musicxml/elements/effect.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Effect {
   /// Element-specific attributes
   pub attributes: EffectAttributes,
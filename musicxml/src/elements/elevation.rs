@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// The [Elevation] and [Pan][super::Pan] elements allow placing of sound in a 3-D space relative to the listener.
 ///
 /// Both are expressed in degrees ranging from -180 to 180. For [Elevation], 0 is level with the listener, 90 is directly above, and -90 is directly below.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Elevation {
   /// Element-specific attributes
   pub attributes: (),
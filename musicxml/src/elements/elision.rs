@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Elision] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ElisionAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -25,7 +25,7 @@ pub struct ElisionAttributes {
 /// The text content specifies the symbol used to display the elision. Common values are a no-break space (Unicode 00A0), an underscore (Unicode 005F),
 /// or an undertie (Unicode 203F). If the text content is empty, the `smufl` attribute is used to specify the symbol to use.
 /// If neither text content nor a `smufl` attribute are present, the elision glyph is application-specific.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Elision {
   /// Element-specific attributes
   pub attributes: ElisionAttributes,
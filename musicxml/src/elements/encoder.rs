@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Encoder] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct EncoderAttributes {
   /// Standard values are music, words, and arrangement, but other types may be used.
   /// This attribute is only needed when there are multiple [Encoder] elements.
@@ -12,7 +12,7 @@ pub struct EncoderAttributes {
 }
 
 /// The [Encoder] element contains information about who did the digital encoding.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Encoder {
   /// Element-specific attributes
   pub attributes: EncoderAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [EncodingContents] element specifies all possible options available for use in an [Encoding] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum EncodingContents {
   /// The [EncodingDate] element specifies the date of the encoding.
   #[rename("encoding-date")]
@@ -21,7 +21,7 @@ pub enum EncodingContents {
 }
 
 /// The [Encoding] element contains information about who did the digital encoding, when, with what software, and in what aspects.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Encoding {
   /// Element-specific attributes
   pub attributes: (),
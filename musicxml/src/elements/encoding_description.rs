@@ -4,7 +4,7 @@ use musicxml_macros::*;
 
 /// The [EncodingDescription] element contains descriptive information about the digital encoding
 /// that is not provided in the other [Encoding][super::Encoding] child elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("encoding-description")]
 pub struct EncodingDescription {
   /// Element-specific attributes
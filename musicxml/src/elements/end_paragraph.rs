@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [EndParagraph] element comes from RP-017 for Standard MIDI File Lyric meta-events.
 ///
 /// It facilitates lyric display for Karaoke and similar applications.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("end-paragraph")]
 pub struct EndParagraph {
   /// Element-specific attributes
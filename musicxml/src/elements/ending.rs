@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Ending] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct EndingAttributes {
   /// Indicates which times the ending is played, similar to the `time_only` attribute used by other elements.
   /// While this often represents the numeric values for what is under the ending line, it can also indicate whether
@@ -64,7 +64,7 @@ pub struct EndingAttributes {
 /// ![Ending](https://hedgetechllc.github.io/musicxml/musicxml/elements/ending.png)
 ///
 /// The element text is used when the text displayed in the ending is different than what appears in the number attribute.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ending {
   /// Element-specific attributes
   pub attributes: EndingAttributes,
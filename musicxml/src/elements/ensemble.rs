@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// The [Ensemble] element is present if performance is intended by an ensemble such as an orchestral section.
 ///
 /// The text of the [Ensemble] element contains the size of the section, or is empty if the ensemble size is not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ensemble {
   /// Element-specific attributes
   pub attributes: (),
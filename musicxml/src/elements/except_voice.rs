@@ -6,7 +6,7 @@ use musicxml_macros::*;
 ///
 /// Any [Note][super::Note] elements that are in voices specified by the [ExceptVoice] elements are displayed in normal notation,
 /// in addition to the slash notation that is always displayed.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("except-voice")]
 pub struct ExceptVoice {
   /// Element-specific attributes
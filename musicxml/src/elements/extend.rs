@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Extend] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ExtendAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -33,7 +33,7 @@ pub struct ExtendAttributes {
 }
 
 /// The [Extend] element represents lyric word extension / melisma lines as well as figured bass extensions.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Extend {
   /// Element-specific attributes
   pub attributes: ExtendAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Eyeglasses] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct EyeglassesAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -57,7 +57,7 @@ pub struct EyeglassesAttributes {
 /// The [Eyeglasses] element represents the eyeglasses symbol, common in commercial music.
 ///
 /// ![Eyeglasses](https://hedgetechllc.github.io/musicxml/musicxml/elements/eyeglasses.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Eyeglasses {
   /// Element-specific attributes
   pub attributes: EyeglassesAttributes,
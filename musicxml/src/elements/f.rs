@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [F] element represents a forte dynamic marking.
 ///
 /// ![f](https://hedgetechllc.github.io/musicxml/musicxml/elements/f.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct F {
   /// Element-specific attributes
   pub attributes: (),
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Falloff] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FalloffAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -56,7 +56,7 @@ pub struct FalloffAttributes {
 /// The falloff appears after the main note and goes below the main pitch.
 ///
 /// ![Falloff](https://hedgetechllc.github.io/musicxml/musicxml/elements/falloff.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Falloff {
   /// Element-specific attributes
   pub attributes: FalloffAttributes,
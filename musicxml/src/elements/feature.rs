@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Feature] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FeatureAttributes {
   /// Represents the type of the feature. This type is flexible to allow for different analyses.
   pub r#type: Option<Token>,
@@ -13,7 +13,7 @@ pub struct FeatureAttributes {
 /// The [Feature] element is a part of the [Grouping][super::Grouping] element used for musical analysis.
 ///
 /// The `type` attribute represents the type of the feature and the element content represents its value.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Feature {
   /// Element-specific attributes
   pub attributes: FeatureAttributes,
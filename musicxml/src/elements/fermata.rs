@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Fermata] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FermataAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -46,7 +46,7 @@ pub struct FermataAttributes {
 /// An empty [Fermata] element represents a normal fermata.
 ///
 /// ![Fermata](https://hedgetechllc.github.io/musicxml/musicxml/elements/fermata.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fermata {
   /// Element-specific attributes
   pub attributes: FermataAttributes,
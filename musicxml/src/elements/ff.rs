@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Ff] element represents a fortissimo dynamic marking.
 ///
 /// ![ff](https://hedgetechllc.github.io/musicxml/musicxml/elements/ff.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ff {
   /// Element-specific attributes
   pub attributes: (),
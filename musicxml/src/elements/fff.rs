@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Fff] element represents a triple forte dynamic marking.
 ///
 /// ![fff](https://hedgetechllc.github.io/musicxml/musicxml/elements/fff.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fff {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Ffff] element represents an ffff dynamic marking.
 ///
 /// ![ffff](https://hedgetechllc.github.io/musicxml/musicxml/elements/ffff.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ffff {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Fffff] element represents an fffff dynamic marking.
 ///
 /// ![fffff](https://hedgetechllc.github.io/musicxml/musicxml/elements/fffff.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fffff {
   /// Element-specific attributes
   pub attributes: (),
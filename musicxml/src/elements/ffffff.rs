@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Ffffff] element represents an ffffff dynamic marking.
 ///
 /// ![ffffff](https://hedgetechllc.github.io/musicxml/musicxml/elements/ffffff.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ffffff {
   /// Element-specific attributes
   pub attributes: (),
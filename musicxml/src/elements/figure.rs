@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Figure] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct FigureContents {
   /// The [Prefix] element is used to specify the prefix of a figured bass symbol.
   pub prefix: Option<Prefix>,
@@ -21,7 +21,7 @@ pub struct FigureContents {
 }
 
 /// The [Figure] element represents a single figure within a [FiguredBass][super::FiguredBass] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Figure {
   /// Element-specific attributes
   pub attributes: (),
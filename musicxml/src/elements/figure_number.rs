@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [FigureNumber] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FigureNumberAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct FigureNumberAttributes {
 /// ![FigureNumber](https://hedgetechllc.github.io/musicxml/musicxml/elements/figure-number.png)
 ///
 /// Overstrikes of the figure number are represented in the [Suffix][super::Suffix] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("figure-number")]
 pub struct FigureNumber {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [FiguredBass] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FiguredBassAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -75,7 +75,7 @@ pub struct FiguredBassAttributes {
 }
 
 /// Contents of the [FiguredBass] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct FiguredBassContents {
   /// The [Figure] element represents a single figured bass figure.
   pub figure: Vec<Figure>,
@@ -91,7 +91,7 @@ pub struct FiguredBassContents {
 ///
 /// A [FiguredBass] element takes its position from the first regular note (not a grace note or chord note) that follows in score order.
 /// The optional [Duration] element is used to indicate changes of figures under a note. Figures are ordered from top to bottom.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("figured-bass")]
 pub struct FiguredBass {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Fingering] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FingeringAttributes {
   /// Indicates that this is an alternate fingering. It is no if not present.
   pub alternate: Option<YesNo>,
@@ -47,7 +47,7 @@ pub struct FingeringAttributes {
 ///
 /// Multiple fingerings may be given, typically to substitute fingerings in the middle of a note. For guitar and other fretted instruments,
 /// the [Fingering] element represents the fretting finger; the [Pluck][super::Pluck] element represents the plucking finger.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fingering {
   /// Element-specific attributes
   pub attributes: FingeringAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [FirstFret] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FirstFretAttributes {
   /// Indicates whether the text appears to the left or right of the frame.
   pub location: Option<LeftRight>,
@@ -15,7 +15,7 @@ pub struct FirstFretAttributes {
 /// The [FirstFret] element indicates which fret is shown in the top space of the frame.
 ///
 /// It is fret 1 if the element is not present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("first-fret")]
 pub struct FirstFret {
   /// Element-specific attributes
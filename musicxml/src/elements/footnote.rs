@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Footnote] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FootnoteAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -87,7 +87,7 @@ pub struct FootnoteAttributes {
 }
 
 /// The [Footnote] element specifies editorial information that appears in footnotes in the printed score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Footnote {
   /// Element-specific attributes
   pub attributes: FootnoteAttributes,
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [ForPart] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ForPartAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
@@ -16,7 +16,7 @@ pub struct ForPartAttributes {
 }
 
 /// Contents of the [ForPart] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ForPartContents {
   /// The [PartClef] element is used to specify the clef used in a part.
   pub part_clef: Option<PartClef>,
@@ -28,7 +28,7 @@ pub struct ForPartContents {
 ///
 /// It is only used in score files that contain a [ConcertScore][super::ConcertScore] element in the [Defaults][super::Defaults] element.
 /// This allows concert scores with transposed parts to be represented in a single uncompressed MusicXML file.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("for-part")]
 pub struct ForPart {
   /// Element-specific attributes
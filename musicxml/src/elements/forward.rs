@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Forward] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ForwardContents {
   /// The [Duration] element specifies the duration of the forward element.
   pub duration: Duration,
@@ -23,7 +23,7 @@ pub struct ForwardContents {
 ///
 /// The [Forward] element is generally used within voices and staves. [Duration] values should always be positive, and should not cross
 /// measure boundaries or mid-measure changes in the [Divisions][super::Divisions] value.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Forward {
   /// Element-specific attributes
   pub attributes: (),
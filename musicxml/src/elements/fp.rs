@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Fp] element represents a forte piano dynamic marking.
 ///
 /// ![fp](https://hedgetechllc.github.io/musicxml/musicxml/elements/fp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fp {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Frame] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FrameAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -56,7 +56,7 @@ pub struct FrameAttributes {
 }
 
 /// Contents of the [Frame] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct FrameContents {
   /// The [FrameStrings] element specifies the number of strings on the frame.
   pub frame_strings: FrameStrings,
@@ -73,7 +73,7 @@ pub struct FrameContents {
 /// The representation is based on the NIFF guitar grid with additional information. The frame type's `unplayed` attribute indicates
 /// what to display above a string that has no associated [FrameNote] element. Typical values are "x" and the empty string. If the attribute
 /// is not present, the display of the unplayed string is application-defined.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Frame {
   /// Element-specific attributes
   pub attributes: FrameAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [FrameNote] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct FrameNoteContents {
   /// The [StringNumber] element specifies the string number of the note.
   pub string: StringNumber,
@@ -19,7 +19,7 @@ pub struct FrameNoteContents {
 /// The [FrameNote] type represents each note included in the frame.
 ///
 /// An open string will have a fret value of 0, while a muted string will not be associated with a [FrameNote] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("frame-note")]
 pub struct FrameNote {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Fret] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FretAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -21,7 +21,7 @@ pub struct FretAttributes {
 /// The [Fret] element is used with tablature notation and chord diagrams.
 ///
 /// Fret numbers start with 0 for an open string and 1 for the first fret.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fret {
   /// Element-specific attributes
   pub attributes: FretAttributes,
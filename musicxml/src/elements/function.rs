@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Function] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct FunctionAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -41,7 +41,7 @@ pub struct FunctionAttributes {
 ///
 /// It represents the Roman numeral part of a functional harmony rather than the complete function itself. It has been deprecated
 /// as of MusicXML 4.0 in favor of the [Numeral][super::Numeral] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Function {
   /// Element-specific attributes
   pub attributes: FunctionAttributes,
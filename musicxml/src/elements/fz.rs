@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Fz] element represents a forzando fz dynamic marking.
 ///
 /// ![fz](https://hedgetechllc.github.io/musicxml/musicxml/elements/fz.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Fz {
   /// Element-specific attributes
   pub attributes: (),
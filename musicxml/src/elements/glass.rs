@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Glass] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GlassAttributes {
   /// Distinguishes different SMuFL glyphs for wind chimes in the Chimes pictograms range, including those made of materials other than glass.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct GlassAttributes {
 /// The [Glass] element represents pictograms for glass percussion instruments.
 ///
 /// ![Glass](https://hedgetechllc.github.io/musicxml/musicxml/elements/glass.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Glass {
   /// Element-specific attributes
   pub attributes: GlassAttributes,
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Glissando] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GlissandoAttributes {
   /// Indicates if this is the start or stop of the glissando.
   pub r#type: StartStop,
@@ -54,7 +54,7 @@ pub struct GlissandoAttributes {
 /// The [Glissando] and [Slide][super::Slide] elements both indicate rapidly moving from one pitch to the other so that individual notes are not discerned.
 ///
 /// A [Glissando] sounds the distinct notes in between the two pitches and defaults to a wavy line. The optional text is printed alongside the line.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Glissando {
   /// Element-specific attributes
   pub attributes: GlissandoAttributes,
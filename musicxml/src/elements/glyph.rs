@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Glyph] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GlyphAttributes {
   /// The type of glyph that is being defined.
   pub r#type: GlyphType,
@@ -18,7 +18,7 @@ pub struct GlyphAttributes {
 /// The SMuFL canonical glyph name should match the type. For instance, a type of quarter-rest would use values "restQuarter," "restQuarterOld," or
 /// "restQuarterZ". A type of g-clef-ottava-bassa would use values "gClef8vb," "gClef8vbOld," or "gClef8vbCClef". A type of octave-shift-up-8 would use values
 /// "ottava," "ottavaBassa," "ottavaBassaBa," "ottavaBassaVb," or "octaveBassa".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Glyph {
   /// Element-specific attributes
   pub attributes: GlyphAttributes,
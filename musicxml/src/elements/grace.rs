@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Grace] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GraceAttributes {
   /// Indicates to make time, not steal time, for grace note playback.
   /// The units are in real-time divisions for the grace note.
@@ -20,7 +20,7 @@ pub struct GraceAttributes {
 /// The [Grace] element indicates the presence of a grace note.
 ///
 /// ![Grace](https://hedgetechllc.github.io/musicxml/musicxml/elements/grace.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Grace {
   /// Element-specific attributes
   pub attributes: GraceAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [GroupAbbreviation] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GroupAbbreviationAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct GroupAbbreviationAttributes {
 /// The [GroupAbbreviation] element describes the abbreviation of a [PartGroup][super::PartGroup] element.
 ///
 /// The formatting attributes are deprecated as of Version 2.0 in favor of the new [GroupAbbreviationDisplay][super::GroupAbbreviationDisplay] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("group-abbreviation")]
 pub struct GroupAbbreviation {
   /// Element-specific attributes
@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [GroupAbbreviationDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GroupAbbreviationDisplayAttributes {
   /// Specifies whether or not to print an object. It is yes if not specified.
   pub print_object: Option<YesNo>,
 }
 
 /// Contents of the [GroupAbbreviationDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct GroupAbbreviationDisplayContents {
   /// The [DisplayText] element specifies the text of the group abbreviation.
   pub display_text: Vec<DisplayText>,
@@ -26,7 +26,7 @@ pub struct GroupAbbreviationDisplayContents {
 ///
 /// Formatting specified in the [GroupAbbreviationDisplay] element overrides formatting specified in the
 /// [GroupAbbreviation][super::GroupAbbreviation] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("group-abbreviation-display")]
 pub struct GroupAbbreviationDisplay {
   /// Element-specific attributes
@@ -4,14 +4,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [GroupBarline] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GroupBarlineAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
 }
 
 /// The [GroupBarline] element indicates if the group should have common barlines.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("group-barline")]
 pub struct GroupBarline {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 ///
 /// The optional [GroupLink] elements identify the groups used in the linked document. The content of a [GroupLink] element should match the content
 /// of a [Group][super::Group] element in the linked document.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("group-link")]
 pub struct GroupLink {
   /// Element-specific attributes
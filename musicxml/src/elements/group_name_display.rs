@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [GroupNameDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GroupNameDisplayAttributes {
   /// Specifies whether or not to print an object. It is yes if not specified.
   pub print_object: Option<YesNo>,
 }
 
 /// Contents of the [GroupNameDisplay] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct GroupNameDisplayContents {
   /// The [DisplayText] element specifies the text to display for the group name.
   pub display_text: Vec<DisplayText>,
@@ -25,7 +25,7 @@ pub struct GroupNameDisplayContents {
 /// The `print_object` attribute can be used to determine what, if anything, is printed at the start of each system.
 ///
 /// Formatting specified in the [GroupNameDisplay] element overrides formatting specified in the [GroupName][super::GroupName] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("group-name-display")]
 pub struct GroupNameDisplay {
   /// Element-specific attributes
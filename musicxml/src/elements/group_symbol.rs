@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [GroupSymbol] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GroupSymbolAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -32,7 +32,7 @@ pub struct GroupSymbolAttributes {
 /// The [GroupSymbol] element indicates how the symbol for a group is indicated in the score.
 ///
 /// It is none if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("group-symbol")]
 pub struct GroupSymbol {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Grouping] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct GroupingAttributes {
   /// Indicates if this is a single-note grouping, or the start or stop of a multi-note grouping.
   pub r#type: StartStopSingle,
@@ -18,7 +18,7 @@ pub struct GroupingAttributes {
 }
 
 /// Contents of the [Grouping] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct GroupingContents {
   /// The [Feature] element is used for musical analysis.
   pub feature: Vec<Feature>,
@@ -31,7 +31,7 @@ pub struct GroupingContents {
 ///
 /// This element is flexible to allow for different types of analyses. Future versions of the MusicXML format may add elements that can represent more
 /// standardized categories of analysis data, allowing for easier data sharing.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Grouping {
   /// Element-specific attributes
   pub attributes: GroupingAttributes,
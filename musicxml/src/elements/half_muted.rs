@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [HalfMuted] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HalfMutedAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -48,7 +48,7 @@ pub struct HalfMutedAttributes {
 ///
 /// The `smufl` attribute can be used to distinguish different SMuFL glyphs that have a similar appearance such as "brassMuteHalfClosed" and "guitarHalfOpenPedal".
 /// If not present, the default glyph is "brassMuteHalfClosed".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("half-muted")]
 pub struct HalfMuted {
   /// Element-specific attributes
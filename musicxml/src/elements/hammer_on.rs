@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [HammerOn] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HammerOnAttributes {
   /// Indicates if this is the start or stop of the hammer-on.
   pub r#type: StartStop,
@@ -51,7 +51,7 @@ pub struct HammerOnAttributes {
 ///
 /// Since a single slur can be marked over many notes, the [HammerOn] element is separate so the individual pair of notes can be specified.
 /// The element content can be used to specify how the [HammerOn] should be notated. An empty element leaves this choice up to the application.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("hammer-on")]
 pub struct HammerOn {
   /// Element-specific attributes
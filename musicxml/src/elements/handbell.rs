@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Handbell] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HandbellAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -40,7 +40,7 @@ pub struct HandbellAttributes {
 }
 
 /// The [Handbell] element represents notation for various techniques used in handbell and handchime music.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Handbell {
   /// Element-specific attributes
   pub attributes: HandbellAttributes,
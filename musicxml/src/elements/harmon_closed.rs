@@ -4,14 +4,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [HarmonClosed] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HarmonClosedAttributes {
   /// Indicates which portion of the symbol is filled in when the element value is half.
   pub location: Option<HarmonClosedLocation>,
 }
 
 /// The [HarmonClosed] element represents whether the harmon mute is closed, open, or half-open.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("harmon-closed")]
 pub struct HarmonClosed {
   /// Element-specific attributes
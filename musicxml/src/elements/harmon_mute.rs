@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [HarmonMute] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HarmonMuteAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -41,7 +41,7 @@ pub struct HarmonMuteAttributes {
 }
 
 /// Contents of the [HarmonMute] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct HarmonMuteContents {
   /// The [HarmonClosed] element represents the symbols used for harmon mutes in brass notation.
   pub harmon_closed: HarmonClosed,
@@ -50,7 +50,7 @@ pub struct HarmonMuteContents {
 /// The [HarmonMute] element represents the symbols used for harmon mutes in brass notation.
 ///
 /// ![HarmonMute](https://hedgetechllc.github.io/musicxml/musicxml/elements/harmon-mute.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("harmon-mute")]
 pub struct HarmonMute {
   /// Element-specific attributes
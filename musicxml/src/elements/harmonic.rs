@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Harmonic] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HarmonicAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -43,7 +43,7 @@ pub struct HarmonicAttributes {
 }
 
 /// Contents of the [Harmonic] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct HarmonicContents {
   /// The [Natural] element indicates natural harmonics.
   pub natural: Option<Natural>,
@@ -62,7 +62,7 @@ pub struct HarmonicContents {
 /// Allowing the type of pitch to be specified, combined with controls for appearance/playback differences, allows both the notation and the
 /// sound to be represented. Artificial harmonics can add a notated touching pitch; artificial pinch harmonics will usually not notate a touching pitch.
 /// The attributes for the [Harmonic] element refer to the use of the circular harmonic symbol, typically but not always used with natural harmonics.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Harmonic {
   /// Element-specific attributes
   pub attributes: HarmonicAttributes,
@@ -10,7 +10,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Harmony] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HarmonyAttributes {
   /// Specifies how multiple harmony-chords are arranged relative to each other.
   /// Harmony-chords with vertical arrangement are separated by horizontal lines.
@@ -61,7 +61,7 @@ pub struct HarmonyAttributes {
 }
 
 /// Contents of the [HarmonySubcontents] element.
-#[derive(Debug, PartialEq, Eq, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentSerialize, ContentWalk)]
 pub struct HarmonySubcontents {
   /// The [Root] element specifies the root of a harmony-chord.
   pub root: Option<Root>,
@@ -80,7 +80,7 @@ pub struct HarmonySubcontents {
 }
 
 /// Contents of the [Harmony] element.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct HarmonyContents {
   /// The [HarmonySubcontents] element specifies the root, numeral, function, kind, inversion, bass, and degree of a harmony-chord.
   pub harmony: Vec<HarmonySubcontents>,
@@ -97,7 +97,7 @@ pub struct HarmonyContents {
 }
 
 impl ContentDeserializer for HarmonyContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut harmony: Vec<HarmonySubcontents> = Vec::new();
     for element in elements {
       if element.name == "root" || element.name == "numeral" || element.name == "function" {
@@ -115,7 +115,7 @@ impl ContentDeserializer for HarmonyContents {
         });
       }
       if let Some(sub) = harmony.last_mut() {
-        match element.name.as_str() {
+        match element.name.as_ref() {
           "root" => sub.root = Some(Root::deserialize(element)?),
           "numeral" => sub.numeral = Some(Numeral::deserialize(element)?),
           "function" => sub.function = Some(Function::deserialize(element)?),
@@ -154,7 +154,7 @@ impl ContentDeserializer for HarmonyContents {
 }
 
 impl ContentSerializer for HarmonyContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     for el in &element.harmony {
       elements.extend(HarmonySubcontents::serialize(el));
@@ -190,7 +190,7 @@ impl ContentSerializer for HarmonyContents {
 /// A [Root] is a pitch name like C, D, E, while a [Numeral] is a scale degree like 1, 2, 3. The [Root] element is generally used with pop chord symbols,
 /// while the [Numeral] element is generally used with classical functional harmony and Nashville numbers. It is an either/or choice to avoid data inconsistency.
 /// The [Function] element, which represents Roman numerals with roman numeral text, has been deprecated as of MusicXML 4.0.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Harmony {
   /// Element-specific attributes
   pub attributes: HarmonyAttributes,
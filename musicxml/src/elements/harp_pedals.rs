@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [HarpPedals] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HarpPedalsAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -56,7 +56,7 @@ pub struct HarpPedalsAttributes {
 }
 
 /// Contents of the [HarpPedals] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct HarpPedalsContents {
   /// The [PedalTuning] element specifies the tuning of a single harp pedal.
   pub pedal_tuning: Vec<PedalTuning>,
@@ -69,7 +69,7 @@ pub struct HarpPedalsContents {
 /// The [PedalStep][super::PedalStep] and [PedalAlter][super::PedalAlter] elements use the same values
 /// as the [Step][super::Step] and [Alter][super::Alter] elements. For easiest reading, the [PedalTuning] elements should follow standard harp pedal order,
 /// with [PedalStep][super::PedalStep] values of D, C, B, E, F, G, and A.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("harp-pedals")]
 pub struct HarpPedals {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Haydn] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HaydnAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -61,7 +61,7 @@ pub struct HaydnAttributes {
 /// ![Haydn](https://hedgetechllc.github.io/musicxml/musicxml/elements/haydn.png)
 ///
 /// This is defined in the Standard Music Font Layout (SMuFL) as ornamentHaydn.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Haydn {
   /// Element-specific attributes
   pub attributes: HaydnAttributes,
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Hole] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HoleAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -41,7 +41,7 @@ pub struct HoleAttributes {
 }
 
 /// Contents of the [Hole] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct HoleContents {
   /// The [HoleType] element specifies the type of hole.
   pub hole_type: Option<HoleType>,
@@ -54,7 +54,7 @@ pub struct HoleContents {
 /// The [Hole] element represents the symbols used for woodwind and brass fingerings as well as other notations.
 ///
 /// ![Hole](https://hedgetechllc.github.io/musicxml/musicxml/elements/hole.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Hole {
   /// Element-specific attributes
   pub attributes: HoleAttributes,
@@ -4,14 +4,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [HoleClosed] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct HoleClosedAttributes {
   /// Indicates which portion of the hole is filled in when the element value is half.
   pub location: Option<HoleClosedLocation>,
 }
 
 /// The [HoleClosed] element represents whether the hole is closed, open, or half-open.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("hole-closed")]
 pub struct HoleClosed {
   /// Element-specific attributes
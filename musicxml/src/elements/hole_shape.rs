@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [HoleShape] element indicates the shape of the hole symbol.
 ///
 /// It is a circle if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("hole-shape")]
 pub struct HoleShape {
   /// Element-specific attributes
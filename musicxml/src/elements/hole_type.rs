@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The content of the [HoleType] element indicates what the hole symbol represents in terms of instrument fingering or other techniques.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("hole-type")]
 pub struct HoleType {
   /// Element-specific attributes
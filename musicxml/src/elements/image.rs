@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Image] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ImageAttributes {
   /// The URL for the image file.
   pub source: AnyUri,
@@ -55,7 +55,7 @@ pub struct ImageAttributes {
 }
 
 /// The [Image] element is used to include graphical images in a score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Image {
   /// Element-specific attributes
   pub attributes: ImageAttributes,
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [InstrumentAbbreviation] element is typically used within a software application, rather than appearing on the printed page of a score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("instrument-abbreviation")]
 pub struct InstrumentAbbreviation {
   /// Element-specific attributes
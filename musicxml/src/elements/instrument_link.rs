@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [InstrumentLink] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct InstrumentLinkAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: IdRef,
@@ -15,7 +15,7 @@ pub struct InstrumentLinkAttributes {
 /// For example, a "Clarinet 1 and 2" part in a score file could link to separate "Clarinet 1" and "Clarinet 2" part files.
 /// The [InstrumentLink] element distinguishes which of the [ScoreInstruments][super::ScoreInstrument] within a [ScorePart][super::ScorePart]
 /// are in which part file.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("instrument-link")]
 pub struct InstrumentLink {
   /// Element-specific attributes
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [InstrumentName] element is typically used within a software application, rather than appearing on the printed page of a score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("instrument-name")]
 pub struct InstrumentName {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 ///
 /// This description is independent of a particular virtual or MIDI instrument specification and allows playback to be shared
 /// more easily between applications and libraries.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("instrument-sound")]
 pub struct InstrumentSound {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// The [Instruments] element is only used if more than one instrument is represented in the part.
 ///
 /// For example, oboe I and II where they play together most of the time. If absent, a value of 1 is assumed.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Instruments {
   /// Element-specific attributes
   pub attributes: (),
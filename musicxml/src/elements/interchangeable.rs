@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Interchangeable] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct InterchangeableAttributes {
   /// Indicates how to display the arrangement between the [Beats] and [BeatType] values in the second of the dual time signatures.
   pub separator: Option<TimeSeparator>,
@@ -14,7 +14,7 @@ pub struct InterchangeableAttributes {
 }
 
 /// Contents of the [InterchangeableBeatData] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct InterchangeableBeatData {
   /// The number of beats in the second of the dual time signatures.
   pub beats: Beats,
@@ -23,7 +23,7 @@ pub struct InterchangeableBeatData {
 }
 
 /// Contents of the [Interchangeable] element.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct InterchangeableContents {
   /// The time relation between the two dual time signatures.
   pub time_relation: Option<TimeRelation>,
@@ -32,12 +32,12 @@ pub struct InterchangeableContents {
 }
 
 impl ContentDeserializer for InterchangeableContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut temp_beats: Option<Beats> = None;
     let mut time_relation = None;
     let mut beat_data = Vec::new();
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "time-relation" => {
           time_relation = Some(TimeRelation::deserialize(element)?);
         }
@@ -51,7 +51,7 @@ impl ContentDeserializer for InterchangeableContents {
               beat_type: BeatType::deserialize(element)?,
             }),
             _ => Err(String::from("Missing required \"beat\" element prior to \"beat-type\""))?,
-          };
+          }
           temp_beats = None;
         }
         _ => (),
@@ -65,7 +65,7 @@ impl ContentDeserializer for InterchangeableContents {
 }
 
 impl ContentSerializer for InterchangeableContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     if let Some(el) = &element.time_relation {
       elements.push(TimeRelation::serialize(el));
@@ -79,7 +79,7 @@ impl ContentSerializer for InterchangeableContents {
 }
 
 /// The [Interchangeable] element is used to represent the second in a pair of interchangeable dual time signatures, such as the 6/8 in 3/4 (6/8).
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Interchangeable {
   /// Element-specific attributes
   pub attributes: InterchangeableAttributes,
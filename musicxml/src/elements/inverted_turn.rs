@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [InvertedTurn] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct InvertedTurnAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -61,7 +61,7 @@ pub struct InvertedTurnAttributes {
 /// The [InvertedTurn] element has the shape which goes down and then up.
 ///
 /// ![InvertedTurn](https://hedgetechllc.github.io/musicxml/musicxml/elements/inverted-turn.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("inverted-turn")]
 pub struct InvertedTurn {
   /// Element-specific attributes
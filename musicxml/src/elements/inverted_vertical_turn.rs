@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [InvertedVerticalTurn] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct InvertedVerticalTurnAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -59,7 +59,7 @@ pub struct InvertedVerticalTurnAttributes {
 /// The [InvertedVerticalTurn] element has the turn symbol shape arranged vertically going from upper right to lower left.
 ///
 /// ![InvertedVerticalTurn](https://hedgetechllc.github.io/musicxml/musicxml/elements/inverted-vertical-turn.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("inverted-vertical-turn")]
 pub struct InvertedVerticalTurn {
   /// Element-specific attributes
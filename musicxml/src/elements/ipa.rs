@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Ipa] element represents International Phonetic Alphabet (IPA) sounds for vocal music.
 ///
 /// String content is limited to IPA 2015 symbols represented in Unicode 13.0.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ipa {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Key] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct KeyAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -45,7 +45,7 @@ pub struct KeyAttributes {
 }
 
 /// Contents of the [ExplicitKeyContents] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ExplicitKeyContents {
   /// The [Cancel] element indicates the cancellation of a previous key signature.
   pub cancel: Option<Cancel>,
@@ -58,7 +58,7 @@ pub struct ExplicitKeyContents {
 }
 
 /// Contents of the [RelativeKeyContents] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct RelativeKeyContents {
   /// The [KeyStep] element represents the pitch step of the key signature.
   pub key_step: KeyStep,
@@ -73,7 +73,7 @@ pub struct RelativeKeyContents {
 /// Contents of the [Key] element.
 ///
 /// The [Key] element may contain either [ExplicitKeyContents] or [RelativeKeyContents].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum KeyContents {
   /// The [ExplicitKeyContents] element represents a key signature with a specified number of flats or sharps.
   Explicit(ExplicitKeyContents),
@@ -82,7 +82,7 @@ pub enum KeyContents {
 }
 
 impl ContentDeserializer for KeyContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     Ok(if elements.iter().any(|el| el.name == "fifths") {
       KeyContents::Explicit(ExplicitKeyContents::deserialize(elements)?)
     } else {
@@ -92,7 +92,7 @@ impl ContentDeserializer for KeyContents {
 }
 
 impl ContentSerializer for KeyContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     match &element {
       KeyContents::Explicit(content) => ExplicitKeyContents::serialize(content),
       KeyContents::Relative(content) => RelativeKeyContents::serialize(content),
@@ -104,7 +104,7 @@ impl ContentSerializer for KeyContents {
 ///
 /// Both traditional and non-traditional key signatures are supported. Key signatures appear at the start of each system
 /// unless the `print_object` attribute has been set to "no".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Key {
   /// Element-specific attributes
   pub attributes: KeyAttributes,
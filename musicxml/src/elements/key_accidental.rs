@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [KeyAccidental] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct KeyAccidentalAttributes {
   /// Specifies a Standard Music Font Layout (SMuFL) accidental character by its canonical glyph name.
   pub smufl: Option<SmuflAccidentalGlyphName>,
@@ -15,7 +15,7 @@ pub struct KeyAccidentalAttributes {
 ///
 /// It is used for disambiguating microtonal accidentals. The different element names indicate the different meaning of altering notes
 /// in a scale versus altering a sounding pitch.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("key-accidental")]
 pub struct KeyAccidental {
   /// Element-specific attributes
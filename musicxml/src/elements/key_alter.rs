@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// as the [Alter][super::Alter] element.
 ///
 /// The different element names indicate the different meaning of altering notes in a scale versus altering a sounding pitch.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("key-alter")]
 pub struct KeyAlter {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [KeyOctave] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct KeyOctaveAttributes {
   /// A positive integer that refers to the key signature element in left-to-right order.
   pub number: PositiveInteger,
@@ -17,7 +17,7 @@ pub struct KeyOctaveAttributes {
 /// The [KeyOctave] element specifies in which octave an element of a key signature appears.
 ///
 /// The content specifies the octave value using the same values as the [DisplayOctave][super::DisplayOctave] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("key-octave")]
 pub struct KeyOctave {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Kind] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct KindAttributes {
   /// The `bracket_degrees`` attribute is yes if all the degrees should be in a bracket. The default is implementation-dependent.
   pub bracket_degrees: Option<YesNo>,
@@ -85,7 +85,7 @@ pub struct KindAttributes {
 ///
 /// The `text` attribute may use strings such as "13sus" that refer to both the kind and one or more [Degree][super::Degree] elements. In this case,
 /// the corresponding [Degree][super::Degree] elements should have the `print_object` attribute set to "no" to keep redundant alterations from being displayed.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Kind {
   /// Element-specific attributes
   pub attributes: KindAttributes,
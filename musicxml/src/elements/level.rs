@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Level] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LevelAttributes {
   /// Specifies whether or not brackets are put around a symbol for an editorial indication. If not specified, it is left to application defaults.
   pub bracket: Option<YesNo>,
@@ -24,7 +24,7 @@ pub struct LevelAttributes {
 /// The [Level] element is used to specify editorial information for different MusicXML elements.
 ///
 /// The content contains identifying and/or descriptive text about the editorial status of the parent element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Level {
   /// Element-specific attributes
   pub attributes: LevelAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [LineDetail] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LineDetailAttributes {
   /// Indicates the staff line affected, numbered from bottom to top.
   pub line: StaffLine,
@@ -25,7 +25,7 @@ pub struct LineDetailAttributes {
 /// where a note placed below the higher line is distinct from a note placed above the lower line. Hidden staff lines are included when specifying clef
 /// lines and determining [DisplayStep][super::DisplayStep] / [DisplayOctave][super::DisplayOctave] values, but are not counted as lines for the purposes
 /// of the [SystemLayout][super::SystemLayout] and [StaffLayout][super::StaffLayout] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("line-detail")]
 pub struct LineDetail {
   /// Element-specific attributes
@@ -4,14 +4,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [LineWidth] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LineWidthAttributes {
   /// The type of line whose width is being defined.
   pub r#type: LineWidthType,
 }
 
 /// The [LineWidth] element indicates the width of a specific line type in tenths.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("line-width")]
 pub struct LineWidth {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Link] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LinkAttributes {
   /// The `xlink_href` attribute provides the data that allows an application to find a remote resource or resource fragment.
   /// See the definition in the [XML Linking Language recommendation](https://www.w3.org/TR/xlink11/#link-locators).
@@ -60,7 +60,7 @@ pub struct LinkAttributes {
 /// The [Link] element serves as an outgoing simple XLink.
 ///
 /// If a relative link is used within a document that is part of a compressed MusicXML file, the link is relative to the root folder of the zip file.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Link {
   /// Element-specific attributes
   pub attributes: LinkAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [ListenContents] element specifies all possible options available for use in a [Listen] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum ListenContents {
   /// The [Assess] element specifies the assessment of a performance.
   Assess(Assess),
@@ -20,7 +20,7 @@ pub enum ListenContents {
 ///
 /// The [Listen] element handles interactions that are specific to a note. If multiple child elements of the same type are present,
 /// they should have distinct `player` and/or `time_only` attributes.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Listen {
   /// Element-specific attributes
   pub attributes: (),
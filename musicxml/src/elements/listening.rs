@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Listening] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ListeningContents {
   /// The [Sync] element specifies the synchronization of the score-following or machine-listening data with the music.
   pub sync: Option<Sync>,
@@ -25,7 +25,7 @@ pub struct ListeningContents {
 ///
 /// Note that the [Offset] reflects the intended musical position for the change in state. It should not be used to compensate for latency issues in
 /// particular hardware configurations.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Listening {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Lyric] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LyricAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -48,7 +48,7 @@ pub struct LyricAttributes {
 }
 
 /// Contents of the [AdditionalTextLyric] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct AdditionalTextLyric {
   /// The [Elision] element represents an elision in a lyric.
   pub elision: Option<Elision>,
@@ -59,7 +59,7 @@ pub struct AdditionalTextLyric {
 }
 
 /// The [TextLyric] element represents the text of a lyric.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct TextLyric {
   /// The [Syllabic] element represents the type of syllable for a lyric.
   pub syllabic: Option<Syllabic>,
@@ -80,10 +80,10 @@ pub struct TextLyric {
 }
 
 impl ContentDeserializer for TextLyric {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut text_lyric = TextLyric::default();
     for el in elements {
-      match el.name.as_str() {
+      match el.name.as_ref() {
         "syllabic" => {
           if text_lyric.syllabic.is_none() {
             text_lyric.syllabic = Some(Syllabic::deserialize(el)?);
@@ -130,7 +130,7 @@ impl ContentDeserializer for TextLyric {
 }
 
 impl ContentSerializer for TextLyric {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     if let Some(content) = &element.syllabic {
       elements.push(Syllabic::serialize(content));
@@ -165,7 +165,7 @@ impl ContentSerializer for TextLyric {
 }
 
 /// The [ExtendLyric] element represents an extension of a lyric.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ExtendLyric {
   /// The [Extend] element represents an extension of a lyric.
   pub extend: Extend,
@@ -180,7 +180,7 @@ pub struct ExtendLyric {
 }
 
 /// The [LaughingLyric] element represents a laughing lyric.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct LaughingLyric {
   /// The [Laughing] element represents a laughing lyric.
   pub laughing: Laughing,
@@ -195,7 +195,7 @@ pub struct LaughingLyric {
 }
 
 /// The [HummingLyric] element represents a humming lyric.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct HummingLyric {
   /// The [Humming] element represents a humming lyric.
   pub humming: Humming,
@@ -212,7 +212,7 @@ pub struct HummingLyric {
 /// Contents of the [Lyric] element.
 ///
 /// The [Lyric] element may contain either a [TextLyric], [ExtendLyric], [LaughingLyric], or [HummingLyric].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum LyricContents {
   /// The [TextLyric] element represents the text of a lyric.
   Text(TextLyric),
@@ -225,7 +225,7 @@ pub enum LyricContents {
 }
 
 impl ContentDeserializer for LyricContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     Ok(if elements.iter().any(|el| el.name == "text") {
       LyricContents::Text(TextLyric::deserialize(elements)?)
     } else if elements.iter().any(|el| el.name == "laughing") {
@@ -239,7 +239,7 @@ impl ContentDeserializer for LyricContents {
 }
 
 impl ContentSerializer for LyricContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     match &element {
       LyricContents::Text(content) => TextLyric::serialize(content),
       LyricContents::Extend(content) => ExtendLyric::serialize(content),
@@ -265,7 +265,7 @@ impl ContentSerializer for LyricContents {
 ///
 /// The `print_object` attribute can override a [Note][super::Note]'s `print_lyric` attribute in cases where only some lyrics on a note are printed,
 /// as when lyrics for later verses are printed in a block of text rather than with each note.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Lyric {
   /// Element-specific attributes
   pub attributes: LyricAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [LyricFont] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LyricFontAttributes {
   /// A comma-separated list of font names.
   pub font_family: Option<FontFamily>,
@@ -21,7 +21,7 @@ pub struct LyricFontAttributes {
 }
 
 /// The [LyricFont] element specifies the default font for a particular name and number of lyric.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("lyric-font")]
 pub struct LyricFont {
   /// Element-specific attributes
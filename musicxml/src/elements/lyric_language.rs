@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [LyricLanguage] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct LyricLanguageAttributes {
   /// Specifies the language used in the element content. It is Italian ("it") if not specified.
   pub xml_lang: XmlLang,
@@ -15,7 +15,7 @@ pub struct LyricLanguageAttributes {
 }
 
 /// The [LyricLanguage] element specifies the default language for a particular name and number of lyric.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("lyric-language")]
 pub struct LyricLanguage {
   /// Element-specific attributes
@@ -8,7 +8,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Measure] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MeasureAttributes {
   /// The attribute that identifies the measure. Going from partwise to timewise, measures are grouped via this attribute.
   /// In partwise files, it should be the same for measures in different parts that share the same left barline.
@@ -36,7 +36,7 @@ pub struct MeasureAttributes {
 }
 
 /// The [MeasureElement] specifies all possible elements available for use in a [Measure] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum MeasureElement {
   /// The [Part] element is a container for musical data that is part of a score.
   Part(Part),
@@ -72,7 +72,7 @@ pub enum MeasureElement {
 }
 
 /// The [Measure] element includes the basic musical data such as [Notes][super::Note] within a document.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Measure {
   /// Element-specific attributes
   pub attributes: MeasureAttributes,
@@ -7,7 +7,7 @@ use musicxml_macros::*;
 ///
 /// This value is only used for systems where there is horizontal whitespace in the middle of a system, as in systems with codas.
 /// To specify the measure width, use the `width` attribute of the [Measure][super::Measure] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("measure-distance")]
 pub struct MeasureDistance {
   /// Element-specific attributes
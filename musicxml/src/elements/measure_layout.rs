@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [MeasureLayout] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct MeasureLayoutContents {
   /// The [MeasureDistance] element includes the horizontal distance from the previous measure.
   pub measure_distance: Option<MeasureDistance>,
@@ -13,7 +13,7 @@ pub struct MeasureLayoutContents {
 /// The [MeasureLayout] element includes the horizontal distance from the previous measure.
 ///
 /// It applies to the current measure only.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("measure-layout")]
 pub struct MeasureLayout {
   /// Element-specific attributes
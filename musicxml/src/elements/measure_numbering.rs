@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MeasureNumbering] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MeasureNumberingAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -74,7 +74,7 @@ pub struct MeasureNumberingAttributes {
 ///
 /// The `text` attribute from the [Measure][super::Measure] element is used for display, or the `number` attribute if the text attribute is not present.
 /// Measures with an `implicit` attribute set to yes never display a measure number, regardless of the [MeasureNumbering] setting.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("measure-numbering")]
 pub struct MeasureNumbering {
   /// Element-specific attributes
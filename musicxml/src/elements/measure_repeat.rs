@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MeasureRepeat] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MeasureRepeatAttributes {
   /// Indicates the starting or stopping point of the section displaying the measure repeat symbols.
   pub r#type: Option<StartStop>,
@@ -23,7 +23,7 @@ pub struct MeasureRepeatAttributes {
 ///
 /// The [MeasureRepeat] element specifies a notation style for repetitions. The actual music being repeated needs to be repeated within each measure of the MusicXML file.
 /// This element specifies the notation that indicates the repeat.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("measure-repeat")]
 pub struct MeasureRepeat {
   /// Element-specific attributes
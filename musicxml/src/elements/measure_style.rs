@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MeasureStyle] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MeasureStyleAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -27,7 +27,7 @@ pub struct MeasureStyleAttributes {
 /// Contents of the [MeasureStyle] element.
 ///
 /// The [MeasureStyle] element may contain either a [MultipleRest], [MeasureRepeat], [BeatRepeat], or [Slash].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum MeasureStyleContents {
   /// The [MultipleRest] element indicates a rest that spans multiple measures.
   MultipleRest(MultipleRest),
@@ -46,16 +46,17 @@ pub enum MeasureStyleContents {
 /// The [MultipleRest] and [MeasureRepeat] elements indicate the number of measures covered in the element content.
 /// The [BeatRepeat] and [Slash] elements can cover partial measures. All but the [MultipleRest] element use a `type` attribute to indicate
 /// starting and stopping the use of the style.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementWalk)]
 pub struct MeasureStyle {
   /// Element-specific attributes
   pub attributes: MeasureStyleAttributes,
+  #[flatten]
   /// Element-specific content
   pub content: MeasureStyleContents,
 }
 
 impl ElementDeserializer for MeasureStyle {
-  fn deserialize(element: &XmlElement) -> Result<Self, String> {
+  fn deserialize(element: &XmlElement<'_>) -> Result<Self, String> {
     Ok(MeasureStyle {
       attributes: MeasureStyleAttributes::deserialize(&element.attributes)?,
       content: match element
@@ -74,9 +75,9 @@ impl ElementDeserializer for MeasureStyle {
 }
 
 impl ElementSerializer for MeasureStyle {
-  fn serialize(element: &Self) -> XmlElement {
+  fn serialize(element: &Self) -> XmlElement<'static> {
     XmlElement {
-      name: String::from("measure-style"),
+      name: "measure-style".into(),
       attributes: MeasureStyleAttributes::serialize(&element.attributes),
       elements: vec![match &element.content {
         MeasureStyleContents::MultipleRest(content) => MultipleRest::serialize(content),
@@ -84,7 +85,7 @@ impl ElementSerializer for MeasureStyle {
         MeasureStyleContents::BeatRepeat(content) => BeatRepeat::serialize(content),
         MeasureStyleContents::Slash(content) => Slash::serialize(content),
       }],
-      text: String::new(),
+      ..Default::default()
     }
   }
 }
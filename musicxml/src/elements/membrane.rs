@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Membrane] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MembraneAttributes {
   /// Distinguishes different SMuFL stylistic alternates.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct MembraneAttributes {
 /// The [Membrane] element represents pictograms for membrane percussion instruments.
 ///
 /// ![Membrane](https://hedgetechllc.github.io/musicxml/musicxml/elements/membrane.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Membrane {
   /// Element-specific attributes
   pub attributes: MembraneAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Metal] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MetalAttributes {
   /// Distinguishes different SMuFL stylistic alternates.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct MetalAttributes {
 /// The [Metal] element represents pictograms for metal percussion instruments.
 ///
 /// ![Metal](https://hedgetechllc.github.io/musicxml/musicxml/elements/metal.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Metal {
   /// Element-specific attributes
   pub attributes: MetalAttributes,
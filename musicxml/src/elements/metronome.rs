@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Metronome] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MetronomeAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -66,7 +66,7 @@ pub struct MetronomeAttributes {
 }
 
 /// The [BeatBasedEquation] element specifies the metronome mark in terms of beat units.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct BeatBasedEquation {
   /// The [BeatUnit] element specifies the beat unit for the metronome mark.
   pub beat_unit: BeatUnit,
@@ -77,7 +77,7 @@ pub struct BeatBasedEquation {
 }
 
 /// The [BeatEquation] element specifies the metronome mark in terms of beats per minute or beat units.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum BeatEquation {
   /// The [PerMinute] element specifies the metronome mark in terms of beats per minute.
   BPM(PerMinute),
@@ -86,7 +86,7 @@ pub enum BeatEquation {
 }
 
 /// The [BeatBased] element specifies the metronome mark in terms of beat units.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct BeatBased {
   /// The [BeatUnit] element specifies the beat unit for the metronome mark.
   pub beat_unit: BeatUnit,
@@ -99,13 +99,13 @@ pub struct BeatBased {
 }
 
 impl ContentDeserializer for BeatBased {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut beat_unit: Option<BeatUnit> = None;
     let mut beat_unit_dot: Vec<BeatUnitDot> = Vec::new();
     let mut beat_unit_tied: Vec<BeatUnitTied> = Vec::new();
     let mut equals: Option<BeatEquation> = None;
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "beat-unit" => {
           if beat_unit.as_mut().is_some() {
             equals = Some(BeatEquation::Beats(BeatBasedEquation {
@@ -151,7 +151,7 @@ impl ContentDeserializer for BeatBased {
 }
 
 impl ContentSerializer for BeatBased {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     elements.push(BeatUnit::serialize(&element.beat_unit));
     for el in &element.beat_unit_dot {
@@ -177,7 +177,7 @@ impl ContentSerializer for BeatBased {
 }
 
 /// The [AdditionalMetronomeBasedContents] element specifies additional metronome marks.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct AdditionalMetronomeBasedContents {
   /// The [MetronomeRelation] element specifies the relationship between additional metronome marks.
   pub metronome_relation: MetronomeRelation,
@@ -186,7 +186,7 @@ pub struct AdditionalMetronomeBasedContents {
 }
 
 /// The [MetronomeBased] element specifies the metronome mark in terms of beat units.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct MetronomeBased {
   /// The [MetronomeArrows] element specifies the appearance of arrows in the metronome mark.
   pub metronome_arrows: Option<MetronomeArrows>,
@@ -197,10 +197,10 @@ pub struct MetronomeBased {
 }
 
 impl ContentDeserializer for MetronomeBased {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut content = MetronomeBased::default();
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "metronome-arrows" => {
           content.metronome_arrows = Some(MetronomeArrows::deserialize(element)?);
         }
@@ -225,7 +225,7 @@ impl ContentDeserializer for MetronomeBased {
 }
 
 impl ContentSerializer for MetronomeBased {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     if let Some(content) = &element.metronome_arrows {
       elements.push(MetronomeArrows::serialize(content));
@@ -246,7 +246,7 @@ impl ContentSerializer for MetronomeBased {
 /// Contents of the [Metronome] element.
 ///
 /// The [Metronome] element may contain either [BeatBased] or [MetronomeBased] contents.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum MetronomeContents {
   /// The [BeatBased] element specifies the metronome mark in terms of beat units.
   BeatBased(BeatBased),
@@ -255,7 +255,7 @@ pub enum MetronomeContents {
 }
 
 impl ContentDeserializer for MetronomeContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     Ok(if elements.iter().any(|el| el.name == "metronome-note") {
       MetronomeContents::MetronomeBased(MetronomeBased::deserialize(elements)?)
     } else {
@@ -265,7 +265,7 @@ impl ContentDeserializer for MetronomeContents {
 }
 
 impl ContentSerializer for MetronomeContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     match &element {
       MetronomeContents::BeatBased(content) => BeatBased::serialize(content),
       MetronomeContents::MetronomeBased(content) => MetronomeBased::serialize(content),
@@ -280,7 +280,7 @@ impl ContentSerializer for MetronomeContents {
 /// such as swing tempo marks where two eighths are equated to a quarter note / eighth note triplet.
 /// Tied notes can be represented in both types of metronome marks by using the [BeatUnitTied] and [MetronomeTied][super::MetronomeTied] elements.
 /// The `print_object` attribute is set to "no" in cases where the [Metronome] element represents a relationship or range that is not displayed in the music notation.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Metronome {
   /// Element-specific attributes
   pub attributes: MetronomeAttributes,
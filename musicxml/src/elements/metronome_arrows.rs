@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// If the [MetronomeArrows] element is present, it indicates that metric modulation arrows are displayed on both sides of the metronome mark.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-arrows")]
 pub struct MetronomeArrows {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MetronomeBeam] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MetronomeBeamAttributes {
   /// Indicates eighth note through 1024th note beams using number values 1 thru 8 respectively. The default value is 1.
   pub number: Option<BeamLevel>,
@@ -14,7 +14,7 @@ pub struct MetronomeBeamAttributes {
 /// but does not include all the attributes available in the [Beam][super::Beam] element.
 ///
 /// ![MetronomeBeam](https://hedgetechllc.github.io/musicxml/musicxml/elements/metronome-beam.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-beam")]
 pub struct MetronomeBeam {
   /// Element-specific attributes
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [MetronomeDot] element works like the [Dot][super::Dot] element in defining metric relationships.
-#[derive(Debug, Default, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-dot")]
 pub struct MetronomeDot {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [MetronomeNote] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct MetronomeNoteContents {
   /// The [MetronomeType] element specifies the note type for the metronome mark.
   pub metronome_type: MetronomeType,
@@ -19,7 +19,7 @@ pub struct MetronomeNoteContents {
 }
 
 /// The [MetronomeNote] element defines the appearance of a note within a metric relationship mark.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-note")]
 pub struct MetronomeNote {
   /// Element-specific attributes
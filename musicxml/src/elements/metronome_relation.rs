@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// ![MetronomeRelation](https://hedgetechllc.github.io/musicxml/musicxml/elements/metronome-relation.png)
 ///
 /// The currently allowed value is "equals", but this may expand in future versions. If the element is empty, the equals value is used.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-relation")]
 pub struct MetronomeRelation {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MetronomeTied] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MetronomeTiedAttributes {
   /// Indicates if this is the start or stop of the tie.
   pub r#type: StartStop,
@@ -14,7 +14,7 @@ pub struct MetronomeTiedAttributes {
 ///
 /// As with the [Tied][super::Tied] element, both the start and stop of the tie should be specified, in this case within separate
 /// [MetronomeNote][super::MetronomeNote] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-tied")]
 pub struct MetronomeTied {
   /// Element-specific attributes
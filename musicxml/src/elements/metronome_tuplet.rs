@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MetronomeTuplet] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MetronomeTupletAttributes {
   /// Indicates if this is the start or stop of the tuplet.
   pub r#type: StartStop,
@@ -16,7 +16,7 @@ pub struct MetronomeTupletAttributes {
 }
 
 /// Contents of the [MetronomeTuplet] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct MetronomeTupletContents {
   /// The [ActualNotes] element specifies the number of notes in the tuplet.
   pub actual_notes: ActualNotes,
@@ -32,7 +32,7 @@ pub struct MetronomeTupletContents {
 /// along with some attributes from the [Tuplet][super::Tuplet] element.
 ///
 /// ![MetronomeTuplet](https://hedgetechllc.github.io/musicxml/musicxml/elements/metronome-tuplet.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("metronome-tuplet")]
 pub struct MetronomeTuplet {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Mf] element represents a mezzo forte dynamic marking.
 ///
 /// ![mf](https://hedgetechllc.github.io/musicxml/musicxml/elements/mf.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Mf {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [MidiBank] element specifies a MIDI 1.0 bank number ranging from 1 to 16,384.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("midi-bank")]
 pub struct MidiBank {
   /// Element-specific attributes
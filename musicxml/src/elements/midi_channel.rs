@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [MidiChannel] element specifies a MIDI 1.0 channel numbers ranging from 1 to 16.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("midi-channel")]
 pub struct MidiChannel {
   /// Element-specific attributes
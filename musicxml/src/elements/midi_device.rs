@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MidiDevice] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MidiDeviceAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<IdRef>,
@@ -15,7 +15,7 @@ pub struct MidiDeviceAttributes {
 /// The [MidiDevice] element corresponds to the `DeviceName` meta event in Standard MIDI Files.
 ///
 /// Unlike the `DeviceName` meta event, there can be multiple [MidiDevice] elements per MusicXML part.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("midi-device")]
 pub struct MidiDevice {
   /// Element-specific attributes
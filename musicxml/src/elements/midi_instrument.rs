@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MidiInstrument] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MidiInstrumentAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: IdRef,
 }
 
 /// Contents of the [MidiInstrument] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct MidiInstrumentContents {
   /// The [MidiChannel] element specifies the MIDI 1.0 channel number.
   pub midi_channel: Option<MidiChannel>,
@@ -36,7 +36,7 @@ pub struct MidiInstrumentContents {
 ///
 /// The [MidiInstrument] element can be a part of either the [ScoreInstrument][super::ScoreInstrument] element at the start of a part,
 /// or the [Sound][super::Sound] element within a part.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("midi-instrument")]
 pub struct MidiInstrument {
   /// Element-specific attributes
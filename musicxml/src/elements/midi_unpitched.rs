@@ -7,7 +7,7 @@ use musicxml_macros::*;
 ///
 /// It is usually used with MIDI banks for percussion. Note that MIDI 1.0 note numbers are generally specified from 0 to 127
 /// rather than the 1 to 128 numbering used in this element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("midi-unpitched")]
 pub struct MidiUnpitched {
   /// Element-specific attributes
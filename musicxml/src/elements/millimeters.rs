@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [Millimeters] element contains the number of millimeters that correspond to the given number of tenths within the [Scaling][super::Scaling] element formula.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Millimeters {
   /// Element-specific attributes
   pub attributes: (),
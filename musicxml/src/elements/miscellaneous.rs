@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Miscellaneous] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct MiscellaneousContents {
   /// The [MiscellaneousField] element specifies a part of the metadata.
   pub miscellaneous_field: Vec<MiscellaneousField>,
@@ -13,7 +13,7 @@ pub struct MiscellaneousContents {
 /// If a program has other metadata not yet supported in the MusicXML format, it can go in the [Miscellaneous] element.
 ///
 /// The [Miscellaneous] element puts each separate part of metadata into its own [MiscellaneousField] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Miscellaneous {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,14 +4,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MiscellaneousField] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MiscellaneousFieldAttributes {
   /// Indicates the type of metadata the element content represents.
   pub name: Token,
 }
 
 /// If a program has other metadata not yet supported in the MusicXML format, each type of metadata can go in a [MiscellaneousField] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("miscellaneous-field")]
 pub struct MiscellaneousField {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Mordent] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MordentAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -67,7 +67,7 @@ pub struct MordentAttributes {
 /// ![Mordent](https://hedgetechllc.github.io/musicxml/musicxml/elements/mordent.png)
 ///
 /// The choice of which mordent sign is inverted differs between MusicXML and the Standard Music Font Layout (SMuFL).
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Mordent {
   /// Element-specific attributes
   pub attributes: MordentAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MultipleRest] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MultipleRestAttributes {
   /// Specifies whether the multiple rests uses the 1-bar / 2-bar / 4-bar rest symbols,
   /// or a single shape. It is no if not specified.
@@ -16,7 +16,7 @@ pub struct MultipleRestAttributes {
 /// ![MultipleRest](https://hedgetechllc.github.io/musicxml/musicxml/elements/multiple-rest.png)
 ///
 /// The element text indicates the number of measures in the multiple rest.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("multiple-rest")]
 pub struct MultipleRest {
   /// Element-specific attributes
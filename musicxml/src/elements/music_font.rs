@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [MusicFont] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct MusicFontAttributes {
   /// A comma-separated list of font names.
   pub font_family: Option<FontFamily>,
@@ -17,7 +17,7 @@ pub struct MusicFontAttributes {
 }
 
 /// The [MusicFont] element represents the default values for the music font in the score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("music-font")]
 pub struct MusicFont {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [N] element represents a niente dynamic marking.
 ///
 /// ![n](https://hedgetechllc.github.io/musicxml/musicxml/elements/n.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct N {
   /// Element-specific attributes
   pub attributes: (),
@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// ![Natural](https://hedgetechllc.github.io/musicxml/musicxml/elements/natural.png)
 ///
 /// These are usually notated at base pitch rather than sounding pitch.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Natural {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [NonArpeggiate] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NonArpeggiateAttributes {
   /// Indicates whether this is the top or bottom of the symbol.
   pub r#type: TopBottom,
@@ -40,7 +40,7 @@ pub struct NonArpeggiateAttributes {
 /// The [NonArpeggiate] element indicates that this [Note][super::Note] is at the top or bottom of a bracket indicating to not arpeggiate these notes.
 ///
 /// Since this does not involve playback, it is only used on the top or bottom notes, not on each [Note][super::Note] as for the [Arpeggiate][super::Arpeggiate] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("non-arpeggiate")]
 pub struct NonArpeggiate {
   /// Element-specific attributes
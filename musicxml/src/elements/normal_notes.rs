@@ -8,7 +8,7 @@ use musicxml_macros::*;
 /// If the type associated with the number in the [NormalNotes] element is different than the current note type
 /// (e.g., a quarter note within an eighth note triplet), then the [NormalNotes] type (e.g. eighth) is specified
 /// in the [NormalType][super::NormalType] and [NormalDot][super::NormalDot] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("normal-notes")]
 pub struct NormalNotes {
   /// Element-specific attributes
@@ -8,7 +8,7 @@ use musicxml_macros::*;
 /// If the type associated with the number in the [NormalNotes][super::NormalNotes] element is different than the current note type
 /// (e.g., a quarter note within an eighth note triplet), then the [NormalNotes][super::NormalNotes] type
 /// (e.g. eighth) is specified in the [NormalType] and [NormalDot][super::NormalDot] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("normal-type")]
 pub struct NormalType {
   /// Element-specific attributes
@@ -8,7 +8,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Notations] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NotationsAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
@@ -17,7 +17,7 @@ pub struct NotationsAttributes {
 }
 
 /// The [NotationContentTypes] contains the various notations that can be applied to a note or chord.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum NotationContentTypes {
   /// The [Tied] element indicates that a tie begins or ends with this note.
   Tied(Tied),
@@ -50,7 +50,7 @@ pub enum NotationContentTypes {
 }
 
 /// Contents of the [Notations] element.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct NotationsContents {
   /// The [Footnote] element is used to specify editorial information or analysis.
   pub footnote: Option<Footnote>,
@@ -61,10 +61,10 @@ pub struct NotationsContents {
 }
 
 impl ContentDeserializer for NotationsContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut notations = NotationsContents::default();
     for child in elements {
-      match child.name.as_str() {
+      match child.name.as_ref() {
         "footnote" => notations.footnote = Some(Footnote::deserialize(child)?),
         "level" => notations.level = Some(Level::deserialize(child)?),
         "tied" => notations
@@ -121,7 +121,7 @@ impl ContentDeserializer for NotationsContents {
 }
 
 impl ContentSerializer for NotationsContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     if let Some(content) = &element.footnote {
       elements.push(Footnote::serialize(content));
@@ -156,7 +156,7 @@ impl ContentSerializer for NotationsContents {
 /// Multiple [Notations] elements are allowed in order to represent multiple editorial levels.
 /// The `print_object` attribute allows [Notations] to represent details of performance technique, such as fingerings,
 /// without having them appear in the score. This element is not related to the concept of XML notations.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Notations {
   /// Element-specific attributes
   pub attributes: NotationsAttributes,
@@ -10,7 +10,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Note] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NoteAttributes {
   /// Alters the starting time of the note from when it would otherwise occur based on the flow of durations -
   /// information that is specific to a performance. It is expressed in terms of divisions, either positive or negative.
@@ -79,7 +79,7 @@ pub struct NoteAttributes {
 }
 
 /// The [AudibleType] element contains whether the note is a pitch, unpitched, or rest.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum AudibleType {
   /// The [Pitch] element contains the pitch of a note.
   Pitch(Pitch),
@@ -90,7 +90,7 @@ pub enum AudibleType {
 }
 
 /// The [GraceNormalInfo] element contains the actual data for a normal grace note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct GraceNormalInfo {
   /// The [Chord] element indicates that this note is a chord note.
   pub chord: Option<Chord>,
@@ -101,12 +101,12 @@ pub struct GraceNormalInfo {
 }
 
 impl ContentDeserializer for GraceNormalInfo {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut chord: Option<Chord> = None;
     let mut audible: Option<AudibleType> = None;
     let mut tie: Vec<Tie> = Vec::new();
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "chord" => chord = Some(Chord::deserialize(element)?),
         "pitch" => audible = Some(AudibleType::Pitch(Pitch::deserialize(element)?)),
         "unpitched" => audible = Some(AudibleType::Unpitched(Unpitched::deserialize(element)?)),
@@ -128,7 +128,7 @@ impl ContentDeserializer for GraceNormalInfo {
 }
 
 impl ContentSerializer for GraceNormalInfo {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     if let Some(content) = &element.chord {
       elements.push(Chord::serialize(content));
@@ -146,7 +146,7 @@ impl ContentSerializer for GraceNormalInfo {
 }
 
 /// The [GraceCueInfo] element contains the actual data for a cue grace note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct GraceCueInfo {
   /// The [Cue] element indicates that this note is a cue note.
   pub cue: Cue,
@@ -157,12 +157,12 @@ pub struct GraceCueInfo {
 }
 
 impl ContentDeserializer for GraceCueInfo {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut cue: Option<Cue> = None;
     let mut chord: Option<Chord> = None;
     let mut audible: Option<AudibleType> = None;
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "cue" => cue = Some(Cue::deserialize(element)?),
         "chord" => chord = Some(Chord::deserialize(element)?),
         "pitch" => audible = Some(AudibleType::Pitch(Pitch::deserialize(element)?)),
@@ -188,7 +188,7 @@ impl ContentDeserializer for GraceCueInfo {
 }
 
 impl ContentSerializer for GraceCueInfo {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     elements.push(Cue::serialize(&element.cue));
     if let Some(content) = &element.chord {
@@ -204,7 +204,7 @@ impl ContentSerializer for GraceCueInfo {
 }
 
 /// The [GraceType] element contains the actual data for either a cue grace note or a normal grace note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum GraceType {
   /// The [GraceCueInfo] element contains the actual data for a cue grace note.
   Cue(GraceCueInfo),
@@ -213,7 +213,7 @@ pub enum GraceType {
 }
 
 /// The [GraceInfo] element contains the actual data for a grace note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct GraceInfo {
   /// The [Grace] element indicates that this note is a grace note.
   pub grace: Grace,
@@ -222,7 +222,7 @@ pub struct GraceInfo {
 }
 
 impl ContentDeserializer for GraceInfo {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     Ok(GraceInfo {
       grace: Grace::deserialize(elements.first().ok_or("Missing <grace> sub-element")?)?,
       info: if elements.iter().any(|el| el.name == "cue") {
@@ -235,7 +235,7 @@ impl ContentDeserializer for GraceInfo {
 }
 
 impl ContentSerializer for GraceInfo {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     elements.push(Grace::serialize(&element.grace));
     match &element.info {
@@ -247,7 +247,7 @@ impl ContentSerializer for GraceInfo {
 }
 
 /// The [CueInfo] element contains the actual data for a cue note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct CueInfo {
   /// The [Cue] element indicates that this note is a cue note.
   pub cue: Cue,
@@ -260,13 +260,13 @@ pub struct CueInfo {
 }
 
 impl ContentDeserializer for CueInfo {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut cue: Option<Cue> = None;
     let mut chord: Option<Chord> = None;
     let mut audible: Option<AudibleType> = None;
     let mut duration: Option<Duration> = None;
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "cue" => cue = Some(Cue::deserialize(element)?),
         "chord" => chord = Some(Chord::deserialize(element)?),
         "pitch" => audible = Some(AudibleType::Pitch(Pitch::deserialize(element)?)),
@@ -298,7 +298,7 @@ impl ContentDeserializer for CueInfo {
 }
 
 impl ContentSerializer for CueInfo {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     elements.push(Cue::serialize(&element.cue));
     if let Some(content) = &element.chord {
@@ -315,7 +315,7 @@ impl ContentSerializer for CueInfo {
 }
 
 /// The [NormalInfo] element contains the actual data for a normal note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct NormalInfo {
   /// The [Chord] element indicates that this note is a chord note.
   pub chord: Option<Chord>,
@@ -328,13 +328,13 @@ pub struct NormalInfo {
 }
 
 impl ContentDeserializer for NormalInfo {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut chord: Option<Chord> = None;
     let mut audible: Option<AudibleType> = None;
     let mut duration: Option<Duration> = None;
     let mut tie: Vec<Tie> = Vec::new();
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "chord" => chord = Some(Chord::deserialize(element)?),
         "pitch" => audible = Some(AudibleType::Pitch(Pitch::deserialize(element)?)),
         "unpitched" => audible = Some(AudibleType::Unpitched(Unpitched::deserialize(element)?)),
@@ -362,7 +362,7 @@ impl ContentDeserializer for NormalInfo {
 }
 
 impl ContentSerializer for NormalInfo {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     if let Some(content) = &element.chord {
       elements.push(Chord::serialize(content));
@@ -381,7 +381,7 @@ impl ContentSerializer for NormalInfo {
 }
 
 /// The [NoteType] element contains the actual data for a note.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum NoteType {
   /// The [GraceInfo] element contains the actual data for a grace note.
   Grace(GraceInfo),
@@ -392,7 +392,7 @@ pub enum NoteType {
 }
 
 /// Contents of the [Note] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct NoteContents {
   /// The [NoteType] element contains the actual data for the note.
   pub info: NoteType,
@@ -433,7 +433,7 @@ pub struct NoteContents {
 }
 
 impl ContentDeserializer for NoteContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut note_contents = NoteContents {
       info: if elements.first().ok_or("Missing <note> sub-element")?.name == "grace" {
         NoteType::Grace(GraceInfo::deserialize(elements)?)
@@ -461,7 +461,7 @@ impl ContentDeserializer for NoteContents {
       listen: None,
     };
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "instrument" => note_contents.instrument.push(Instrument::deserialize(element)?),
         "footnote" => note_contents.footnote = Some(Footnote::deserialize(element)?),
         "level" => note_contents.level = Some(Level::deserialize(element)?),
@@ -487,7 +487,7 @@ impl ContentDeserializer for NoteContents {
 }
 
 impl ContentSerializer for NoteContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     match &element.info {
       NoteType::Grace(content) => elements.extend(GraceInfo::serialize(content)),
@@ -555,7 +555,7 @@ impl ContentSerializer for NoteContents {
 /// (e.g., [Tie] is used for sound, [Tied][super::Tied] for notation). Thus grace notes do not have a [Duration] element. Cue notes
 /// have a [Duration] element, as do [Forward][super::Forward] elements, but no [Tie] elements. Having these two types of information
 /// available can make interchange easier, as some programs handle one type of information more readily than the other.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Note {
   /// Element-specific attributes
   pub attributes: NoteAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [NoteSize] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NoteSizeAttributes {
   /// The type of note size being defined.
   pub r#type: NoteSizeType,
@@ -14,7 +14,7 @@ pub struct NoteSizeAttributes {
 /// as defined in the [Type][super::Type] element.
 ///
 /// A value of 100 would be identical to the size of a regular note as defined by the music font.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("note-size")]
 pub struct NoteSize {
   /// Element-specific attributes
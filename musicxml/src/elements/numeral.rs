@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Numeral] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct NumeralContents {
   /// The [NumeralRoot] element specifies the root of the Roman numeral or Nashville number.
   pub numeral_root: NumeralRoot,
@@ -17,7 +17,7 @@ pub struct NumeralContents {
 /// The [Numeral] element represents the Roman numeral or Nashville number part of a harmony.
 ///
 /// It requires that the key be specified in the encoding, either with a [Key][super::Key] or [NumeralKey] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Numeral {
   /// Element-specific attributes
   pub attributes: (),
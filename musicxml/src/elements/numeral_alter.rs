@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [NumeralAlter] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NumeralAlterAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct NumeralAlterAttributes {
 
 /// The [NumeralAlter] element represents an alteration to the [NumeralRoot][super::NumeralRoot],
 /// similar to the [Alter][super::Alter] element for a [Pitch][super::Pitch].
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("numeral-alter")]
 pub struct NumeralAlter {
   /// Element-specific attributes
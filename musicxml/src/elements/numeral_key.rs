@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [NumeralKey] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NumeralKeyAttributes {
   /// Specifies whether or not to print an object. It is yes if not specified.
   pub print_object: Option<YesNo>,
 }
 
 /// Contents of the [NumeralKey] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct NumeralKeyContents {
   /// The [NumeralFifths] element specifies the number of fifths in the key signature of the numeral.
   pub numeral_fifths: NumeralFifths,
@@ -21,7 +21,7 @@ pub struct NumeralKeyContents {
 }
 
 /// The [NumeralKey] element is used when the key for the numeral is different than the key specified by the key signature.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("numeral-key")]
 pub struct NumeralKey {
   /// Element-specific attributes
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [NumeralMode] specifies the scale that is used to interpret the [NumeralRoot][super::NumeralRoot] element values.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("numeral-mode")]
 pub struct NumeralMode {
   /// Element-specific attributes
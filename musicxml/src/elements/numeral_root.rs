@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [NumeralRoot] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct NumeralRootAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -41,7 +41,7 @@ pub struct NumeralRootAttributes {
 }
 
 /// The [NumeralRoot] element represents the Roman numeral or Nashville number as a positive integer from 1 to 7.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("numeral-root")]
 pub struct NumeralRoot {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 ///
 /// The [OctaveChange] element should be included when using transposition intervals of an octave or more,
 /// and should not be present for intervals of less than an octave.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("octave-change")]
 pub struct OctaveChange {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OctaveShift] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OctaveShiftAttributes {
   /// Indicates if this is the start, stop, or continuation of the octave shift.
   /// The start is specified as a shift up or down from their performed values.
@@ -79,7 +79,7 @@ impl Default for OctaveShiftAttributes {
 /// ![OctaveShift](https://hedgetechllc.github.io/musicxml/musicxml/elements/octave-shift.png)
 ///
 /// A treble clef line noted with 8va will be indicated with an [OctaveShift] down from the pitch data indicated in the notes.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("octave-shift")]
 pub struct OctaveShift {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Offset] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OffsetAttributes {
   /// The offset affects the visual appearance of the direction. If the `sound` attribute is yes,
   /// then the offset affects playback and listening too. If it is no, then any [Sound][super::Sound] or
@@ -17,7 +17,7 @@ pub struct OffsetAttributes {
 ///
 /// The current musical location is always within the current measure, even at the end of a measure. If an element within a [Direction][super::Direction]
 /// includes a `default_x` attribute, the [Offset] value will be ignored when determining the appearance of that element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Offset {
   /// Element-specific attributes
   pub attributes: OffsetAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Open] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OpenAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -46,7 +46,7 @@ pub struct OpenAttributes {
 ///
 /// The `smufl` attribute can be used to distinguish different Standard Music Font Layout (SMuFL) glyphs that have a similar appearance
 /// such as "brassMuteOpen" and "guitarOpenPedal". If not present, the default glyph is "brassMuteOpen".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Open {
   /// Element-specific attributes
   pub attributes: OpenAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Opus] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OpusAttributes {
   /// The `xlink_href` attribute provides the data that allows an application to find a remote resource or resource fragment.
   /// See the definition in the [XML Linking Language recommendation](https://www.w3.org/TR/xlink11/#link-locators).
@@ -27,7 +27,7 @@ pub struct OpusAttributes {
 }
 
 /// The [Opus] element represents a link to a MusicXML opus document that composes multiple MusicXML scores into a collection.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Opus {
   /// Element-specific attributes
   pub attributes: OpusAttributes,
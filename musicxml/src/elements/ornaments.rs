@@ -8,14 +8,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Ornaments] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OrnamentsAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
 }
 
 /// The [OrnamentType] element specifies the ornaments available to be performed.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum OrnamentType {
   /// The [TrillMark] element represents the trill-mark symbol.
   #[rename("trill-mark")]
@@ -59,7 +59,7 @@ pub enum OrnamentType {
 }
 
 /// Contents of the [Ornaments] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentSerialize, ContentWalk)]
 pub struct OrnamentContents {
   /// The [OrnamentType] elements specify the ornaments to be performed.
   pub ornaments: Vec<OrnamentType>,
@@ -68,7 +68,7 @@ pub struct OrnamentContents {
 }
 
 impl ContentDeserializer for OrnamentContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     Ok(OrnamentContents {
       ornaments: elements
         .iter()
@@ -92,7 +92,7 @@ impl ContentDeserializer for OrnamentContents {
 ///
 /// The [AccidentalMark] element's content is represented the same as an [Accidental][super::Accidental] element, but with a different
 /// name to reflect the different musical meaning.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ornaments {
   /// Element-specific attributes
   pub attributes: OrnamentsAttributes,
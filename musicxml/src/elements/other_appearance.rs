@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherAppearance] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherAppearanceAttributes {
   /// The appearance type being specified.
   pub r#type: Token,
@@ -13,7 +13,7 @@ pub struct OtherAppearanceAttributes {
 /// The [OtherAppearance] element is used to define any graphical settings not yet in the current version of the MusicXML format.
 ///
 /// This allows extended representation, though without application interoperability.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-appearance")]
 pub struct OtherAppearance {
   /// Element-specific attributes
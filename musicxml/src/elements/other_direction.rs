@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherDirection] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherDirectionAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -66,7 +66,7 @@ pub struct OtherDirectionAttributes {
 /// The `smufl` attribute can be used to specify a particular direction symbol, allowing application interoperability without requiring every
 /// SMuFL glyph to have a MusicXML element equivalent. Using the [OtherDirection] element without the `smufl` attribute allows for extended representation,
 /// though without application interoperability.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-direction")]
 pub struct OtherDirection {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherListen] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherListenAttributes {
   /// Indicates the type of listening to which the element content applies.
   pub r#type: Token,
@@ -16,7 +16,7 @@ pub struct OtherListenAttributes {
 }
 
 /// The [OtherListen] element represents other types of listening control and interaction that are specific to a note.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-listen")]
 pub struct OtherListen {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherListening] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherListeningAttributes {
   /// Indicates the type of listening to which the element content applies.
   pub r#type: Token,
@@ -17,7 +17,7 @@ pub struct OtherListeningAttributes {
 
 /// The [OtherListening] element represents other types of listening control and interaction that change the state of the
 /// listening application from the specified point in the performance onward.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-listening")]
 pub struct OtherListening {
   /// Element-specific attributes
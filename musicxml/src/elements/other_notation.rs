@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherNotation] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherNotationAttributes {
   /// Indicates if this is a single-note notation, or the start or stop of a multi-note notation.
   pub r#type: StartStopSingle,
@@ -61,7 +61,7 @@ pub struct OtherNotationAttributes {
 /// The `smufl` attribute can be used to specify a particular notation, allowing application interoperability without requiring every
 /// Standard Music Font Layout (SMuFL) glyph to have a MusicXML element equivalent. Using the [OtherNotation] element without the `smufl` attribute
 /// allows for extended representation, though without application interoperability.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-notation")]
 pub struct OtherNotation {
   /// Element-specific attributes
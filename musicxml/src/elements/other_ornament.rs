@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherOrnament] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherOrnamentAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -47,7 +47,7 @@ pub struct OtherOrnamentAttributes {
 /// The `smufl` attribute can be used to specify a particular ornament, allowing application interoperability without requiring every
 /// Standard Music Font Layout (SMuFL) ornament to have a MusicXML element equivalent. Using the [OtherOrnament] element without the `smufl` attribute
 /// allows for extended representation, though without application interoperability.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-ornament")]
 pub struct OtherOrnament {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherPercussion] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherPercussionAttributes {
   /// Indicates a particular Standard Music Font Layout (SMuFL) character using its canonical glyph name.
   /// Sometimes this is a formatting choice, and sometimes this is a refinement of the semantic meaning of an element.
@@ -12,7 +12,7 @@ pub struct OtherPercussionAttributes {
 }
 
 /// The [OtherPercussion] element represents percussion pictograms not defined elsewhere.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-percussion")]
 pub struct OtherPercussion {
   /// Element-specific attributes
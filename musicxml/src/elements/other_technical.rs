@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [OtherTechnical] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct OtherTechnicalAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -47,7 +47,7 @@ pub struct OtherTechnicalAttributes {
 /// The `smufl` attribute can be used to specify a particular glyph, allowing application interoperability without requiring every
 /// Standard Music Font Layout (SMuFL) technical indication to have a MusicXML element equivalent. Using the [OtherTechnical] element
 /// without the `smufl` attribute allows for extended representation, though without application interoperability.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("other-technical")]
 pub struct OtherTechnical {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [P] element represents a piano dynamic marking.
 ///
 /// ![p](https://hedgetechllc.github.io/musicxml/musicxml/elements/p.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct P {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [PageLayout] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PageLayoutContents {
   /// The [PageHeight] element specifies the height of the page.
   pub page_height: Option<PageHeight>,
@@ -22,7 +22,7 @@ pub struct PageLayoutContents {
 /// All other pages use the default values as determined by the [Defaults][super::Defaults] element. If any child elements are missing
 /// from the [PageLayout] element in a [Print][super::Print] element, the values determined by the [Defaults][super::Defaults] element
 /// are used there as well.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("page-layout")]
 pub struct PageLayout {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PageMargins] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PageMarginsAttributes {
   /// Specifies whether the margins apply to even pages, odd pages, or both.
   /// This attribute is not needed when used as part of a [Print][super::Print] element.
@@ -14,7 +14,7 @@ pub struct PageMarginsAttributes {
 }
 
 /// Contents of the [PageMargins] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PageMarginsContents {
   /// The [LeftMargin] element specifies the left margin in tenths.
   pub left_margin: LeftMargin,
@@ -27,7 +27,7 @@ pub struct PageMarginsContents {
 }
 
 /// The [PageMargins] element specifies page margins in tenths either for both even and odd pages, or via separate odd and even page number values.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("page-margins")]
 pub struct PageMargins {
   /// Element-specific attributes
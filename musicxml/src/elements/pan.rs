@@ -7,7 +7,7 @@ use musicxml_macros::*;
 ///
 /// Both are expressed in degrees ranging from -180 to 180. For [Pan], 0 is straight ahead, -90 is hard left, 90 is hard right,
 /// and -180 and 180 are directly behind the listener.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pan {
   /// Element-specific attributes
   pub attributes: (),
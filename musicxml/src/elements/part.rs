@@ -8,14 +8,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Part] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: IdRef,
 }
 
 /// The [PartElement] specifies all possible elements available for use in a [Part] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum PartElement {
   /// The [Measure] element includes the basic musical data containers within a document.
   Measure(Measure),
@@ -51,7 +51,7 @@ pub enum PartElement {
 }
 
 /// The [Part] element includes the basic musical data such as [Notes][super::Note] or [Measures][Measure] within a document.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Part {
   /// Element-specific attributes
   pub attributes: PartAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartAbbreviation] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartAbbreviationAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -46,7 +46,7 @@ pub struct PartAbbreviationAttributes {
 /// The [PartName][super::PartName] and [PartAbbreviation] elements describe the name and abbreviation of a [ScorePart][super::ScorePart] element, respectively.
 /// Formatting attributes for these elements were deprecated in Version 2.0 in favor of the [PartNameDisplay][super::PartNameDisplay] and
 /// [PartAbbreviationDisplay][super::PartAbbreviationDisplay] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-abbreviation")]
 pub struct PartAbbreviation {
   /// Element-specific attributes
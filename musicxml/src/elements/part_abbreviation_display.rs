@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartAbbreviationDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartAbbreviationDisplayAttributes {
   /// Specifies whether or not to print an object. It is yes if not specified.
   pub print_object: Option<YesNo>,
 }
 
 /// Contents of the [PartAbbreviationDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PartAbbreviationDisplayContents {
   /// The [DisplayText] element specifies the text to display for the part abbreviation.
   pub display_text: Option<DisplayText>,
@@ -26,7 +26,7 @@ pub struct PartAbbreviationDisplayContents {
 ///
 /// Formatting specified in the [PartAbbreviationDisplay] element overrides formatting specified in the
 /// [PartAbbreviation][super::PartAbbreviation] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-abbreviation-display")]
 pub struct PartAbbreviationDisplay {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [PartClef] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PartClefContents {
   /// The [Sign] element represents the clef symbol.
   pub sign: Sign,
@@ -18,7 +18,7 @@ pub struct PartClefContents {
 ///
 /// The child elements of the [PartClef] element have the same meaning as for the [Clef][super::Clef] element. However that meaning applies
 /// to a transposed part created from the existing score file.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-clef")]
 pub struct PartClef {
   /// Element-specific attributes
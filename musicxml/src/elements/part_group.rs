@@ -8,7 +8,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartGroup] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartGroupAttributes {
   /// Indicates the start or stop of the [PartGroup].
   pub r#type: StartStop,
@@ -18,7 +18,7 @@ pub struct PartGroupAttributes {
 }
 
 /// Contents of the [PartGroup] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PartGroupContents {
   /// The [GroupName] element specifies the name of the group.
   pub group_name: Option<GroupName>,
@@ -50,7 +50,7 @@ pub struct PartGroupContents {
 /// A [PartGroup] element is not needed for a single multi-staff part. By default, multi-staff parts include a brace symbol and
 /// (if appropriate given the [BarStyle][super::BarStyle]) common barlines. The symbol formatting for a multi-staff part can be more fully specified
 /// using the [PartSymbol][super::PartSymbol] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-group")]
 pub struct PartGroup {
   /// Element-specific attributes
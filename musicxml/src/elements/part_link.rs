@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartLink] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartLinkAttributes {
   /// The `xlink_href` attribute provides the data that allows an application to find a remote resource or resource fragment. See the definition
   /// in the [XML Linking Language recommendation](https://www.w3.org/TR/xlink11/#link-locators).
@@ -28,7 +28,7 @@ pub struct PartLinkAttributes {
 }
 
 /// Contents of the [PartLink] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PartLinkContents {
   /// The [InstrumentLink] element allows MusicXML data for a single instrument to be defined within a part.
   pub instrument_link: Vec<InstrumentLink>,
@@ -40,7 +40,7 @@ pub struct PartLinkContents {
 ///
 /// It links a [ScorePart][super::ScorePart] from a score document to MusicXML documents that contain parts data. In the case of a single
 /// compressed MusicXML file, the link href values are paths that are relative to the root folder of the zip file.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-link")]
 pub struct PartLink {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 
 /// The [PartListElement] specifies all possible elements available for use in a [PartList] element.
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum PartListElement {
   /// The [PartGroup] element indicates a group of parts that is bracketed together.
   PartGroup(PartGroup),
@@ -14,14 +14,14 @@ pub enum PartListElement {
 }
 
 /// Contents of the [PartList] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct PartListContents {
   /// Ordered list of [PartList] content elements.
   pub content: Vec<PartListElement>,
 }
 
 impl ContentDeserializer for PartListContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut content = PartListContents { content: Vec::new() };
     for element in elements {
       if element.name == "part-group" {
@@ -41,7 +41,7 @@ impl ContentDeserializer for PartListContents {
 }
 
 impl ContentSerializer for PartListContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     for el in &element.content {
       match el {
@@ -60,7 +60,7 @@ impl ContentSerializer for PartListContents {
 ///
 /// There must be at least one [ScorePart], combined as desired with [PartGroup] elements that indicate braces and brackets. Parts are ordered from
 /// top to bottom in a score based on the order in which they appear in the [PartList].
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-list")]
 pub struct PartList {
   /// Element-specific attributes
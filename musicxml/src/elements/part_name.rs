@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartName] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartNameAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -47,7 +47,7 @@ pub struct PartNameAttributes {
 ///
 /// Formatting attributes for these elements were deprecated in Version 2.0 in favor of the [PartNameDisplay][super::PartNameDisplay] and
 /// [PartAbbreviationDisplay][super::PartAbbreviationDisplay] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-name")]
 pub struct PartName {
   /// Element-specific attributes
@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartNameDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartNameDisplayAttributes {
   /// Specifies whether or not to print an object. It is yes if not specified.
   pub print_object: Option<YesNo>,
 }
 
 /// Contents of the [PartNameDisplay] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PartNameDisplayContents {
   /// The [DisplayText] element specifies the text to display for the part name.
   pub display_text: Option<DisplayText>,
@@ -25,7 +25,7 @@ pub struct PartNameDisplayContents {
 /// The `print_object` attribute can be used to determine what, if anything, is printed at the start of each system.
 ///
 /// Formatting specified in the [PartNameDisplay] element overrides formatting specified in the [PartName][super::PartName] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-name-display")]
 pub struct PartNameDisplay {
   /// Element-specific attributes
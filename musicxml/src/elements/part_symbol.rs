@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PartSymbol] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PartSymbolAttributes {
   /// Specifies the bottom staff of the symbol when it does not extend across the entire part.
   pub bottom_staff: Option<StaffNumber>,
@@ -41,7 +41,7 @@ pub struct PartSymbolAttributes {
 /// part, the `top_staff` will typically be 1 for the right hand, while the `bottom_staff` will typically be 2 for the left hand. Staff 3 for the pedals
 /// is usually outside the brace. By default, the presence of a [PartSymbol] element that does not extend across the entire part also indicates a corresponding
 /// change in the common barlines within a part.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-symbol")]
 pub struct PartSymbol {
   /// Element-specific attributes
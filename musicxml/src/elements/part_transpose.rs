@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [PartTranspose] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PartTransposeContents {
   /// The [Diatonic] element specifies the number of diatonic steps up or down from the written key signature.
   pub diatonic: Option<Diatonic>,
@@ -22,7 +22,7 @@ pub struct PartTransposeContents {
 ///
 /// The [Chromatic] element in a [PartTranspose] element will usually have a non-zero value, since octave transpositions can be represented in concert
 /// scores using the [Transpose][super::Transpose] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("part-transpose")]
 pub struct PartTranspose {
   /// Element-specific attributes
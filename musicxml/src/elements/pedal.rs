@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Pedal] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PedalAttributes {
   /// Distinguishes different types of pedal directions.
   pub r#type: PedalType,
@@ -57,7 +57,7 @@ pub struct PedalAttributes {
 ///
 /// The soft pedal is not included here because there is no special symbol or graphic used for it beyond what can be specified
 /// with [Words][super::Words] and [Bracket][super::Bracket] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pedal {
   /// Element-specific attributes
   pub attributes: PedalAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [PedalTuning] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PedalTuningContents {
   /// The [PedalStep] element specifies the step of the tuning.
   pub pedal_step: PedalStep,
@@ -13,7 +13,7 @@ pub struct PedalTuningContents {
 }
 
 /// The [PedalTuning] element specifies the tuning of a single harp pedal.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("pedal-tuning")]
 pub struct PedalTuning {
   /// Element-specific attributes
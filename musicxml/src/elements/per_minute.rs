@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PerMinute] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PerMinuteAttributes {
   /// A comma-separated list of font names.
   pub font_family: Option<FontFamily>,
@@ -21,7 +21,7 @@ pub struct PerMinuteAttributes {
 /// If a font is specified, it overrides the font specified for the overall [Metronome][super::Metronome] element.
 /// This allows separate specification of a music font for the [BeatUnit][super::BeatUnit] and a text font for the numeric value,
 /// in cases where a single metronome font is not used.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("per-minute")]
 pub struct PerMinute {
   /// Element-specific attributes
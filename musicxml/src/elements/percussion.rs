@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Percussion] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PercussionAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -60,7 +60,7 @@ pub struct PercussionAttributes {
 }
 
 /// The [PercussionContents] element specifies all possible options for use in a [Percussion] element.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub enum PercussionContents {
   /// The [Glass] element is used to define glass pictogram symbols.
   Glass(Glass),
@@ -92,20 +92,21 @@ pub enum PercussionContents {
 ///
 /// The organization of these symbols follows the definitions in Kurt Stone's "Music Notation in the Twentieth Century" on pages 206-212 and 223.
 /// More pictograms have been added to the ones listed in Stone, based on how usage has evolved since the book was published in 1980.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementWalk)]
 pub struct Percussion {
   /// Element-specific attributes
   pub attributes: PercussionAttributes,
+  #[flatten]
   /// Element-specific content
   pub content: PercussionContents,
 }
 
 impl ElementDeserializer for Percussion {
-  fn deserialize(element: &XmlElement) -> Result<Self, String> {
+  fn deserialize(element: &XmlElement<'_>) -> Result<Self, String> {
     let el = element.elements.first().ok_or("No sub-element found in <percussion>")?;
     Ok(Percussion {
       attributes: PercussionAttributes::deserialize(&element.attributes)?,
-      content: match el.name.as_str() {
+      content: match el.name.as_ref() {
         "glass" => PercussionContents::Glass(Glass::deserialize(el)?),
         "metal" => PercussionContents::Metal(Metal::deserialize(el)?),
         "wood" => PercussionContents::Wood(Wood::deserialize(el)?),
@@ -124,60 +125,59 @@ impl ElementDeserializer for Percussion {
 }
 
 impl ElementSerializer for Percussion {
-  fn serialize(element: &Self) -> XmlElement {
+  fn serialize(element: &Self) -> XmlElement<'static> {
     let name;
     let mut xml_element = match &element.content {
       PercussionContents::Glass(content) => {
-        name = String::from("glass");
+        name = "glass";
         Glass::serialize(content)
       }
       PercussionContents::Metal(content) => {
-        name = String::from("metal");
+        name = "metal";
         Metal::serialize(content)
       }
       PercussionContents::Wood(content) => {
-        name = String::from("wood");
+        name = "wood";
         Wood::serialize(content)
       }
       PercussionContents::Pitched(content) => {
-        name = String::from("pitched");
+        name = "pitched";
         Pitched::serialize(content)
       }
       PercussionContents::Membrane(content) => {
-        name = String::from("membrane");
+        name = "membrane";
         Membrane::serialize(content)
       }
       PercussionContents::Effect(content) => {
-        name = String::from("effect");
+        name = "effect";
         Effect::serialize(content)
       }
       PercussionContents::Timpani(content) => {
-        name = String::from("timpani");
+        name = "timpani";
         Timpani::serialize(content)
       }
       PercussionContents::Beater(content) => {
-        name = String::from("beater");
+        name = "beater";
         Beater::serialize(content)
       }
       PercussionContents::Stick(content) => {
-        name = String::from("stick");
+        name = "stick";
         Stick::serialize(content)
       }
       PercussionContents::StickLocation(content) => {
-        name = String::from("stick-location");
+        name = "stick-location";
         StickLocation::serialize(content)
       }
       PercussionContents::OtherPercussion(content) => {
-        name = String::from("other-percussion");
+        name = "other-percussion";
         OtherPercussion::serialize(content)
       }
     };
-    xml_element.name = name;
+    xml_element.name = name.into();
     XmlElement {
-      name: String::new(),
       attributes: PercussionAttributes::serialize(&element.attributes),
       elements: vec![xml_element],
-      text: String::new(),
+      ..Default::default()
     }
   }
 }
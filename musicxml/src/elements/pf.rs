@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Pf] element represents a piano forte dynamic marking.
 ///
 /// ![pf](https://hedgetechllc.github.io/musicxml/musicxml/elements/pf.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pf {
   /// Element-specific attributes
   pub attributes: (),
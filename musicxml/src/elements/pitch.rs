@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Pitch] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PitchContents {
   /// The [Step] element represents the step of the diatonic scale.
   pub step: Step,
@@ -15,7 +15,7 @@ pub struct PitchContents {
 }
 
 /// [Pitch] is represented as a combination of the step of the diatonic scale, the chromatic alteration, and the octave.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pitch {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Pitched] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PitchedAttributes {
   /// Distinguishes different SMuFL glyphs for a particular pictogram within the Tuned mallet percussion pictograms range.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct PitchedAttributes {
 /// The [Pitched] element represents pictograms for pitched percussion instruments.
 ///
 /// ![Pitched](https://hedgetechllc.github.io/musicxml/musicxml/elements/pitched.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pitched {
   /// Element-specific attributes
   pub attributes: PitchedAttributes,
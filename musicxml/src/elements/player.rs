@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Player] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PlayerAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Id,
 }
 
 /// Contents of the [Player] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PlayerContents {
   /// The [PlayerName] element specifies the name of the player.
   pub player_name: PlayerName,
@@ -21,7 +21,7 @@ pub struct PlayerContents {
 /// The [Player] element allows for multiple players per [ScorePart][super::ScorePart] for use in listening applications.
 ///
 /// One player may play multiple instruments, while a single instrument may include multiple players in divisi sections.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Player {
   /// Element-specific attributes
   pub attributes: PlayerAttributes,
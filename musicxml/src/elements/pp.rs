@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Pp] element represents a pianissimo dynamic marking.
 ///
 /// ![pp](https://hedgetechllc.github.io/musicxml/musicxml/elements/pp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pp {
   /// Element-specific attributes
   pub attributes: (),
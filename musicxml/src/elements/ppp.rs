@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Ppp] element represents a triple piano dynamic marking.
 ///
 /// ![ppp](https://hedgetechllc.github.io/musicxml/musicxml/elements/ppp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ppp {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Pppp] element represents a pppp dynamic marking.
 ///
 /// ![pppp](https://hedgetechllc.github.io/musicxml/musicxml/elements/pppp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pppp {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Ppppp] element represents a pppp dynamic marking.
 ///
 /// ![ppppp](https://hedgetechllc.github.io/musicxml/musicxml/elements/ppppp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Ppppp {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Pppppp] element represents a pppp dynamic marking.
 ///
 /// ![pppppp](https://hedgetechllc.github.io/musicxml/musicxml/elements/pppppp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Pppppp {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Prefix] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PrefixAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -40,7 +40,7 @@ pub struct PrefixAttributes {
 /// Values for the [Prefix] element include plus and the accidental values sharp, flat, natural, double-sharp, flat-flat, and sharp-sharp.
 ///
 /// The [Prefix] element may contain additional values for symbols specific to particular figured bass styles.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Prefix {
   /// Element-specific attributes
   pub attributes: PrefixAttributes,
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PrincipalVoice] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PrincipalVoiceAttributes {
   /// Indicates the type of symbol used. When used for analysis separate from any printed score markings,
   /// it should be set to none. Otherwise if the type is stop it should be set to plain.
@@ -65,7 +65,7 @@ pub struct PrincipalVoiceAttributes {
 /// The [PrincipalVoice] element represents principal and secondary voices in a score, either for analysis or for square bracket symbols that appear in a score.
 ///
 /// The content of the [PrincipalVoice] element is used for analysis and may be any text value.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("principal-voice")]
 pub struct PrincipalVoice {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Print] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PrintAttributes {
   /// The number of blank pages to insert before the current measure. It is ignored if `new_page` is not "yes".
   /// These blank pages have no music, but may have text or images specified by the [Credit][super::Credit] element.
@@ -31,7 +31,7 @@ pub struct PrintAttributes {
 }
 
 /// Contents of the [Print] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct PrintContents {
   /// The [PageLayout] element contains general page layout parameters.
   pub page_layout: Option<PageLayout>,
@@ -56,7 +56,7 @@ pub struct PrintContents {
 ///
 /// Layout group elements in a [Print] element only apply to the current page, system, or staff. Music that follows continues to take
 /// the default values from the layout determined by the [Defaults][super::Defaults] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Print {
   /// Element-specific attributes
   pub attributes: PrintAttributes,
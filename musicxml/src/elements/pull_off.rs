@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [PullOff] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct PullOffAttributes {
   /// Distinguishes multiple pull-offs when they overlap in MusicXML document order.
   pub r#type: StartStop,
@@ -52,7 +52,7 @@ pub struct PullOffAttributes {
 /// Since a single slur can be marked over many notes, the [PullOff] element is separate so the individual pair of notes can be specified.
 ///
 /// The element content can be used to specify how the [PullOff] should be notated. An empty element leaves this choice up to the application.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("pull-off")]
 pub struct PullOff {
   /// Element-specific attributes
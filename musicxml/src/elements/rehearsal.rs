@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Rehearsal] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RehearsalAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -93,7 +93,7 @@ pub struct RehearsalAttributes {
 /// ![Rehearsal](https://hedgetechllc.github.io/musicxml/musicxml/elements/rehearsal.png)
 ///
 /// The `enclosure` is square if not specified. Left justification is used if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Rehearsal {
   /// Element-specific attributes
   pub attributes: RehearsalAttributes,
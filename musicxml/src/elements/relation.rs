@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Relation] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RelationAttributes {
   /// Standard type values are music, words, and arrangement, but other types may be used.
   pub r#type: Token,
@@ -13,7 +13,7 @@ pub struct RelationAttributes {
 /// The [Relation] element describes a related resource for the music that is encoded.
 ///
 /// This is similar to the [Dublin Core relation](https://www.dublincore.org/specifications/dublin-core/dcmi-terms/elements11/relation/) element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Relation {
   /// Element-specific attributes
   pub attributes: RelationAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Repeat] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RepeatAttributes {
   /// The start of the repeat has a forward direction while the end of the repeat has a backward direction.
   pub direction: BackwardForward,
@@ -21,7 +21,7 @@ pub struct RepeatAttributes {
 /// The [Repeat] element represents repeat marks.
 ///
 /// ![Repeat](https://hedgetechllc.github.io/musicxml/musicxml/elements/repeat.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Repeat {
   /// Element-specific attributes
   pub attributes: RepeatAttributes,
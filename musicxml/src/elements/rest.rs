@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Rest] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RestAttributes {
   /// If yes, this indicates this is a complete measure rest.
   pub measure: Option<YesNo>,
 }
 
 /// Contents of the [Rest] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct RestContents {
   /// The [DisplayStep] element specifies the step of the rest.
   pub display_step: Option<DisplayStep>,
@@ -23,7 +23,7 @@ pub struct RestContents {
 /// The [Rest] element indicates notated rests or silences.
 ///
 /// A [Rest] element is usually empty, but placement on the staff can be specified using [DisplayStep] and [DisplayOctave] elements.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Rest {
   /// Element-specific attributes
   pub attributes: RestAttributes,
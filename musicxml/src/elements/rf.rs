@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Rf] element represents a rinforzando rf dynamic marking.
 ///
 /// ![rf](https://hedgetechllc.github.io/musicxml/musicxml/elements/rf.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Rf {
   /// Element-specific attributes
   pub attributes: (),
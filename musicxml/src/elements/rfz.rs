@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Rfz] element represents a rinforzando rfz dynamic marking.
 ///
 /// ![rfz](https://hedgetechllc.github.io/musicxml/musicxml/elements/rfz.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Rfz {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [RightDivider] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RightDividerAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -58,7 +58,7 @@ pub struct RightDividerAttributes {
 ///
 /// The default vertical position is half the [SystemDistance][super::SystemDistance] value from the top of the system that is below the divider.
 /// The default horizontal position is the right system margin.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("right-divider")]
 pub struct RightDivider {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Rights] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RightsAttributes {
   /// Standard type values are music, words, and arrangement, but other types may be used.
   /// This attribute is only needed when there are multiple [Rights] elements.
@@ -15,7 +15,7 @@ pub struct RightsAttributes {
 ///
 /// This is similar to the [Dublin Core rights](https://www.dublincore.org/specifications/dublin-core/dcmi-terms/elements11/rights/) element.
 /// Words, music, and derivatives can have different types, so multiple [Rights] elements with different type attributes are supported.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Rights {
   /// Element-specific attributes
   pub attributes: RightsAttributes,
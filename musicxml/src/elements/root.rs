@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Root] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct RootContents {
   /// The [RootStep] element specifies the pitch like C, D, E vs. a scale degree like 1, 2, 3.
   pub root_step: RootStep,
@@ -16,7 +16,7 @@ pub struct RootContents {
 ///
 /// It is used with chord symbols in popular music. The [Root] element has a [RootStep] and optional [RootAlter] element similar to the
 /// [Step][super::Step] and [Alter][super::Alter] elements, but renamed to distinguish the different musical meanings.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Root {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [RootAlter] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct RootAlterAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -47,7 +47,7 @@ pub struct RootAlterAttributes {
 ///
 /// In some chord styles, the text for the [RootStep][super::RootStep] element may include [RootAlter] information. In that case,
 /// the `print_object` attribute of the [RootAlter] element can be set to "no".
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("root-alter")]
 pub struct RootAlter {
   /// Element-specific attributes
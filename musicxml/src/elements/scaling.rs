@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Scaling] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ScalingContents {
   /// The [Millimeters] element specifies the number of millimeters that are equivalent to the scaling number.
   pub millimeters: Millimeters,
@@ -17,7 +17,7 @@ pub struct ScalingContents {
 /// The translation to absolute units is done with the [Scaling] element, which specifies how many millimeters are equal to how many tenths.
 /// For a staff height of 7 mm, [Millimeters] would be set to 7 while [Tenths] is set to 40. The ability to set a formula rather than a single scaling
 /// factor helps avoid roundoff errors.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Scaling {
   /// Element-specific attributes
   pub attributes: (),
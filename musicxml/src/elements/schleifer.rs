@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Schleifer] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SchleiferAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct SchleiferAttributes {
 /// The name for the [Schleifer] element is based on the German ornament name, to avoid confusion with the [Slide][super::Slide] element.
 ///
 /// ![Schleifer](https://hedgetechllc.github.io/musicxml/musicxml/elements/schleifer.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Schleifer {
   /// Element-specific attributes
   pub attributes: SchleiferAttributes,
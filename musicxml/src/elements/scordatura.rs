@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Scordatura] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ScordaturaAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
@@ -16,7 +16,7 @@ pub struct ScordaturaAttributes {
 /// ![Scordatura](https://hedgetechllc.github.io/musicxml/musicxml/elements/scordatura.png)
 ///
 /// Strings are numbered from high to low.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Scordatura {
   /// Element-specific attributes
   pub attributes: ScordaturaAttributes,
@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [ScoreInstrument] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ScoreInstrumentAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Id,
 }
 
 /// Contents of the [ScoreInstrument] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ScoreInstrumentContents {
   /// The [InstrumentName] element specifies the name of the instrument.
   pub instrument_name: InstrumentName,
@@ -38,7 +38,7 @@ pub struct ScoreInstrumentContents {
 ///
 /// The [ScoreInstrument] element can also distinguish multiple instruments of the same type that are on the same part, such as Clarinet 1 and
 /// Clarinet 2 instruments within a Clarinets 1 and 2 part.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("score-instrument")]
 pub struct ScoreInstrument {
   /// Element-specific attributes
@@ -8,14 +8,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [ScorePart] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ScorePartAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Id,
 }
 
 /// Contents of the [ScorePart] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ScorePartContents {
   /// The [Identification] element contains information about the creator, rights, and encoding of the part.
   pub identification: Option<Identification>,
@@ -45,7 +45,7 @@ pub struct ScorePartContents {
 ///
 /// Often each MusicXML part corresponds to a track in a Standard MIDI Format 1 file. In this case, the [MidiDevice] element is used to make a
 /// MIDI device or port assignment for the given track or specific MIDI instruments. Initial [MidiInstrument] assignments may be made here as well.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("score-part")]
 pub struct ScorePart {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [ScorePartwise] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ScorePartwiseAttributes {
   /// The `version` attribute was added in Version 1.1 for the [ScorePartwise] and [ScoreTimewise][super::ScoreTimewise]
   /// documents. It provides an easier way to get version information than through the MusicXML public ID. The default value is
@@ -15,7 +15,7 @@ pub struct ScorePartwiseAttributes {
 }
 
 /// Contents of the [ScorePartwise] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ScorePartwiseContents {
   /// The [Work] element specifies the work title and opus number for the score.
   pub work: Option<Work>,
@@ -38,7 +38,7 @@ pub struct ScorePartwiseContents {
 /// The [ScorePartwise] element is the root element for a partwise MusicXML score.
 ///
 /// It includes score header information followed by a series of [Part] elements with [Measure][super::Measure] elements inside.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("score-partwise")]
 pub struct ScorePartwise {
   /// Element-specific attributes
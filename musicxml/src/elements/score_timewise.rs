@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [ScoreTimewise] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ScoreTimewiseAttributes {
   /// The `version` attribute was added in Version 1.1 for the [ScorePartwise][super::ScorePartwise] and [ScoreTimewise]
   /// documents. It provides an easier way to get version information than through the MusicXML public ID. The default value is
@@ -15,7 +15,7 @@ pub struct ScoreTimewiseAttributes {
 }
 
 /// Contents of the [ScoreTimewise] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct ScoreTimewiseContents {
   /// The [Work] element specifies the work title and movement or opus number information.
   pub work: Option<Work>,
@@ -38,7 +38,7 @@ pub struct ScoreTimewiseContents {
 /// The [ScoreTimewise] element is the root element for a timewise MusicXML score.
 ///
 /// It includes score header information followed by a series of [Measure] elements with [Part][super::Part] elements inside.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("score-timewise")]
 pub struct ScoreTimewise {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Segno] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SegnoAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -63,7 +63,7 @@ pub struct SegnoAttributes {
 /// ![Segno](https://hedgetechllc.github.io/musicxml/musicxml/elements/segno.png)
 ///
 /// A [Sound][super::Sound] element is also needed to guide playback applications reliably.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Segno {
   /// Element-specific attributes
   pub attributes: SegnoAttributes,
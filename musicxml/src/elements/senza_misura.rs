@@ -6,7 +6,7 @@ use musicxml_macros::*;
 ///
 /// The optional element content indicates the symbol to be used, if any, such as an X. The [Time][super::Time] element's `symbol`
 /// attribute is not used when a [SenzaMisura] element is present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("senza-misura")]
 pub struct SenzaMisura {
   /// Element-specific attributes
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Sf] element represents a sforzando sf dynamic marking.
 ///
 /// ![sf](https://hedgetechllc.github.io/musicxml/musicxml/elements/sf.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sf {
   /// Element-specific attributes
   pub attributes: (),
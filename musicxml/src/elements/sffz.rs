@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Sffz] element represents a sforzando sffz dynamic marking.
 ///
 /// ![sffz](https://hedgetechllc.github.io/musicxml/musicxml/elements/sffz.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sffz {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Sfp] element represents a sforzando piano sfp dynamic marking.
 ///
 /// ![sfp](https://hedgetechllc.github.io/musicxml/musicxml/elements/sfp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sfp {
   /// Element-specific attributes
   pub attributes: (),
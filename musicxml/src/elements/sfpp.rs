@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Sfpp] element represents a sforzando pianissimo sfpp dynamic marking.
 ///
 /// ![sfpp](https://hedgetechllc.github.io/musicxml/musicxml/elements/sfpp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sfpp {
   /// Element-specific attributes
   pub attributes: (),
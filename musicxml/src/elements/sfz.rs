@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Sfz] element represents a sforzando sfz dynamic marking.
 ///
 /// ![sfz](https://hedgetechllc.github.io/musicxml/musicxml/elements/sfz.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sfz {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Sfzp] element represents a sforzando piano sfzp dynamic marking.
 ///
 /// ![sfzp](https://hedgetechllc.github.io/musicxml/musicxml/elements/sfzp.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sfzp {
   /// Element-specific attributes
   pub attributes: (),
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Shake] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ShakeAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -59,7 +59,7 @@ pub struct ShakeAttributes {
 /// The [Shake] element has a similar appearance to an [InvertedMordent][super::InvertedMordent] element.
 ///
 /// ![Shake](https://hedgetechllc.github.io/musicxml/musicxml/elements/shake.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Shake {
   /// Element-specific attributes
   pub attributes: ShakeAttributes,
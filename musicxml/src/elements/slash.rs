@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Slash] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SlashAttributes {
   /// Indicates the starting or stopping point of the section displaying slash notation.
   pub r#type: StartStop,
@@ -16,7 +16,7 @@ pub struct SlashAttributes {
 }
 
 /// Contents of the [Slash] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct SlashContents {
   /// The [SlashType] element indicates the notation style for the slash notation.
   pub slash_type: Option<SlashType>,
@@ -32,7 +32,7 @@ pub struct SlashContents {
 ///
 /// The stop type indicates the first beat where slash notation no longer displayed. Both the start and stop of the slash notation should be
 /// specified unless the slashes are displayed through the end of the part.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Slash {
   /// Element-specific attributes
   pub attributes: SlashAttributes,
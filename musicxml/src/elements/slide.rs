@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Slide] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SlideAttributes {
   /// Indicates if this is the start or stop of the slide.
   pub r#type: StartStop,
@@ -65,7 +65,7 @@ pub struct SlideAttributes {
 /// ![Slide](https://hedgetechllc.github.io/musicxml/musicxml/elements/slide.png)
 ///
 /// A [Slide] is continuous between the two pitches and defaults to a solid line. The optional text is printed alongside the line.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Slide {
   /// Element-specific attributes
   pub attributes: SlideAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Slur] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SlurAttributes {
   /// Indicates if this is the start, stop, or continuation of the slur.
   pub r#type: StartStopContinue,
@@ -66,7 +66,7 @@ pub struct SlurAttributes {
 ///
 /// Normal slurs and S-shaped slurs need only two bezier points: one associated with the start of the slur, the other with the stop.
 /// Complex slurs and slurs divided over system breaks can specify additional bezier data at [Slur] elements with a continue type.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Slur {
   /// Element-specific attributes
   pub attributes: SlurAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [SnapPizzicato] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SnapPizzicatoAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -45,7 +45,7 @@ pub struct SnapPizzicatoAttributes {
 ///
 /// This is a circle with a line, where the line comes inside the circle. It is distinct from the [ThumbPosition][super::ThumbPosition] symbol,
 /// where the line does not come inside the circle.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("snap-pizzicato")]
 pub struct SnapPizzicato {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [SoftAccent] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SoftAccentAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct SoftAccentAttributes {
 /// ![SoftAccent](https://hedgetechllc.github.io/musicxml/musicxml/elements/soft-accent.png)
 ///
 /// It can be combined with other articulations to implement the first eight symbols in the Standard Music Font Layout (SMuFL) Articulation supplement range.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("soft-accent")]
 pub struct SoftAccent {
   /// Element-specific attributes
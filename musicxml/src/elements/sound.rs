@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Sound] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SoundAttributes {
   /// Indicates the end point for a forward jump to a coda sign. If there are multiple jumps, the value of these parameters can be used to name and distinguish them.
   pub coda: Option<Token>,
@@ -61,7 +61,7 @@ pub struct SoundAttributes {
 }
 
 /// Contents of the [Sound] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct SoundContents {
   /// The [InstrumentChange] element is used to change instruments mid-score.
   pub instrument_change: Vec<InstrumentChange>,
@@ -89,7 +89,7 @@ pub struct SoundContents {
 /// If the [Sound] element is a child of a [Direction][super::Direction] element, the sound [Offset] element overrides the
 /// direction [Offset] element if both elements are present. Note that the offset reflects the intended musical position for
 /// the change in sound. It should not be used to compensate for latency issues in particular hardware configurations.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sound {
   /// Element-specific attributes
   pub attributes: SoundAttributes,
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The presence of the [SoundingPitch] element indicates this is the pitch which is heard when playing the harmonic.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("sounding-pitch")]
 pub struct SoundingPitch {
   /// Element-specific attributes
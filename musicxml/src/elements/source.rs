@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Source] element describes the source for the music that is encoded.
 ///
 /// This is similar to the [Dublin Core source](https://www.dublincore.org/specifications/dublin-core/dcmi-terms/elements11/source/) element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Source {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Spiccato] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SpiccatoAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct SpiccatoAttributes {
 /// The [Spiccato] element is used for a stroke articulation, as opposed to a dot or a wedge.
 ///
 /// ![Spiccato](https://hedgetechllc.github.io/musicxml/musicxml/elements/spiccato.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Spiccato {
   /// Element-specific attributes
   pub attributes: SpiccatoAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Staccatissimo] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StaccatissimoAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct StaccatissimoAttributes {
 /// The [Staccatissimo] element is used for a wedge articulation, as opposed to a dot or a stroke.
 ///
 /// ![Staccatissimo](https://hedgetechllc.github.io/musicxml/musicxml/elements/staccatissimo.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Staccatissimo {
   /// Element-specific attributes
   pub attributes: StaccatissimoAttributes,
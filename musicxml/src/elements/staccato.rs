@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Staccato] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StaccatoAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct StaccatoAttributes {
 /// The [Staccato] element is used for a dot articulation, as opposed to a stroke or a wedge.
 ///
 /// ![Staccato](https://hedgetechllc.github.io/musicxml/musicxml/elements/staccato.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Staccato {
   /// Element-specific attributes
   pub attributes: StaccatoAttributes,
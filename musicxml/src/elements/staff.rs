@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// [Staff] assignment is only needed for music notated on multiple staves.
 ///
 /// Staff values are numbers, with 1 referring to the top-most staff in a part.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Staff {
   /// Element-specific attributes
   pub attributes: (),
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [StaffDetails] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StaffDetailsAttributes {
   /// Specifies the staff number from top to bottom within the part. The value is 1 if not present.
   pub number: Option<StaffNumber>,
@@ -18,7 +18,7 @@ pub struct StaffDetailsAttributes {
 }
 
 /// Contents of the [StaffDetails] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct StaffDetailsContents {
   /// The [StaffType] element indicates the type of staff.
   pub staff_type: Option<StaffType>,
@@ -35,7 +35,7 @@ pub struct StaffDetailsContents {
 }
 
 /// The [StaffDetails] element is used to indicate different types of staves.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("staff-details")]
 pub struct StaffDetails {
   /// Element-specific attributes
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [StaffDivide] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StaffDivideAttributes {
   /// Specifies the type of staff division symbol.
   pub r#type: StaffDivideSymbol,
@@ -61,7 +61,7 @@ pub struct StaffDivideAttributes {
 /// The [StaffDivide] element represents the staff division arrow symbols found in the Standard Music Font Layout (SMuFL)
 /// [Staff brackets and dividers](https://www.w3.org/2021/03/smufl14/tables/staff-brackets-and-dividers.html) range at code points
 /// U+E00B, U+E00C, and U+E00D.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("staff-divide")]
 pub struct StaffDivide {
   /// Element-specific attributes
@@ -5,14 +5,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [StaffTuning] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StaffTuningAttributes {
   /// Indicates the staff line for this tuning, numbered from bottom to top.
   pub line: StaffLine,
 }
 
 /// Contents of the [StaffTuning] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct StaffTuningContents {
   /// The [TuningStep] element specifies the step of the tuning.
   pub tuning_step: TuningStep,
@@ -25,7 +25,7 @@ pub struct StaffTuningContents {
 /// The [StaffTuning] element specifies the open, non-capo tuning of the lines on a tablature staff.
 ///
 /// ![StaffTuning](https://hedgetechllc.github.io/musicxml/musicxml/elements/staff-tuning.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("staff-tuning")]
 pub struct StaffTuning {
   /// Element-specific attributes
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [StaffType] element specifies different uses for the staff, as listed in the [StaffType][crate::datatypes::StaffType] data type.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("staff-type")]
 pub struct StaffType {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Stem] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StemAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -32,7 +32,7 @@ pub struct StemAttributes {
 /// Values for the [Stem] element can be down, up, none, or double.
 ///
 /// A [Stem] element associated with a [Rest][super::Rest] refers to a stemlet.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Stem {
   /// Element-specific attributes
   pub attributes: StemAttributes,
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Stick] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StickAttributes {
   /// Indicates the presence of a dashed circle around the round beater part of a pictogram. The value is no if not specified.
   pub dashed_circle: Option<YesNo>,
@@ -16,7 +16,7 @@ pub struct StickAttributes {
 }
 
 /// Contents of the [Stick] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct StickContents {
   /// The [StickType] element indicates the pictogram type of the stick, mallet, or beater.
   pub stick_type: StickType,
@@ -27,7 +27,7 @@ pub struct StickContents {
 /// The [Stick] element represents pictograms where the material of the stick, mallet, or beater is included.
 ///
 /// ![Stick](https://hedgetechllc.github.io/musicxml/musicxml/elements/stick.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Stick {
   /// Element-specific attributes
   pub attributes: StickAttributes,
@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [StickLocation] element represents pictograms for the location of sticks, beaters, or mallets on cymbals, gongs, drums, and other instruments.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("stick-location")]
 pub struct StickLocation {
   /// Element-specific attributes
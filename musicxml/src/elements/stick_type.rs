@@ -3,7 +3,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [StickType] element represents the shape of pictograms where the material in the stick, mallet, or beater is represented in the pictogram.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("stick-type")]
 pub struct StickType {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Stopped] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StoppedAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -48,7 +48,7 @@ pub struct StoppedAttributes {
 ///
 /// The `smufl` attribute distinguishes different Standard Music Font Layout (SMuFL) glyphs that have a similar appearance
 /// such as "handbellsMalletBellSuspended" and "guitarClosePedal". If not present, the default glyph is brassMuteClosed.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Stopped {
   /// Element-specific attributes
   pub attributes: StoppedAttributes,
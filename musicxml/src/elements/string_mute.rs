@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [StringMute] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct StringMuteAttributes {
   /// Specifies if the string mute is going on or off.
   pub r#type: OnOff,
@@ -61,7 +61,7 @@ pub struct StringMuteAttributes {
 /// The [StringMute] element represents string mute on and mute off symbols.
 ///
 /// ![StringMute](https://hedgetechllc.github.io/musicxml/musicxml/elements/string-mute.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("string-mute")]
 pub struct StringMute {
   /// Element-specific attributes
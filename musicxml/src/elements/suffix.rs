@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Suffix] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SuffixAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct SuffixAttributes {
 /// Suffixes include both symbols that come after the figure number and those that overstrike the figure number. The [Suffix] values slash, back-slash,
 /// and vertical are used for slashed numbers indicating chromatic alteration. The orientation and display of the slash usually depends on the figure number.
 /// The [Suffix] element may contain additional values for symbols specific to particular figured bass styles.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Suffix {
   /// Element-specific attributes
   pub attributes: SuffixAttributes,
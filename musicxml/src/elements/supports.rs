@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Supports] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SupportsAttributes {
   /// Indicates the element that is supported or not by the encoding.
   pub element: NmToken,
@@ -23,7 +23,7 @@ pub struct SupportsAttributes {
 /// of an element is ambiguous if you do not know if the encoding supports that element. It also allows programs to indicate support for
 /// specific attributes, or specific attributes with specific values. This lets applications communicate, for example, that all system and/or
 /// page breaks are contained in the MusicXML file.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Supports {
   /// Element-specific attributes
   pub attributes: SupportsAttributes,
@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// The [SwingType] element specifies the note type, either eighth or 16th, to which the [First][super::First] to [Second][super::Second] ratio is applied.
 ///
 /// The value is eighth if this element is not present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("swing-type")]
 pub struct SwingType {
   /// Element-specific attributes
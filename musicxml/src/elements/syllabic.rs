@@ -5,7 +5,7 @@ use musicxml_macros::*;
 /// The [Syllabic] element indicates lyric hyphenation.
 ///
 /// The single, begin, end, and middle values represent single-syllable words, word-beginning syllables, word-ending syllables, and mid-word syllables, respectively.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Syllabic {
   /// Element-specific attributes
   pub attributes: (),
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Symbol] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SymbolAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -88,7 +88,7 @@ pub struct SymbolAttributes {
 ///
 /// It is used when an occasional musical symbol is interspersed into text. It should not be used in place of semantic markup, such as metronome marks
 /// that mix text and symbols. Left justification is used if not specified. Enclosure is none if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Symbol {
   /// Element-specific attributes
   pub attributes: SymbolAttributes,
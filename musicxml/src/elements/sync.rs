@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Sync] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct SyncAttributes {
   /// Specifies the style that a score following application should use to synchronize an accompaniment with a performer.
   pub r#type: SyncType,
@@ -20,7 +20,7 @@ pub struct SyncAttributes {
 /// The [Sync] element specifies the style that a score-following application should use the synchronize an accompaniment with a performer.
 ///
 /// If this element is not included in a score, default synchronization depends on the application.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Sync {
   /// Element-specific attributes
   pub attributes: SyncAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [SystemDividers] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct SystemDividersContents {
   /// The [LeftDivider] element indicates the presence or absence of a system divider on the left side of the page.
   pub left_divider: LeftDivider,
@@ -17,7 +17,7 @@ pub struct SystemDividersContents {
 /// Dividers on the left and right side of the page are controlled by the [LeftDivider] and [RightDivider] elements respectively.
 ///
 /// When used in the [Print][super::Print] element, the [SystemDividers] element affects the dividers that would appear between the current system and the previous system.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("system-dividers")]
 pub struct SystemDividers {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [SystemLayout] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct SystemLayoutContents {
   /// The [SystemMargins] element specifies the left and right margins of the system.
   pub system_margins: Option<SystemMargins>,
@@ -31,7 +31,7 @@ pub struct SystemLayoutContents {
 /// use the default values as determined by the [Defaults][super::Defaults] element. If any child elements are missing from the [SystemLayout] element
 /// in a [Print][super::Print] element, the values determined by the [Defaults][super::Defaults] element are used there as well. This type of
 /// [SystemLayout] element need only be read from or written to the first visible part in the score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("system-layout")]
 pub struct SystemLayout {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [SystemMargins] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct SystemMarginsContents {
   /// The [LeftMargin] element specifies the left system margin.
   pub left_margin: LeftMargin,
@@ -15,7 +15,7 @@ pub struct SystemMarginsContents {
 /// System margins are relative to the page margins.
 ///
 /// Positive values indent and negative values reduce the margin size.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("system-margins")]
 pub struct SystemMargins {
   /// Element-specific attributes
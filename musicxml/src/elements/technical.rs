@@ -9,14 +9,14 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Technical] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TechnicalAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
 }
 
 /// The [TechnicalContents] element specifies all possible options available for use in a [Technical] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub enum TechnicalContents {
   /// The [UpBow] element represents the symbol for up-bow.
   #[rename("up-bow")]
@@ -97,7 +97,7 @@ pub enum TechnicalContents {
 }
 
 /// The [Technical] element groups together technical indications that give performance information for specific instruments.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Technical {
   /// Element-specific attributes
   pub attributes: TechnicalAttributes,
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [TextAttributes] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TextAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct TextAttributes {
 /// The [Text] element represents a syllable or portion of a syllable for lyric text underlay.
 ///
 /// A hyphen in the element content should only be used for an actual hyphenated word.
-#[derive(Debug, Default, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Text {
   /// Element-specific attributes
   pub attributes: TextAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [ThumbPosition] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct ThumbPositionAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -44,7 +44,7 @@ pub struct ThumbPositionAttributes {
 /// ![ThumbPosition](https://hedgetechllc.github.io/musicxml/musicxml/elements/thumb-position.png)
 ///
 /// This is a circle with a line, where the line does not come within the circle. It is distinct from the [SnapPizzicato][super::SnapPizzicato] symbol, where the line comes inside the circle.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("thumb-position")]
 pub struct ThumbPosition {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Tie] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TieAttributes {
   /// Indicates if this is the start or stop of the tie.
   pub r#type: StartStop,
@@ -16,7 +16,7 @@ pub struct TieAttributes {
 /// The [Tie] element indicates that a tie begins or ends with this note.
 ///
 /// The [Tie] element indicates sound; the [Tied][super::Tied] element indicates notation.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Tie {
   /// Element-specific attributes
   pub attributes: TieAttributes,
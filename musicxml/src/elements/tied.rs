@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Tied] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TiedAttributes {
   /// Indicates if this is the start, stop, or continuation of a tie, or if this is a tie indicating that an instrument should be undamped.
   pub r#type: StartStopContinue,
@@ -79,7 +79,7 @@ pub struct TiedAttributes {
 ///
 /// Normal ties need only two bezier points: one associated with the start of the tie, the other with the stop. Ties divided over system breaks can specify
 /// additional bezier data at [Tied] elements with a continue type.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Tied {
   /// Element-specific attributes
   pub attributes: TiedAttributes,
@@ -8,7 +8,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Time] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TimeAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -67,7 +67,7 @@ pub struct TimeAttributes {
 }
 
 /// The [TimeBeatContents] element specifies the number of beats in a measure and the beat unit.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentWalk)]
 pub struct TimeBeatContents {
   /// The [Beats] element specifies the number of beats in a measure.
   pub beats: Beats,
@@ -76,7 +76,7 @@ pub struct TimeBeatContents {
 }
 
 /// Contents of the [Time] element.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentWalk)]
 pub struct TimeContents {
   /// The [TimeBeatContents] element specifies the number of beats in a measure and the beat unit.
   pub beats: Vec<TimeBeatContents>,
@@ -87,13 +87,13 @@ pub struct TimeContents {
 }
 
 impl ContentDeserializer for TimeContents {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String> {
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String> {
     let mut beats = Vec::new();
     let mut time_beats = None;
     let mut interchangeable = None;
     let mut senza_misura = None;
     for element in elements {
-      match element.name.as_str() {
+      match element.name.as_ref() {
         "beats" => time_beats = Some(Beats::deserialize(element)?),
         "beat-type" => {
           beats.push(TimeBeatContents {
@@ -116,7 +116,7 @@ impl ContentDeserializer for TimeContents {
 }
 
 impl ContentSerializer for TimeContents {
-  fn serialize(element: &Self) -> Vec<XmlElement> {
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
     let mut elements: Vec<XmlElement> = Vec::new();
     for el in &element.beats {
       elements.push(Beats::serialize(&el.beats));
@@ -127,7 +127,7 @@ impl ContentSerializer for TimeContents {
     }
     if let Some(content) = &element.senza_misura {
       let mut xml_element = SenzaMisura::serialize(content);
-      xml_element.name = String::from("senza-misura");
+      xml_element.name = "senza-misura".into();
       elements.push(xml_element);
     }
     elements
@@ -143,7 +143,7 @@ impl ContentSerializer for TimeContents {
 ///
 /// The `print_object` attribute allows a time signature to be specified but not printed, as is the case for excerpts from the middle of a score.
 /// The value is "yes" if not present.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Time {
   /// Element-specific attributes
   pub attributes: TimeAttributes,
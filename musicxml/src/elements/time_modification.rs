@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [TimeModification] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct TimeModificationContents {
   /// The [ActualNotes] element specifies the number of notes in the tuplet.
   pub actual_notes: ActualNotes,
@@ -21,7 +21,7 @@ pub struct TimeModificationContents {
 /// A [TimeModification] element shows how the cumulative, sounding effect of tuplets and double-note tremolos compare to the written
 /// note type represented by the [Type][super::Type] and [Dot][super::Dot] elements. Nested tuplets and other notations that use more detailed
 /// information need both the [TimeModification] and [Tuplet][super::Tuplet] elements to be represented accurately.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("time-modification")]
 pub struct TimeModification {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_macros::*;
 
 /// The [TimeRelation] element indicates the symbol used to represent the interchangeable aspect of dual time signatures,
 /// as specified in the [TimeRelation][crate::datatypes::TimeRelation] type.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("time-relation")]
 pub struct TimeRelation {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Timpani] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TimpaniAttributes {
   /// Distinguishes different SMuFL stylistic alternates.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct TimpaniAttributes {
 /// The [Timpani] element represents the timpani pictogram.
 ///
 /// ![Timpani](https://hedgetechllc.github.io/musicxml/musicxml/elements/timpani.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Timpani {
   /// Element-specific attributes
   pub attributes: TimpaniAttributes,
@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Transpose] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TransposeAttributes {
   /// Specifies an ID that is unique to the entire document.
   pub id: Option<Id>,
@@ -15,7 +15,7 @@ pub struct TransposeAttributes {
 }
 
 /// Contents of the [Transpose] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct TransposeContents {
   /// The [Diatonic] element specifies the number of diatonic steps to transpose.
   pub diatonic: Option<Diatonic>,
@@ -30,7 +30,7 @@ pub struct TransposeContents {
 /// The [Transpose] element represents what must be added to a written pitch to get a correct sounding pitch.
 ///
 /// It is used for encoding parts for transposing instruments that are in written vs. concert pitch.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Transpose {
   /// Element-specific attributes
   pub attributes: TransposeAttributes,
@@ -6,7 +6,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Tremolo] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TremoloAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -61,7 +61,7 @@ pub struct TremoloAttributes {
 /// range for an unmeasured tremolo. It is ignored for other tremolo types. The SMuFL "buzzRoll" glyph is used if the attribute is missing.
 ///
 /// Using repeater beams for indicating tremolos is deprecated as of Version 3.0.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Tremolo {
   /// Element-specific attributes
   pub attributes: TremoloAttributes,
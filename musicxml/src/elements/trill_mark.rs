@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [TrillMark] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TrillMarkAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -59,7 +59,7 @@ pub struct TrillMarkAttributes {
 /// The [TrillMark] element represents the trill symbol.
 ///
 /// ![TrillMark](https://hedgetechllc.github.io/musicxml/musicxml/elements/trill-mark.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("trill-mark")]
 pub struct TrillMark {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [TripleTongue] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TripleTongueAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct TripleTongueAttributes {
 /// The [TripleTongue] element represents the triple tongue symbol (three dots arranged horizontally).
 ///
 /// ![TripleTongue](https://hedgetechllc.github.io/musicxml/musicxml/elements/triple-tongue.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("triple-tongue")]
 pub struct TripleTongue {
   /// Element-specific attributes
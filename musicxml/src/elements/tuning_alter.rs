@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// The [TuningAlter] element is represented like the [Alter][super::Alter] element, with a different name to reflect its different function in string tuning.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("tuning-alter")]
 pub struct TuningAlter {
   /// Element-specific attributes
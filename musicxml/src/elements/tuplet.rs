@@ -5,7 +5,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Tuplet] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TupletAttributes {
   /// Indicates if this is the start or stop of the tuplet.
   pub r#type: StartStop,
@@ -45,7 +45,7 @@ pub struct TupletAttributes {
 }
 
 /// Contents of the [Tuplet] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct TupletContents {
   /// The [TupletActual] element specifies the number of notes in the tuplet.
   pub tuplet_actual: Option<TupletActual>,
@@ -61,7 +61,7 @@ pub struct TupletContents {
 /// Whereas a [TimeModification][super::TimeModification] element shows how the cumulative, sounding effect of tuplets and double-note tremolos compare
 /// to the written note type, the [Tuplet] element describes how this is displayed. The [Tuplet] element also provides more detailed representation information
 /// than the [TimeModification][super::TimeModification] element, and is needed to represent nested tuplets and other complex tuplets accurately.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Tuplet {
   /// Element-specific attributes
   pub attributes: TupletAttributes,
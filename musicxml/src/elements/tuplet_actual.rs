@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [TupletActual] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct TupletActualContents {
   /// The [TupletNumber] element specifies the number of notes in the tuplet.
   pub tuplet_number: Option<TupletNumber>,
@@ -18,7 +18,7 @@ pub struct TupletActualContents {
 /// including number and note type (with dots).
 ///
 /// If any of these elements are absent, their values are based on the [TimeModification][super::TimeModification] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("tuplet-actual")]
 pub struct TupletActual {
   /// Element-specific attributes
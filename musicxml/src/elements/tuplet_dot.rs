@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [TupletDot] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TupletDotAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -19,7 +19,7 @@ pub struct TupletDotAttributes {
 }
 
 /// The [TupletDot] element is used to specify dotted tuplet types.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("tuplet-dot")]
 pub struct TupletDot {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [TupletNumber] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TupletNumberAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -19,7 +19,7 @@ pub struct TupletNumberAttributes {
 }
 
 /// The [TupletNumber] element indicates the number of notes for this portion of the tuplet.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("tuplet-number")]
 pub struct TupletNumber {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [TupletType] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TupletTypeAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -19,7 +19,7 @@ pub struct TupletTypeAttributes {
 }
 
 /// The [TupletType] element indicates the graphical note type of the notes for this portion of the tuplet.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("tuplet-type")]
 pub struct TupletType {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Type] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct TypeAttributes {
   /// Specifies the symbol size to use for an editorial indication. If not specified, it is left to application defaults.
   pub size: Option<SymbolSize>,
@@ -13,7 +13,7 @@ pub struct TypeAttributes {
 /// The [Type] element indicates the graphic note type.
 ///
 /// Values range from 1024th to maxima.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Type {
   /// Element-specific attributes
   pub attributes: TypeAttributes,
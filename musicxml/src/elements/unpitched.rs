@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Unpitched] element.
-#[derive(Debug, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct UnpitchedContents {
   /// The [DisplayStep] element specifies the pitch step of the unpitched note.
   pub display_step: DisplayStep,
@@ -18,7 +18,7 @@ pub struct UnpitchedContents {
 ///
 /// If the child elements are not present, the note is placed on the middle line of the staff. This is generally used with a one-line staff.
 /// Notes in percussion clef should always use an [Unpitched] element rather than a [Pitch][super::Pitch] element.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Unpitched {
   /// Element-specific attributes
   pub attributes: (),
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [UpBow] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct UpBowAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -42,7 +42,7 @@ pub struct UpBowAttributes {
 /// The [UpBow] element represents the symbol that is used both for up-bowing on bowed instruments, and up-stroke on plucked instruments.
 ///
 /// ![UpBow](https://hedgetechllc.github.io/musicxml/musicxml/elements/up-bow.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("up-bow")]
 pub struct UpBow {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [VerticalTurn] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct VerticalTurnAttributes {
   /// If yes, the trill accelerates during playback. It is no if not specified.
   pub accelerate: Option<YesNo>,
@@ -61,7 +61,7 @@ pub struct VerticalTurnAttributes {
 /// The [VerticalTurn] element has the turn symbol shape arranged vertically going from upper left to lower right.
 ///
 /// ![VerticalTurn](https://hedgetechllc.github.io/musicxml/musicxml/elements/vertical-turn.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("vertical-turn")]
 pub struct VerticalTurn {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [VirtualInstrument] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct VirtualInstrumentContents {
   /// The [VirtualLibrary] element specifies the virtual library used for the virtual instrument.
   pub virtual_library: Option<VirtualLibrary>,
@@ -13,7 +13,7 @@ pub struct VirtualInstrumentContents {
 }
 
 /// The [VirtualInstrument] element defines a specific virtual instrument used for an instrument sound.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("virtual-instrument")]
 pub struct VirtualInstrument {
   /// Element-specific attributes
@@ -7,7 +7,7 @@ use musicxml_macros::*;
 /// ![Voice](https://hedgetechllc.github.io/musicxml/musicxml/elements/voice.png)
 ///
 /// The [Voice] element is used to distinguish between multiple voices in individual parts.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Voice {
   /// Element-specific attributes
   pub attributes: (),
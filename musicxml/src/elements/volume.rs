@@ -6,7 +6,7 @@ use musicxml_macros::*;
 /// The [Volume] element value is a percentage of the maximum ranging from 0 to 100, with decimal values allowed.
 ///
 /// This corresponds to a scaling value for the MIDI 1.0 channel volume controller.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Volume {
   /// Element-specific attributes
   pub attributes: (),
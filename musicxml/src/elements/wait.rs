@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Wait] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct WaitAttributes {
   /// Restricts the [Wait] to apply to a single player.
   pub player: Option<IdRef>,
@@ -17,7 +17,7 @@ pub struct WaitAttributes {
 ///
 /// This typically happens at the start of new sections or after a held note or indeterminate music. These waiting points cannot always be
 /// inferred reliably from the contents of the displayed score.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Wait {
   /// Element-specific attributes
   pub attributes: WaitAttributes,
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [WavyLine] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct WavyLineAttributes {
   /// Indicates if this is the start, stop, or continuation of the wavy line. The value should be continue whenever used within a [Barline][super::Barline] element.
   pub r#type: StartStopContinue,
@@ -57,7 +57,7 @@ pub struct WavyLineAttributes {
 /// Wavy lines are one way to indicate trills and vibrato.
 ///
 /// ![WavyLine](https://hedgetechllc.github.io/musicxml/musicxml/elements/wavy-line.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("wavy-line")]
 pub struct WavyLine {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Wedge] element.
-#[derive(Debug, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct WedgeAttributes {
   /// The value is crescendo for the start of a wedge that is closed at the left side, diminuendo for the start of a wedge that is closed on the right side, and stop for the end of a wedge.
   pub r#type: WedgeType,
@@ -49,7 +49,7 @@ pub struct WedgeAttributes {
 /// The [Wedge] element represents crescendo and diminuendo wedge symbols.
 ///
 /// The `line_type` attribute is solid if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Wedge {
   /// Element-specific attributes
   pub attributes: WedgeAttributes,
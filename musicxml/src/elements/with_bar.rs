@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [WithBar] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct WithBarAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -43,7 +43,7 @@ pub struct WithBarAttributes {
 ///
 /// The content of the element indicates how this should be notated. Content values of "scoop" and "dip" refer to the Standard Music Font Layout (SMuFL)
 /// "guitarVibratoBarScoop" and "guitarVibratoBarDip" glyphs.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 #[rename("with-bar")]
 pub struct WithBar {
   /// Element-specific attributes
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Wood] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct WoodAttributes {
   /// Distinguishes different SMuFL stylistic alternates.
   pub smufl: Option<SmuflPictogramGlyphName>,
@@ -13,7 +13,7 @@ pub struct WoodAttributes {
 /// The [Wood] element represents pictograms for wood percussion instruments.
 ///
 /// ![Wood](https://hedgetechllc.github.io/musicxml/musicxml/elements/wood.png)
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Wood {
   /// Element-specific attributes
   pub attributes: WoodAttributes,
@@ -7,7 +7,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Attributes pertaining to the [Words] element.
-#[derive(Debug, Default, PartialEq, Eq, AttributeDeserialize, AttributeSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, AttributeDeserialize, AttributeSerialize)]
 pub struct WordsAttributes {
   /// Indicates the color of an element.
   pub color: Option<Color>,
@@ -91,7 +91,7 @@ pub struct WordsAttributes {
 /// The [Words] element specifies a standard text direction.
 ///
 /// The `enclosure` attribute is none if not specified. Left justification is used if not specified.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Words {
   /// Element-specific attributes
   pub attributes: WordsAttributes,
@@ -4,7 +4,7 @@ use musicxml_internal::*;
 use musicxml_macros::*;
 
 /// Contents of the [Work] element.
-#[derive(Debug, Default, PartialEq, Eq, ContentDeserialize, ContentSerialize)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, ContentDeserialize, ContentSerialize, ContentWalk)]
 pub struct WorkContents {
   /// The [WorkNumber] element specifies the number of the work.
   pub work_number: Option<WorkNumber>,
@@ -17,7 +17,7 @@ pub struct WorkContents {
 /// Works are optionally identified by number and title.
 ///
 /// The [Work] element also may indicate a link to the [Opus] document that composes multiple scores into a collection.
-#[derive(Debug, PartialEq, Eq, ElementDeserialize, ElementSerialize)]
+#[derive(Debug, PartialEq, Eq, Clone, ElementDeserialize, ElementSerialize, ElementWalk)]
 pub struct Work {
   /// Element-specific attributes
   pub attributes: (),
@@ -151,6 +151,61 @@ pub mod elements;
 /// and writing process.
 pub mod parser;
 
+/// Contains fluent builders for constructing scores programmatically.
+///
+/// The [ScoreBuilder][builder::ScoreBuilder] produces a valid [ScorePartwise] object without requiring every
+/// element to be filled in by hand, automatically computing the divisions, durations, note types, dots, and time
+/// modifications required by each note.
+pub mod builder;
+
+/// Contains visitors for walking every element of a score along with its position in the score.
+///
+/// A [Visitor][visit::Visitor] or [VisitorMut][visit::VisitorMut] receives each element in document order together
+/// with a [VisitContext][visit::VisitContext] describing its part, measure, voice, staff, and onset, allowing
+/// queries and transformations to be written without traversing the nested element types by hand.
+pub mod visit;
+
+/// Contains a path-based query language for selecting elements from a score.
+///
+/// Queries such as `part[@id=P2]/measure[10..20]/note[voice=2]` are evaluated directly over the typed elements of a
+/// score and return references to the matching elements along with their locations. See [Query][query::Query] for a
+/// description of the query syntax.
+pub mod query;
+
+/// Contains functions for restructuring scores, such as extracting individual parts from a full score or cutting and
+/// joining excerpts.
+///
+/// Each transformation takes an existing [ScorePartwise] object and returns a new one, leaving the original score
+/// unchanged.
+pub mod transform;
+
+/// Contains functions for extracting the words of a score as structured verses and for adding lyrics from plain text.
+///
+/// Extracted [Verses][lyrics::Verse] are divided into lines, words, and syllables, with each syllable referring back to
+/// the [Note][elements::Note] on which it is sung.
+pub mod lyrics;
+
+/// Contains a summary of the metadata, structure, and contents of a score.
+///
+/// A [ScoreSummary][summary::ScoreSummary] collects the information most often needed to catalogue a score, such as
+/// its title and creators, parts, length, key and time signatures, and the notational features it uses.
+pub mod summary;
+
+/// Contains functions for converting scores to and from other music notation formats.
+///
+/// Each supported format lives in its own submodule and exposes a pair of functions: one that exports a
+/// [ScorePartwise] object into the target format, and one that imports data in that format back into a
+/// [ScorePartwise] object.
+pub mod convert;
+
+/// Contains functions for rendering scores into other media, such as audio.
+///
+/// Rendering is one-way: unlike the [convert] module, the output of a renderer cannot be read back into a
+/// [ScorePartwise] object.
+pub mod render;
+
+mod util;
+
 use elements::{ScorePartwise, ScoreTimewise};
 
 /// Reads a MusicXML file and returns a [ScorePartwise] object.
@@ -270,3 +325,81 @@ pub fn write_timewise_score_data(
 ) -> Result<Vec<u8>, String> {
   parser::parse_score_timewise_to_data(score, compressed, true, write_as_partwise)
 }
+
+/// Writes a [ScorePartwise] object into a MusicXML file using the specified [WriteOptions][parser::WriteOptions].
+///
+/// If the `compressed` parameter is set to `true`, the MusicXML file will be written as a compressed `.mxl` file.
+/// If the `write_as_timewise` parameter is set to `true`, the MusicXML file will be converted into a timewise
+/// format and written as a `<score-timewise>` element.
+///
+/// # Errors
+///
+/// If the file cannot be written or the data cannot be serialized into a valid MusicXML format, an error message
+/// will be returned.
+pub fn write_partwise_score_with_options(
+  path: &str,
+  score: &ScorePartwise,
+  compressed: bool,
+  write_as_timewise: bool,
+  options: &parser::WriteOptions,
+) -> Result<(), String> {
+  parser::parse_score_partwise_to_file(path, score, compressed, options, write_as_timewise)
+}
+
+/// Writes a [ScoreTimewise] object into a MusicXML file using the specified [WriteOptions][parser::WriteOptions].
+///
+/// If the `compressed` parameter is set to `true`, the MusicXML file will be written as a compressed `.mxl` file.
+/// If the `write_as_partwise` parameter is set to `true`, the MusicXML file will be converted into a partwise
+/// format and written as a `<score-partwise>` element.
+///
+/// # Errors
+///
+/// If the file cannot be written or the data cannot be serialized into a valid MusicXML format, an error message
+/// will be returned.
+pub fn write_timewise_score_with_options(
+  path: &str,
+  score: &ScoreTimewise,
+  compressed: bool,
+  write_as_partwise: bool,
+  options: &parser::WriteOptions,
+) -> Result<(), String> {
+  parser::parse_score_timewise_to_file(path, score, compressed, options, write_as_partwise)
+}
+
+/// Writes a [ScorePartwise] object into a MusicXML data buffer using the specified
+/// [WriteOptions][parser::WriteOptions].
+///
+/// If the `compressed` parameter is set to `true`, the MusicXML contents will be written as compressed `.mxl` data.
+/// If the `write_as_timewise` parameter is set to `true`, the MusicXML contents will be converted into a timewise
+/// format and written as a `<score-timewise>` element.
+///
+/// # Errors
+///
+/// If the data cannot be serialized into a valid MusicXML format, an error message will be returned.
+pub fn write_partwise_score_data_with_options(
+  score: &ScorePartwise,
+  compressed: bool,
+  write_as_timewise: bool,
+  options: &parser::WriteOptions,
+) -> Result<Vec<u8>, String> {
+  parser::parse_score_partwise_to_data(score, compressed, options, write_as_timewise)
+}
+
+/// Writes a [ScoreTimewise] object into a MusicXML data buffer using the specified
+/// [WriteOptions][parser::WriteOptions].
+///
+/// If the `compressed` parameter is set to `true`, the MusicXML contents will be written as compressed `.mxl` data.
+/// If the `write_as_partwise` parameter is set to `true`, the MusicXML contents will be converted into a partwise
+/// format and written as a `<score-partwise>` element.
+///
+/// # Errors
+///
+/// If the data cannot be serialized into a valid MusicXML format, an error message will be returned.
+pub fn write_timewise_score_data_with_options(
+  score: &ScoreTimewise,
+  compressed: bool,
+  write_as_partwise: bool,
+  options: &parser::WriteOptions,
+) -> Result<Vec<u8>, String> {
+  parser::parse_score_timewise_to_data(score, compressed, options, write_as_partwise)
+}
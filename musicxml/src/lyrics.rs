@@ -0,0 +1,878 @@
+use crate::datatypes::{NmToken, StartStopContinue, Syllabic};
+use crate::elements::{
+  AdditionalTextLyric, Elision, ElisionAttributes, EndLine, EndParagraph, Extend, ExtendAttributes, Lyric,
+  LyricAttributes, LyricContents, Measure, MeasureElement, NotationContentTypes, Note, Part, ScorePartwise,
+  Syllabic as SyllabicElement, Text, TextAttributes, TextLyric,
+};
+use crate::util::{
+  note_is_chord, note_is_cue, note_is_grace, note_is_rest, note_tied, note_voice, part_measures, part_measures_mut,
+  performance_order,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The location of the [Note] carrying a lyric syllable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteReference {
+  /// The `id` of the [Part] containing the note.
+  pub part_id: String,
+  /// The index of the [Measure] containing the note within its part, starting from 0.
+  pub measure_index: usize,
+  /// The `number` of the [Measure] containing the note.
+  pub measure_number: String,
+  /// The index of the note within the contents of its measure, counting every [MeasureElement].
+  pub element_index: usize,
+  /// The pass through a repeated section on which the syllable is sung, starting from 1.
+  ///
+  /// This is always 1 unless repeats have been unrolled.
+  pub pass: u32,
+}
+
+/// A single syllable of a lyric.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricSyllable {
+  /// The text of the syllable.
+  pub text: String,
+  /// Whether the syllable is extended over the following notes by a melisma.
+  pub extend: bool,
+  /// The note on which the syllable is sung.
+  pub note: NoteReference,
+}
+
+/// A word made up of one or more syllables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricWord {
+  /// The syllables of the word, in order.
+  pub syllables: Vec<LyricSyllable>,
+}
+
+impl LyricWord {
+  /// Returns the text of the word with all of its syllables joined together.
+  #[must_use]
+  pub fn text(&self) -> String {
+    self.syllables.iter().map(|syllable| syllable.text.as_str()).collect()
+  }
+}
+
+/// A line of lyrics, ending at an [EndLine] or [EndParagraph] element or at the end of its verse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricLine {
+  /// The words of the line, in order.
+  pub words: Vec<LyricWord>,
+  /// Whether the line is the last line of a paragraph.
+  pub end_paragraph: bool,
+}
+
+impl LyricLine {
+  /// Returns the text of the line with its words separated by spaces.
+  #[must_use]
+  pub fn text(&self) -> String {
+    self.words.iter().map(LyricWord::text).collect::<Vec<_>>().join(" ")
+  }
+}
+
+/// The words sung by a single voice of a part for one verse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verse {
+  /// The `id` of the [Part] in which the verse is sung.
+  pub part_id: String,
+  /// The voice in which the verse is sung.
+  pub voice: String,
+  /// The `number` of the [Lyric] elements making up the verse, which defaults to `"1"` when unspecified.
+  ///
+  /// This is `None` when repeats have been unrolled, since a single verse may then combine several lyric numbers.
+  pub number: Option<String>,
+  /// The `name` of the [Lyric] elements making up the verse, such as `"chorus"`, if specified.
+  pub name: Option<String>,
+  /// The lines of the verse, in order.
+  pub lines: Vec<LyricLine>,
+}
+
+impl Verse {
+  /// Returns the text of the verse with one line per [LyricLine] and a blank line after each paragraph.
+  #[must_use]
+  pub fn text(&self) -> String {
+    let mut text = String::new();
+    for (index, line) in self.lines.iter().enumerate() {
+      if index > 0 {
+        text.push('\n');
+        if self.lines[index - 1].end_paragraph {
+          text.push('\n');
+        }
+      }
+      text += &line.text();
+    }
+    text
+  }
+}
+
+/// Assembles syllables into words and lines according to their syllabic types and line endings.
+struct VerseBuilder {
+  part_id: String,
+  voice: String,
+  number: Option<String>,
+  name: Option<String>,
+  lines: Vec<LyricLine>,
+  words: Vec<LyricWord>,
+  syllables: Vec<LyricSyllable>,
+}
+
+impl VerseBuilder {
+  fn finish_word(&mut self) {
+    if !self.syllables.is_empty() {
+      self.words.push(LyricWord {
+        syllables: core::mem::take(&mut self.syllables),
+      });
+    }
+  }
+
+  fn finish_line(&mut self, end_paragraph: bool) {
+    self.finish_word();
+    if !self.words.is_empty() {
+      self.lines.push(LyricLine {
+        words: core::mem::take(&mut self.words),
+        end_paragraph,
+      });
+    } else if let Some(line) = self.lines.last_mut() {
+      line.end_paragraph |= end_paragraph;
+    }
+  }
+
+  fn push(&mut self, text: &str, syllabic: Option<&Syllabic>, note: &NoteReference) {
+    if matches!(syllabic, None | Some(Syllabic::Begin | Syllabic::Single)) {
+      self.finish_word();
+    }
+    self.syllables.push(LyricSyllable {
+      text: text.to_string(),
+      extend: false,
+      note: note.clone(),
+    });
+    if matches!(syllabic, None | Some(Syllabic::End | Syllabic::Single)) {
+      self.finish_word();
+    }
+  }
+
+  fn extend(&mut self) {
+    let last = match self.syllables.last_mut() {
+      Some(syllable) => Some(syllable),
+      None => self.words.last_mut().and_then(|word| word.syllables.last_mut()),
+    };
+    if let Some(syllable) = last {
+      syllable.extend = true;
+    }
+  }
+
+  fn add(&mut self, lyric: &Lyric, note: &NoteReference) {
+    if self.name.is_none() {
+      self.name = lyric.attributes.name.as_ref().map(|name| name.to_string());
+    }
+    match &lyric.content {
+      LyricContents::Text(text) => {
+        self.push(
+          &text.text.content,
+          text.syllabic.as_ref().map(|syllabic| &syllabic.content),
+          note,
+        );
+        for additional in &text.additional {
+          if additional.elision.is_some() {
+            self.finish_word();
+          }
+          self.push(
+            &additional.text.content,
+            additional.syllabic.as_ref().map(|syllabic| &syllabic.content),
+            note,
+          );
+        }
+        if text.extend.is_some() {
+          self.extend();
+        }
+        if text.end_paragraph.is_some() {
+          self.finish_line(true);
+        } else if text.end_line.is_some() {
+          self.finish_line(false);
+        }
+      }
+      LyricContents::Extend(_) => self.extend(),
+      LyricContents::Laughing(_) | LyricContents::Humming(_) => {}
+    }
+  }
+
+  fn finish(mut self) -> Verse {
+    self.finish_line(false);
+    Verse {
+      part_id: self.part_id,
+      voice: self.voice,
+      number: self.number,
+      name: self.name,
+      lines: self.lines,
+    }
+  }
+}
+
+/// Returns the builder for a verse, creating it if it has not been seen before.
+fn verse_builder<'a>(
+  verses: &'a mut Vec<VerseBuilder>,
+  part_id: &str,
+  voice: &str,
+  number: Option<&str>,
+) -> &'a mut VerseBuilder {
+  let index = verses
+    .iter()
+    .position(|verse| verse.part_id == part_id && verse.voice == voice && verse.number.as_deref() == number)
+    .unwrap_or_else(|| {
+      verses.push(VerseBuilder {
+        part_id: part_id.to_string(),
+        voice: voice.to_string(),
+        number: number.map(ToString::to_string),
+        name: None,
+        lines: Vec::new(),
+        words: Vec::new(),
+        syllables: Vec::new(),
+      });
+      verses.len() - 1
+    });
+  &mut verses[index]
+}
+
+/// Returns the verse number of a lyric, which defaults to `"1"` when unspecified.
+fn lyric_number(lyric: &Lyric) -> &str {
+  lyric.attributes.number.as_ref().map_or("1", |number| number.as_str())
+}
+
+/// Returns every note in a measure that carries lyrics, along with its index in the measure contents.
+fn lyric_notes(measure: &Measure) -> impl Iterator<Item = (usize, &Note)> {
+  measure
+    .content
+    .iter()
+    .enumerate()
+    .filter_map(|(index, element)| match element {
+      MeasureElement::Note(note) if !note.content.lyric.is_empty() => Some((index, note)),
+      _ => None,
+    })
+}
+
+/// Collects the verses sung in a part, following the measures in the specified order.
+fn part_verses(part: &Part, order: &[(usize, u32)], unrolled: bool, verses: &mut Vec<VerseBuilder>) {
+  let part_id = part.attributes.id.as_str();
+  let measures: Vec<&Measure> = part_measures(part).collect();
+  for &(measure_index, pass) in order {
+    let measure = measures[measure_index];
+    for (element_index, note) in lyric_notes(measure) {
+      let reference = NoteReference {
+        part_id: part_id.to_string(),
+        measure_index,
+        measure_number: measure.attributes.number.to_string(),
+        element_index,
+        pass,
+      };
+      let voice = note_voice(note);
+      if unrolled {
+        // Each pass sings the lyric numbered after it, falling back to the first lyric on the note
+        let pass = pass.to_string();
+        let lyric = note
+          .content
+          .lyric
+          .iter()
+          .find(|lyric| lyric_number(lyric) == pass)
+          .unwrap_or(&note.content.lyric[0]);
+        verse_builder(verses, part_id, voice, None).add(lyric, &reference);
+      } else {
+        for lyric in &note.content.lyric {
+          verse_builder(verses, part_id, voice, Some(lyric_number(lyric))).add(lyric, &reference);
+        }
+      }
+    }
+  }
+}
+
+/// Extracts the words of every verse in a score.
+///
+/// A separate [Verse] is returned for every combination of part, voice, and [Lyric] `number`, in order of first
+/// appearance. Syllables are joined into words according to their [Syllabic] types, with an [Elision] separating the
+/// words sung on a single note, and words are split into lines at every [EndLine] and [EndParagraph] element. Repeats
+/// are not followed, so each note is visited exactly once; see [extract_unrolled_verses] for the words as sung.
+#[must_use]
+pub fn extract_verses(score: &ScorePartwise) -> Vec<Verse> {
+  let mut verses = Vec::new();
+  for part in &score.content.part {
+    let order: Vec<(usize, u32)> = (0..part_measures(part).count()).map(|index| (index, 1)).collect();
+    part_verses(part, &order, false, &mut verses);
+  }
+  verses.into_iter().map(VerseBuilder::finish).collect()
+}
+
+/// Extracts the words of a score in the order in which they are sung, following all repeats and volta endings.
+///
+/// A single [Verse] is returned for every combination of part and voice. On the `n`th pass through a repeated
+/// section, each note contributes its lyric numbered `n`, or its first lyric if it has no such lyric, so that verses
+/// written beneath repeated music are sung in turn while lyrics that are the same on every pass are repeated. Words
+/// and lines are otherwise assembled as described in [extract_verses].
+#[must_use]
+pub fn extract_unrolled_verses(score: &ScorePartwise) -> Vec<Verse> {
+  let mut verses = Vec::new();
+  for part in &score.content.part {
+    let measures: Vec<&Measure> = part_measures(part).collect();
+    part_verses(part, &performance_order(&measures), true, &mut verses);
+  }
+  verses.into_iter().map(VerseBuilder::finish).collect()
+}
+
+/// A group of syllables to be sung on a single note.
+enum LyricToken {
+  /// Syllables joined by elisions, along with their syllabic types.
+  Syllables(Vec<(String, Syllabic)>),
+  /// A note to be skipped as part of a melisma.
+  Melisma,
+  /// The end of a line of text, possibly ending a paragraph.
+  LineEnd(bool),
+}
+
+/// Returns the syllabic type of a syllable according to whether it begins and/or ends its word.
+fn syllabic(begins: bool, ends: bool) -> Syllabic {
+  match (begins, ends) {
+    (true, true) => Syllabic::Single,
+    (true, false) => Syllabic::Begin,
+    (false, true) => Syllabic::End,
+    (false, false) => Syllabic::Middle,
+  }
+}
+
+/// Splits plain lyric text into the syllables to be sung on each note.
+fn tokenize_lyrics(text: &str) -> Vec<LyricToken> {
+  let mut tokens = Vec::new();
+  let lines: Vec<&str> = text.lines().map(str::trim).collect();
+  for (index, line) in lines.iter().enumerate() {
+    // A standalone run of hyphens joins the neighbouring words into a single hyphenated word
+    let mut words: Vec<String> = Vec::new();
+    let mut joined = false;
+    for word in line.split_whitespace() {
+      if word.chars().all(|c| c == '-') {
+        joined = !words.is_empty();
+      } else if let (true, Some(last)) = (joined, words.last_mut()) {
+        last.push('-');
+        last.push_str(word);
+        joined = false;
+      } else {
+        words.push(word.to_string());
+      }
+    }
+    for word in &words {
+      if word == "_" {
+        tokens.push(LyricToken::Melisma);
+        continue;
+      }
+      let syllables: Vec<&str> = word.split('-').filter(|syllable| !syllable.is_empty()).collect();
+      for (position, syllable) in syllables.iter().enumerate() {
+        let pieces: Vec<&str> = syllable.split('~').collect();
+        let elided = pieces
+          .iter()
+          .enumerate()
+          .filter(|(_, piece)| !piece.is_empty())
+          .map(|(piece_index, piece)| {
+            let begins = position == 0 || piece_index > 0;
+            let ends = position + 1 == syllables.len() || piece_index + 1 < pieces.len();
+            (piece.to_string(), syllabic(begins, ends))
+          })
+          .collect();
+        tokens.push(LyricToken::Syllables(elided));
+      }
+    }
+    if !line.is_empty() && lines[index + 1..].iter().any(|next| !next.is_empty()) {
+      tokens.push(LyricToken::LineEnd(lines[index + 1].is_empty()));
+    }
+  }
+  tokens
+}
+
+/// Returns a lyric that sings one or more syllables, joined by elisions, on a single note.
+fn new_lyric(number: &str, syllables: &[(String, Syllabic)]) -> Lyric {
+  let syllable = |syllabic: &Syllabic| {
+    Some(SyllabicElement {
+      attributes: (),
+      content: syllabic.clone(),
+    })
+  };
+  let text = |text: &str| Text {
+    attributes: TextAttributes::default(),
+    content: text.to_string(),
+  };
+  let mut lyric = TextLyric::default();
+  for (index, (content, syllabic)) in syllables.iter().enumerate() {
+    if index == 0 {
+      lyric.syllabic = syllable(syllabic);
+      lyric.text = text(content);
+    } else {
+      lyric.additional.push(AdditionalTextLyric {
+        elision: Some(Elision {
+          attributes: ElisionAttributes::default(),
+          content: String::from("\u{203F}"),
+        }),
+        syllabic: syllable(syllabic),
+        text: text(content),
+      });
+    }
+  }
+  Lyric {
+    attributes: LyricAttributes {
+      number: Some(NmToken(number.to_string())),
+      ..Default::default()
+    },
+    content: LyricContents::Text(lyric),
+  }
+}
+
+/// Adds an extension line to a lyric if its last syllable ends a word and it is not already extended.
+fn extend_lyric(lyric: &mut Lyric) {
+  if let LyricContents::Text(text) = &mut lyric.content {
+    let syllabic = match text.additional.last() {
+      Some(additional) => additional.syllabic.as_ref(),
+      None => text.syllabic.as_ref(),
+    };
+    let ends_word = syllabic.is_some_and(|syllabic| matches!(syllabic.content, Syllabic::Single | Syllabic::End));
+    if ends_word && text.extend.is_none() {
+      text.extend = Some(Extend {
+        attributes: ExtendAttributes::default(),
+        content: (),
+      });
+    }
+  }
+}
+
+/// Ends the line of a lyric, possibly also ending a paragraph.
+fn end_lyric_line(lyric: &mut Lyric, paragraph: bool) {
+  let (end_line, end_paragraph) = match &mut lyric.content {
+    LyricContents::Text(text) => (&mut text.end_line, &mut text.end_paragraph),
+    LyricContents::Extend(extend) => (&mut extend.end_line, &mut extend.end_paragraph),
+    LyricContents::Laughing(laughing) => (&mut laughing.end_line, &mut laughing.end_paragraph),
+    LyricContents::Humming(humming) => (&mut humming.end_line, &mut humming.end_paragraph),
+  };
+  if paragraph {
+    *end_paragraph = Some(EndParagraph {
+      attributes: (),
+      content: (),
+    });
+  } else {
+    *end_line = Some(EndLine {
+      attributes: (),
+      content: (),
+    });
+  }
+}
+
+/// Finds the notes of a voice that are available to sing a syllable, clearing any existing lyrics of the same verse.
+///
+/// Each note is returned by its measure and element index, along with whether it continues a tie or slur and so
+/// extends the previous syllable as a melisma. Returns [None] if the voice does not contain any notes.
+fn singable_notes(part: &mut Part, voice: &str, number: &str) -> Option<Vec<((usize, usize), bool)>> {
+  let mut notes: Vec<((usize, usize), bool)> = Vec::new();
+  let mut slurs: Vec<u8> = Vec::new();
+  let mut found = false;
+  for (measure_index, measure) in part_measures_mut(part).enumerate() {
+    for (note_index, element) in measure.content.iter_mut().enumerate() {
+      let MeasureElement::Note(note) = element else {
+        continue;
+      };
+      if note_voice(note) != voice {
+        continue;
+      }
+      found = true;
+      note.content.lyric.retain(|lyric| lyric_number(lyric) != number);
+      if note_is_rest(note) || note_is_chord(note) || note_is_grace(note) || note_is_cue(note) {
+        continue;
+      }
+      let tied = note_tied(note).1;
+      notes.push(((measure_index, note_index), tied || !slurs.is_empty()));
+      let note_slurs = note
+        .content
+        .notations
+        .iter()
+        .flat_map(|notations| notations.content.notations.iter())
+        .filter_map(|notation| match notation {
+          NotationContentTypes::Slur(slur) => Some(slur),
+          _ => None,
+        });
+      for slur in note_slurs {
+        let slur_number = slur.attributes.number.as_ref().map_or(1, |number| number.0);
+        match slur.attributes.r#type {
+          StartStopContinue::Start if !slurs.contains(&slur_number) => slurs.push(slur_number),
+          StartStopContinue::Stop => slurs.retain(|other| *other != slur_number),
+          _ => {}
+        }
+      }
+    }
+  }
+  found.then_some(notes)
+}
+
+/// Adds lyrics from plain text to the notes of a single voice, returning the updated score.
+///
+/// Any existing lyrics with the specified verse `number` are removed from the voice before the text is assigned to
+/// its notes in order, one syllable per note. Rests, grace notes, cue notes, and additional chord notes never receive
+/// a syllable, and notes that continue a tie or lie within a slur are treated as part of a melisma, extending the
+/// previous syllable rather than receiving a new one. The text is interpreted as follows:
+///
+/// - Words are separated by whitespace, and the syllables of a word by hyphens, as in `"Hal-le-lu-jah"`. A hyphen
+///   surrounded by whitespace, as in `"Hal -- le -- lu -- jah"`, also joins syllables into a word.
+/// - A tilde joins two words sung on a single note with an [Elision], as in `"the~eye"`.
+/// - An underscore on its own skips a note, extending the previous syllable as a melisma.
+/// - Each line of text ends with an [EndLine], and a blank line ends a paragraph with an [EndParagraph].
+///
+/// # Errors
+///
+/// If the part or voice does not exist, or if the text contains more syllables than there are notes available in the
+/// voice, an error message will be returned.
+pub fn import_lyrics(
+  score: &ScorePartwise,
+  part_id: &str,
+  voice: &str,
+  number: &str,
+  text: &str,
+) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  let part = score
+    .content
+    .part
+    .iter_mut()
+    .find(|part| *part.attributes.id == part_id)
+    .ok_or_else(|| format!("Part \"{part_id}\" does not exist in the score"))?;
+
+  let notes = singable_notes(part, voice, number)
+    .ok_or_else(|| format!("Voice \"{voice}\" does not exist in part \"{part_id}\""))?;
+
+  // Assign the syllables to the available notes, skipping over melismas
+  let mut lyrics: Vec<(usize, Lyric)> = Vec::new();
+  let mut next = 0;
+  let skip_melismas = |next: &mut usize, lyrics: &mut Vec<(usize, Lyric)>| {
+    while *next < notes.len() && notes[*next].1 {
+      *next += 1;
+      if let Some((_, lyric)) = lyrics.last_mut() {
+        extend_lyric(lyric);
+      }
+    }
+  };
+  for token in tokenize_lyrics(text) {
+    match token {
+      LyricToken::Syllables(syllables) => {
+        skip_melismas(&mut next, &mut lyrics);
+        if next == notes.len() {
+          return Err(format!(
+            "Lyric text has more syllables than there are notes in voice \"{voice}\" of part \"{part_id}\""
+          ));
+        }
+        lyrics.push((next, new_lyric(number, &syllables)));
+        next += 1;
+      }
+      LyricToken::Melisma => {
+        if next < notes.len() {
+          next += 1;
+          if let Some((_, lyric)) = lyrics.last_mut() {
+            extend_lyric(lyric);
+          }
+        }
+      }
+      LyricToken::LineEnd(paragraph) => {
+        if let Some((_, lyric)) = lyrics.last_mut() {
+          end_lyric_line(lyric, paragraph);
+        }
+      }
+    }
+  }
+  skip_melismas(&mut next, &mut lyrics);
+
+  let mut measures: Vec<&mut Measure> = part_measures_mut(part).collect();
+  for (index, lyric) in lyrics {
+    let ((measure_index, note_index), _) = notes[index];
+    let MeasureElement::Note(note) = &mut measures[measure_index].content[note_index] else {
+      continue;
+    };
+    // Lyrics are kept in order of their verse numbers
+    let follows = |other: &str| match (other.parse::<u32>(), number.parse::<u32>()) {
+      (Ok(other), Ok(number)) => other > number,
+      _ => other > number,
+    };
+    let position = note
+      .content
+      .lyric
+      .iter()
+      .position(|other| follows(lyric_number(other)))
+      .unwrap_or(note.content.lyric.len());
+    note.content.lyric.insert(position, lyric);
+  }
+  Ok(score)
+}
+
+#[cfg(test)]
+mod lyrics_tests {
+  use super::*;
+  use crate::parser::parse_from_xml_str;
+
+  fn words(verse: &Verse) -> Vec<Vec<String>> {
+    verse
+      .lines
+      .iter()
+      .map(|line| line.words.iter().map(LyricWord::text).collect())
+      .collect()
+  }
+
+  #[test]
+  fn extract_joins_syllables_into_words() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name></part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>begin</syllabic><text>Hal</text></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>middle</syllabic><text>le</text></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>middle</syllabic><text>lu</text></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>end</syllabic><text>jah</text><end-line/></lyric>
+            </note>
+          </measure>
+          <measure number="2">
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>praise</text><extend/></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><extend/></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1">
+                <syllabic>single</syllabic><text>the</text><elision>‿</elision><syllabic>single</syllabic><text>eye</text>
+              </lyric>
+            </note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let verses = extract_verses(&score);
+    assert_eq!(verses.len(), 1);
+    let verse = &verses[0];
+    assert_eq!((verse.part_id.as_str(), verse.voice.as_str()), ("P1", "1"));
+    assert_eq!(verse.number.as_deref(), Some("1"));
+    assert_eq!(words(verse), vec![vec!["Hallelujah"], vec!["praise", "the", "eye"]]);
+    assert_eq!(verse.text(), "Hallelujah\npraise the eye");
+    let jah = &verse.lines[0].words[0].syllables[3];
+    assert_eq!(
+      jah.note,
+      NoteReference {
+        part_id: "P1".to_string(),
+        measure_index: 0,
+        measure_number: "1".to_string(),
+        element_index: 4,
+        pass: 1,
+      }
+    );
+    let praise = &verse.lines[1].words[0].syllables[0];
+    assert!(praise.extend);
+    assert_eq!(
+      verse.lines[1].words[1].syllables[0].note,
+      verse.lines[1].words[2].syllables[0].note
+    );
+  }
+
+  #[test]
+  fn extract_separates_verses_and_voices() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name></part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>Glo</text><end-paragraph/></lyric>
+              <lyric number="2"><syllabic>single</syllabic><text>Praise</text></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>ry</text></lyric>
+            </note>
+            <backup><duration>2</duration></backup>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration><voice>2</voice>
+              <lyric name="chorus"><syllabic>single</syllabic><text>Ah</text></lyric>
+            </note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let verses = extract_verses(&score);
+    let summary: Vec<(&str, Option<&str>, Option<&str>, String)> = verses
+      .iter()
+      .map(|verse| {
+        (
+          verse.voice.as_str(),
+          verse.number.as_deref(),
+          verse.name.as_deref(),
+          verse.text(),
+        )
+      })
+      .collect();
+    assert_eq!(
+      summary,
+      vec![
+        ("1", Some("1"), None, "Glo\n\nry".to_string()),
+        ("1", Some("2"), None, "Praise".to_string()),
+        ("2", Some("1"), Some("chorus"), "Ah".to_string()),
+      ]
+    );
+    assert!(verses[0].lines[0].end_paragraph);
+  }
+
+  #[test]
+  fn extract_unrolled_follows_repeats() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name></part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <barline location="left"><repeat direction="forward"/></barline>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>one</text></lyric>
+              <lyric number="2"><syllabic>single</syllabic><text>two</text></lyric>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>la</text></lyric>
+            </note>
+            <barline location="right"><repeat direction="backward"/></barline>
+          </measure>
+          <measure number="2">
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>end</text></lyric>
+            </note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    assert_eq!(extract_verses(&score)[1].text(), "two");
+    let verses = extract_unrolled_verses(&score);
+    assert_eq!(verses.len(), 1);
+    assert_eq!(verses[0].number, None);
+    assert_eq!(verses[0].text(), "one la two la end");
+    let passes: Vec<(usize, u32)> = verses[0].lines[0]
+      .words
+      .iter()
+      .map(|word| (word.syllables[0].note.measure_index, word.syllables[0].note.pass))
+      .collect();
+    assert_eq!(passes, vec![(0, 1), (0, 1), (0, 2), (0, 2), (1, 1)]);
+  }
+
+  #[test]
+  fn import_assigns_syllables() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name></part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <lyric number="1"><syllabic>single</syllabic><text>old</text></lyric>
+              <lyric number="2"><syllabic>single</syllabic><text>keep</text></lyric>
+            </note>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration><tie type="start"/></note>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration><tie type="stop"/></note>
+            <note><rest/><duration>1</duration></note>
+          </measure>
+          <measure number="2">
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <notations><slur type="start"/></notations>
+            </note>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration>
+              <notations><slur type="stop"/></notations>
+            </note>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+            <note><chord/><pitch><step>E</step><octave>4</octave></pitch><duration>1</duration></note>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+          <measure number="3">
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let imported = import_lyrics(&score, "P1", "1", "1", "Al-le-lu-ia\n\nthe~eye _ end").unwrap();
+    let verses = extract_verses(&imported);
+    assert_eq!(verses[0].text(), "Alleluia\n\nthe eye end");
+    assert_eq!(verses[1].text(), "keep");
+    let notes: Vec<(usize, usize)> = verses[0]
+      .lines
+      .iter()
+      .flat_map(|line| line.words.iter())
+      .map(|word| {
+        (
+          word.syllables[0].note.measure_index,
+          word.syllables[0].note.element_index,
+        )
+      })
+      .collect();
+    assert_eq!(notes, vec![(0, 1), (1, 4), (1, 4), (2, 1)]);
+    let syllables: Vec<(usize, usize)> = verses[0].lines[0].words[0]
+      .syllables
+      .iter()
+      .map(|syllable| (syllable.note.measure_index, syllable.note.element_index))
+      .collect();
+    assert_eq!(syllables, vec![(0, 1), (0, 2), (1, 0), (1, 2)]);
+    assert!(verses[0].lines[1].words[1].syllables[0].extend);
+    assert!(!verses[0].lines[0].words[0].syllables[1].extend);
+
+    let imported = import_lyrics(&imported, "P1", "1", "2", "Hal -- le -- lu -- jah").unwrap();
+    let verses = extract_verses(&imported);
+    assert_eq!(words(&verses[1]), vec![vec!["Hallelujah"]]);
+    assert_eq!(verses[0].text(), "Alleluia\n\nthe eye end");
+  }
+
+  #[test]
+  fn import_lyrics_errors() {
+    let score = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name></part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    assert_eq!(
+      import_lyrics(&score, "P2", "1", "1", "la").unwrap_err(),
+      "Part \"P2\" does not exist in the score"
+    );
+    assert_eq!(
+      import_lyrics(&score, "P1", "3", "1", "la").unwrap_err(),
+      "Voice \"3\" does not exist in part \"P1\""
+    );
+    assert_eq!(
+      import_lyrics(&score, "P1", "1", "1", "la la-la").unwrap_err(),
+      "Lyric text has more syllables than there are notes in voice \"1\" of part \"P1\""
+    );
+  }
+}
@@ -0,0 +1,325 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Write, iter::Peekable, str::Chars};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+  Array(Vec<JsonValue>),
+  Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+  pub fn get(&self, key: &str) -> Option<&JsonValue> {
+    match self {
+      JsonValue::Object(members) => members.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+      _ => None,
+    }
+  }
+
+  pub fn as_str(&self) -> Option<&str> {
+    match self {
+      JsonValue::String(value) => Some(value),
+      _ => None,
+    }
+  }
+
+  pub fn as_i64(&self) -> Option<i64> {
+    match self {
+      #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::float_cmp)]
+      JsonValue::Number(value) if *value == (*value as i64) as f64 => Some(*value as i64),
+      _ => None,
+    }
+  }
+
+  pub fn as_bool(&self) -> Option<bool> {
+    match self {
+      JsonValue::Bool(value) => Some(*value),
+      _ => None,
+    }
+  }
+
+  pub fn as_array(&self) -> &[JsonValue] {
+    match self {
+      JsonValue::Array(values) => values,
+      _ => &[],
+    }
+  }
+
+  pub fn as_object(&self) -> &[(String, JsonValue)] {
+    match self {
+      JsonValue::Object(members) => members,
+      _ => &[],
+    }
+  }
+}
+
+fn write_string(json_str: &mut String, value: &str) {
+  json_str.push('"');
+  for c in value.chars() {
+    match c {
+      '"' => json_str.push_str("\\\""),
+      '\\' => json_str.push_str("\\\\"),
+      '\n' => json_str.push_str("\\n"),
+      '\r' => json_str.push_str("\\r"),
+      '\t' => json_str.push_str("\\t"),
+      c if (c as u32) < 0x20 => {
+        let _ = write!(json_str, "\\u{:04x}", c as u32);
+      }
+      c => json_str.push(c),
+    }
+  }
+  json_str.push('"');
+}
+
+fn write_indent(json_str: &mut String, depth: i16) {
+  if depth >= 0 {
+    json_str.push('\n');
+    for _ in 0..depth {
+      json_str.push_str("  ");
+    }
+  }
+}
+
+pub fn parse_to_string(json: &JsonValue, depth: i16) -> String {
+  let mut json_str = String::new();
+  let inner_depth = if depth >= 0 { depth + 1 } else { depth };
+  match json {
+    JsonValue::Null => json_str.push_str("null"),
+    JsonValue::Bool(value) => json_str.push_str(if *value { "true" } else { "false" }),
+    JsonValue::Number(value) => {
+      let _ = write!(json_str, "{value}");
+    }
+    JsonValue::String(value) => write_string(&mut json_str, value),
+    JsonValue::Array(values) if values.is_empty() => json_str.push_str("[]"),
+    JsonValue::Array(values) => {
+      json_str.push('[');
+      for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+          json_str.push(',');
+        }
+        write_indent(&mut json_str, inner_depth);
+        json_str += parse_to_string(value, inner_depth).as_str();
+      }
+      write_indent(&mut json_str, depth);
+      json_str.push(']');
+    }
+    JsonValue::Object(members) if members.is_empty() => json_str.push_str("{}"),
+    JsonValue::Object(members) => {
+      json_str.push('{');
+      for (index, (key, value)) in members.iter().enumerate() {
+        if index > 0 {
+          json_str.push(',');
+        }
+        write_indent(&mut json_str, inner_depth);
+        write_string(&mut json_str, key);
+        json_str.push_str(if depth >= 0 { ": " } else { ":" });
+        json_str += parse_to_string(value, inner_depth).as_str();
+      }
+      write_indent(&mut json_str, depth);
+      json_str.push('}');
+    }
+  }
+  json_str
+}
+
+fn skip_whitespace(it: &mut Peekable<Chars>) {
+  while it.next_if(|c| matches!(c, ' ' | '\t' | '\r' | '\n')).is_some() {}
+}
+
+fn expect_literal(it: &mut Peekable<Chars>, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+  for expected in literal.chars() {
+    if it.next() != Some(expected) {
+      return Err(format!("Invalid JSON literal...expected '{literal}'"));
+    }
+  }
+  Ok(value)
+}
+
+fn read_hex(it: &mut Peekable<Chars>) -> Result<u32, String> {
+  let mut code = 0;
+  for _ in 0..4 {
+    let digit = it
+      .next()
+      .and_then(|c| c.to_digit(16))
+      .ok_or_else(|| String::from("Invalid unicode escape in JSON string"))?;
+    code = code * 16 + digit;
+  }
+  Ok(code)
+}
+
+fn read_string(it: &mut Peekable<Chars>) -> Result<String, String> {
+  let mut value = String::new();
+  loop {
+    match it.next() {
+      Some('"') => return Ok(value),
+      Some('\\') => match it.next() {
+        Some('"') => value.push('"'),
+        Some('\\') => value.push('\\'),
+        Some('/') => value.push('/'),
+        Some('b') => value.push('\u{8}'),
+        Some('f') => value.push('\u{c}'),
+        Some('n') => value.push('\n'),
+        Some('r') => value.push('\r'),
+        Some('t') => value.push('\t'),
+        Some('u') => {
+          let mut code = read_hex(it)?;
+          if (0xd800..0xdc00).contains(&code) {
+            if it.next() != Some('\\') || it.next() != Some('u') {
+              return Err(String::from("Unpaired surrogate in JSON string"));
+            }
+            code = 0x10000 + ((code - 0xd800) << 10) + (read_hex(it)? - 0xdc00);
+          }
+          value.push(char::from_u32(code).ok_or_else(|| String::from("Invalid unicode escape in JSON string"))?);
+        }
+        _ => return Err(String::from("Invalid escape sequence in JSON string")),
+      },
+      Some(c) => value.push(c),
+      None => return Err(String::from("Unterminated JSON string")),
+    }
+  }
+}
+
+fn read_value(it: &mut Peekable<Chars>) -> Result<JsonValue, String> {
+  skip_whitespace(it);
+  match it.peek().copied() {
+    Some('{') => {
+      it.next();
+      let mut members = Vec::new();
+      skip_whitespace(it);
+      if it.next_if_eq(&'}').is_some() {
+        return Ok(JsonValue::Object(members));
+      }
+      loop {
+        skip_whitespace(it);
+        if it.next() != Some('"') {
+          return Err(String::from("Expected a string key in JSON object"));
+        }
+        let key = read_string(it)?;
+        skip_whitespace(it);
+        if it.next() != Some(':') {
+          return Err(format!("Expected ':' after JSON key '{key}'"));
+        }
+        members.push((key, read_value(it)?));
+        skip_whitespace(it);
+        match it.next() {
+          Some(',') => (),
+          Some('}') => return Ok(JsonValue::Object(members)),
+          _ => return Err(String::from("Expected ',' or '}' in JSON object")),
+        }
+      }
+    }
+    Some('[') => {
+      it.next();
+      let mut values = Vec::new();
+      skip_whitespace(it);
+      if it.next_if_eq(&']').is_some() {
+        return Ok(JsonValue::Array(values));
+      }
+      loop {
+        values.push(read_value(it)?);
+        skip_whitespace(it);
+        match it.next() {
+          Some(',') => (),
+          Some(']') => return Ok(JsonValue::Array(values)),
+          _ => return Err(String::from("Expected ',' or ']' in JSON array")),
+        }
+      }
+    }
+    Some('"') => {
+      it.next();
+      Ok(JsonValue::String(read_string(it)?))
+    }
+    Some('t') => expect_literal(it, "true", JsonValue::Bool(true)),
+    Some('f') => expect_literal(it, "false", JsonValue::Bool(false)),
+    Some('n') => expect_literal(it, "null", JsonValue::Null),
+    Some(c) if c == '-' || c.is_ascii_digit() => {
+      let mut number = String::new();
+      while let Some(c) = it.next_if(|c| matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E')) {
+        number.push(c);
+      }
+      number
+        .parse::<f64>()
+        .map(JsonValue::Number)
+        .map_err(|_| format!("Invalid JSON number '{number}'"))
+    }
+    Some(c) => Err(format!("Unexpected character '{c}' in JSON data")),
+    None => Err(String::from("Unexpected end of JSON data")),
+  }
+}
+
+pub fn parse_from_string(str: &str) -> Result<JsonValue, String> {
+  let mut it = str.trim_start_matches('\u{feff}').chars().peekable();
+  let value = read_value(&mut it)?;
+  skip_whitespace(&mut it);
+  match it.next() {
+    Some(c) => Err(format!("Unexpected character '{c}' after JSON data")),
+    None => Ok(value),
+  }
+}
+
+#[cfg(test)]
+mod json_parser_tests {
+  use super::*;
+
+  fn test_json() -> JsonValue {
+    JsonValue::Object(vec![
+      (
+        String::from("name"),
+        JsonValue::String(String::from("Waltz in E♭ \"Major\"")),
+      ),
+      (
+        String::from("values"),
+        JsonValue::Array(vec![
+          JsonValue::Number(1.0),
+          JsonValue::Number(-2.5),
+          JsonValue::Bool(true),
+          JsonValue::Null,
+        ]),
+      ),
+      (String::from("empty"), JsonValue::Object(vec![])),
+    ])
+  }
+
+  #[test]
+  fn serialize_valid_json() {
+    let test_json_str = "{
+  \"name\": \"Waltz in E♭ \\\"Major\\\"\",
+  \"values\": [
+    1,
+    -2.5,
+    true,
+    null
+  ],
+  \"empty\": {}
+}";
+    assert_eq!(parse_to_string(&test_json(), 0), test_json_str);
+    assert_eq!(
+      parse_to_string(&test_json(), -1),
+      "{\"name\":\"Waltz in E♭ \\\"Major\\\"\",\"values\":[1,-2.5,true,null],\"empty\":{}}"
+    );
+  }
+
+  #[test]
+  fn deserialize_valid_json() {
+    let result = parse_from_string(
+      " { \"name\" : \"Waltz in E\\u266d \\\"Major\\\"\", \"values\": [1, -2.5e0, true, null], \"empty\": {} } ",
+    );
+    assert_eq!(result, Ok(test_json()));
+    assert_eq!(
+      parse_from_string("\"\\ud834\\udd1e\""),
+      Ok(JsonValue::String(String::from("𝄞")))
+    );
+  }
+
+  #[test]
+  fn deserialize_invalid_json() {
+    assert!(parse_from_string("{\"key\" 1}").is_err());
+    assert!(parse_from_string("[1, 2").is_err());
+    assert!(parse_from_string("\"unterminated").is_err());
+    assert!(parse_from_string("tru").is_err());
+    assert!(parse_from_string("{} {}").is_err());
+  }
+}
@@ -1,5 +1,5 @@
 use crate::elements::{ScorePartwise, ScoreTimewise};
-use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
 use musicxml_internal::{bytes_to_string, ElementDeserializer, ElementSerializer, XmlElement};
 
 #[cfg(feature = "std")]
@@ -8,9 +8,23 @@ extern crate std;
 #[cfg(feature = "std")]
 use {alloc::string::ToString, std::io::Write};
 
-mod xml_parser;
+pub(crate) mod json_parser;
+mod write_options;
+pub(crate) mod xml_parser;
 mod zip_parser;
 
+pub use write_options::{
+  AttributeOrder, Doctype, LineEnding, SelfClosingStyle, WriteOptions, DEFAULT_MUSICXML_VERSION,
+};
+
+/// Contains an [MxlArchive][mxl::MxlArchive] type for reading every file stored in a compressed `.mxl` archive,
+/// including alternate rootfiles and embedded images, and an [MxlWriter][mxl::MxlWriter] builder for writing them.
+pub mod mxl;
+
+/// Contains functions for streaming scores directly into an [XmlSink][stream::XmlSink], such as a file or a
+/// formatter, without first building the complete document in memory.
+pub mod stream;
+
 #[inline]
 fn is_mxl_data(data: Option<&[u8]>) -> bool {
   if let Some(data) = data {
@@ -21,33 +35,12 @@ fn is_mxl_data(data: Option<&[u8]>) -> bool {
 }
 
 fn get_musicxml_contents(data: Vec<u8>) -> Result<String, String> {
-  let mut contents = String::new();
   if is_mxl_data(data.get(0..4)) {
-    let mut xml_path: Option<String> = None;
-    let mut mxl_data = zip_parser::ZipData::new();
-    mxl_data.content = data;
-    let archive = zip_parser::ZipArchive::new(&mut mxl_data);
-    for file_name in archive.iter() {
-      if file_name == "META-INF/container.xml" {
-        let container = xml_parser::parse_from_string(archive.read_file_to_string(file_name)?.as_str())?;
-        xml_path = container
-          .elements
-          .iter()
-          .find(|&el| el.name == "rootfiles")
-          .and_then(|el| el.elements.iter().find(|&el| el.name == "rootfile"))
-          .and_then(|el| el.attributes.iter().find(|&attr| attr.0 == "full-path"))
-          .map(|attr| attr.1.clone());
-      }
-    }
-    if let Some(full_path) = &xml_path {
-      contents = archive.read_file_to_string(full_path)?;
-    } else {
-      Err(String::from("Cannot find MusicXML file in compressed archive"))?;
-    }
+    let archive = mxl::MxlArchive::from_data(data)?;
+    archive.read_entry_to_string(&archive.default_rootfile().full_path)
   } else {
-    contents = bytes_to_string(&data)?;
+    bytes_to_string(&data)
   }
-  Ok(contents)
 }
 
 #[cfg(feature = "std")]
@@ -62,46 +55,41 @@ fn get_musicxml_contents_from_file(_path: &str) -> Result<String, String> {
   ))
 }
 
-fn put_musicxml_contents(xml: &XmlElement, pretty_print: bool) -> Vec<u8> {
+fn put_musicxml_contents(xml: &XmlElement, options: &WriteOptions) -> Vec<u8> {
   let mut buffer = Vec::new();
-  buffer.extend_from_slice(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
-  if xml.name == "score-partwise" {
-    buffer.extend_from_slice(b"<!DOCTYPE score-partwise PUBLIC \"-//Recordare//DTD MusicXML 3.0 Partwise//EN\" \"http://www.musicxml.org/dtds/partwise.dtd\">\n");
-  } else if xml.name == "score-timewise" {
-    buffer.extend_from_slice(b"<!DOCTYPE score-timewise PUBLIC \"-//Recordare//DTD MusicXML 3.0 Timewise//EN\" \"http://www.musicxml.org/dtds/timewise.dtd\">\n");
-  }
-  buffer.extend_from_slice(xml_parser::parse_to_string(xml, if pretty_print { 0 } else { -1 }).as_ref());
+  // Writing into a memory buffer cannot fail
+  let _ = xml_parser::write_document(&mut buffer, xml, options);
   buffer
 }
 
-fn write_musicxml_contents(xml: &XmlElement, compressed: bool, pretty_print: bool) -> Vec<u8> {
+fn write_musicxml_contents(xml: &XmlElement, compressed: bool, options: &WriteOptions) -> Vec<u8> {
   if compressed {
-    let mut archiver = zip_parser::ZipArchiver::new();
-    archiver.start_file("META-INF/container.xml");
-    archiver.write_data(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<container>\n  <rootfiles>\n    <rootfile full-path=\"score.musicxml\" media-type=\"application/vnd.recordare.musicxml+xml\"/>\n  </rootfiles>\n</container>");
-    archiver.start_file("score.musicxml");
-    archiver.write_data(put_musicxml_contents(xml, pretty_print).as_slice());
-    archiver.finish()
+    // The default writer has no user-specified paths or timestamp, so writing cannot fail
+    mxl::MxlWriter::new().write_xml(xml, options).unwrap_or_default()
   } else {
-    put_musicxml_contents(xml, pretty_print)
+    put_musicxml_contents(xml, options)
   }
 }
 
+#[cfg(feature = "std")]
+fn create_file(path: &str) -> Result<std::fs::File, String> {
+  std::fs::OpenOptions::new()
+    .write(true)
+    .create(true)
+    .truncate(true)
+    .open(path)
+    .map_err(|e| e.to_string())
+}
+
 #[cfg(feature = "std")]
 fn write_musicxml_contents_to_file(
   path: &str,
   xml: &XmlElement,
   compressed: bool,
-  pretty_print: bool,
+  options: &WriteOptions,
 ) -> Result<(), String> {
-  let mut file = std::fs::OpenOptions::new()
-    .write(true)
-    .create(true)
-    .truncate(true)
-    .open(path)
-    .map_err(|e| e.to_string())?;
-  file
-    .write_all(&write_musicxml_contents(xml, compressed, pretty_print))
+  create_file(path)?
+    .write_all(&write_musicxml_contents(xml, compressed, options))
     .map_err(|e| e.to_string())
 }
 
@@ -110,20 +98,40 @@ fn write_musicxml_contents_to_file(
   _path: &str,
   _xml: &XmlElement,
   _compressed: bool,
-  _pretty_print: bool,
+  _options: &WriteOptions,
 ) -> Result<(), String> {
   Err(String::from(
     "Writing MusicXML files is not supported in a 'no_std' environment",
   ))
 }
 
-fn convert_xml_partwise_to_timewise(xml: XmlElement) -> Result<XmlElement, String> {
+#[cfg(feature = "std")]
+fn stream_musicxml_contents_to_file(
+  path: &str,
+  write_contents: impl FnOnce(&mut dyn stream::XmlSink) -> Result<(), String>,
+) -> Result<(), String> {
+  let mut sink = stream::IoSink(std::io::BufWriter::new(create_file(path)?));
+  write_contents(&mut sink)?;
+  sink.0.flush().map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "std"))]
+fn stream_musicxml_contents_to_file(
+  _path: &str,
+  _write_contents: impl FnOnce(&mut dyn stream::XmlSink) -> Result<(), String>,
+) -> Result<(), String> {
+  Err(String::from(
+    "Writing MusicXML files is not supported in a 'no_std' environment",
+  ))
+}
+
+pub(crate) fn convert_xml_partwise_to_timewise(xml: XmlElement<'_>) -> Result<XmlElement<'_>, String> {
   if xml.name == "score-timewise" {
     Ok(xml)
   } else if xml.name == "score-partwise" {
     let mut measures: BTreeMap<usize, XmlElement> = BTreeMap::new();
     let mut converted_xml = XmlElement {
-      name: String::from("score-timewise"),
+      name: Cow::Borrowed("score-timewise"),
       attributes: xml.attributes,
       elements: Vec::new(),
       text: xml.text,
@@ -159,9 +167,7 @@ fn convert_xml_partwise_to_timewise(xml: XmlElement) -> Result<XmlElement, Strin
         converted_xml.elements.push(element);
       }
     }
-    measures
-      .into_values()
-      .for_each(|measure| converted_xml.elements.push(measure));
+    converted_xml.elements.extend(measures.into_values());
     Ok(converted_xml)
   } else {
     Err(String::from(
@@ -170,13 +176,13 @@ fn convert_xml_partwise_to_timewise(xml: XmlElement) -> Result<XmlElement, Strin
   }
 }
 
-fn convert_xml_timewise_to_partwise(xml: XmlElement) -> Result<XmlElement, String> {
+pub(crate) fn convert_xml_timewise_to_partwise(xml: XmlElement<'_>) -> Result<XmlElement<'_>, String> {
   if xml.name == "score-partwise" {
     Ok(xml)
   } else if xml.name == "score-timewise" {
-    let mut parts: BTreeMap<String, XmlElement> = BTreeMap::new();
+    let mut parts: BTreeMap<Cow<str>, XmlElement> = BTreeMap::new();
     let mut converted_xml = XmlElement {
-      name: String::from("score-partwise"),
+      name: Cow::Borrowed("score-partwise"),
       attributes: xml.attributes,
       elements: Vec::new(),
       text: xml.text,
@@ -211,7 +217,7 @@ fn convert_xml_timewise_to_partwise(xml: XmlElement) -> Result<XmlElement, Strin
         converted_xml.elements.push(element);
       }
     }
-    parts.into_values().for_each(|part| converted_xml.elements.push(part));
+    converted_xml.elements.extend(parts.into_values());
     Ok(converted_xml)
   } else {
     Err(String::from(
@@ -237,9 +243,13 @@ pub fn parse_from_xml_str<T: ElementDeserializer>(str: &str) -> Result<T, String
 ///
 /// This function can be used to convert any MusicXML datatype or element into a string. It is not required that the
 /// element being parsed be a top-level element such as `<score-partwise>` or `<score-timewise>`.
-pub fn parse_to_xml_str<T: ElementSerializer>(data: &T, pretty_print: bool) -> String {
+///
+/// The `options` parameter specifies how the string is formatted and accepts either a [WriteOptions] value or a
+/// `bool` indicating whether the string should be written with indentation and newlines. No XML declaration or
+/// DOCTYPE is written.
+pub fn parse_to_xml_str<T: ElementSerializer>(data: &T, options: impl Into<WriteOptions>) -> String {
   let xml = T::serialize(data);
-  xml_parser::parse_to_string(&xml, if pretty_print { 0 } else { -1 })
+  xml_parser::write_to_string(&xml, &options.into())
 }
 
 /// Parses the contents of the specified MusicXML file into a [ScorePartwise] element.
@@ -302,7 +312,8 @@ pub fn parse_score_timewise_from_data(data: Vec<u8>) -> Result<ScoreTimewise, St
 /// If the `write_timewise` parameter is set to `true`, the MusicXML file will be converted into a timewise format and
 /// written as a `<score-timewise>` element.
 ///
-/// The `pretty_print` parameter specifies whether the MusicXML file should be written with indentation and newlines.
+/// The `options` parameter specifies how the MusicXML file is formatted and accepts either a [WriteOptions] value
+/// or a `bool` indicating whether the file should be written with indentation and newlines.
 ///
 /// # Errors
 ///
@@ -312,15 +323,17 @@ pub fn parse_score_partwise_to_file(
   path: &str,
   score: &ScorePartwise,
   compressed: bool,
-  pretty_print: bool,
+  options: impl Into<WriteOptions>,
   write_timewise: bool,
 ) -> Result<(), String> {
-  let xml = ScorePartwise::serialize(score);
+  let options = options.into();
   if write_timewise {
-    convert_xml_partwise_to_timewise(xml)
-      .and_then(|xml| write_musicxml_contents_to_file(path, &xml, compressed, pretty_print))
+    convert_xml_partwise_to_timewise(ScorePartwise::serialize(score))
+      .and_then(|xml| write_musicxml_contents_to_file(path, &xml, compressed, &options))
   } else {
-    write_musicxml_contents_to_file(path, &xml, compressed, pretty_print)
+    stream_musicxml_contents_to_file(path, |sink| {
+      stream::write_partwise(sink, score, compressed, options).map(|_| ())
+    })
   }
 }
 
@@ -330,7 +343,8 @@ pub fn parse_score_partwise_to_file(
 /// If the `write_partwise` parameter is set to `true`, the MusicXML file will be converted into a partwise format and
 /// written as a `<score-partwise>` element.
 ///
-/// The `pretty_print` parameter specifies whether the MusicXML file should be written with indentation and newlines.
+/// The `options` parameter specifies how the MusicXML file is formatted and accepts either a [WriteOptions] value
+/// or a `bool` indicating whether the file should be written with indentation and newlines.
 ///
 /// # Errors
 ///
@@ -340,15 +354,17 @@ pub fn parse_score_timewise_to_file(
   path: &str,
   score: &ScoreTimewise,
   compressed: bool,
-  pretty_print: bool,
+  options: impl Into<WriteOptions>,
   write_partwise: bool,
 ) -> Result<(), String> {
-  let xml = ScoreTimewise::serialize(score);
+  let options = options.into();
   if write_partwise {
-    convert_xml_timewise_to_partwise(xml)
-      .and_then(|xml| write_musicxml_contents_to_file(path, &xml, compressed, pretty_print))
+    convert_xml_timewise_to_partwise(ScoreTimewise::serialize(score))
+      .and_then(|xml| write_musicxml_contents_to_file(path, &xml, compressed, &options))
   } else {
-    write_musicxml_contents_to_file(path, &xml, compressed, pretty_print)
+    stream_musicxml_contents_to_file(path, |sink| {
+      stream::write_timewise(sink, score, compressed, options).map(|_| ())
+    })
   }
 }
 
@@ -358,8 +374,8 @@ pub fn parse_score_timewise_to_file(
 /// If the `write_timewise` parameter is set to `true`, the MusicXML contents will be converted into a timewise
 /// format and written as a `<score-timewise>` element.
 ///
-/// The `pretty_print` parameter specifies whether the MusicXML contents should be written with indentation
-/// and newlines.
+/// The `options` parameter specifies how the MusicXML contents are formatted and accepts either a [WriteOptions]
+/// value or a `bool` indicating whether the contents should be written with indentation and newlines.
 ///
 /// # Errors
 ///
@@ -367,14 +383,14 @@ pub fn parse_score_timewise_to_file(
 pub fn parse_score_partwise_to_data(
   score: &ScorePartwise,
   compressed: bool,
-  pretty_print: bool,
+  options: impl Into<WriteOptions>,
   write_timewise: bool,
 ) -> Result<Vec<u8>, String> {
-  let xml = ScorePartwise::serialize(score);
+  let (xml, options) = (ScorePartwise::serialize(score), options.into());
   if write_timewise {
-    convert_xml_partwise_to_timewise(xml).map(|xml| write_musicxml_contents(&xml, compressed, pretty_print))
+    convert_xml_partwise_to_timewise(xml).map(|xml| write_musicxml_contents(&xml, compressed, &options))
   } else {
-    Ok(write_musicxml_contents(&xml, compressed, pretty_print))
+    Ok(write_musicxml_contents(&xml, compressed, &options))
   }
 }
 
@@ -384,8 +400,8 @@ pub fn parse_score_partwise_to_data(
 /// If the `write_partwise` parameter is set to `true`, the MusicXML contents will be converted into a partwise
 /// format and written as a `<score-partwise>` element.
 ///
-/// The `pretty_print` parameter specifies whether the MusicXML contents should be written with indentation
-/// and newlines.
+/// The `options` parameter specifies how the MusicXML contents are formatted and accepts either a [WriteOptions]
+/// value or a `bool` indicating whether the contents should be written with indentation and newlines.
 ///
 /// # Errors
 ///
@@ -393,13 +409,13 @@ pub fn parse_score_partwise_to_data(
 pub fn parse_score_timewise_to_data(
   score: &ScoreTimewise,
   compressed: bool,
-  pretty_print: bool,
+  options: impl Into<WriteOptions>,
   write_partwise: bool,
 ) -> Result<Vec<u8>, String> {
-  let xml = ScoreTimewise::serialize(score);
+  let (xml, options) = (ScoreTimewise::serialize(score), options.into());
   if write_partwise {
-    convert_xml_timewise_to_partwise(xml).map(|xml| write_musicxml_contents(&xml, compressed, pretty_print))
+    convert_xml_timewise_to_partwise(xml).map(|xml| write_musicxml_contents(&xml, compressed, &options))
   } else {
-    Ok(write_musicxml_contents(&xml, compressed, pretty_print))
+    Ok(write_musicxml_contents(&xml, compressed, &options))
   }
 }
@@ -0,0 +1,871 @@
+//! Typed access to the complete contents of a compressed MXL archive.
+//!
+//! An MXL file is a ZIP archive whose `META-INF/container.xml` file lists one or more rootfiles. The first rootfile
+//! is the main MusicXML score, while any additional rootfiles are alternate renditions of the same score, such as
+//! PDF or MIDI files. The archive may also contain other files, such as the images referenced by
+//! [Image][crate::elements::Image] and [CreditImage][crate::elements::CreditImage] elements or a `sounds.xml` file,
+//! all of which can be read using an [MxlArchive][crate::parser::mxl::MxlArchive].
+
+use super::stream::XmlSink;
+use super::{
+  convert_xml_partwise_to_timewise, convert_xml_timewise_to_partwise, is_mxl_data, put_musicxml_contents, stream,
+  xml_parser, zip_parser, WriteOptions,
+};
+use crate::elements::{
+  CreditSubcontents, DirectionTypeContents, MeasureElement, PartElement, ScorePartwise, ScoreTimewise,
+};
+use crate::util::{xml_escape, xml_unescape};
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use musicxml_internal::{bytes_to_string, ElementDeserializer, ElementSerializer, XmlElement};
+
+pub use super::zip_parser::ArchiveError;
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+
+/// Path of the container file that lists the rootfiles of an MXL archive.
+pub const CONTAINER_PATH: &str = "META-INF/container.xml";
+
+/// Path of the uncompressed file that identifies an archive as an MXL file.
+pub const MIMETYPE_PATH: &str = "mimetype";
+
+/// Media type of an uncompressed MusicXML file, which is assumed for rootfiles that do not specify one.
+pub const MUSICXML_MEDIA_TYPE: &str = "application/vnd.recordare.musicxml+xml";
+
+/// Media type of a compressed MXL file, which is stored in its `mimetype` file.
+pub const MXL_MEDIA_TYPE: &str = "application/vnd.recordare.musicxml";
+
+/// A file listed in the `<rootfiles>` element of an MXL container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rootfile {
+  /// Path of the file within the archive.
+  pub full_path: String,
+  /// Media type of the file, which defaults to [MUSICXML_MEDIA_TYPE] if unspecified.
+  pub media_type: String,
+}
+
+impl Rootfile {
+  /// Returns whether the rootfile contains uncompressed MusicXML data.
+  #[must_use]
+  pub fn is_musicxml(&self) -> bool {
+    self.media_type == MUSICXML_MEDIA_TYPE || self.media_type == MXL_MEDIA_TYPE
+  }
+}
+
+/// A compressed MXL archive along with the list of rootfiles from its container.
+///
+/// The first rootfile is the default score, which is what the top-level [read_score_partwise][crate::read_score_partwise]
+/// function returns for an `.mxl` file. Any other rootfile or file in the archive can be read from the archive
+/// directly.
+pub struct MxlArchive {
+  archive: zip_parser::ZipArchive,
+  rootfiles: Vec<Rootfile>,
+}
+
+impl MxlArchive {
+  /// Parses the contents of an MXL archive that has already been read into memory.
+  ///
+  /// # Errors
+  ///
+  /// If the data is not a valid ZIP archive or does not contain a valid `META-INF/container.xml` file listing at
+  /// least one rootfile, an [ArchiveError] describing the problem will be returned.
+  pub fn from_data(data: Vec<u8>) -> Result<Self, ArchiveError> {
+    if !is_mxl_data(data.get(0..4)) {
+      return Err(ArchiveError::NotAnArchive);
+    }
+    let archive = zip_parser::ZipArchive::new(data)?;
+    if !archive.contains(CONTAINER_PATH) {
+      return Err(ArchiveError::InvalidContainer(format!(
+        "Cannot find \"{CONTAINER_PATH}\""
+      )));
+    }
+    let contents = archive
+      .read_file_to_string(CONTAINER_PATH)
+      .map_err(ArchiveError::InvalidContainer)?;
+    let container = xml_parser::parse_from_string(&contents).map_err(ArchiveError::InvalidContainer)?;
+    let rootfiles: Vec<Rootfile> = container
+      .elements
+      .iter()
+      .filter(|el| el.name == "rootfiles")
+      .flat_map(|el| el.elements.iter().filter(|el| el.name == "rootfile"))
+      .filter_map(|el| {
+        let attribute = |name: &str| {
+          el.attributes
+            .iter()
+            .find(|attr| attr.0 == name)
+            .map(|attr| xml_unescape(&attr.1))
+        };
+        attribute("full-path").map(|full_path| Rootfile {
+          full_path,
+          media_type: attribute("media-type").unwrap_or(String::from(MUSICXML_MEDIA_TYPE)),
+        })
+      })
+      .collect();
+    if rootfiles.is_empty() {
+      return Err(ArchiveError::InvalidContainer(String::from("No rootfiles are listed")));
+    }
+    Ok(Self { archive, rootfiles })
+  }
+
+  /// Reads and parses the MXL archive at the specified path.
+  ///
+  /// # Errors
+  ///
+  /// If the file cannot be read or does not represent a valid MXL archive, an [ArchiveError] describing the problem
+  /// will be returned.
+  #[cfg(feature = "std")]
+  pub fn open(path: &str) -> Result<Self, ArchiveError> {
+    Self::from_data(std::fs::read(path).map_err(|e| ArchiveError::Io(e.to_string()))?)
+  }
+
+  /// Returns all rootfiles listed in the archive container, starting with the default score.
+  #[must_use]
+  pub fn rootfiles(&self) -> &[Rootfile] {
+    &self.rootfiles
+  }
+
+  /// Returns the default rootfile, which is the first rootfile listed in the archive container.
+  #[must_use]
+  pub fn default_rootfile(&self) -> &Rootfile {
+    &self.rootfiles[0]
+  }
+
+  /// Returns the paths of all files stored in the archive.
+  pub fn entries(&self) -> impl Iterator<Item = &str> {
+    self.archive.iter().map(String::as_str)
+  }
+
+  /// Returns whether a file with the specified path exists in the archive.
+  #[must_use]
+  pub fn contains(&self, path: &str) -> bool {
+    self.archive.contains(path)
+  }
+
+  /// Reads the decompressed contents of the file with the specified path.
+  ///
+  /// # Errors
+  ///
+  /// If the file does not exist, cannot be decompressed, or does not match its recorded size and checksum, an
+  /// [ArchiveError] describing the problem will be returned.
+  pub fn read_entry(&self, path: &str) -> Result<Vec<u8>, ArchiveError> {
+    self.archive.read_file(path)
+  }
+
+  /// Reads the decompressed contents of the file with the specified path as a string.
+  ///
+  /// The character encoding is detected from any byte order mark or XML declaration in the file, so UTF-8, UTF-16,
+  /// ISO-8859-1 and Windows-1252 text is supported, and the file is decoded as UTF-8 if it specifies neither.
+  ///
+  /// # Errors
+  ///
+  /// If the file does not exist, cannot be decompressed, or is not valid text in a supported encoding, an error
+  /// message will be returned.
+  pub fn read_entry_to_string(&self, path: &str) -> Result<String, String> {
+    bytes_to_string(&self.archive.read_file(path)?)
+  }
+
+  /// Resolves a URI, such as the `source` attribute of an [Image][crate::elements::Image] or
+  /// [CreditImage][crate::elements::CreditImage] element, to the path of a file in the archive.
+  ///
+  /// Relative URIs are resolved against the directory containing the default rootfile, and URIs that start with a
+  /// `/` are resolved against the root of the archive. If the URI refers to an external resource or does not
+  /// resolve to a file in the archive, `None` is returned.
+  #[must_use]
+  pub fn resolve_uri(&self, uri: &str) -> Option<String> {
+    if uri.contains("://") || uri.starts_with("data:") {
+      return None;
+    }
+    let uri = uri.split(['?', '#']).next().unwrap_or_default();
+    let mut segments: Vec<String> = Vec::new();
+    let relative = if let Some(absolute) = uri.strip_prefix('/') {
+      absolute
+    } else {
+      let base = &self.default_rootfile().full_path;
+      if let Some((directory, _)) = base.rsplit_once('/') {
+        segments.extend(directory.split('/').map(String::from));
+      }
+      uri
+    };
+    for segment in relative.split('/') {
+      match segment {
+        "" | "." => {}
+        ".." => {
+          segments.pop()?;
+        }
+        segment => segments.push(percent_decode(segment)),
+      }
+    }
+    let path = segments.join("/");
+    self.contains(&path).then_some(path)
+  }
+
+  /// Reads the contents of the archive file referenced by the specified URI, as resolved by
+  /// [resolve_uri][MxlArchive::resolve_uri].
+  ///
+  /// # Errors
+  ///
+  /// If the URI does not resolve to a file in the archive or the file cannot be read, an [ArchiveError] describing
+  /// the problem will be returned.
+  pub fn read_uri(&self, uri: &str) -> Result<Vec<u8>, ArchiveError> {
+    let path = self
+      .resolve_uri(uri)
+      .ok_or_else(|| ArchiveError::FileNotFound(String::from(uri)))?;
+    self.read_entry(&path)
+  }
+
+  /// Returns the archive paths of all images referenced by the [CreditImage][crate::elements::CreditImage] and
+  /// [Image][crate::elements::Image] elements of the specified score, in document order and without duplicates.
+  ///
+  /// Image sources that cannot be resolved to a file in the archive are skipped.
+  #[must_use]
+  pub fn image_entries(&self, score: &ScorePartwise) -> Vec<String> {
+    let credit_sources = score
+      .content
+      .credit
+      .iter()
+      .filter_map(|credit| match &credit.content.credit {
+        CreditSubcontents::Image(image) => image.credit_image.attributes.source.as_ref().map(|source| &source.0),
+        CreditSubcontents::Text(_) => None,
+      });
+    let direction_sources = score
+      .content
+      .part
+      .iter()
+      .flat_map(|part| part.content.iter())
+      .filter_map(|element| match element {
+        PartElement::Measure(measure) => Some(measure.content.iter()),
+        _ => None,
+      })
+      .flatten()
+      .filter_map(|element| match element {
+        MeasureElement::Direction(direction) => Some(direction.content.direction_type.iter()),
+        _ => None,
+      })
+      .flatten()
+      .filter_map(|direction_type| match &direction_type.content {
+        DirectionTypeContents::Image(image) => Some(&image.attributes.source.0),
+        _ => None,
+      });
+    let mut entries: Vec<String> = Vec::new();
+    for path in credit_sources
+      .chain(direction_sources)
+      .filter_map(|uri| self.resolve_uri(uri))
+    {
+      if !entries.contains(&path) {
+        entries.push(path);
+      }
+    }
+    entries
+  }
+
+  /// Parses the default rootfile of the archive into a [ScorePartwise] element.
+  ///
+  /// # Errors
+  ///
+  /// If the default rootfile cannot be read or is not a valid MusicXML file, an error message will be returned.
+  pub fn read_score_partwise(&self) -> Result<ScorePartwise, String> {
+    self.read_rootfile_partwise(&self.default_rootfile().full_path)
+  }
+
+  /// Parses the default rootfile of the archive into a [ScoreTimewise] element.
+  ///
+  /// # Errors
+  ///
+  /// If the default rootfile cannot be read or is not a valid MusicXML file, an error message will be returned.
+  pub fn read_score_timewise(&self) -> Result<ScoreTimewise, String> {
+    self.read_rootfile_timewise(&self.default_rootfile().full_path)
+  }
+
+  /// Parses the MusicXML file at the specified path within the archive into a [ScorePartwise] element.
+  ///
+  /// # Errors
+  ///
+  /// If the file cannot be read or is not a valid MusicXML file, an error message will be returned.
+  pub fn read_rootfile_partwise(&self, path: &str) -> Result<ScorePartwise, String> {
+    let contents = self.read_entry_to_string(path)?;
+    let xml = xml_parser::parse_from_string(&contents)?;
+    convert_xml_timewise_to_partwise(xml).and_then(|xml| ScorePartwise::deserialize(&xml))
+  }
+
+  /// Parses the MusicXML file at the specified path within the archive into a [ScoreTimewise] element.
+  ///
+  /// # Errors
+  ///
+  /// If the file cannot be read or is not a valid MusicXML file, an error message will be returned.
+  pub fn read_rootfile_timewise(&self, path: &str) -> Result<ScoreTimewise, String> {
+    let contents = self.read_entry_to_string(path)?;
+    let xml = xml_parser::parse_from_string(&contents)?;
+    convert_xml_partwise_to_timewise(xml).and_then(|xml| ScoreTimewise::deserialize(&xml))
+  }
+}
+
+/// Compression method used to store a file in an MXL archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+  /// The file is stored without compression.
+  Stored,
+  /// The file is compressed using DEFLATE at the specified level, from `0` (fastest) to `10` (smallest).
+  Deflated(u8),
+}
+
+impl Default for Compression {
+  fn default() -> Self {
+    Compression::Deflated(10)
+  }
+}
+
+impl Compression {
+  fn level(self) -> Option<u8> {
+    match self {
+      Compression::Stored => None,
+      Compression::Deflated(level) => Some(level.min(10)),
+    }
+  }
+}
+
+/// Modification timestamp written for every file in an MXL archive.
+///
+/// ZIP archives store timestamps in MS-DOS format, which can only represent years from 1980 to 2107 and seconds at
+/// a resolution of two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timestamp {
+  /// Year, from 1980 to 2107.
+  pub year: u16,
+  /// Month of the year, from 1 to 12.
+  pub month: u8,
+  /// Day of the month, from 1 to 31.
+  pub day: u8,
+  /// Hour of the day, from 0 to 23.
+  pub hour: u8,
+  /// Minute of the hour, from 0 to 59.
+  pub minute: u8,
+  /// Second of the minute, from 0 to 59.
+  pub second: u8,
+}
+
+impl Timestamp {
+  fn validate(self) -> Result<(), String> {
+    if !(1980..=2107).contains(&self.year)
+      || !(1..=12).contains(&self.month)
+      || !(1..=31).contains(&self.day)
+      || self.hour > 23
+      || self.minute > 59
+      || self.second > 59
+    {
+      Err(format!(
+        "Timestamp {self:?} cannot be represented in a compressed archive"
+      ))
+    } else {
+      Ok(())
+    }
+  }
+}
+
+/// A file to be written into an MXL archive alongside the main score.
+#[derive(Debug, Clone)]
+struct MxlEntry {
+  path: String,
+  media_type: Option<String>,
+  data: Vec<u8>,
+  compression: Compression,
+}
+
+/// Builder for writing compressed MXL archives that contain more than just the main score.
+///
+/// The archive is written according to the MusicXML 4.0 specification: an uncompressed `mimetype` file comes first,
+/// followed by the `META-INF/container.xml` file, the main score, any additional rootfiles, and finally any other
+/// assets, such as the images referenced by [CreditImage][crate::elements::CreditImage] elements.
+///
+/// ```rust
+/// use musicxml::parser::mxl::{Compression, MxlWriter, Timestamp};
+/// # let score: musicxml::elements::ScorePartwise = musicxml::parser::parse_from_xml_str(
+/// #   "<score-partwise><part-list/></score-partwise>").unwrap();
+///
+/// let data = MxlWriter::new()
+///   .with_root_path("music/score.musicxml")
+///   .with_rootfile("score.pdf", "application/pdf", b"%PDF-1.4".to_vec(), Compression::Stored)
+///   .with_asset("music/logo.png", b"PNG".to_vec(), Compression::Stored)
+///   .with_timestamp(Timestamp { year: 2024, month: 1, day: 1, hour: 0, minute: 0, second: 0 })
+///   .write_partwise(&score, true)
+///   .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MxlWriter {
+  root_path: String,
+  root_compression: Compression,
+  entries: Vec<MxlEntry>,
+  timestamp: Option<Timestamp>,
+}
+
+impl Default for MxlWriter {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl MxlWriter {
+  /// Creates a writer that stores the main score as a compressed `score.musicxml` file, timestamped with the
+  /// current time if available.
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      root_path: String::from("score.musicxml"),
+      root_compression: Compression::default(),
+      entries: Vec::new(),
+      timestamp: None,
+    }
+  }
+
+  /// Sets the path of the main score within the archive.
+  #[must_use]
+  pub fn with_root_path(mut self, path: &str) -> Self {
+    self.root_path = String::from(path);
+    self
+  }
+
+  /// Sets the compression method used for the main score and the `META-INF/container.xml` file.
+  #[must_use]
+  pub fn with_root_compression(mut self, compression: Compression) -> Self {
+    self.root_compression = compression;
+    self
+  }
+
+  /// Adds an alternate rootfile, such as a PDF rendering of the score, to be listed in the archive container.
+  #[must_use]
+  pub fn with_rootfile(mut self, path: &str, media_type: &str, data: Vec<u8>, compression: Compression) -> Self {
+    self.entries.push(MxlEntry {
+      path: String::from(path),
+      media_type: Some(String::from(media_type)),
+      data,
+      compression,
+    });
+    self
+  }
+
+  /// Adds a file, such as an image, that is stored in the archive without being listed in its container.
+  #[must_use]
+  pub fn with_asset(mut self, path: &str, data: Vec<u8>, compression: Compression) -> Self {
+    self.entries.push(MxlEntry {
+      path: String::from(path),
+      media_type: None,
+      data,
+      compression,
+    });
+    self
+  }
+
+  /// Sets a fixed modification timestamp for every file in the archive so that its output is reproducible.
+  #[must_use]
+  pub fn with_timestamp(mut self, timestamp: Timestamp) -> Self {
+    self.timestamp = Some(timestamp);
+    self
+  }
+
+  /// Writes the specified [ScorePartwise] element as the main score of a new MXL archive.
+  ///
+  /// The `options` parameter specifies how the score is formatted and accepts either a [WriteOptions] value or a
+  /// `bool` indicating whether the score should be written with indentation and newlines.
+  ///
+  /// # Errors
+  ///
+  /// If two files share the same path, a file uses a reserved path, or the timestamp cannot be represented in a
+  /// ZIP archive, an error message will be returned.
+  pub fn write_partwise(&self, score: &ScorePartwise, options: impl Into<WriteOptions>) -> Result<Vec<u8>, String> {
+    self.write_xml(&ScorePartwise::serialize(score), &options.into())
+  }
+
+  /// Writes the specified [ScoreTimewise] element as the main score of a new MXL archive.
+  ///
+  /// The `options` parameter specifies how the score is formatted and accepts either a [WriteOptions] value or a
+  /// `bool` indicating whether the score should be written with indentation and newlines.
+  ///
+  /// # Errors
+  ///
+  /// If two files share the same path, a file uses a reserved path, or the timestamp cannot be represented in a
+  /// ZIP archive, an error message will be returned.
+  pub fn write_timewise(&self, score: &ScoreTimewise, options: impl Into<WriteOptions>) -> Result<Vec<u8>, String> {
+    self.write_xml(&ScoreTimewise::serialize(score), &options.into())
+  }
+
+  /// Streams the specified [ScorePartwise] element as the main score of a new MXL archive directly into a sink.
+  ///
+  /// Unlike [MxlWriter::write_partwise], the score is compressed while it is being serialized, so the complete
+  /// archive is never held in memory. The `options` parameter accepts either a [WriteOptions] value or a `bool`
+  /// indicating whether the score should be written with indentation and newlines.
+  ///
+  /// # Errors
+  ///
+  /// If two files share the same path, a file uses a reserved path, the timestamp cannot be represented in a ZIP
+  /// archive, or the sink cannot be written, an error message will be returned.
+  pub fn stream_partwise<S: XmlSink>(
+    &self,
+    sink: S,
+    score: &ScorePartwise,
+    options: impl Into<WriteOptions>,
+  ) -> Result<S, String> {
+    let options = options.into();
+    self.stream(sink, |sink| stream::write_partwise_contents(sink, score, &options))
+  }
+
+  /// Streams the specified [ScoreTimewise] element as the main score of a new MXL archive directly into a sink.
+  ///
+  /// Unlike [MxlWriter::write_timewise], the score is compressed while it is being serialized, so the complete
+  /// archive is never held in memory. The `options` parameter accepts either a [WriteOptions] value or a `bool`
+  /// indicating whether the score should be written with indentation and newlines.
+  ///
+  /// # Errors
+  ///
+  /// If two files share the same path, a file uses a reserved path, the timestamp cannot be represented in a ZIP
+  /// archive, or the sink cannot be written, an error message will be returned.
+  pub fn stream_timewise<S: XmlSink>(
+    &self,
+    sink: S,
+    score: &ScoreTimewise,
+    options: impl Into<WriteOptions>,
+  ) -> Result<S, String> {
+    let options = options.into();
+    self.stream(sink, |sink| stream::write_timewise_contents(sink, score, &options))
+  }
+
+  fn validate(&self) -> Result<Option<&Timestamp>, String> {
+    let mut paths: Vec<&str> = vec![MIMETYPE_PATH, CONTAINER_PATH];
+    for path in core::iter::once(&self.root_path).chain(self.entries.iter().map(|entry| &entry.path)) {
+      if path.is_empty() || path.starts_with('/') {
+        Err(format!("Invalid path \"{path}\" for a file within compressed archive"))?;
+      } else if paths.contains(&path.as_str()) {
+        Err(format!(
+          "Duplicate path \"{path}\" for a file within compressed archive"
+        ))?;
+      }
+      paths.push(path);
+    }
+    if let Some(timestamp) = &self.timestamp {
+      timestamp.validate()?;
+    }
+    Ok(self.timestamp.as_ref())
+  }
+
+  fn container(&self) -> String {
+    let rootfiles =
+      core::iter::once((&self.root_path, MUSICXML_MEDIA_TYPE)).chain(self.entries.iter().filter_map(|entry| {
+        entry
+          .media_type
+          .as_ref()
+          .map(|media_type| (&entry.path, media_type.as_str()))
+      }));
+    let mut container = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<container>\n  <rootfiles>\n");
+    for (path, media_type) in rootfiles {
+      let _ = writeln!(
+        container,
+        "    <rootfile full-path=\"{}\" media-type=\"{}\"/>",
+        xml_escape(path),
+        xml_escape(media_type)
+      );
+    }
+    container += "  </rootfiles>\n</container>";
+    container
+  }
+
+  fn ordered_entries(&self) -> impl Iterator<Item = &MxlEntry> {
+    let (rootfiles, assets): (Vec<&MxlEntry>, Vec<&MxlEntry>) =
+      self.entries.iter().partition(|entry| entry.media_type.is_some());
+    rootfiles.into_iter().chain(assets)
+  }
+
+  pub(crate) fn write_xml(&self, xml: &XmlElement, options: &WriteOptions) -> Result<Vec<u8>, String> {
+    let mut archiver = zip_parser::ZipArchiver::new();
+    if let Some(timestamp) = self.validate()? {
+      archiver.set_datetime(
+        timestamp.year,
+        u16::from(timestamp.month),
+        u16::from(timestamp.day),
+        u16::from(timestamp.hour),
+        u16::from(timestamp.minute),
+        u16::from(timestamp.second),
+      );
+    }
+    archiver.start_file_with_compression(MIMETYPE_PATH, None);
+    archiver.write_data(MXL_MEDIA_TYPE.as_bytes());
+    archiver.start_file_with_compression(CONTAINER_PATH, self.root_compression.level());
+    archiver.write_data(self.container().as_bytes());
+    archiver.start_file_with_compression(&self.root_path, self.root_compression.level());
+    archiver.write_data(put_musicxml_contents(xml, options).as_slice());
+    for entry in self.ordered_entries() {
+      archiver.start_file_with_compression(&entry.path, entry.compression.level());
+      archiver.write_data(&entry.data);
+    }
+    Ok(archiver.finish())
+  }
+
+  fn stream<S: XmlSink>(
+    &self,
+    sink: S,
+    write_score: impl FnOnce(&mut dyn XmlSink) -> Result<(), String>,
+  ) -> Result<S, String> {
+    let mut streamer = zip_parser::ZipStreamer::new(sink);
+    if let Some(timestamp) = self.validate()? {
+      streamer.set_datetime(
+        timestamp.year,
+        u16::from(timestamp.month),
+        u16::from(timestamp.day),
+        u16::from(timestamp.hour),
+        u16::from(timestamp.minute),
+        u16::from(timestamp.second),
+      );
+    }
+    streamer.add_file(MIMETYPE_PATH, MXL_MEDIA_TYPE.as_bytes(), None)?;
+    streamer.add_file(
+      CONTAINER_PATH,
+      self.container().as_bytes(),
+      self.root_compression.level(),
+    )?;
+    if let Some(level) = self.root_compression.level() {
+      streamer.start_file(&self.root_path, level)?;
+      write_score(&mut streamer)?;
+    } else {
+      // Stored files must be preceded by their sizes, so an uncompressed score has to be buffered
+      let mut data = Vec::new();
+      write_score(&mut data)?;
+      streamer.add_file(&self.root_path, &data, None)?;
+    }
+    for entry in self.ordered_entries() {
+      streamer.add_file(&entry.path, &entry.data, entry.compression.level())?;
+    }
+    streamer.finish()
+  }
+}
+
+fn percent_decode(segment: &str) -> String {
+  let bytes = segment.as_bytes();
+  let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+  let mut index = 0;
+  while index < bytes.len() {
+    let hex = bytes
+      .get(index + 1..index + 3)
+      .and_then(|hex| core::str::from_utf8(hex).ok())
+      .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+    match (bytes[index], hex) {
+      (b'%', Some(value)) => {
+        decoded.push(value);
+        index += 3;
+      }
+      (byte, _) => {
+        decoded.push(byte);
+        index += 1;
+      }
+    }
+  }
+  String::from_utf8(decoded).unwrap_or_else(|_| String::from(segment))
+}
+
+#[cfg(test)]
+mod mxl_tests {
+  use super::*;
+  use crate::parser::zip_parser::ZipArchiver;
+
+  fn archive(container: &str, files: &[(&str, &[u8])]) -> MxlArchive {
+    let mut archiver = ZipArchiver::new();
+    archiver.start_file(CONTAINER_PATH);
+    archiver.write_data(container.as_bytes());
+    for (name, data) in files {
+      archiver.start_file(name);
+      archiver.write_data(data);
+    }
+    MxlArchive::from_data(archiver.finish()).unwrap()
+  }
+
+  const SCORE: &[u8] = b"<score-partwise><credit><credit-image source=\"images/logo%201.png\" type=\"image/png\"/></credit><part-list><score-part id=\"P1\"><part-name>Music</part-name></score-part></part-list><part id=\"P1\"><measure number=\"1\"><direction><direction-type><image source=\"../cover.jpg\" type=\"image/jpeg\"/></direction-type></direction></measure></part></score-partwise>";
+
+  #[test]
+  fn read_rootfiles() {
+    let archive = archive(
+      "<container><rootfiles><rootfile full-path=\"music/score.musicxml\"/><rootfile full-path=\"score.pdf\" media-type=\"application/pdf\"/></rootfiles></container>",
+      &[("music/score.musicxml", SCORE), ("score.pdf", b"%PDF-1.4")],
+    );
+    assert_eq!(archive.rootfiles().len(), 2);
+    assert_eq!(archive.default_rootfile().full_path, "music/score.musicxml");
+    assert!(archive.default_rootfile().is_musicxml());
+    assert_eq!(archive.rootfiles()[1].media_type, "application/pdf");
+    assert!(!archive.rootfiles()[1].is_musicxml());
+    assert_eq!(archive.read_entry("score.pdf").unwrap(), b"%PDF-1.4");
+    assert_eq!(
+      archive.entries().collect::<Vec<_>>(),
+      ["META-INF/container.xml", "music/score.musicxml", "score.pdf"]
+    );
+  }
+
+  #[test]
+  fn read_scores() {
+    let archive = archive(
+      "<container><rootfiles><rootfile full-path=\"score.musicxml\"/><rootfile full-path=\"alternate.musicxml\"/></rootfiles></container>",
+      &[
+        ("score.musicxml", SCORE),
+        ("alternate.musicxml", b"<score-partwise><part-list/></score-partwise>"),
+      ],
+    );
+    assert_eq!(archive.read_score_partwise().unwrap().content.part.len(), 1);
+    assert_eq!(archive.read_score_timewise().unwrap().content.measure.len(), 1);
+    assert!(archive
+      .read_rootfile_partwise("alternate.musicxml")
+      .unwrap()
+      .content
+      .part
+      .is_empty());
+    assert!(archive.read_rootfile_partwise("missing.musicxml").is_err());
+  }
+
+  #[test]
+  fn resolve_uris() {
+    let archive = archive(
+      "<container><rootfiles><rootfile full-path=\"music/score.musicxml\"/></rootfiles></container>",
+      &[
+        ("music/score.musicxml", SCORE),
+        ("music/images/logo 1.png", b"PNG"),
+        ("cover.jpg", b"JPG"),
+      ],
+    );
+    assert_eq!(
+      archive.resolve_uri("images/logo%201.png").unwrap(),
+      "music/images/logo 1.png"
+    );
+    assert_eq!(
+      archive.resolve_uri("./images/logo 1.png").unwrap(),
+      "music/images/logo 1.png"
+    );
+    assert_eq!(archive.resolve_uri("../cover.jpg").unwrap(), "cover.jpg");
+    assert_eq!(archive.resolve_uri("/cover.jpg").unwrap(), "cover.jpg");
+    assert_eq!(archive.resolve_uri("../../cover.jpg"), None);
+    assert_eq!(archive.resolve_uri("http://example.com/cover.jpg"), None);
+    assert_eq!(archive.resolve_uri("missing.png"), None);
+    assert_eq!(archive.read_uri("../cover.jpg").unwrap(), b"JPG");
+    let score = archive.read_score_partwise().unwrap();
+    assert_eq!(archive.image_entries(&score), ["music/images/logo 1.png", "cover.jpg"]);
+  }
+
+  #[test]
+  fn read_invalid() {
+    assert!(matches!(
+      MxlArchive::from_data(SCORE.to_vec()),
+      Err(ArchiveError::NotAnArchive)
+    ));
+    let mut archiver = ZipArchiver::new();
+    archiver.start_file("score.musicxml");
+    archiver.write_data(SCORE);
+    assert!(matches!(
+      MxlArchive::from_data(archiver.finish()),
+      Err(ArchiveError::InvalidContainer(_))
+    ));
+    let mut archiver = ZipArchiver::new();
+    archiver.start_file(CONTAINER_PATH);
+    archiver.write_data(b"<container><rootfiles/></container>");
+    assert!(matches!(
+      MxlArchive::from_data(archiver.finish()),
+      Err(ArchiveError::InvalidContainer(_))
+    ));
+    assert!(matches!(MxlArchive::open("missing.mxl"), Err(ArchiveError::Io(_))));
+  }
+
+  #[test]
+  fn write_layout() {
+    let score: ScorePartwise = crate::parser::parse_from_xml_str(core::str::from_utf8(SCORE).unwrap()).unwrap();
+    let data = MxlWriter::new().write_partwise(&score, false).unwrap();
+    assert_eq!(data[0..4], [0x50, 0x4B, 0x03, 0x04]);
+    assert_eq!(data[8..10], [0x00, 0x00]);
+    assert_eq!(&data[30..38], b"mimetype");
+    assert_eq!(&data[38..72], MXL_MEDIA_TYPE.as_bytes());
+    let archive = MxlArchive::from_data(data).unwrap();
+    assert_eq!(archive.default_rootfile().full_path, "score.musicxml");
+    assert_eq!(archive.read_score_partwise().unwrap(), score);
+  }
+
+  #[test]
+  fn write_entries() {
+    let score: ScorePartwise = crate::parser::parse_from_xml_str(core::str::from_utf8(SCORE).unwrap()).unwrap();
+    let data = MxlWriter::new()
+      .with_root_path("music/score.musicxml")
+      .with_root_compression(Compression::Deflated(1))
+      .with_asset("music/images/logo 1.png", b"PNG".to_vec(), Compression::Deflated(10))
+      .with_rootfile(
+        "score & parts.pdf",
+        "application/pdf",
+        b"%PDF-1.4".to_vec(),
+        Compression::Stored,
+      )
+      .with_asset("cover.jpg", b"JPG".to_vec(), Compression::Deflated(0))
+      .write_partwise(&score, true)
+      .unwrap();
+    let archive = MxlArchive::from_data(data).unwrap();
+    assert_eq!(
+      archive.rootfiles(),
+      [
+        Rootfile {
+          full_path: String::from("music/score.musicxml"),
+          media_type: String::from(MUSICXML_MEDIA_TYPE),
+        },
+        Rootfile {
+          full_path: String::from("score & parts.pdf"),
+          media_type: String::from("application/pdf"),
+        },
+      ]
+    );
+    assert_eq!(archive.read_entry("music/images/logo 1.png").unwrap(), b"PNG");
+    assert_eq!(archive.read_entry("cover.jpg").unwrap(), b"JPG");
+    assert_eq!(archive.read_score_partwise().unwrap(), score);
+    assert_eq!(
+      archive.image_entries(&archive.read_score_partwise().unwrap()),
+      ["music/images/logo 1.png", "cover.jpg"]
+    );
+  }
+
+  #[test]
+  fn write_timestamp() {
+    let score: ScorePartwise = crate::parser::parse_from_xml_str(core::str::from_utf8(SCORE).unwrap()).unwrap();
+    let writer = MxlWriter::new().with_timestamp(Timestamp {
+      year: 2024,
+      month: 5,
+      day: 17,
+      hour: 13,
+      minute: 45,
+      second: 30,
+    });
+    let data = writer.write_partwise(&score, true).unwrap();
+    assert_eq!(data, writer.write_partwise(&score, true).unwrap());
+    assert_eq!(u16::from_le_bytes([data[10], data[11]]), (13 << 11) | (45 << 5) | 15);
+    assert_eq!(u16::from_le_bytes([data[12], data[13]]), (44 << 9) | (5 << 5) | 17);
+    let invalid = Timestamp {
+      year: 1970,
+      ..writer.timestamp.unwrap()
+    };
+    assert!(MxlWriter::new()
+      .with_timestamp(invalid)
+      .write_partwise(&score, true)
+      .is_err());
+  }
+
+  #[test]
+  fn write_invalid() {
+    let score: ScorePartwise = crate::parser::parse_from_xml_str(core::str::from_utf8(SCORE).unwrap()).unwrap();
+    let asset = |path: &str| MxlWriter::new().with_asset(path, Vec::new(), Compression::Stored);
+    assert!(asset("score.musicxml").write_partwise(&score, true).is_err());
+    assert!(asset("mimetype").write_partwise(&score, true).is_err());
+    assert!(asset(CONTAINER_PATH).write_partwise(&score, true).is_err());
+    assert!(asset("/image.png").write_partwise(&score, true).is_err());
+    assert!(asset("").write_partwise(&score, true).is_err());
+    assert!(asset("image.png")
+      .with_asset("image.png", Vec::new(), Compression::default())
+      .write_partwise(&score, true)
+      .is_err());
+  }
+
+  #[test]
+  fn read_file() {
+    let archive = MxlArchive::open("tests/Grande Valse Brillante.mxl").unwrap();
+    assert!(archive.default_rootfile().is_musicxml());
+    assert!(archive.contains(&archive.default_rootfile().full_path));
+    assert_eq!(
+      archive.read_score_partwise().unwrap(),
+      crate::read_score_partwise("tests/Grande Valse Brillante.mxl").unwrap()
+    );
+  }
+}
@@ -0,0 +1,382 @@
+//! Streaming serialization of complete scores directly into a sink.
+//!
+//! The functions in [parser][crate::parser] first convert a whole score into an intermediate XML tree and then
+//! into a single string. The functions in this module instead serialize one header element, note, or other measure
+//! item at a time and write it straight into an [XmlSink][crate::parser::stream::XmlSink], so writing a large score
+//! needs little memory beyond the score itself. When writing a compressed `.mxl` file, the score is passed through
+//! the DEFLATE compressor as it is written.
+
+use super::{mxl::MxlWriter, xml_parser, WriteOptions};
+use crate::elements::{
+  Credit, Defaults, Identification, Measure, MeasureAttributes, MeasureElement, MovementNumber, MovementTitle, Part,
+  PartAttributes, PartElement, PartList, ScorePartwise, ScorePartwiseAttributes, ScoreTimewise,
+  ScoreTimewiseAttributes, Work,
+};
+use alloc::{string::String, vec::Vec};
+use musicxml_internal::{AttributeSerializer, ElementSerializer, XmlAttribute};
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+
+/// A destination for serialized MusicXML contents.
+///
+/// The trait is implemented for `Vec<u8>` and `String`, for any [std::io::Write] type wrapped in an [IoSink], and for
+/// any [core::fmt::Write] type wrapped in a [FmtSink].
+pub trait XmlSink {
+  /// Writes a string into the sink.
+  ///
+  /// # Errors
+  ///
+  /// If the sink cannot be written, an error message will be returned.
+  fn write_str(&mut self, value: &str) -> Result<(), String> {
+    self.write_bytes(value.as_bytes())
+  }
+
+  /// Writes raw bytes into the sink.
+  ///
+  /// # Errors
+  ///
+  /// If the sink cannot be written or does not accept the bytes, an error message will be returned.
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String>;
+}
+
+impl XmlSink for Vec<u8> {
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+    self.extend_from_slice(bytes);
+    Ok(())
+  }
+}
+
+impl XmlSink for String {
+  fn write_str(&mut self, value: &str) -> Result<(), String> {
+    self.push_str(value);
+    Ok(())
+  }
+
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+    self.push_str(core::str::from_utf8(bytes).map_err(|_| String::from("Binary data cannot be written to a string"))?);
+    Ok(())
+  }
+}
+
+impl<S: XmlSink + ?Sized> XmlSink for &mut S {
+  fn write_str(&mut self, value: &str) -> Result<(), String> {
+    (**self).write_str(value)
+  }
+
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+    (**self).write_bytes(bytes)
+  }
+}
+
+/// Adapts a [std::io::Write] type into an [XmlSink].
+///
+/// Contents are written in many small pieces, so unbuffered writers such as files should be wrapped in a
+/// [std::io::BufWriter] first.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoSink<W: std::io::Write>(pub W);
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> XmlSink for IoSink<W> {
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+    self.0.write_all(bytes).map_err(|e| e.to_string())
+  }
+}
+
+/// Adapts a [core::fmt::Write] type into an [XmlSink].
+///
+/// Only text can be written into a formatter, so compressed `.mxl` contents cannot be streamed into this sink.
+#[derive(Debug)]
+pub struct FmtSink<W: core::fmt::Write>(pub W);
+
+impl<W: core::fmt::Write> XmlSink for FmtSink<W> {
+  fn write_str(&mut self, value: &str) -> Result<(), String> {
+    self
+      .0
+      .write_str(value)
+      .map_err(|_| String::from("Unable to write into formatter"))
+  }
+
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+    self
+      .write_str(core::str::from_utf8(bytes).map_err(|_| String::from("Binary data cannot be written to a formatter"))?)
+  }
+}
+
+macro_rules! write_header {
+  ($sink:expr, $content:expr, $options:expr) => {{
+    let header = $content
+      .work
+      .iter()
+      .map(Work::serialize)
+      .chain($content.movement_number.iter().map(MovementNumber::serialize))
+      .chain($content.movement_title.iter().map(MovementTitle::serialize))
+      .chain($content.identification.iter().map(Identification::serialize))
+      .chain($content.defaults.iter().map(Defaults::serialize))
+      .chain($content.credit.iter().map(Credit::serialize))
+      .chain(core::iter::once(PartList::serialize(&$content.part_list)));
+    for element in header {
+      xml_parser::write_child($sink, &element, $options, 1)?;
+    }
+  }};
+}
+
+fn write_root<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  name: &str,
+  version: Option<&String>,
+  attributes: &[XmlAttribute<'_>],
+  options: &WriteOptions,
+) -> Result<(), String> {
+  xml_parser::write_prolog(sink, name, version.map(String::as_str), options)?;
+  xml_parser::write_start_tag(
+    sink,
+    name,
+    attributes,
+    xml_parser::root_version(name, options),
+    options,
+    0,
+  )
+}
+
+fn write_part<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  part: &Part,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
+  if part.content.is_empty() {
+    return xml_parser::write_child(sink, &Part::serialize(part), options, depth);
+  }
+  let attributes = PartAttributes::serialize(&part.attributes);
+  xml_parser::write_start_tag(sink, "part", &attributes, None, options, depth)?;
+  for element in &part.content {
+    match element {
+      PartElement::Measure(measure) => write_measure(sink, measure, options, depth + 1)?,
+      _ => xml_parser::write_child(sink, &PartElement::serialize(element), options, depth + 1)?,
+    }
+  }
+  xml_parser::write_end_tag(sink, "part", options, depth)
+}
+
+fn write_measure<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  measure: &Measure,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
+  if measure.content.is_empty() {
+    return xml_parser::write_child(sink, &Measure::serialize(measure), options, depth);
+  }
+  let attributes = MeasureAttributes::serialize(&measure.attributes);
+  xml_parser::write_start_tag(sink, "measure", &attributes, None, options, depth)?;
+  for element in &measure.content {
+    match element {
+      MeasureElement::Part(part) => write_part(sink, part, options, depth + 1)?,
+      _ => xml_parser::write_child(sink, &MeasureElement::serialize(element), options, depth + 1)?,
+    }
+  }
+  xml_parser::write_end_tag(sink, "measure", options, depth)
+}
+
+pub(crate) fn write_partwise_contents<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  score: &ScorePartwise,
+  options: &WriteOptions,
+) -> Result<(), String> {
+  let attributes = ScorePartwiseAttributes::serialize(&score.attributes);
+  let version = score.attributes.version.as_ref().map(|version| &version.0);
+  write_root(sink, "score-partwise", version, &attributes, options)?;
+  write_header!(sink, score.content, options);
+  for part in &score.content.part {
+    write_part(sink, part, options, 1)?;
+  }
+  xml_parser::write_end_tag(sink, "score-partwise", options, 0)
+}
+
+pub(crate) fn write_timewise_contents<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  score: &ScoreTimewise,
+  options: &WriteOptions,
+) -> Result<(), String> {
+  let attributes = ScoreTimewiseAttributes::serialize(&score.attributes);
+  let version = score.attributes.version.as_ref().map(|version| &version.0);
+  write_root(sink, "score-timewise", version, &attributes, options)?;
+  write_header!(sink, score.content, options);
+  for measure in &score.content.measure {
+    write_measure(sink, measure, options, 1)?;
+  }
+  xml_parser::write_end_tag(sink, "score-timewise", options, 0)
+}
+
+/// Streams the specified [ScorePartwise] element into a sink and returns the sink once the score has been written.
+///
+/// If the `compressed` parameter is set to `true`, the score will be written as a compressed `.mxl` archive using
+/// the default [MxlWriter] settings. The `options` parameter specifies how the score is formatted and accepts either
+/// a [WriteOptions] value or a `bool` indicating whether the score should be written with indentation and newlines.
+/// The output is identical to that of [parse_score_partwise_to_data][crate::parser::parse_score_partwise_to_data]
+/// for uncompressed contents.
+///
+/// # Errors
+///
+/// If the sink cannot be written, an error message will be returned.
+pub fn write_partwise<S: XmlSink>(
+  mut sink: S,
+  score: &ScorePartwise,
+  compressed: bool,
+  options: impl Into<WriteOptions>,
+) -> Result<S, String> {
+  if compressed {
+    MxlWriter::new().stream_partwise(sink, score, options)
+  } else {
+    write_partwise_contents(&mut sink, score, &options.into()).map(|()| sink)
+  }
+}
+
+/// Streams the specified [ScoreTimewise] element into a sink and returns the sink once the score has been written.
+///
+/// If the `compressed` parameter is set to `true`, the score will be written as a compressed `.mxl` archive using
+/// the default [MxlWriter] settings. The `options` parameter specifies how the score is formatted and accepts either
+/// a [WriteOptions] value or a `bool` indicating whether the score should be written with indentation and newlines.
+/// The output is identical to that of [parse_score_timewise_to_data][crate::parser::parse_score_timewise_to_data]
+/// for uncompressed contents.
+///
+/// # Errors
+///
+/// If the sink cannot be written, an error message will be returned.
+pub fn write_timewise<S: XmlSink>(
+  mut sink: S,
+  score: &ScoreTimewise,
+  compressed: bool,
+  options: impl Into<WriteOptions>,
+) -> Result<S, String> {
+  if compressed {
+    MxlWriter::new().stream_timewise(sink, score, options)
+  } else {
+    write_timewise_contents(&mut sink, score, &options.into()).map(|()| sink)
+  }
+}
+
+#[cfg(test)]
+mod stream_tests {
+  use super::*;
+  use crate::parser::{
+    parse_score_partwise_from_data, parse_score_partwise_from_file, parse_score_partwise_to_data,
+    parse_score_partwise_to_file, parse_score_timewise_from_file, parse_score_timewise_to_data, AttributeOrder,
+    Doctype, LineEnding,
+  };
+
+  const FILES: [&str; 2] = ["tests/Grande Valse Brillante.musicxml", "tests/MozaChloSample.musicxml"];
+
+  fn all_options() -> [WriteOptions; 3] {
+    [
+      WriteOptions::default(),
+      WriteOptions::compact(),
+      WriteOptions {
+        doctype: Doctype::Version(String::from("3.1")),
+        attribute_order: AttributeOrder::Alphabetical,
+        line_ending: LineEnding::CrLf,
+        empty_elements: false,
+        ..WriteOptions::default()
+      },
+    ]
+  }
+
+  #[test]
+  fn stream_matches_tree() {
+    for file in FILES {
+      let partwise = parse_score_partwise_from_file(file).unwrap();
+      let timewise = parse_score_timewise_from_file(file).unwrap();
+      for options in all_options() {
+        assert_eq!(
+          write_partwise(Vec::new(), &partwise, false, &options).unwrap(),
+          parse_score_partwise_to_data(&partwise, false, &options, false).unwrap()
+        );
+        assert_eq!(
+          write_timewise(Vec::new(), &timewise, false, &options).unwrap(),
+          parse_score_timewise_to_data(&timewise, false, &options, false).unwrap()
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn stream_fmt_sink() {
+    let score = parse_score_partwise_from_file(FILES[0]).unwrap();
+    let FmtSink(written) = write_partwise(FmtSink(String::new()), &score, false, true).unwrap();
+    assert_eq!(
+      written.into_bytes(),
+      parse_score_partwise_to_data(&score, false, true, false).unwrap()
+    );
+    assert!(write_partwise(FmtSink(String::new()), &score, true, true).is_err());
+  }
+
+  #[test]
+  fn stream_io_sink() {
+    #[derive(Debug)]
+    struct FailingWriter(usize);
+    impl std::io::Write for FailingWriter {
+      fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        if self.0 > 1000 {
+          Err(std::io::Error::other("Disk full"))
+        } else {
+          Ok(buf.len())
+        }
+      }
+      fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+      }
+    }
+    let score = parse_score_partwise_from_file(FILES[1]).unwrap();
+    let IoSink(written) = write_partwise(IoSink(Vec::new()), &score, false, false).unwrap();
+    assert_eq!(
+      written,
+      parse_score_partwise_to_data(&score, false, false, false).unwrap()
+    );
+    assert_eq!(
+      write_partwise(IoSink(FailingWriter(0)), &score, false, false).unwrap_err(),
+      "Disk full"
+    );
+    assert!(write_partwise(IoSink(FailingWriter(0)), &score, true, false).is_err());
+  }
+
+  #[test]
+  fn stream_compressed() {
+    let score = parse_score_partwise_from_file(FILES[0]).unwrap();
+    let data = write_partwise(Vec::new(), &score, true, true).unwrap();
+    assert_eq!(parse_score_partwise_from_data(data).unwrap(), score);
+    let writer = MxlWriter::new()
+      .with_root_compression(crate::parser::mxl::Compression::Stored)
+      .with_asset(
+        "images/cover.png",
+        vec![0x89, 0x50, 0x4e, 0x47],
+        crate::parser::mxl::Compression::Stored,
+      );
+    let mut data = Vec::new();
+    writer.stream_partwise(&mut data, &score, false).unwrap();
+    let archive = crate::parser::mxl::MxlArchive::from_data(data).unwrap();
+    assert_eq!(archive.read_score_partwise().unwrap(), score);
+    assert_eq!(
+      archive.read_entry("images/cover.png").unwrap(),
+      [0x89, 0x50, 0x4e, 0x47]
+    );
+  }
+
+  #[test]
+  fn stream_to_file() {
+    let score = parse_score_partwise_from_file(FILES[1]).unwrap();
+    let directory = std::env::temp_dir();
+    for (name, compressed) in [("stream_test.musicxml", false), ("stream_test.mxl", true)] {
+      let path = directory.join(name);
+      let path = path.to_str().unwrap();
+      parse_score_partwise_to_file(path, &score, compressed, true, false).unwrap();
+      assert_eq!(parse_score_partwise_from_file(path).unwrap(), score);
+      std::fs::remove_file(path).unwrap();
+    }
+  }
+}
@@ -0,0 +1,291 @@
+use alloc::string::String;
+
+/// MusicXML version written into the DOCTYPE when a score does not specify its own `version` attribute.
+pub const DEFAULT_MUSICXML_VERSION: &str = "4.0";
+
+/// Selects which `<!DOCTYPE>` declaration, if any, precedes a written score.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Doctype {
+  /// Uses the `version` attribute of the score, falling back to [DEFAULT_MUSICXML_VERSION] if it is missing.
+  #[default]
+  Auto,
+  /// Writes the DOCTYPE for the specified MusicXML version and sets the `version` attribute of the score to match.
+  Version(String),
+  /// Omits the DOCTYPE declaration entirely.
+  Omit,
+}
+
+/// Selects the order in which element attributes are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeOrder {
+  /// Writes attributes in the order in which they are declared by the MusicXML schema.
+  #[default]
+  Declaration,
+  /// Writes attributes sorted alphabetically by name.
+  Alphabetical,
+}
+
+/// Selects the line ending written after the XML declaration, the DOCTYPE, and each indented element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+  /// Unix-style `\n` line endings.
+  #[default]
+  Lf,
+  /// Windows-style `\r\n` line endings.
+  CrLf,
+}
+
+impl LineEnding {
+  pub(crate) fn as_str(self) -> &'static str {
+    match self {
+      Self::Lf => "\n",
+      Self::CrLf => "\r\n",
+    }
+  }
+}
+
+/// Selects how elements without any content are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelfClosingStyle {
+  /// Writes empty elements as `<chord/>`.
+  #[default]
+  Compact,
+  /// Writes empty elements as `<chord />`.
+  Spaced,
+  /// Writes empty elements as `<chord></chord>`.
+  Expanded,
+}
+
+/// Options controlling how MusicXML contents are formatted when written.
+///
+/// The default options reproduce the standard output of this library: a UTF-8 XML declaration, a DOCTYPE matching
+/// the version of the score, two-space indentation, and `\n` line endings. A `bool` converts into either the
+/// default options (`true`) or [WriteOptions::compact] (`false`), so every write function accepting options can
+/// still be called with a simple `pretty_print` flag.
+///
+/// ```rust
+/// use musicxml::parser::{AttributeOrder, Doctype, LineEnding, WriteOptions};
+///
+/// let options = WriteOptions {
+///   doctype: Doctype::Version(String::from("3.1")),
+///   indent: Some(String::from("\t")),
+///   line_ending: LineEnding::CrLf,
+///   attribute_order: AttributeOrder::Alphabetical,
+///   ..WriteOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteOptions {
+  /// DOCTYPE declaration written before the root element.
+  pub doctype: Doctype,
+  /// Whether to write the `<?xml ...?>` declaration at the start of the document.
+  pub xml_declaration: bool,
+  /// String used for each level of indentation, or `None` to write the whole document on a single line.
+  pub indent: Option<String>,
+  /// Order in which element attributes are written.
+  pub attribute_order: AttributeOrder,
+  /// Line ending written between the declaration, the DOCTYPE, and indented elements.
+  pub line_ending: LineEnding,
+  /// Whether to write optional text elements that have no text and no attributes, such as an empty `<work-title>`.
+  ///
+  /// Empty elements whose presence carries meaning, such as `<chord/>` or `<rest/>`, are always written.
+  pub empty_elements: bool,
+  /// Style used to write elements without any content.
+  pub self_closing: SelfClosingStyle,
+}
+
+impl Default for WriteOptions {
+  fn default() -> Self {
+    Self {
+      doctype: Doctype::Auto,
+      xml_declaration: true,
+      indent: Some(String::from("  ")),
+      attribute_order: AttributeOrder::Declaration,
+      line_ending: LineEnding::Lf,
+      empty_elements: true,
+      self_closing: SelfClosingStyle::Compact,
+    }
+  }
+}
+
+impl WriteOptions {
+  /// Returns the default options without any indentation or newlines between elements.
+  #[must_use]
+  pub fn compact() -> Self {
+    Self {
+      indent: None,
+      ..Self::default()
+    }
+  }
+}
+
+impl From<bool> for WriteOptions {
+  fn from(pretty_print: bool) -> Self {
+    if pretty_print {
+      Self::default()
+    } else {
+      Self::compact()
+    }
+  }
+}
+
+impl From<&WriteOptions> for WriteOptions {
+  fn from(options: &WriteOptions) -> Self {
+    options.clone()
+  }
+}
+
+#[cfg(test)]
+mod write_options_tests {
+  use super::super::{put_musicxml_contents, xml_parser};
+  use super::*;
+  use alloc::vec::Vec;
+  use musicxml_internal::XmlElement;
+
+  fn element<'a>(
+    name: &'a str,
+    attributes: &[(&'a str, &'a str)],
+    elements: Vec<XmlElement<'a>>,
+    text: &'a str,
+  ) -> XmlElement<'a> {
+    XmlElement {
+      name: name.into(),
+      attributes: attributes
+        .iter()
+        .map(|&(key, value)| (key.into(), value.into()))
+        .collect(),
+      elements,
+      text: text.into(),
+    }
+  }
+
+  fn test_score(version: Option<&str>) -> XmlElement<'_> {
+    let attributes: Vec<(&str, &str)> = version.iter().map(|version| ("version", *version)).collect();
+    element(
+      "score-partwise",
+      &attributes,
+      vec![
+        element("work", &[], vec![element("work-title", &[], vec![], "")], ""),
+        element("part-list", &[], vec![], ""),
+      ],
+      "",
+    )
+  }
+
+  fn write(xml: &XmlElement, options: &WriteOptions) -> String {
+    String::from_utf8(put_musicxml_contents(xml, options)).unwrap()
+  }
+
+  #[test]
+  fn write_doctype() {
+    let default_doctype = "<!DOCTYPE score-partwise PUBLIC \"-//Recordare//DTD MusicXML 4.0 Partwise//EN\"";
+    assert!(write(&test_score(None), &WriteOptions::default()).contains(default_doctype));
+    let written = write(&test_score(Some("3.1")), &WriteOptions::default());
+    assert!(written.contains("DTD MusicXML 3.1 Partwise//EN"));
+    assert!(written.contains("<score-partwise version=\"3.1\">"));
+    let options = WriteOptions {
+      doctype: Doctype::Version(String::from("4.0")),
+      ..WriteOptions::default()
+    };
+    let written = write(&test_score(Some("3.1")), &options);
+    assert!(written.contains("DTD MusicXML 4.0 Partwise//EN"));
+    assert!(written.contains("<score-partwise version=\"4.0\">"));
+    assert!(write(&test_score(None), &options).contains("<score-partwise version=\"4.0\">"));
+    let options = WriteOptions {
+      doctype: Doctype::Omit,
+      xml_declaration: false,
+      ..WriteOptions::default()
+    };
+    assert!(write(&test_score(None), &options).starts_with("<score-partwise>"));
+  }
+
+  #[test]
+  fn write_whitespace() {
+    let options = WriteOptions {
+      doctype: Doctype::Omit,
+      indent: Some(String::from("\t")),
+      line_ending: LineEnding::CrLf,
+      ..WriteOptions::default()
+    };
+    assert_eq!(
+      write(&test_score(None), &options),
+      "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\r\n<score-partwise>\r\n\t<work>\r\n\t\t<work-title/>\r\n\t</work>\r\n\t<part-list/>\r\n</score-partwise>"
+    );
+    assert_eq!(
+      xml_parser::write_to_string(&test_score(None), &WriteOptions::from(false)),
+      "<score-partwise><work><work-title/></work><part-list/></score-partwise>"
+    );
+  }
+
+  #[test]
+  fn write_attribute_order() {
+    let xml = element(
+      "note",
+      &[("default-x", "10"), ("color", "#000000"), ("print-object", "no")],
+      vec![],
+      "",
+    );
+    assert_eq!(
+      xml_parser::write_to_string(&xml, &WriteOptions::default()),
+      "<note default-x=\"10\" color=\"#000000\" print-object=\"no\"/>"
+    );
+    let options = WriteOptions {
+      attribute_order: AttributeOrder::Alphabetical,
+      ..WriteOptions::default()
+    };
+    assert_eq!(
+      xml_parser::write_to_string(&xml, &options),
+      "<note color=\"#000000\" default-x=\"10\" print-object=\"no\"/>"
+    );
+  }
+
+  #[test]
+  fn write_empty_elements() {
+    let mut xml = test_score(None);
+    xml.elements.push(element(
+      "part",
+      &[("id", "P1")],
+      vec![element(
+        "measure",
+        &[("number", "1")],
+        vec![element(
+          "note",
+          &[],
+          vec![
+            element("chord", &[], vec![], ""),
+            element("rest", &[], vec![], ""),
+            element("voice", &[], vec![], ""),
+          ],
+          "",
+        )],
+        "",
+      )],
+      "",
+    ));
+    let options = WriteOptions {
+      empty_elements: false,
+      ..WriteOptions::compact()
+    };
+    assert_eq!(
+      xml_parser::write_to_string(&xml, &options),
+      "<score-partwise><work/><part-list/><part id=\"P1\"><measure number=\"1\"><note><chord/><rest/></note></measure></part></score-partwise>"
+    );
+  }
+
+  #[test]
+  fn write_self_closing_style() {
+    let xml = element("note", &[], vec![element("chord", &[], vec![], "")], "");
+    let styles = [
+      (SelfClosingStyle::Compact, "<note><chord/></note>"),
+      (SelfClosingStyle::Spaced, "<note><chord /></note>"),
+      (SelfClosingStyle::Expanded, "<note><chord></chord></note>"),
+    ];
+    for (style, expected) in styles {
+      let options = WriteOptions {
+        self_closing: style,
+        ..WriteOptions::compact()
+      };
+      assert_eq!(xml_parser::write_to_string(&xml, &options), expected);
+    }
+  }
+}
@@ -1,151 +1,400 @@
-use alloc::{string::String, vec::Vec};
-use musicxml_internal::XmlElement;
+use super::stream::XmlSink;
+use super::write_options::{AttributeOrder, Doctype, SelfClosingStyle, WriteOptions, DEFAULT_MUSICXML_VERSION};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use musicxml_internal::{XmlAttribute, XmlElement};
 
-enum TagType {
-  Opening(XmlElement),
-  Closing(XmlElement),
-  SelfClosing(XmlElement),
+enum TagType<'a> {
+  Opening(XmlElement<'a>),
+  Closing(&'a str),
+  SelfClosing(XmlElement<'a>),
   Ignored,
   Done,
 }
 
-fn read_tag_str(str: &mut core::str::Chars) -> TagType {
+fn is_xml_whitespace(ch: char) -> bool {
+  matches!(ch, ' ' | '\t' | '\r' | '\n')
+}
+
+fn remove_line_breaks(value: &str) -> Cow<'_, str> {
+  if value.contains(['\r', '\n']) {
+    Cow::Owned(value.chars().filter(|&ch| ch != '\r' && ch != '\n').collect())
+  } else {
+    Cow::Borrowed(value)
+  }
+}
+
+fn skip_to<'a>(str: &'a str, pattern: &str) -> (TagType<'a>, usize) {
+  match str.find(pattern) {
+    Some(index) => (TagType::Ignored, index + pattern.len()),
+    None => (TagType::Done, str.len()),
+  }
+}
+
+/// Reads the tag that starts immediately after a `<` and returns it along with the number of bytes it occupies,
+/// including its closing `>`. Names and attribute values borrow from the input whenever possible.
+fn read_tag_str(str: &str) -> (TagType<'_>, usize) {
+  if str.starts_with("!--") {
+    return skip_to(str, "-->");
+  } else if str.starts_with(['?', '!']) {
+    return skip_to(str, ">");
+  } else if let Some(closing) = str.strip_prefix('/') {
+    return match closing.find('>') {
+      Some(index) => (
+        TagType::Closing(closing[..index].trim_end_matches(is_xml_whitespace)),
+        index + 2,
+      ),
+      None => (TagType::Done, str.len()),
+    };
+  }
+  let name_length = str
+    .find(|ch: char| is_xml_whitespace(ch) || ch == '/' || ch == '>')
+    .unwrap_or(str.len());
   let mut tag = XmlElement {
-    name: String::new(),
-    attributes: Vec::new(),
-    elements: Vec::new(),
-    text: String::new(),
+    name: Cow::Borrowed(&str[..name_length]),
+    ..XmlElement::default()
   };
-  let (mut is_closing, mut is_self_closing, mut in_attribute, mut in_string, mut in_tag, mut ignore) =
-    (false, false, true, false, true, false);
-  let (mut attribute, mut value) = (String::new(), String::new());
-  for (i, c) in str.enumerate() {
-    match c {
-      '>' => {
-        if !in_attribute {
-          tag.attributes.push((attribute.clone(), value.clone()));
-        }
-        return if ignore {
-          TagType::Ignored
-        } else if is_closing {
-          TagType::Closing(tag)
-        } else if is_self_closing {
-          TagType::SelfClosing(tag)
-        } else {
-          TagType::Opening(tag)
-        };
-      }
-      '\r' | '\n' => (),
-      '/' => {
-        if in_string {
-          value.push(c);
-        } else if i == 0 {
-          is_closing = true;
-        } else {
-          is_self_closing = true;
-        }
-      }
-      '?' | '!' => {
-        if i == 0 {
-          ignore = true;
-        }
-      }
-      ' ' => {
-        if in_tag {
-          in_tag = false;
-        } else if in_string {
-          value.push(c);
-        } else {
-          tag.attributes.push((attribute.clone(), value.clone()));
-          in_attribute = true;
-          attribute.clear();
-          value.clear();
-        }
-      }
-      '"' => in_string = !in_string,
-      '=' => in_attribute = false,
-      _ => {
-        if in_tag {
-          tag.name.push(c);
-        } else if in_attribute {
-          attribute.push(c);
-        } else {
-          value.push(c);
-        }
-      }
+  let mut index = name_length;
+  loop {
+    let remaining = &str[index..];
+    let trimmed = remaining.trim_start_matches(is_xml_whitespace);
+    index += remaining.len() - trimmed.len();
+    if trimmed.starts_with('>') {
+      return (TagType::Opening(tag), index + 1);
+    } else if let Some(closing) = trimmed.strip_prefix('/') {
+      return match closing.find('>') {
+        Some(offset) => (TagType::SelfClosing(tag), index + offset + 2),
+        None => (TagType::Done, str.len()),
+      };
+    } else if trimmed.is_empty() {
+      return (TagType::Done, str.len());
+    }
+    let name_length = trimmed
+      .find(|ch: char| is_xml_whitespace(ch) || matches!(ch, '=' | '/' | '>'))
+      .unwrap_or(trimmed.len());
+    let attribute = &trimmed[..name_length];
+    let value_start = trimmed[name_length..].trim_start_matches(is_xml_whitespace);
+    index += trimmed.len() - value_start.len();
+    let Some(value) = value_start.strip_prefix('=') else {
+      tag.attributes.push((Cow::Borrowed(attribute), Cow::Borrowed("")));
+      continue;
+    };
+    let value = value.trim_start_matches(is_xml_whitespace);
+    index += value_start.len() - value.len();
+    let Some(quote) = value.chars().next().filter(|&ch| ch == '"' || ch == '\'') else {
+      return (TagType::Done, str.len());
+    };
+    let Some(value_length) = value[1..].find(quote) else {
+      return (TagType::Done, str.len());
+    };
+    tag
+      .attributes
+      .push((Cow::Borrowed(attribute), remove_line_breaks(&value[1..=value_length])));
+    index += value_length + 2;
+  }
+}
+
+/// Appends a segment of character data to the text of an element, dropping tabs, line breaks and leading spaces.
+///
+/// The text continues to borrow from the input unless characters need to be removed from its middle or it is made
+/// up of several segments separated by child elements.
+fn append_text<'a>(text: &mut Cow<'a, str>, segment: &'a str) {
+  let segment = if text.is_empty() {
+    segment.trim_start_matches(is_xml_whitespace)
+  } else {
+    segment
+  };
+  if text.is_empty() && !segment.contains(['\t', '\r', '\n']) {
+    *text = Cow::Borrowed(segment);
+  } else if !segment.is_empty() {
+    text
+      .to_mut()
+      .extend(segment.chars().filter(|&ch| !matches!(ch, '\t' | '\r' | '\n')));
+  }
+}
+
+fn trim_text_end(text: &mut Cow<'_, str>) {
+  match text {
+    Cow::Borrowed(value) => *value = value.trim_end(),
+    Cow::Owned(value) => value.truncate(value.trim_end().len()),
+  }
+}
+
+/// Optional text elements that carry no meaning when written without any text or attributes.
+const OPTIONAL_TEXT_ELEMENTS: [&str; 37] = [
+  "bass-separator",
+  "creator",
+  "credit-type",
+  "directive",
+  "encoder",
+  "encoding-description",
+  "except-voice",
+  "feature",
+  "figure-number",
+  "footnote",
+  "function",
+  "group",
+  "group-abbreviation",
+  "group-link",
+  "group-name",
+  "instrument-abbreviation",
+  "instrument-sound",
+  "ipa",
+  "level",
+  "midi-device",
+  "midi-name",
+  "miscellaneous-field",
+  "movement-number",
+  "movement-title",
+  "part-abbreviation",
+  "prefix",
+  "rights",
+  "software",
+  "source",
+  "suffix",
+  "swing-style",
+  "virtual-library",
+  "virtual-name",
+  "voice",
+  "with-bar",
+  "work-number",
+  "work-title",
+];
+
+fn is_omitted(xml: &XmlElement, options: &WriteOptions) -> bool {
+  !options.empty_elements
+    && xml.attributes.is_empty()
+    && xml.elements.is_empty()
+    && xml.text.is_empty()
+    && OPTIONAL_TEXT_ELEMENTS.binary_search(&xml.name.as_ref()).is_ok()
+}
+
+fn write_line_break<S: XmlSink + ?Sized>(sink: &mut S, options: &WriteOptions, depth: usize) -> Result<(), String> {
+  if let Some(indent) = &options.indent {
+    sink.write_str(options.line_ending.as_str())?;
+    for _ in 0..depth {
+      sink.write_str(indent)?;
     }
   }
-  TagType::Done
+  Ok(())
 }
 
-pub fn parse_to_string(xml: &XmlElement, depth: i16) -> String {
-  let mut xml_str = String::new();
+/// Returns the `version` attribute that the options require for a root element with the specified name.
+pub fn root_version<'a>(name: &str, options: &'a WriteOptions) -> Option<&'a str> {
+  match &options.doctype {
+    Doctype::Version(version) if name == "score-partwise" || name == "score-timewise" => Some(version.as_str()),
+    _ => None,
+  }
+}
+
+/// Writes the XML declaration and DOCTYPE required by the options for a root element with the specified name and
+/// `version` attribute.
+pub fn write_prolog<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  name: &str,
+  version: Option<&str>,
+  options: &WriteOptions,
+) -> Result<(), String> {
+  let line_ending = options.line_ending.as_str();
+  if options.xml_declaration {
+    sink.write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    sink.write_str(line_ending)?;
+  }
+  let orientation = match name {
+    "score-partwise" => "Partwise",
+    "score-timewise" => "Timewise",
+    _ => return Ok(()),
+  };
+  let version = match &options.doctype {
+    Doctype::Auto => version.unwrap_or(DEFAULT_MUSICXML_VERSION),
+    Doctype::Version(version) => version.as_str(),
+    Doctype::Omit => return Ok(()),
+  };
+  sink.write_str(&format!(
+    "<!DOCTYPE {name} PUBLIC \"-//Recordare//DTD MusicXML {version} {orientation}//EN\" \"http://www.musicxml.org/dtds/{}.dtd\">",
+    orientation.to_lowercase()
+  ))?;
+  sink.write_str(line_ending)
+}
+
+/// Writes the opening tag of an element, replacing its `version` attribute if one is specified.
+pub fn write_start_tag<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  name: &str,
+  attributes: &[XmlAttribute<'_>],
+  version: Option<&str>,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
+  write_opening(sink, name, attributes, version, options, depth)?;
+  sink.write_str(">")
+}
+
+/// Writes the closing tag of an element whose children were written at a greater depth.
+pub fn write_end_tag<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  name: &str,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
+  write_line_break(sink, options, depth)?;
+  sink.write_str("</")?;
+  sink.write_str(name)?;
+  sink.write_str(">")
+}
+
+fn write_opening<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  name: &str,
+  attributes: &[XmlAttribute<'_>],
+  version: Option<&str>,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
   if depth > 0 {
-    xml_str += "\n";
+    write_line_break(sink, options, depth)?;
   }
-  for _ in 0..depth {
-    xml_str += "  ";
+  sink.write_str("<")?;
+  sink.write_str(name)?;
+  let mut attributes: Vec<(&str, &str)> = attributes
+    .iter()
+    .map(|(key, value)| (key.as_ref(), value.as_ref()))
+    .collect();
+  if let Some(version) = version {
+    match attributes.iter_mut().find(|(key, _)| *key == "version") {
+      Some(attribute) => attribute.1 = version,
+      None => attributes.insert(0, ("version", version)),
+    }
+  }
+  if options.attribute_order == AttributeOrder::Alphabetical {
+    attributes.sort_by_key(|(key, _)| *key);
   }
-  xml_str += ["<", &xml.name].concat().as_str();
-  for (key, value) in &xml.attributes {
-    xml_str += [" ", key, "=\"", value, "\""].concat().as_str();
+  for (key, value) in attributes {
+    sink.write_str(" ")?;
+    sink.write_str(key)?;
+    sink.write_str("=\"")?;
+    sink.write_str(value)?;
+    sink.write_str("\"")?;
   }
-  if xml.elements.is_empty() && xml.text.is_empty() {
-    xml_str += "/>";
+  Ok(())
+}
+
+fn write_element<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  xml: &XmlElement,
+  version: Option<&str>,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
+  write_opening(sink, &xml.name, &xml.attributes, version, options, depth)?;
+  let mut elements = xml
+    .elements
+    .iter()
+    .filter(|element| !is_omitted(element, options))
+    .peekable();
+  if elements.peek().is_none() && xml.text.is_empty() {
+    match options.self_closing {
+      SelfClosingStyle::Compact => sink.write_str("/>"),
+      SelfClosingStyle::Spaced => sink.write_str(" />"),
+      SelfClosingStyle::Expanded => {
+        sink.write_str("></")?;
+        sink.write_str(&xml.name)?;
+        sink.write_str(">")
+      }
+    }
   } else {
-    xml_str += ">";
-    for element in &xml.elements {
-      xml_str += parse_to_string(element, if depth >= 0 { depth + 1 } else { depth }).as_str();
+    sink.write_str(">")?;
+    for element in elements {
+      write_element(sink, element, None, options, depth + 1)?;
     }
-    if xml.text.is_empty() && depth >= 0 {
-      xml_str += "\n";
-      for _ in 0..depth {
-        xml_str += "  ";
-      }
+    if xml.text.is_empty() {
+      write_line_break(sink, options, depth)?;
     } else {
-      xml_str += xml.text.as_str();
+      sink.write_str(&xml.text)?;
     }
-    xml_str += ["</", &xml.name, ">"].concat().as_str();
+    sink.write_str("</")?;
+    sink.write_str(&xml.name)?;
+    sink.write_str(">")
+  }
+}
+
+/// Writes a child element at the specified depth, unless the options omit it for being empty.
+pub fn write_child<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  xml: &XmlElement,
+  options: &WriteOptions,
+  depth: usize,
+) -> Result<(), String> {
+  if is_omitted(xml, options) {
+    Ok(())
+  } else {
+    write_element(sink, xml, None, options, depth)
   }
-  xml_str
 }
 
-pub fn parse_from_string(str: &str) -> Result<XmlElement, String> {
-  let mut it = str.chars();
+/// Writes a complete document consisting of the prolog required by the options followed by the root element.
+pub fn write_document<S: XmlSink + ?Sized>(
+  sink: &mut S,
+  xml: &XmlElement,
+  options: &WriteOptions,
+) -> Result<(), String> {
+  let version = xml
+    .attributes
+    .iter()
+    .find(|(key, _)| key == "version")
+    .map(|(_, version)| version.as_ref());
+  write_prolog(sink, &xml.name, version, options)?;
+  write_element(sink, xml, root_version(&xml.name, options), options, 0)
+}
+
+/// Writes an XML element and all of its children into a string formatted according to the specified options.
+///
+/// If the options specify a DOCTYPE version and the element is a `<score-partwise>` or `<score-timewise>` root,
+/// its `version` attribute is set to match.
+pub fn write_to_string(xml: &XmlElement, options: &WriteOptions) -> String {
+  let mut buffer = String::new();
+  // Writing into a string cannot fail
+  let _ = write_element(&mut buffer, xml, root_version(&xml.name, options), options, 0);
+  buffer
+}
+
+pub fn parse_to_string(xml: &XmlElement, depth: i16) -> String {
+  write_to_string(xml, &WriteOptions::from(depth >= 0))
+}
+
+/// Parses an XML document into a tree of elements that borrow from the input string wherever possible.
+pub fn parse_from_string(str: &str) -> Result<XmlElement<'_>, String> {
+  let mut remaining = str;
   let mut open_tags: Vec<XmlElement> = Vec::new();
-  while let Some(ch) = it.next() {
-    if ch == '<' {
-      match read_tag_str(&mut it) {
-        TagType::Ignored => (),
-        TagType::Opening(tag) => open_tags.push(tag),
-        TagType::SelfClosing(tag) => match open_tags.last_mut() {
-          Some(last_open_tag) => last_open_tag.elements.push(tag),
-          None => return Err(String::from("Root tag cannot be self-closing")),
-        },
-        TagType::Closing(tag) => {
-          if let Some(mut element) = open_tags.pop() {
-            element.text.truncate(element.text.trim().len());
-            if tag.name != element.name {
-              return Err(format!(
-                "Mismatched closing tag...expected '{}' but found '{}'",
-                element.name, tag.name
-              ));
-            }
-            if let Some(last_open_tag) = open_tags.last_mut() {
-              last_open_tag.elements.push(element);
-            } else {
-              return Ok(element);
-            }
+  while let Some(start) = remaining.find('<') {
+    if let Some(item) = open_tags.last_mut() {
+      append_text(&mut item.text, &remaining[..start]);
+    }
+    let (tag, length) = read_tag_str(&remaining[start + 1..]);
+    remaining = &remaining[start + 1 + length..];
+    match tag {
+      TagType::Ignored => (),
+      TagType::Opening(tag) => open_tags.push(tag),
+      TagType::SelfClosing(tag) => match open_tags.last_mut() {
+        Some(last_open_tag) => last_open_tag.elements.push(tag),
+        None => return Err(String::from("Root tag cannot be self-closing")),
+      },
+      TagType::Closing(name) => {
+        if let Some(mut element) = open_tags.pop() {
+          trim_text_end(&mut element.text);
+          if name != element.name {
+            return Err(format!(
+              "Mismatched closing tag...expected '{}' but found '{}'",
+              element.name, name
+            ));
+          }
+          if let Some(last_open_tag) = open_tags.last_mut() {
+            last_open_tag.elements.push(element);
+          } else {
+            return Ok(element);
           }
-        }
-        TagType::Done => break,
-      }
-    } else if ch != '\r' && ch != '\n' && ch != '\t' {
-      if let Some(item) = open_tags.last_mut() {
-        if !item.text.is_empty() || ch != ' ' {
-          item.text.push(ch);
         }
       }
+      TagType::Done => break,
     }
   }
   Err(String::from("Missing one or more matched tags"))
@@ -167,48 +416,48 @@ mod xml_parser_tests {
   <self-close/>
 </outer2>";
     let test_xml = XmlElement {
-      name: String::from("outer2"),
-      attributes: vec![(String::from("attr"), String::from("Test Attr"))],
+      name: Cow::from("outer2"),
+      attributes: vec![(Cow::from("attr"), Cow::from("Test Attr"))],
       elements: vec![
         XmlElement {
-          name: String::from("inner"),
+          name: Cow::from("inner"),
           attributes: vec![],
           elements: vec![],
-          text: String::from("Inner Test1"),
+          text: Cow::from("Inner Test1"),
         },
         XmlElement {
-          name: String::from("inner"),
-          attributes: vec![(String::from("test"), String::from("More Attr"))],
+          name: Cow::from("inner"),
+          attributes: vec![(Cow::from("test"), Cow::from("More Attr"))],
           elements: vec![],
-          text: String::from("Inner Test2"),
+          text: Cow::from("Inner Test2"),
         },
         XmlElement {
-          name: String::from("inner2"),
+          name: Cow::from("inner2"),
           attributes: vec![],
           elements: vec![
             XmlElement {
-              name: String::from("val1"),
+              name: Cow::from("val1"),
               attributes: vec![],
               elements: vec![],
-              text: String::from("123"),
+              text: Cow::from("123"),
             },
             XmlElement {
-              name: String::from("val2"),
+              name: Cow::from("val2"),
               attributes: vec![],
               elements: vec![],
-              text: String::from("567"),
+              text: Cow::from("567"),
             },
           ],
-          text: String::new(),
+          text: Cow::default(),
         },
         XmlElement {
-          name: String::from("self-close"),
+          name: Cow::from("self-close"),
           attributes: vec![],
           elements: vec![],
-          text: String::new(),
+          text: Cow::default(),
         },
       ],
-      text: String::new(),
+      text: Cow::default(),
     };
     let result = parse_to_string(&test_xml, 0);
     assert_eq!(result.as_str(), test_xml_str);
@@ -230,42 +479,42 @@ mod xml_parser_tests {
     assert_eq!(
       result.unwrap(),
       XmlElement {
-        name: String::from("outer2"),
-        attributes: vec![(String::from("attr"), String::from("Test Attr"))],
+        name: Cow::from("outer2"),
+        attributes: vec![(Cow::from("attr"), Cow::from("Test Attr"))],
         elements: vec![
           XmlElement {
-            name: String::from("inner"),
+            name: Cow::from("inner"),
             attributes: vec![],
             elements: vec![],
-            text: String::from("Inner Test1")
+            text: Cow::from("Inner Test1")
           },
           XmlElement {
-            name: String::from("inner"),
-            attributes: vec![(String::from("test"), String::from("More Attr"))],
+            name: Cow::from("inner"),
+            attributes: vec![(Cow::from("test"), Cow::from("More Attr"))],
             elements: vec![],
-            text: String::from("Inner Test2")
+            text: Cow::from("Inner Test2")
           },
           XmlElement {
-            name: String::from("inner2"),
+            name: Cow::from("inner2"),
             attributes: vec![],
             elements: vec![
               XmlElement {
-                name: String::from("val1"),
+                name: Cow::from("val1"),
                 attributes: vec![],
                 elements: vec![],
-                text: String::from("123")
+                text: Cow::from("123")
               },
               XmlElement {
-                name: String::from("val2"),
+                name: Cow::from("val2"),
                 attributes: vec![],
                 elements: vec![],
-                text: String::from("567")
+                text: Cow::from("567")
               },
             ],
-            text: String::new()
+            text: Cow::default()
           },
         ],
-        text: String::new()
+        text: Cow::default()
       }
     );
   }
@@ -274,23 +523,23 @@ mod xml_parser_tests {
   fn serialize_valid_unicode_str() {
     let test_xml_str = "<element><test1>Waltz in E♭ Major</test1><test2>Frédéric François Chopin</test2></element>";
     let test_xml = XmlElement {
-      name: String::from("element"),
+      name: Cow::from("element"),
       attributes: vec![],
       elements: vec![
         XmlElement {
-          name: String::from("test1"),
+          name: Cow::from("test1"),
           attributes: vec![],
           elements: vec![],
-          text: String::from("Waltz in E♭ Major"),
+          text: Cow::from("Waltz in E♭ Major"),
         },
         XmlElement {
-          name: String::from("test2"),
+          name: Cow::from("test2"),
           attributes: vec![],
           elements: vec![],
-          text: String::from("Frédéric François Chopin"),
+          text: Cow::from("Frédéric François Chopin"),
         },
       ],
-      text: String::new(),
+      text: Cow::default(),
     };
     let result = parse_to_string(&test_xml, -1);
     assert_eq!(result.as_str(), test_xml_str);
@@ -304,24 +553,74 @@ mod xml_parser_tests {
     assert_eq!(
       result.unwrap(),
       XmlElement {
-        name: String::from("element"),
+        name: Cow::from("element"),
         attributes: vec![],
         elements: vec![
           XmlElement {
-            name: String::from("test1"),
+            name: Cow::from("test1"),
             attributes: vec![],
             elements: vec![],
-            text: String::from("Waltz in E♭ Major")
+            text: Cow::from("Waltz in E♭ Major")
           },
           XmlElement {
-            name: String::from("test2"),
+            name: Cow::from("test2"),
             attributes: vec![],
             elements: vec![],
-            text: String::from("Frédéric François Chopin")
+            text: Cow::from("Frédéric François Chopin")
           },
         ],
-        text: String::new()
+        text: Cow::default()
       }
     );
   }
+
+  #[test]
+  fn deserialize_borrowed_str() {
+    let test_xml = "<?xml version=\"1.0\"?>
+<!-- A comment containing <tags> -->
+<outer attr='Single Quoted' other = \"Spaced\">
+  <inner>Borrowed text</inner>
+  <inner>Owned
+    text</inner>
+  <inner>Mixed<empty/>text </inner>
+</outer>";
+    let result = parse_from_string(test_xml).unwrap();
+    assert!(matches!(result.name, Cow::Borrowed("outer")));
+    assert_eq!(
+      result.attributes,
+      vec![
+        (Cow::from("attr"), Cow::from("Single Quoted")),
+        (Cow::from("other"), Cow::from("Spaced"))
+      ]
+    );
+    assert!(result
+      .attributes
+      .iter()
+      .all(|(key, value)| matches!(key, Cow::Borrowed(_)) && matches!(value, Cow::Borrowed(_))));
+    assert!(matches!(result.elements[0].text, Cow::Borrowed("Borrowed text")));
+    assert!(matches!(&result.elements[1].text, Cow::Owned(text) if text == "Owned    text"));
+    assert_eq!(result.elements[2].text, "Mixedtext");
+    assert_eq!(result.elements[2].elements[0].name, "empty");
+  }
+
+  #[test]
+  fn deserialize_invalid_str() {
+    assert_eq!(
+      parse_from_string("<outer><inner></outer>").unwrap_err(),
+      "Mismatched closing tag...expected 'inner' but found 'outer'"
+    );
+    assert_eq!(
+      parse_from_string("<outer/>").unwrap_err(),
+      "Root tag cannot be self-closing"
+    );
+    assert_eq!(
+      parse_from_string("<outer><inner attr=\"unterminated></inner></outer>").unwrap_err(),
+      "Missing one or more matched tags"
+    );
+  }
+
+  #[test]
+  fn optional_text_elements_sorted() {
+    assert!(OPTIONAL_TEXT_ELEMENTS.windows(2).all(|pair| pair[0] < pair[1]));
+  }
 }
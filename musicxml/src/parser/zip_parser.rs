@@ -1,254 +1,394 @@
 #![allow(dead_code)]
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+  boxed::Box,
+  collections::BTreeMap,
+  string::{String, ToString},
+  vec::Vec,
+};
+use core::fmt;
 use crc32fast;
 use miniz_oxide::deflate::compress_to_vec;
-use miniz_oxide::inflate::decompress_to_vec;
+use miniz_oxide::deflate::core::{
+  compress, create_comp_flags_from_zip_params, CompressorOxide, TDEFLFlush, TDEFLStatus,
+};
+use miniz_oxide::inflate::decompress_to_vec_with_limit;
 use musicxml_internal::bytes_to_string;
 
+use super::stream::XmlSink;
+
 #[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
-#[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
-
+const STORED_METHOD_CODE: u16 = 0;
 const DEFLATE_METHOD_CODE: u16 = 8;
-const LOCAL_FILE_HEADER_LEN: usize = core::mem::size_of::<LocalFileHeader>();
-const CENTRAL_FILE_HEADER_LEN: usize = core::mem::size_of::<CentralFileHeader>();
-const CENTRAL_DIR_END_LEN: usize = core::mem::size_of::<CentralDirEnd>();
-
-#[repr(C, packed)]
-struct LocalFileHeader {
-  signature: u32,
-  version_needed_to_extract: u16,
-  general_purpose_bit_flag: u16,
-  compression_method: u16,
-  last_mod_file_time: u16,
-  last_mod_file_date: u16,
-  crc32: u32,
-  compressed_size: u32,
-  uncompressed_size: u32,
-  file_name_length: u16,
-  extra_field_length: u16,
+const DEFAULT_COMPRESSION_LEVEL: u8 = 10;
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_FILE_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const CENTRAL_DIR_END_SIGNATURE: u32 = 0x0605_4b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x0807_4b50;
+const ZIP64_CENTRAL_DIR_END_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_CENTRAL_DIR_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+
+const LOCAL_FILE_HEADER_LEN: usize = 30;
+const CENTRAL_FILE_HEADER_LEN: usize = 46;
+const CENTRAL_DIR_END_LEN: usize = 22;
+const ZIP64_CENTRAL_DIR_END_LEN: usize = 56;
+const ZIP64_CENTRAL_DIR_LOCATOR_LEN: usize = 20;
+
+const FLAG_ENCRYPTED: u16 = 0b0000_0000_0001;
+const FLAG_DATA_DESCRIPTOR: u16 = 0b0000_0000_1000;
+const FLAG_UTF8: u16 = 0b1000_0000_0000;
+
+/// Characters 0x80 through 0xFF of code page 437, which ZIP file names use unless they are flagged as UTF-8.
+const CP437_HIGH: [char; 128] = [
+  'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û',
+  'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡',
+  '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─',
+  '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█',
+  '▄', '▌', '▐', '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±', '≥',
+  '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// Describes why a compressed archive or one of its files could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveError {
+  /// The data does not contain an end of central directory record and is therefore not a ZIP archive.
+  NotAnArchive,
+  /// A structure within the archive extends past the end of the data.
+  Truncated,
+  /// A header within the archive does not start with its expected signature.
+  InvalidSignature {
+    /// Byte offset of the invalid header.
+    offset: u64,
+  },
+  /// The archive is split across multiple disks, which is not supported.
+  MultiDisk,
+  /// The specified file does not exist within the archive.
+  FileNotFound(String),
+  /// The file is compressed using a method other than stored or DEFLATE.
+  UnsupportedCompression {
+    /// Path of the file within the archive.
+    file_name: String,
+    /// ZIP compression method code.
+    method: u16,
+  },
+  /// The file is encrypted, which is not supported.
+  Encrypted(String),
+  /// The compressed data of the file cannot be decompressed.
+  CorruptData(String),
+  /// The decompressed size of the file does not match the size recorded in the archive.
+  SizeMismatch {
+    /// Path of the file within the archive.
+    file_name: String,
+    /// Size recorded in the archive.
+    expected: u64,
+    /// Size of the decompressed data.
+    actual: u64,
+  },
+  /// The CRC-32 checksum of the file does not match the checksum recorded in the archive.
+  ChecksumMismatch {
+    /// Path of the file within the archive.
+    file_name: String,
+    /// Checksum recorded in the archive.
+    expected: u32,
+    /// Checksum of the decompressed data.
+    actual: u32,
+  },
+  /// The `META-INF/container.xml` file of an MXL archive is missing, invalid, or does not list any rootfiles.
+  InvalidContainer(String),
+  /// The archive could not be read from the file system.
+  Io(String),
 }
 
-impl LocalFileHeader {
-  pub fn len(&self) -> usize {
-    LOCAL_FILE_HEADER_LEN + self.file_name_length as usize + self.extra_field_length as usize
-  }
-
-  pub fn from_bytes(ptr: &[u8]) -> &Self {
-    unsafe { ptr.as_ptr().cast::<Self>().as_ref().unwrap_unchecked() }
+impl fmt::Display for ArchiveError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ArchiveError::NotAnArchive => write!(f, "Data does not represent a compressed archive"),
+      ArchiveError::Truncated => write!(f, "Compressed archive is truncated"),
+      ArchiveError::InvalidSignature { offset } => {
+        write!(f, "Invalid header signature at offset {offset} in compressed archive")
+      }
+      ArchiveError::MultiDisk => write!(f, "Compressed archives spanning multiple disks are not supported"),
+      ArchiveError::FileNotFound(file_name) => write!(f, "File \"{file_name}\" not found within compressed archive"),
+      ArchiveError::UnsupportedCompression { file_name, method } => {
+        write!(f, "File \"{file_name}\" uses unsupported compression method {method}")
+      }
+      ArchiveError::Encrypted(file_name) => write!(f, "File \"{file_name}\" is encrypted"),
+      ArchiveError::CorruptData(file_name) => write!(f, "File \"{file_name}\" contains corrupt compressed data"),
+      ArchiveError::SizeMismatch {
+        file_name,
+        expected,
+        actual,
+      } => write!(
+        f,
+        "File \"{file_name}\" has a size of {actual} bytes but {expected} bytes were expected"
+      ),
+      ArchiveError::ChecksumMismatch {
+        file_name,
+        expected,
+        actual,
+      } => write!(
+        f,
+        "File \"{file_name}\" has a CRC-32 of {actual:08x} but {expected:08x} was expected"
+      ),
+      ArchiveError::InvalidContainer(message) => write!(f, "Invalid MXL container: {message}"),
+      ArchiveError::Io(message) => write!(f, "{message}"),
+    }
   }
 }
 
-#[repr(C, packed)]
-struct CentralFileHeader {
-  signature: u32,
-  version_made_by: u16,
-  version_needed_to_extract: u16,
-  general_purpose_bit_flag: u16,
-  compression_method: u16,
-  last_mod_file_time: u16,
-  last_mod_file_date: u16,
-  crc32: u32,
-  compressed_size: u32,
-  uncompressed_size: u32,
-  file_name_length: u16,
-  extra_field_length: u16,
-  file_comment_length: u16,
-  disk_number_start: u16,
-  internal_file_attributes: u16,
-  external_file_attributes: u32,
-  relative_offset_of_local_header: u32,
-}
+#[cfg(feature = "std")]
+impl std::error::Error for ArchiveError {}
 
-impl CentralFileHeader {
-  pub fn len(&self) -> usize {
-    CENTRAL_FILE_HEADER_LEN
-      + self.file_name_length as usize
-      + self.extra_field_length as usize
-      + self.file_comment_length as usize
+impl From<ArchiveError> for String {
+  fn from(error: ArchiveError) -> Self {
+    error.to_string()
   }
+}
 
-  pub fn from_bytes(bytes: &[u8]) -> &Self {
-    unsafe { bytes.as_ptr().cast::<Self>().as_ref().unwrap_unchecked() }
-  }
+fn read_bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], ArchiveError> {
+  offset
+    .checked_add(len)
+    .and_then(|end| data.get(offset..end))
+    .ok_or(ArchiveError::Truncated)
 }
 
-#[repr(C, packed)]
-struct CentralDirEnd {
-  signature: u32,
-  number_of_disk: u16,
-  number_of_start_central_directory_disk: u16,
-  total_entries_this_disk: u16,
-  total_entries_all_disk: u16,
-  size_of_the_central_directory: u32,
-  central_directory_offset: u32,
-  zip_file_comment_length: u16,
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ArchiveError> {
+  read_bytes(data, offset, 2).map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
 }
 
-impl CentralDirEnd {
-  pub fn from_bytes(bytes: &[u8]) -> &Self {
-    unsafe { bytes.as_ptr().cast::<Self>().as_ref().unwrap_unchecked() }
-  }
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, ArchiveError> {
+  read_bytes(data, offset, 4).map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
 }
 
-struct LocalFile {
-  pub file_name: String,
-  pub relative_offset: usize,
-  pub compressed_size: usize,
-  pub uncompressed_size: usize,
+fn read_u64(data: &[u8], offset: usize) -> Result<u64, ArchiveError> {
+  read_bytes(data, offset, 8).map(|bytes| {
+    u64::from_le_bytes([
+      bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+    ])
+  })
 }
 
-impl LocalFile {
-  pub fn new(file_name: String, file_offset: usize, details: &LocalFileHeader) -> Self {
-    Self {
-      file_name,
-      relative_offset: file_offset,
-      compressed_size: details.compressed_size as usize,
-      uncompressed_size: details.uncompressed_size as usize,
-    }
-  }
+/// Returns the position that lies `len` bytes after `offset`, or an error if it cannot be represented.
+fn advance(offset: usize, len: usize) -> Result<usize, ArchiveError> {
+  offset.checked_add(len).ok_or(ArchiveError::Truncated)
 }
 
-pub(crate) struct ZipData {
-  index: usize,
-  pub content: Vec<u8>,
+fn to_offset(value: u64) -> Result<usize, ArchiveError> {
+  usize::try_from(value).map_err(|_| ArchiveError::Truncated)
 }
 
-impl ZipData {
-  pub fn new() -> Self {
-    Self {
-      index: 0,
-      content: Vec::new(),
-    }
+fn expect_signature(data: &[u8], offset: usize, signature: u32) -> Result<(), ArchiveError> {
+  if read_u32(data, offset)? == signature {
+    Ok(())
+  } else {
+    Err(ArchiveError::InvalidSignature { offset: offset as u64 })
   }
+}
 
-  fn read(&mut self, buf: &mut [u8]) -> usize {
-    let to_read = core::cmp::min(self.content.len() - self.index, buf.len());
-    buf[..to_read].copy_from_slice(&self.content[self.index..self.index + to_read]);
-    self.index += to_read;
-    to_read
+fn decode_file_name(bytes: &[u8], flags: u16) -> String {
+  if flags & FLAG_UTF8 != 0 {
+    String::from_utf8_lossy(bytes).into_owned()
+  } else {
+    bytes
+      .iter()
+      .map(|&byte| {
+        if byte < 0x80 {
+          byte as char
+        } else {
+          CP437_HIGH[usize::from(byte - 0x80)]
+        }
+      })
+      .collect()
   }
+}
 
-  fn read_exact(&mut self, buf: &mut [u8]) -> Option<usize> {
-    match self.read(buf) {
-      bytes_read if bytes_read == buf.len() => Some(bytes_read),
-      _ => None,
-    }
-  }
+/// Location and properties of a single file within a ZIP archive, as recorded in its central directory.
+struct LocalFile {
+  flags: u16,
+  compression_method: u16,
+  crc32: u32,
+  compressed_size: u64,
+  uncompressed_size: u64,
+  header_offset: u64,
 }
 
-struct ZipParser<'a> {
-  contents: &'a mut ZipData,
-  buffer: [u8; 128],
-  buffer_len: usize,
+/// Values from the end of central directory record, after resolving any ZIP64 extensions.
+struct CentralDirEnd {
+  total_entries: u64,
+  size: u64,
+  offset: u64,
 }
 
-impl<'a> ZipParser<'a> {
-  pub fn new(contents: &'a mut ZipData) -> Self {
-    Self {
-      contents,
-      buffer: [0; 128],
-      buffer_len: 0,
+impl CentralDirEnd {
+  fn find(data: &[u8]) -> Result<(usize, Self), ArchiveError> {
+    let end = data
+      .len()
+      .checked_sub(CENTRAL_DIR_END_LEN)
+      .ok_or(ArchiveError::NotAnArchive)?;
+    let start = end.saturating_sub(usize::from(u16::MAX));
+    let offset = (start..=end)
+      .rev()
+      .find(|&offset| {
+        read_bytes(data, offset, CENTRAL_DIR_END_LEN).is_ok_and(|record| {
+          read_u32(record, 0) == Ok(CENTRAL_DIR_END_SIGNATURE)
+            && read_u16(record, 20).is_ok_and(|len| usize::from(len) <= data.len() - offset - CENTRAL_DIR_END_LEN)
+        })
+      })
+      .ok_or(ArchiveError::NotAnArchive)?;
+    let end_record = read_bytes(data, offset, CENTRAL_DIR_END_LEN)?;
+    let mut record = Self {
+      total_entries: u64::from(read_u16(end_record, 10)?),
+      size: u64::from(read_u32(end_record, 12)?),
+      offset: u64::from(read_u32(end_record, 16)?),
+    };
+    let locator = offset
+      .checked_sub(ZIP64_CENTRAL_DIR_LOCATOR_LEN)
+      .and_then(|locator| read_bytes(data, locator, ZIP64_CENTRAL_DIR_LOCATOR_LEN).ok())
+      .filter(|locator| read_u32(locator, 0) == Ok(ZIP64_CENTRAL_DIR_LOCATOR_SIGNATURE));
+    if let Some(locator) = locator {
+      if read_u32(locator, 4)? != 0 || read_u32(locator, 16)? > 1 {
+        return Err(ArchiveError::MultiDisk);
+      }
+      let zip64_offset = to_offset(read_u64(locator, 8)?)?;
+      expect_signature(data, zip64_offset, ZIP64_CENTRAL_DIR_END_SIGNATURE)?;
+      let zip64_record = read_bytes(data, zip64_offset, ZIP64_CENTRAL_DIR_END_LEN)?;
+      if read_u32(zip64_record, 16)? != 0 || read_u32(zip64_record, 20)? != 0 {
+        return Err(ArchiveError::MultiDisk);
+      }
+      record = Self {
+        total_entries: read_u64(zip64_record, 32)?,
+        size: read_u64(zip64_record, 40)?,
+        offset: read_u64(zip64_record, 48)?,
+      };
+    } else if read_u16(end_record, 4)? != 0 || read_u16(end_record, 6)? != 0 {
+      return Err(ArchiveError::MultiDisk);
     }
+    Ok((offset, record))
   }
 }
 
-impl<'a> Iterator for ZipParser<'a> {
-  type Item = LocalFile;
-
-  fn next(&mut self) -> Option<Self::Item> {
-    // Search for a Central Directory File Entry
-    let mut entry_found = false;
-    while !entry_found {
-      match self.contents.read(&mut self.buffer[self.buffer_len..]) {
-        bytes_read if self.buffer_len + bytes_read < 4 => return None,
-        bytes_read => {
-          for i in 0..(self.buffer_len + bytes_read - 4) {
-            if self.buffer[i..i + 4] == [0x50, 0x4b, 0x01, 0x02] {
-              let len = self.buffer_len + bytes_read - i;
-              self.buffer.copy_within(i..(len + i), 0);
-              self.buffer_len = len;
-              entry_found = true;
-              break;
-            }
-          }
-          if entry_found {
-            match self.contents.read(&mut self.buffer[self.buffer_len..]) {
-              bytes_read if self.buffer_len + bytes_read < CENTRAL_FILE_HEADER_LEN => return None,
-              bytes_read => {
-                self.buffer_len += bytes_read;
-                if self.buffer[28] < 8 || self.buffer[29] > 0 {
-                  self.buffer.copy_within(4..self.buffer_len, 0);
-                  self.buffer_len -= 4;
-                  entry_found = false;
-                }
-              }
-            }
-          } else if bytes_read > 2 {
-            self.buffer.copy_within((bytes_read - 3).., 0);
-            self.buffer_len = 3;
-          } else {
-            return None;
+/// Parses every central directory entry of an archive into a map from file names to their locations.
+fn parse_central_directory(data: &[u8]) -> Result<BTreeMap<String, LocalFile>, ArchiveError> {
+  let (end_offset, end) = CentralDirEnd::find(data)?;
+  let mut offset = to_offset(end.offset)?;
+  let directory_end = offset
+    .checked_add(to_offset(end.size)?)
+    .ok_or(ArchiveError::Truncated)?;
+  if directory_end > end_offset {
+    return Err(ArchiveError::Truncated);
+  }
+  let mut file_map = BTreeMap::new();
+  for _ in 0..end.total_entries {
+    expect_signature(data, offset, CENTRAL_FILE_HEADER_SIGNATURE)?;
+    let header = read_bytes(data, offset, CENTRAL_FILE_HEADER_LEN)?;
+    let flags = read_u16(header, 8)?;
+    let name_len = usize::from(read_u16(header, 28)?);
+    let extra_len = usize::from(read_u16(header, 30)?);
+    let comment_len = usize::from(read_u16(header, 32)?);
+    let mut file = LocalFile {
+      flags,
+      compression_method: read_u16(header, 10)?,
+      crc32: read_u32(header, 16)?,
+      compressed_size: u64::from(read_u32(header, 20)?),
+      uncompressed_size: u64::from(read_u32(header, 24)?),
+      header_offset: u64::from(read_u32(header, 42)?),
+    };
+    let name_offset = advance(offset, CENTRAL_FILE_HEADER_LEN)?;
+    let name = read_bytes(data, name_offset, name_len)?;
+    let mut extra = read_bytes(data, advance(name_offset, name_len)?, extra_len)?;
+    while extra.len() >= 4 {
+      let (id, len) = (read_u16(extra, 0)?, usize::from(read_u16(extra, 2)?));
+      let field = read_bytes(extra, 4, len)?;
+      if id == ZIP64_EXTRA_FIELD_ID {
+        let mut position = 0;
+        for value in [
+          &mut file.uncompressed_size,
+          &mut file.compressed_size,
+          &mut file.header_offset,
+        ] {
+          if *value == u64::from(u32::MAX) {
+            *value = read_u64(field, position)?;
+            position += 8;
           }
         }
       }
+      extra = &extra[4 + len..];
     }
-
-    // Read the file name, header, and compression method
-    let (file_name, file_header, file_offset, compression_method, entry_length) = {
-      let file_info = CentralFileHeader::from_bytes(&self.buffer);
-      (
-        self.buffer[CENTRAL_FILE_HEADER_LEN..(CENTRAL_FILE_HEADER_LEN + file_info.file_name_length as usize)]
-          .iter()
-          .map(|&x| x as char)
-          .collect::<String>(),
-        &self.contents.content[file_info.relative_offset_of_local_header as usize..],
-        file_info.relative_offset_of_local_header as usize,
-        file_info.compression_method,
-        file_info.len(),
-      )
-    };
-
-    // Move remainder of the contents to beginning of the buffer for next iteration
-    self.buffer.copy_within(entry_length..self.buffer_len, 0);
-    self.buffer_len -= entry_length;
-
-    // Decompress the file if its compression method is DEFLATE
-    if compression_method == DEFLATE_METHOD_CODE {
-      let header = LocalFileHeader::from_bytes(file_header);
-      Some(LocalFile::new(file_name, file_offset + header.len(), header))
-    } else {
-      self.next()
+    let file_name = decode_file_name(name, flags);
+    offset = advance(name_offset, name_len + extra_len + comment_len)?;
+    if !(file_name.ends_with('/') && file.uncompressed_size == 0) {
+      file_map.insert(file_name, file);
     }
   }
+  Ok(file_map)
 }
 
-pub(crate) struct ZipArchive<'a> {
-  zip_data: &'a mut ZipData,
+pub(crate) struct ZipArchive {
+  content: Vec<u8>,
   file_map: BTreeMap<String, LocalFile>,
 }
 
-impl<'a> ZipArchive<'a> {
-  pub fn new(zip_data: &'a mut ZipData) -> Self {
-    let mut file_map = BTreeMap::new();
-    for file in ZipParser::new(zip_data) {
-      file_map.insert(file.file_name.clone(), file);
-    }
-    Self { zip_data, file_map }
+impl ZipArchive {
+  pub fn new(content: Vec<u8>) -> Result<Self, ArchiveError> {
+    let file_map = parse_central_directory(&content)?;
+    Ok(Self { content, file_map })
   }
 
-  pub fn read_file_to_string(&self, file_name: &str) -> Result<String, String> {
+  pub fn contains(&self, file_name: &str) -> bool {
+    self.file_map.contains_key(file_name)
+  }
+
+  pub fn read_file(&self, file_name: &str) -> Result<Vec<u8>, ArchiveError> {
     let file = self
       .file_map
       .get(file_name)
-      .ok_or(format!("File \"{file_name}\" not found within compressed archive"))?;
-    let decoded_data =
-      decompress_to_vec(&self.zip_data.content[file.relative_offset..(file.relative_offset + file.compressed_size)])
-        .map_err(|e| e.to_string())?;
-    bytes_to_string(&decoded_data).map_err(|e| e.to_string())
+      .ok_or_else(|| ArchiveError::FileNotFound(String::from(file_name)))?;
+    if file.flags & FLAG_ENCRYPTED != 0 {
+      return Err(ArchiveError::Encrypted(String::from(file_name)));
+    }
+    let header_offset = to_offset(file.header_offset)?;
+    expect_signature(&self.content, header_offset, LOCAL_FILE_HEADER_SIGNATURE)?;
+    let header = read_bytes(&self.content, header_offset, LOCAL_FILE_HEADER_LEN)?;
+    let data_offset = advance(
+      header_offset,
+      LOCAL_FILE_HEADER_LEN + usize::from(read_u16(header, 26)?) + usize::from(read_u16(header, 28)?),
+    )?;
+    let compressed_data = read_bytes(&self.content, data_offset, to_offset(file.compressed_size)?)?;
+    let data = match file.compression_method {
+      STORED_METHOD_CODE => compressed_data.to_vec(),
+      DEFLATE_METHOD_CODE => decompress_to_vec_with_limit(compressed_data, to_offset(file.uncompressed_size)?)
+        .map_err(|_| ArchiveError::CorruptData(String::from(file_name)))?,
+      method => {
+        return Err(ArchiveError::UnsupportedCompression {
+          file_name: String::from(file_name),
+          method,
+        })
+      }
+    };
+    if data.len() as u64 != file.uncompressed_size {
+      return Err(ArchiveError::SizeMismatch {
+        file_name: String::from(file_name),
+        expected: file.uncompressed_size,
+        actual: data.len() as u64,
+      });
+    }
+    let crc32 = crc32fast::hash(&data);
+    if crc32 != file.crc32 {
+      return Err(ArchiveError::ChecksumMismatch {
+        file_name: String::from(file_name),
+        expected: file.crc32,
+        actual: crc32,
+      });
+    }
+    Ok(data)
+  }
+
+  pub fn read_file_to_string(&self, file_name: &str) -> Result<String, String> {
+    bytes_to_string(&self.read_file(file_name)?)
   }
 
   pub fn iter(&self) -> impl Iterator<Item = &String> {
@@ -256,11 +396,92 @@ impl<'a> ZipArchive<'a> {
   }
 }
 
+/// Header fields shared by the local and central directory records of a file being written.
+struct FileHeader {
+  version_needed_to_extract: u16,
+  general_purpose_bit_flag: u16,
+  compression_method: u16,
+  last_mod_file_time: u16,
+  last_mod_file_date: u16,
+  crc32: u32,
+  compressed_size: u32,
+  uncompressed_size: u32,
+  file_name_length: u16,
+}
+
+impl FileHeader {
+  #[allow(clippy::cast_possible_truncation)]
+  fn new(file_name: &str, compression_level: Option<u8>, datetime: (u16, u16, u16, u16, u16, u16)) -> Self {
+    let (year, month, day, hour, minute, second) = datetime;
+    Self {
+      version_needed_to_extract: if compression_level.is_some() { 20 } else { 10 },
+      general_purpose_bit_flag: FLAG_UTF8,
+      compression_method: if compression_level.is_some() {
+        DEFLATE_METHOD_CODE
+      } else {
+        STORED_METHOD_CODE
+      },
+      last_mod_file_time: ZipArchiver::dostime(hour, minute, second),
+      last_mod_file_date: ZipArchiver::dosdate(year, month, day),
+      crc32: 0,
+      compressed_size: 0,
+      uncompressed_size: 0,
+      file_name_length: file_name.len() as u16,
+    }
+  }
+
+  fn write_common(&self, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&self.general_purpose_bit_flag.to_le_bytes());
+    buffer.extend_from_slice(&self.compression_method.to_le_bytes());
+    buffer.extend_from_slice(&self.last_mod_file_time.to_le_bytes());
+    buffer.extend_from_slice(&self.last_mod_file_date.to_le_bytes());
+    buffer.extend_from_slice(&self.crc32.to_le_bytes());
+    buffer.extend_from_slice(&self.compressed_size.to_le_bytes());
+    buffer.extend_from_slice(&self.uncompressed_size.to_le_bytes());
+    buffer.extend_from_slice(&self.file_name_length.to_le_bytes());
+    buffer.extend_from_slice(&0_u16.to_le_bytes());
+  }
+
+  fn write_local(&self, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+    buffer.extend_from_slice(&self.version_needed_to_extract.to_le_bytes());
+    self.write_common(buffer);
+  }
+
+  fn write_central(&self, buffer: &mut Vec<u8>, offset: u32) {
+    buffer.extend_from_slice(&CENTRAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+    buffer.extend_from_slice(&self.version_needed_to_extract.to_le_bytes());
+    buffer.extend_from_slice(&self.version_needed_to_extract.to_le_bytes());
+    self.write_common(buffer);
+    buffer.extend_from_slice(&[0; 10]);
+    buffer.extend_from_slice(&offset.to_le_bytes());
+  }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn write_central_directory(buffer: &mut Vec<u8>, files: &[(String, FileHeader, usize)], directory_offset: usize) {
+  let directory_start = buffer.len();
+  for (file_name, header, file_offset) in files {
+    header.write_central(buffer, *file_offset as u32);
+    buffer.extend_from_slice(file_name.as_bytes());
+  }
+  let directory_size = buffer.len() - directory_start;
+  buffer.extend_from_slice(&CENTRAL_DIR_END_SIGNATURE.to_le_bytes());
+  buffer.extend_from_slice(&[0; 4]);
+  buffer.extend_from_slice(&(files.len() as u16).to_le_bytes());
+  buffer.extend_from_slice(&(files.len() as u16).to_le_bytes());
+  buffer.extend_from_slice(&(directory_size as u32).to_le_bytes());
+  buffer.extend_from_slice(&(directory_offset as u32).to_le_bytes());
+  buffer.extend_from_slice(&[0; 2]);
+}
+
 pub struct ZipArchiver {
   content: Vec<u8>,
-  files: Vec<(String, LocalFileHeader, usize)>,
-  open_file: Option<(String, LocalFileHeader, Vec<u8>)>,
+  files: Vec<(String, FileHeader, usize)>,
+  open_file: Option<(String, FileHeader, Vec<u8>)>,
+  open_file_level: u8,
   current_offset: usize,
+  datetime: Option<(u16, u16, u16, u16, u16, u16)>,
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -270,10 +491,16 @@ impl ZipArchiver {
       content: Vec::new(),
       files: Vec::new(),
       open_file: None,
+      open_file_level: DEFAULT_COMPRESSION_LEVEL,
       current_offset: 0,
+      datetime: None,
     }
   }
 
+  pub fn set_datetime(&mut self, year: u16, month: u16, day: u16, hour: u16, minute: u16, second: u16) {
+    self.datetime = Some((year, month, day, hour, minute, second));
+  }
+
   #[cfg(feature = "std")]
   fn current_datetime() -> (u16, u16, u16, u16, u16, u16) {
     let now = SystemTime::now()
@@ -302,24 +529,22 @@ impl ZipArchiver {
   }
 
   fn dostime(hour: u16, minute: u16, second: u16) -> u16 {
-    let time = ((hour & 0b1_1111) << 11) | ((minute & 0b11_1111) << 5) | ((second / 2) & 0b1_1111);
-    (time >> 8) | ((time & 0x00FF) << 8)
+    ((hour & 0b1_1111) << 11) | ((minute & 0b11_1111) << 5) | ((second / 2) & 0b1_1111)
   }
 
   fn dosdate(year: u16, month: u16, day: u16) -> u16 {
-    let date = ((year - 1980) << 9) | ((month & 0b1111) << 5) | (day & 0b1_1111);
-    (date >> 8) | ((date & 0x00FF) << 8)
+    ((year.saturating_sub(1980) & 0b111_1111) << 9) | ((month & 0b1111) << 5) | (day & 0b1_1111)
   }
 
   fn finish_open_file(&mut self) {
     if let Some((file_name, mut header, mut data)) = self.open_file.take() {
       header.crc32 = crc32fast::hash(data.as_slice());
       header.uncompressed_size = data.len() as u32;
-      data = compress_to_vec(data.as_slice(), 10);
+      if header.compression_method == DEFLATE_METHOD_CODE {
+        data = compress_to_vec(data.as_slice(), self.open_file_level);
+      }
       header.compressed_size = data.len() as u32;
-      self.content.extend_from_slice(unsafe {
-        core::slice::from_raw_parts(core::ptr::from_ref(&header).cast::<u8>(), LOCAL_FILE_HEADER_LEN)
-      });
+      header.write_local(&mut self.content);
       self.content.extend_from_slice(file_name.as_bytes());
       self.content.append(&mut data);
       self.files.push((file_name, header, self.current_offset));
@@ -328,23 +553,14 @@ impl ZipArchiver {
   }
 
   pub fn start_file(&mut self, file_name: &str) {
+    self.start_file_with_compression(file_name, Some(DEFAULT_COMPRESSION_LEVEL));
+  }
+
+  pub fn start_file_with_compression(&mut self, file_name: &str, compression_level: Option<u8>) {
     self.finish_open_file();
-    let file_name_bytes = file_name.as_bytes();
-    let file_name_len = file_name_bytes.len();
-    let (year, month, day, hour, minute, second) = Self::current_datetime();
-    let header = LocalFileHeader {
-      signature: 0x0403_4b50,
-      version_needed_to_extract: 20,
-      general_purpose_bit_flag: 0b1000_0000_0000,
-      compression_method: DEFLATE_METHOD_CODE,
-      last_mod_file_time: Self::dostime(hour, minute, second),
-      last_mod_file_date: Self::dosdate(year, month, day),
-      crc32: 0,
-      compressed_size: 0,
-      uncompressed_size: 0,
-      file_name_length: file_name_len as u16,
-      extra_field_length: 0,
-    };
+    self.open_file_level = compression_level.unwrap_or(0);
+    let datetime = self.datetime.unwrap_or_else(Self::current_datetime);
+    let header = FileHeader::new(file_name, compression_level, datetime);
     self.open_file = Some((String::from(file_name), header, Vec::new()));
   }
 
@@ -356,67 +572,354 @@ impl ZipArchiver {
 
   pub fn finish(&mut self) -> Vec<u8> {
     self.finish_open_file();
-    for (file_name, header, file_offset) in &self.files {
-      let header = CentralFileHeader {
-        signature: 0x0201_4b50,
-        version_made_by: header.version_needed_to_extract,
-        version_needed_to_extract: header.version_needed_to_extract,
-        general_purpose_bit_flag: header.general_purpose_bit_flag,
-        compression_method: header.compression_method,
-        last_mod_file_time: header.last_mod_file_time,
-        last_mod_file_date: header.last_mod_file_date,
-        crc32: header.crc32,
-        compressed_size: header.compressed_size,
-        uncompressed_size: header.uncompressed_size,
-        file_name_length: header.file_name_length,
-        extra_field_length: 0,
-        file_comment_length: 0,
-        disk_number_start: 0,
-        internal_file_attributes: 0,
-        external_file_attributes: 0,
-        relative_offset_of_local_header: *file_offset as u32,
-      };
-      let header_bytes =
-        unsafe { core::slice::from_raw_parts(core::ptr::from_ref(&header).cast::<u8>(), CENTRAL_FILE_HEADER_LEN) };
-      self.content.extend_from_slice(header_bytes);
-      self.content.extend_from_slice(file_name.as_bytes());
+    write_central_directory(&mut self.content, &self.files, self.current_offset);
+    self.content.clone()
+  }
+}
+
+struct StreamedFile {
+  file_name: String,
+  header: FileHeader,
+  offset: usize,
+  hasher: crc32fast::Hasher,
+  compressor: Box<CompressorOxide>,
+}
+
+/// Writes a ZIP archive directly into a sink without holding the archive in memory.
+///
+/// Files whose contents are streamed are compressed as they are written and followed by a data descriptor holding
+/// their checksum and sizes, so only the central directory is kept until the archive is finished.
+pub struct ZipStreamer<S: XmlSink> {
+  sink: S,
+  files: Vec<(String, FileHeader, usize)>,
+  open_file: Option<StreamedFile>,
+  buffer: Vec<u8>,
+  current_offset: usize,
+  datetime: Option<(u16, u16, u16, u16, u16, u16)>,
+}
+
+impl<S: XmlSink> ZipStreamer<S> {
+  pub fn new(sink: S) -> Self {
+    Self {
+      sink,
+      files: Vec::new(),
+      open_file: None,
+      buffer: vec![0; 1 << 15],
+      current_offset: 0,
+      datetime: None,
     }
-    let central_dir_end = CentralDirEnd {
-      signature: 0x0605_4b50,
-      number_of_disk: 0,
-      number_of_start_central_directory_disk: 0,
-      total_entries_this_disk: self.files.len() as u16,
-      total_entries_all_disk: self.files.len() as u16,
-      size_of_the_central_directory: (self.content.len() - self.current_offset) as u32,
-      central_directory_offset: self.current_offset as u32,
-      zip_file_comment_length: 0,
+  }
+
+  pub fn set_datetime(&mut self, year: u16, month: u16, day: u16, hour: u16, minute: u16, second: u16) {
+    self.datetime = Some((year, month, day, hour, minute, second));
+  }
+
+  fn write(&mut self, data: &[u8]) -> Result<(), String> {
+    self.current_offset += data.len();
+    self.sink.write_bytes(data)
+  }
+
+  fn file_header(&self, file_name: &str, compression_level: Option<u8>) -> FileHeader {
+    FileHeader::new(
+      file_name,
+      compression_level,
+      self.datetime.unwrap_or_else(ZipArchiver::current_datetime),
+    )
+  }
+
+  /// Writes a complete file whose contents are already known.
+  pub fn add_file(&mut self, file_name: &str, data: &[u8], compression_level: Option<u8>) -> Result<(), String> {
+    self.finish_file()?;
+    let mut header = self.file_header(file_name, compression_level);
+    header.crc32 = crc32fast::hash(data);
+    header.uncompressed_size = to_size(data.len())?;
+    let compressed = compression_level.map(|level| compress_to_vec(data, level));
+    let data = compressed.as_deref().unwrap_or(data);
+    header.compressed_size = to_size(data.len())?;
+    let offset = self.current_offset;
+    let mut local = Vec::new();
+    header.write_local(&mut local);
+    local.extend_from_slice(file_name.as_bytes());
+    self.write(&local)?;
+    self.write(data)?;
+    self.files.push((String::from(file_name), header, offset));
+    Ok(())
+  }
+
+  /// Starts a new deflated file whose contents are provided by subsequent calls to [ZipStreamer::write_data].
+  pub fn start_file(&mut self, file_name: &str, compression_level: u8) -> Result<(), String> {
+    self.finish_file()?;
+    let mut header = self.file_header(file_name, Some(compression_level));
+    header.general_purpose_bit_flag |= FLAG_DATA_DESCRIPTOR;
+    let offset = self.current_offset;
+    let mut local = Vec::new();
+    header.write_local(&mut local);
+    local.extend_from_slice(file_name.as_bytes());
+    self.write(&local)?;
+    self.open_file = Some(StreamedFile {
+      file_name: String::from(file_name),
+      header,
+      offset,
+      hasher: crc32fast::Hasher::new(),
+      compressor: Box::new(CompressorOxide::new(create_comp_flags_from_zip_params(
+        i32::from(compression_level),
+        0,
+        0,
+      ))),
+    });
+    Ok(())
+  }
+
+  fn deflate(&mut self, mut data: &[u8], flush: TDEFLFlush) -> Result<(), String> {
+    let Some(file) = self.open_file.as_mut() else {
+      return Ok(());
     };
-    let central_dir_end_bytes =
-      unsafe { core::slice::from_raw_parts(core::ptr::from_ref(&central_dir_end).cast::<u8>(), CENTRAL_DIR_END_LEN) };
-    self.content.extend_from_slice(central_dir_end_bytes);
-    self.content.clone()
+    loop {
+      let (status, consumed, produced) = compress(&mut file.compressor, data, &mut self.buffer, flush);
+      data = &data[consumed..];
+      file.header.compressed_size = to_size(file.header.compressed_size as usize + produced)?;
+      self.current_offset += produced;
+      self.sink.write_bytes(&self.buffer[..produced])?;
+      match status {
+        TDEFLStatus::Done => return Ok(()),
+        TDEFLStatus::Okay if flush != TDEFLFlush::Finish && data.is_empty() && produced < self.buffer.len() => {
+          return Ok(());
+        }
+        TDEFLStatus::Okay => (),
+        _ => return Err(format!("Unable to compress file \"{}\"", file.file_name)),
+      }
+    }
+  }
+
+  /// Appends data to the file started by [ZipStreamer::start_file].
+  pub fn write_data(&mut self, data: &[u8]) -> Result<(), String> {
+    if let Some(file) = self.open_file.as_mut() {
+      file.hasher.update(data);
+      file.header.uncompressed_size = to_size(file.header.uncompressed_size as usize + data.len())?;
+      self.deflate(data, TDEFLFlush::None)?;
+    }
+    Ok(())
+  }
+
+  fn finish_file(&mut self) -> Result<(), String> {
+    self.deflate(&[], TDEFLFlush::Finish)?;
+    if let Some(mut file) = self.open_file.take() {
+      file.header.crc32 = file.hasher.finalize();
+      let mut descriptor = Vec::new();
+      descriptor.extend_from_slice(&DATA_DESCRIPTOR_SIGNATURE.to_le_bytes());
+      descriptor.extend_from_slice(&file.header.crc32.to_le_bytes());
+      descriptor.extend_from_slice(&file.header.compressed_size.to_le_bytes());
+      descriptor.extend_from_slice(&file.header.uncompressed_size.to_le_bytes());
+      self.write(&descriptor)?;
+      self.files.push((file.file_name, file.header, file.offset));
+    }
+    Ok(())
+  }
+
+  /// Writes the central directory and returns the sink holding the completed archive.
+  pub fn finish(mut self) -> Result<S, String> {
+    self.finish_file()?;
+    to_size(self.current_offset)?;
+    let mut directory = Vec::new();
+    write_central_directory(&mut directory, &self.files, self.current_offset);
+    self.sink.write_bytes(&directory)?;
+    Ok(self.sink)
   }
 }
 
+impl<S: XmlSink> XmlSink for ZipStreamer<S> {
+  fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), String> {
+    self.write_data(bytes)
+  }
+}
+
+fn to_size(size: usize) -> Result<u32, String> {
+  u32::try_from(size).map_err(|_| String::from("Compressed archive is too large to be written"))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::parser::parse_score_partwise_from_data;
   use std::io::Read;
 
+  const FIXTURES: [&str; 5] = ["infozip", "bsdtar", "descriptors", "zip64", "cp437"];
+
+  fn fixture(name: &str) -> Vec<u8> {
+    std::fs::read(format!("tests/archives/{name}.mxl")).unwrap()
+  }
+
+  fn central_header_offset(data: &[u8]) -> usize {
+    data.windows(4).position(|window| window == b"PK\x01\x02").unwrap()
+  }
+
   #[test]
   fn test_zip_parser() {
-    let mut zip_data = ZipData::new();
+    let mut zip_data = Vec::new();
     std::fs::File::open("tests/Grande Valse Brillante.mxl")
       .unwrap()
-      .read_to_end(&mut zip_data.content)
+      .read_to_end(&mut zip_data)
       .unwrap_or(0);
-    let zip_archive = ZipArchive::new(&mut zip_data);
+    let zip_archive = ZipArchive::new(zip_data).unwrap();
     for item in zip_archive.iter() {
       println!("File: {}", item);
       println!("Content: {}", zip_archive.read_file_to_string(item).unwrap());
     }
   }
 
+  #[test]
+  fn test_zip_fixtures() {
+    for name in FIXTURES {
+      let archive = ZipArchive::new(fixture(name)).unwrap();
+      let score_name = if name == "cp437" {
+        "café.musicxml"
+      } else {
+        "score.musicxml"
+      };
+      let mut expected = ["META-INF/container.xml", "mimetype", score_name];
+      expected.sort_unstable();
+      assert_eq!(
+        archive.iter().map(String::as_str).collect::<Vec<_>>(),
+        expected,
+        "{name}"
+      );
+      assert_eq!(
+        archive.read_file("mimetype").unwrap(),
+        b"application/vnd.recordare.musicxml"
+      );
+      assert!(archive
+        .read_file_to_string(score_name)
+        .unwrap()
+        .contains("<work-title>Archive Test</work-title>"));
+      let score = crate::parser::parse_score_partwise_from_data(fixture(name)).unwrap();
+      assert_eq!(score.content.part.len(), 1, "{name}");
+    }
+  }
+
+  #[test]
+  fn test_zip_errors() {
+    let data = fixture("infozip");
+    let archive = |data: Vec<u8>| ZipArchive::new(data).unwrap();
+    assert!(matches!(
+      archive(data.clone()).read_file("missing.xml"),
+      Err(ArchiveError::FileNotFound(_))
+    ));
+    let mut corrupt = data.clone();
+    corrupt[38] ^= 0xFF;
+    assert!(matches!(
+      archive(corrupt).read_file("mimetype"),
+      Err(ArchiveError::ChecksumMismatch { .. })
+    ));
+    let (mut unsupported, mut encrypted) = (data.clone(), data.clone());
+    unsupported[central_header_offset(&data) + 10] = 12;
+    encrypted[central_header_offset(&data) + 8] |= 1;
+    assert!(matches!(
+      archive(unsupported).read_file("mimetype"),
+      Err(ArchiveError::UnsupportedCompression { method: 12, .. })
+    ));
+    assert!(matches!(
+      archive(encrypted).read_file("mimetype"),
+      Err(ArchiveError::Encrypted(_))
+    ));
+    let mut resized = data.clone();
+    resized[central_header_offset(&data) + 24] += 1;
+    assert!(matches!(
+      archive(resized).read_file("mimetype"),
+      Err(ArchiveError::SizeMismatch {
+        expected: 35,
+        actual: 34,
+        ..
+      })
+    ));
+    let mut moved = data.clone();
+    moved[central_header_offset(&data) + 42] = 1;
+    assert!(matches!(
+      archive(moved).read_file("mimetype"),
+      Err(ArchiveError::InvalidSignature { offset: 1 })
+    ));
+    let mut zip64 = fixture("zip64");
+    let locator = zip64
+      .windows(4)
+      .rposition(|bytes| bytes == ZIP64_CENTRAL_DIR_LOCATOR_SIGNATURE.to_le_bytes())
+      .unwrap();
+    zip64[locator + 8..locator + 16].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(ZipArchive::new(zip64.clone()), Err(ArchiveError::Truncated)));
+    assert!(parse_score_partwise_from_data(zip64).is_err());
+    assert!(matches!(ZipArchive::new(Vec::new()), Err(ArchiveError::NotAnArchive)));
+    assert!(matches!(
+      ZipArchive::new(b"PK\x03\x04 not really an archive".to_vec()),
+      Err(ArchiveError::NotAnArchive)
+    ));
+    assert!(matches!(
+      ZipArchive::new(data[central_header_offset(&data)..].to_vec()),
+      Err(ArchiveError::Truncated)
+    ));
+  }
+
+  #[test]
+  fn test_zip_fuzz() {
+    let mut seed: u32 = 0x1234_5678;
+    let mut random = move || {
+      seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+      (seed >> 8) as usize
+    };
+    for name in FIXTURES {
+      let data = fixture(name);
+      let mut inputs: Vec<Vec<u8>> = (0..data.len()).map(|len| data[..len].to_vec()).collect();
+      for _ in 0..500 {
+        let mut mutated = data.clone();
+        for _ in 0..1 + random() % 4 {
+          let index = random() % mutated.len();
+          mutated[index] = random() as u8;
+        }
+        inputs.push(mutated);
+      }
+      for input in inputs {
+        if let Ok(archive) = ZipArchive::new(input.clone()) {
+          let names: Vec<String> = archive.iter().cloned().collect();
+          for name in names {
+            let _ = archive.read_file(&name);
+          }
+        }
+        let _ = crate::parser::parse_score_partwise_from_data(input);
+      }
+    }
+  }
+
+  #[test]
+  fn test_zip_roundtrip() {
+    let mut archiver = ZipArchiver::new();
+    archiver.set_datetime(2024, 2, 29, 23, 59, 58);
+    archiver.start_file_with_compression("stored.txt", None);
+    archiver.write_data(b"Stored data");
+    archiver.start_file("Ünïcödé.txt");
+    archiver.write_data(&[b'x'; 1000]);
+    let archive = ZipArchive::new(archiver.finish()).unwrap();
+    assert_eq!(archive.read_file("stored.txt").unwrap(), b"Stored data");
+    assert_eq!(archive.read_file("Ünïcödé.txt").unwrap(), [b'x'; 1000]);
+  }
+
+  #[test]
+  fn test_zip_streamer() {
+    let large: Vec<u8> = (0..200_000_u32).flat_map(|value| value.to_le_bytes()).collect();
+    let mut streamer = ZipStreamer::new(Vec::new());
+    streamer.set_datetime(2024, 2, 29, 23, 59, 58);
+    streamer.add_file("stored.txt", b"Stored data", None).unwrap();
+    streamer.add_file("deflated.txt", &[b'x'; 1000], Some(6)).unwrap();
+    streamer.start_file("streamed.bin", DEFAULT_COMPRESSION_LEVEL).unwrap();
+    for chunk in large.chunks(7919) {
+      streamer.write_data(chunk).unwrap();
+    }
+    streamer.start_file("empty.txt", DEFAULT_COMPRESSION_LEVEL).unwrap();
+    let archive = ZipArchive::new(streamer.finish().unwrap()).unwrap();
+    assert_eq!(archive.file_map["stored.txt"].flags & FLAG_DATA_DESCRIPTOR, 0);
+    assert_ne!(archive.file_map["streamed.bin"].flags & FLAG_DATA_DESCRIPTOR, 0);
+    assert_eq!(archive.read_file("stored.txt").unwrap(), b"Stored data");
+    assert_eq!(archive.read_file("deflated.txt").unwrap(), [b'x'; 1000]);
+    assert_eq!(archive.read_file("streamed.bin").unwrap(), large);
+    assert!(archive.read_file("empty.txt").unwrap().is_empty());
+  }
+
   #[test]
   fn test_zip_creator() {
     let mut archiver = ZipArchiver::new();
@@ -0,0 +1,552 @@
+use crate::elements::{ScorePartwise, ScoreTimewise};
+use crate::visit::{ContextTracker, VisitContext, Walk, WalkElement, Walker};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt;
+
+/// An element matched by a [Query], along with its location within the score.
+#[derive(Clone)]
+pub struct QueryMatch<'a> {
+  /// The name of the matched element, such as `note` or `staccato`.
+  pub name: &'static str,
+  /// The location of the matched element within the score.
+  pub context: VisitContext,
+  element: &'a dyn WalkElement,
+}
+
+impl<'a> QueryMatch<'a> {
+  /// Returns the matched element so that it can be downcast to its concrete type.
+  #[must_use]
+  pub fn element(&self) -> &'a dyn Any {
+    self.element
+  }
+
+  /// Returns the matched element as a reference to the concrete type `T`, or `None` if it is of a different type.
+  #[must_use]
+  pub fn get<T: Any>(&self) -> Option<&'a T> {
+    self.element().downcast_ref::<T>()
+  }
+}
+
+impl fmt::Debug for QueryMatch<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("QueryMatch")
+      .field("name", &self.name)
+      .field("context", &self.context)
+      .finish_non_exhaustive()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Comparison {
+  negated: bool,
+  value: String,
+}
+
+impl Comparison {
+  fn test(comparison: Option<&Comparison>, value: &str) -> bool {
+    comparison.is_none_or(|comparison| (comparison.value == value) != comparison.negated)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Predicate {
+  Attribute(String, Option<Comparison>),
+  Number(i64, i64),
+  Path(Vec<Step>, Option<Comparison>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+  name: String,
+  descendant: bool,
+  predicates: Vec<Predicate>,
+}
+
+impl Step {
+  fn matches(&self, name: &str, element: &dyn WalkElement) -> bool {
+    (self.name == "*" || self.name == name)
+      && self.predicates.iter().all(|predicate| match predicate {
+        Predicate::Attribute(attribute, comparison) => element
+          .attributes()
+          .iter()
+          .any(|(key, value)| key == attribute && Comparison::test(comparison.as_ref(), value)),
+        Predicate::Number(min, max) => element.attributes().iter().any(|(key, value)| {
+          key == "number" && value.parse::<i64>().is_ok_and(|number| (*min..=*max).contains(&number))
+        }),
+        Predicate::Path(steps, comparison) => {
+          let mut matcher = Matcher::new(steps, None);
+          element.walk(&mut matcher);
+          matcher
+            .matches
+            .iter()
+            .any(|found| Comparison::test(comparison.as_ref(), &found.element.text()))
+        }
+      })
+  }
+}
+
+/// Walks an element tree while tracking which query steps remain to be matched below each open element.
+struct Matcher<'a, 's> {
+  steps: &'s [Step],
+  pending: Vec<Vec<usize>>,
+  tracker: Option<ContextTracker>,
+  matches: Vec<QueryMatch<'a>>,
+}
+
+impl<'s> Matcher<'_, 's> {
+  fn new(steps: &'s [Step], tracker: Option<ContextTracker>) -> Self {
+    Matcher {
+      steps,
+      pending: Vec::new(),
+      tracker,
+      matches: Vec::new(),
+    }
+  }
+}
+
+impl<'a> Walker<'a> for Matcher<'a, '_> {
+  fn enter(&mut self, name: &'static str, element: &'a dyn WalkElement) {
+    if let Some(tracker) = &mut self.tracker {
+      tracker.enter(element);
+    }
+    let Some(parent) = self.pending.last() else {
+      // The root element itself is never matched, only its descendants
+      self.pending.push(vec![0]);
+      return;
+    };
+    let mut pending = Vec::new();
+    let mut matched = false;
+    for &index in parent {
+      let step = &self.steps[index];
+      if step.descendant && !pending.contains(&index) {
+        pending.push(index);
+      }
+      if step.matches(name, element) {
+        if index + 1 == self.steps.len() {
+          matched = true;
+        } else if !pending.contains(&(index + 1)) {
+          pending.push(index + 1);
+        }
+      }
+    }
+    if matched {
+      self.matches.push(QueryMatch {
+        name,
+        context: self
+          .tracker
+          .as_ref()
+          .map_or_else(VisitContext::default, |tracker| tracker.context.clone()),
+        element,
+      });
+    }
+    self.pending.push(pending);
+  }
+
+  fn leave(&mut self, _name: &'static str, element: &'a dyn WalkElement) {
+    self.pending.pop();
+    if let Some(tracker) = &mut self.tracker {
+      tracker.leave(element);
+    }
+  }
+}
+
+/// Parses query strings one character at a time.
+struct QueryParser<'q> {
+  text: &'q str,
+  position: usize,
+}
+
+impl QueryParser<'_> {
+  fn rest(&self) -> &str {
+    &self.text[self.position..]
+  }
+
+  fn error(&self, reason: &str) -> String {
+    format!(
+      "Invalid query \"{}\": {reason} at position {}",
+      self.text, self.position
+    )
+  }
+
+  fn eat(&mut self, token: &str) -> bool {
+    if self.rest().starts_with(token) {
+      self.position += token.len();
+      true
+    } else {
+      false
+    }
+  }
+
+  fn skip_whitespace(&mut self) {
+    self.position = self.text.len() - self.rest().trim_start().len();
+  }
+
+  fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+    let start = self.position;
+    self.position += self.rest().find(|c: char| !predicate(c)).unwrap_or(self.rest().len());
+    &self.text[start..self.position]
+  }
+
+  fn name(&mut self) -> Result<String, String> {
+    if self.eat("*") {
+      return Ok(String::from("*"));
+    }
+    let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == ':');
+    if name.is_empty() {
+      Err(self.error("expected an element name"))
+    } else {
+      Ok(name.to_string())
+    }
+  }
+
+  fn number(&mut self) -> Result<i64, String> {
+    let digits = self.take_while(|c| c.is_ascii_digit());
+    digits.parse::<i64>().map_err(|_| self.error("expected a number"))
+  }
+
+  fn comparison(&mut self) -> Result<Option<Comparison>, String> {
+    self.skip_whitespace();
+    let negated = if self.eat("!=") {
+      true
+    } else if self.eat("=") {
+      false
+    } else {
+      return Ok(None);
+    };
+    self.skip_whitespace();
+    let value = if let Some(quote) = self.rest().chars().next().filter(|&c| c == '"' || c == '\'') {
+      self.position += 1;
+      let value = self.take_while(|c| c != quote).to_string();
+      if !self.eat(&quote.to_string()) {
+        return Err(self.error("unterminated quoted value"));
+      }
+      value
+    } else {
+      self.take_while(|c| c != ']').trim_end().to_string()
+    };
+    Ok(Some(Comparison { negated, value }))
+  }
+
+  fn predicate(&mut self) -> Result<Predicate, String> {
+    self.skip_whitespace();
+    let predicate = if self.eat("@") {
+      let name = self.name()?;
+      Predicate::Attribute(name, self.comparison()?)
+    } else if self.rest().starts_with(|c: char| c.is_ascii_digit()) {
+      let min = self.number()?;
+      let max = if self.eat("..") { self.number()? } else { min };
+      if max < min {
+        return Err(self.error("empty number range"));
+      }
+      Predicate::Number(min, max)
+    } else {
+      let steps = self.steps()?;
+      Predicate::Path(steps, self.comparison()?)
+    };
+    self.skip_whitespace();
+    if self.eat("]") {
+      Ok(predicate)
+    } else {
+      Err(self.error("expected \"]\""))
+    }
+  }
+
+  fn steps(&mut self) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::new();
+    let mut descendant = self.eat("//");
+    loop {
+      let name = self.name()?;
+      let mut predicates = Vec::new();
+      while self.eat("[") {
+        predicates.push(self.predicate()?);
+      }
+      steps.push(Step {
+        name,
+        descendant,
+        predicates,
+      });
+      if self.eat("//") {
+        descendant = true;
+      } else if self.eat("/") {
+        descendant = false;
+      } else {
+        return Ok(steps);
+      }
+    }
+  }
+}
+
+/// A compiled path query that selects elements from a score.
+///
+/// Queries are written as a `/`-separated path of element names, starting from the children of the element being
+/// queried, where `*` matches any element and `//` matches elements at any depth below the preceding step. A leading
+/// `/` is ignored. Each step may be followed by any number of predicates in square brackets, all of which must hold:
+///
+/// - `[@name]` requires the attribute `name` to be present, and `[@name=value]` or `[@name!=value]` compares its value
+/// - `[path]` requires a matching descendant element, and `[path=value]` or `[path!=value]` compares its text
+/// - `[10]` and `[10..20]` require the `number` attribute to be equal to a number or to lie within an inclusive range
+///
+/// Values may optionally be quoted using single or double quotes.
+///
+/// # Example
+///
+/// ```no_run
+/// use musicxml::elements::Note;
+/// use musicxml::read_score_partwise;
+///
+/// let score = read_score_partwise("path/to/file.musicxml").unwrap();
+/// let query = "part[@id=P2]/measure[10..20]/note[voice=2][notations/articulations/staccato]";
+/// for found in score.query(query).unwrap() {
+///   let note: &Note = found.get().unwrap();
+///   println!("Staccato note in measure {:?} at onset {}", found.context.measure_number, found.context.onset);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+  steps: Vec<Step>,
+}
+
+impl Query {
+  /// Compiles a query from its textual path representation.
+  ///
+  /// # Errors
+  ///
+  /// If the path is not a valid query, an error message describing the problem will be returned.
+  pub fn new(path: &str) -> Result<Self, String> {
+    let mut parser = QueryParser {
+      text: path,
+      position: 0,
+    };
+    parser.skip_whitespace();
+    if !parser.rest().starts_with("//") {
+      parser.eat("/");
+    }
+    let steps = parser.steps()?;
+    parser.skip_whitespace();
+    if parser.rest().is_empty() {
+      Ok(Query { steps })
+    } else {
+      Err(parser.error("unexpected character"))
+    }
+  }
+
+  /// Returns every element below `root` that matches the query, in document order.
+  #[must_use]
+  pub fn run<'a, T: Walk + ?Sized>(&self, root: &'a T) -> Vec<QueryMatch<'a>> {
+    let mut matcher = Matcher::new(&self.steps, Some(ContextTracker::default()));
+    root.walk(&mut matcher);
+    matcher.matches
+  }
+}
+
+/// Returns every element below `root` that matches the query `path`, in document order.
+///
+/// See [Query] for a description of the query syntax.
+///
+/// # Errors
+///
+/// If the path is not a valid query, an error message describing the problem will be returned.
+pub fn query<'a, T: Walk + ?Sized>(root: &'a T, path: &str) -> Result<Vec<QueryMatch<'a>>, String> {
+  Ok(Query::new(path)?.run(root))
+}
+
+impl ScorePartwise {
+  /// Returns every element of the score that matches the query `path`, in document order.
+  ///
+  /// Paths start from the children of the `<score-partwise>` element, for example
+  /// `part[@id=P1]/measure[1..4]/note[voice=1]`. See [Query] for a description of the query syntax.
+  ///
+  /// # Errors
+  ///
+  /// If the path is not a valid query, an error message describing the problem will be returned.
+  pub fn query(&self, path: &str) -> Result<Vec<QueryMatch<'_>>, String> {
+    query(self, path)
+  }
+}
+
+impl ScoreTimewise {
+  /// Returns every element of the score that matches the query `path`, in document order.
+  ///
+  /// Paths start from the children of the `<score-timewise>` element, for example
+  /// `measure[1..4]/part[@id=P1]/note[voice=1]`. See [Query] for a description of the query syntax.
+  ///
+  /// # Errors
+  ///
+  /// If the path is not a valid query, an error message describing the problem will be returned.
+  pub fn query(&self, path: &str) -> Result<Vec<QueryMatch<'_>>, String> {
+    query(self, path)
+  }
+}
+
+#[cfg(test)]
+mod query_tests {
+  use super::*;
+  use crate::elements::{Fermata, Note, Pitch, Step as StepElement};
+  use crate::parser::{parse_from_xml_str, parse_score_partwise_from_file, parse_score_timewise_from_data};
+  use crate::util::note_pitch;
+  use musicxml_internal::{ElementSerializer, XmlElement};
+
+  const SCORE: &str = r#"<score-partwise version="4.0">
+    <part-list>
+      <score-part id="P1"><part-name>Flute</part-name></score-part>
+      <score-part id="P2"><part-name>Piano</part-name></score-part>
+    </part-list>
+    <part id="P1">
+      <measure number="1">
+        <note><pitch><step>C</step><octave>5</octave></pitch><duration>4</duration><voice>2</voice>
+          <notations><articulations><staccato/></articulations></notations></note>
+      </measure>
+    </part>
+    <part id="P2">
+      <measure number="1">
+        <note><pitch><step>D</step><octave>4</octave></pitch><duration>4</duration><voice>2</voice>
+          <notations><articulations><staccato/></articulations></notations></note>
+      </measure>
+      <measure number="2">
+        <note><pitch><step>E</step><octave>4</octave></pitch><duration>2</duration><voice>1</voice>
+          <notations><articulations><staccato/></articulations></notations></note>
+        <note><pitch><step>F</step><octave>4</octave></pitch><duration>2</duration><voice>1</voice></note>
+        <backup><duration>4</duration></backup>
+        <note><pitch><step>G</step><octave>3</octave></pitch><duration>2</duration><voice>2</voice>
+          <notations><articulations><accent/></articulations></notations></note>
+        <note><pitch><step>A</step><octave>3</octave></pitch><duration>2</duration><voice>2</voice>
+          <notations><articulations><staccato/></articulations><fermata type="upright"/></notations></note>
+      </measure>
+      <measure number="3">
+        <note><pitch><step>B</step><octave>3</octave></pitch><duration>4</duration><voice>2</voice>
+          <notations><articulations><staccato/></articulations></notations></note>
+      </measure>
+      <measure number="4">
+        <note><pitch><step>C</step><octave>4</octave></pitch><duration>4</duration><voice>2</voice>
+          <notations><articulations><staccato/></articulations><fermata/></notations></note>
+      </measure>
+    </part>
+  </score-partwise>"#;
+
+  fn steps(matches: &[QueryMatch<'_>]) -> String {
+    matches
+      .iter()
+      .map(|found| {
+        let pitch = note_pitch(found.get::<Note>().unwrap()).unwrap();
+        crate::util::step_letter(&pitch.content.step.content)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn query_notes() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let matches = score
+      .query("part[@id=P2]/measure[2..3]/note[voice=2][notations/articulations/staccato]")
+      .unwrap();
+    assert_eq!(steps(&matches), "AB");
+    assert_eq!(matches[0].name, "note");
+    assert_eq!(matches[0].context.part_id.as_deref(), Some("P2"));
+    assert_eq!(matches[0].context.measure_number.as_deref(), Some("2"));
+    assert_eq!(matches[0].context.voice.as_deref(), Some("2"));
+    assert_eq!(matches[0].context.onset, 2);
+    assert_eq!(matches[1].context.measure_number.as_deref(), Some("3"));
+    assert_eq!(matches[1].context.onset, 0);
+
+    assert_eq!(steps(&score.query("part/measure[1]/note").unwrap()), "CD");
+    assert_eq!(
+      steps(&score.query("/part[@id!=P1]/measure/note[voice=1]").unwrap()),
+      "EF"
+    );
+    assert_eq!(
+      steps(&score.query("part/*/note[notations/articulations/accent]").unwrap()),
+      "G"
+    );
+  }
+
+  #[test]
+  fn query_descendants() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let fermatas = score.query("//fermata").unwrap();
+    assert_eq!(fermatas.len(), 2);
+    assert!(fermatas.iter().all(|found| found.get::<Fermata>().is_some()));
+    assert_eq!(fermatas[0].context.measure_number.as_deref(), Some("2"));
+    assert_eq!(fermatas[1].context.measure_number.as_deref(), Some("4"));
+    assert_eq!(score.query("//fermata[@type]").unwrap().len(), 1);
+    assert_eq!(score.query("//fermata[@type='upright']").unwrap().len(), 1);
+    assert_eq!(steps(&score.query("part//note[notations//fermata]").unwrap()), "AC");
+    let c_steps = score.query("part//pitch[step=\"C\"]/step").unwrap();
+    assert_eq!(c_steps.len(), 2);
+    assert!(c_steps.iter().all(|found| found.get::<StepElement>().is_some()));
+    assert!(score
+      .query("//pitch")
+      .unwrap()
+      .iter()
+      .all(|found| found.get::<Pitch>().is_some()));
+    assert_eq!(score.query("//pitch").unwrap().len(), 8);
+  }
+
+  #[test]
+  fn query_timewise() {
+    let score = parse_score_timewise_from_data(SCORE.as_bytes().to_vec()).unwrap();
+    let matches = score.query("measure[2]/part[@id=P2]/note[voice=2]").unwrap();
+    assert_eq!(steps(&matches), "GA");
+    assert_eq!(matches[1].context.part_id.as_deref(), Some("P2"));
+    assert_eq!(matches[1].context.onset, 2);
+    let notes: Vec<&Note> = query(&score, "measure/part/note")
+      .unwrap()
+      .iter()
+      .filter_map(QueryMatch::get)
+      .collect();
+    assert_eq!(notes.len(), 8);
+  }
+
+  #[test]
+  fn query_invalid() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    assert_eq!(
+      Query::new("part[@id=P2").unwrap_err(),
+      "Invalid query \"part[@id=P2\": expected \"]\" at position 11"
+    );
+    assert_eq!(
+      Query::new("part/measure[4..2]").unwrap_err(),
+      "Invalid query \"part/measure[4..2]\": empty number range at position 17"
+    );
+    assert_eq!(
+      Query::new("part/").unwrap_err(),
+      "Invalid query \"part/\": expected an element name at position 5"
+    );
+    assert_eq!(
+      Query::new("part measure").unwrap_err(),
+      "Invalid query \"part measure\": unexpected character at position 5"
+    );
+    assert!(Query::new("part[@id='P2]").is_err());
+    assert!(score.query("").is_err());
+    assert!(score.query("part/measure[99]").unwrap().is_empty());
+  }
+
+  #[test]
+  fn query_names_match_serialization() {
+    fn serialized_names(element: &XmlElement<'_>, names: &mut Vec<String>) {
+      names.push(element.name.to_string());
+      for child in &element.elements {
+        serialized_names(child, names);
+      }
+    }
+
+    struct Names(Vec<String>);
+    impl Walker<'_> for Names {
+      fn enter(&mut self, name: &'static str, _element: &dyn WalkElement) {
+        self.0.push(String::from(name));
+      }
+
+      fn leave(&mut self, _name: &'static str, _element: &dyn WalkElement) {}
+    }
+
+    for file in ["tests/Grande Valse Brillante.musicxml", "tests/MozaChloSample.musicxml"] {
+      let score = parse_score_partwise_from_file(file).unwrap();
+      let mut expected = Vec::new();
+      serialized_names(&ScorePartwise::serialize(&score), &mut expected);
+      let mut walked = Names(Vec::new());
+      score.walk(&mut walked);
+      assert_eq!(walked.0, expected);
+    }
+  }
+}
@@ -0,0 +1,5 @@
+/// Rendering of scores into PCM audio stored in the RIFF WAV format.
+pub mod wav;
+
+/// Minimal engraving of the opening measures of a part into SVG images.
+pub mod svg;
@@ -0,0 +1,1391 @@
+//! Engraves the opening measures of a single part of a [ScorePartwise][crate::elements::ScorePartwise] score as an
+//! SVG image.
+//!
+//! The engraver is intentionally minimal and is meant for thumbnails and incipits rather than for print: all measures
+//! are placed on a single system, and only staff lines, clefs, key and time signatures, notes, rests, beams, and
+//! barlines are drawn. Every coordinate is computed in MusicXML tenths, where one staff space equals ten tenths, and
+//! the [Scaling][crate::elements::Scaling] of the score determines the physical size of the image in millimeters.
+//!
+//! Notes are placed horizontally using their `default-x` attribute whenever every note in a measure has one and the
+//! measure specifies its `width`; otherwise a simple duration-based spacing algorithm is used. Stems honor the
+//! `default-y` attribute of their [Stem][crate::elements::Stem] element when present. All musical symbols are drawn as
+//! text using their SMuFL code points, so the resulting image must be viewed with a SMuFL-compliant font such as
+//! Bravura or Leland installed. The `<music-font>` named in the score's defaults is ignored, since it is frequently a
+//! legacy font whose glyphs do not follow SMuFL.
+
+use crate::datatypes::{
+  AccidentalValue, BackwardForward, BarStyle, BeamValue, ClefSign, NoteheadValue, RightLeftMiddle, StemValue,
+  TimeSymbol, YesNo,
+};
+use crate::elements::{
+  Attributes, AudibleType, Barline, Clef, KeyContents, Measure, MeasureElement, Note, ScorePartwise, Time,
+};
+use crate::parser::xml_parser;
+use crate::util::{
+  length_to_note_type, note_audible, note_dots, note_is_grace, note_staff, note_voice, part_measures, part_staves,
+  step_letter, Fraction, MeasureEvent, PartTimeline, XmlElementExt, NOTE_TYPES,
+};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use musicxml_internal::{DatatypeSerializer, XmlElement};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const MUSIC_FONTS: &str = "Bravura, Leland";
+const DEFAULT_MILLIMETERS: f64 = 7.0;
+const DEFAULT_TENTHS: f64 = 40.0;
+const DEFAULT_STAFF_DISTANCE: f64 = 65.0;
+const MARGIN: f64 = 20.0;
+const STEM_LENGTH: f64 = 35.0;
+const BEAM_THICKNESS: f64 = 5.0;
+const BEAM_SPACING: f64 = 7.5;
+const HOOK_LENGTH: f64 = 10.0;
+const STAFF_LINE_WIDTH: f64 = 1.3;
+const STEM_WIDTH: f64 = 1.2;
+const LEDGER_LINE_WIDTH: f64 = 1.6;
+const THIN_BARLINE_WIDTH: f64 = 1.6;
+const THICK_BARLINE_WIDTH: f64 = 5.0;
+
+/// SMuFL glyph names and their code points.
+const GLYPHS: [(&str, char); 87] = [
+  ("repeatDot", '\u{E044}'),
+  ("gClef", '\u{E050}'),
+  ("gClef8vb", '\u{E052}'),
+  ("gClef8va", '\u{E053}'),
+  ("cClef", '\u{E05C}'),
+  ("fClef", '\u{E062}'),
+  ("fClef8vb", '\u{E064}'),
+  ("fClef8va", '\u{E065}'),
+  ("unpitchedPercussionClef1", '\u{E069}'),
+  ("6stringTabClef", '\u{E06D}'),
+  ("timeSig0", '\u{E080}'),
+  ("timeSig1", '\u{E081}'),
+  ("timeSig2", '\u{E082}'),
+  ("timeSig3", '\u{E083}'),
+  ("timeSig4", '\u{E084}'),
+  ("timeSig5", '\u{E085}'),
+  ("timeSig6", '\u{E086}'),
+  ("timeSig7", '\u{E087}'),
+  ("timeSig8", '\u{E088}'),
+  ("timeSig9", '\u{E089}'),
+  ("timeSigCommon", '\u{E08A}'),
+  ("timeSigCutCommon", '\u{E08B}'),
+  ("timeSigPlus", '\u{E08C}'),
+  ("noteheadDoubleWhole", '\u{E0A0}'),
+  ("noteheadWhole", '\u{E0A2}'),
+  ("noteheadHalf", '\u{E0A3}'),
+  ("noteheadBlack", '\u{E0A4}'),
+  ("noteheadXWhole", '\u{E0A7}'),
+  ("noteheadXHalf", '\u{E0A8}'),
+  ("noteheadXBlack", '\u{E0A9}'),
+  ("noteheadCircleX", '\u{E0B3}'),
+  ("noteheadSquareWhite", '\u{E0B8}'),
+  ("noteheadSquareBlack", '\u{E0B9}'),
+  ("noteheadTriangleUpWhole", '\u{E0BB}'),
+  ("noteheadTriangleUpHalf", '\u{E0BC}'),
+  ("noteheadTriangleUpBlack", '\u{E0BE}'),
+  ("noteheadDiamondWhole", '\u{E0D8}'),
+  ("noteheadDiamondHalf", '\u{E0D9}'),
+  ("noteheadDiamondBlack", '\u{E0DB}'),
+  ("noteheadSlashHorizontalEnds", '\u{E101}'),
+  ("noteheadSlashWhiteWhole", '\u{E102}'),
+  ("noteheadSlashWhiteHalf", '\u{E103}'),
+  ("augmentationDot", '\u{E1E7}'),
+  ("flag8thUp", '\u{E240}'),
+  ("flag8thDown", '\u{E241}'),
+  ("flag16thUp", '\u{E242}'),
+  ("flag16thDown", '\u{E243}'),
+  ("flag32ndUp", '\u{E244}'),
+  ("flag32ndDown", '\u{E245}'),
+  ("flag64thUp", '\u{E246}'),
+  ("flag64thDown", '\u{E247}'),
+  ("flag128thUp", '\u{E248}'),
+  ("flag128thDown", '\u{E249}'),
+  ("flag256thUp", '\u{E24A}'),
+  ("flag256thDown", '\u{E24B}'),
+  ("flag512thUp", '\u{E24C}'),
+  ("flag512thDown", '\u{E24D}'),
+  ("flag1024thUp", '\u{E24E}'),
+  ("flag1024thDown", '\u{E24F}'),
+  ("accidentalFlat", '\u{E260}'),
+  ("accidentalNatural", '\u{E261}'),
+  ("accidentalSharp", '\u{E262}'),
+  ("accidentalDoubleSharp", '\u{E263}'),
+  ("accidentalDoubleFlat", '\u{E264}'),
+  ("accidentalTripleSharp", '\u{E265}'),
+  ("accidentalTripleFlat", '\u{E266}'),
+  ("accidentalNaturalFlat", '\u{E267}'),
+  ("accidentalNaturalSharp", '\u{E268}'),
+  ("accidentalSharpSharp", '\u{E269}'),
+  ("accidentalQuarterToneFlatStein", '\u{E280}'),
+  ("accidentalThreeQuarterTonesFlatZimmermann", '\u{E281}'),
+  ("accidentalQuarterToneSharpStein", '\u{E282}'),
+  ("accidentalThreeQuarterTonesSharpStein", '\u{E283}'),
+  ("restMaxima", '\u{E4E0}'),
+  ("restLonga", '\u{E4E1}'),
+  ("restDoubleWhole", '\u{E4E2}'),
+  ("restWhole", '\u{E4E3}'),
+  ("restHalf", '\u{E4E4}'),
+  ("restQuarter", '\u{E4E5}'),
+  ("rest8th", '\u{E4E6}'),
+  ("rest16th", '\u{E4E7}'),
+  ("rest32nd", '\u{E4E8}'),
+  ("rest64th", '\u{E4E9}'),
+  ("rest128th", '\u{E4EA}'),
+  ("rest256th", '\u{E4EB}'),
+  ("rest512th", '\u{E4EC}'),
+  ("rest1024th", '\u{E4ED}'),
+];
+
+/// SMuFL rest glyphs for each note type, in the same order as [NOTE_TYPES].
+const REST_GLYPHS: [&str; 14] = [
+  "restMaxima",
+  "restLonga",
+  "restDoubleWhole",
+  "restWhole",
+  "restHalf",
+  "restQuarter",
+  "rest8th",
+  "rest16th",
+  "rest32nd",
+  "rest64th",
+  "rest128th",
+  "rest256th",
+  "rest512th",
+  "rest1024th",
+];
+
+/// SMuFL flag glyphs for stems pointing up and down, starting with the eighth note.
+const FLAG_GLYPHS: [(&str, &str); 8] = [
+  ("flag8thUp", "flag8thDown"),
+  ("flag16thUp", "flag16thDown"),
+  ("flag32ndUp", "flag32ndDown"),
+  ("flag64thUp", "flag64thDown"),
+  ("flag128thUp", "flag128thDown"),
+  ("flag256thUp", "flag256thDown"),
+  ("flag512thUp", "flag512thDown"),
+  ("flag1024thUp", "flag1024thDown"),
+];
+
+/// MusicXML notehead values and the SMuFL glyphs used for whole, half, and filled noteheads.
+const NOTEHEAD_GLYPHS: [(&str, [&str; 3]); 9] = [
+  ("x", ["noteheadXWhole", "noteheadXHalf", "noteheadXBlack"]),
+  ("cross", ["noteheadXWhole", "noteheadXHalf", "noteheadXBlack"]),
+  ("circle-x", ["noteheadCircleX", "noteheadCircleX", "noteheadCircleX"]),
+  (
+    "diamond",
+    ["noteheadDiamondWhole", "noteheadDiamondHalf", "noteheadDiamondBlack"],
+  ),
+  (
+    "triangle",
+    [
+      "noteheadTriangleUpWhole",
+      "noteheadTriangleUpHalf",
+      "noteheadTriangleUpBlack",
+    ],
+  ),
+  (
+    "square",
+    ["noteheadSquareWhite", "noteheadSquareWhite", "noteheadSquareBlack"],
+  ),
+  (
+    "rectangle",
+    ["noteheadSquareWhite", "noteheadSquareWhite", "noteheadSquareBlack"],
+  ),
+  (
+    "slash",
+    [
+      "noteheadSlashWhiteWhole",
+      "noteheadSlashWhiteHalf",
+      "noteheadSlashHorizontalEnds",
+    ],
+  ),
+  ("normal", ["noteheadWhole", "noteheadHalf", "noteheadBlack"]),
+];
+
+/// MusicXML accidental values and their SMuFL glyphs.
+const ACCIDENTAL_GLYPHS: [(&str, &str); 14] = [
+  ("sharp", "accidentalSharp"),
+  ("natural", "accidentalNatural"),
+  ("flat", "accidentalFlat"),
+  ("double-sharp", "accidentalDoubleSharp"),
+  ("sharp-sharp", "accidentalSharpSharp"),
+  ("flat-flat", "accidentalDoubleFlat"),
+  ("natural-sharp", "accidentalNaturalSharp"),
+  ("natural-flat", "accidentalNaturalFlat"),
+  ("quarter-flat", "accidentalQuarterToneFlatStein"),
+  ("quarter-sharp", "accidentalQuarterToneSharpStein"),
+  ("three-quarters-flat", "accidentalThreeQuarterTonesFlatZimmermann"),
+  ("three-quarters-sharp", "accidentalThreeQuarterTonesSharpStein"),
+  ("triple-sharp", "accidentalTripleSharp"),
+  ("triple-flat", "accidentalTripleFlat"),
+];
+
+/// Staff positions of the accidentals in sharp and flat key signatures on a treble staff, in half spaces below the
+/// top line.
+const KEY_SHARPS: [i32; 7] = [0, 3, -1, 2, 5, 1, 4];
+const KEY_FLATS: [i32; 7] = [4, 1, 5, 2, 6, 3, 7];
+
+/// Diatonic index of the top line of a treble staff (F5), against which key signature positions are defined.
+const TREBLE_TOP: i32 = 38;
+
+fn glyph_char(name: &str) -> Option<char> {
+  GLYPHS
+    .iter()
+    .find(|(glyph, _)| *glyph == name)
+    .map(|(_, code_point)| *code_point)
+}
+
+fn number(value: f64) -> String {
+  let text = format!("{value:.2}");
+  let text = text.trim_end_matches('0').trim_end_matches('.');
+  if text == "-0" {
+    String::from("0")
+  } else {
+    String::from(text)
+  }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// STAFF STATE --------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The clef in effect on a staff, along with the information needed to place pitches relative to it.
+#[derive(Debug, Clone, Copy)]
+struct ClefState {
+  glyph: Option<&'static str>,
+  centered: bool,
+  line: i32,
+  reference: i32,
+}
+
+impl Default for ClefState {
+  fn default() -> Self {
+    ClefState {
+      glyph: Some("gClef"),
+      centered: false,
+      line: 2,
+      reference: 32,
+    }
+  }
+}
+
+impl ClefState {
+  fn new(clef: &Clef) -> Self {
+    let octave_change = clef
+      .content
+      .clef_octave_change
+      .as_ref()
+      .map_or(0, |change| i32::from(change.content));
+    let (glyph, centered, default_line, reference) = match clef.content.sign.content {
+      ClefSign::G => (
+        match octave_change {
+          -1 => "gClef8vb",
+          1 => "gClef8va",
+          _ => "gClef",
+        },
+        false,
+        2,
+        32,
+      ),
+      ClefSign::F => (
+        match octave_change {
+          -1 => "fClef8vb",
+          1 => "fClef8va",
+          _ => "fClef",
+        },
+        false,
+        4,
+        24,
+      ),
+      ClefSign::C => ("cClef", false, 3, 28),
+      ClefSign::Percussion => ("unpitchedPercussionClef1", true, 2, 32),
+      ClefSign::TAB => ("6stringTabClef", true, 2, 32),
+      ClefSign::Jianpu | ClefSign::None => ("", false, 2, 32),
+    };
+    ClefState {
+      glyph: Some(glyph).filter(|glyph| !glyph.is_empty()),
+      centered,
+      line: clef
+        .content
+        .line
+        .as_ref()
+        .map_or(default_line, |line| i32::from(line.content.0)),
+      reference: reference + 7 * octave_change,
+    }
+  }
+}
+
+/// The state of a single staff while its measures are being engraved.
+#[derive(Debug, Clone, Copy)]
+struct StaffState {
+  top: f64,
+  lines: i32,
+  clef: ClefState,
+}
+
+impl StaffState {
+  /// Returns the diatonic index of the pitch on the top line of the staff.
+  fn top_pitch(&self) -> i32 {
+    self.clef.reference + 2 * (self.lines - self.clef.line)
+  }
+
+  /// Returns the staff position of a diatonic pitch, in half spaces below the top line.
+  fn position(&self, step: char, octave: u8) -> i32 {
+    let step = (0..)
+      .zip("CDEFGAB".chars())
+      .find_map(|(index, letter)| (letter == step).then_some(index))
+      .unwrap_or(0);
+    self.top_pitch() - (i32::from(octave) * 7 + step)
+  }
+
+  fn middle(&self) -> i32 {
+    self.lines - 1
+  }
+
+  fn bottom(&self) -> f64 {
+    self.y(2 * (self.lines - 1).max(0))
+  }
+
+  fn y(&self, position: i32) -> f64 {
+    self.top + 5.0 * f64::from(position)
+  }
+}
+
+/// The attributes and chords that share the same onset within a measure.
+#[derive(Default)]
+struct Column<'a> {
+  attributes: Vec<&'a Attributes>,
+  chords: Vec<(&'a [&'a Note], Fraction)>,
+}
+
+/// A note or chord whose stem is drawn once the beam group it belongs to is complete.
+struct BeamedChord<'a> {
+  x: f64,
+  head_width: f64,
+  top: f64,
+  bottom: f64,
+  middle: f64,
+  stem: Option<bool>,
+  tip: Option<f64>,
+  beams: Vec<(u8, &'a BeamValue)>,
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// ENGRAVER -----------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+struct Engraver {
+  staves: Vec<StaffState>,
+  elements: Vec<XmlElement<'static>>,
+  min_y: f64,
+  max_y: f64,
+}
+
+impl Engraver {
+  fn extend(&mut self, y: f64) {
+    self.min_y = self.min_y.min(y);
+    self.max_y = self.max_y.max(y);
+  }
+
+  fn glyph(&mut self, name: &str, x: f64, y: f64) {
+    if let Some(code_point) = glyph_char(name) {
+      self.extend(y - 20.0);
+      self.extend(y + 20.0);
+      self.elements.push(
+        XmlElement::named("text")
+          .with_attribute("x", number(x))
+          .with_attribute("y", number(y))
+          .with_text(code_point.to_string()),
+      );
+    }
+  }
+
+  fn line(&mut self, class: &str, (x1, y1): (f64, f64), (x2, y2): (f64, f64), width: f64) {
+    self.extend(y1);
+    self.extend(y2);
+    self.elements.push(
+      XmlElement::named("line")
+        .with_attribute("class", class)
+        .with_attribute("x1", number(x1))
+        .with_attribute("y1", number(y1))
+        .with_attribute("x2", number(x2))
+        .with_attribute("y2", number(y2))
+        .with_attribute("stroke-width", number(width)),
+    );
+  }
+
+  fn polygon(&mut self, class: &str, points: &[(f64, f64)]) {
+    let points: Vec<String> = points
+      .iter()
+      .map(|(x, y)| format!("{},{}", number(*x), number(*y)))
+      .collect();
+    self.elements.push(
+      XmlElement::named("polygon")
+        .with_attribute("class", class)
+        .with_attribute("points", points.join(" ")),
+    );
+  }
+
+  fn staff(&self, note: &Note) -> usize {
+    (note_staff(note) as usize).clamp(1, self.staves.len()) - 1
+  }
+
+  fn engrave_clef(&mut self, staff: usize, x: f64) {
+    let state = self.staves[staff];
+    if let Some(glyph) = state.clef.glyph {
+      let position = if state.clef.centered {
+        state.middle()
+      } else {
+        2 * (state.lines - state.clef.line)
+      };
+      self.glyph(glyph, x, state.y(position));
+    }
+  }
+
+  fn engrave_key(&mut self, staff: usize, x: f64, fifths: i8) -> f64 {
+    let state = self.staves[staff];
+    if state.clef.centered || state.clef.glyph.is_none() {
+      return 0.0;
+    }
+    let mut shift = (state.top_pitch() - TREBLE_TOP).rem_euclid(7);
+    if shift > 3 {
+      shift -= 7;
+    }
+    let (positions, glyph, advance) = if fifths > 0 {
+      (&KEY_SHARPS, "accidentalSharp", 10.0)
+    } else {
+      (&KEY_FLATS, "accidentalFlat", 9.0)
+    };
+    let count = fifths.unsigned_abs().min(7);
+    for (index, position) in (0..count).zip(positions) {
+      self.glyph(glyph, x + advance * f64::from(index), state.y(position - shift));
+    }
+    advance * f64::from(count)
+  }
+
+  fn engrave_time(&mut self, staff: usize, x: f64, time: &Time) -> f64 {
+    let state = self.staves[staff];
+    let Some(signature) = time.content.beats.first() else {
+      return 0.0;
+    };
+    match time.attributes.symbol {
+      Some(TimeSymbol::Common) => {
+        self.glyph("timeSigCommon", x, state.y(state.middle()));
+        20.0
+      }
+      Some(TimeSymbol::Cut) => {
+        self.glyph("timeSigCutCommon", x, state.y(state.middle()));
+        20.0
+      }
+      _ => {
+        let single = time.attributes.symbol == Some(TimeSymbol::SingleNumber);
+        let rows: Vec<(&str, i32)> = if single {
+          vec![(signature.beats.content.as_str(), state.middle())]
+        } else {
+          vec![
+            (signature.beats.content.as_str(), state.middle() - 2),
+            (signature.beat_type.content.as_str(), state.middle() + 2),
+          ]
+        };
+        let digits_width = |digits: &str| f64::from(u32::try_from(digits.chars().count()).unwrap_or(u32::MAX)) * 18.0;
+        let width = rows.iter().map(|(digits, _)| digits_width(digits)).fold(0.0, f64::max);
+        for (digits, position) in rows {
+          let mut digit_x = x + (width - digits_width(digits)) / 2.0;
+          for digit in digits.chars() {
+            let glyph = if digit == '+' {
+              String::from("timeSigPlus")
+            } else {
+              format!("timeSig{digit}")
+            };
+            self.glyph(&glyph, digit_x, state.y(position));
+            digit_x += 18.0;
+          }
+        }
+        width
+      }
+    }
+  }
+
+  /// Applies and draws the clef, key, and time signature changes in an [Attributes] element.
+  ///
+  /// Returns the horizontal space taken up by the symbols that were drawn.
+  fn engrave_attributes(&mut self, attributes: &Attributes, x: f64) -> f64 {
+    let mut cursor = x;
+    let staves: Vec<usize> = (0..self.staves.len()).collect();
+    let targets = |number: Option<u8>| -> Vec<usize> {
+      match number {
+        Some(number) => staves
+          .iter()
+          .copied()
+          .filter(|staff| *staff + 1 == usize::from(number))
+          .collect(),
+        None => staves.clone(),
+      }
+    };
+    if !attributes.content.clef.is_empty() {
+      for clef in &attributes.content.clef {
+        for staff in targets(clef.attributes.number.as_ref().map(|number| number.0)) {
+          self.staves[staff].clef = ClefState::new(clef);
+          if clef.attributes.print_object != Some(YesNo::No) {
+            self.engrave_clef(staff, cursor);
+          }
+        }
+      }
+      cursor += 35.0;
+    }
+    let mut width: f64 = 0.0;
+    for key in &attributes.content.key {
+      if let (KeyContents::Explicit(key_contents), false) =
+        (&key.content, key.attributes.print_object == Some(YesNo::No))
+      {
+        for staff in targets(key.attributes.number.as_ref().map(|number| number.0)) {
+          width = width.max(self.engrave_key(staff, cursor, key_contents.fifths.content.0));
+        }
+      }
+    }
+    if width > 0.0 {
+      cursor += width + 8.0;
+    }
+    width = 0.0;
+    for time in &attributes.content.time {
+      if time.attributes.print_object != Some(YesNo::No) {
+        for staff in targets(time.attributes.number.as_ref().map(|number| number.0)) {
+          width = width.max(self.engrave_time(staff, cursor, time));
+        }
+      }
+    }
+    if width > 0.0 {
+      cursor += width + 10.0;
+    }
+    cursor - x
+  }
+
+  fn engrave_rest(&mut self, note: &Note, x: f64, type_index: usize) {
+    let state = self.staves[self.staff(note)];
+    let position = match note_audible(note) {
+      AudibleType::Rest(rest) => match (&rest.content.display_step, &rest.content.display_octave) {
+        (Some(step), Some(octave)) => Some(state.position(step_letter(&step.content), octave.content.0)),
+        _ => None,
+      },
+      _ => None,
+    }
+    .unwrap_or(if type_index == 3 {
+      state.middle() - 2
+    } else {
+      state.middle()
+    });
+    self.glyph(REST_GLYPHS[type_index], x, state.y(position));
+    for dot in 0..note_dots(note) {
+      let dot_y = state.y(position - i32::from(position % 2 == 0));
+      self.glyph("augmentationDot", x + 20.0 + 5.0 * f64::from(dot), dot_y);
+    }
+  }
+
+  /// Draws the noteheads, accidentals, dots, and ledger lines of a chord, along with its stem and flags if unbeamed.
+  ///
+  /// Returns the chord itself if it belongs to a beam group, so that its stem can be drawn along with the beam.
+  #[allow(clippy::too_many_lines)]
+  fn engrave_chord<'a>(&mut self, notes: &[&'a Note], x: f64, duration: Fraction) -> Option<BeamedChord<'a>> {
+    let first = notes[0];
+    let state = self.staves[self.staff(first)];
+    let note_type = first
+      .content
+      .r#type
+      .as_ref()
+      .map(|note_type| &note_type.content)
+      .or_else(|| {
+        length_to_note_type(duration)
+          .and_then(|(note_type, _)| NOTE_TYPES.iter().find(|candidate| **candidate == note_type))
+      });
+    let type_index = note_type
+      .and_then(|note_type| NOTE_TYPES.iter().position(|candidate| candidate == note_type))
+      .unwrap_or(5);
+    if matches!(note_audible(first), AudibleType::Rest(_)) {
+      self.engrave_rest(first, x, type_index);
+      return None;
+    }
+    let head_width = match type_index {
+      0..=2 => 25.0,
+      3 => 17.0,
+      _ => 11.8,
+    };
+    let mut positions = Vec::new();
+    for note in notes {
+      let position = match note_audible(note) {
+        AudibleType::Pitch(pitch) => {
+          state.position(step_letter(&pitch.content.step.content), pitch.content.octave.content.0)
+        }
+        AudibleType::Unpitched(unpitched) => state.position(
+          step_letter(&unpitched.content.display_step.content),
+          unpitched.content.display_octave.content.0,
+        ),
+        AudibleType::Rest(_) => continue,
+      };
+      positions.push(position);
+      let y = state.y(position);
+      let notehead = note.content.notehead.as_ref();
+      let value = notehead.map_or(String::from("normal"), |notehead| {
+        NoteheadValue::serialize(&notehead.content)
+      });
+      let filled = notehead
+        .and_then(|notehead| notehead.attributes.filled.as_ref())
+        .map_or(type_index > 4, |filled| *filled == YesNo::Yes);
+      let glyph = notehead
+        .and_then(|notehead| notehead.attributes.smufl.as_ref())
+        .map(|smufl| smufl.0.as_str())
+        .filter(|smufl| glyph_char(smufl).is_some())
+        .or_else(|| {
+          let glyphs = NOTEHEAD_GLYPHS
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map_or(NOTEHEAD_GLYPHS[NOTEHEAD_GLYPHS.len() - 1].1, |(_, glyphs)| *glyphs);
+          match (value.as_str(), type_index) {
+            ("none", _) => None,
+            ("normal", 0..=2) => Some("noteheadDoubleWhole"),
+            (_, 0..=3) => Some(glyphs[0]),
+            _ => Some(glyphs[if filled { 2 } else { 1 }]),
+          }
+        });
+      if let Some(glyph) = glyph {
+        self.glyph(glyph, x, y);
+      }
+      if let Some(accidental) = &note.content.accidental {
+        let value = AccidentalValue::serialize(&accidental.content);
+        let glyph = accidental
+          .attributes
+          .smufl
+          .as_ref()
+          .map(|smufl| smufl.0.as_str())
+          .filter(|smufl| glyph_char(smufl).is_some())
+          .or_else(|| {
+            ACCIDENTAL_GLYPHS
+              .iter()
+              .find(|(name, _)| *name == value)
+              .map(|(_, glyph)| *glyph)
+          });
+        if let Some(glyph) = glyph {
+          self.glyph(glyph, x - 13.0, y);
+        }
+      }
+      for dot in 0..note_dots(note) {
+        let dot_y = state.y(position - i32::from(position % 2 == 0));
+        self.glyph("augmentationDot", x + head_width + 5.0 + 5.0 * f64::from(dot), dot_y);
+      }
+      let ledger = (x - 4.0, x + head_width + 4.0);
+      let bottom_line = 2 * (state.lines - 1);
+      let mut ledger_position = -2;
+      while ledger_position >= position {
+        let ledger_y = state.y(ledger_position);
+        self.line(
+          "ledger-line",
+          (ledger.0, ledger_y),
+          (ledger.1, ledger_y),
+          LEDGER_LINE_WIDTH,
+        );
+        ledger_position -= 2;
+      }
+      ledger_position = bottom_line + 2;
+      while ledger_position <= position {
+        let ledger_y = state.y(ledger_position);
+        self.line(
+          "ledger-line",
+          (ledger.0, ledger_y),
+          (ledger.1, ledger_y),
+          LEDGER_LINE_WIDTH,
+        );
+        ledger_position += 2;
+      }
+    }
+    let (Some(&highest), Some(&lowest)) = (positions.iter().min(), positions.iter().max()) else {
+      return None;
+    };
+    let stem = first.content.stem.as_ref();
+    let direction = match stem.map(|stem| &stem.content) {
+      Some(StemValue::None) => return None,
+      Some(StemValue::Up | StemValue::Double) => Some(true),
+      Some(StemValue::Down) => Some(false),
+      None => None,
+    };
+    if type_index < 4 {
+      return None;
+    }
+    let chord = BeamedChord {
+      x,
+      head_width,
+      top: state.y(highest),
+      bottom: state.y(lowest),
+      middle: state.y(state.middle()),
+      stem: direction,
+      tip: stem
+        .and_then(|stem| stem.attributes.default_y.as_ref())
+        .map(|default_y| state.top - default_y.0),
+      beams: first
+        .content
+        .beam
+        .iter()
+        .map(|beam| {
+          (
+            beam.attributes.number.as_ref().map_or(1, |number| number.0),
+            &beam.content,
+          )
+        })
+        .collect(),
+    };
+    if !chord.beams.is_empty() {
+      return Some(chord);
+    }
+    let up = chord.stem.unwrap_or(chord.top + chord.bottom > 2.0 * chord.middle);
+    let flags = type_index.saturating_sub(5).min(FLAG_GLYPHS.len());
+    let extension = 5.0 * f64::from(u32::try_from(flags.saturating_sub(2)).unwrap_or(0));
+    let (stem_x, start, tip) = if up {
+      (
+        x + head_width - STEM_WIDTH / 2.0,
+        chord.bottom,
+        chord.tip.unwrap_or(chord.top - STEM_LENGTH - extension),
+      )
+    } else {
+      (
+        x + STEM_WIDTH / 2.0,
+        chord.top,
+        chord.tip.unwrap_or(chord.bottom + STEM_LENGTH + extension),
+      )
+    };
+    self.line("stem", (stem_x, start), (stem_x, tip), STEM_WIDTH);
+    if flags > 0 {
+      let (up_glyph, down_glyph) = FLAG_GLYPHS[flags - 1];
+      self.glyph(if up { up_glyph } else { down_glyph }, stem_x - STEM_WIDTH / 2.0, tip);
+    }
+    None
+  }
+
+  /// Draws the stems and beams of a complete beam group.
+  fn engrave_beams(&mut self, group: &[BeamedChord<'_>]) {
+    let (Some(first), Some(last)) = (group.first(), group.last()) else {
+      return;
+    };
+    let up = group.iter().find_map(|chord| chord.stem).unwrap_or_else(|| {
+      let notes: f64 = group.iter().map(|chord| chord.top + chord.bottom).sum();
+      let middles: f64 = group.iter().map(|chord| 2.0 * chord.middle).sum();
+      notes > middles
+    });
+    let stem_x = |chord: &BeamedChord<'_>| {
+      if up {
+        chord.x + chord.head_width - STEM_WIDTH / 2.0
+      } else {
+        chord.x + STEM_WIDTH / 2.0
+      }
+    };
+    let tip = |chord: &BeamedChord<'_>| {
+      chord.tip.unwrap_or(if up {
+        chord.top - STEM_LENGTH
+      } else {
+        chord.bottom + STEM_LENGTH
+      })
+    };
+    let (x1, x2) = (stem_x(first), stem_x(last));
+    let mut start = tip(first);
+    let slope = if x2 > x1 {
+      ((tip(last) - start) / (x2 - x1)).clamp(-0.15, 0.15)
+    } else {
+      0.0
+    };
+    let shift = group
+      .iter()
+      .map(|chord| {
+        let beam_y = start + slope * (stem_x(chord) - x1);
+        if up {
+          beam_y - tip(chord)
+        } else {
+          tip(chord) - beam_y
+        }
+      })
+      .fold(0.0, f64::max);
+    start += if up { -shift } else { shift };
+    let beam_y = |x: f64| start + slope * (x - x1);
+    for chord in group {
+      let x = stem_x(chord);
+      let note_y = if up { chord.bottom } else { chord.top };
+      self.line("stem", (x, note_y), (x, beam_y(x)), STEM_WIDTH);
+    }
+    let direction = if up { 1.0 } else { -1.0 };
+    let levels = group
+      .iter()
+      .flat_map(|chord| chord.beams.iter().map(|(level, _)| *level))
+      .max()
+      .unwrap_or(1);
+    for level in 1..=levels {
+      let offset = direction * BEAM_SPACING * f64::from(level - 1);
+      let mut segments = Vec::new();
+      let mut open: Option<f64> = None;
+      for chord in group {
+        let x = stem_x(chord);
+        match chord
+          .beams
+          .iter()
+          .find(|(number, _)| *number == level)
+          .map(|(_, value)| *value)
+        {
+          Some(BeamValue::Begin) => open = Some(x),
+          Some(BeamValue::Continue) => open = open.or(Some(x)),
+          Some(BeamValue::End) => {
+            if let Some(open_x) = open.take() {
+              segments.push((open_x, x));
+            }
+          }
+          Some(BeamValue::ForwardHook) => segments.push((x, x + HOOK_LENGTH)),
+          Some(BeamValue::BackwardHook) => segments.push((x - HOOK_LENGTH, x)),
+          None => {}
+        }
+      }
+      if let Some(open_x) = open {
+        segments.push((open_x, x2));
+      }
+      for (from, to) in segments {
+        let (from_y, to_y) = (beam_y(from) + offset, beam_y(to) + offset);
+        let thickness = direction * BEAM_THICKNESS;
+        self.polygon(
+          "beam",
+          &[
+            (from, from_y),
+            (to, to_y),
+            (to, to_y + thickness),
+            (from, from_y + thickness),
+          ],
+        );
+      }
+    }
+  }
+
+  fn engrave_barline(&mut self, x: f64, style: Option<&BarStyle>, repeat: Option<&BackwardForward>) {
+    let (Some(first), Some(last)) = (self.staves.first().copied(), self.staves.last().copied()) else {
+      return;
+    };
+    let (top, bottom) = (first.top, last.bottom());
+    let thin = |engraver: &mut Engraver, x: f64| {
+      engraver.line("barline", (x, top), (x, bottom), THIN_BARLINE_WIDTH);
+    };
+    let thick = |engraver: &mut Engraver, x: f64| {
+      engraver.line("barline", (x, top), (x, bottom), THICK_BARLINE_WIDTH);
+    };
+    match style {
+      Some(BarStyle::None) => {}
+      Some(BarStyle::LightLight) => {
+        thin(self, x - 6.0);
+        thin(self, x);
+      }
+      Some(BarStyle::LightHeavy) => {
+        thin(self, x - 9.0);
+        thick(self, x - 2.5);
+      }
+      Some(BarStyle::HeavyLight) => {
+        thick(self, x + 2.5);
+        thin(self, x + 9.0);
+      }
+      Some(BarStyle::Heavy) => thick(self, x),
+      Some(BarStyle::HeavyHeavy) => {
+        thick(self, x - 4.0);
+        thick(self, x + 4.0);
+      }
+      Some(BarStyle::Dashed | BarStyle::Dotted) => {
+        thin(self, x);
+        let dashes = if style == Some(&BarStyle::Dashed) { "5 5" } else { "1 4" };
+        if let Some(barline) = self.elements.last_mut() {
+          barline
+            .attributes
+            .push((Cow::Borrowed("stroke-dasharray"), Cow::Owned(String::from(dashes))));
+        }
+      }
+      Some(BarStyle::Tick) => {
+        for staff in self.staves.clone() {
+          self.line(
+            "barline",
+            (x, staff.top - 5.0),
+            (x, staff.top + 5.0),
+            THIN_BARLINE_WIDTH,
+          );
+        }
+      }
+      Some(BarStyle::Short) => {
+        for staff in self.staves.clone() {
+          self.line(
+            "barline",
+            (x, staff.y(1)),
+            (x, staff.bottom() - 5.0),
+            THIN_BARLINE_WIDTH,
+          );
+        }
+      }
+      Some(BarStyle::Regular) | None => thin(self, x),
+    }
+    if let Some(repeat) = repeat {
+      let dot_x = match repeat {
+        BackwardForward::Backward => x - 20.0,
+        BackwardForward::Forward => x + 14.0,
+      };
+      for staff in self.staves.clone() {
+        self.glyph("repeatDot", dot_x, staff.y(staff.middle() - 1));
+        self.glyph("repeatDot", dot_x, staff.y(staff.middle() + 1));
+      }
+    }
+  }
+
+  /// Engraves a single measure starting at the given horizontal position, returning the position of its end.
+  #[allow(clippy::too_many_lines)]
+  fn engrave_measure(&mut self, measure: &Measure, timeline: &mut PartTimeline, x: f64) -> f64 {
+    let events = timeline.measure(measure).events;
+    let barline = |location: RightLeftMiddle| -> Option<&Barline> {
+      measure.content.iter().find_map(|element| match element {
+        MeasureElement::Barline(barline)
+          if barline.attributes.location.as_ref().unwrap_or(&RightLeftMiddle::Right) == &location =>
+        {
+          Some(barline)
+        }
+        _ => None,
+      })
+    };
+    let mut cursor = x + 12.0;
+    if let Some(left) = barline(RightLeftMiddle::Left) {
+      let repeat = left.content.repeat.as_ref().map(|repeat| &repeat.attributes.direction);
+      self.engrave_barline(x, left.content.bar_style.as_ref().map(|style| &style.content), repeat);
+      cursor += if repeat.is_some() { 15.0 } else { 5.0 };
+    }
+
+    // Group events into columns that share the same onset
+    let mut columns: BTreeMap<Fraction, Column<'_>> = BTreeMap::new();
+    let mut measure_rests = Vec::new();
+    let mut end = Fraction::ZERO;
+    for event in &events {
+      match &event.event {
+        MeasureEvent::Attributes(attributes) => columns.entry(event.position).or_default().attributes.push(attributes),
+        MeasureEvent::Notes(notes) if !note_is_grace(notes[0]) => {
+          end = end.max(event.position + event.duration);
+          let measure_rest = matches!(note_audible(notes[0]), AudibleType::Rest(rest)
+            if rest.attributes.measure == Some(YesNo::Yes) || notes[0].content.r#type.is_none());
+          if measure_rest {
+            measure_rests.push(notes[0]);
+          } else {
+            columns
+              .entry(event.position)
+              .or_default()
+              .chords
+              .push((notes.as_slice(), event.duration));
+          }
+        }
+        _ => {}
+      }
+    }
+    let file_layout = measure.attributes.width.is_some()
+      && columns
+        .values()
+        .flat_map(|column| column.chords.iter())
+        .all(|(notes, _)| notes[0].attributes.default_x.is_some());
+    if measure_rests.is_empty() && columns.values().all(|column| column.chords.is_empty()) {
+      end = Fraction::ZERO;
+    }
+
+    // Lay out and draw each column
+    let positions: Vec<Fraction> = columns.keys().copied().collect();
+    let mut groups: BTreeMap<&str, Vec<BeamedChord<'_>>> = BTreeMap::new();
+    let mut finished = Vec::new();
+    for (index, (position, column)) in columns.iter().enumerate() {
+      for attributes in &column.attributes {
+        cursor += self.engrave_attributes(attributes, cursor);
+      }
+      let chords = &column.chords;
+      if chords.is_empty() {
+        continue;
+      }
+      let column_x = match chords[0].0[0].attributes.default_x.as_ref() {
+        Some(default_x) if file_layout => x + default_x.0,
+        _ => {
+          let accidentals = chords
+            .iter()
+            .any(|(notes, _)| notes.iter().any(|note| note.content.accidental.is_some()));
+          cursor + if accidentals { 12.0 } else { 0.0 }
+        }
+      };
+      for (notes, duration) in chords {
+        if let Some(chord) = self.engrave_chord(notes, column_x, *duration) {
+          let voice = note_voice(notes[0]);
+          let begins = chord
+            .beams
+            .iter()
+            .any(|(level, value)| *level == 1 && **value == BeamValue::Begin);
+          let ends = chord
+            .beams
+            .iter()
+            .any(|(level, value)| *level == 1 && **value == BeamValue::End);
+          if begins {
+            if let Some(group) = groups.remove(voice) {
+              finished.push(group);
+            }
+          }
+          let group = groups.entry(voice).or_default();
+          group.push(chord);
+          if ends {
+            if let Some(group) = groups.remove(voice) {
+              finished.push(group);
+            }
+          }
+        }
+      }
+      let next = positions.get(index + 1).copied().unwrap_or(end);
+      cursor = column_x + spacing(next - *position);
+    }
+    finished.extend(groups.into_values());
+    for group in &finished {
+      self.engrave_beams(group);
+    }
+
+    // Finish the measure with any whole-measure rests and its right barline
+    if !measure_rests.is_empty() && columns.values().all(|column| column.chords.is_empty()) {
+      cursor += spacing(Fraction::from_int(4));
+    }
+    let end_x = match measure.attributes.width.as_ref() {
+      Some(width) if file_layout => x + width.0,
+      _ => cursor + 5.0,
+    };
+    for rest in measure_rests {
+      let state = self.staves[self.staff(rest)];
+      let rest_x = x + (end_x - x) / 2.0 - 8.0;
+      self.glyph("restWhole", rest_x, state.y(state.middle() - 2));
+    }
+    let right = barline(RightLeftMiddle::Right);
+    self.engrave_barline(
+      end_x,
+      right.and_then(|barline| barline.content.bar_style.as_ref().map(|style| &style.content)),
+      right.and_then(|barline| {
+        barline
+          .content
+          .repeat
+          .as_ref()
+          .map(|repeat| &repeat.attributes.direction)
+      }),
+    );
+    end_x
+  }
+}
+
+/// Returns the horizontal space allotted to a note or rest of a given length in quarter notes.
+fn spacing(length: Fraction) -> f64 {
+  let mut width = 18.0;
+  let mut step = Fraction::new(1, 2);
+  while step < length {
+    width += 10.0;
+    step = step * Fraction::from_int(2);
+  }
+  width
+}
+
+/// Renders the first `measures` measures of the part with the given ID as a standalone SVG document.
+///
+/// The returned image contains all staves of the part on a single system. Its width and height are expressed in
+/// millimeters using the [Scaling][crate::elements::Scaling] in the score's defaults, or 7 millimeters per 40 tenths
+/// when none is specified.
+///
+/// # Errors
+///
+/// Returns an error if no measures are requested or if the score does not contain a part with the given ID.
+#[allow(clippy::too_many_lines)]
+pub fn render_svg(score: &ScorePartwise, part_id: &str, measures: usize) -> Result<String, String> {
+  if measures == 0 {
+    return Err(String::from("At least one measure must be rendered"));
+  }
+  let part = score
+    .content
+    .part
+    .iter()
+    .find(|part| part.attributes.id.0 == part_id)
+    .ok_or_else(|| format!("Score does not contain a part with ID '{part_id}'"))?;
+  let defaults = score.content.defaults.as_ref().map(|defaults| &defaults.content);
+  let millimeters_per_tenth = defaults
+    .and_then(|defaults| defaults.scaling.as_ref())
+    .filter(|scaling| scaling.content.tenths.content.0 > 0.0)
+    .map_or(DEFAULT_MILLIMETERS / DEFAULT_TENTHS, |scaling| {
+      scaling.content.millimeters.content.0 / scaling.content.tenths.content.0
+    });
+
+  // Stack the staves vertically, using the staff details and staff layouts of the first measure
+  let mut staves: Vec<StaffState> = Vec::new();
+  let first_attributes: Vec<&Attributes> = part_measures(part)
+    .next()
+    .into_iter()
+    .flat_map(|measure| measure.content.iter())
+    .filter_map(|element| match element {
+      MeasureElement::Attributes(attributes) => Some(attributes),
+      _ => None,
+    })
+    .collect();
+  for staff in 1..=part_staves(part) {
+    let lines = first_attributes
+      .iter()
+      .flat_map(|attributes| attributes.content.staff_details.iter())
+      .filter(|details| {
+        details
+          .attributes
+          .number
+          .as_ref()
+          .map_or(1, |number| u32::from(number.0))
+          == staff
+      })
+      .find_map(|details| details.content.staff_lines.as_ref())
+      .map_or(5, |lines| i32::try_from(lines.content.0.min(16)).unwrap_or(16));
+    let distance = defaults
+      .iter()
+      .flat_map(|defaults| defaults.staff_layout.iter())
+      .find(|layout| {
+        layout
+          .attributes
+          .number
+          .as_ref()
+          .map_or(1, |number| u32::from(number.0))
+          == staff
+      })
+      .and_then(|layout| layout.content.staff_distance.as_ref())
+      .map_or(DEFAULT_STAFF_DISTANCE, |distance| distance.content.0);
+    let top = staves.last().map_or(0.0, |previous| previous.bottom() + distance);
+    staves.push(StaffState {
+      top,
+      lines,
+      clef: ClefState::default(),
+    });
+  }
+  let mut engraver = Engraver {
+    min_y: staves.first().map_or(0.0, |staff| staff.top),
+    max_y: staves.last().map_or(0.0, StaffState::bottom),
+    staves,
+    elements: Vec::new(),
+  };
+
+  // Engrave each measure in turn, then draw the staff lines underneath everything else
+  let mut timeline = PartTimeline::default();
+  let mut x = MARGIN;
+  for measure in part_measures(part).take(measures) {
+    x = engraver.engrave_measure(measure, &mut timeline, x);
+  }
+  let mut staff_lines = Vec::new();
+  for staff in &engraver.staves {
+    for line in 0..staff.lines {
+      let y = staff.y(2 * line);
+      staff_lines.push(
+        XmlElement::named("line")
+          .with_attribute("class", "staff-line")
+          .with_attribute("x1", number(MARGIN))
+          .with_attribute("y1", number(y))
+          .with_attribute("x2", number(x))
+          .with_attribute("y2", number(y))
+          .with_attribute("stroke-width", number(STAFF_LINE_WIDTH)),
+      );
+    }
+  }
+  let (glyphs, shapes): (Vec<XmlElement<'static>>, Vec<XmlElement<'static>>) = engraver
+    .elements
+    .into_iter()
+    .partition(|element| element.name == "text");
+  let (width, top, height) = (
+    x + MARGIN,
+    engraver.min_y - MARGIN,
+    engraver.max_y - engraver.min_y + 2.0 * MARGIN,
+  );
+  let svg = XmlElement::named("svg")
+    .with_attribute("xmlns", SVG_NAMESPACE)
+    .with_attribute("version", "1.1")
+    .with_attribute("width", format!("{}mm", number(width * millimeters_per_tenth)))
+    .with_attribute("height", format!("{}mm", number(height * millimeters_per_tenth)))
+    .with_attribute(
+      "viewBox",
+      format!("0 {} {} {}", number(top), number(width), number(height)),
+    )
+    .with_child(XmlElement {
+      elements: staff_lines.into_iter().chain(shapes).collect(),
+      ..XmlElement::named("g")
+        .with_attribute("stroke", "black")
+        .with_attribute("fill", "black")
+    })
+    .with_child(XmlElement {
+      elements: glyphs,
+      ..XmlElement::named("g")
+        .with_attribute("font-family", MUSIC_FONTS)
+        .with_attribute("font-size", "40")
+        .with_attribute("fill", "black")
+    });
+  Ok(format!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n",
+    xml_parser::parse_to_string(&svg, 0)
+  ))
+}
+
+#[cfg(test)]
+mod svg_tests {
+  use super::*;
+  use crate::parser::parse_from_xml_str;
+
+  const SCORE: &str = "<score-partwise version=\"4.0\">
+    <defaults><scaling><millimeters>8</millimeters><tenths>40</tenths></scaling></defaults>
+    <part-list><score-part id=\"P1\"><part-name>Piano</part-name></score-part></part-list>
+    <part id=\"P1\">
+      <measure number=\"1\">
+        <attributes>
+          <divisions>2</divisions>
+          <key><fifths>-2</fifths></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <staves>2</staves>
+          <clef number=\"1\"><sign>G</sign><line>2</line></clef>
+          <clef number=\"2\"><sign>F</sign><line>4</line></clef>
+        </attributes>
+        <note>
+          <pitch><step>C</step><octave>4</octave></pitch>
+          <duration>3</duration><voice>1</voice><type>quarter</type><dot/><stem>up</stem><staff>1</staff>
+        </note>
+        <note>
+          <pitch><step>F</step><alter>1</alter><octave>5</octave></pitch>
+          <duration>1</duration><voice>1</voice><type>eighth</type><accidental>sharp</accidental><stem>down</stem><staff>1</staff>
+        </note>
+        <note>
+          <pitch><step>G</step><octave>5</octave></pitch>
+          <duration>1</duration><voice>1</voice><type>eighth</type><stem>down</stem><staff>1</staff>
+          <beam number=\"1\">begin</beam>
+        </note>
+        <note>
+          <pitch><step>A</step><octave>5</octave></pitch>
+          <duration>1</duration><voice>1</voice><type>eighth</type><stem>down</stem><staff>1</staff>
+          <beam number=\"1\">end</beam>
+        </note>
+        <backup><duration>6</duration></backup>
+        <note>
+          <pitch><step>C</step><octave>3</octave></pitch>
+          <duration>4</duration><voice>5</voice><type>half</type><staff>2</staff>
+        </note>
+        <note>
+          <chord/>
+          <pitch><step>E</step><octave>3</octave></pitch>
+          <duration>4</duration><voice>5</voice><type>half</type><staff>2</staff>
+        </note>
+        <note><rest/><duration>2</duration><voice>5</voice><type>quarter</type><staff>2</staff></note>
+      </measure>
+      <measure number=\"2\" width=\"200\">
+        <note default-x=\"50\">
+          <pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch>
+          <duration>6</duration><voice>1</voice><type>half</type><dot/><stem>down</stem><staff>1</staff>
+        </note>
+        <backup><duration>6</duration></backup>
+        <note><rest measure=\"yes\"/><duration>6</duration><voice>5</voice><staff>2</staff></note>
+        <barline location=\"right\"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+      <measure number=\"3\">
+        <note>
+          <pitch><step>C</step><octave>5</octave></pitch>
+          <duration>6</duration><voice>1</voice><type>half</type><dot/><staff>1</staff>
+        </note>
+      </measure>
+    </part>
+  </score-partwise>";
+
+  fn render(measures: usize) -> XmlElement<'static> {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let svg = render_svg(&score, "P1", measures).unwrap();
+    xml_parser::parse_from_string(&svg).unwrap().into_owned()
+  }
+
+  fn count(svg: &XmlElement, class: &str) -> usize {
+    svg
+      .elements
+      .iter()
+      .flat_map(|group| group.elements.iter())
+      .filter(|element| element.attribute("class") == Some(class))
+      .count()
+  }
+
+  fn glyphs(svg: &XmlElement, name: &str) -> Vec<(f64, f64)> {
+    let code_point = glyph_char(name).unwrap().to_string();
+    svg.elements[1]
+      .elements
+      .iter()
+      .filter(|element| element.text == code_point)
+      .map(|element| {
+        (
+          element.attribute("x").unwrap().parse().unwrap(),
+          element.attribute("y").unwrap().parse().unwrap(),
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn render_structure() {
+    let svg = render(2);
+    assert_eq!(svg.name, "svg");
+    assert_eq!(svg.attribute("xmlns"), Some(SVG_NAMESPACE));
+    assert_eq!(count(&svg, "staff-line"), 10);
+    assert_eq!(count(&svg, "barline"), 3);
+    assert_eq!(count(&svg, "beam"), 1);
+    assert_eq!(count(&svg, "stem"), 6);
+    assert_eq!(glyphs(&svg, "gClef").len(), 1);
+    assert_eq!(glyphs(&svg, "fClef").len(), 1);
+    assert_eq!(glyphs(&svg, "accidentalFlat").len(), 4);
+    assert_eq!(glyphs(&svg, "accidentalSharp").len(), 1);
+    assert_eq!(glyphs(&svg, "timeSig3").len(), 2);
+    assert_eq!(glyphs(&svg, "flag8thDown").len(), 1);
+    assert_eq!(glyphs(&svg, "restQuarter").len(), 1);
+    assert_eq!(glyphs(&svg, "restWhole").len(), 1);
+    assert_eq!(glyphs(&svg, "augmentationDot").len(), 2);
+    assert_eq!(glyphs(&svg, "noteheadBlack").len(), 4);
+    assert_eq!(glyphs(&svg, "noteheadHalf").len(), 3);
+  }
+
+  #[test]
+  fn render_positions() {
+    let svg = render(2);
+
+    // Middle C sits on the first ledger line below the treble staff, and A5 on the first one above it
+    let heads = glyphs(&svg, "noteheadBlack");
+    assert_eq!(heads[0].1, 50.0);
+    assert_eq!(count(&svg, "ledger-line"), 2);
+
+    // Bass clef notes are placed relative to the second staff
+    let bass_top = 40.0 + DEFAULT_STAFF_DISTANCE;
+    let halves = glyphs(&svg, "noteheadHalf");
+    assert_eq!(halves[0].1, bass_top + 25.0);
+    assert_eq!(halves[1].1, bass_top + 15.0);
+
+    // The note in the second measure uses its default-x relative to the start of the measure
+    let measure_start = svg.elements[0]
+      .elements
+      .iter()
+      .filter(|element| element.attribute("class") == Some("barline"))
+      .map(|element| element.attribute("x1").unwrap().parse::<f64>().unwrap())
+      .next()
+      .unwrap();
+    assert_eq!(halves[2].0, measure_start + 50.0);
+  }
+
+  #[test]
+  fn render_scaling() {
+    let svg = render(1);
+    let view_box: Vec<f64> = svg
+      .attribute("viewBox")
+      .unwrap()
+      .split(' ')
+      .map(|value| value.parse().unwrap())
+      .collect();
+    let width: f64 = svg.attribute("width").unwrap().trim_end_matches("mm").parse().unwrap();
+    assert!((width - view_box[2] * 0.2).abs() < 0.01);
+    assert!(render(3).attribute("viewBox") != svg.attribute("viewBox"));
+  }
+
+  #[test]
+  fn render_invalid() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    assert!(render_svg(&score, "P2", 1).is_err());
+    assert!(render_svg(&score, "P1", 0).is_err());
+  }
+
+  #[test]
+  fn render_file() {
+    let score = crate::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    let svg = render_svg(&score, "P1", 4).unwrap();
+    assert!(xml_parser::parse_from_string(&svg).is_ok());
+  }
+}
@@ -0,0 +1,759 @@
+//! Renders [ScorePartwise][crate::elements::ScorePartwise] scores into 16-bit stereo PCM audio using a small
+//! built-in synthesizer.
+//!
+//! Every part is walked using the same timing model as the format converters, and note onsets are converted from
+//! quarter notes into seconds using the tempo given by `<sound tempo>` attributes and `<metronome>` marks, which
+//! defaults to 120 quarter notes per minute. Note velocities follow `<dynamics>` directions, the `dynamics`
+//! attribute of `<sound>` elements, and the `dynamics` attribute of individual notes.
+//!
+//! Each [ScoreInstrument][crate::elements::ScoreInstrument] is played by a simple oscillator chosen from its
+//! `<instrument-sound>` or, failing that, its General MIDI program. Unpitched notes are played as decaying noise
+//! bursts, and every voice is panned using the `<pan>` and `<volume>` of its matching
+//! [MidiInstrument][crate::elements::MidiInstrument]. No randomness is involved, so rendering the same score twice
+//! always produces identical output.
+
+use crate::elements::{
+  AudibleType, BeatEquation, Direction, DirectionTypeContents, Dynamics, MetronomeContents, MidiInstrument, Note,
+  PartListElement, ScorePart, ScorePartwise, Sound,
+};
+use crate::util::{
+  dotted_length, note_audible, note_is_cue, note_is_grace, note_tied, parse_decimal, part_measures, pitch_to_midi,
+  Fraction, MeasureEvent, PartTimeline,
+};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use musicxml_internal::ElementSerializer;
+
+const DEFAULT_TEMPO: f64 = 120.0;
+const DEFAULT_VELOCITY: f64 = 80.0;
+const FORTE_VELOCITY: f64 = 90.0;
+const MASTER_GAIN: f64 = 0.25;
+const ATTACK_SECONDS: f64 = 0.005;
+const RELEASE_SECONDS: f64 = 0.05;
+const DECAY_SECONDS: f64 = 0.25;
+
+/// Frequency ratios of the twelve equal-tempered semitones within an octave.
+const SEMITONE_RATIOS: [f64; 12] = [
+  1.0,
+  1.059_463_094_359_295_3,
+  1.122_462_048_309_373,
+  1.189_207_115_002_721,
+  1.259_921_049_894_873_2,
+  1.334_839_854_170_034_4,
+  core::f64::consts::SQRT_2,
+  1.498_307_076_876_681_5,
+  1.587_401_051_968_199_4,
+  1.681_792_830_507_429,
+  1.781_797_436_280_678_5,
+  1.887_748_625_363_386_8,
+];
+
+/// MusicXML dynamics and the MIDI velocities of the note they accent and of the notes that follow it.
+///
+/// Accents such as `sf` leave the velocity of subsequent notes unchanged.
+const DYNAMIC_LEVELS: [(&str, f64, Option<f64>); 26] = [
+  ("pppppp", 5.0, Some(5.0)),
+  ("ppppp", 8.0, Some(8.0)),
+  ("pppp", 12.0, Some(12.0)),
+  ("ppp", 20.0, Some(20.0)),
+  ("pp", 36.0, Some(36.0)),
+  ("p", 52.0, Some(52.0)),
+  ("mp", 66.0, Some(66.0)),
+  ("mf", 80.0, Some(80.0)),
+  ("f", 96.0, Some(96.0)),
+  ("ff", 110.0, Some(110.0)),
+  ("fff", 120.0, Some(120.0)),
+  ("ffff", 124.0, Some(124.0)),
+  ("fffff", 126.0, Some(126.0)),
+  ("ffffff", 127.0, Some(127.0)),
+  ("fp", 96.0, Some(52.0)),
+  ("pf", 52.0, Some(96.0)),
+  ("sf", 112.0, None),
+  ("sfz", 112.0, None),
+  ("sffz", 124.0, None),
+  ("sfp", 112.0, Some(52.0)),
+  ("sfpp", 112.0, Some(36.0)),
+  ("sfzp", 112.0, Some(52.0)),
+  ("fz", 112.0, None),
+  ("rf", 104.0, None),
+  ("rfz", 104.0, None),
+  ("n", 0.0, Some(0.0)),
+];
+
+/// Standard sound ID prefixes and the waveforms and envelopes used to play them.
+const SOUND_VOICES: [(&str, Waveform, bool); 12] = [
+  ("keyboard.organ", Waveform::Square, false),
+  ("keyboard.", Waveform::Triangle, true),
+  ("pluck.", Waveform::Triangle, true),
+  ("pitched-percussion.", Waveform::Sine, true),
+  ("drum.", Waveform::Sine, true),
+  ("metal.", Waveform::Sine, true),
+  ("wood.", Waveform::Sine, true),
+  ("strings.", Waveform::Sawtooth, false),
+  ("brass.", Waveform::Sawtooth, false),
+  ("wind.", Waveform::Square, false),
+  ("voice.", Waveform::Sine, false),
+  ("synth.", Waveform::Square, false),
+];
+
+/// General MIDI instrument families, in program order, and the waveforms and envelopes used to play them.
+const PROGRAM_VOICES: [(Waveform, bool); 16] = [
+  (Waveform::Triangle, true),  // Piano
+  (Waveform::Sine, true),      // Chromatic percussion
+  (Waveform::Square, false),   // Organ
+  (Waveform::Triangle, true),  // Guitar
+  (Waveform::Triangle, true),  // Bass
+  (Waveform::Sawtooth, false), // Strings
+  (Waveform::Sawtooth, false), // Ensemble
+  (Waveform::Sawtooth, false), // Brass
+  (Waveform::Square, false),   // Reed
+  (Waveform::Sine, false),     // Pipe
+  (Waveform::Square, false),   // Synth lead
+  (Waveform::Sine, false),     // Synth pad
+  (Waveform::Sine, false),     // Synth effects
+  (Waveform::Triangle, true),  // Ethnic
+  (Waveform::Sine, true),      // Percussive
+  (Waveform::Sine, true),      // Sound effects
+];
+
+// --------------------------------------------------------------------------------------------------------------------
+// SYNTHESIS ----------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Waveform {
+  Sine,
+  Triangle,
+  Square,
+  Sawtooth,
+}
+
+/// The oscillator and mixer settings used to play the notes of a single score instrument.
+#[derive(Debug, Clone, Copy)]
+struct Voice {
+  waveform: Waveform,
+  percussive: bool,
+  unpitched: bool,
+  pan: f64,
+  volume: f64,
+}
+
+impl Default for Voice {
+  fn default() -> Self {
+    Voice {
+      waveform: Waveform::Triangle,
+      percussive: false,
+      unpitched: false,
+      pan: 0.0,
+      volume: 1.0,
+    }
+  }
+}
+
+impl Voice {
+  fn update(&mut self, midi_instrument: &MidiInstrument) {
+    let content = &midi_instrument.content;
+    if let Some(program) = &content.midi_program {
+      let family = usize::from(program.content.0.saturating_sub(1) / 8);
+      (self.waveform, self.percussive) = PROGRAM_VOICES[family.min(PROGRAM_VOICES.len() - 1)];
+    }
+    if content.midi_unpitched.is_some()
+      || content
+        .midi_channel
+        .as_ref()
+        .is_some_and(|channel| channel.content.0 == 10)
+    {
+      self.unpitched = true;
+    }
+    if let Some(pan) = &content.pan {
+      self.pan = pan_position(f64::from(pan.content.0));
+    }
+    if let Some(volume) = &content.volume {
+      self.volume = (volume.content.0 / 100.0).clamp(0.0, 1.0);
+    }
+  }
+}
+
+/// A single note to be synthesized, with all timing already resolved.
+struct RenderedNote {
+  start: Fraction,
+  end: Fraction,
+  key: i32,
+  velocity: f64,
+  voice: Voice,
+}
+
+/// Converts a MusicXML pan angle in degrees into a stereo position between -1 (left) and 1 (right).
+///
+/// Angles behind the listener are mirrored to the front, so that 180 degrees is centered.
+fn pan_position(degrees: f64) -> f64 {
+  let degrees = if degrees > 90.0 {
+    180.0 - degrees
+  } else if degrees < -90.0 {
+    -180.0 - degrees
+  } else {
+    degrees
+  };
+  (degrees / 90.0).clamp(-1.0, 1.0)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn fraction_value(fraction: Fraction) -> f64 {
+  fraction.num as f64 / fraction.den as f64
+}
+
+/// Returns the equal-tempered frequency of a MIDI key number, where key 69 is A4 at 440 Hz.
+fn key_frequency(key: i32) -> f64 {
+  let offset = key - 69;
+  let mut frequency = 440.0 * SEMITONE_RATIOS[offset.rem_euclid(12) as usize];
+  let octaves = offset.div_euclid(12);
+  for _ in 0..octaves.unsigned_abs() {
+    if octaves > 0 {
+      frequency *= 2.0;
+    } else {
+      frequency /= 2.0;
+    }
+  }
+  frequency
+}
+
+/// Approximates the sine of a full oscillator cycle, where `phase` lies in the range [0, 1).
+fn sine(phase: f64) -> f64 {
+  let mut x = (phase - 0.5) * core::f64::consts::TAU;
+  if x > core::f64::consts::FRAC_PI_2 {
+    x = core::f64::consts::PI - x;
+  } else if x < -core::f64::consts::FRAC_PI_2 {
+    x = -core::f64::consts::PI - x;
+  }
+  let x2 = x * x;
+  -x * (1.0 - x2 / 6.0 * (1.0 - x2 / 20.0 * (1.0 - x2 / 42.0 * (1.0 - x2 / 72.0 * (1.0 - x2 / 110.0)))))
+}
+
+fn oscillator(waveform: Waveform, phase: f64) -> f64 {
+  match waveform {
+    Waveform::Sine => sine(phase),
+    Waveform::Triangle => {
+      if phase < 0.5 {
+        4.0 * phase - 1.0
+      } else {
+        3.0 - 4.0 * phase
+      }
+    }
+    Waveform::Square => {
+      if phase < 0.5 {
+        0.5
+      } else {
+        -0.5
+      }
+    }
+    Waveform::Sawtooth => 0.6 * (2.0 * phase - 1.0),
+  }
+}
+
+/// Returns the envelope level of a note at `time` seconds after its onset.
+fn envelope(voice: &Voice, time: f64, length: f64) -> f64 {
+  let attack = (time / ATTACK_SECONDS).min(1.0);
+  if voice.percussive || voice.unpitched {
+    let decay = if voice.unpitched {
+      DECAY_SECONDS
+    } else {
+      length.max(DECAY_SECONDS)
+    };
+    attack * (1.0 - time / decay).max(0.0)
+  } else if time > length {
+    attack * (1.0 - (time - length) / RELEASE_SECONDS).max(0.0)
+  } else {
+    attack
+  }
+}
+
+/// Returns the number of seconds that a note continues sounding after its onset.
+fn sounding_length(voice: &Voice, length: f64) -> f64 {
+  if voice.unpitched {
+    DECAY_SECONDS
+  } else if voice.percussive {
+    length.max(DECAY_SECONDS)
+  } else {
+    length + RELEASE_SECONDS
+  }
+}
+
+/// Converts score positions in quarter notes into seconds using a list of tempo changes.
+struct TempoMap {
+  changes: Vec<(Fraction, f64)>,
+}
+
+impl TempoMap {
+  fn seconds(&self, position: Fraction) -> f64 {
+    let (mut seconds, mut last, mut tempo) = (0.0, Fraction::ZERO, DEFAULT_TEMPO);
+    for &(change, bpm) in &self.changes {
+      if change > position {
+        break;
+      }
+      seconds += fraction_value(change - last) * 60.0 / tempo;
+      (last, tempo) = (change, bpm);
+    }
+    seconds + fraction_value(position - last) * 60.0 / tempo
+  }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// SCORE TRAVERSAL ----------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// The playback state of a single part while its measures are being walked.
+struct PartRenderer<'a> {
+  voices: Vec<(&'a str, Voice)>,
+  velocity: f64,
+  accent: Option<f64>,
+  open_ties: BTreeMap<i32, usize>,
+}
+
+impl<'a> PartRenderer<'a> {
+  fn new(score_part: Option<&'a ScorePart>) -> Self {
+    let mut voices = Vec::new();
+    if let Some(score_part) = score_part {
+      for score_instrument in &score_part.content.score_instrument {
+        let mut voice = Voice::default();
+        if let Some(sound) = &score_instrument.content.instrument_sound {
+          if let Some(&(_, waveform, percussive)) = SOUND_VOICES
+            .iter()
+            .find(|(prefix, _, _)| sound.content.starts_with(prefix))
+          {
+            (voice.waveform, voice.percussive) = (waveform, percussive);
+          }
+        }
+        voices.push((score_instrument.attributes.id.0.as_str(), voice));
+      }
+      if voices.is_empty() {
+        voices.push(("", Voice::default()));
+      }
+      for midi_instrument in &score_part.content.midi_instrument {
+        let id = midi_instrument.attributes.id.0.as_str();
+        for (voice_id, voice) in &mut voices {
+          if *voice_id == id || voice_id.is_empty() {
+            voice.update(midi_instrument);
+          }
+        }
+      }
+    } else {
+      voices.push(("", Voice::default()));
+    }
+    PartRenderer {
+      voices,
+      velocity: DEFAULT_VELOCITY,
+      accent: None,
+      open_ties: BTreeMap::new(),
+    }
+  }
+
+  fn voice(&self, note: &Note) -> Voice {
+    note
+      .content
+      .instrument
+      .first()
+      .and_then(|instrument| self.voices.iter().find(|(id, _)| *id == instrument.attributes.id.0))
+      .map_or(self.voices[0].1, |(_, voice)| *voice)
+  }
+
+  fn apply_dynamics(&mut self, dynamics: &Dynamics) {
+    for marking in Dynamics::serialize(dynamics).elements {
+      if let Some(&(_, onset, sustain)) = DYNAMIC_LEVELS.iter().find(|(name, _, _)| *name == marking.name) {
+        self.velocity = sustain.unwrap_or(self.velocity);
+        self.accent = if sustain == Some(onset) { None } else { Some(onset) };
+      }
+    }
+  }
+
+  fn apply_sound(&mut self, sound: &Sound, position: Fraction, tempo: &mut Vec<(Fraction, f64)>) {
+    if let Some(bpm) = sound.attributes.tempo.as_ref().filter(|bpm| bpm.0 > 0.0) {
+      tempo.push((position, bpm.0));
+    }
+    if let Some(dynamics) = &sound.attributes.dynamics {
+      self.velocity = (dynamics.0 * FORTE_VELOCITY / 100.0).min(127.0);
+      self.accent = None;
+    }
+    if let Some(pan) = &sound.attributes.pan {
+      for (_, voice) in &mut self.voices {
+        voice.pan = pan_position(f64::from(pan.0));
+      }
+    }
+    for midi_instrument in &sound.content.midi_instrument {
+      let id = midi_instrument.attributes.id.0.as_str();
+      if let Some((_, voice)) = self.voices.iter_mut().find(|(voice_id, _)| *voice_id == id) {
+        voice.update(midi_instrument);
+      }
+    }
+  }
+
+  fn apply_direction(&mut self, direction: &Direction, position: Fraction, tempo: &mut Vec<(Fraction, f64)>) {
+    for direction_type in &direction.content.direction_type {
+      match &direction_type.content {
+        DirectionTypeContents::Dynamics(dynamics) => {
+          for dynamics in dynamics {
+            self.apply_dynamics(dynamics);
+          }
+        }
+        DirectionTypeContents::Metronome(metronome) => {
+          if let MetronomeContents::BeatBased(beat_based) = &metronome.content {
+            if let BeatEquation::BPM(per_minute) = &beat_based.equals {
+              let beat = dotted_length(
+                &beat_based.beat_unit.content,
+                u8::try_from(beat_based.beat_unit_dot.len()).unwrap_or(u8::MAX),
+              );
+              if let Some(bpm) = parse_decimal(&per_minute.content).filter(Fraction::is_positive) {
+                tempo.push((position, fraction_value(bpm * beat)));
+              }
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+    if let Some(sound) = &direction.content.sound {
+      self.apply_sound(sound, position, tempo);
+    }
+  }
+
+  fn add_note(&mut self, note: &Note, start: Fraction, duration: Fraction, notes: &mut Vec<RenderedNote>) {
+    let mut voice = self.voice(note);
+    let key = match note_audible(note) {
+      AudibleType::Pitch(pitch) => pitch_to_midi(pitch),
+      AudibleType::Unpitched(_) => {
+        voice.unpitched = true;
+        0
+      }
+      AudibleType::Rest(_) => return,
+    };
+    let (tie_start, tie_stop) = note_tied(note);
+    if tie_stop && !voice.unpitched {
+      if let Some(index) = self.open_ties.remove(&key) {
+        notes[index].end = start + duration;
+        if tie_start {
+          self.open_ties.insert(key, index);
+        }
+        return;
+      }
+    }
+    let velocity = note.attributes.dynamics.as_ref().map_or_else(
+      || self.accent.unwrap_or(self.velocity),
+      |dynamics| (dynamics.0 * FORTE_VELOCITY / 100.0).min(127.0),
+    );
+    if tie_start && !voice.unpitched {
+      self.open_ties.insert(key, notes.len());
+    }
+    notes.push(RenderedNote {
+      start,
+      end: start + duration,
+      key,
+      velocity,
+      voice,
+    });
+  }
+}
+
+/// Returns the onset of every measure in quarter notes, using the longest part to determine each measure's length.
+fn measure_starts(score: &ScorePartwise) -> Vec<Fraction> {
+  let mut lengths: Vec<Fraction> = Vec::new();
+  for part in &score.content.part {
+    let mut timeline = PartTimeline::default();
+    for (index, measure) in part_measures(part).enumerate() {
+      let length = timeline
+        .measure(measure)
+        .events
+        .iter()
+        .map(|event| event.position + event.duration)
+        .max()
+        .unwrap_or(Fraction::ZERO);
+      if index < lengths.len() {
+        lengths[index] = lengths[index].max(length);
+      } else {
+        lengths.push(length);
+      }
+    }
+  }
+  let mut position = Fraction::ZERO;
+  lengths
+    .into_iter()
+    .map(|length| {
+      let start = position;
+      position += length;
+      start
+    })
+    .collect()
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// WAV OUTPUT ---------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+fn mix_note(samples: &mut [f64], note: &RenderedNote, tempo: &TempoMap, sample_rate: u32, seed: u32) {
+  let rate = f64::from(sample_rate);
+  let onset = tempo.seconds(note.start);
+  let length = tempo.seconds(note.end) - onset;
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  let (first_frame, frame_count) = (
+    (onset * rate) as usize,
+    (sounding_length(&note.voice, length) * rate) as usize,
+  );
+  let amplitude = MASTER_GAIN * note.voice.volume * note.velocity / 127.0;
+  let (left, right) = (
+    amplitude * (1.0 - note.voice.pan).min(1.0),
+    amplitude * (1.0 + note.voice.pan).min(1.0),
+  );
+  let step = key_frequency(note.key) / rate;
+  let (mut phase, mut noise) = (0.0, seed.wrapping_mul(2_654_435_761) | 1);
+  for frame in 0..frame_count {
+    let index = 2 * (first_frame + frame);
+    if index + 1 >= samples.len() {
+      break;
+    }
+    let value = if note.voice.unpitched {
+      noise = noise.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+      f64::from(noise >> 16) / 32768.0 - 1.0
+    } else {
+      phase += step;
+      while phase >= 1.0 {
+        phase -= 1.0;
+      }
+      oscillator(note.voice.waveform, phase)
+    };
+    #[allow(clippy::cast_precision_loss)]
+    let level = value * envelope(&note.voice, frame as f64 / rate, length);
+    samples[index] += level * left;
+    samples[index + 1] += level * right;
+  }
+}
+
+fn write_wav(samples: &[f64], sample_rate: u32) -> Result<Vec<u8>, String> {
+  let data_length = u32::try_from(samples.len() * 2)
+    .ok()
+    .filter(|length| *length <= u32::MAX - 36)
+    .ok_or_else(|| String::from("Rendered audio is too long to be stored in a WAV file"))?;
+  let mut wav = Vec::with_capacity(44 + samples.len() * 2);
+  wav.extend_from_slice(b"RIFF");
+  wav.extend_from_slice(&(36 + data_length).to_le_bytes());
+  wav.extend_from_slice(b"WAVEfmt ");
+  wav.extend_from_slice(&16u32.to_le_bytes());
+  wav.extend_from_slice(&1u16.to_le_bytes());
+  wav.extend_from_slice(&2u16.to_le_bytes());
+  wav.extend_from_slice(&sample_rate.to_le_bytes());
+  wav.extend_from_slice(&sample_rate.saturating_mul(4).to_le_bytes());
+  wav.extend_from_slice(&4u16.to_le_bytes());
+  wav.extend_from_slice(&16u16.to_le_bytes());
+  wav.extend_from_slice(b"data");
+  wav.extend_from_slice(&data_length.to_le_bytes());
+  for sample in samples {
+    #[allow(clippy::cast_possible_truncation)]
+    let sample = (sample.clamp(-1.0, 1.0) * 32767.0) as i16;
+    wav.extend_from_slice(&sample.to_le_bytes());
+  }
+  Ok(wav)
+}
+
+/// Renders a [ScorePartwise] into a 16-bit stereo RIFF WAV buffer at the given sample rate.
+///
+/// Repeats and jumps are not expanded, so the score is played exactly once from beginning to end. Grace notes and
+/// cue notes are not played.
+///
+/// # Errors
+///
+/// Returns an error if the sample rate is zero, if the score does not contain any parts, or if the rendered audio
+/// is too long to be stored in a WAV file.
+pub fn render_wav(score: &ScorePartwise, sample_rate: u32) -> Result<Vec<u8>, String> {
+  if sample_rate == 0 {
+    return Err(String::from("Sample rate must be greater than zero"));
+  }
+  if score.content.part.is_empty() {
+    return Err(String::from("Score does not contain any parts"));
+  }
+  let score_parts: Vec<&ScorePart> = score
+    .content
+    .part_list
+    .content
+    .content
+    .iter()
+    .filter_map(|element| match element {
+      PartListElement::ScorePart(score_part) => Some(score_part),
+      PartListElement::PartGroup(_) => None,
+    })
+    .collect();
+  let starts = measure_starts(score);
+  let mut tempo = Vec::new();
+  let mut notes = Vec::new();
+  for part in &score.content.part {
+    let score_part = score_parts
+      .iter()
+      .find(|score_part| score_part.attributes.id.0 == part.attributes.id.0)
+      .copied();
+    let mut renderer = PartRenderer::new(score_part);
+    let mut timeline = PartTimeline::default();
+    for (measure, start) in part_measures(part).zip(starts.iter().copied()) {
+      for event in timeline.measure(measure).events {
+        let position = start + event.position;
+        match event.event {
+          MeasureEvent::Notes(chord) => {
+            if note_is_grace(chord[0]) || note_is_cue(chord[0]) {
+              continue;
+            }
+            for note in chord {
+              renderer.add_note(note, position, event.duration, &mut notes);
+            }
+            renderer.accent = None;
+          }
+          MeasureEvent::Direction(direction) => renderer.apply_direction(direction, position, &mut tempo),
+          MeasureEvent::Sound(sound) => renderer.apply_sound(sound, position, &mut tempo),
+          MeasureEvent::Attributes(_) | MeasureEvent::Harmony(_) => {}
+        }
+      }
+    }
+  }
+  tempo.sort_by_key(|(position, _)| *position);
+  let tempo = TempoMap { changes: tempo };
+  let duration = notes
+    .iter()
+    .map(|note| {
+      let onset = tempo.seconds(note.start);
+      onset + sounding_length(&note.voice, tempo.seconds(note.end) - onset)
+    })
+    .fold(0.0, f64::max);
+  // Extremely slow tempos can make the duration infinite, so it is bounded before being converted into frames
+  let frames = duration * f64::from(sample_rate);
+  if !frames.is_finite() || frames >= f64::from(u32::MAX / 4) {
+    return Err(String::from("Rendered audio is too long to be stored in a WAV file"));
+  }
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  let frames = (frames.max(0.0) as usize)
+    .checked_add(1)
+    .ok_or_else(|| String::from("Rendered audio is too long to be stored in a WAV file"))?;
+  let mut samples = vec![0.0; 2 * frames];
+  for (seed, note) in (1..).zip(notes.iter()) {
+    mix_note(&mut samples, note, &tempo, sample_rate, seed);
+  }
+  write_wav(&samples, sample_rate)
+}
+
+#[cfg(test)]
+mod wav_tests {
+  use super::*;
+  use crate::parser::parse_from_xml_str;
+
+  const SCORE: &str = "<score-partwise version=\"4.0\">
+    <part-list>
+      <score-part id=\"P1\">
+        <part-name>Flute</part-name>
+        <score-instrument id=\"P1-I1\"><instrument-name>Flute</instrument-name><instrument-sound>wind.flutes.flute</instrument-sound></score-instrument>
+        <midi-instrument id=\"P1-I1\"><midi-channel>1</midi-channel><midi-program>74</midi-program><pan>-90</pan></midi-instrument>
+      </score-part>
+      <score-part id=\"P2\">
+        <part-name>Drums</part-name>
+        <score-instrument id=\"P2-I1\"><instrument-name>Snare Drum</instrument-name></score-instrument>
+        <midi-instrument id=\"P2-I1\"><midi-channel>10</midi-channel><midi-unpitched>39</midi-unpitched><pan>90</pan></midi-instrument>
+      </score-part>
+    </part-list>
+    <part id=\"P1\">
+      <measure number=\"1\">
+        <attributes><divisions>2</divisions><time><beats>2</beats><beat-type>4</beat-type></time></attributes>
+        <direction>
+          <direction-type><metronome><beat-unit>quarter</beat-unit><per-minute>60</per-minute></metronome></direction-type>
+          <direction-type><dynamics><p/></dynamics></direction-type>
+        </direction>
+        <note><pitch><step>A</step><octave>4</octave></pitch><duration>2</duration><tie type=\"start\"/><type>quarter</type></note>
+        <note><pitch><step>A</step><octave>4</octave></pitch><duration>2</duration><tie type=\"stop\"/><type>quarter</type></note>
+      </measure>
+      <measure number=\"2\">
+        <direction><direction-type><dynamics><ff/></dynamics></direction-type><sound tempo=\"120\"/></direction>
+        <note><pitch><step>C</step><octave>5</octave></pitch><duration>2</duration><type>quarter</type></note>
+        <note><rest/><duration>2</duration><type>quarter</type></note>
+      </measure>
+    </part>
+    <part id=\"P2\">
+      <measure number=\"1\">
+        <attributes><divisions>1</divisions></attributes>
+        <note><unpitched><display-step>C</display-step><display-octave>5</display-octave></unpitched><duration>2</duration><instrument id=\"P2-I1\"/><type>half</type></note>
+      </measure>
+      <measure number=\"2\">
+        <note><unpitched><display-step>C</display-step><display-octave>5</display-octave></unpitched><duration>1</duration><instrument id=\"P2-I1\"/><type>quarter</type></note>
+        <note><rest/><duration>1</duration><type>quarter</type></note>
+      </measure>
+    </part>
+  </score-partwise>";
+
+  fn read_u32(wav: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([wav[offset], wav[offset + 1], wav[offset + 2], wav[offset + 3]])
+  }
+
+  fn channel_peaks(wav: &[u8], from_seconds: f64, to_seconds: f64) -> (i32, i32) {
+    let rate = f64::from(read_u32(wav, 24));
+    let (first, last) = ((from_seconds * rate) as usize, (to_seconds * rate) as usize);
+    wav[44..]
+      .chunks_exact(4)
+      .skip(first)
+      .take(last - first)
+      .map(|frame| {
+        (
+          i32::from(i16::from_le_bytes([frame[0], frame[1]])).abs(),
+          i32::from(i16::from_le_bytes([frame[2], frame[3]])).abs(),
+        )
+      })
+      .fold((0, 0), |(left, right), (l, r)| (left.max(l), right.max(r)))
+  }
+
+  #[test]
+  fn render_header() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let wav = render_wav(&score, 8000).unwrap();
+    assert_eq!(&wav[0..4], b"RIFF");
+    assert_eq!(&wav[8..16], b"WAVEfmt ");
+    assert_eq!(read_u32(&wav, 4) as usize, wav.len() - 8);
+    assert_eq!(read_u32(&wav, 24), 8000);
+    assert_eq!(read_u32(&wav, 28), 32000);
+    assert_eq!(&wav[36..40], b"data");
+    assert_eq!(read_u32(&wav, 40) as usize, wav.len() - 44);
+
+    // Two quarters at 60 bpm, then a quarter and a rest at 120 bpm, plus the release of the final note
+    let seconds = ((wav.len() - 44) / 4) as f64 / 8000.0;
+    assert!((seconds - (2.5 + RELEASE_SECONDS)).abs() < 0.001);
+  }
+
+  #[test]
+  fn render_deterministic() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    assert_eq!(render_wav(&score, 11025), render_wav(&score, 11025));
+  }
+
+  #[test]
+  fn render_pan_and_dynamics() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let wav = render_wav(&score, 8000).unwrap();
+
+    // The tied flute note is panned hard left, while the drum is panned hard right and only lasts a short while
+    let (left, right) = channel_peaks(&wav, 1.0, 2.0);
+    assert!(left > 0);
+    assert_eq!(right, 0);
+    let (left, right) = channel_peaks(&wav, 0.0, 0.2);
+    assert!(left > 0 && right > 0);
+
+    // The fortissimo note in the second measure is louder than the piano notes in the first
+    let (piano, _) = channel_peaks(&wav, 1.0, 2.0);
+    let (fortissimo, _) = channel_peaks(&wav, 2.0, 2.5);
+    assert!(2 * fortissimo > 3 * piano);
+  }
+
+  #[test]
+  fn render_invalid() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    assert!(render_wav(&score, 0).is_err());
+    let slow = parse_from_xml_str::<ScorePartwise>(&SCORE.replace("tempo=\"120\"", "tempo=\"1e-300\"")).unwrap();
+    assert!(render_wav(&slow, 8000).is_err());
+  }
+
+  #[test]
+  fn render_file() {
+    let score = crate::read_score_partwise("tests/MozaChloSample.musicxml").unwrap();
+    let wav = render_wav(&score, 8000).unwrap();
+    assert!(wav.len() > 44);
+    assert!(wav[44..].iter().any(|byte| *byte != 0));
+  }
+}
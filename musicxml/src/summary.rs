@@ -0,0 +1,832 @@
+use crate::datatypes::ClefSign;
+use crate::elements::{
+  Attributes, BeatEquation, Clef, Direction, DirectionTypeContents, Grace, Harmony, KeyContents, Lyric,
+  MetronomeContents, Note, Part, PartListElement, ScorePart, ScorePartwise, Sound, TimeModification, Tuplet, Unpitched,
+};
+use crate::util::{
+  alter_symbols, dotted_length, note_is_rest, note_pitch, parse_decimal, part_measures, pitch_alter, pitch_to_midi,
+  step_letter, Fraction, MeasureEvent, PartTimeline,
+};
+use crate::visit::for_each;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use musicxml_internal::DatatypeSerializer;
+
+/// Tempo in quarter notes per minute assumed before the first tempo marking of a score.
+const DEFAULT_TEMPO: f64 = 120.0;
+
+/// A pitch at one end of the [Ambitus] of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbitusPitch {
+  /// The written name of the pitch in scientific pitch notation, such as `"F#4"` or `"Bb2"`.
+  pub name: String,
+  /// The MIDI note number of the pitch, where middle C (C4) is 60.
+  pub midi: i32,
+}
+
+/// The range of written pitches used by a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambitus {
+  /// The lowest written pitch.
+  pub lowest: AmbitusPitch,
+  /// The highest written pitch.
+  pub highest: AmbitusPitch,
+}
+
+/// Summary information about a single [Part].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartSummary {
+  /// The `id` of the part.
+  pub id: String,
+  /// The [PartName][crate::elements::PartName] of the part, or an empty string if it has no score part.
+  pub name: String,
+  /// The names of every [ScoreInstrument][crate::elements::ScoreInstrument] in the part.
+  pub instruments: Vec<String>,
+  /// The number of measures in the part.
+  pub measures: usize,
+  /// The number of notes in the part, counting every note of a chord and including grace and cue notes.
+  pub notes: usize,
+  /// The number of rests in the part.
+  pub rests: usize,
+  /// The range of written pitches in the part, or `None` if it contains no pitched notes.
+  pub ambitus: Option<Ambitus>,
+}
+
+/// A key signature taking effect at the start of, or within, a measure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyChange {
+  /// The `number` of the measure containing the key signature.
+  pub measure_number: String,
+  /// The number of sharps (if positive) or flats (if negative) in the key signature.
+  pub fifths: i8,
+  /// The mode of the key signature, such as `"major"` or `"minor"`, if specified.
+  pub mode: Option<String>,
+}
+
+/// A time signature taking effect at the start of, or within, a measure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeChange {
+  /// The `number` of the measure containing the time signature.
+  pub measure_number: String,
+  /// The time signature written as a fraction, such as `"3/4"`, with any composite parts joined by `"+"`.
+  pub signature: String,
+}
+
+/// A tempo marking given by a [Metronome][crate::elements::Metronome] mark or [Sound] tempo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TempoMarking {
+  /// The `number` of the measure containing the tempo marking.
+  pub measure_number: String,
+  /// Any [Words][crate::elements::Words] written as part of the same direction, such as `"Allegro"`.
+  pub text: Option<String>,
+  /// The tempo in quarter notes per minute, if it can be determined.
+  pub quarters_per_minute: Option<f64>,
+}
+
+/// A notational feature that may appear anywhere in a score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScoreFeature {
+  /// A note has a [TimeModification] or [Tuplet].
+  Tuplets,
+  /// The score contains [Grace] notes.
+  GraceNotes,
+  /// A note has a [Lyric].
+  Lyrics,
+  /// The score contains [Harmony] elements.
+  ChordSymbols,
+  /// The score contains [Unpitched] notes or percussion [Clefs][Clef].
+  Percussion,
+  /// The score contains tablature [Clefs][Clef].
+  Tablature,
+}
+
+/// Summary information about a score, suitable for cataloguing.
+///
+/// Durations are measured as written, playing every measure exactly once without following repeats.
+///
+/// # Example
+///
+/// ```no_run
+/// use musicxml::read_score_partwise;
+/// use musicxml::summary::ScoreSummary;
+///
+/// let score = read_score_partwise("path/to/file.musicxml").unwrap();
+/// let summary = ScoreSummary::from(&score);
+/// println!("{:?} has {} measures", summary.title, summary.measures);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreSummary {
+  /// The [WorkTitle][crate::elements::WorkTitle] of the score, or its
+  /// [MovementTitle][crate::elements::MovementTitle] if it has no work title.
+  pub title: Option<String>,
+  /// The [MovementTitle][crate::elements::MovementTitle] of the score.
+  pub movement_title: Option<String>,
+  /// Every [Creator][crate::elements::Creator] of type `"composer"`.
+  pub composers: Vec<String>,
+  /// Every [Creator][crate::elements::Creator] of type `"lyricist"`.
+  pub lyricists: Vec<String>,
+  /// A summary of every part, in score order.
+  pub parts: Vec<PartSummary>,
+  /// The number of measures in the longest part.
+  pub measures: usize,
+  /// The total duration of the score in quarter-note beats.
+  pub duration_quarters: f64,
+  /// The total duration of the score in seconds, or `None` if the score contains no tempo.
+  ///
+  /// A tempo of 120 quarter notes per minute is assumed before the first tempo marking.
+  pub duration_seconds: Option<f64>,
+  /// Every change of key signature in the first part, including the initial key signature.
+  pub key_changes: Vec<KeyChange>,
+  /// Every change of time signature in the first part, including the initial time signature.
+  pub time_changes: Vec<TimeChange>,
+  /// Every tempo marking in the score, in order.
+  pub tempo_markings: Vec<TempoMarking>,
+  /// The total number of notes in all parts.
+  pub notes: usize,
+  /// The total number of rests in all parts.
+  pub rests: usize,
+  /// The notational features used by the score.
+  pub features: BTreeSet<ScoreFeature>,
+}
+
+/// Converts a fraction into a floating-point number.
+#[allow(clippy::cast_precision_loss)]
+fn fraction_value(fraction: Fraction) -> f64 {
+  fraction.num as f64 / fraction.den as f64
+}
+
+/// Returns the tempo marking given by a direction, if it contains a metronome mark or a sound tempo.
+///
+/// The playback tempo of a sound takes precedence over the tempo shown by a metronome mark.
+fn direction_tempo(direction: &Direction, measure_number: &str) -> Option<TempoMarking> {
+  let mut words: Vec<&str> = Vec::new();
+  let mut metronome = false;
+  let mut tempo = direction
+    .content
+    .sound
+    .as_ref()
+    .and_then(|sound| sound.attributes.tempo.as_ref())
+    .map(|tempo| tempo.0);
+  for direction_type in &direction.content.direction_type {
+    match &direction_type.content {
+      DirectionTypeContents::Words(contents) => {
+        words.extend(
+          contents
+            .iter()
+            .map(|words| words.content.trim())
+            .filter(|text| !text.is_empty()),
+        );
+      }
+      DirectionTypeContents::Metronome(contents) => {
+        metronome = true;
+        if let MetronomeContents::BeatBased(beat_based) = &contents.content {
+          if let BeatEquation::BPM(per_minute) = &beat_based.equals {
+            let beat = dotted_length(
+              &beat_based.beat_unit.content,
+              u8::try_from(beat_based.beat_unit_dot.len()).unwrap_or(u8::MAX),
+            );
+            if let Some(bpm) = parse_decimal(&per_minute.content).filter(Fraction::is_positive) {
+              tempo = tempo.or(Some(fraction_value(bpm * beat)));
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+  (metronome || tempo.is_some()).then(|| TempoMarking {
+    measure_number: measure_number.to_string(),
+    text: (!words.is_empty()).then(|| words.join(" ")),
+    quarters_per_minute: tempo.filter(|tempo| *tempo > 0.0),
+  })
+}
+
+/// Returns the tempo marking given by a standalone sound element, if it specifies a tempo.
+fn sound_tempo(sound: &Sound, measure_number: &str) -> Option<TempoMarking> {
+  sound.attributes.tempo.as_ref().map(|tempo| TempoMarking {
+    measure_number: measure_number.to_string(),
+    text: None,
+    quarters_per_minute: Some(tempo.0).filter(|tempo| *tempo > 0.0),
+  })
+}
+
+impl PartSummary {
+  /// Creates an empty summary of a part, taking its name and instruments from the matching score part.
+  fn new(part: &Part, score_part: Option<&ScorePart>) -> Self {
+    PartSummary {
+      id: part.attributes.id.0.clone(),
+      name: score_part.map_or(String::new(), |score_part| score_part.content.part_name.content.clone()),
+      instruments: score_part
+        .iter()
+        .flat_map(|score_part| score_part.content.score_instrument.iter())
+        .map(|instrument| instrument.content.instrument_name.content.clone())
+        .collect(),
+      measures: 0,
+      notes: 0,
+      rests: 0,
+      ambitus: None,
+    }
+  }
+
+  /// Counts a note or rest of the part, widening the ambitus to include its pitch.
+  fn count_note(&mut self, note: &Note) {
+    if note_is_rest(note) {
+      self.rests += 1;
+      return;
+    }
+    self.notes += 1;
+    if let Some(pitch) = note_pitch(note) {
+      let pitch = AmbitusPitch {
+        name: format!(
+          "{}{}{}",
+          step_letter(&pitch.content.step.content),
+          alter_symbols(pitch_alter(pitch)),
+          *pitch.content.octave.content
+        ),
+        midi: pitch_to_midi(pitch),
+      };
+      let ambitus = self.ambitus.get_or_insert_with(|| Ambitus {
+        lowest: pitch.clone(),
+        highest: pitch.clone(),
+      });
+      if pitch.midi < ambitus.lowest.midi {
+        ambitus.lowest = pitch;
+      } else if pitch.midi > ambitus.highest.midi {
+        ambitus.highest = pitch;
+      }
+    }
+  }
+}
+
+/// Returns the fifths and mode of the first explicit key signature in an attributes element.
+fn key_signature(attributes: &Attributes) -> Option<(i8, Option<String>)> {
+  attributes.content.key.iter().find_map(|key| match &key.content {
+    KeyContents::Explicit(key) => Some((
+      key.fifths.content.0,
+      key
+        .mode
+        .as_ref()
+        .map(|mode| DatatypeSerializer::serialize(&mode.content)),
+    )),
+    KeyContents::Relative(_) => None,
+  })
+}
+
+/// Returns the first time signature in an attributes element written as a fraction, such as `"3/4"`.
+fn time_signature(attributes: &Attributes) -> Option<String> {
+  let time = attributes.content.time.first()?;
+  let signature = time
+    .content
+    .beats
+    .iter()
+    .map(|beats| format!("{}/{}", beats.beats.content, beats.beat_type.content))
+    .collect::<Vec<_>>()
+    .join("+");
+  (!signature.is_empty()).then_some(signature)
+}
+
+/// Records the key and time signatures of an attributes element wherever they differ from the previous ones.
+fn push_signature_changes(
+  attributes: &Attributes,
+  measure_number: &str,
+  key_changes: &mut Vec<KeyChange>,
+  time_changes: &mut Vec<TimeChange>,
+) {
+  if let Some((fifths, mode)) = key_signature(attributes) {
+    if key_changes
+      .last()
+      .is_none_or(|last| (last.fifths, &last.mode) != (fifths, &mode))
+    {
+      key_changes.push(KeyChange {
+        measure_number: measure_number.to_string(),
+        fifths,
+        mode,
+      });
+    }
+  }
+  if let Some(signature) = time_signature(attributes) {
+    if time_changes.last().is_none_or(|last| last.signature != signature) {
+      time_changes.push(TimeChange {
+        measure_number: measure_number.to_string(),
+        signature,
+      });
+    }
+  }
+}
+
+/// Returns the duration of a score in quarter notes and in seconds, given the length of each measure and the
+/// measure index and position of each tempo marking.
+fn score_duration(lengths: &[Fraction], tempos: &[(usize, Fraction, TempoMarking)]) -> (f64, Option<f64>) {
+  // Measures are laid end to end, with each as long as its longest part
+  let mut starts = Vec::with_capacity(lengths.len() + 1);
+  starts.push(Fraction::ZERO);
+  for length in lengths {
+    starts.push(*starts.last().unwrap_or(&Fraction::ZERO) + *length);
+  }
+  let total = fraction_value(*starts.last().unwrap_or(&Fraction::ZERO));
+  let changes: Vec<(f64, f64)> = tempos
+    .iter()
+    .filter_map(|(index, position, tempo)| {
+      Some((fraction_value(starts[*index] + *position), tempo.quarters_per_minute?))
+    })
+    .collect();
+  let seconds = (!changes.is_empty()).then(|| {
+    let (mut seconds, mut position, mut tempo) = (0.0, 0.0, DEFAULT_TEMPO);
+    for (start, new_tempo) in changes {
+      seconds += (start - position) * 60.0 / tempo;
+      (position, tempo) = (start, new_tempo);
+    }
+    seconds + (total - position) * 60.0 / tempo
+  });
+  (total, seconds)
+}
+
+/// Returns the notational features used anywhere in a score.
+fn score_features(score: &ScorePartwise) -> BTreeSet<ScoreFeature> {
+  let mut features = BTreeSet::new();
+  for_each(score, |_: &TimeModification, _| {
+    features.insert(ScoreFeature::Tuplets);
+  });
+  for_each(score, |_: &Tuplet, _| {
+    features.insert(ScoreFeature::Tuplets);
+  });
+  for_each(score, |_: &Grace, _| {
+    features.insert(ScoreFeature::GraceNotes);
+  });
+  for_each(score, |_: &Lyric, _| {
+    features.insert(ScoreFeature::Lyrics);
+  });
+  for_each(score, |_: &Harmony, _| {
+    features.insert(ScoreFeature::ChordSymbols);
+  });
+  for_each(score, |_: &Unpitched, _| {
+    features.insert(ScoreFeature::Percussion);
+  });
+  for_each(score, |clef: &Clef, _| match clef.content.sign.content {
+    ClefSign::Percussion => {
+      features.insert(ScoreFeature::Percussion);
+    }
+    ClefSign::TAB => {
+      features.insert(ScoreFeature::Tablature);
+    }
+    _ => {}
+  });
+  features
+}
+
+impl From<&ScorePartwise> for ScoreSummary {
+  fn from(score: &ScorePartwise) -> Self {
+    let movement_title = score.content.movement_title.as_ref().map(|title| title.content.clone());
+    let title = score
+      .content
+      .work
+      .as_ref()
+      .and_then(|work| work.content.work_title.as_ref())
+      .map(|title| title.content.clone())
+      .or_else(|| movement_title.clone());
+    let creators = |kind: &str| -> Vec<String> {
+      score
+        .content
+        .identification
+        .iter()
+        .flat_map(|identification| identification.content.creator.iter())
+        .filter(|creator| creator.attributes.r#type.as_ref().is_some_and(|other| other.0 == kind))
+        .map(|creator| creator.content.clone())
+        .collect()
+    };
+    let score_parts: Vec<_> = score
+      .content
+      .part_list
+      .content
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        PartListElement::ScorePart(score_part) => Some(score_part),
+        PartListElement::PartGroup(_) => None,
+      })
+      .collect();
+
+    let mut parts = Vec::new();
+    let mut lengths: Vec<Fraction> = Vec::new();
+    let mut tempos: Vec<(usize, Fraction, TempoMarking)> = Vec::new();
+    let (mut key_changes, mut time_changes): (Vec<KeyChange>, Vec<TimeChange>) = (Vec::new(), Vec::new());
+    for (part_index, part) in score.content.part.iter().enumerate() {
+      let score_part = score_parts
+        .iter()
+        .find(|score_part| score_part.attributes.id.0 == part.attributes.id.0);
+      let mut summary = PartSummary::new(part, score_part.copied());
+      let mut timeline = PartTimeline::default();
+      for (index, measure) in part_measures(part).enumerate() {
+        let number = measure.attributes.number.as_str();
+        summary.measures += 1;
+        if index == lengths.len() {
+          lengths.push(Fraction::ZERO);
+        }
+        for event in timeline.measure(measure).events {
+          lengths[index] = lengths[index].max(event.position + event.duration);
+          let tempo = match event.event {
+            MeasureEvent::Notes(notes) => {
+              for note in notes {
+                summary.count_note(note);
+              }
+              None
+            }
+            MeasureEvent::Attributes(attributes) if part_index == 0 => {
+              push_signature_changes(attributes, number, &mut key_changes, &mut time_changes);
+              None
+            }
+            MeasureEvent::Direction(direction) => direction_tempo(direction, number),
+            MeasureEvent::Sound(sound) => sound_tempo(sound, number),
+            _ => None,
+          };
+
+          // Tempo markings are often repeated in every part, so only the first occurrence is kept
+          if let Some(tempo) = tempo {
+            if !tempos
+              .iter()
+              .any(|(other_index, position, other)| (*other_index, *position, other) == (index, event.position, &tempo))
+            {
+              tempos.push((index, event.position, tempo));
+            }
+          }
+        }
+      }
+      parts.push(summary);
+    }
+    tempos.sort_by_key(|(index, position, _)| (*index, *position));
+
+    let (total, duration_seconds) = score_duration(&lengths, &tempos);
+
+    ScoreSummary {
+      title,
+      movement_title,
+      composers: creators("composer"),
+      lyricists: creators("lyricist"),
+      measures: parts.iter().map(|part| part.measures).max().unwrap_or(0),
+      notes: parts.iter().map(|part| part.notes).sum(),
+      rests: parts.iter().map(|part| part.rests).sum(),
+      parts,
+      duration_quarters: total,
+      duration_seconds,
+      key_changes,
+      time_changes,
+      tempo_markings: tempos.into_iter().map(|(_, _, tempo)| tempo).collect(),
+      features: score_features(score),
+    }
+  }
+}
+
+#[cfg(test)]
+mod summary_tests {
+  use super::*;
+  use crate::parser::parse_from_xml_str;
+
+  #[test]
+  fn summary_metadata() {
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <work><work-title>Sonata</work-title></work>
+          <movement-title>Allegro</movement-title>
+          <identification>
+            <creator type="composer">A. Composer</creator>
+            <creator type="lyricist">A. Poet</creator>
+            <creator type="composer">B. Composer</creator>
+            <creator>Someone</creator>
+          </identification>
+          <part-list>
+            <score-part id="P1">
+              <part-name>Winds</part-name>
+              <score-instrument id="P1-I1"><instrument-name>Flute</instrument-name></score-instrument>
+              <score-instrument id="P1-I2"><instrument-name>Oboe</instrument-name></score-instrument>
+            </score-part>
+            <score-part id="P2"><part-name>Cello</part-name></score-part>
+          </part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+            </measure>
+            <measure number="2"/>
+          </part>
+          <part id="P2">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+            </measure>
+            <measure number="2"/>
+            <measure number="3"/>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert_eq!(summary.title.as_deref(), Some("Sonata"));
+    assert_eq!(summary.movement_title.as_deref(), Some("Allegro"));
+    assert_eq!(summary.composers, vec!["A. Composer", "B. Composer"]);
+    assert_eq!(summary.lyricists, vec!["A. Poet"]);
+    let parts: Vec<(&str, &str, usize)> = summary
+      .parts
+      .iter()
+      .map(|part| (part.id.as_str(), part.name.as_str(), part.measures))
+      .collect();
+    assert_eq!(parts, vec![("P1", "Winds", 2), ("P2", "Cello", 3)]);
+    assert_eq!(summary.parts[0].instruments, vec!["Flute", "Oboe"]);
+    assert!(summary.parts[1].instruments.is_empty());
+    assert_eq!(summary.measures, 3);
+
+    let untitled = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <movement-title>Prelude</movement-title>
+          <part-list><score-part id="P1"><part-name>Piano</part-name></score-part></part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert_eq!(untitled.title.as_deref(), Some("Prelude"));
+    assert!(untitled.composers.is_empty());
+  }
+
+  #[test]
+  fn summary_counts_and_ambitus() {
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list>
+            <score-part id="P1"><part-name>Piano</part-name></score-part>
+            <score-part id="P2"><part-name>Drums</part-name></score-part>
+          </part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+              <note><chord/><pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch><duration>1</duration></note>
+              <note><rest/><duration>1</duration></note>
+              <note><pitch><step>F</step><alter>1</alter><octave>3</octave></pitch><duration>1</duration></note>
+              <note>
+                <unpitched><display-step>E</display-step><display-octave>4</display-octave></unpitched>
+                <duration>1</duration>
+              </note>
+            </measure>
+          </part>
+          <part id="P2">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <note><rest measure="yes"/><duration>4</duration></note>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    let piano = &summary.parts[0];
+    assert_eq!((piano.notes, piano.rests), (4, 1));
+    let ambitus = piano.ambitus.as_ref().unwrap();
+    assert_eq!((ambitus.lowest.name.as_str(), ambitus.lowest.midi), ("F#3", 54));
+    assert_eq!((ambitus.highest.name.as_str(), ambitus.highest.midi), ("Bb4", 70));
+    assert_eq!(summary.parts[1].ambitus, None);
+    assert_eq!((summary.notes, summary.rests), (4, 2));
+  }
+
+  #[test]
+  fn summary_duration_and_tempo() {
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list>
+            <score-part id="P1"><part-name>Violin</part-name></score-part>
+            <score-part id="P2"><part-name>Viola</part-name></score-part>
+          </part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <note><pitch><step>C</step><octave>4</octave></pitch><duration>4</duration></note>
+            </measure>
+            <measure number="2">
+              <direction>
+                <direction-type><words>Adagio</words></direction-type>
+                <direction-type><metronome><beat-unit>quarter</beat-unit><per-minute>60</per-minute></metronome></direction-type>
+              </direction>
+              <note><pitch><step>C</step><octave>4</octave></pitch><duration>4</duration></note>
+            </measure>
+          </part>
+          <part id="P2">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <note><pitch><step>C</step><octave>3</octave></pitch><duration>4</duration></note>
+            </measure>
+            <measure number="2">
+              <direction>
+                <direction-type><words>Adagio</words></direction-type>
+                <direction-type><metronome><beat-unit>quarter</beat-unit><per-minute>60</per-minute></metronome></direction-type>
+              </direction>
+              <note><pitch><step>C</step><octave>3</octave></pitch><duration>4</duration></note>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert_eq!(summary.duration_quarters, 8.0);
+    assert_eq!(summary.duration_seconds, Some(6.0));
+    assert_eq!(
+      summary.tempo_markings,
+      vec![TempoMarking {
+        measure_number: "2".to_string(),
+        text: Some("Adagio".to_string()),
+        quarters_per_minute: Some(60.0),
+      }]
+    );
+
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <direction>
+                <direction-type>
+                  <metronome><beat-unit>quarter</beat-unit><beat-unit-dot/><per-minute>40</per-minute></metronome>
+                </direction-type>
+                <sound tempo="50"/>
+              </direction>
+              <note><pitch><step>C</step><octave>4</octave></pitch><duration>3</duration></note>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert_eq!(summary.tempo_markings[0].quarters_per_minute, Some(50.0));
+    assert_eq!(summary.duration_seconds, Some(3.6));
+
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <note><pitch><step>C</step><octave>4</octave></pitch><duration>3</duration></note>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert_eq!(summary.duration_quarters, 3.0);
+    assert_eq!(summary.duration_seconds, None);
+  }
+
+  #[test]
+  fn summary_key_and_time_changes() {
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list>
+            <score-part id="P1"><part-name>Clarinet</part-name></score-part>
+            <score-part id="P2"><part-name>Piano</part-name></score-part>
+          </part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+            </measure>
+            <measure number="2">
+              <attributes>
+                <key><fifths>-2</fifths><mode>minor</mode></key><time><beats>3</beats><beat-type>4</beat-type></time>
+              </attributes>
+            </measure>
+            <measure number="3">
+              <attributes>
+                <key><fifths>-2</fifths><mode>minor</mode></key>
+                <time><beats>2</beats><beat-type>4</beat-type><beats>3</beats><beat-type>8</beat-type></time>
+              </attributes>
+            </measure>
+          </part>
+          <part id="P2">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <attributes><key><fifths>5</fifths></key></attributes>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    let keys: Vec<(&str, i8, Option<&str>)> = summary
+      .key_changes
+      .iter()
+      .map(|key| (key.measure_number.as_str(), key.fifths, key.mode.as_deref()))
+      .collect();
+    assert_eq!(keys, vec![("1", 0, None), ("2", -2, Some("minor"))]);
+    let times: Vec<(&str, &str)> = summary
+      .time_changes
+      .iter()
+      .map(|time| (time.measure_number.as_str(), time.signature.as_str()))
+      .collect();
+    assert_eq!(times, vec![("1", "4/4"), ("2", "3/4"), ("3", "2/4+3/8")]);
+  }
+
+  #[test]
+  fn summary_features() {
+    let plain = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list><score-part id="P1"><part-name>Piano</part-name></score-part></part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <note><pitch><step>C</step><octave>4</octave></pitch><duration>4</duration></note>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert!(plain.features.is_empty());
+
+    let summary = ScoreSummary::from(
+      &parse_from_xml_str::<ScorePartwise>(
+        r#"<score-partwise>
+          <part-list>
+            <score-part id="P1"><part-name>Voice</part-name></score-part>
+            <score-part id="P2"><part-name>Guitar</part-name></score-part>
+            <score-part id="P3"><part-name>Drums</part-name></score-part>
+          </part-list>
+          <part id="P1">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <harmony><root><root-step>C</root-step></root><kind>major</kind></harmony>
+              <note><grace/><pitch><step>D</step><octave>4</octave></pitch></note>
+              <note>
+                <pitch><step>C</step><octave>4</octave></pitch><duration>2</duration>
+                <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+                <lyric><text>la</text></lyric>
+              </note>
+            </measure>
+          </part>
+          <part id="P2">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <attributes><clef><sign>TAB</sign><line>5</line></clef></attributes>
+            </measure>
+          </part>
+          <part id="P3">
+            <measure number="1">
+              <attributes>
+                <divisions>1</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              </attributes>
+              <attributes><clef><sign>percussion</sign></clef></attributes>
+            </measure>
+          </part>
+        </score-partwise>"#,
+      )
+      .unwrap(),
+    );
+    assert_eq!(
+      summary.features.into_iter().collect::<Vec<_>>(),
+      vec![
+        ScoreFeature::Tuplets,
+        ScoreFeature::GraceNotes,
+        ScoreFeature::Lyrics,
+        ScoreFeature::ChordSymbols,
+        ScoreFeature::Percussion,
+        ScoreFeature::Tablature,
+      ]
+    );
+  }
+}
@@ -0,0 +1,303 @@
+use super::divisions::{part_divisions, rescale_part};
+use crate::datatypes::Token;
+use crate::elements::{PartElement, PartListElement, ScorePartwise};
+use crate::util::part_measures;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Splits a measure number token into its leading integer and any remaining text.
+pub(super) fn split_measure_number(number: &str) -> Option<(i64, &str)> {
+  let digits = number.find(|c: char| !c.is_ascii_digit()).unwrap_or(number.len());
+  Some((number[..digits].parse().ok()?, &number[digits..]))
+}
+
+/// Returns the `(id, name)` of every score part in a score.
+fn score_parts(score: &ScorePartwise) -> Vec<(String, String)> {
+  score
+    .content
+    .part_list
+    .content
+    .content
+    .iter()
+    .filter_map(|element| match element {
+      PartListElement::ScorePart(score_part) => Some((
+        score_part.attributes.id.to_string(),
+        score_part.content.part_name.content.trim().to_string(),
+      )),
+      PartListElement::PartGroup(_) => None,
+    })
+    .collect()
+}
+
+/// Returns the index of the part in `score` that matches a part with the specified ID and name, preferring a match
+/// by ID over a match by name, and skipping parts that have already been matched.
+fn matching_part(score: &ScorePartwise, id: &str, name: &str, matched: &[usize]) -> Option<usize> {
+  let parts = score_parts(score);
+  let matched_id = parts
+    .iter()
+    .find(|(other, _)| other == id)
+    .or_else(|| parts.iter().find(|(_, other)| !name.is_empty() && other == name))
+    .map(|(other, _)| other.as_str())?;
+  (0..score.content.part.len())
+    .filter(|index| !matched.contains(index))
+    .find(|&index| *score.content.part[index].attributes.id == matched_id)
+}
+
+/// Appends the measures of one score to the end of another, producing a single continuous score.
+///
+/// Every part in `first` is matched to a part in `second` with the same ID or, failing that, the same part name, and
+/// the measures of the matching part are appended to it. The header, credits, and part list of the resulting score
+/// are taken from `first`. Where the two parts use different [Divisions][crate::elements::Divisions], the joined part
+/// is rewritten to use a single value as described in [normalize_divisions][super::normalize_divisions], scaling all
+/// durations accordingly. Measures taken from `second` are renumbered to continue on from the last measure number in
+/// `first`, keeping any non-numeric suffix such as the `"a"` in `"12a"`.
+///
+/// # Errors
+///
+/// If any part in either score does not have a matching part in the other score, or if the durations of a joined
+/// part are too large to be represented using a single divisions value, an error message will be returned.
+pub fn concat(first: &ScorePartwise, second: &ScorePartwise) -> Result<ScorePartwise, String> {
+  let mut score = first.clone();
+  let mut other = second.clone();
+  let parts = score_parts(&score);
+  let mut matched = Vec::new();
+  let mut offset = None;
+  for part in &mut score.content.part {
+    let id = part.attributes.id.to_string();
+    let name = parts
+      .iter()
+      .find(|(other, _)| *other == id)
+      .map(|(_, name)| name.as_str())
+      .unwrap_or_default();
+    let index = matching_part(&other, &id, name, &matched)
+      .ok_or_else(|| format!("Part \"{id}\" has no matching part in the appended score"))?;
+    matched.push(index);
+
+    // Measure numbers continue on from the last numbered measure of the first part
+    let elements = core::mem::take(&mut other.content.part[index].content);
+    let offset = *offset.get_or_insert_with(|| {
+      let last = part_measures(part)
+        .filter_map(|measure| split_measure_number(&measure.attributes.number))
+        .map(|(number, _)| number)
+        .max()
+        .unwrap_or(0);
+      let first = elements
+        .iter()
+        .find_map(|element| match element {
+          PartElement::Measure(measure) => split_measure_number(&measure.attributes.number),
+          _ => None,
+        })
+        .map_or(1, |(number, _)| number);
+      last + 1 - first
+    });
+    for mut element in elements {
+      if let PartElement::Measure(measure) = &mut element {
+        let number = split_measure_number(&measure.attributes.number)
+          .map(|(number, suffix)| format!("{}{suffix}", number + offset));
+        if let Some(number) = number {
+          measure.attributes.number = Token(number);
+        }
+      }
+      part.content.push(element);
+    }
+    let divisions = part_divisions(part)?;
+    rescale_part(part, divisions)?;
+  }
+  if let Some(unmatched) = (0..other.content.part.len()).find(|index| !matched.contains(index)) {
+    return Err(format!(
+      "Part \"{}\" in the appended score has no matching part",
+      other.content.part[unmatched].attributes.id.as_str()
+    ));
+  }
+  Ok(score)
+}
+
+#[cfg(test)]
+mod concat_tests {
+  use super::*;
+  use crate::datatypes::IdRef;
+  use crate::elements::{Backup, Divisions, Note};
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_duration, part_measures};
+  use crate::visit::for_each;
+
+  fn measure_numbers(score: &ScorePartwise) -> Vec<String> {
+    part_measures(&score.content.part[0])
+      .map(|measure| measure.attributes.number.to_string())
+      .collect()
+  }
+
+  #[test]
+  fn concat_renumbers_measures() {
+    let first = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+          <measure number="2">
+            <note><pitch><step>D</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let second = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>E</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+          <measure number="2a">
+            <note><pitch><step>F</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+          <measure number="X">
+            <note><pitch><step>G</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let joined = concat(&first, &second).unwrap();
+    assert_eq!(measure_numbers(&joined), vec!["1", "2", "3", "4a", "X"]);
+
+    // A score starting with a pickup measure numbered 0 continues from the last measure of the first score
+    let pickup = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="0" implicit="yes">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+          <measure number="1">
+            <note><pitch><step>D</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let joined = concat(&pickup, &pickup).unwrap();
+    assert_eq!(measure_numbers(&joined), vec!["0", "1", "2", "3"]);
+  }
+
+  #[test]
+  fn concat_scales_divisions() {
+    let first = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>2</divisions></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>2</duration></note>
+            <backup><duration>2</duration></backup>
+            <note><rest/><duration>2</duration><voice>2</voice></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let second = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>3</divisions></attributes>
+            <note><pitch><step>D</step><octave>4</octave></pitch><duration>3</duration></note>
+            <backup><duration>3</duration></backup>
+            <note><rest/><duration>3</duration><voice>2</voice></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let joined = concat(&first, &second).unwrap();
+    let mut divisions = Vec::new();
+    for_each(&joined, |element: &Divisions, _| divisions.push(*element.content));
+    assert_eq!(divisions, vec![6]);
+    let mut durations = Vec::new();
+    for_each(&joined, |note: &Note, _| durations.push(note_duration(note)));
+    assert_eq!(durations, vec![6, 6, 6, 6]);
+    let mut backups = Vec::new();
+    for_each(&joined, |backup: &Backup, _| {
+      backups.push(*backup.content.duration.content)
+    });
+    assert_eq!(backups, vec![6, 6]);
+  }
+
+  #[test]
+  fn concat_matches_parts_by_name() {
+    let first = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let second = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="V1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="V1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>D</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let joined = concat(&first, &second).unwrap();
+    assert_eq!(joined.content.part.len(), 1);
+    assert_eq!(*joined.content.part[0].attributes.id, "P1");
+    assert_eq!(measure_numbers(&joined), vec!["1", "2"]);
+  }
+
+  #[test]
+  fn concat_unmatched_parts() {
+    let violin = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Violin</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let viola = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P2"><part-name>Viola</part-name></score-part></part-list>
+        <part id="P2">
+          <measure number="1">
+            <attributes><divisions>1</divisions></attributes>
+            <note><pitch><step>C</step><octave>3</octave></pitch><duration>1</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    assert_eq!(
+      concat(&violin, &viola).unwrap_err(),
+      "Part \"P1\" has no matching part in the appended score"
+    );
+    let mut second = violin.clone();
+    let mut extra = second.content.part[0].clone();
+    extra.attributes.id = IdRef(String::from("P2"));
+    second.content.part.push(extra);
+    assert_eq!(
+      concat(&violin, &second).unwrap_err(),
+      "Part \"P2\" in the appended score has no matching part"
+    );
+  }
+}
@@ -0,0 +1,396 @@
+use crate::datatypes::{Divisions, PositiveDivisions};
+use crate::elements::{
+  Attributes, AttributesContents, Barline, Duration, MeasureElement, Note, Offset, Part, ScorePartwise, Sound,
+};
+use crate::util::{
+  dotted_length, gcd, lcm, note_dots, note_is_grace, note_time_modification, part_measures, part_measures_mut, Fraction,
+};
+use crate::visit::{visit_mut, VisitContext, VisitorMut};
+use alloc::string::String;
+
+/// Returns the number of divisions set by an [Attributes] element, if any.
+fn attributes_divisions(attributes: &Attributes) -> Option<u32> {
+  attributes
+    .content
+    .divisions
+    .as_ref()
+    .map(|divisions| *divisions.content)
+    .filter(|&divisions| divisions > 0)
+}
+
+/// Returns the number of divisions per quarter note needed to represent the notated length of a note exactly,
+/// accounting for dots and any tuplet [TimeModification][crate::elements::TimeModification].
+///
+/// Grace notes and notes without a type do not require any particular number of divisions.
+fn note_divisions(note: &Note) -> i64 {
+  let Some(note_type) = note.content.r#type.as_ref().filter(|_| !note_is_grace(note)) else {
+    return 1;
+  };
+  let (actual, normal) = note_time_modification(note);
+  (dotted_length(&note_type.content, note_dots(note)) * Fraction::new(i64::from(normal), i64::from(actual))).den
+}
+
+/// Applies a function to every value expressed in divisions within the visited elements, replacing each value with
+/// the result.
+///
+/// Values for which the function returns `None`, or whose result cannot be represented, are left unchanged and cause
+/// `valid` to be cleared.
+struct DurationMap<F> {
+  map: F,
+  valid: bool,
+}
+
+impl<F: FnMut(i64) -> Option<i64>> DurationMap<F> {
+  fn new(map: F) -> Self {
+    Self { map, valid: true }
+  }
+
+  fn map_positive(&mut self, value: &mut PositiveDivisions) {
+    match (self.map)(i64::from(value.0)).and_then(|mapped| u32::try_from(mapped).ok()) {
+      Some(mapped) => value.0 = mapped,
+      None => self.valid = false,
+    }
+  }
+
+  fn map_signed(&mut self, value: &mut Divisions) {
+    match (self.map)(i64::from(value.0)).and_then(|mapped| i32::try_from(mapped).ok()) {
+      Some(mapped) => value.0 = mapped,
+      None => self.valid = false,
+    }
+  }
+}
+
+impl<F: FnMut(i64) -> Option<i64>> VisitorMut for DurationMap<F> {
+  fn visit_duration_mut(&mut self, element: &mut Duration, _context: &VisitContext) {
+    self.map_positive(&mut element.content);
+  }
+
+  fn visit_offset_mut(&mut self, element: &mut Offset, _context: &VisitContext) {
+    self.map_signed(&mut element.content);
+  }
+
+  fn visit_note_mut(&mut self, element: &mut Note, _context: &VisitContext) {
+    for value in [&mut element.attributes.attack, &mut element.attributes.release]
+      .into_iter()
+      .flatten()
+    {
+      self.map_signed(value);
+    }
+  }
+
+  fn visit_barline_mut(&mut self, element: &mut Barline, _context: &VisitContext) {
+    if let Some(value) = &mut element.attributes.divisions {
+      self.map_signed(value);
+    }
+  }
+
+  fn visit_sound_mut(&mut self, element: &mut Sound, _context: &VisitContext) {
+    if let Some(value) = &mut element.attributes.divisions {
+      self.map_signed(value);
+    }
+  }
+}
+
+/// Returns the least common multiple of every [Divisions][crate::elements::Divisions] value used in a part, along
+/// with the divisions needed to represent the notated length of every note, including tuplets.
+///
+/// Returns an error if the least common multiple is too large to be represented.
+pub(crate) fn part_divisions(part: &Part) -> Result<u32, String> {
+  part_measures(part)
+    .flat_map(|measure| measure.content.iter())
+    .map(|element| match element {
+      MeasureElement::Attributes(attributes) => attributes_divisions(attributes).map_or(1, i64::from),
+      MeasureElement::Note(note) => note_divisions(note),
+      _ => 1,
+    })
+    .try_fold(1, |divisions: u32, value| {
+      let divisions = i64::from(divisions);
+      (divisions / gcd(divisions, value))
+        .checked_mul(value)
+        .and_then(|multiple| u32::try_from(multiple).ok())
+        .ok_or_else(|| {
+          format!(
+            "Part \"{}\" requires too many divisions per quarter note to be represented",
+            part.attributes.id.as_str()
+          )
+        })
+    })
+}
+
+/// Rewrites a part so that it uses a single `divisions` value throughout.
+///
+/// Every duration, offset, and other value expressed in divisions is rescaled according to the divisions value in
+/// effect where it appears. Only the first [Divisions][crate::elements::Divisions] element in the part is kept, and
+/// any [Attributes] elements left empty by removing the others are dropped. Each divisions value used in the part
+/// must either divide the specified `divisions` or be divisible by it without leaving any fractional durations, such
+/// as the values returned by [part_divisions] and [reduced_divisions].
+///
+/// Returns an error if a rescaled value is too large to be represented or cannot be represented exactly.
+pub(crate) fn rescale_part(part: &mut Part, divisions: u32) -> Result<(), String> {
+  let mut current = divisions;
+  let mut written = false;
+  let mut valid = true;
+  for measure in part_measures_mut(part) {
+    for element in &mut measure.content {
+      if let MeasureElement::Attributes(attributes) = element {
+        if let Some(value) = attributes_divisions(attributes) {
+          current = value;
+          if written {
+            attributes.content.divisions = None;
+          } else if let Some(element) = &mut attributes.content.divisions {
+            element.content = PositiveDivisions(divisions);
+            written = true;
+          }
+        }
+      }
+      let factor = Fraction::new(i64::from(divisions), i64::from(current));
+      if factor != Fraction::from_int(1) {
+        let mut map = DurationMap::new(|value: i64| {
+          value
+            .checked_mul(factor.num)
+            .filter(|scaled| scaled % factor.den == 0)
+            .map(|scaled| scaled / factor.den)
+        });
+        visit_mut(element, &mut map);
+        valid &= map.valid;
+      }
+    }
+    measure.content.retain(|element| {
+      !matches!(element, MeasureElement::Attributes(attributes) if attributes.content == AttributesContents::default())
+    });
+  }
+  if valid {
+    Ok(())
+  } else {
+    Err(format!(
+      "Durations in part \"{}\" cannot be represented using {divisions} divisions per quarter note",
+      part.attributes.id.as_str()
+    ))
+  }
+}
+
+/// Returns the smallest divisions value that can represent every duration in a part which already uses the
+/// specified `divisions` throughout, along with the notated length of every note.
+fn reduced_divisions(part: &mut Part, divisions: u32) -> u32 {
+  let mut common = i64::from(divisions);
+  for measure in part_measures_mut(part) {
+    visit_mut(
+      measure,
+      &mut DurationMap::new(|value| {
+        common = gcd(common, value);
+        Some(value)
+      }),
+    );
+  }
+  let required = part_measures(part)
+    .flat_map(|measure| measure.content.iter())
+    .filter_map(|element| match element {
+      MeasureElement::Note(note) => Some(note),
+      _ => None,
+    })
+    .fold(i64::from(divisions) / common.max(1), |required, note| {
+      lcm(required, note_divisions(note))
+    });
+  u32::try_from(required).unwrap_or(divisions)
+}
+
+/// Rewrites every part of a score so that it uses a single [Divisions][crate::elements::Divisions] value.
+///
+/// The divisions value chosen for each part is the least common multiple of every divisions value used in the part,
+/// extended where necessary so that the notated length of every note, including notes within tuplets specified by a
+/// [TimeModification][crate::elements::TimeModification], can be represented exactly. The value is written once at
+/// the start of the part, and every [Duration][crate::elements::Duration], [Backup][crate::elements::Backup],
+/// [Forward][crate::elements::Forward], and [Offset][crate::elements::Offset], as well as the `attack` and `release`
+/// attributes of each [Note][crate::elements::Note] and the `divisions` attributes of each
+/// [Barline][crate::elements::Barline] and [Sound][crate::elements::Sound], is rescaled to match.
+///
+/// # Errors
+///
+/// If a rescaled value is too large to be represented, an error message will be returned.
+pub fn normalize_divisions(score: &ScorePartwise) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  for part in &mut score.content.part {
+    let divisions = part_divisions(part)?;
+    rescale_part(part, divisions)?;
+  }
+  Ok(score)
+}
+
+/// Rewrites every part of a score so that it uses the smallest [Divisions][crate::elements::Divisions] value that
+/// can represent all of its durations exactly.
+///
+/// Each part is first normalized to a single divisions value as described in [normalize_divisions], after which the
+/// divisions value and every value expressed in divisions are divided by their greatest common divisor. The reduced
+/// value remains large enough to represent the notated length of every note, including tuplets.
+///
+/// # Errors
+///
+/// If a rescaled value is too large to be represented, an error message will be returned.
+pub fn reduce_divisions(score: &ScorePartwise) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  for part in &mut score.content.part {
+    let divisions = part_divisions(part)?;
+    rescale_part(part, divisions)?;
+    let reduced = reduced_divisions(part, divisions);
+    rescale_part(part, reduced)?;
+  }
+  Ok(score)
+}
+
+#[cfg(test)]
+mod divisions_tests {
+  use super::*;
+  use crate::elements::{Attributes, Backup, Barline, Divisions, Forward, Note, Offset, Sound};
+  use crate::parser::parse_from_xml_str;
+  use crate::util::note_duration;
+  use crate::visit::for_each;
+
+  const SCORE: &str = r#"<score-partwise>
+    <part-list><score-part id="P1"><part-name>Flute</part-name></score-part></part-list>
+    <part id="P1">
+      <measure number="1">
+        <attributes><divisions>2</divisions></attributes>
+        <direction><direction-type><words>dolce</words></direction-type><offset>1</offset>
+          <sound divisions="2" tempo="90"/></direction>
+        <note attack="-1" release="1"><pitch><step>C</step><octave>5</octave></pitch><duration>4</duration>
+          <type>half</type></note>
+        <backup><duration>4</duration></backup>
+        <forward><duration>4</duration></forward>
+      </measure>
+      <measure number="2">
+        <attributes><divisions>6</divisions></attributes>
+        <note><pitch><step>D</step><octave>5</octave></pitch><duration>2</duration><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification></note>
+        <note><pitch><step>E</step><octave>5</octave></pitch><duration>2</duration><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification></note>
+        <note><pitch><step>F</step><octave>5</octave></pitch><duration>2</duration><type>eighth</type>
+          <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification></note>
+        <note><pitch><step>G</step><octave>5</octave></pitch><duration>18</duration><type>half</type><dot/></note>
+        <barline location="right" divisions="6"><bar-style>light-heavy</bar-style></barline>
+      </measure>
+    </part>
+  </score-partwise>"#;
+
+  fn divisions(score: &ScorePartwise) -> Vec<u32> {
+    let mut divisions = Vec::new();
+    for_each(score, |element: &Divisions, _| divisions.push(*element.content));
+    divisions
+  }
+
+  fn durations(score: &ScorePartwise) -> Vec<u32> {
+    let mut durations = Vec::new();
+    for_each(score, |note: &Note, _| durations.push(note_duration(note)));
+    for_each(score, |backup: &Backup, _| {
+      durations.push(*backup.content.duration.content)
+    });
+    for_each(score, |forward: &Forward, _| {
+      durations.push(*forward.content.duration.content)
+    });
+    durations
+  }
+
+  #[test]
+  fn normalize_single_divisions() {
+    let normalized = normalize_divisions(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap()).unwrap();
+    assert_eq!(divisions(&normalized), vec![6]);
+    assert_eq!(durations(&normalized), vec![12, 2, 2, 2, 18, 12, 12]);
+    let mut measures = Vec::new();
+    for_each(&normalized, |_: &Attributes, context| {
+      measures.push(context.measure_number.clone().unwrap());
+    });
+    assert_eq!(measures, vec!["1"]);
+  }
+
+  #[test]
+  fn normalize_rescales_attributes() {
+    let normalized = normalize_divisions(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap()).unwrap();
+    let mut offsets = Vec::new();
+    for_each(&normalized, |offset: &Offset, _| offsets.push(*offset.content));
+    assert_eq!(offsets, vec![3]);
+    let mut notes = Vec::new();
+    for_each(&normalized, |note: &Note, _| {
+      notes.push((
+        note.attributes.attack.as_ref().map(|value| **value),
+        note.attributes.release.as_ref().map(|value| **value),
+      ));
+    });
+    assert_eq!(notes[0], (Some(-3), Some(3)));
+    let mut sounds = Vec::new();
+    for_each(&normalized, |sound: &Sound, _| {
+      sounds.push(sound.attributes.divisions.as_ref().map(|value| **value))
+    });
+    assert_eq!(sounds, vec![Some(6)]);
+    let mut barlines = Vec::new();
+    for_each(&normalized, |barline: &Barline, _| {
+      barlines.push(barline.attributes.divisions.as_ref().map(|value| **value));
+    });
+    assert_eq!(barlines, vec![Some(6)]);
+  }
+
+  #[test]
+  fn normalize_includes_tuplets() {
+    let source = SCORE
+      .replace("<divisions>6</divisions>", "<divisions>2</divisions>")
+      .replace("<duration>2</duration>", "<duration>1</duration>")
+      .replace("<duration>18</duration>", "<duration>6</duration>")
+      .replace("divisions=\"6\"", "divisions=\"2\"");
+    let normalized = normalize_divisions(&parse_from_xml_str::<ScorePartwise>(&source).unwrap()).unwrap();
+    assert_eq!(divisions(&normalized), vec![6]);
+    assert_eq!(durations(&normalized), vec![12, 3, 3, 3, 18, 12, 12]);
+  }
+
+  #[test]
+  fn reduce_smallest_divisions() {
+    let source = SCORE
+      .replace("<divisions>2</divisions>", "<divisions>12</divisions>")
+      .replace("<duration>4</duration>", "<duration>24</duration>");
+    let source = source
+      .replace("<offset>1</offset>", "<offset>6</offset>")
+      .replace("attack=\"-1\" release=\"1\"", "attack=\"-6\" release=\"6\"")
+      .replace("divisions=\"2\"", "divisions=\"12\"");
+    let reduced = reduce_divisions(&parse_from_xml_str::<ScorePartwise>(&source).unwrap()).unwrap();
+    assert_eq!(divisions(&reduced), vec![6]);
+    assert_eq!(durations(&reduced), vec![12, 2, 2, 2, 18, 12, 12]);
+    let mut offsets = Vec::new();
+    for_each(&reduced, |offset: &Offset, _| offsets.push(*offset.content));
+    assert_eq!(offsets, vec![3]);
+  }
+
+  #[test]
+  fn reduce_without_tuplets() {
+    let source = r#"<score-partwise>
+      <part-list><score-part id="P1"><part-name>Flute</part-name></score-part></part-list>
+      <part id="P1"><measure number="1">
+        <attributes><divisions>8</divisions></attributes>
+        <note><pitch><step>C</step><octave>5</octave></pitch><duration>16</duration><type>half</type></note>
+        <note><rest/><duration>16</duration></note>
+      </measure></part>
+    </score-partwise>"#;
+    let reduced = reduce_divisions(&parse_from_xml_str::<ScorePartwise>(source).unwrap()).unwrap();
+    assert_eq!(divisions(&reduced), vec![1]);
+    assert_eq!(durations(&reduced), vec![2, 2]);
+  }
+
+  #[test]
+  fn normalize_too_large() {
+    let source = r#"<score-partwise>
+      <part-list><score-part id="P1"><part-name>Flute</part-name></score-part></part-list>
+      <part id="P1">
+        <measure number="1">
+          <attributes><divisions>65537</divisions></attributes>
+          <note><pitch><step>C</step><octave>5</octave></pitch><duration>65537</duration><type>quarter</type></note>
+        </measure>
+        <measure number="2">
+          <attributes><divisions>65539</divisions></attributes>
+          <note><pitch><step>C</step><octave>5</octave></pitch><duration>65539</duration><type>quarter</type></note>
+        </measure>
+      </part>
+    </score-partwise>"#;
+    assert!(normalize_divisions(&parse_from_xml_str::<ScorePartwise>(source).unwrap()).is_err());
+    assert!(reduce_divisions(&parse_from_xml_str::<ScorePartwise>(source).unwrap()).is_err());
+    let source = source
+      .replace("<divisions>65539</divisions>", "<divisions>1</divisions>")
+      .replace("<duration>65539</duration>", "<duration>70000</duration>");
+    assert!(normalize_divisions(&parse_from_xml_str::<ScorePartwise>(&source).unwrap()).is_err());
+  }
+}
@@ -0,0 +1,410 @@
+use crate::datatypes::{LeftCenterRight, PositiveInteger, Valign};
+use crate::elements::{
+  Attributes, AttributesContents, Credit, CreditAttributes, CreditContents, CreditSubcontents, CreditTextContents,
+  CreditType, CreditWords, CreditWordsAttributes, Measure, MeasureElement, MeasureStyle, MeasureStyleAttributes,
+  MeasureStyleContents, MultipleRest, MultipleRestAttributes, Note, Part, PartElement, PartListElement, Print,
+  PrintAttributes, PrintContents, ScorePart, ScorePartwise,
+};
+use crate::util::{note_is_cue, note_is_rest, part_measures_mut};
+use crate::visit::{for_each, for_each_mut};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Removes score-only layout information from a `<print>` element, such as system and page breaks.
+///
+/// Returns whether the element still contains anything once the layout has been removed.
+fn strip_print_layout(print: &mut Print) -> bool {
+  print.attributes.new_system = None;
+  print.attributes.new_page = None;
+  print.attributes.blank_page = None;
+  print.attributes.page_number = None;
+  print.attributes.staff_spacing = None;
+  print.content.page_layout = None;
+  print.content.system_layout = None;
+  print.content.staff_layout.clear();
+  print.content.measure_layout = None;
+  print.attributes != PrintAttributes::default() || print.content != PrintContents::default()
+}
+
+/// Returns whether a measure contains nothing but rests, allowing an `<attributes>` element only when it may begin
+/// a multiple rest. Cue notes, rests with notations, and any directions or barlines prevent consolidation.
+fn is_empty_measure(measure: &Measure, starts_rest: bool) -> bool {
+  measure.content.iter().all(|element| match element {
+    MeasureElement::Note(note) => note_is_rest(note) && !note_is_cue(note) && note.content.notations.is_empty(),
+    MeasureElement::Backup(_) | MeasureElement::Forward(_) | MeasureElement::Print(_) => true,
+    MeasureElement::Attributes(_) => starts_rest,
+    _ => false,
+  })
+}
+
+/// Consolidates runs of two or more consecutive empty measures into multiple rests.
+fn consolidate_multiple_rests(part: &mut Part) {
+  let mut consolidated = false;
+  for_each(&*part, |_: &MultipleRest, _| consolidated = true);
+  if consolidated {
+    return;
+  }
+  let mut measures: Vec<&mut Measure> = part_measures_mut(part).collect();
+  let mut start = 0;
+  while start < measures.len() {
+    if !is_empty_measure(measures[start], true) {
+      start += 1;
+      continue;
+    }
+    let mut end = start + 1;
+    while end < measures.len() && is_empty_measure(measures[end], false) {
+      end += 1;
+    }
+    if end - start > 1 {
+      let measure_style = MeasureStyle {
+        attributes: MeasureStyleAttributes::default(),
+        content: MeasureStyleContents::MultipleRest(MultipleRest {
+          attributes: MultipleRestAttributes::default(),
+          content: PositiveInteger(u32::try_from(end - start).unwrap_or(u32::MAX)),
+        }),
+      };
+      let measure = &mut *measures[start];
+      if let Some(attributes) = measure.content.iter_mut().find_map(|element| match element {
+        MeasureElement::Attributes(attributes) => Some(attributes),
+        _ => None,
+      }) {
+        attributes.content.measure_style.push(measure_style);
+      } else {
+        let position = measure
+          .content
+          .iter()
+          .position(|element| !matches!(element, MeasureElement::Print(_)))
+          .unwrap_or(measure.content.len());
+        measure.content.insert(
+          position,
+          MeasureElement::Attributes(Attributes {
+            attributes: (),
+            content: AttributesContents {
+              measure_style: vec![measure_style],
+              ..Default::default()
+            },
+          }),
+        );
+      }
+    }
+    start = end;
+  }
+}
+
+/// Removes any `<part-link>` elements from a score part, keeping only the instruments that they link to.
+///
+/// Returns the IDs of any instruments that were removed.
+fn resolve_part_links(score_part: &mut ScorePart) -> Vec<String> {
+  let linked: Vec<String> = score_part
+    .content
+    .part_link
+    .iter()
+    .flat_map(|link| link.content.instrument_link.iter())
+    .map(|link| link.attributes.id.to_string())
+    .collect();
+  score_part.content.part_link.clear();
+  if linked.is_empty() {
+    return Vec::new();
+  }
+  let removed: Vec<String> = score_part
+    .content
+    .score_instrument
+    .iter()
+    .map(|instrument| instrument.attributes.id.to_string())
+    .filter(|id| !linked.contains(id))
+    .collect();
+  score_part
+    .content
+    .score_instrument
+    .retain(|instrument| !removed.contains(&instrument.attributes.id));
+  score_part
+    .content
+    .midi_instrument
+    .retain(|instrument| !removed.contains(&instrument.attributes.id));
+  removed
+}
+
+/// Sets the text of the part name credit to the name of the extracted part, adding a new credit if the score
+/// contains other credits but none for the part name.
+fn update_part_name_credit(score: &mut ScorePartwise, part_name: &str) {
+  let credits = &mut score.content.credit;
+  if let Some(credit) = credits.iter_mut().find(|credit| {
+    credit
+      .content
+      .credit_type
+      .iter()
+      .any(|credit_type| credit_type.content == "part name")
+  }) {
+    if let CreditSubcontents::Text(CreditTextContents {
+      credit_words: Some(words),
+      ..
+    }) = &mut credit.content.credit
+    {
+      words.content = part_name.to_string();
+    }
+  } else if !credits.is_empty() {
+    credits.push(Credit {
+      attributes: CreditAttributes {
+        page: Some(PositiveInteger(1)),
+        ..Default::default()
+      },
+      content: CreditContents {
+        credit_type: vec![CreditType {
+          attributes: (),
+          content: String::from("part name"),
+        }],
+        link: Vec::new(),
+        bookmark: Vec::new(),
+        credit: CreditSubcontents::Text(CreditTextContents {
+          credit_words: Some(CreditWords {
+            attributes: CreditWordsAttributes {
+              justify: Some(LeftCenterRight::Left),
+              valign: Some(Valign::Top),
+              ..Default::default()
+            },
+            content: part_name.to_string(),
+          }),
+          ..Default::default()
+        }),
+      },
+    });
+  }
+}
+
+/// Extracts a single part from a score, producing a score suitable for printing an individual part.
+///
+/// The resulting score contains only the [ScorePart][crate::elements::ScorePart] and
+/// [Part][crate::elements::Part] with the specified `part_id`, along with all header metadata and credits from the
+/// original score. The following changes are also made:
+///
+/// - The credit for the part name is updated, or added if the score contains credits without one
+/// - Score-only `<print>` layout, such as system and page breaks, is removed
+/// - Runs of two or more consecutive measures containing only rests are consolidated into multiple rests using a
+///   [MeasureStyle][crate::elements::MeasureStyle]; measures containing cue notes are kept as written
+/// - Any [PartLink][crate::elements::PartLink] elements are resolved, so that only the instruments linked to the part
+///   remain and the links themselves are removed
+///
+/// # Errors
+///
+/// If the score does not contain a part with the specified ID, an error message will be returned.
+pub fn extract_part(score: &ScorePartwise, part_id: &str) -> Result<ScorePartwise, String> {
+  let missing = || format!("Part \"{part_id}\" does not exist in the score");
+  let mut score = score.clone();
+
+  // Keep only the chosen score part, resolving any links to separate part documents
+  let part_list = &mut score.content.part_list.content.content;
+  part_list.retain(|element| matches!(element, PartListElement::ScorePart(part) if *part.attributes.id == part_id));
+  let Some(PartListElement::ScorePart(score_part)) = part_list.first_mut() else {
+    return Err(missing());
+  };
+  let removed_instruments = resolve_part_links(score_part);
+  let part_name = score_part.content.part_name.content.clone();
+
+  // Keep only the chosen part, removing score layout and consolidating empty measures
+  score.content.part.retain(|part| *part.attributes.id == part_id);
+  let part = score.content.part.first_mut().ok_or_else(missing)?;
+  part.content.retain_mut(|element| match element {
+    PartElement::Print(print) => strip_print_layout(print),
+    _ => true,
+  });
+  for measure in part_measures_mut(part) {
+    measure.content.retain_mut(|element| match element {
+      MeasureElement::Print(print) => strip_print_layout(print),
+      _ => true,
+    });
+  }
+  for_each_mut(part, |note: &mut Note, _| {
+    note
+      .content
+      .instrument
+      .retain(|instrument| !removed_instruments.contains(&instrument.attributes.id));
+  });
+  consolidate_multiple_rests(part);
+  update_part_name_credit(&mut score, &part_name);
+  Ok(score)
+}
+
+#[cfg(test)]
+mod extract_tests {
+  use super::*;
+  use crate::elements::{
+    Attributes, Credit, CreditSubcontents, Measure, MeasureElement, MeasureStyleContents, Note, PartListElement, Print,
+  };
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_is_cue, part_measures};
+  use crate::visit::for_each;
+
+  const SCORE: &str = r#"<score-partwise version="4.0">
+    <work><work-title>Serenade</work-title></work>
+    <identification><creator type="composer">Anonymous</creator></identification>
+    <credit page="1"><credit-type>title</credit-type><credit-words>Serenade</credit-words></credit>
+    <credit page="1"><credit-type>part name</credit-type><credit-words>Score</credit-words></credit>
+    <part-list>
+      <part-group type="start" number="1"/>
+      <score-part id="P1"><part-name>Flute</part-name></score-part>
+      <score-part id="P2">
+        <part-name>Percussion</part-name>
+        <part-link xlink:href="parts/snare.musicxml"><instrument-link id="P2-I1"/></part-link>
+        <score-instrument id="P2-I1"><instrument-name>Snare Drum</instrument-name></score-instrument>
+        <score-instrument id="P2-I2"><instrument-name>Bass Drum</instrument-name></score-instrument>
+        <midi-instrument id="P2-I1"><midi-channel>10</midi-channel></midi-instrument>
+        <midi-instrument id="P2-I2"><midi-channel>10</midi-channel></midi-instrument>
+      </score-part>
+      <part-group type="stop" number="1"/>
+    </part-list>
+    <part id="P1">
+      <measure number="1">
+        <print new-page="yes"><system-layout><top-system-distance>70</top-system-distance></system-layout></print>
+        <attributes><divisions>1</divisions><time><beats>4</beats><beat-type>4</beat-type></time></attributes>
+        <note><rest measure="yes"/><duration>4</duration></note>
+      </measure>
+      <measure number="2"><note><rest measure="yes"/><duration>4</duration></note></measure>
+      <measure number="3">
+        <print new-system="yes"><measure-numbering>system</measure-numbering></print>
+        <note><rest measure="yes"/><duration>4</duration></note>
+      </measure>
+      <measure number="4"><note><pitch><step>C</step><octave>5</octave></pitch><duration>4</duration></note></measure>
+      <measure number="5"><note><rest/><duration>4</duration></note></measure>
+      <measure number="6"><note><rest/><duration>4</duration></note></measure>
+      <measure number="7">
+        <note><rest/><duration>4</duration></note>
+        <backup><duration>4</duration></backup>
+        <note><cue/><pitch><step>E</step><octave>5</octave></pitch><duration>4</duration></note>
+      </measure>
+      <measure number="8"><note><rest/><duration>4</duration></note></measure>
+      <measure number="9">
+        <attributes><key><fifths>1</fifths></key></attributes>
+        <note><rest/><duration>4</duration></note>
+      </measure>
+    </part>
+    <part id="P2">
+      <measure number="1">
+        <attributes><divisions>1</divisions></attributes>
+        <note><unpitched><display-step>C</display-step><display-octave>5</display-octave></unpitched>
+          <duration>4</duration><instrument id="P2-I1"/></note>
+        <note><chord/><unpitched><display-step>F</display-step><display-octave>4</display-octave></unpitched>
+          <duration>4</duration><instrument id="P2-I2"/></note>
+      </measure>
+    </part>
+  </score-partwise>"#;
+
+  fn credit_words(score: &ScorePartwise) -> Vec<String> {
+    score
+      .content
+      .credit
+      .iter()
+      .filter_map(|credit: &Credit| match &credit.content.credit {
+        CreditSubcontents::Text(text) => text.credit_words.as_ref().map(|words| words.content.clone()),
+        CreditSubcontents::Image(_) => None,
+      })
+      .collect()
+  }
+
+  fn multiple_rests(measure: &Measure) -> Option<u32> {
+    measure.content.iter().find_map(|element| match element {
+      MeasureElement::Attributes(attributes) => attributes.content.measure_style.iter().find_map(|style| match &style
+        .content
+      {
+        MeasureStyleContents::MultipleRest(rest) => Some(*rest.content),
+        _ => None,
+      }),
+      _ => None,
+    })
+  }
+
+  #[test]
+  fn extract_header() {
+    let part = extract_part(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), "P1").unwrap();
+    assert_eq!(part.content.part.len(), 1);
+    assert_eq!(*part.content.part[0].attributes.id, "P1");
+    assert_eq!(part.content.part_list.content.content.len(), 1);
+    assert!(matches!(
+      &part.content.part_list.content.content[0],
+      PartListElement::ScorePart(score_part) if *score_part.attributes.id == "P1"
+    ));
+    assert_eq!(
+      part
+        .content
+        .work
+        .as_ref()
+        .unwrap()
+        .content
+        .work_title
+        .as_ref()
+        .unwrap()
+        .content,
+      "Serenade"
+    );
+    assert!(part.content.identification.is_some());
+    assert_eq!(credit_words(&part), vec!["Serenade", "Flute"]);
+    assert_eq!(
+      extract_part(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), "P3").unwrap_err(),
+      "Part \"P3\" does not exist in the score"
+    );
+  }
+
+  #[test]
+  fn extract_adds_part_name_credit() {
+    let source = SCORE.replace(
+      "<credit page=\"1\"><credit-type>part name</credit-type><credit-words>Score</credit-words></credit>",
+      "",
+    );
+    let part = extract_part(&parse_from_xml_str::<ScorePartwise>(&source).unwrap(), "P2").unwrap();
+    assert_eq!(credit_words(&part), vec!["Serenade", "Percussion"]);
+    let source = source.replace(
+      "<credit page=\"1\"><credit-type>title</credit-type><credit-words>Serenade</credit-words></credit>",
+      "",
+    );
+    let part = extract_part(&parse_from_xml_str::<ScorePartwise>(&source).unwrap(), "P2").unwrap();
+    assert!(part.content.credit.is_empty());
+  }
+
+  #[test]
+  fn extract_removes_print_layout() {
+    let part = extract_part(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), "P1").unwrap();
+    let mut prints = Vec::new();
+    for_each(&part, |print: &Print, context| {
+      prints.push((context.measure_number.clone().unwrap(), print));
+    });
+    assert_eq!(prints.len(), 1);
+    assert_eq!(prints[0].0, "3");
+    assert!(prints[0].1.attributes.new_system.is_none());
+    assert!(prints[0].1.content.measure_numbering.is_some());
+    assert!(prints[0].1.content.system_layout.is_none());
+  }
+
+  #[test]
+  fn extract_consolidates_multiple_rests() {
+    let part = extract_part(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), "P1").unwrap();
+    let rests: Vec<Option<u32>> = part_measures(&part.content.part[0]).map(multiple_rests).collect();
+    assert_eq!(rests, vec![Some(3), None, None, None, Some(2), None, None, None, None]);
+    let mut cues = 0;
+    for_each(&part, |note: &Note, _| cues += usize::from(note_is_cue(note)));
+    assert_eq!(cues, 1);
+    let mut divisions = 0;
+    for_each(&part, |attributes: &Attributes, _| {
+      divisions += usize::from(attributes.content.divisions.is_some());
+    });
+    assert_eq!(divisions, 1);
+
+    // Scores that already contain multiple rests are left unchanged
+    let again = extract_part(&part, "P1").unwrap();
+    let rests: Vec<Option<u32>> = part_measures(&again.content.part[0]).map(multiple_rests).collect();
+    assert_eq!(rests, vec![Some(3), None, None, None, Some(2), None, None, None, None]);
+  }
+
+  #[test]
+  fn extract_resolves_part_links() {
+    let part = extract_part(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), "P2").unwrap();
+    let PartListElement::ScorePart(score_part) = &part.content.part_list.content.content[0] else {
+      panic!("Expected a score part");
+    };
+    assert!(score_part.content.part_link.is_empty());
+    assert_eq!(score_part.content.score_instrument.len(), 1);
+    assert_eq!(*score_part.content.score_instrument[0].attributes.id, "P2-I1");
+    assert_eq!(score_part.content.midi_instrument.len(), 1);
+    let mut instruments = Vec::new();
+    for_each(&part, |note: &Note, _| instruments.push(note.content.instrument.len()));
+    assert_eq!(instruments, vec![1, 0]);
+  }
+}
@@ -0,0 +1,764 @@
+use super::concat::split_measure_number;
+use super::slice::{measure_range, CarriedState};
+use crate::datatypes::{MeasureText, PositiveDivisions, PositiveInteger, Token, YesNo};
+use crate::elements::{
+  Attributes, AttributesContents, Backup, BackupContents, Divisions, Duration, Measure, MeasureAttributes,
+  MeasureElement, NormalInfo, Note, NoteAttributes, NoteContents, NoteType, Part, PartElement, Rest, RestAttributes,
+  RestContents, ScorePartwise, ScoreTimewise, Staff, Time, Voice,
+};
+use crate::parser::{convert_xml_partwise_to_timewise, convert_xml_timewise_to_partwise};
+use crate::util::{part_measures, part_measures_mut, Fraction};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::RangeBounds;
+use musicxml_internal::{ElementDeserializer, ElementSerializer};
+
+/// A new sequence of measures, given as indices into the original measures of a part, where `None` represents a new
+/// empty measure.
+type MeasureOrder = Vec<Option<usize>>;
+
+/// Returns whether a measure is marked as implicit, meaning that it does not count towards measure numbering.
+fn is_implicit(measure: &Measure) -> bool {
+  measure.attributes.implicit == Some(YesNo::Yes)
+}
+
+/// Returns the length of a measure in quarter notes according to a [Time] element, which defaults to 4/4 when there
+/// is no time signature or the music is unmeasured.
+pub(super) fn time_length(time: Option<&Time>) -> Fraction {
+  let mut length = Fraction::ZERO;
+  for signature in time.map_or(&[][..], |time| &time.content.beats) {
+    let beats = signature
+      .beats
+      .content
+      .split('+')
+      .map(|value| value.trim().parse::<i64>().ok())
+      .sum::<Option<i64>>();
+    if let (Some(beats), Ok(beat_type)) = (beats, signature.beat_type.content.trim().parse::<i64>()) {
+      length += Fraction::new(beats * 4, beat_type);
+    }
+  }
+  if length.is_positive() {
+    length
+  } else {
+    Fraction::from_int(4)
+  }
+}
+
+/// Returns a whole-measure rest with the specified duration in the given voice, placed on a staff if there is more
+/// than one.
+fn measure_rest(duration: u32, voice: u32, staff: Option<u32>) -> Note {
+  Note {
+    attributes: NoteAttributes::default(),
+    content: NoteContents {
+      info: NoteType::Normal(NormalInfo {
+        chord: None,
+        audible: crate::elements::AudibleType::Rest(Rest {
+          attributes: RestAttributes {
+            measure: Some(YesNo::Yes),
+          },
+          content: RestContents {
+            display_step: None,
+            display_octave: None,
+          },
+        }),
+        duration: Duration {
+          attributes: (),
+          content: PositiveDivisions(duration),
+        },
+        tie: Vec::new(),
+      }),
+      instrument: Vec::new(),
+      footnote: None,
+      level: None,
+      voice: Some(Voice {
+        attributes: (),
+        content: voice.to_string(),
+      }),
+      r#type: None,
+      dot: Vec::new(),
+      accidental: None,
+      time_modification: None,
+      stem: None,
+      notehead: None,
+      notehead_text: None,
+      staff: staff.map(|staff| Staff {
+        attributes: (),
+        content: PositiveInteger(staff),
+      }),
+      beam: Vec::new(),
+      notations: Vec::new(),
+      lyric: Vec::new(),
+      play: None,
+      listen: None,
+    },
+  }
+}
+
+/// Adds a whole-measure rest on every staff of an empty measure, according to the attributes in effect.
+///
+/// If the measure length cannot be represented using the current divisions, the measure sets its own divisions value.
+fn fill_measure_rests(measure: &mut Measure, state: &CarriedState) {
+  let carried = &state.attributes;
+  let mut divisions = carried
+    .divisions
+    .as_ref()
+    .map_or(1, |divisions| i64::from(*divisions.content));
+  let staves = carried.staves.as_ref().map_or(1, |staves| *staves.content).max(1);
+  let length = time_length(
+    carried
+      .time
+      .iter()
+      .find(|time| time.attributes.number.is_none())
+      .or_else(|| carried.time.first()),
+  );
+  let duration = length * Fraction::from_int(divisions);
+  if duration.den != 1 {
+    divisions *= duration.den;
+    let element = Divisions {
+      attributes: (),
+      content: PositiveDivisions(u32::try_from(divisions).unwrap_or(u32::MAX)),
+    };
+    match measure.content.iter_mut().find_map(|element| match element {
+      MeasureElement::Attributes(attributes) => Some(attributes),
+      _ => None,
+    }) {
+      Some(attributes) => attributes.content.divisions = Some(element),
+      None => measure.content.push(MeasureElement::Attributes(Attributes {
+        attributes: (),
+        content: AttributesContents {
+          divisions: Some(element),
+          ..AttributesContents::default()
+        },
+      })),
+    }
+  }
+  let duration = u32::try_from((length * Fraction::from_int(divisions)).num).unwrap_or(u32::MAX);
+  for staff in 1..=staves {
+    if staff > 1 {
+      measure.content.push(MeasureElement::Backup(Backup {
+        attributes: (),
+        content: BackupContents {
+          duration: Duration {
+            attributes: (),
+            content: PositiveDivisions(duration),
+          },
+          footnote: None,
+          level: None,
+        },
+      }));
+    }
+    let rest = measure_rest(duration, staff, (staves > 1).then_some(staff));
+    measure.content.push(MeasureElement::Note(rest));
+  }
+}
+
+/// Returns the state in effect after the attributes at the very start of a measure, before any of its notes.
+fn leading_state(state: &CarriedState, measure: &Measure) -> CarriedState {
+  let mut leading = state.clone();
+  if let Some(attributes) = measure
+    .content
+    .iter()
+    .take_while(|element| {
+      !matches!(
+        element,
+        MeasureElement::Note(_) | MeasureElement::Backup(_) | MeasureElement::Forward(_)
+      )
+    })
+    .find_map(|element| match element {
+      MeasureElement::Attributes(attributes) => Some(attributes),
+      _ => None,
+    })
+  {
+    leading.update_attributes(attributes);
+  }
+  leading
+}
+
+/// Returns a new empty measure, which is numbered when the part is renumbered.
+fn empty_measure() -> Measure {
+  Measure {
+    attributes: MeasureAttributes {
+      number: Token(String::from("0")),
+      id: None,
+      implicit: None,
+      non_controlling: None,
+      text: None,
+      width: None,
+    },
+    content: Vec::new(),
+  }
+}
+
+/// Rearranges the measures of every part in a partwise score.
+///
+/// The `order` function receives each part and returns its new sequence of measures. Wherever a measure no longer
+/// follows the measure that originally preceded it, any attributes that it relied on are added to its start.
+fn rearrange_measures(
+  score: &mut ScorePartwise,
+  order: &dyn Fn(&Part) -> Result<MeasureOrder, String>,
+) -> Result<(), String> {
+  for part in &mut score.content.part {
+    let order = order(part)?;
+    let mut measures: Vec<Option<Measure>> = Vec::new();
+    let mut states = Vec::new();
+    let mut state = CarriedState::default();
+    for element in core::mem::take(&mut part.content) {
+      match element {
+        PartElement::Measure(measure) => {
+          states.push(state.clone());
+          state.update(&measure);
+          measures.push(Some(measure));
+        }
+        element => part.content.push(element),
+      }
+    }
+
+    let mut running = CarriedState::default();
+    for (position, entry) in order.iter().enumerate() {
+      let measure = if let Some(index) = *entry {
+        let mut measure = measures[index].take().ok_or("Measures cannot be repeated")?;
+        let predecessor = position.checked_sub(1).and_then(|previous| order[previous]);
+        if predecessor != index.checked_sub(1) {
+          states[index].difference(&running).apply_attributes(&mut measure);
+        }
+        running.update(&measure);
+        measure
+      } else {
+        let mut measure = empty_measure();
+        if running.attributes == AttributesContents::default() {
+          if let Some(next) = order[position..].iter().flatten().next() {
+            if let Some(next_measure) = &measures[*next] {
+              let expected = leading_state(&states[*next], next_measure);
+              expected.difference(&running).apply_attributes(&mut measure);
+            }
+          }
+        }
+        let mut expected = running.clone();
+        expected.update(&measure);
+        fill_measure_rests(&mut measure, &expected);
+        running.update(&measure);
+        measure
+      };
+      part.content.push(PartElement::Measure(measure));
+    }
+  }
+  Ok(())
+}
+
+/// Returns the number of the first measure in a partwise score that is not implicit, or one if there is no such
+/// measure.
+fn first_measure_number(score: &ScorePartwise) -> u32 {
+  score
+    .content
+    .part
+    .first()
+    .and_then(|part| part_measures(part).find(|measure| !is_implicit(measure)))
+    .and_then(|measure| split_measure_number(&measure.attributes.number))
+    .and_then(|(number, _)| u32::try_from(number).ok())
+    .unwrap_or(1)
+}
+
+/// Renumbers every measure in a partwise score, starting from `first`.
+fn renumber(score: &mut ScorePartwise, first: u32) {
+  for part in &mut score.content.part {
+    let mut next = i64::from(first);
+    let mut previous: Option<String> = None;
+    let mut suffix = b'a';
+    for measure in part_measures_mut(part) {
+      let number = if !is_implicit(measure) {
+        let number = next.to_string();
+        next += 1;
+        suffix = b'a';
+        previous = Some(number.clone());
+        number
+      } else if let Some(previous) = &previous {
+        let number = format!("{previous}{}", char::from(suffix));
+        suffix = suffix.saturating_add(1).min(b'z');
+        number
+      } else {
+        (i64::from(first) - 1).to_string()
+      };
+      if let Some(text) = measure
+        .attributes
+        .text
+        .as_mut()
+        .filter(|text| text.0 == *measure.attributes.number)
+      {
+        *text = MeasureText(number.clone());
+      }
+      measure.attributes.number = Token(number);
+    }
+  }
+}
+
+/// Returns the rearranged order of measures for inserting `count` empty measures before `index`.
+fn insertion_order(part: &Part, index: usize, count: usize) -> Result<MeasureOrder, String> {
+  let total = part_measures(part).count();
+  if index > total {
+    return Err(format!(
+      "Cannot insert measures at index {index} in part \"{}\" with {total} measures",
+      part.attributes.id.as_str()
+    ));
+  }
+  let mut order: MeasureOrder = (0..total).map(Some).collect();
+  order.splice(index..index, core::iter::repeat_n(None, count));
+  Ok(order)
+}
+
+/// Returns the rearranged order of measures for deleting a range of measures.
+fn deletion_order(part: &Part, range: &impl RangeBounds<usize>) -> Result<MeasureOrder, String> {
+  let total = part_measures(part).count();
+  let (start, end) = measure_range(range, part)?;
+  if end - start == total {
+    return Err(format!(
+      "Cannot delete every measure in part \"{}\"",
+      part.attributes.id.as_str()
+    ));
+  }
+  Ok((0..start).chain(end..total).map(Some).collect())
+}
+
+/// Returns the rearranged order of measures for moving a range of measures before `index`.
+fn movement_order(part: &Part, range: &impl RangeBounds<usize>, index: usize) -> Result<MeasureOrder, String> {
+  let total = part_measures(part).count();
+  let (start, end) = measure_range(range, part)?;
+  if index > total {
+    return Err(format!(
+      "Cannot move measures to index {index} in part \"{}\" with {total} measures",
+      part.attributes.id.as_str()
+    ));
+  } else if index > start && index < end {
+    return Err(format!(
+      "Cannot move measures {start}..{end} to index {index} within the moved range"
+    ));
+  }
+  let order: Vec<usize> = if index <= start {
+    (0..index)
+      .chain(start..end)
+      .chain(index..start)
+      .chain(end..total)
+      .collect()
+  } else {
+    (0..start)
+      .chain(end..index)
+      .chain(start..end)
+      .chain(index..total)
+      .collect()
+  };
+  Ok(order.into_iter().map(Some).collect())
+}
+
+/// Applies an edit to the partwise form of a timewise score.
+fn edit_timewise(
+  score: &ScoreTimewise,
+  edit: impl FnOnce(&mut ScorePartwise) -> Result<(), String>,
+) -> Result<ScoreTimewise, String> {
+  let mut partwise = ScorePartwise::deserialize(&convert_xml_timewise_to_partwise(ScoreTimewise::serialize(score))?)?;
+  edit(&mut partwise)?;
+  ScoreTimewise::deserialize(&convert_xml_partwise_to_timewise(ScorePartwise::serialize(&partwise))?)
+}
+
+/// Inserts `count` empty measures before the measure at zero-based `index` in every part of a score.
+///
+/// An `index` equal to the number of measures appends the new measures to the end of the score. Each new measure
+/// contains a whole-measure rest on every staff, with a length determined by the time signature in effect, and
+/// measures inserted at the start of a score take on the attributes of the measure that follows them. All measures
+/// are then renumbered as described in [renumber_measures], starting from the number of the first measure that is not
+/// implicit.
+///
+/// # Errors
+///
+/// If `index` is beyond the last measure of any part, an error message will be returned.
+pub fn insert_measures(score: &ScorePartwise, index: usize, count: usize) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  insert(&mut score, index, count)?;
+  Ok(score)
+}
+
+/// Deletes a range of measures, specified using zero-based measure indices, from every part of a score.
+///
+/// Any attributes that the measures following the deleted range relied on, such as a key change within the deleted
+/// measures, are added to the first measure after the range. All measures are then renumbered as described in
+/// [renumber_measures], starting from the number of the first measure that is not implicit.
+///
+/// # Errors
+///
+/// If the range is empty, extends past the last measure of any part, or includes every measure in the score, an
+/// error message will be returned.
+pub fn delete_measures(score: &ScorePartwise, range: impl RangeBounds<usize>) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  delete(&mut score, &range)?;
+  Ok(score)
+}
+
+/// Moves a range of measures, specified using zero-based measure indices, so that it starts before the measure at
+/// zero-based `index` in every part of a score.
+///
+/// Measures that no longer follow the measure originally preceding them have any attributes they relied on added to
+/// their start, so that each moved passage keeps its key, time signature, clefs, and divisions. All measures are then
+/// renumbered as described in [renumber_measures], starting from the number of the first measure that is not
+/// implicit.
+///
+/// # Errors
+///
+/// If the range is empty or extends past the last measure of any part, or if `index` falls within the range being
+/// moved, an error message will be returned.
+pub fn move_measures(
+  score: &ScorePartwise,
+  range: impl RangeBounds<usize>,
+  index: usize,
+) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  move_range(&mut score, &range, index)?;
+  Ok(score)
+}
+
+/// Renumbers the measures in every part of a score consecutively, starting from `first`.
+///
+/// Measures marked as `implicit` do not count towards the numbering. An implicit pickup measure at the start of the
+/// score is numbered one less than `first`, while any later implicit measure, such as the second half of a measure
+/// split by a repeat barline, takes the number of the measure before it with a letter suffix, such as `"12a"`. Any
+/// [MeasureText][crate::datatypes::MeasureText] shown in place of a measure number is updated along with the number
+/// if it matched the original number, and is otherwise left unchanged.
+///
+/// # Errors
+///
+/// This function does not currently return an error, and returns a [Result] for consistency with
+/// [ScoreTimewise::renumber_measures].
+pub fn renumber_measures(score: &ScorePartwise, first: u32) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  renumber(&mut score, first);
+  Ok(score)
+}
+
+/// Inserts empty measures into a partwise score and renumbers it.
+fn insert(score: &mut ScorePartwise, index: usize, count: usize) -> Result<(), String> {
+  let first = first_measure_number(score);
+  rearrange_measures(score, &|part| insertion_order(part, index, count))?;
+  renumber(score, first);
+  Ok(())
+}
+
+/// Deletes measures from a partwise score and renumbers it.
+fn delete(score: &mut ScorePartwise, range: &impl RangeBounds<usize>) -> Result<(), String> {
+  let first = first_measure_number(score);
+  rearrange_measures(score, &|part| deletion_order(part, range))?;
+  renumber(score, first);
+  Ok(())
+}
+
+/// Moves measures within a partwise score and renumbers it.
+fn move_range(score: &mut ScorePartwise, range: &impl RangeBounds<usize>, index: usize) -> Result<(), String> {
+  let first = first_measure_number(score);
+  rearrange_measures(score, &|part| movement_order(part, range, index))?;
+  renumber(score, first);
+  Ok(())
+}
+
+impl ScorePartwise {
+  /// Inserts `count` empty measures before the measure at zero-based `index` in every part.
+  ///
+  /// See [insert_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// If `index` is beyond the last measure of any part, an error message will be returned.
+  pub fn insert_measures(&self, index: usize, count: usize) -> Result<ScorePartwise, String> {
+    insert_measures(self, index, count)
+  }
+
+  /// Deletes a range of measures, specified using zero-based measure indices, from every part.
+  ///
+  /// See [delete_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// If the range is empty, extends past the last measure of any part, or includes every measure in the score, an
+  /// error message will be returned.
+  pub fn delete_measures(&self, range: impl RangeBounds<usize>) -> Result<ScorePartwise, String> {
+    delete_measures(self, range)
+  }
+
+  /// Moves a range of measures, specified using zero-based measure indices, so that it starts before the measure at
+  /// zero-based `index` in every part.
+  ///
+  /// See [move_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// If the range is empty or extends past the last measure of any part, or if `index` falls within the range being
+  /// moved, an error message will be returned.
+  pub fn move_measures(&self, range: impl RangeBounds<usize>, index: usize) -> Result<ScorePartwise, String> {
+    move_measures(self, range, index)
+  }
+
+  /// Renumbers the measures in every part consecutively, starting from `first`.
+  ///
+  /// See [renumber_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// This function does not currently return an error, and returns a [Result] for consistency with the timewise
+  /// version.
+  pub fn renumber_measures(&self, first: u32) -> Result<ScorePartwise, String> {
+    renumber_measures(self, first)
+  }
+}
+
+impl ScoreTimewise {
+  /// Inserts `count` empty measures before the measure at zero-based `index`, with an empty measure added for every
+  /// part.
+  ///
+  /// See [insert_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// If `index` is beyond the last measure of any part, an error message will be returned.
+  pub fn insert_measures(&self, index: usize, count: usize) -> Result<ScoreTimewise, String> {
+    edit_timewise(self, |score| insert(score, index, count))
+  }
+
+  /// Deletes a range of measures, specified using zero-based measure indices.
+  ///
+  /// See [delete_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// If the range is empty, extends past the last measure of any part, or includes every measure in the score, an
+  /// error message will be returned.
+  pub fn delete_measures(&self, range: impl RangeBounds<usize>) -> Result<ScoreTimewise, String> {
+    edit_timewise(self, |score| delete(score, &range))
+  }
+
+  /// Moves a range of measures, specified using zero-based measure indices, so that it starts before the measure at
+  /// zero-based `index`.
+  ///
+  /// See [move_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// If the range is empty or extends past the last measure of any part, or if `index` falls within the range being
+  /// moved, an error message will be returned.
+  pub fn move_measures(&self, range: impl RangeBounds<usize>, index: usize) -> Result<ScoreTimewise, String> {
+    edit_timewise(self, |score| move_range(score, &range, index))
+  }
+
+  /// Renumbers the measures consecutively, starting from `first`.
+  ///
+  /// See [renumber_measures] for details.
+  ///
+  /// # Errors
+  ///
+  /// This function only returns an error if the score cannot be converted between timewise and partwise forms.
+  pub fn renumber_measures(&self, first: u32) -> Result<ScoreTimewise, String> {
+    edit_timewise(self, |score| {
+      renumber(score, first);
+      Ok(())
+    })
+  }
+}
+
+#[cfg(test)]
+mod measures_tests {
+  use super::*;
+  use crate::elements::{Attributes, Measure, MeasureElement, Note};
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_duration, note_is_rest, note_pitch, part_measures, step_letter};
+  use crate::visit::for_each;
+
+  const SCORE: &str = r#"<score-partwise>
+    <part-list>
+      <score-part id="P1"><part-name>Violin</part-name></score-part>
+      <score-part id="P2"><part-name>Piano</part-name></score-part>
+    </part-list>
+    <part id="P1">
+      <measure number="0" implicit="yes">
+        <attributes><divisions>1</divisions><key><fifths>0</fifths></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time></attributes>
+        <note><pitch><step>G</step><octave>4</octave></pitch><duration>1</duration></note>
+      </measure>
+      <measure number="1" text="1"><note><pitch><step>C</step><octave>5</octave></pitch><duration>3</duration></note></measure>
+      <measure number="2">
+        <attributes><key><fifths>2</fifths></key><time><beats>6</beats><beat-type>8</beat-type></time></attributes>
+        <note><pitch><step>D</step><octave>5</octave></pitch><duration>3</duration></note>
+      </measure>
+      <measure number="3" text="A"><note><pitch><step>E</step><octave>5</octave></pitch><duration>3</duration></note></measure>
+    </part>
+    <part id="P2">
+      <measure number="0" implicit="yes">
+        <attributes><divisions>2</divisions><key><fifths>0</fifths></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time><staves>2</staves></attributes>
+        <note><rest/><duration>2</duration><staff>1</staff></note>
+      </measure>
+      <measure number="1"><note><rest measure="yes"/><duration>6</duration><staff>1</staff></note></measure>
+      <measure number="2">
+        <attributes><key><fifths>2</fifths></key><time><beats>6</beats><beat-type>8</beat-type></time></attributes>
+        <note><rest measure="yes"/><duration>6</duration><staff>1</staff></note>
+      </measure>
+      <measure number="3"><note><rest measure="yes"/><duration>6</duration><staff>1</staff></note></measure>
+    </part>
+  </score-partwise>"#;
+
+  fn numbers(score: &ScorePartwise, part: usize) -> Vec<String> {
+    part_measures(&score.content.part[part])
+      .map(|measure: &Measure| measure.attributes.number.to_string())
+      .collect()
+  }
+
+  fn pitches(score: &ScorePartwise) -> Vec<String> {
+    part_measures(&score.content.part[0])
+      .map(|measure| {
+        let mut steps = String::new();
+        for_each(measure, |note: &Note, _| {
+          steps.push(note_pitch(note).map_or('-', |pitch| step_letter(&pitch.content.step.content)));
+        });
+        steps
+      })
+      .collect()
+  }
+
+  #[test]
+  fn insert_empty_measures() {
+    let edited = insert_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 2, 2).unwrap();
+    assert_eq!(numbers(&edited, 0), vec!["0", "1", "2", "3", "4", "5"]);
+    assert_eq!(numbers(&edited, 1), vec!["0", "1", "2", "3", "4", "5"]);
+    assert_eq!(pitches(&edited), vec!["G", "C", "-", "-", "D", "E"]);
+    let mut rests = Vec::new();
+    for_each(&edited, |note: &Note, context| {
+      if context.measure_number.as_deref() == Some("2") {
+        rests.push((
+          context.part_id.clone().unwrap(),
+          note_is_rest(note),
+          note_duration(note),
+          context.staff,
+        ));
+      }
+    });
+    assert_eq!(
+      rests,
+      vec![
+        ("P1".to_string(), true, 3, None),
+        ("P2".to_string(), true, 6, Some(1)),
+        ("P2".to_string(), true, 6, Some(2)),
+      ]
+    );
+
+    // Inserting at the start of a score copies the attributes of the following measure
+    let edited = insert_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 0, 1).unwrap();
+    let first = part_measures(&edited.content.part[0]).next().unwrap();
+    let mut divisions = Vec::new();
+    for_each(first, |attributes: &Attributes, _| {
+      divisions.push(attributes.content.divisions.is_some())
+    });
+    assert_eq!(divisions, vec![true]);
+    assert_eq!(numbers(&edited, 0), vec!["1", "1a", "2", "3", "4"]);
+    assert!(insert_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 5, 1).is_err());
+  }
+
+  #[test]
+  fn insert_rescales_divisions() {
+    let source = SCORE.replace("<beats>6</beats>", "<beats>7</beats>");
+    let edited = insert_measures(&parse_from_xml_str::<ScorePartwise>(&source).unwrap(), 3, 1).unwrap();
+    let mut durations = Vec::new();
+    for_each(&edited, |note: &Note, context| {
+      if matches!(context.measure_number.as_deref(), Some("3" | "4")) {
+        durations.push((
+          context.measure_number.clone().unwrap(),
+          context.divisions,
+          note_duration(note),
+        ));
+      }
+    });
+    let expected = [("3", 2, 7), ("4", 1, 3), ("3", 2, 7), ("3", 2, 7), ("4", 2, 6)];
+    assert_eq!(
+      durations,
+      expected.map(|(number, divisions, duration)| (number.to_string(), divisions, duration))
+    );
+  }
+
+  #[test]
+  fn delete_carries_attributes() {
+    let edited = delete_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 1..3).unwrap();
+    assert_eq!(numbers(&edited, 0), vec!["0", "1"]);
+    assert_eq!(pitches(&edited), vec!["G", "E"]);
+    let mut keys = Vec::new();
+    for_each(&edited, |attributes: &Attributes, context| {
+      if context.part_id.as_deref() == Some("P1") {
+        keys.push((
+          context.measure_number.clone().unwrap(),
+          attributes.content.key.len(),
+          attributes.content.time.len(),
+        ));
+      }
+    });
+    assert_eq!(keys, vec![("0".to_string(), 1, 1), ("1".to_string(), 1, 1)]);
+    assert_eq!(
+      delete_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), ..).unwrap_err(),
+      "Cannot delete every measure in part \"P1\""
+    );
+  }
+
+  #[test]
+  fn move_measures_between_positions() {
+    let edited = move_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 3..4, 1).unwrap();
+    assert_eq!(pitches(&edited), vec!["G", "E", "C", "D"]);
+    assert_eq!(numbers(&edited, 0), vec!["0", "1", "2", "3"]);
+    let mut divisions = Vec::new();
+    for_each(&edited, |_: &Note, context| {
+      if context.part_id.as_deref() == Some("P2") {
+        divisions.push(context.divisions);
+      }
+    });
+    assert_eq!(divisions, vec![2, 2, 2, 2]);
+    let moved = part_measures(&edited.content.part[0]).nth(1).unwrap();
+    assert_eq!(
+      moved.attributes.text.as_ref().map(|text| text.to_string()),
+      Some("A".to_string())
+    );
+    let mut keys = 0;
+    for_each(moved, |attributes: &Attributes, _| keys += attributes.content.key.len());
+    assert_eq!(keys, 1);
+    assert_eq!(
+      move_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 1..3, 2).unwrap_err(),
+      "Cannot move measures 1..3 to index 2 within the moved range"
+    );
+    assert_eq!(
+      pitches(&move_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 0..2, 4).unwrap()),
+      vec!["D", "E", "G", "C"]
+    );
+  }
+
+  #[test]
+  fn renumber_pickups_and_text() {
+    let renumbered = parse_from_xml_str::<ScorePartwise>(SCORE)
+      .unwrap()
+      .renumber_measures(10)
+      .unwrap();
+    assert_eq!(numbers(&renumbered, 0), vec!["9", "10", "11", "12"]);
+    let texts: Vec<Option<String>> = part_measures(&renumbered.content.part[0])
+      .map(|measure| measure.attributes.text.as_ref().map(|text| text.to_string()))
+      .collect();
+    assert_eq!(texts, vec![None, Some("10".to_string()), None, Some("A".to_string())]);
+
+    // Timewise scores are edited in the same way
+    let xml = convert_xml_partwise_to_timewise(ScorePartwise::serialize(
+      &parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(),
+    ))
+    .unwrap();
+    let timewise = ScoreTimewise::deserialize(&xml).unwrap();
+    let edited = timewise.insert_measures(1, 1).unwrap().renumber_measures(1).unwrap();
+    let numbers: Vec<String> = edited
+      .content
+      .measure
+      .iter()
+      .map(|measure| measure.attributes.number.to_string())
+      .collect();
+    assert_eq!(numbers, vec!["0", "1", "2", "3", "4"]);
+    let parts = edited.content.measure[1]
+      .content
+      .iter()
+      .filter(|element| matches!(element, MeasureElement::Part(_)))
+      .count();
+    assert_eq!(parts, 2);
+  }
+}
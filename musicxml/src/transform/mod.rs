@@ -0,0 +1,15 @@
+mod concat;
+mod divisions;
+mod extract;
+mod measures;
+mod reduce;
+mod slice;
+mod voices;
+
+pub use concat::concat;
+pub use divisions::{normalize_divisions, reduce_divisions};
+pub use extract::extract_part;
+pub use measures::{delete_measures, insert_measures, move_measures, renumber_measures};
+pub use reduce::reduce_parts;
+pub use slice::slice_measures;
+pub use voices::{merge_voices, move_voice_to_staff, renumber_voices, split_voices, MeasureVoices, VoiceSequence};
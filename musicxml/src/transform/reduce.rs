@@ -0,0 +1,1082 @@
+use super::measures::time_length;
+use crate::datatypes::{
+  ClefSign, Fifths, Id, IdRef, NonNegativeInteger, NoteTypeValue, Octave as OctaveValue, PositiveDivisions,
+  PositiveInteger, RightLeftMiddle, Semitones, StaffLinePosition, StaffNumber, StartStop, StartStopContinue,
+  Step as StepValue, YesNo,
+};
+use crate::elements::{
+  ActualNotes, Alter, Attributes, AttributesContents, AudibleType, Backup, BackupContents, Chord, Clef, ClefAttributes,
+  ClefContents, Divisions, Dot, DotAttributes, Duration, Forward, ForwardContents, Key, KeyContents, Line, Measure,
+  MeasureAttributes, MeasureElement, NormalInfo, NormalNotes, NotationContentTypes, Notations, NotationsAttributes,
+  NotationsContents, Note, NoteAttributes, NoteContents, NoteType, Octave, Part, PartAttributes, PartElement,
+  PartListElement, PartName, PartNameAttributes, Pitch, PitchContents, Rest, RestAttributes, RestContents, ScorePart,
+  ScorePartAttributes, ScorePartContents, ScorePartwise, Sign, Staff, Staves, Step, Tie, TieAttributes, Tied,
+  TiedAttributes, Time, TimeModification, TimeModificationContents, Transpose, Type, TypeAttributes, Voice,
+};
+use crate::util::{
+  dotted_length, lcm, note_is_cue, note_is_grace, note_pitch, note_staff, note_tied, note_time_modification,
+  part_measures, pitch_to_midi, split_length, step_from_letter, step_letter, Fraction, MeasureEvent, PartTimeline,
+};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Letter names of the diatonic steps, starting from C.
+const STEP_LETTERS: [char; 7] = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+
+/// Number of semitones between C and each diatonic step.
+const STEP_SEMITONES: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// MIDI note number of the lowest pitch placed on the upper staff (middle C).
+const UPPER_STAFF_LOWEST: i32 = 60;
+
+/// Number of voice numbers reserved for the upper staff, so that the lower staff starts at voice 5.
+const UPPER_STAFF_VOICES: usize = 4;
+
+/// The interval between the written and sounding pitch of a transposing instrument.
+#[derive(Debug, Clone, Copy, Default)]
+struct Interval {
+  steps: i32,
+  semitones: i32,
+}
+
+impl Interval {
+  fn from_transpose(transpose: &Transpose) -> Self {
+    let octaves = transpose
+      .content
+      .octave_change
+      .as_ref()
+      .map_or(0, |octave_change| i32::from(octave_change.content));
+    let chromatic = i32::from(*transpose.content.chromatic.content);
+    let steps = match &transpose.content.diatonic {
+      Some(diatonic) => i32::from(diatonic.content),
+      // Without a diatonic value, spell the interval as the nearest diatonic step below the chromatic distance
+      None => {
+        (0..)
+          .zip(STEP_SEMITONES)
+          .filter(|&(_, semitones)| semitones <= chromatic.rem_euclid(12))
+          .last()
+          .map_or(0, |(step, _)| step)
+          + 7 * chromatic.div_euclid(12)
+      }
+    };
+    Interval {
+      steps: steps + 7 * octaves,
+      semitones: chromatic + 12 * octaves,
+    }
+  }
+
+  /// Returns the number of sharps added to a key signature transposed by this interval.
+  fn fifths(self) -> i32 {
+    7 * self.semitones - 12 * self.steps
+  }
+}
+
+/// A single pitched note at concert pitch, positioned in quarter notes from the start of its measure.
+#[derive(Debug, Clone)]
+struct ReducedNote {
+  position: Fraction,
+  duration: Fraction,
+  step: usize,
+  alter: i32,
+  octave: i32,
+  midi: i32,
+  tie: (bool, bool),
+  note_type: Option<(NoteTypeValue, usize)>,
+  time_modification: (u32, u32),
+}
+
+impl ReducedNote {
+  fn new(note: &Note, pitch: &Pitch, position: Fraction, duration: Fraction, interval: Interval) -> Self {
+    let letter = step_letter(&pitch.content.step.content);
+    let step = (0..)
+      .zip(STEP_LETTERS)
+      .find_map(|(step, other)| (other == letter).then_some(step))
+      .unwrap_or(0);
+    let diatonic = i32::from(*pitch.content.octave.content) * 7 + step + interval.steps;
+    let (octave, step) = (diatonic.div_euclid(7), diatonic.rem_euclid(7) as usize);
+    let midi = pitch_to_midi(pitch) + interval.semitones;
+    ReducedNote {
+      position,
+      duration,
+      step,
+      alter: midi - (octave + 1) * 12 - STEP_SEMITONES[step],
+      octave,
+      midi,
+      tie: note_tied(note),
+      note_type: note
+        .content
+        .r#type
+        .as_ref()
+        .map(|note_type| (note_type.content.clone(), note.content.dot.len())),
+      time_modification: note_time_modification(note),
+    }
+  }
+}
+
+/// Notes on the same staff sharing an onset and duration, written as a single chord.
+#[derive(Debug)]
+struct NoteGroup {
+  position: Fraction,
+  duration: Fraction,
+  notes: Vec<ReducedNote>,
+}
+
+/// The contents gathered from all selected parts for a single measure of the reduction.
+#[derive(Default)]
+struct MeasureContents {
+  notes: Vec<ReducedNote>,
+  length: Fraction,
+  key: Option<Key>,
+  time_change: Option<Time>,
+  time: Option<Time>,
+}
+
+/// Converts lengths in quarter notes into durations, recording the divisions needed to represent every length
+/// exactly.
+///
+/// The reduction is built twice: once to find the required divisions, and again using them.
+struct DurationScale {
+  divisions: i64,
+  required: i64,
+}
+
+impl DurationScale {
+  fn new(divisions: i64) -> Self {
+    DurationScale { divisions, required: 1 }
+  }
+
+  fn duration(&mut self, length: Fraction) -> Duration {
+    self.required = lcm(self.required, length.den);
+    let divisions = (length.num * self.divisions / length.den).max(1);
+    Duration {
+      attributes: (),
+      content: PositiveDivisions(u32::try_from(divisions).unwrap_or(u32::MAX)),
+    }
+  }
+}
+
+/// Returns a key signature at concert pitch, applying to all staves.
+fn concert_key(key: &Key, interval: Interval) -> Key {
+  let mut key = key.clone();
+  key.attributes.number = None;
+  if let KeyContents::Explicit(explicit) = &mut key.content {
+    let mut value = i32::from(explicit.fifths.content.0) + interval.fifths();
+    while value > 7 {
+      value -= 12;
+    }
+    while value < -7 {
+      value += 12;
+    }
+    explicit.fifths.content = Fifths(i8::try_from(value).unwrap_or_default());
+  }
+  key
+}
+
+/// Returns a time signature applying to all staves.
+fn common_time(time: &Time) -> Time {
+  let mut time = time.clone();
+  time.attributes.number = None;
+  time
+}
+
+/// Gathers the concert-pitch notes of a part into the contents of each measure, recording key and time signatures
+/// when the part is the reference part of the reduction.
+fn gather_part(part: &Part, measures: &mut [MeasureContents], reference: bool) {
+  let mut timeline = PartTimeline::default();
+  let mut intervals: BTreeMap<u32, Interval> = BTreeMap::new();
+  let mut time = None;
+  for (measure, contents) in part_measures(part).zip(measures.iter_mut()) {
+    for event in timeline.measure(measure).events {
+      match event.event {
+        MeasureEvent::Attributes(attributes) => {
+          for transpose in &attributes.content.transpose {
+            if let Some(number) = &transpose.attributes.number {
+              intervals.insert(u32::from(**number), Interval::from_transpose(transpose));
+            } else {
+              intervals.clear();
+              intervals.insert(0, Interval::from_transpose(transpose));
+            }
+          }
+          if reference {
+            let interval = intervals.get(&1).or(intervals.get(&0)).copied().unwrap_or_default();
+            if let Some(key) = attributes
+              .content
+              .key
+              .iter()
+              .find(|key| key.attributes.number.as_ref().is_none_or(|number| **number == 1))
+            {
+              contents.key = Some(concert_key(key, interval));
+            }
+            if let Some(signature) = attributes.content.time.first() {
+              time = Some(common_time(signature));
+              contents.time_change.clone_from(&time);
+            }
+          }
+        }
+        MeasureEvent::Notes(notes) => {
+          contents.length = contents.length.max(event.position + event.duration);
+          for note in notes
+            .into_iter()
+            .filter(|note| !note_is_grace(note) && !note_is_cue(note))
+          {
+            if let Some(pitch) = note_pitch(note) {
+              let interval = intervals
+                .get(&note_staff(note))
+                .or(intervals.get(&0))
+                .copied()
+                .unwrap_or_default();
+              contents
+                .notes
+                .push(ReducedNote::new(note, pitch, event.position, event.duration, interval));
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+    if reference {
+      contents.time.clone_from(&time);
+    }
+  }
+}
+
+/// Merges notes with identical rhythms into chords and distributes the chords between as few voices as possible
+/// without any overlap.
+fn voice_groups(mut notes: Vec<ReducedNote>) -> Vec<Vec<NoteGroup>> {
+  notes.sort_by(|a, b| (a.position, b.duration, a.midi).cmp(&(b.position, a.duration, b.midi)));
+  let mut groups: Vec<NoteGroup> = Vec::new();
+  for note in notes {
+    match groups.last_mut() {
+      Some(group) if group.position == note.position && group.duration == note.duration => {
+        match group.notes.iter_mut().find(|other| other.midi == note.midi) {
+          Some(unison) => unison.tie = (unison.tie.0 || note.tie.0, unison.tie.1 || note.tie.1),
+          None => group.notes.push(note),
+        }
+      }
+      _ => groups.push(NoteGroup {
+        position: note.position,
+        duration: note.duration,
+        notes: vec![note],
+      }),
+    }
+  }
+  let mut voices: Vec<Vec<NoteGroup>> = Vec::new();
+  for group in groups {
+    match voices.iter_mut().find(|voice| {
+      voice
+        .last()
+        .is_none_or(|last| last.position + last.duration <= group.position)
+    }) {
+      Some(voice) => voice.push(group),
+      None => voices.push(vec![group]),
+    }
+  }
+  voices
+}
+
+/// Returns a note on a single voice and staff, leaving all other optional contents empty.
+fn new_note(audible: AudibleType, chord: bool, duration: Duration, voice: &str, staff: u32) -> Note {
+  Note {
+    attributes: NoteAttributes::default(),
+    content: NoteContents {
+      info: NoteType::Normal(NormalInfo {
+        chord: chord.then_some(Chord {
+          attributes: (),
+          content: (),
+        }),
+        audible,
+        duration,
+        tie: Vec::new(),
+      }),
+      instrument: Vec::new(),
+      footnote: None,
+      level: None,
+      voice: Some(Voice {
+        attributes: (),
+        content: voice.to_string(),
+      }),
+      r#type: None,
+      dot: Vec::new(),
+      accidental: None,
+      time_modification: None,
+      stem: None,
+      notehead: None,
+      notehead_text: None,
+      staff: Some(Staff {
+        attributes: (),
+        content: PositiveInteger(staff),
+      }),
+      beam: Vec::new(),
+      notations: Vec::new(),
+      lyric: Vec::new(),
+      play: None,
+      listen: None,
+    },
+  }
+}
+
+/// Returns a rest, which lasts the whole measure if `measure` is set.
+fn rest(measure: bool) -> AudibleType {
+  AudibleType::Rest(Rest {
+    attributes: RestAttributes {
+      measure: measure.then_some(YesNo::Yes),
+    },
+    content: RestContents {
+      display_step: None,
+      display_octave: None,
+    },
+  })
+}
+
+/// Sets the note type and augmentation dots of a note.
+fn set_note_type(note: &mut Note, note_type: &NoteTypeValue, dots: usize) {
+  note.content.r#type = Some(Type {
+    attributes: TypeAttributes::default(),
+    content: note_type.clone(),
+  });
+  note.content.dot = (0..dots)
+    .map(|_| Dot {
+      attributes: DotAttributes::default(),
+      content: (),
+    })
+    .collect();
+}
+
+/// Fills a gap within a voice, using rests in the first voice of a staff and invisible forwards in all others.
+fn fill_gap(
+  elements: &mut Vec<MeasureElement>,
+  scale: &mut DurationScale,
+  gap: Fraction,
+  voice: &str,
+  staff: u32,
+  rests: bool,
+) {
+  if !gap.is_positive() {
+    return;
+  }
+  if !rests {
+    elements.push(MeasureElement::Forward(Forward {
+      attributes: (),
+      content: ForwardContents {
+        duration: scale.duration(gap),
+        footnote: None,
+        level: None,
+        voice: Some(Voice {
+          attributes: (),
+          content: voice.to_string(),
+        }),
+        staff: Some(Staff {
+          attributes: (),
+          content: PositiveInteger(staff),
+        }),
+      },
+    }));
+    return;
+  }
+  let pieces = split_length(gap);
+  let typed = pieces
+    .iter()
+    .map(|(note_type, dots)| dotted_length(note_type, *dots))
+    .fold(Fraction::ZERO, |total, length| total + length)
+    == gap;
+  let pieces: Vec<(Fraction, Option<(NoteTypeValue, usize)>)> = if typed {
+    pieces
+      .into_iter()
+      .map(|(note_type, dots)| (dotted_length(&note_type, dots), Some((note_type, usize::from(dots)))))
+      .collect()
+  } else {
+    vec![(gap, None)]
+  };
+  for (duration, note_type) in pieces {
+    let mut note = new_note(rest(false), false, scale.duration(duration), voice, staff);
+    if let Some((note_type, dots)) = note_type {
+      set_note_type(&mut note, &note_type, dots);
+    }
+    elements.push(MeasureElement::Note(note));
+  }
+}
+
+/// Returns a note from a chord group, marking it as a chord member if it is not the first note.
+fn reduced_note(
+  note: &ReducedNote,
+  group: &NoteGroup,
+  chord: bool,
+  scale: &mut DurationScale,
+  voice: &str,
+  staff: u32,
+) -> Note {
+  let pitch = AudibleType::Pitch(Pitch {
+    attributes: (),
+    content: PitchContents {
+      step: Step {
+        attributes: (),
+        content: step_from_letter(STEP_LETTERS[note.step]).unwrap_or(StepValue::C),
+      },
+      alter: (note.alter != 0).then(|| Alter {
+        attributes: (),
+        content: Semitones(i16::try_from(note.alter).unwrap_or_default()),
+      }),
+      octave: Octave {
+        attributes: (),
+        content: OctaveValue(u8::try_from(note.octave).unwrap_or_default()),
+      },
+    },
+  });
+  let mut reduced = new_note(pitch, chord, scale.duration(group.duration), voice, staff);
+  let ties: Vec<StartStop> = [(note.tie.1, StartStop::Stop), (note.tie.0, StartStop::Start)]
+    .into_iter()
+    .filter_map(|(tied, kind)| tied.then_some(kind))
+    .collect();
+  if let NoteType::Normal(info) = &mut reduced.content.info {
+    info.tie = ties
+      .iter()
+      .map(|kind| Tie {
+        attributes: TieAttributes {
+          r#type: kind.clone(),
+          time_only: None,
+        },
+        content: (),
+      })
+      .collect();
+  }
+  let head = &group.notes[0];
+  if let Some((note_type, dots)) = &head.note_type {
+    set_note_type(&mut reduced, note_type, *dots);
+  }
+  if head.time_modification != (1, 1) {
+    reduced.content.time_modification = Some(TimeModification {
+      attributes: (),
+      content: TimeModificationContents {
+        actual_notes: ActualNotes {
+          attributes: (),
+          content: NonNegativeInteger(head.time_modification.0),
+        },
+        normal_notes: NormalNotes {
+          attributes: (),
+          content: NonNegativeInteger(head.time_modification.1),
+        },
+        normal_type: None,
+        normal_dot: Vec::new(),
+      },
+    });
+  }
+  if !ties.is_empty() {
+    reduced.content.notations.push(Notations {
+      attributes: NotationsAttributes::default(),
+      content: NotationsContents {
+        notations: ties.iter().map(|kind| NotationContentTypes::Tied(tied(kind))).collect(),
+        ..NotationsContents::default()
+      },
+    });
+  }
+  reduced
+}
+
+/// Returns a notated tie of the specified type.
+fn tied(kind: &StartStop) -> Tied {
+  Tied {
+    attributes: TiedAttributes {
+      r#type: match kind {
+        StartStop::Start => StartStopContinue::Start,
+        StartStop::Stop => StartStopContinue::Stop,
+      },
+      bezier_offset: None,
+      bezier_offset2: None,
+      bezier_x: None,
+      bezier_x2: None,
+      bezier_y: None,
+      bezier_y2: None,
+      color: None,
+      dash_length: None,
+      default_x: None,
+      default_y: None,
+      id: None,
+      line_type: None,
+      number: None,
+      orientation: None,
+      placement: None,
+      relative_x: None,
+      relative_y: None,
+      space_length: None,
+    },
+    content: (),
+  }
+}
+
+/// Returns a clef for a staff of the reduction.
+fn staff_clef(number: u8, sign: ClefSign, line: i16) -> Clef {
+  Clef {
+    attributes: ClefAttributes {
+      number: Some(StaffNumber(number)),
+      ..ClefAttributes::default()
+    },
+    content: ClefContents {
+      sign: Sign {
+        attributes: (),
+        content: sign,
+      },
+      line: Some(Line {
+        attributes: (),
+        content: StaffLinePosition(line),
+      }),
+      clef_octave_change: None,
+    },
+  }
+}
+
+/// Returns a backup by the specified length.
+fn backup(scale: &mut DurationScale, length: Fraction) -> MeasureElement {
+  MeasureElement::Backup(Backup {
+    attributes: (),
+    content: BackupContents {
+      duration: scale.duration(length),
+      footnote: None,
+      level: None,
+    },
+  })
+}
+
+/// Builds a measure of the reduction from its gathered contents and the corresponding measure of the reference part.
+fn reduced_measure(
+  contents: &MeasureContents,
+  reference: &Measure,
+  first: bool,
+  lower_voice: usize,
+  scale: &mut DurationScale,
+) -> Measure {
+  let implicit = reference.attributes.implicit == Some(YesNo::Yes);
+  let mut measure = Measure {
+    attributes: MeasureAttributes {
+      number: reference.attributes.number.clone(),
+      id: None,
+      implicit: reference.attributes.implicit.clone(),
+      non_controlling: None,
+      text: None,
+      width: None,
+    },
+    content: Vec::new(),
+  };
+  let length = if implicit && contents.length.is_positive() {
+    contents.length
+  } else {
+    contents.length.max(time_length(contents.time.as_ref()))
+  };
+
+  // Attributes and left barlines precede the notes, all other barlines follow them
+  if first || contents.key.is_some() || contents.time_change.is_some() {
+    let mut attributes = AttributesContents {
+      key: contents.key.iter().cloned().collect(),
+      time: contents.time_change.iter().cloned().collect(),
+      ..AttributesContents::default()
+    };
+    if first {
+      attributes.divisions = Some(Divisions {
+        attributes: (),
+        content: PositiveDivisions(u32::try_from(scale.divisions).unwrap_or(u32::MAX)),
+      });
+      attributes.staves = Some(Staves {
+        attributes: (),
+        content: NonNegativeInteger(2),
+      });
+      attributes.clef = vec![staff_clef(1, ClefSign::G, 2), staff_clef(2, ClefSign::F, 4)];
+    }
+    measure.content.push(MeasureElement::Attributes(Attributes {
+      attributes: (),
+      content: attributes,
+    }));
+  }
+  let (left, right): (Vec<MeasureElement>, Vec<MeasureElement>) = reference
+    .content
+    .iter()
+    .filter(|element| matches!(element, MeasureElement::Barline(_)))
+    .cloned()
+    .partition(|element| {
+      matches!(element, MeasureElement::Barline(barline) if barline.attributes.location == Some(RightLeftMiddle::Left))
+    });
+  measure.content.extend(left);
+
+  let (upper, lower): (Vec<_>, Vec<_>) = contents
+    .notes
+    .iter()
+    .cloned()
+    .partition(|note| note.midi >= UPPER_STAFF_LOWEST);
+  let mut cursor = Fraction::ZERO;
+  for (staff, notes, first_voice) in [(1, upper, 1), (2, lower, lower_voice)] {
+    let voices = voice_groups(notes);
+    if voices.is_empty() {
+      if cursor.is_positive() {
+        measure.content.push(backup(scale, cursor));
+      }
+      let rest = new_note(
+        rest(true),
+        false,
+        scale.duration(length),
+        &first_voice.to_string(),
+        staff,
+      );
+      measure.content.push(MeasureElement::Note(rest));
+      cursor = length;
+      continue;
+    }
+    for (index, groups) in voices.iter().enumerate() {
+      let voice = (first_voice + index).to_string();
+      if cursor.is_positive() {
+        measure.content.push(backup(scale, cursor));
+      }
+      cursor = Fraction::ZERO;
+      for group in groups {
+        fill_gap(
+          &mut measure.content,
+          scale,
+          group.position - cursor,
+          &voice,
+          staff,
+          index == 0,
+        );
+        for (position, note) in group.notes.iter().enumerate() {
+          let note = reduced_note(note, group, position > 0, scale, &voice, staff);
+          measure.content.push(MeasureElement::Note(note));
+        }
+        cursor = group.position + group.duration;
+      }
+      if index == 0 {
+        fill_gap(&mut measure.content, scale, length - cursor, &voice, staff, true);
+        cursor = cursor.max(length);
+      }
+    }
+  }
+  measure.content.extend(right);
+  measure
+}
+
+/// Creates a piano reduction of several parts, appending it to the score as a new two-staff part.
+///
+/// The notes of every part in `part_ids` are converted to concert pitch using the [Transpose] element in effect, and
+/// placed on the upper (treble clef) staff if they are at or above middle C or on the lower (bass clef) staff
+/// otherwise. Notes on the same staff sharing an onset and duration are merged into a single chord, with unisons
+/// written only once, while notes with distinct rhythms are kept in separate voices: voices 1 to 4 on the upper staff
+/// and voices 5 onwards on the lower staff. Gaps in the first voice of each staff are filled with rests.
+///
+/// The measures of the reduction follow the first selected part, from which measure numbers, barlines, and key and
+/// time signatures (at concert pitch) are also taken. Grace notes, cue notes, unpitched notes, and all notations other
+/// than ties are omitted. The new part is named `part_name` and receives the first unused ID of the form `"P<n>"`.
+///
+/// # Errors
+///
+/// If no parts are selected or any selected part does not exist in the score, an error message will be returned.
+pub fn reduce_parts(score: &ScorePartwise, part_ids: &[&str], part_name: &str) -> Result<ScorePartwise, String> {
+  if part_ids.is_empty() {
+    return Err("At least one part must be selected for reduction".to_string());
+  }
+  let parts = part_ids
+    .iter()
+    .map(|id| {
+      score
+        .content
+        .part
+        .iter()
+        .find(|part| *part.attributes.id == *id)
+        .ok_or_else(|| format!("Part \"{id}\" does not exist in the score"))
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+  let reference: Vec<&Measure> = part_measures(parts[0]).collect();
+  let mut measures: Vec<MeasureContents> = reference.iter().map(|_| MeasureContents::default()).collect();
+  for (index, part) in parts.iter().enumerate() {
+    if !parts[..index]
+      .iter()
+      .any(|other| other.attributes.id == part.attributes.id)
+    {
+      gather_part(part, &mut measures, index == 0);
+    }
+  }
+
+  // Lower staff voices start after all voices used by the upper staff
+  let lower_voice = measures
+    .iter()
+    .map(|contents| {
+      voice_groups(
+        contents
+          .notes
+          .iter()
+          .filter(|note| note.midi >= UPPER_STAFF_LOWEST)
+          .cloned()
+          .collect(),
+      )
+      .len()
+    })
+    .fold(UPPER_STAFF_VOICES, usize::max)
+    + 1;
+
+  // Build the measures once to find the divisions needed to represent every duration, then again using them
+  let build = |scale: &mut DurationScale| -> Vec<PartElement> {
+    measures
+      .iter()
+      .zip(&reference)
+      .enumerate()
+      .map(|(index, (contents, measure))| {
+        PartElement::Measure(reduced_measure(contents, measure, index == 0, lower_voice, scale))
+      })
+      .collect()
+  };
+  let mut scale = DurationScale::new(1);
+  build(&mut scale);
+  let mut scale = DurationScale::new(scale.required);
+  let measures = build(&mut scale);
+
+  let mut score = score.clone();
+  let part_list = &mut score.content.part_list.content.content;
+  let ids: Vec<String> = part_list
+    .iter()
+    .filter_map(|element| match element {
+      PartListElement::ScorePart(score_part) => Some(score_part.attributes.id.to_string()),
+      PartListElement::PartGroup(_) => None,
+    })
+    .collect();
+  let id = (ids.len() + 1..=2 * ids.len() + 1)
+    .map(|number| format!("P{number}"))
+    .find(|id| !ids.contains(id))
+    .unwrap_or_default();
+  part_list.push(PartListElement::ScorePart(ScorePart {
+    attributes: ScorePartAttributes { id: Id(id.clone()) },
+    content: ScorePartContents {
+      identification: None,
+      part_link: Vec::new(),
+      part_name: PartName {
+        attributes: PartNameAttributes::default(),
+        content: part_name.to_string(),
+      },
+      part_name_display: None,
+      part_abbreviation: None,
+      part_abbreviation_display: None,
+      group: Vec::new(),
+      score_instrument: Vec::new(),
+      player: Vec::new(),
+      midi_device: Vec::new(),
+      midi_instrument: Vec::new(),
+    },
+  }));
+  score.content.part.push(Part {
+    attributes: PartAttributes { id: IdRef(id) },
+    content: measures,
+  });
+  Ok(score)
+}
+
+#[cfg(test)]
+mod reduce_tests {
+  use super::*;
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_duration, note_is_chord, note_voice};
+  use crate::visit::for_each;
+
+  /// Returns the reduced part as `(chord, pitch, duration, voice, staff)` for every note, with `"R"` for rests.
+  fn reduced_notes(score: &ScorePartwise) -> Vec<(bool, String, String, String, String)> {
+    let measure = part_measures(score.content.part.last().unwrap()).next().unwrap();
+    measure
+      .content
+      .iter()
+      .filter_map(|element| match element {
+        MeasureElement::Note(note) => Some(note),
+        _ => None,
+      })
+      .map(|note| {
+        let pitch = note_pitch(note).map_or("R".to_string(), |pitch| {
+          let alter = pitch.content.alter.as_ref().map(|alter| alter.content.0.to_string());
+          format!(
+            "{}{}{}",
+            step_letter(&pitch.content.step.content),
+            alter.unwrap_or_default(),
+            pitch.content.octave.content.0
+          )
+        });
+        (
+          note_is_chord(note),
+          pitch,
+          note_duration(note).to_string(),
+          note_voice(note).to_string(),
+          note_staff(note).to_string(),
+        )
+      })
+      .collect()
+  }
+
+  /// Returns the first attributes of the reduced part.
+  fn reduced_attributes(score: &ScorePartwise) -> &AttributesContents {
+    let mut attributes = None;
+    for_each(score.content.part.last().unwrap(), |element: &Attributes, _| {
+      attributes.get_or_insert(&element.content);
+    });
+    attributes.unwrap()
+  }
+
+  fn entry(
+    chord: bool,
+    pitch: &str,
+    duration: &str,
+    voice: &str,
+    staff: &str,
+  ) -> (bool, String, String, String, String) {
+    (
+      chord,
+      pitch.to_string(),
+      duration.to_string(),
+      voice.to_string(),
+      staff.to_string(),
+    )
+  }
+
+  /// Returns the key signatures of the reduced part.
+  fn reduced_fifths(score: &ScorePartwise) -> Vec<i8> {
+    let mut fifths = Vec::new();
+    for_each(
+      score.content.part.last().unwrap(),
+      |element: &crate::elements::Fifths, _| {
+        fifths.push(element.content.0);
+      },
+    );
+    fifths
+  }
+
+  #[test]
+  fn reduce_satb_to_grand_staff() {
+    let satb = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list>
+          <score-part id="S"><part-name>Soprano</part-name></score-part>
+          <score-part id="A"><part-name>Alto</part-name></score-part>
+          <score-part id="T"><part-name>Tenor</part-name></score-part>
+          <score-part id="B"><part-name>Bass</part-name></score-part>
+        </part-list>
+        <part id="S">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>E</step><octave>5</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+        <part id="A">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>C</step><octave>5</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+        <part id="T">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>G</step><octave>3</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+        <part id="B">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>C</step><octave>3</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let reduced = reduce_parts(&satb, &["S", "A", "T", "B"], "Piano").unwrap();
+    assert_eq!(reduced.content.part.len(), 5);
+    assert_eq!(*reduced.content.part[4].attributes.id, "P5");
+    let Some(PartListElement::ScorePart(score_part)) = reduced.content.part_list.content.content.last() else {
+      panic!("Expected a score part");
+    };
+    assert_eq!(*score_part.attributes.id, "P5");
+    assert_eq!(score_part.content.part_name.content, "Piano");
+    let attributes = reduced_attributes(&reduced);
+    assert_eq!(*attributes.staves.as_ref().unwrap().content, 2);
+    let clefs: Vec<_> = attributes
+      .clef
+      .iter()
+      .map(|clef| clef.content.sign.content.clone())
+      .collect();
+    assert_eq!(clefs, vec![ClefSign::G, ClefSign::F]);
+    assert_eq!(
+      reduced_notes(&reduced),
+      vec![
+        entry(false, "C5", "4", "1", "1"),
+        entry(true, "E5", "4", "1", "1"),
+        entry(false, "C3", "4", "5", "2"),
+        entry(true, "G3", "4", "5", "2"),
+      ]
+    );
+  }
+
+  #[test]
+  fn reduce_keeps_distinct_rhythms_in_voices() {
+    let quartet = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list>
+          <score-part id="V1"><part-name>Violin I</part-name></score-part>
+          <score-part id="V2"><part-name>Violin II</part-name></score-part>
+          <score-part id="C"><part-name>Cello</part-name></score-part>
+        </part-list>
+        <part id="V1">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>G</step><octave>4</octave></pitch><duration>4</duration></note>
+            <note><pitch><step>A</step><octave>4</octave></pitch><duration>4</duration></note>
+          </measure>
+        </part>
+        <part id="V2">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>E</step><octave>4</octave></pitch><duration>2</duration></note>
+            <note><pitch><step>F</step><octave>4</octave></pitch><duration>2</duration></note>
+            <note><pitch><step>G</step><octave>4</octave></pitch><duration>4</duration></note>
+          </measure>
+        </part>
+        <part id="C">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>G</step><octave>4</octave></pitch><duration>4</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let reduced = reduce_parts(&quartet, &["V1", "V2", "C"], "Piano").unwrap();
+    assert_eq!(
+      reduced_notes(&reduced),
+      vec![
+        entry(false, "G4", "2", "1", "1"),
+        entry(false, "G4", "2", "1", "1"),
+        entry(true, "A4", "2", "1", "1"),
+        entry(false, "E4", "1", "2", "1"),
+        entry(false, "F4", "1", "2", "1"),
+        entry(false, "R", "4", "5", "2"),
+      ]
+    );
+  }
+
+  #[test]
+  fn reduce_concert_pitch() {
+    let clarinet = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="Cl"><part-name>Clarinet in B-flat</part-name></score-part></part-list>
+        <part id="Cl">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>2</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              <transpose><diatonic>-1</diatonic><chromatic>-2</chromatic></transpose>
+            </attributes>
+            <note><pitch><step>D</step><octave>5</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let reduced = reduce_parts(&clarinet, &["Cl"], "Piano").unwrap();
+    assert_eq!(reduced_notes(&reduced)[0], entry(false, "C5", "4", "1", "1"));
+    assert_eq!(reduced_fifths(&reduced), vec![0]);
+    let mut transposes = 0;
+    for_each(reduced.content.part.last().unwrap(), |_: &Transpose, _| transposes += 1);
+    assert_eq!(transposes, 0);
+
+    let horn = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="Hn"><part-name>Horn in F</part-name></score-part></part-list>
+        <part id="Hn">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+              <transpose><diatonic>-4</diatonic><chromatic>-7</chromatic><octave-change>-1</octave-change></transpose>
+            </attributes>
+            <note><pitch><step>C</step><octave>5</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let reduced = reduce_parts(&horn, &["Hn"], "Piano").unwrap();
+    assert_eq!(reduced_notes(&reduced)[1], entry(false, "F3", "4", "5", "2"));
+    assert_eq!(reduced_fifths(&reduced), vec![-1]);
+  }
+
+  #[test]
+  fn reduce_fills_gaps_with_rests() {
+    let piece = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Flute</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes>
+              <divisions>3</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <forward><duration>3</duration></forward>
+            <note>
+              <pitch><step>C</step><octave>4</octave></pitch><duration>1</duration><type>eighth</type>
+              <time-modification><actual-notes>3</actual-notes><normal-notes>2</normal-notes></time-modification>
+            </note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    let reduced = reduce_parts(&piece, &["P1"], "Piano").unwrap();
+    let divisions = reduced_attributes(&reduced).divisions.as_ref().unwrap();
+    assert_eq!(*divisions.content, 3);
+    let mut modifications = 0;
+    for_each(reduced.content.part.last().unwrap(), |_: &TimeModification, _| {
+      modifications += 1;
+    });
+    assert_eq!(modifications, 1);
+    assert_eq!(
+      reduced_notes(&reduced),
+      vec![
+        entry(false, "R", "3", "1", "1"),
+        entry(false, "C4", "1", "1", "1"),
+        entry(false, "R", "8", "1", "1"),
+        entry(false, "R", "12", "5", "2"),
+      ]
+    );
+    let mut rests = Vec::new();
+    for_each(reduced.content.part.last().unwrap(), |note: &Note, _| {
+      rests.push(note.content.r#type.as_ref().map(|note_type| note_type.content.clone()));
+    });
+    assert_eq!(
+      rests,
+      vec![Some(NoteTypeValue::Quarter), Some(NoteTypeValue::Eighth), None, None]
+    );
+  }
+
+  #[test]
+  fn reduce_invalid_parts() {
+    let piece = parse_from_xml_str::<ScorePartwise>(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Flute</part-name></score-part></part-list>
+        <part id="P1">
+          <measure number="1">
+            <attributes>
+              <divisions>2</divisions><key><fifths>0</fifths></key><time><beats>4</beats><beat-type>4</beat-type></time>
+            </attributes>
+            <note><pitch><step>C</step><octave>4</octave></pitch><duration>8</duration></note>
+          </measure>
+        </part>
+      </score-partwise>"#,
+    )
+    .unwrap();
+    assert_eq!(
+      reduce_parts(&piece, &[], "Piano").unwrap_err(),
+      "At least one part must be selected for reduction"
+    );
+    assert_eq!(
+      reduce_parts(&piece, &["P1", "P2"], "Piano").unwrap_err(),
+      "Part \"P2\" does not exist in the score"
+    );
+  }
+}
@@ -0,0 +1,589 @@
+use crate::datatypes::{NumberLevel, StartStop, StartStopContinue};
+use crate::elements::{
+  Attributes, AttributesContents, AudibleType, Clef, Key, Measure, MeasureElement, NotationContentTypes, Notations,
+  NotationsAttributes, NotationsContents, Note, Part, PartElement, ScorePartwise, Slur, SlurAttributes, Tie,
+  TieAttributes, Tied, TiedAttributes, Time, Transpose,
+};
+use crate::util::{note_ties, note_ties_mut, note_voice, part_measures, part_measures_mut, step_letter};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+/// An attribute that can apply either to a single staff or to every staff in a part.
+pub(super) trait StaffAttribute: Clone + PartialEq {
+  /// Returns the staff that the attribute applies to, where `None` applies to all staves.
+  fn staff(&self) -> Option<u8>;
+}
+
+impl StaffAttribute for Key {
+  fn staff(&self) -> Option<u8> {
+    self.attributes.number.as_ref().map(|number| number.0)
+  }
+}
+
+impl StaffAttribute for Time {
+  fn staff(&self) -> Option<u8> {
+    self.attributes.number.as_ref().map(|number| number.0)
+  }
+}
+
+impl StaffAttribute for Clef {
+  fn staff(&self) -> Option<u8> {
+    Some(self.attributes.number.as_ref().map_or(1, |number| number.0))
+  }
+}
+
+impl StaffAttribute for Transpose {
+  fn staff(&self) -> Option<u8> {
+    self.attributes.number.as_ref().map(|number| number.0)
+  }
+}
+
+/// Replaces any carried attributes for the same staves as each of the `changes`.
+fn carry<T: StaffAttribute>(carried: &mut Vec<T>, changes: &[T]) {
+  for change in changes {
+    let staff = change.staff();
+    carried.retain(|attribute| staff.is_some() && attribute.staff() != staff);
+    carried.push(change.clone());
+  }
+}
+
+/// Adds each carried attribute unless `existing` already sets one for the same staff.
+fn merge<T: StaffAttribute>(existing: &mut Vec<T>, carried: &[T]) {
+  for attribute in carried {
+    let staff = attribute.staff();
+    if !existing
+      .iter()
+      .map(StaffAttribute::staff)
+      .any(|other| other.is_none() || other == staff)
+    {
+      existing.push(attribute.clone());
+    }
+  }
+}
+
+/// Returns the attributes in `carried` that are not also present in `other`.
+fn missing<T: StaffAttribute>(carried: &[T], other: &[T]) -> Vec<T> {
+  carried
+    .iter()
+    .filter(|attribute| !other.contains(attribute))
+    .cloned()
+    .collect()
+}
+
+/// The step, alteration, and octave identifying a pitch, where unpitched notes have no alteration.
+type PitchKey = (char, Option<i16>, u8);
+
+/// Returns the key identifying the pitch of a note, or `None` for rests.
+fn pitch_key(note: &Note) -> Option<PitchKey> {
+  match crate::util::note_audible(note) {
+    AudibleType::Pitch(pitch) => Some((
+      step_letter(&pitch.content.step.content),
+      Some(pitch.content.alter.as_ref().map_or(0, |alter| alter.content.0)),
+      pitch.content.octave.content.0,
+    )),
+    AudibleType::Unpitched(unpitched) => Some((
+      step_letter(&unpitched.content.display_step.content),
+      None,
+      unpitched.content.display_octave.content.0,
+    )),
+    AudibleType::Rest(_) => None,
+  }
+}
+
+/// Returns every notation attached to a note.
+fn note_notations(note: &Note) -> impl Iterator<Item = &NotationContentTypes> {
+  note
+    .content
+    .notations
+    .iter()
+    .flat_map(|notations| notations.content.notations.iter())
+}
+
+/// Returns the first [Notations] element of a note, adding one if it does not exist.
+fn notations_mut(note: &mut Note) -> &mut Vec<NotationContentTypes> {
+  if note.content.notations.is_empty() {
+    note.content.notations.push(Notations {
+      attributes: NotationsAttributes::default(),
+      content: NotationsContents::default(),
+    });
+  }
+  &mut note.content.notations[0].content.notations
+}
+
+/// Returns the number of a slur, which defaults to 1.
+fn slur_number(slur: &Slur) -> u8 {
+  slur.attributes.number.as_ref().map_or(1, |number| number.0)
+}
+
+/// Returns a `<tied>` notation marking the end of a tie.
+fn tied_stop() -> Tied {
+  Tied {
+    attributes: TiedAttributes {
+      r#type: StartStopContinue::Stop,
+      bezier_offset: None,
+      bezier_offset2: None,
+      bezier_x: None,
+      bezier_x2: None,
+      bezier_y: None,
+      bezier_y2: None,
+      color: None,
+      dash_length: None,
+      default_x: None,
+      default_y: None,
+      id: None,
+      line_type: None,
+      number: None,
+      orientation: None,
+      placement: None,
+      relative_x: None,
+      relative_y: None,
+      space_length: None,
+    },
+    content: (),
+  }
+}
+
+/// Returns a `<slur>` notation marking the start of the slur with the specified number.
+fn slur_start(number: u8) -> Slur {
+  Slur {
+    attributes: SlurAttributes {
+      r#type: StartStopContinue::Start,
+      bezier_offset: None,
+      bezier_offset2: None,
+      bezier_x: None,
+      bezier_x2: None,
+      bezier_y: None,
+      bezier_y2: None,
+      color: None,
+      dash_length: None,
+      default_x: None,
+      default_y: None,
+      id: None,
+      line_type: None,
+      number: Some(NumberLevel(number)),
+      orientation: None,
+      placement: None,
+      relative_x: None,
+      relative_y: None,
+      space_length: None,
+    },
+    content: (),
+  }
+}
+
+/// Attributes, ties, and slurs in effect at a particular point in a part.
+///
+/// Only the attributes that remain in effect until they are changed are carried: the divisions, key and time
+/// signatures, number of staves, clefs, and transposition.
+#[derive(Clone, Default)]
+pub(super) struct CarriedState {
+  pub(super) attributes: AttributesContents,
+  ties: BTreeSet<(String, PitchKey)>,
+  slurs: BTreeMap<u8, String>,
+}
+
+impl CarriedState {
+  /// Updates the state with the contents of a measure.
+  pub(super) fn update(&mut self, measure: &Measure) {
+    for element in &measure.content {
+      match element {
+        MeasureElement::Attributes(attributes) => self.update_attributes(attributes),
+        MeasureElement::Note(note) => self.update_note(note),
+        _ => {}
+      }
+    }
+  }
+
+  /// Updates the carried attributes with the contents of an [Attributes] element.
+  pub(super) fn update_attributes(&mut self, attributes: &Attributes) {
+    let changes = &attributes.content;
+    let carried = &mut self.attributes;
+    if changes.divisions.is_some() {
+      carried.divisions.clone_from(&changes.divisions);
+    }
+    if changes.staves.is_some() {
+      carried.staves.clone_from(&changes.staves);
+    }
+    carry(&mut carried.key, &changes.key);
+    carry(&mut carried.time, &changes.time);
+    carry(&mut carried.clef, &changes.clef);
+    carry(&mut carried.transpose, &changes.transpose);
+  }
+
+  /// Updates the open ties and slurs with the contents of a note.
+  fn update_note(&mut self, note: &Note) {
+    let voice = note_voice(note).to_string();
+    if let Some(pitch) = pitch_key(note) {
+      let ties = note_ties(note)
+        .iter()
+        .map(|tie| match tie.attributes.r#type {
+          StartStop::Start => StartStopContinue::Start,
+          StartStop::Stop => StartStopContinue::Stop,
+        })
+        .chain(note_notations(note).filter_map(|notation| match notation {
+          NotationContentTypes::Tied(tied) => Some(tied.attributes.r#type.clone()),
+          _ => None,
+        }));
+      for tie in ties {
+        match tie {
+          StartStopContinue::Start => _ = self.ties.insert((voice.clone(), pitch)),
+          StartStopContinue::Stop => _ = self.ties.remove(&(voice.clone(), pitch)),
+          StartStopContinue::Continue => {}
+        }
+      }
+    }
+    for notation in note_notations(note) {
+      if let NotationContentTypes::Slur(slur) = notation {
+        match slur.attributes.r#type {
+          StartStopContinue::Start => _ = self.slurs.insert(slur_number(slur), voice.clone()),
+          StartStopContinue::Stop => _ = self.slurs.remove(&slur_number(slur)),
+          StartStopContinue::Continue => {}
+        }
+      }
+    }
+  }
+
+  /// Returns the carried attributes that are not also present in another state, without any ties or slurs.
+  pub(super) fn difference(&self, other: &CarriedState) -> CarriedState {
+    let (carried, other) = (&self.attributes, &other.attributes);
+    CarriedState {
+      attributes: AttributesContents {
+        divisions: carried
+          .divisions
+          .clone()
+          .filter(|_| carried.divisions != other.divisions),
+        key: missing(&carried.key, &other.key),
+        time: missing(&carried.time, &other.time),
+        staves: carried.staves.clone().filter(|_| carried.staves != other.staves),
+        clef: missing(&carried.clef, &other.clef),
+        transpose: missing(&carried.transpose, &other.transpose),
+        ..AttributesContents::default()
+      },
+      ..CarriedState::default()
+    }
+  }
+
+  /// Merges the carried attributes into the [Attributes] element at the start of a measure, keeping any values
+  /// already set there.
+  pub(super) fn apply_attributes(&self, measure: &mut Measure) {
+    if self.attributes == AttributesContents::default() {
+      return;
+    }
+    let position = measure
+      .content
+      .iter()
+      .position(|element| {
+        !matches!(
+          element,
+          MeasureElement::Print(_)
+            | MeasureElement::Barline(_)
+            | MeasureElement::Direction(_)
+            | MeasureElement::Harmony(_)
+        )
+      })
+      .unwrap_or(measure.content.len());
+    if !matches!(measure.content.get(position), Some(MeasureElement::Attributes(_))) {
+      measure.content.insert(
+        position,
+        MeasureElement::Attributes(Attributes {
+          attributes: (),
+          content: AttributesContents::default(),
+        }),
+      );
+    }
+    let MeasureElement::Attributes(attributes) = &mut measure.content[position] else {
+      return;
+    };
+    let existing = &mut attributes.content;
+    if existing.divisions.is_none() {
+      existing.divisions.clone_from(&self.attributes.divisions);
+    }
+    if existing.staves.is_none() {
+      existing.staves.clone_from(&self.attributes.staves);
+    }
+    merge(&mut existing.key, &self.attributes.key);
+    merge(&mut existing.time, &self.attributes.time);
+    merge(&mut existing.clef, &self.attributes.clef);
+    merge(&mut existing.transpose, &self.attributes.transpose);
+  }
+
+  /// Marks the first note of each open tie within the excerpt as the end of a tie.
+  fn apply_ties(&self, measure: &mut Measure) {
+    for (voice, pitch) in &self.ties {
+      let Some(note) = measure.content.iter_mut().find_map(|element| match element {
+        MeasureElement::Note(note) if note_voice(note) == voice && pitch_key(note) == Some(*pitch) => Some(note),
+        _ => None,
+      }) else {
+        continue;
+      };
+      if let Some(ties) = note_ties_mut(note) {
+        if !ties.iter().any(|tie| tie.attributes.r#type == StartStop::Stop) {
+          ties.push(Tie {
+            attributes: TieAttributes {
+              r#type: StartStop::Stop,
+              time_only: None,
+            },
+            content: (),
+          });
+        }
+      }
+      let notations = notations_mut(note);
+      let is_stop = |notation: &NotationContentTypes| match notation {
+        NotationContentTypes::Tied(tied) => tied.attributes.r#type == StartStopContinue::Stop,
+        _ => false,
+      };
+      if !notations.iter().any(is_stop) {
+        notations.push(NotationContentTypes::Tied(tied_stop()));
+      }
+    }
+  }
+
+  /// Starts each open slur on the first pitched note of its voice within the excerpt, or removes the end of the slur
+  /// if it ends on that note.
+  fn apply_slurs(&self, measures: &mut [&mut Measure]) {
+    for (&number, voice) in &self.slurs {
+      let Some(note) = measures
+        .iter_mut()
+        .flat_map(|measure| measure.content.iter_mut())
+        .find_map(|element| match element {
+          MeasureElement::Note(note) if note_voice(note) == voice && pitch_key(note).is_some() => Some(note),
+          _ => None,
+        })
+      else {
+        continue;
+      };
+      let is_stop = |notation: &NotationContentTypes| {
+        matches!(notation, NotationContentTypes::Slur(slur)
+          if slur_number(slur) == number && slur.attributes.r#type == StartStopContinue::Stop)
+      };
+      if note_notations(note).any(is_stop) {
+        for notations in &mut note.content.notations {
+          notations.content.notations.retain(|notation| !is_stop(notation));
+        }
+        note
+          .content
+          .notations
+          .retain(|notations| notations.content != NotationsContents::default());
+      } else {
+        notations_mut(note).push(NotationContentTypes::Slur(slur_start(number)));
+      }
+    }
+  }
+}
+
+/// Resolves a range of zero-based measure indices within a part.
+///
+/// # Errors
+///
+/// If the range is empty or extends past the last measure of the part, an error message will be returned.
+pub(super) fn measure_range(range: &impl RangeBounds<usize>, part: &Part) -> Result<(usize, usize), String> {
+  let count = part_measures(part).count();
+  let start = match range.start_bound() {
+    Bound::Included(&start) => start,
+    Bound::Excluded(&start) => start.saturating_add(1),
+    Bound::Unbounded => 0,
+  };
+  let end = match range.end_bound() {
+    Bound::Included(&end) => end.saturating_add(1),
+    Bound::Excluded(&end) => end,
+    Bound::Unbounded => count,
+  };
+  if start >= end {
+    Err(format!("Measure range {start}..{end} is empty"))
+  } else if end > count {
+    Err(format!(
+      "Measure range {start}..{end} is out of bounds for part \"{}\" with {count} measures",
+      part.attributes.id.as_str()
+    ))
+  } else {
+    Ok((start, end))
+  }
+}
+
+/// Cuts an excerpt containing a range of measures from a score.
+///
+/// The `range` is specified using zero-based measure indices rather than the measure numbers printed in the score,
+/// and is applied to every part. The first measure of each part in the excerpt is updated so that the excerpt can
+/// stand on its own:
+///
+/// - The [Attributes][crate::elements::Attributes] in effect at the start of the excerpt, including the divisions,
+///   key and time signatures, number of staves, clefs, and transposition, are merged into the first measure without
+///   overriding any values that the measure already sets
+/// - Notes that continue a tie from before the excerpt are marked as the end of a tie
+/// - Slurs that are still open at the start of the excerpt begin on the first pitched note in their voice instead
+///
+/// Measure numbers are left unchanged, and ties or slurs that continue past the end of the excerpt are kept as
+/// written.
+///
+/// # Errors
+///
+/// If the range is empty or extends past the last measure of any part, an error message will be returned.
+pub fn slice_measures(score: &ScorePartwise, range: impl RangeBounds<usize>) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  for part in &mut score.content.part {
+    let (start, end) = measure_range(&range, part)?;
+
+    // Collect the state in effect at the start of the excerpt before removing the measures around it
+    let mut state = CarriedState::default();
+    for measure in part_measures(part).take(start) {
+      state.update(measure);
+    }
+    let mut index = 0;
+    part.content.retain(|element| {
+      if !matches!(element, PartElement::Measure(_)) {
+        return true;
+      }
+      index += 1;
+      (start..end).contains(&(index - 1))
+    });
+
+    let mut measures: Vec<&mut Measure> = part_measures_mut(part).collect();
+    state.apply_attributes(measures[0]);
+    state.apply_ties(measures[0]);
+    state.apply_slurs(&mut measures);
+  }
+  Ok(score)
+}
+
+#[cfg(test)]
+mod slice_tests {
+  use super::*;
+  use crate::elements::KeyContents;
+  use crate::parser::parse_from_xml_str;
+  use crate::visit::for_each;
+  use musicxml_internal::{DatatypeSerializer, ElementSerializer};
+
+  const SCORE: &str = r#"<score-partwise version="4.0">
+    <part-list><score-part id="P1"><part-name>Piano</part-name></score-part></part-list>
+    <part id="P1">
+      <measure number="1">
+        <attributes>
+          <divisions>2</divisions>
+          <key><fifths>2</fifths></key>
+          <time><beats>3</beats><beat-type>4</beat-type></time>
+          <staves>2</staves>
+          <clef number="1"><sign>G</sign><line>2</line></clef>
+          <clef number="2"><sign>F</sign><line>4</line></clef>
+        </attributes>
+        <note><pitch><step>D</step><octave>5</octave></pitch><duration>6</duration><voice>1</voice><staff>1</staff>
+          <notations><slur type="start" number="1"/></notations></note>
+        <backup><duration>6</duration></backup>
+        <note><pitch><step>D</step><octave>3</octave></pitch><duration>6</duration><voice>2</voice><staff>2</staff></note>
+      </measure>
+      <measure number="2">
+        <attributes><clef number="2"><sign>G</sign><line>2</line></clef></attributes>
+        <note><pitch><step>E</step><octave>5</octave></pitch><duration>6</duration><tie type="start"/><voice>1</voice>
+          <staff>1</staff><notations><tied type="start"/></notations></note>
+        <backup><duration>6</duration></backup>
+        <note><rest/><duration>6</duration><voice>2</voice><staff>2</staff></note>
+      </measure>
+      <measure number="3">
+        <note><pitch><step>E</step><octave>5</octave></pitch><duration>6</duration><voice>1</voice><staff>1</staff></note>
+        <backup><duration>6</duration></backup>
+        <note><rest/><duration>6</duration><voice>2</voice><staff>2</staff></note>
+      </measure>
+      <measure number="4">
+        <attributes><key><fifths>-1</fifths></key></attributes>
+        <note><pitch><step>F</step><octave>5</octave></pitch><duration>6</duration><voice>1</voice><staff>1</staff>
+          <notations><slur type="stop" number="1"/></notations></note>
+      </measure>
+    </part>
+  </score-partwise>"#;
+
+  fn key_fifths(attributes: &Attributes) -> i8 {
+    match &attributes.content.key[0].content {
+      KeyContents::Explicit(key) => key.fifths.content.0,
+      KeyContents::Relative(_) => panic!("Expected an explicit key"),
+    }
+  }
+
+  #[test]
+  fn slice_carries_attributes() {
+    let excerpt = slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 2..4).unwrap();
+    let measures: Vec<_> = part_measures(&excerpt.content.part[0]).collect();
+    assert_eq!(measures.len(), 2);
+    assert_eq!(*measures[0].attributes.number, "3");
+    let mut attributes = Vec::new();
+    for_each(&excerpt, |element: &Attributes, context| {
+      attributes.push((context.measure_number.clone().unwrap(), element));
+    });
+    assert_eq!(attributes.len(), 2);
+    let (number, first) = &attributes[0];
+    assert_eq!(number, "3");
+    assert_eq!(*first.content.divisions.as_ref().unwrap().content, 2);
+    assert_eq!(first.content.key.len(), 1);
+    assert_eq!(first.content.time.len(), 1);
+    assert_eq!(*first.content.staves.as_ref().unwrap().content, 2);
+    let signs: Vec<String> = first
+      .content
+      .clef
+      .iter()
+      .map(|clef| DatatypeSerializer::serialize(&clef.content.sign.content))
+      .collect();
+    assert_eq!(signs, vec!["G", "G"]);
+    assert_eq!(attributes[1].1.content.key.len(), 1);
+    assert!(attributes[1].1.content.divisions.is_none());
+  }
+
+  #[test]
+  fn slice_keeps_existing_attributes() {
+    let excerpt = slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 3..).unwrap();
+    let mut keys = Vec::new();
+    for_each(&excerpt, |attributes: &Attributes, _| {
+      keys.push(key_fifths(attributes));
+    });
+    assert_eq!(keys, vec![-1]);
+  }
+
+  #[test]
+  fn slice_carries_ties_and_slurs() {
+    let excerpt = slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 2..=2).unwrap();
+    let mut ties = Vec::new();
+    for_each(&excerpt, |tie: &Tie, _| {
+      ties.push(DatatypeSerializer::serialize(&tie.attributes.r#type))
+    });
+    assert_eq!(ties, vec!["stop"]);
+    let mut tied = Vec::new();
+    for_each(&excerpt, |element: &Tied, _| {
+      tied.push(DatatypeSerializer::serialize(&element.attributes.r#type))
+    });
+    assert_eq!(tied, vec!["stop"]);
+    let mut slurs = Vec::new();
+    for_each(&excerpt, |slur: &Slur, _| {
+      slurs.push(DatatypeSerializer::serialize(&slur.attributes.r#type))
+    });
+    assert_eq!(slurs, vec!["start"]);
+
+    // A slur that ends on the first note of the excerpt is removed entirely
+    let excerpt = slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 3..4).unwrap();
+    let mut slurs = 0;
+    for_each(&excerpt, |_: &Slur, _| slurs += 1);
+    assert_eq!(slurs, 0);
+    let mut notations = 0;
+    for_each(&excerpt, |note: &Note, _| notations += note.content.notations.len());
+    assert_eq!(notations, 0);
+  }
+
+  #[test]
+  fn slice_whole_score() {
+    let excerpt = slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), ..).unwrap();
+    assert_eq!(
+      ScorePartwise::serialize(&excerpt),
+      ScorePartwise::serialize(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap())
+    );
+  }
+
+  #[test]
+  fn slice_invalid_range() {
+    assert_eq!(
+      slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 2..2).unwrap_err(),
+      "Measure range 2..2 is empty"
+    );
+    assert_eq!(
+      slice_measures(&parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(), 3..6).unwrap_err(),
+      "Measure range 3..6 is out of bounds for part \"P1\" with 4 measures"
+    );
+  }
+}
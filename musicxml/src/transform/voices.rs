@@ -0,0 +1,587 @@
+use crate::datatypes::{PositiveDivisions, PositiveInteger};
+use crate::elements::{
+  AudibleType, Backup, BackupContents, Duration, Forward, ForwardContents, Measure, MeasureAttributes, MeasureElement,
+  NormalInfo, Note, NoteAttributes, NoteContents, NoteType, Part, Rest, RestAttributes, RestContents, ScorePartwise,
+  Staff, Voice,
+};
+use crate::util::{note_duration, note_is_chord, note_voice, part_measures, part_measures_mut};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A single voice separated from a [Measure].
+#[derive(Debug, PartialEq, Eq)]
+pub struct VoiceSequence {
+  /// The [Voice][crate::elements::Voice] number of every element in the sequence.
+  pub voice: String,
+  /// The [Notes][crate::elements::Note] and [Directions][crate::elements::Direction] belonging to the voice, in
+  /// order.
+  ///
+  /// Each sequence starts at the beginning of the measure and runs continuously until its end, so the position of any
+  /// element is the total duration of the non-chord notes before it.
+  pub elements: Vec<MeasureElement>,
+}
+
+/// The contents of a [Measure] separated into individual voices.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MeasureVoices {
+  /// The attributes of the original measure.
+  pub attributes: MeasureAttributes,
+  /// Elements that do not belong to any voice, such as [Attributes][crate::elements::Attributes] and
+  /// [Barlines][crate::elements::Barline], along with their positions in divisions from the start of the measure.
+  pub shared: Vec<(u32, MeasureElement)>,
+  /// The voices contained in the measure, in order of their first appearance.
+  pub voices: Vec<VoiceSequence>,
+}
+
+/// Returns the voice of a note, direction, or forward element, which defaults to `"1"` for notes.
+fn element_voice(element: &MeasureElement) -> Option<&str> {
+  match element {
+    MeasureElement::Note(note) => Some(note_voice(note)),
+    MeasureElement::Direction(direction) => direction.content.voice.as_ref().map(|voice| voice.content.trim()),
+    MeasureElement::Forward(forward) => forward.content.voice.as_ref().map(|voice| voice.content.trim()),
+    _ => None,
+  }
+}
+
+/// Returns the staff of a note, direction, or forward element, if specified.
+fn element_staff(element: &MeasureElement) -> Option<u32> {
+  match element {
+    MeasureElement::Note(note) => note.content.staff.as_ref(),
+    MeasureElement::Direction(direction) => direction.content.staff.as_ref(),
+    MeasureElement::Forward(forward) => forward.content.staff.as_ref(),
+    _ => None,
+  }
+  .map(|staff| *staff.content)
+}
+
+/// Returns how far an element advances the current position within a measure.
+fn element_advance(element: &MeasureElement) -> u32 {
+  match element {
+    MeasureElement::Note(note) if !note_is_chord(note) => note_duration(note),
+    MeasureElement::Forward(forward) => *forward.content.duration.content,
+    _ => 0,
+  }
+}
+
+/// Sets the staff of a note, direction, or forward element.
+fn set_staff(element: &mut MeasureElement, staff: u32) {
+  let staff = Some(Staff {
+    attributes: (),
+    content: PositiveInteger(staff),
+  });
+  match element {
+    MeasureElement::Note(note) => note.content.staff = staff,
+    MeasureElement::Direction(direction) => direction.content.staff = staff,
+    MeasureElement::Forward(forward) => forward.content.staff = staff,
+    _ => {}
+  }
+}
+
+/// Sets the voice of a note, direction, or forward element.
+fn set_voice(element: &mut MeasureElement, voice: &str) {
+  let voice = Some(Voice {
+    attributes: (),
+    content: voice.to_string(),
+  });
+  match element {
+    MeasureElement::Note(note) => note.content.voice = voice,
+    MeasureElement::Direction(direction) => direction.content.voice = voice,
+    MeasureElement::Forward(forward) => forward.content.voice = voice,
+    _ => {}
+  }
+}
+
+/// Returns a duration element lasting the specified number of divisions.
+fn duration(divisions: u32) -> Duration {
+  Duration {
+    attributes: (),
+    content: PositiveDivisions(divisions),
+  }
+}
+
+/// Creates a rest filling a gap in a voice.
+fn gap_rest(divisions: u32, voice: &str, staff: Option<u32>) -> MeasureElement {
+  let mut rest = MeasureElement::Note(Note {
+    attributes: NoteAttributes::default(),
+    content: NoteContents {
+      info: NoteType::Normal(NormalInfo {
+        chord: None,
+        audible: AudibleType::Rest(Rest {
+          attributes: RestAttributes::default(),
+          content: RestContents {
+            display_step: None,
+            display_octave: None,
+          },
+        }),
+        duration: duration(divisions),
+        tie: Vec::new(),
+      }),
+      instrument: Vec::new(),
+      footnote: None,
+      level: None,
+      voice: None,
+      r#type: None,
+      dot: Vec::new(),
+      accidental: None,
+      time_modification: None,
+      stem: None,
+      notehead: None,
+      notehead_text: None,
+      staff: None,
+      beam: Vec::new(),
+      notations: Vec::new(),
+      lyric: Vec::new(),
+      play: None,
+      listen: None,
+    },
+  });
+  set_voice(&mut rest, voice);
+  if let Some(staff) = staff {
+    set_staff(&mut rest, staff);
+  }
+  rest
+}
+
+/// A voice being collected from a measure.
+struct VoiceBuilder {
+  voice: String,
+  elements: Vec<MeasureElement>,
+  end: u32,
+  staff: Option<u32>,
+}
+
+impl VoiceBuilder {
+  /// Fills any gap between the end of the voice and `position` with a rest.
+  fn fill_to(&mut self, position: u32, staff: Option<u32>) {
+    if position > self.end {
+      let staff = staff.or(self.staff);
+      self.elements.push(gap_rest(position - self.end, &self.voice, staff));
+      self.end = position;
+    }
+  }
+}
+
+/// Separates a [Measure] into its individual voices.
+///
+/// Every [Note][crate::elements::Note] is assigned to the voice given by its [Voice][crate::elements::Voice] element,
+/// or voice `"1"` if it has none, and each [Direction][crate::elements::Direction] with a voice is assigned to that
+/// voice when it falls at or after the last note of the voice. [Backup][crate::elements::Backup] and
+/// [Forward][crate::elements::Forward] elements are removed, and any gaps before, between, or after the notes of each
+/// voice are filled with explicit rests, so that every voice spans the full length of the measure. All other elements
+/// are kept as shared elements, along with their positions within the measure.
+///
+/// Voices are assumed not to overlap themselves, as required by MusicXML. Use [merge_voices] to turn the separated
+/// voices back into a measure.
+///
+/// # Errors
+///
+/// This function does not currently return an error, and returns a [Result] for consistency with [merge_voices].
+pub fn split_voices(measure: &Measure) -> Result<MeasureVoices, String> {
+  let mut voices: Vec<VoiceBuilder> = Vec::new();
+  let mut shared = Vec::new();
+  let (mut position, mut length) = (0_u32, 0_u32);
+  for element in &measure.content {
+    let voice =
+      element_voice(element).filter(|_| matches!(element, MeasureElement::Note(_) | MeasureElement::Direction(_)));
+    match (element, voice) {
+      (MeasureElement::Backup(backup), _) => position = position.saturating_sub(*backup.content.duration.content),
+      (MeasureElement::Forward(forward), _) => position += *forward.content.duration.content,
+      (_, Some(voice)) => {
+        let index = if let Some(index) = voices.iter().position(|builder| builder.voice == voice) {
+          index
+        } else {
+          voices.push(VoiceBuilder {
+            voice: voice.to_string(),
+            elements: Vec::new(),
+            end: 0,
+            staff: None,
+          });
+          voices.len() - 1
+        };
+        let builder = &mut voices[index];
+        let staff = element_staff(element);
+        let is_direction = matches!(element, MeasureElement::Direction(_));
+        if is_direction && position < builder.end {
+          shared.push((position, element.clone()));
+          continue;
+        }
+        if !matches!(element, MeasureElement::Note(note) if note_is_chord(note)) {
+          builder.fill_to(position, staff);
+        }
+        if staff.is_some() {
+          builder.staff = staff;
+        }
+        position += element_advance(element);
+        builder.end = builder.end.max(position);
+        builder.elements.push(element.clone());
+      }
+      _ => shared.push((position, element.clone())),
+    }
+    length = length.max(position);
+  }
+
+  let voices = voices
+    .into_iter()
+    .map(|mut builder| {
+      builder.fill_to(length, None);
+      VoiceSequence {
+        voice: builder.voice,
+        elements: builder.elements,
+      }
+    })
+    .collect();
+  Ok(MeasureVoices {
+    attributes: measure.attributes.clone(),
+    shared,
+    voices,
+  })
+}
+
+/// Combines separated voices back into a single [Measure].
+///
+/// The voices are written one after another, each preceded by a [Backup][crate::elements::Backup] to the start of
+/// the measure. Shared elements are written before the first voice element starting at the same position, or after
+/// the last voice if they fall at its end. Any remaining shared elements, which fall partway through a note, are
+/// written at the end of the measure using the minimal [Backup][crate::elements::Backup] or
+/// [Forward][crate::elements::Forward] needed to reach their positions.
+/// Every element in a voice is assigned the voice number of its sequence.
+///
+/// # Errors
+///
+/// This function does not currently return an error, and returns a [Result] for consistency with [split_voices].
+pub fn merge_voices(voices: &MeasureVoices) -> Result<Measure, String> {
+  let mut content = Vec::new();
+  let mut shared = voices.shared.clone();
+  shared.sort_by_key(|(position, _)| *position);
+
+  // Shared elements are written between the elements of each voice wherever they line up with them
+  let mut position = 0_u32;
+  let mut write_shared = |content: &mut Vec<MeasureElement>, position: u32| {
+    while let Some(index) = shared.iter().position(|(at, _)| *at == position) {
+      content.push(shared.remove(index).1);
+    }
+  };
+  let backup = |divisions: u32| {
+    MeasureElement::Backup(Backup {
+      attributes: (),
+      content: BackupContents {
+        duration: duration(divisions),
+        footnote: None,
+        level: None,
+      },
+    })
+  };
+  for sequence in &voices.voices {
+    if position > 0 {
+      content.push(backup(position));
+      position = 0;
+    }
+    for element in &sequence.elements {
+      let mut element = element.clone();
+      if !matches!(&element, MeasureElement::Note(note) if note_is_chord(note)) {
+        write_shared(&mut content, position);
+      }
+      set_voice(&mut element, &sequence.voice);
+      position += element_advance(&element);
+      content.push(element);
+    }
+  }
+  write_shared(&mut content, position);
+  for (at, element) in shared {
+    if at < position {
+      content.push(backup(position - at));
+    } else if at > position {
+      content.push(MeasureElement::Forward(Forward {
+        attributes: (),
+        content: ForwardContents {
+          duration: duration(at - position),
+          footnote: None,
+          level: None,
+          voice: None,
+          staff: None,
+        },
+      }));
+    }
+    position = at;
+    content.push(element);
+  }
+  Ok(Measure {
+    attributes: voices.attributes.clone(),
+    content,
+  })
+}
+
+/// Returns the part with the specified ID, or an error if it does not exist.
+fn find_part<'a>(score: &'a mut ScorePartwise, part_id: &str) -> Result<&'a mut Part, String> {
+  score
+    .content
+    .part
+    .iter_mut()
+    .find(|part| *part.attributes.id == part_id)
+    .ok_or_else(|| format!("Part \"{part_id}\" does not exist in the score"))
+}
+
+/// Returns an iterator over every voiced element in a part: notes, directions, and forward elements.
+fn voiced_elements(part: &mut Part) -> impl Iterator<Item = &mut MeasureElement> {
+  part_measures_mut(part)
+    .flat_map(|measure| measure.content.iter_mut())
+    .filter(|element| {
+      matches!(
+        element,
+        MeasureElement::Note(_) | MeasureElement::Direction(_) | MeasureElement::Forward(_)
+      )
+    })
+}
+
+/// Moves every element of a voice within a part onto a different staff.
+///
+/// The [Staff][crate::elements::Staff] of every [Note][crate::elements::Note], [Direction][crate::elements::Direction],
+/// and [Forward][crate::elements::Forward] in the voice is set to `staff` throughout the part.
+///
+/// # Errors
+///
+/// If the part or voice does not exist, or if the part does not contain the specified staff, an error message will be
+/// returned.
+pub fn move_voice_to_staff(
+  score: &ScorePartwise,
+  part_id: &str,
+  voice: &str,
+  staff: u32,
+) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  let part = find_part(&mut score, part_id)?;
+  let staves = part_measures(part)
+    .flat_map(|measure| measure.content.iter())
+    .filter_map(|element| match element {
+      MeasureElement::Attributes(attributes) => attributes.content.staves.as_ref(),
+      _ => None,
+    })
+    .map(|staves| *staves.content)
+    .fold(1, u32::max);
+  if staff == 0 || staff > staves {
+    return Err(format!(
+      "Staff {staff} does not exist in part \"{part_id}\" with {staves} staves"
+    ));
+  }
+  let mut found = false;
+  for element in voiced_elements(part).filter(|element| element_voice(element) == Some(voice)) {
+    set_staff(element, staff);
+    found = true;
+  }
+  if !found {
+    return Err(format!("Voice \"{voice}\" does not exist in part \"{part_id}\""));
+  }
+  Ok(score)
+}
+
+/// Renumbers the voices in every part of a score consecutively, starting from 1.
+///
+/// Within each part, voices are ordered by the lowest [Staff][crate::elements::Staff] on which they appear and then
+/// by their first appearance, and each original voice keeps the same new number throughout the part. The voices of
+/// every [Note][crate::elements::Note], [Direction][crate::elements::Direction], and
+/// [Forward][crate::elements::Forward] are updated accordingly.
+///
+/// # Errors
+///
+/// This function does not currently return an error, and returns a [Result] for consistency with
+/// [move_voice_to_staff].
+pub fn renumber_voices(score: &ScorePartwise) -> Result<ScorePartwise, String> {
+  let mut score = score.clone();
+  for part in &mut score.content.part {
+    let mut voices: Vec<(Option<u32>, String)> = Vec::new();
+    for element in voiced_elements(part) {
+      if let Some(voice) = element_voice(element) {
+        let staff = element_staff(element);
+        match voices.iter_mut().find(|(_, existing)| *existing == voice) {
+          Some((lowest, _)) => {
+            *lowest = match (*lowest, staff) {
+              (Some(lowest), Some(staff)) => Some(lowest.min(staff)),
+              (lowest, staff) => lowest.or(staff),
+            }
+          }
+          None => voices.push((staff, voice.to_string())),
+        }
+      }
+    }
+    voices.sort_by_key(|(staff, _)| staff.unwrap_or(1));
+    for element in voiced_elements(part) {
+      if let Some(index) =
+        element_voice(element).and_then(|voice| voices.iter().position(|(_, existing)| existing == voice))
+      {
+        set_voice(element, &(index + 1).to_string());
+      }
+    }
+  }
+  Ok(score)
+}
+
+#[cfg(test)]
+mod voices_tests {
+  use super::*;
+  use crate::elements::{Direction, Note};
+  use crate::parser::parse_from_xml_str;
+  use crate::util::{note_is_rest, note_staff};
+  use crate::visit::for_each;
+  use musicxml_internal::ElementSerializer;
+
+  const MEASURE: &str = r#"<measure number="1">
+    <attributes><divisions>1</divisions><staves>2</staves></attributes>
+    <direction><direction-type><words>legato</words></direction-type><voice>1</voice></direction>
+    <note><pitch><step>C</step><octave>5</octave></pitch><duration>2</duration><voice>1</voice><staff>1</staff></note>
+    <note><chord/><pitch><step>E</step><octave>5</octave></pitch><duration>2</duration><voice>1</voice><staff>1</staff></note>
+    <forward><duration>2</duration></forward>
+    <backup><duration>4</duration></backup>
+    <forward><duration>1</duration><voice>2</voice></forward>
+    <direction><direction-type><words>cresc.</words></direction-type></direction>
+    <note><pitch><step>C</step><octave>3</octave></pitch><duration>2</duration><voice>2</voice><staff>2</staff></note>
+    <forward><duration>1</duration></forward>
+    <barline location="right"><bar-style>light-heavy</bar-style></barline>
+  </measure>"#;
+
+  fn summary(sequence: &VoiceSequence) -> Vec<(bool, u32, u32)> {
+    sequence
+      .elements
+      .iter()
+      .filter_map(|element| match element {
+        MeasureElement::Note(note) => Some((note_is_rest(note), note_duration(note), note_staff(note))),
+        _ => None,
+      })
+      .collect()
+  }
+
+  fn names(measure: &Measure) -> Vec<String> {
+    Measure::serialize(measure)
+      .elements
+      .iter()
+      .map(|element| element.name.to_string())
+      .collect()
+  }
+
+  #[test]
+  fn split_fills_gaps() {
+    let voices = split_voices(&parse_from_xml_str::<Measure>(MEASURE).unwrap()).unwrap();
+    assert_eq!(voices.voices.len(), 2);
+    assert_eq!(voices.voices[0].voice, "1");
+    assert_eq!(voices.voices[0].elements.len(), 4);
+    assert!(matches!(voices.voices[0].elements[0], MeasureElement::Direction(_)));
+    assert_eq!(
+      summary(&voices.voices[0]),
+      vec![(false, 2, 1), (false, 2, 1), (true, 2, 1)]
+    );
+    assert_eq!(voices.voices[1].voice, "2");
+    assert_eq!(
+      summary(&voices.voices[1]),
+      vec![(true, 1, 2), (false, 2, 2), (true, 1, 2)]
+    );
+    let shared: Vec<u32> = voices.shared.iter().map(|(position, _)| *position).collect();
+    assert_eq!(shared, vec![0, 1, 4]);
+  }
+
+  #[test]
+  fn merge_voices_round_trip() {
+    let voices = split_voices(&parse_from_xml_str::<Measure>(MEASURE).unwrap()).unwrap();
+    let merged = merge_voices(&voices).unwrap();
+    assert_eq!(
+      names(&merged),
+      vec![
+        "attributes",
+        "direction",
+        "note",
+        "note",
+        "note",
+        "backup",
+        "note",
+        "direction",
+        "note",
+        "note",
+        "barline"
+      ]
+    );
+    assert_eq!(split_voices(&merged).unwrap(), voices);
+
+    // Shared elements that fall partway through a note are written at the end
+    let mut voices = voices;
+    voices.voices.pop();
+    let merged = merge_voices(&voices).unwrap();
+    assert_eq!(
+      names(&merged),
+      vec![
+        "attributes",
+        "direction",
+        "note",
+        "note",
+        "note",
+        "barline",
+        "backup",
+        "direction"
+      ]
+    );
+  }
+
+  #[test]
+  fn move_voice_between_staves() {
+    let score = parse_from_xml_str::<ScorePartwise>(&format!(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Piano</part-name></score-part></part-list>
+        <part id="P1">{MEASURE}</part>
+      </score-partwise>"#
+    ))
+    .unwrap();
+    let moved = move_voice_to_staff(&score, "P1", "2", 1).unwrap();
+    let mut staves = Vec::new();
+    for_each(&moved, |note: &Note, _| staves.push(note_staff(note)));
+    assert_eq!(staves, vec![1, 1, 1]);
+    let moved = move_voice_to_staff(&score, "P1", "1", 2).unwrap();
+    let mut staves = Vec::new();
+    for_each(&moved, |direction: &Direction, _| {
+      staves.push(direction.content.staff.as_ref().map(|staff| *staff.content));
+    });
+    assert_eq!(staves, vec![Some(2), None]);
+    assert_eq!(
+      move_voice_to_staff(&score, "P1", "3", 1).unwrap_err(),
+      "Voice \"3\" does not exist in part \"P1\""
+    );
+    assert_eq!(
+      move_voice_to_staff(&score, "P1", "1", 3).unwrap_err(),
+      "Staff 3 does not exist in part \"P1\" with 2 staves"
+    );
+    assert!(move_voice_to_staff(&score, "P2", "1", 1).is_err());
+  }
+
+  #[test]
+  fn renumber_voices_by_staff() {
+    let source = MEASURE
+      .replace("<voice>1</voice>", "<voice>7</voice>")
+      .replace("<voice>2</voice>", "<voice>3</voice>")
+      .replace("<staff>1</staff>", "<staff>9</staff>")
+      .replace("<staff>2</staff>", "<staff>1</staff>")
+      .replace("<staff>9</staff>", "<staff>2</staff>");
+    let score = parse_from_xml_str::<ScorePartwise>(&format!(
+      r#"<score-partwise>
+        <part-list><score-part id="P1"><part-name>Piano</part-name></score-part></part-list>
+        <part id="P1">{source}</part>
+      </score-partwise>"#
+    ))
+    .unwrap();
+    let renumbered = renumber_voices(&score).unwrap();
+    let mut voices = Vec::new();
+    for_each(&renumbered, |note: &Note, _| voices.push(note_voice(note).to_string()));
+    assert_eq!(voices, vec!["2", "2", "1"]);
+  }
+
+  #[test]
+  fn split_voices_span_measure() {
+    let voices = split_voices(&parse_from_xml_str::<Measure>(MEASURE).unwrap()).unwrap();
+    for sequence in &voices.voices {
+      let length: u32 = sequence
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+          MeasureElement::Note(note) if !note_is_chord(note) => Some(note_duration(note)),
+          _ => None,
+        })
+        .sum();
+      assert_eq!(length, 4);
+    }
+  }
+}
@@ -0,0 +1,1167 @@
+use crate::datatypes::{
+  BackwardForward, KindValue, NoteTypeValue, StartStop, StartStopContinue, StartStopDiscontinue, Step as StepValue,
+};
+use crate::elements::{
+  Attributes, AudibleType, Direction, GraceType, Harmony, HarmonySubcontents, Measure, MeasureElement,
+  NotationContentTypes, Note, NoteType, Part, PartElement, Pitch, Sound, Tie,
+};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use musicxml_internal::XmlElement;
+
+/// Exact rational number used for all internal timing calculations.
+///
+/// Unless otherwise noted, timing values are expressed in quarter notes so that they remain
+/// independent of the `<divisions>` value in effect for any given part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Fraction {
+  pub num: i64,
+  pub den: i64,
+}
+
+pub(crate) fn gcd(a: i64, b: i64) -> i64 {
+  let (mut a, mut b) = (a.abs(), b.abs());
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
+}
+
+pub(crate) fn lcm(a: i64, b: i64) -> i64 {
+  if a == 0 || b == 0 {
+    0
+  } else {
+    (a / gcd(a, b) * b).abs()
+  }
+}
+
+impl Fraction {
+  pub const ZERO: Fraction = Fraction { num: 0, den: 1 };
+
+  pub fn new(num: i64, den: i64) -> Self {
+    if den == 0 {
+      return Fraction::ZERO;
+    }
+    let divisor = gcd(num, den).max(1) * den.signum();
+    Fraction {
+      num: num / divisor,
+      den: den / divisor,
+    }
+  }
+
+  pub fn from_int(value: i64) -> Self {
+    Fraction { num: value, den: 1 }
+  }
+
+  pub fn from_divisions(duration: i64, divisions: u32) -> Self {
+    Fraction::new(duration, i64::from(divisions.max(1)))
+  }
+
+  pub fn is_positive(&self) -> bool {
+    self.num > 0
+  }
+
+  /// Rounds the fraction to the nearest multiple of `step`.
+  pub fn rounded(self, step: Fraction) -> Fraction {
+    let steps = self / step;
+    let whole = (2 * steps.num + steps.den).div_euclid(2 * steps.den);
+    Fraction::from_int(whole) * step
+  }
+}
+
+impl Default for Fraction {
+  fn default() -> Self {
+    Fraction::ZERO
+  }
+}
+
+impl Add for Fraction {
+  type Output = Fraction;
+  fn add(self, rhs: Fraction) -> Fraction {
+    let den = lcm(self.den, rhs.den);
+    Fraction::new(self.num * (den / self.den) + rhs.num * (den / rhs.den), den)
+  }
+}
+
+impl AddAssign for Fraction {
+  fn add_assign(&mut self, rhs: Fraction) {
+    *self = *self + rhs;
+  }
+}
+
+impl Sub for Fraction {
+  type Output = Fraction;
+  fn sub(self, rhs: Fraction) -> Fraction {
+    self + (-rhs)
+  }
+}
+
+impl SubAssign for Fraction {
+  fn sub_assign(&mut self, rhs: Fraction) {
+    *self = *self - rhs;
+  }
+}
+
+impl Mul for Fraction {
+  type Output = Fraction;
+  fn mul(self, rhs: Fraction) -> Fraction {
+    let (g1, g2) = (gcd(self.num, rhs.den).max(1), gcd(rhs.num, self.den).max(1));
+    Fraction::new((self.num / g1) * (rhs.num / g2), (self.den / g2) * (rhs.den / g1))
+  }
+}
+
+impl Div for Fraction {
+  type Output = Fraction;
+  #[allow(clippy::suspicious_arithmetic_impl)]
+  fn div(self, rhs: Fraction) -> Fraction {
+    self * Fraction::new(rhs.den, rhs.num)
+  }
+}
+
+impl Neg for Fraction {
+  type Output = Fraction;
+  fn neg(self) -> Fraction {
+    Fraction {
+      num: -self.num,
+      den: self.den,
+    }
+  }
+}
+
+impl PartialOrd for Fraction {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Fraction {
+  fn cmp(&self, other: &Self) -> Ordering {
+    (i128::from(self.num) * i128::from(other.den)).cmp(&(i128::from(other.num) * i128::from(self.den)))
+  }
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// PITCH AND DURATION HELPERS -----------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Returns the number of semitones between C and the specified step.
+pub(crate) fn step_semitones(step: &StepValue) -> i32 {
+  match step {
+    StepValue::C => 0,
+    StepValue::D => 2,
+    StepValue::E => 4,
+    StepValue::F => 5,
+    StepValue::G => 7,
+    StepValue::A => 9,
+    StepValue::B => 11,
+  }
+}
+
+/// Returns the upper-case letter name of a step.
+pub(crate) fn step_letter(step: &StepValue) -> char {
+  match step {
+    StepValue::C => 'C',
+    StepValue::D => 'D',
+    StepValue::E => 'E',
+    StepValue::F => 'F',
+    StepValue::G => 'G',
+    StepValue::A => 'A',
+    StepValue::B => 'B',
+  }
+}
+
+/// Parses a step from its letter name, ignoring case.
+pub(crate) fn step_from_letter(letter: char) -> Option<StepValue> {
+  match letter.to_ascii_uppercase() {
+    'C' => Some(StepValue::C),
+    'D' => Some(StepValue::D),
+    'E' => Some(StepValue::E),
+    'F' => Some(StepValue::F),
+    'G' => Some(StepValue::G),
+    'A' => Some(StepValue::A),
+    'B' => Some(StepValue::B),
+    _ => None,
+  }
+}
+
+/// Returns the chromatic alteration of a pitch in semitones.
+pub(crate) fn pitch_alter(pitch: &Pitch) -> i32 {
+  pitch
+    .content
+    .alter
+    .as_ref()
+    .map_or(0, |alter| i32::from(*alter.content))
+}
+
+/// Returns the MIDI note number of a pitch, where middle C (C4) is 60.
+pub(crate) fn pitch_to_midi(pitch: &Pitch) -> i32 {
+  (i32::from(*pitch.content.octave.content) + 1) * 12 + step_semitones(&pitch.content.step.content) + pitch_alter(pitch)
+}
+
+/// Returns the notated length of a note type in quarter notes.
+pub(crate) fn note_type_length(note_type: &NoteTypeValue) -> Fraction {
+  match note_type {
+    NoteTypeValue::Maxima => Fraction::from_int(32),
+    NoteTypeValue::Long => Fraction::from_int(16),
+    NoteTypeValue::Breve => Fraction::from_int(8),
+    NoteTypeValue::Whole => Fraction::from_int(4),
+    NoteTypeValue::Half => Fraction::from_int(2),
+    NoteTypeValue::Quarter => Fraction::from_int(1),
+    NoteTypeValue::Eighth => Fraction::new(1, 2),
+    NoteTypeValue::Sixteenth => Fraction::new(1, 4),
+    NoteTypeValue::ThirtySecond => Fraction::new(1, 8),
+    NoteTypeValue::SixtyFourth => Fraction::new(1, 16),
+    NoteTypeValue::OneHundredTwentyEighth => Fraction::new(1, 32),
+    NoteTypeValue::TwoHundredFiftySixth => Fraction::new(1, 64),
+    NoteTypeValue::FiveHundredTwelfth => Fraction::new(1, 128),
+    NoteTypeValue::OneThousandTwentyFourth => Fraction::new(1, 256),
+  }
+}
+
+/// All note types ordered from longest to shortest.
+pub(crate) const NOTE_TYPES: [NoteTypeValue; 14] = [
+  NoteTypeValue::Maxima,
+  NoteTypeValue::Long,
+  NoteTypeValue::Breve,
+  NoteTypeValue::Whole,
+  NoteTypeValue::Half,
+  NoteTypeValue::Quarter,
+  NoteTypeValue::Eighth,
+  NoteTypeValue::Sixteenth,
+  NoteTypeValue::ThirtySecond,
+  NoteTypeValue::SixtyFourth,
+  NoteTypeValue::OneHundredTwentyEighth,
+  NoteTypeValue::TwoHundredFiftySixth,
+  NoteTypeValue::FiveHundredTwelfth,
+  NoteTypeValue::OneThousandTwentyFourth,
+];
+
+/// Returns the length of a note type extended by the specified number of augmentation dots.
+pub(crate) fn dotted_length(note_type: &NoteTypeValue, dots: u8) -> Fraction {
+  let base = note_type_length(note_type);
+  let mut length = base;
+  let mut addition = base;
+  for _ in 0..dots {
+    addition = addition * Fraction::new(1, 2);
+    length += addition;
+  }
+  length
+}
+
+/// Finds the note type and number of dots (up to three) which exactly represent a length in quarter notes.
+pub(crate) fn length_to_note_type(length: Fraction) -> Option<(NoteTypeValue, u8)> {
+  for dots in 0..=3 {
+    for note_type in NOTE_TYPES {
+      if dotted_length(&note_type, dots) == length {
+        return Some((note_type, dots));
+      }
+    }
+  }
+  None
+}
+
+/// Splits a length in quarter notes into a sequence of dotted note types whose lengths sum to the original length.
+///
+/// Any remainder too small to be represented by a 1024th note is discarded.
+pub(crate) fn split_length(length: Fraction) -> Vec<(NoteTypeValue, u8)> {
+  let mut parts = Vec::new();
+  let mut remaining = length;
+  while remaining.is_positive() {
+    let Some(note_type) = NOTE_TYPES
+      .into_iter()
+      .find(|note_type| note_type_length(note_type) <= remaining)
+    else {
+      break;
+    };
+    let mut dots = 0;
+    while dots < 3 && dotted_length(&note_type, dots + 1) <= remaining {
+      dots += 1;
+    }
+    remaining -= dotted_length(&note_type, dots);
+    parts.push((note_type, dots));
+  }
+  parts
+}
+
+/// MusicXML dynamics element names whose text representation is identical to the element name.
+pub(crate) const DYNAMICS: [&str; 26] = [
+  "p", "pp", "ppp", "pppp", "ppppp", "pppppp", "f", "ff", "fff", "ffff", "fffff", "ffffff", "mp", "mf", "sf", "sfp",
+  "sfpp", "fp", "rf", "rfz", "sfz", "sffz", "fz", "n", "pf", "sfzp",
+];
+
+/// Returns the accidental symbols used in plain-text pitch and chord names for an alteration in semitones.
+pub(crate) fn alter_symbols(alter: i32) -> String {
+  if alter >= 0 {
+    "#".repeat(alter.unsigned_abs() as usize)
+  } else {
+    "b".repeat(alter.unsigned_abs() as usize)
+  }
+}
+
+/// Returns the conventional chord-symbol suffix for a harmony kind.
+pub(crate) fn kind_suffix(kind: &KindValue) -> &'static str {
+  match kind {
+    KindValue::Major | KindValue::Other => "",
+    KindValue::Minor => "m",
+    KindValue::Augmented => "+",
+    KindValue::Diminished => "dim",
+    KindValue::Dominant => "7",
+    KindValue::MajorSeventh => "maj7",
+    KindValue::MinorSeventh => "m7",
+    KindValue::DiminishedSeventh => "dim7",
+    KindValue::AugmentedSeventh => "+7",
+    KindValue::HalfDiminished => "m7b5",
+    KindValue::MajorMinor => "m(maj7)",
+    KindValue::MajorSixth => "6",
+    KindValue::MinorSixth => "m6",
+    KindValue::DominantNinth => "9",
+    KindValue::MajorNinth => "maj9",
+    KindValue::MinorNinth => "m9",
+    KindValue::Dominant11th => "11",
+    KindValue::Major11th => "maj11",
+    KindValue::Minor11th => "m11",
+    KindValue::Dominant13th => "13",
+    KindValue::Major13th => "maj13",
+    KindValue::Minor13th => "m13",
+    KindValue::SuspendedSecond => "sus2",
+    KindValue::SuspendedFourth => "sus4",
+    KindValue::Neapolitan => "N6",
+    KindValue::Italian => "It+6",
+    KindValue::French => "Fr+6",
+    KindValue::German => "Ger+6",
+    KindValue::Pedal => "ped",
+    KindValue::Power => "5",
+    KindValue::Tristan => "Tristan",
+    KindValue::None => "N.C.",
+  }
+}
+
+/// Chord-symbol suffixes and the MusicXML `<kind>` values they represent, ordered so that longer suffixes are
+/// matched before any of their prefixes.
+const CHORD_SUFFIXES: [(&str, &str); 41] = [
+  ("maj13", "major-13th"),
+  ("maj11", "major-11th"),
+  ("maj9", "major-ninth"),
+  ("maj7", "major-seventh"),
+  ("m(maj7)", "major-minor"),
+  ("mMaj7", "major-minor"),
+  ("m7b5", "half-diminished"),
+  ("ø7", "half-diminished"),
+  ("ø", "half-diminished"),
+  ("dim7", "diminished-seventh"),
+  ("o7", "diminished-seventh"),
+  ("dim", "diminished"),
+  ("o", "diminished"),
+  ("aug7", "augmented-seventh"),
+  ("+7", "augmented-seventh"),
+  ("aug", "augmented"),
+  ("+", "augmented"),
+  ("m13", "minor-13th"),
+  ("m11", "minor-11th"),
+  ("m9", "minor-ninth"),
+  ("m7", "minor-seventh"),
+  ("m6", "minor-sixth"),
+  ("min", "minor"),
+  ("m", "minor"),
+  ("13", "dominant-13th"),
+  ("11", "dominant-11th"),
+  ("9", "dominant-ninth"),
+  ("7", "dominant"),
+  ("6", "major-sixth"),
+  ("sus4", "suspended-fourth"),
+  ("sus2", "suspended-second"),
+  ("sus", "suspended-fourth"),
+  ("5", "power"),
+  ("N6", "Neapolitan"),
+  ("It+6", "Italian"),
+  ("Fr+6", "French"),
+  ("Ger+6", "German"),
+  ("ped", "pedal"),
+  ("maj", "major"),
+  ("M", "major"),
+  ("", "major"),
+];
+
+/// Renders a harmony as a plain-text chord symbol such as `"F#m7/C#"`.
+pub(crate) fn harmony_text(harmony: &HarmonySubcontents) -> String {
+  let mut text = String::new();
+  if let Some(root) = &harmony.root {
+    text.push(step_letter(&root.content.root_step.content));
+    text += &alter_symbols(
+      root
+        .content
+        .root_alter
+        .as_ref()
+        .map_or(0, |alter| i32::from(*alter.content)),
+    );
+  } else if let Some(numeral) = &harmony.numeral {
+    text += &numeral.content.numeral_root.attributes.text.as_ref().map_or_else(
+      || numeral.content.numeral_root.content.0.to_string(),
+      |text| text.0.clone(),
+    );
+  } else if let Some(function) = &harmony.function {
+    text += &function.content;
+  }
+  match &harmony.kind.attributes.text {
+    Some(kind_text) => text += &kind_text.0,
+    None => text += kind_suffix(&harmony.kind.content),
+  }
+  if let Some(bass) = &harmony.bass {
+    text.push('/');
+    text.push(step_letter(&bass.content.bass_step.content));
+    text += &alter_symbols(
+      bass
+        .content
+        .bass_alter
+        .as_ref()
+        .map_or(0, |alter| i32::from(*alter.content)),
+    );
+  }
+  text
+}
+
+/// Returns the concatenated plain-text chord symbols for all chords within a [Harmony] element.
+pub(crate) fn harmony_element_text(harmony: &Harmony) -> String {
+  harmony
+    .content
+    .harmony
+    .iter()
+    .map(harmony_text)
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// Parses a leading pitch name (e.g. `"Bb"`, `"F#"`) from a chord symbol, returning the step, its alteration, and the
+/// remaining unparsed text.
+fn parse_chord_pitch(text: &str) -> Option<(StepValue, i32, &str)> {
+  let mut chars = text.chars();
+  let step = step_from_letter(chars.next()?)?;
+  let mut rest = chars.as_str();
+  let mut alter = 0;
+  loop {
+    if let Some(remaining) = rest.strip_prefix('#').or_else(|| rest.strip_prefix('♯')) {
+      alter += 1;
+      rest = remaining;
+    } else if let Some(remaining) = rest.strip_prefix('b').or_else(|| rest.strip_prefix('♭')) {
+      alter -= 1;
+      rest = remaining;
+    } else {
+      break;
+    }
+  }
+  Some((step, alter, rest))
+}
+
+/// Converts a plain-text chord symbol such as `"Ebmaj7/G"` into a MusicXML `<harmony>` element tree.
+///
+/// Unrecognized chord suffixes are encoded with a `kind` of "other" and preserved in the `text` attribute.
+pub(crate) fn chord_symbol_to_xml(symbol: &str) -> Option<XmlElement<'static>> {
+  let symbol = symbol.trim();
+  if symbol.is_empty() {
+    return None;
+  }
+  let mut harmony = XmlElement::named("harmony");
+  if symbol == "N.C." || symbol == "NC" {
+    return Some(
+      harmony.with_child(
+        XmlElement::named("kind")
+          .with_attribute("text", symbol)
+          .with_text("none"),
+      ),
+    );
+  }
+  let (step, alter, rest) = parse_chord_pitch(symbol)?;
+  let (suffix, bass) = match rest.rsplit_once('/') {
+    Some((suffix, bass)) => (suffix, parse_chord_pitch(bass)),
+    None => (rest, None),
+  };
+  let mut root = XmlElement::named("root").with_child(XmlElement::named("root-step").with_text(step_letter(&step)));
+  if alter != 0 {
+    root = root.with_child(XmlElement::named("root-alter").with_text(alter.to_string()));
+  }
+  harmony = harmony.with_child(root);
+  let kind = CHORD_SUFFIXES
+    .iter()
+    .find(|(text, _)| *text == suffix)
+    .map_or("other", |(_, kind)| kind);
+  harmony = harmony.with_child(XmlElement::named("kind").with_attribute("text", suffix).with_text(kind));
+  if let Some((bass_step, bass_alter, _)) = bass {
+    let mut bass =
+      XmlElement::named("bass").with_child(XmlElement::named("bass-step").with_text(step_letter(&bass_step)));
+    if bass_alter != 0 {
+      bass = bass.with_child(XmlElement::named("bass-alter").with_text(bass_alter.to_string()));
+    }
+    harmony = harmony.with_child(bass);
+  }
+  Some(harmony)
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// NOTE ACCESSORS -----------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Returns whether a note is a pitch, unpitched note, or rest.
+pub(crate) fn note_audible(note: &Note) -> &AudibleType {
+  match &note.content.info {
+    NoteType::Normal(info) => &info.audible,
+    NoteType::Cue(info) => &info.audible,
+    NoteType::Grace(info) => match &info.info {
+      GraceType::Cue(info) => &info.audible,
+      GraceType::Normal(info) => &info.audible,
+    },
+  }
+}
+
+/// Returns the pitch of a note, if it is pitched.
+pub(crate) fn note_pitch(note: &Note) -> Option<&Pitch> {
+  match note_audible(note) {
+    AudibleType::Pitch(pitch) => Some(pitch),
+    _ => None,
+  }
+}
+
+/// Returns whether a note is a rest.
+pub(crate) fn note_is_rest(note: &Note) -> bool {
+  matches!(note_audible(note), AudibleType::Rest(_))
+}
+
+/// Returns whether a note is an additional member of a chord begun by a previous note.
+pub(crate) fn note_is_chord(note: &Note) -> bool {
+  match &note.content.info {
+    NoteType::Normal(info) => info.chord.is_some(),
+    NoteType::Cue(info) => info.chord.is_some(),
+    NoteType::Grace(info) => match &info.info {
+      GraceType::Cue(info) => info.chord.is_some(),
+      GraceType::Normal(info) => info.chord.is_some(),
+    },
+  }
+}
+
+/// Returns whether a note is a grace note.
+pub(crate) fn note_is_grace(note: &Note) -> bool {
+  matches!(note.content.info, NoteType::Grace(_))
+}
+
+/// Returns whether a note is a cue note.
+pub(crate) fn note_is_cue(note: &Note) -> bool {
+  match &note.content.info {
+    NoteType::Cue(_) => true,
+    NoteType::Grace(info) => matches!(info.info, GraceType::Cue(_)),
+    NoteType::Normal(_) => false,
+  }
+}
+
+/// Returns the duration of a note in divisions, which is always zero for grace notes.
+pub(crate) fn note_duration(note: &Note) -> u32 {
+  match &note.content.info {
+    NoteType::Normal(info) => *info.duration.content,
+    NoteType::Cue(info) => *info.duration.content,
+    NoteType::Grace(_) => 0,
+  }
+}
+
+/// Returns the playback ties attached to a note.
+pub(crate) fn note_ties(note: &Note) -> &[Tie] {
+  match &note.content.info {
+    NoteType::Normal(info) => &info.tie,
+    NoteType::Grace(info) => match &info.info {
+      GraceType::Normal(info) => &info.tie,
+      GraceType::Cue(_) => &[],
+    },
+    NoteType::Cue(_) => &[],
+  }
+}
+
+/// Returns a mutable reference to the playback ties attached to a note, or `None` for cue notes, which cannot be tied.
+pub(crate) fn note_ties_mut(note: &mut Note) -> Option<&mut Vec<Tie>> {
+  match &mut note.content.info {
+    NoteType::Normal(info) => Some(&mut info.tie),
+    NoteType::Grace(info) => match &mut info.info {
+      GraceType::Normal(info) => Some(&mut info.tie),
+      GraceType::Cue(_) => None,
+    },
+    NoteType::Cue(_) => None,
+  }
+}
+
+/// Returns whether a note starts and/or stops a tie, taking both playback ties and notated ties into account.
+pub(crate) fn note_tied(note: &Note) -> (bool, bool) {
+  let notated = |kind: StartStopContinue| {
+    note.content.notations.iter().any(|notations| {
+      notations
+        .content
+        .notations
+        .iter()
+        .any(|notation| matches!(notation, NotationContentTypes::Tied(tied) if tied.attributes.r#type == kind))
+    })
+  };
+  let start = note_ties(note)
+    .iter()
+    .any(|tie| tie.attributes.r#type == StartStop::Start)
+    || notated(StartStopContinue::Start);
+  let stop = note_ties(note)
+    .iter()
+    .any(|tie| tie.attributes.r#type == StartStop::Stop)
+    || notated(StartStopContinue::Stop);
+  (start, stop)
+}
+
+/// Returns the voice of a note, which defaults to "1" when unspecified.
+pub(crate) fn note_voice(note: &Note) -> &str {
+  note.content.voice.as_ref().map_or("1", |voice| voice.content.as_str())
+}
+
+/// Returns the staff of a note, which defaults to 1 when unspecified.
+pub(crate) fn note_staff(note: &Note) -> u32 {
+  note.content.staff.as_ref().map_or(1, |staff| *staff.content)
+}
+
+/// Returns the number of augmentation dots on a note.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn note_dots(note: &Note) -> u8 {
+  note.content.dot.len().min(u8::MAX as usize) as u8
+}
+
+/// Returns the tuplet ratio (actual notes, normal notes) applied to a note, or (1, 1) if none.
+pub(crate) fn note_time_modification(note: &Note) -> (u32, u32) {
+  note.content.time_modification.as_ref().map_or((1, 1), |modification| {
+    (
+      (*modification.content.actual_notes.content).max(1),
+      (*modification.content.normal_notes.content).max(1),
+    )
+  })
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// MEASURE TIMELINES --------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Returns an iterator over all measures contained within a part.
+pub(crate) fn part_measures(part: &Part) -> impl Iterator<Item = &Measure> {
+  part.content.iter().filter_map(|element| match element {
+    PartElement::Measure(measure) => Some(measure),
+    _ => None,
+  })
+}
+
+/// Returns an iterator over mutable references to all measures contained within a part.
+pub(crate) fn part_measures_mut(part: &mut Part) -> impl Iterator<Item = &mut Measure> {
+  part.content.iter_mut().filter_map(|element| match element {
+    PartElement::Measure(measure) => Some(measure),
+    _ => None,
+  })
+}
+
+/// Returns the number of staves used by a part, which is always at least one.
+pub(crate) fn part_staves(part: &Part) -> u32 {
+  part_measures(part)
+    .flat_map(|measure| measure.content.iter())
+    .filter_map(|element| match element {
+      MeasureElement::Attributes(attributes) => attributes.content.staves.as_ref().map(|staves| *staves.content),
+      _ => None,
+    })
+    .max()
+    .unwrap_or(1)
+    .max(1)
+}
+
+/// A single musical event located at a specific position within a measure.
+pub(crate) enum MeasureEvent<'a> {
+  /// A note along with any subsequent notes that form a chord with it.
+  Notes(Vec<&'a Note>),
+  Attributes(&'a Attributes),
+  Direction(&'a Direction),
+  Harmony(&'a Harmony),
+  Sound(&'a Sound),
+}
+
+/// A [MeasureEvent] along with its onset and duration, both in quarter notes relative to the start of the measure.
+pub(crate) struct TimedEvent<'a> {
+  pub position: Fraction,
+  pub duration: Fraction,
+  pub event: MeasureEvent<'a>,
+}
+
+/// The events contained within a measure, in document order.
+pub(crate) struct MeasureTimeline<'a> {
+  pub events: Vec<TimedEvent<'a>>,
+}
+
+/// Tracks the state that carries from one measure to the next while walking a part.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PartTimeline {
+  pub divisions: u32,
+}
+
+impl Default for PartTimeline {
+  fn default() -> Self {
+    PartTimeline { divisions: 1 }
+  }
+}
+
+impl PartTimeline {
+  /// Converts the contents of a measure into a list of timed events, grouping chord notes together.
+  pub fn measure<'a>(&mut self, measure: &'a Measure) -> MeasureTimeline<'a> {
+    let mut events: Vec<TimedEvent<'a>> = Vec::new();
+    let mut position = Fraction::ZERO;
+    for element in &measure.content {
+      match element {
+        MeasureElement::Note(note) => {
+          let duration = Fraction::from_divisions(i64::from(note_duration(note)), self.divisions);
+          if note_is_chord(note) {
+            if let Some(TimedEvent {
+              event: MeasureEvent::Notes(notes),
+              ..
+            }) = events
+              .iter_mut()
+              .rev()
+              .find(|event| matches!(event.event, MeasureEvent::Notes(_)))
+            {
+              notes.push(note);
+              continue;
+            }
+          }
+          events.push(TimedEvent {
+            position,
+            duration,
+            event: MeasureEvent::Notes(vec![note]),
+          });
+          position += duration;
+        }
+        MeasureElement::Backup(backup) => {
+          position -= Fraction::from_divisions(i64::from(*backup.content.duration.content), self.divisions);
+          if position < Fraction::ZERO {
+            position = Fraction::ZERO;
+          }
+        }
+        MeasureElement::Forward(forward) => {
+          position += Fraction::from_divisions(i64::from(*forward.content.duration.content), self.divisions);
+        }
+        MeasureElement::Attributes(attributes) => {
+          if let Some(divisions) = &attributes.content.divisions {
+            self.divisions = (*divisions.content).max(1);
+          }
+          events.push(TimedEvent {
+            position,
+            duration: Fraction::ZERO,
+            event: MeasureEvent::Attributes(attributes),
+          });
+        }
+        MeasureElement::Direction(direction) => events.push(TimedEvent {
+          position,
+          duration: Fraction::ZERO,
+          event: MeasureEvent::Direction(direction),
+        }),
+        MeasureElement::Harmony(harmony) => events.push(TimedEvent {
+          position,
+          duration: Fraction::ZERO,
+          event: MeasureEvent::Harmony(harmony),
+        }),
+        MeasureElement::Sound(sound) => events.push(TimedEvent {
+          position,
+          duration: Fraction::ZERO,
+          event: MeasureEvent::Sound(sound),
+        }),
+        _ => {}
+      }
+    }
+    MeasureTimeline { events }
+  }
+}
+
+/// Returns the order in which the measures of a part are performed when repeats and volta endings are followed, as
+/// pairs of measure index and pass number (starting from 1) through the enclosing repeated section.
+///
+/// Nested repeats, jumps such as da capo and dal segno, and endings that are never reached are not expanded.
+pub(crate) fn performance_order(measures: &[&Measure]) -> Vec<(usize, u32)> {
+  struct Repeats {
+    forward: bool,
+    backward: Option<u32>,
+    ending: Option<Vec<u32>>,
+    ending_stop: bool,
+  }
+  let repeats: Vec<Repeats> = measures
+    .iter()
+    .map(|measure| {
+      let mut repeats = Repeats {
+        forward: false,
+        backward: None,
+        ending: None,
+        ending_stop: false,
+      };
+      for element in &measure.content {
+        let MeasureElement::Barline(barline) = element else {
+          continue;
+        };
+        if let Some(repeat) = &barline.content.repeat {
+          match repeat.attributes.direction {
+            BackwardForward::Forward => repeats.forward = true,
+            BackwardForward::Backward => {
+              repeats.backward = Some(repeat.attributes.times.as_ref().map_or(2, |times| **times));
+            }
+          }
+        }
+        if let Some(ending) = &barline.content.ending {
+          match ending.attributes.r#type {
+            StartStopDiscontinue::Start => {
+              repeats.ending = Some(
+                ending
+                  .attributes
+                  .number
+                  .split(|c: char| c == ',' || c.is_whitespace())
+                  .filter_map(|number| number.trim().parse().ok())
+                  .collect(),
+              );
+            }
+            StartStopDiscontinue::Stop | StartStopDiscontinue::Discontinue => repeats.ending_stop = true,
+          }
+        }
+      }
+      repeats
+    })
+    .collect();
+
+  let mut order = Vec::new();
+  let (mut index, mut start, mut pass) = (0, 0, 1);
+  while index < repeats.len() {
+    let measure = &repeats[index];
+    if measure.forward {
+      start = index;
+    }
+    if measure.ending.as_ref().is_some_and(|numbers| !numbers.contains(&pass)) {
+      // Skip over an ending that does not apply to the current pass
+      index = (index..repeats.len())
+        .find(|&end| repeats[end].ending_stop)
+        .unwrap_or(repeats.len())
+        + 1;
+      continue;
+    }
+    order.push((index, pass));
+    match measure.backward {
+      Some(times) if pass < times => {
+        pass += 1;
+        index = start;
+        continue;
+      }
+      Some(_) => (pass, start) = (1, index + 1),
+      None if measure.ending_stop => (pass, start) = (1, index + 1),
+      None => {}
+    }
+    index += 1;
+  }
+  order
+}
+
+// --------------------------------------------------------------------------------------------------------------------
+// XML HELPERS --------------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+/// Convenience methods for building and querying raw [XmlElement] trees.
+pub(crate) trait XmlElementExt<'a>: Sized {
+  fn named(name: &str) -> Self;
+  fn with_attribute(self, name: &str, value: impl Into<String>) -> Self;
+  fn with_text(self, text: impl Into<String>) -> Self;
+  fn with_child(self, child: XmlElement<'a>) -> Self;
+  fn attribute(&self, name: &str) -> Option<&str>;
+  fn child(&self, name: &str) -> Option<&XmlElement<'a>>;
+  fn children_named<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b XmlElement<'a>>
+  where
+    'a: 'b;
+  fn descendant(&self, name: &str) -> Option<&XmlElement<'a>>;
+}
+
+impl<'a> XmlElementExt<'a> for XmlElement<'a> {
+  fn named(name: &str) -> Self {
+    XmlElement {
+      name: Cow::Owned(String::from(name)),
+      ..Default::default()
+    }
+  }
+
+  fn with_attribute(mut self, name: &str, value: impl Into<String>) -> Self {
+    self
+      .attributes
+      .push((Cow::Owned(String::from(name)), Cow::Owned(value.into())));
+    self
+  }
+
+  fn with_text(mut self, text: impl Into<String>) -> Self {
+    self.text = Cow::Owned(text.into());
+    self
+  }
+
+  fn with_child(mut self, child: XmlElement<'a>) -> Self {
+    self.elements.push(child);
+    self
+  }
+
+  fn attribute(&self, name: &str) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|(key, _)| key == name)
+      .map(|(_, value)| value.as_ref())
+  }
+
+  fn child(&self, name: &str) -> Option<&XmlElement<'a>> {
+    self.elements.iter().find(|element| element.name == name)
+  }
+
+  fn children_named<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b XmlElement<'a>>
+  where
+    'a: 'b,
+  {
+    self.elements.iter().filter(move |element| element.name == name)
+  }
+
+  fn descendant(&self, name: &str) -> Option<&XmlElement<'a>> {
+    self.elements.iter().find_map(|element| {
+      if element.name == name {
+        Some(element)
+      } else {
+        element.descendant(name)
+      }
+    })
+  }
+}
+
+/// Builds a MusicXML `<direction>` element containing a single direction type.
+pub(crate) fn direction_xml(
+  direction_type: XmlElement<'static>,
+  place: Option<&str>,
+  staff: Option<u32>,
+) -> XmlElement<'static> {
+  let mut direction = XmlElement::named("direction");
+  if let Some(place) = place {
+    direction = direction.with_attribute("placement", place);
+  }
+  direction = direction.with_child(XmlElement::named("direction-type").with_child(direction_type));
+  match staff {
+    Some(staff) => direction.with_child(XmlElement::named("staff").with_text(staff.to_string())),
+    None => direction,
+  }
+}
+
+/// Escapes the five predefined XML entities within a string.
+pub(crate) fn xml_escape(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for ch in text.chars() {
+    match ch {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&apos;"),
+      _ => escaped.push(ch),
+    }
+  }
+  escaped
+}
+
+/// Replaces predefined XML entities and numeric character references with the characters they represent.
+pub(crate) fn xml_unescape(text: &str) -> String {
+  if !text.contains('&') {
+    return String::from(text);
+  }
+  let mut unescaped = String::with_capacity(text.len());
+  let mut remaining = text;
+  while let Some(start) = remaining.find('&') {
+    unescaped.push_str(&remaining[..start]);
+    remaining = &remaining[start..];
+    let Some(end) = remaining.find(';') else {
+      break;
+    };
+    let entity = &remaining[1..end];
+    let replacement = match entity {
+      "amp" => Some('&'),
+      "lt" => Some('<'),
+      "gt" => Some('>'),
+      "quot" => Some('"'),
+      "apos" => Some('\''),
+      _ => entity
+        .strip_prefix("#x")
+        .or_else(|| entity.strip_prefix("#X"))
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+        .and_then(char::from_u32),
+    };
+    if let Some(ch) = replacement {
+      unescaped.push(ch);
+      remaining = &remaining[end + 1..];
+    } else {
+      unescaped.push('&');
+      remaining = &remaining[1..];
+    }
+  }
+  unescaped.push_str(remaining);
+  unescaped
+}
+
+/// Recursively escapes all attribute values and text within an [XmlElement] tree prior to serialization.
+pub(crate) fn xml_escape_tree(element: &mut XmlElement) {
+  for (_, value) in &mut element.attributes {
+    if value.contains(['&', '<', '>', '"', '\'']) {
+      *value = Cow::Owned(xml_escape(value));
+    }
+  }
+  if element.text.contains(['&', '<', '>', '"', '\'']) {
+    element.text = Cow::Owned(xml_escape(&element.text));
+  }
+  element.elements.iter_mut().for_each(xml_escape_tree);
+}
+
+/// Recursively unescapes all attribute values and text within a parsed [XmlElement] tree.
+pub(crate) fn xml_unescape_tree(element: &mut XmlElement) {
+  for (_, value) in &mut element.attributes {
+    if value.contains('&') {
+      *value = Cow::Owned(xml_unescape(value));
+    }
+  }
+  if element.text.contains('&') {
+    element.text = Cow::Owned(xml_unescape(&element.text));
+  }
+  element.elements.iter_mut().for_each(xml_unescape_tree);
+}
+
+/// Resolves placeholder `<duration>` values written as quarter-note fractions (e.g. `"3/2"`) into whole divisions.
+///
+/// Importers from other formats build MusicXML trees before the required number of divisions per quarter note is
+/// known. This function computes the smallest divisions value that represents every fraction exactly, rewrites all
+/// `<duration>` elements accordingly, and fills every `<divisions>` element with the computed value.
+pub(crate) fn resolve_fractional_durations(element: &mut XmlElement) -> u32 {
+  fn collect_denominators(element: &XmlElement, divisions: &mut i64) {
+    if element.name == "duration" {
+      if let Some(fraction) = parse_fraction(&element.text) {
+        *divisions = lcm(*divisions, fraction.den);
+      }
+    }
+    for child in &element.elements {
+      collect_denominators(child, divisions);
+    }
+  }
+  fn apply_divisions(element: &mut XmlElement, divisions: i64) {
+    if element.name == "duration" {
+      if let Some(fraction) = parse_fraction(&element.text) {
+        element.text = Cow::Owned((fraction.num * divisions / fraction.den).max(1).to_string());
+      }
+    } else if element.name == "divisions" && element.elements.is_empty() {
+      element.text = Cow::Owned(divisions.to_string());
+    }
+    for child in &mut element.elements {
+      apply_divisions(child, divisions);
+    }
+  }
+  let mut divisions = 1;
+  collect_denominators(element, &mut divisions);
+  apply_divisions(element, divisions);
+  u32::try_from(divisions).unwrap_or(u32::MAX)
+}
+
+/// Parses a fraction written as either `"num/den"` or a plain integer.
+pub(crate) fn parse_fraction(text: &str) -> Option<Fraction> {
+  match text.split_once('/') {
+    Some((num, den)) => Some(Fraction::new(num.trim().parse().ok()?, den.trim().parse().ok()?)),
+    None => Some(Fraction::from_int(text.trim().parse().ok()?)),
+  }
+}
+
+/// Formats a fraction as `"num/den"`, or as a plain integer when the denominator is one.
+pub(crate) fn format_fraction(fraction: Fraction) -> String {
+  if fraction.den == 1 {
+    format!("{}", fraction.num)
+  } else {
+    format!("{}/{}", fraction.num, fraction.den)
+  }
+}
+
+/// Parses a decimal number such as `"2.375"` into an exact fraction.
+pub(crate) fn parse_decimal(text: &str) -> Option<Fraction> {
+  let text = text.trim();
+  let (negative, text) = match text.strip_prefix('-') {
+    Some(text) => (true, text),
+    None => (false, text.strip_prefix('+').unwrap_or(text)),
+  };
+  let (whole, decimals) = text.split_once('.').unwrap_or((text, ""));
+  if (whole.is_empty() && decimals.is_empty()) || decimals.len() > 12 {
+    return None;
+  }
+  let mut value = Fraction::from_int(if whole.is_empty() { 0 } else { whole.parse().ok()? });
+  if !decimals.is_empty() {
+    value += Fraction::new(decimals.parse().ok()?, 10_i64.pow(u32::try_from(decimals.len()).ok()?));
+  }
+  Some(if negative { -value } else { value })
+}
+
+/// Formats a fraction as a decimal number with at most four decimal places.
+pub(crate) fn format_decimal(fraction: Fraction) -> String {
+  let scaled = (fraction * Fraction::from_int(10_000)).rounded(Fraction::from_int(1));
+  let sign = if scaled.num < 0 { "-" } else { "" };
+  let (whole, decimals) = (scaled.num.abs() / 10_000, scaled.num.abs() % 10_000);
+  if decimals == 0 {
+    format!("{sign}{whole}")
+  } else {
+    let decimals = format!("{decimals:04}");
+    format!("{sign}{whole}.{}", decimals.trim_end_matches('0'))
+  }
+}
+
+#[cfg(test)]
+mod util_tests {
+  use super::*;
+
+  #[test]
+  fn fraction_arithmetic() {
+    let third = Fraction::new(1, 3);
+    let half = Fraction::new(2, 4);
+    assert_eq!(half, Fraction { num: 1, den: 2 });
+    assert_eq!(third + half, Fraction::new(5, 6));
+    assert_eq!(third - half, Fraction::new(-1, 6));
+    assert_eq!(third * half, Fraction::new(1, 6));
+    assert_eq!(third / half, Fraction::new(2, 3));
+    assert!(third < half);
+  }
+
+  #[test]
+  fn note_type_lengths() {
+    assert_eq!(
+      length_to_note_type(Fraction::new(3, 2)),
+      Some((NoteTypeValue::Quarter, 1))
+    );
+    assert_eq!(
+      length_to_note_type(Fraction::new(7, 4)),
+      Some((NoteTypeValue::Quarter, 2))
+    );
+    assert_eq!(length_to_note_type(Fraction::new(5, 4)), None);
+    assert_eq!(
+      split_length(Fraction::new(5, 4)),
+      vec![(NoteTypeValue::Quarter, 0), (NoteTypeValue::Sixteenth, 0)]
+    );
+  }
+
+  #[test]
+  fn repeat_performance_order() {
+    use crate::elements::ScorePartwise;
+    use crate::parser::parse_from_xml_str;
+    let score = parse_from_xml_str::<ScorePartwise>(
+      "<score-partwise><part-list><score-part id=\"P1\"><part-name/></score-part></part-list><part id=\"P1\">\
+        <measure number=\"1\"/>\
+        <measure number=\"2\"><barline location=\"left\"><repeat direction=\"forward\"/></barline></measure>\
+        <measure number=\"3\"><barline location=\"left\"><ending number=\"1, 2\" type=\"start\"/></barline>\
+          <barline location=\"right\"><ending number=\"1, 2\" type=\"stop\"/>\
+          <repeat direction=\"backward\" times=\"3\"/></barline></measure>\
+        <measure number=\"4\"><barline location=\"left\"><ending number=\"3\" type=\"start\"/></barline>\
+          <barline location=\"right\"><ending number=\"3\" type=\"discontinue\"/></barline></measure>\
+        <measure number=\"5\"><barline location=\"right\"><repeat direction=\"backward\"/></barline></measure>\
+      </part></score-partwise>",
+    )
+    .unwrap();
+    let measures: Vec<&Measure> = part_measures(&score.content.part[0]).collect();
+    assert_eq!(
+      performance_order(&measures),
+      vec![(0, 1), (1, 1), (2, 1), (1, 2), (2, 2), (1, 3), (3, 3), (4, 1), (4, 2)]
+    );
+  }
+}
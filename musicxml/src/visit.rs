@@ -0,0 +1,622 @@
+use crate::elements::*;
+use crate::util::{note_duration, note_is_chord};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::marker::PhantomData;
+use musicxml_internal::XmlAttribute;
+use musicxml_macros::element_visitors;
+
+/// A MusicXML element reached while walking an element tree.
+///
+/// Elements can be downcast to their concrete types by upcasting them to [Any].
+pub trait WalkElement: Any + Walk {
+  /// Returns the serialized attributes of the element.
+  fn attributes(&self) -> Vec<XmlAttribute<'static>>;
+
+  /// Returns the serialized text of the element, which is empty for elements that contain other elements.
+  fn text(&self) -> String;
+
+  /// Passes the element to the [Visitor] method named after its type, such as [Visitor::visit_note].
+  fn visit_with(&self, visitor: &mut dyn Visitor, context: &VisitContext);
+
+  /// Passes the element to the [Visitor] method called after its children, such as [Visitor::leave_note].
+  fn leave_with(&self, visitor: &mut dyn Visitor, context: &VisitContext);
+
+  /// Passes the element to the [VisitorMut] method named after its type, such as [VisitorMut::visit_note_mut].
+  fn visit_with_mut(&mut self, visitor: &mut dyn VisitorMut, context: &VisitContext);
+
+  /// Passes the element to the [VisitorMut] method called after its children, such as [VisitorMut::leave_note_mut].
+  fn leave_with_mut(&mut self, visitor: &mut dyn VisitorMut, context: &VisitContext);
+}
+
+/// Receives every MusicXML element reached while walking an element tree, along with its element name.
+///
+/// The [enter][Walker::enter] method is called before any children of an element are walked and the
+/// [leave][Walker::leave] method is called after all of them have been walked. Content structures and enumerations
+/// that only group other elements are not reported themselves.
+pub trait Walker<'a> {
+  /// Called for each element before any of its children are walked.
+  fn enter(&mut self, name: &'static str, element: &'a dyn WalkElement);
+
+  /// Called for each element after all of its children have been walked.
+  fn leave(&mut self, name: &'static str, element: &'a dyn WalkElement);
+}
+
+/// Receives a mutable reference to every MusicXML element reached while walking an element tree.
+///
+/// Children are walked after [enter][WalkerMut::enter] returns, so any changes made to an element determine which
+/// of its children are subsequently walked.
+pub trait WalkerMut {
+  /// Called for each element before any of its children are walked.
+  fn enter(&mut self, name: &'static str, element: &mut dyn WalkElement);
+
+  /// Called for each element after all of its children have been walked.
+  fn leave(&mut self, name: &'static str, element: &mut dyn WalkElement);
+}
+
+/// Walks an element and all of its descendant elements in document order.
+pub trait Walk {
+  /// Walks the element and its descendants.
+  fn walk<'a>(&'a self, walker: &mut dyn Walker<'a>);
+
+  /// Walks the element and its descendants mutably.
+  fn walk_mut(&mut self, walker: &mut dyn WalkerMut);
+
+  /// Walks the element under the name of the enumeration variant that contains it.
+  fn walk_renamed<'a>(&'a self, _name: &'static str, walker: &mut dyn Walker<'a>) {
+    self.walk(walker);
+  }
+
+  /// Walks the element mutably under the name of the enumeration variant that contains it.
+  fn walk_renamed_mut(&mut self, _name: &'static str, walker: &mut dyn WalkerMut) {
+    self.walk_mut(walker);
+  }
+}
+
+impl<T: Walk> Walk for Option<T> {
+  fn walk<'a>(&'a self, walker: &mut dyn Walker<'a>) {
+    if let Some(element) = self {
+      element.walk(walker);
+    }
+  }
+
+  fn walk_mut(&mut self, walker: &mut dyn WalkerMut) {
+    if let Some(element) = self {
+      element.walk_mut(walker);
+    }
+  }
+
+  fn walk_renamed<'a>(&'a self, name: &'static str, walker: &mut dyn Walker<'a>) {
+    if let Some(element) = self {
+      element.walk_renamed(name, walker);
+    }
+  }
+
+  fn walk_renamed_mut(&mut self, name: &'static str, walker: &mut dyn WalkerMut) {
+    if let Some(element) = self {
+      element.walk_renamed_mut(name, walker);
+    }
+  }
+}
+
+impl<T: Walk> Walk for Vec<T> {
+  fn walk<'a>(&'a self, walker: &mut dyn Walker<'a>) {
+    for element in self {
+      element.walk(walker);
+    }
+  }
+
+  fn walk_mut(&mut self, walker: &mut dyn WalkerMut) {
+    for element in self {
+      element.walk_mut(walker);
+    }
+  }
+
+  fn walk_renamed<'a>(&'a self, name: &'static str, walker: &mut dyn Walker<'a>) {
+    for element in self {
+      element.walk_renamed(name, walker);
+    }
+  }
+
+  fn walk_renamed_mut(&mut self, name: &'static str, walker: &mut dyn WalkerMut) {
+    for element in self {
+      element.walk_renamed_mut(name, walker);
+    }
+  }
+}
+
+/// Location of an element within a score at the time it is visited.
+///
+/// Elements nested inside a [Note], [Direction], [Forward], or [Harmony] element share the context of that
+/// element, so a [Fermata] reports the voice, staff, and onset of the note it is attached to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisitContext {
+  /// The `id` of the enclosing [Part], if any.
+  pub part_id: Option<String>,
+  /// The `number` of the enclosing [Measure], if any.
+  pub measure_number: Option<String>,
+  /// The voice of the enclosing note, direction, or forward element, if specified.
+  pub voice: Option<String>,
+  /// The staff of the enclosing note, direction, forward, or harmony element, if specified.
+  pub staff: Option<u32>,
+  /// The onset of the element in divisions, relative to the start of the enclosing measure.
+  pub onset: u32,
+  /// The number of divisions per quarter note in effect for the enclosing part.
+  pub divisions: u32,
+}
+
+impl Default for VisitContext {
+  fn default() -> Self {
+    VisitContext {
+      part_id: None,
+      measure_number: None,
+      voice: None,
+      staff: None,
+      onset: 0,
+      divisions: 1,
+    }
+  }
+}
+
+element_visitors! {
+  /// Receives every element of a score in document order, along with its [VisitContext].
+  ///
+  /// Every element type has a pair of methods named after it, such as [visit_note][Visitor::visit_note] and
+  /// [leave_note][Visitor::leave_note], which do nothing by default. A visitor overrides the methods for the element
+  /// types it is interested in. See [for_each] for visiting only a single element type.
+  Visitor;
+  /// Receives a mutable reference to every element of a score in document order, along with its [VisitContext].
+  ///
+  /// Children of an element are visited after its `visit_*_mut` method returns, so any children added or removed by
+  /// the visitor are taken into account. See [for_each_mut] for modifying only a single element type.
+  VisitorMut;
+  Accent, Accidental, AccidentalMark, AccidentalText, Accord, AccordionHigh, AccordionLow, AccordionMiddle,
+  AccordionRegistration, ActualNotes, Alter, Appearance, Arpeggiate, Arrow, ArrowDirection, ArrowStyle, Arrowhead,
+  Articulations, Artificial, Assess, Attributes, Backup, BarStyle, Barline, Barre, BasePitch, Bass, BassAlter,
+  BassSeparator, BassStep, Beam, BeatRepeat, BeatType, BeatUnit, BeatUnitDot, BeatUnitTied, Beater, Beats, Bend,
+  BendAlter, Bookmark, BottomMargin, Bracket, BrassBend, BreathMark, Caesura, Cancel, Capo, Chord, Chromatic,
+  CircularArrow, Clef, ClefOctaveChange, Coda, ConcertScore, Creator, Credit, CreditImage, CreditSymbol, CreditType,
+  CreditWords, Cue, Damp, DampAll, Dashes, Defaults, Degree, DegreeAlter, DegreeType, DegreeValue,
+  DelayedInvertedTurn, DelayedTurn, DetachedLegato, Diatonic, Direction, DirectionType, Directive, DisplayOctave,
+  DisplayStep, DisplayText, Distance, Divisions, Doit, Dot, Double, DoubleTongue, DownBow, Duration, Dynamics,
+  Effect, Elevation, Elision, Encoder, Encoding, EncodingDate, EncodingDescription, EndLine, EndParagraph, Ending,
+  Ensemble, ExceptVoice, Extend, Eyeglasses, F, Falloff, Feature, Fermata, Ff, Fff, Ffff, Fffff, Ffffff, Fifths,
+  Figure, FigureNumber, FiguredBass, Fingering, Fingernails, First, FirstFret, Flip, Footnote, ForPart, Forward, Fp,
+  Frame, FrameFrets, FrameNote, FrameStrings, Fret, Function, Fz, Glass, Glissando, Glyph, Golpe, Grace, Group,
+  GroupAbbreviation, GroupAbbreviationDisplay, GroupBarline, GroupLink, GroupName, GroupNameDisplay, GroupSymbol,
+  GroupTime, Grouping, HalfMuted, HammerOn, Handbell, HarmonClosed, HarmonMute, Harmonic, Harmony, HarpPedals, Haydn,
+  Heel, Hole, HoleClosed, HoleShape, HoleType, Humming, Identification, Image, Instrument, InstrumentAbbreviation,
+  InstrumentChange, InstrumentLink, InstrumentName, InstrumentSound, Instruments, Interchangeable, Inversion,
+  InvertedMordent, InvertedTurn, InvertedVerticalTurn, Ipa, Key, KeyAccidental, KeyAlter, KeyOctave, KeyStep, Kind,
+  Laughing, LeftDivider, LeftMargin, Level, Line, LineDetail, LineWidth, Link, Listen, Listening, Lyric, LyricFont,
+  LyricLanguage, Measure, MeasureDistance, MeasureLayout, MeasureNumbering, MeasureRepeat, MeasureStyle, Membrane,
+  Metal, Metronome, MetronomeArrows, MetronomeBeam, MetronomeDot, MetronomeNote, MetronomeRelation, MetronomeTied,
+  MetronomeTuplet, MetronomeType, Mf, MidiBank, MidiChannel, MidiDevice, MidiInstrument, MidiName, MidiProgram,
+  MidiUnpitched, Millimeters, Miscellaneous, MiscellaneousField, Mode, Mordent, MovementNumber, MovementTitle, Mp,
+  MultipleRest, MusicFont, Mute, N, Natural, NonArpeggiate, NormalDot, NormalNotes, NormalType, Notations, Note,
+  NoteSize, Notehead, NoteheadText, Numeral, NumeralAlter, NumeralFifths, NumeralKey, NumeralMode, NumeralRoot,
+  Octave, OctaveChange, OctaveShift, Offset, Open, OpenString, Opus, Ornaments, OtherAppearance, OtherArticulation,
+  OtherDirection, OtherDynamics, OtherListen, OtherListening, OtherNotation, OtherOrnament, OtherPercussion,
+  OtherPlay, OtherTechnical, P, PageHeight, PageLayout, PageMargins, PageWidth, Pan, Part, PartAbbreviation,
+  PartAbbreviationDisplay, PartClef, PartGroup, PartLink, PartList, PartName, PartNameDisplay, PartSymbol,
+  PartTranspose, Pedal, PedalAlter, PedalStep, PedalTuning, PerMinute, Percussion, Pf, Pitch, Pitched, Play, Player,
+  PlayerName, Plop, Pluck, Pp, Ppp, Pppp, Ppppp, Pppppp, PreBend, Prefix, PrincipalVoice, Print, PullOff, Rehearsal,
+  Relation, Release, Repeat, Rest, Rf, Rfz, RightDivider, RightMargin, Rights, Root, RootAlter, RootStep, Scaling,
+  Schleifer, Scoop, Scordatura, ScoreInstrument, ScorePart, ScorePartwise, ScoreTimewise, Second, Segno, SemiPitched,
+  SenzaMisura, Sf, Sffz, Sfp, Sfpp, Sfz, Sfzp, Shake, Sign, Slash, SlashDot, SlashType, Slide, Slur, Smear,
+  SnapPizzicato, SoftAccent, Software, Solo, Sound, SoundingPitch, Source, Spiccato, Staccatissimo, Staccato, Staff,
+  StaffDetails, StaffDistance, StaffDivide, StaffLayout, StaffLines, StaffSize, StaffTuning, StaffType, Staves, Stem,
+  Step, Stick, StickLocation, StickMaterial, StickType, Stopped, Straight, Stress, StringMute, StringNumber,
+  StrongAccent, Suffix, Supports, Swing, SwingStyle, SwingType, Syllabic, Symbol, Sync, SystemDistance,
+  SystemDividers, SystemLayout, SystemMargins, Tap, Technical, Tenths, Tenuto, Text, ThumbPosition, Tie, Tied, Time,
+  TimeModification, TimeRelation, Timpani, Toe, TopMargin, TopSystemDistance, TouchingPitch, Transpose, Tremolo,
+  TrillMark, TripleTongue, TuningAlter, TuningOctave, TuningStep, Tuplet, TupletActual, TupletDot, TupletNormal,
+  TupletNumber, TupletType, Turn, Type, Unpitched, Unstress, UpBow, VerticalTurn, VirtualInstrument, VirtualLibrary,
+  VirtualName, Voice, Volume, Wait, WavyLine, Wedge, WithBar, Wood, WordFont, Words, Work, WorkNumber, WorkTitle,
+}
+
+/// Keeps the [VisitContext] up to date as elements are entered and left.
+#[derive(Default)]
+pub(crate) struct ContextTracker {
+  pub context: VisitContext,
+  position: u32,
+  chord_onset: u32,
+  part_divisions: BTreeMap<String, u32>,
+}
+
+impl ContextTracker {
+  fn reset_position(&mut self) {
+    self.position = 0;
+    self.chord_onset = 0;
+    self.context.onset = 0;
+  }
+
+  pub fn enter(&mut self, element: &dyn Any) {
+    if let Some(part) = element.downcast_ref::<Part>() {
+      self.context.divisions = self
+        .part_divisions
+        .get(part.attributes.id.as_str())
+        .copied()
+        .unwrap_or(1);
+      self.context.part_id = Some(part.attributes.id.to_string());
+      self.reset_position();
+    } else if let Some(measure) = element.downcast_ref::<Measure>() {
+      self.context.measure_number = Some(measure.attributes.number.to_string());
+      self.reset_position();
+    } else if let Some(note) = element.downcast_ref::<Note>() {
+      if !note_is_chord(note) {
+        self.chord_onset = self.position;
+      }
+      self.context.onset = self.chord_onset;
+      self.context.voice = note.content.voice.as_ref().map(|voice| voice.content.clone());
+      self.context.staff = note.content.staff.as_ref().map(|staff| *staff.content);
+    } else if let Some(direction) = element.downcast_ref::<Direction>() {
+      self.context.voice = direction.content.voice.as_ref().map(|voice| voice.content.clone());
+      self.context.staff = direction.content.staff.as_ref().map(|staff| *staff.content);
+    } else if let Some(forward) = element.downcast_ref::<Forward>() {
+      self.context.voice = forward.content.voice.as_ref().map(|voice| voice.content.clone());
+      self.context.staff = forward.content.staff.as_ref().map(|staff| *staff.content);
+    } else if let Some(harmony) = element.downcast_ref::<Harmony>() {
+      self.context.staff = harmony.content.staff.as_ref().map(|staff| *staff.content);
+    } else if let Some(attributes) = element.downcast_ref::<Attributes>() {
+      if let Some(divisions) = &attributes.content.divisions {
+        self.context.divisions = (*divisions.content).max(1);
+        if let Some(part_id) = &self.context.part_id {
+          self.part_divisions.insert(part_id.clone(), self.context.divisions);
+        }
+      }
+    }
+  }
+
+  pub fn leave(&mut self, element: &dyn Any) {
+    if element.is::<Part>() {
+      self.context.part_id = None;
+    } else if element.is::<Measure>() {
+      self.context.measure_number = None;
+    } else if let Some(note) = element.downcast_ref::<Note>() {
+      if !note_is_chord(note) {
+        self.position = self.chord_onset + note_duration(note);
+      }
+    } else if let Some(backup) = element.downcast_ref::<Backup>() {
+      self.position = self.position.saturating_sub(*backup.content.duration.content);
+    } else if let Some(forward) = element.downcast_ref::<Forward>() {
+      self.position += *forward.content.duration.content;
+    } else if !element.is::<Direction>() && !element.is::<Harmony>() {
+      return;
+    }
+    self.context.onset = self.position;
+    self.context.voice = None;
+    self.context.staff = None;
+  }
+}
+
+struct VisitWalker<'a, V: ?Sized> {
+  visitor: &'a mut V,
+  tracker: ContextTracker,
+}
+
+impl Walker<'_> for VisitWalker<'_, dyn Visitor + '_> {
+  fn enter(&mut self, _name: &'static str, element: &dyn WalkElement) {
+    self.tracker.enter(element);
+    element.visit_with(self.visitor, &self.tracker.context);
+  }
+
+  fn leave(&mut self, _name: &'static str, element: &dyn WalkElement) {
+    element.leave_with(self.visitor, &self.tracker.context);
+    self.tracker.leave(element);
+  }
+}
+
+impl WalkerMut for VisitWalker<'_, dyn VisitorMut + '_> {
+  fn enter(&mut self, _name: &'static str, element: &mut dyn WalkElement) {
+    self.tracker.enter(element);
+    element.visit_with_mut(self.visitor, &self.tracker.context);
+  }
+
+  fn leave(&mut self, _name: &'static str, element: &mut dyn WalkElement) {
+    element.leave_with_mut(self.visitor, &self.tracker.context);
+    self.tracker.leave(element);
+  }
+}
+
+/// Passes every element of type `E` to a callback, retaining the lifetime of the walked element tree.
+struct ForEachWalker<F, E> {
+  callback: F,
+  tracker: ContextTracker,
+  element_type: PhantomData<fn(&E)>,
+}
+
+impl<'a, E: Any, F: FnMut(&'a E, &VisitContext)> Walker<'a> for ForEachWalker<F, E> {
+  fn enter(&mut self, _name: &'static str, element: &'a dyn WalkElement) {
+    self.tracker.enter(element);
+    let element: &'a dyn Any = element;
+    if let Some(element) = element.downcast_ref::<E>() {
+      (self.callback)(element, &self.tracker.context);
+    }
+  }
+
+  fn leave(&mut self, _name: &'static str, element: &'a dyn WalkElement) {
+    self.tracker.leave(element);
+  }
+}
+
+impl<E: Any, F: FnMut(&mut E, &VisitContext)> WalkerMut for ForEachWalker<F, E> {
+  fn enter(&mut self, _name: &'static str, element: &mut dyn WalkElement) {
+    self.tracker.enter(element);
+    let element: &mut dyn Any = element;
+    if let Some(element) = element.downcast_mut::<E>() {
+      (self.callback)(element, &self.tracker.context);
+    }
+  }
+
+  fn leave(&mut self, _name: &'static str, element: &mut dyn WalkElement) {
+    self.tracker.leave(element);
+  }
+}
+
+/// Visits every element contained within `root`, including `root` itself, in document order.
+///
+/// The `root` is typically a [ScorePartwise] or
+/// [ScoreTimewise], but any element may be walked.
+pub fn visit<T: Walk + ?Sized, V: Visitor>(root: &T, visitor: &mut V) {
+  root.walk(&mut VisitWalker {
+    visitor: visitor as &mut dyn Visitor,
+    tracker: ContextTracker::default(),
+  });
+}
+
+/// Visits a mutable reference to every element contained within `root`, including `root` itself, in document order.
+pub fn visit_mut<T: Walk + ?Sized, V: VisitorMut>(root: &mut T, visitor: &mut V) {
+  root.walk_mut(&mut VisitWalker {
+    visitor: visitor as &mut dyn VisitorMut,
+    tracker: ContextTracker::default(),
+  });
+}
+
+/// Calls `callback` for every element of type `E` contained within `root`, in document order.
+///
+/// # Example
+///
+/// ```no_run
+/// use musicxml::elements::Dynamics;
+/// use musicxml::{read_score_partwise, visit};
+///
+/// let score = read_score_partwise("path/to/file.musicxml").unwrap();
+/// visit::for_each(&score, |dynamics: &Dynamics, context| {
+///   println!("{:?} in measure {:?} of part {:?}", dynamics, context.measure_number, context.part_id);
+/// });
+/// ```
+pub fn for_each<'a, E: Any, T: Walk + ?Sized>(root: &'a T, callback: impl FnMut(&'a E, &VisitContext)) {
+  root.walk(&mut ForEachWalker {
+    callback,
+    tracker: ContextTracker::default(),
+    element_type: PhantomData,
+  });
+}
+
+/// Calls `callback` with a mutable reference to every element of type `E` contained within `root`, in document order.
+pub fn for_each_mut<E: Any, T: Walk + ?Sized>(root: &mut T, callback: impl FnMut(&mut E, &VisitContext)) {
+  root.walk_mut(&mut ForEachWalker {
+    callback,
+    tracker: ContextTracker::default(),
+    element_type: PhantomData,
+  });
+}
+
+#[cfg(test)]
+mod visit_tests {
+  use super::*;
+  use crate::parser::{parse_from_xml_str, parse_score_timewise_from_data};
+  use alloc::vec::Vec;
+
+  const SCORE: &str = r#"<score-partwise version="4.0">
+    <part-list>
+      <score-part id="P1"><part-name>Piano</part-name></score-part>
+      <score-part id="P2"><part-name>Violin</part-name></score-part>
+    </part-list>
+    <part id="P1">
+      <measure number="1">
+        <attributes><divisions>2</divisions></attributes>
+        <direction placement="below">
+          <direction-type><dynamics><p/></dynamics></direction-type>
+          <staff>2</staff>
+        </direction>
+        <note><pitch><step>C</step><octave>4</octave></pitch><duration>2</duration><voice>1</voice><staff>1</staff></note>
+        <note><chord/><pitch><step>E</step><octave>4</octave></pitch><duration>2</duration><voice>1</voice><staff>1</staff></note>
+        <note>
+          <pitch><step>G</step><octave>4</octave></pitch><duration>2</duration><voice>1</voice><staff>1</staff>
+          <notations><fermata/></notations>
+        </note>
+        <backup><duration>4</duration></backup>
+        <forward><duration>1</duration><voice>2</voice><staff>2</staff></forward>
+        <note><pitch><step>C</step><octave>3</octave></pitch><duration>3</duration><voice>2</voice><staff>2</staff></note>
+        <direction>
+          <direction-type><dynamics><f/></dynamics></direction-type>
+        </direction>
+      </measure>
+      <measure number="2">
+        <note>
+          <rest/><duration>4</duration><voice>1</voice>
+          <notations><fermata/></notations>
+        </note>
+      </measure>
+    </part>
+    <part id="P2">
+      <measure number="1">
+        <note><pitch><step>A</step><octave>4</octave></pitch><duration>1</duration></note>
+      </measure>
+      <measure number="2">
+        <note><pitch><step>B</step><octave>4</octave></pitch><duration>1</duration></note>
+      </measure>
+    </part>
+  </score-partwise>"#;
+
+  #[test]
+  fn visit_dynamics_and_fermatas() {
+    let score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    let mut dynamics = Vec::new();
+    for_each(&score, |_: &Dynamics, context| {
+      dynamics.push((context.measure_number.clone().unwrap(), context.staff, context.onset));
+    });
+    assert_eq!(
+      dynamics,
+      vec![(String::from("1"), Some(2), 0), (String::from("1"), None, 4)]
+    );
+    let mut fermatas = Vec::new();
+    for_each(&score, |_: &Fermata, context| {
+      fermatas.push((
+        context.part_id.clone().unwrap(),
+        context.measure_number.clone().unwrap(),
+        context.voice.clone(),
+        context.onset,
+      ));
+    });
+    assert_eq!(
+      fermatas,
+      vec![
+        (String::from("P1"), String::from("1"), Some(String::from("1")), 2),
+        (String::from("P1"), String::from("2"), Some(String::from("1")), 0),
+      ]
+    );
+  }
+
+  #[test]
+  fn visit_note_onsets() {
+    let mut onsets = Vec::new();
+    for_each(
+      &parse_from_xml_str::<ScorePartwise>(SCORE).unwrap(),
+      |note: &Note, context| {
+        onsets.push((
+          context.part_id.clone().unwrap(),
+          context.voice.clone(),
+          context.staff,
+          context.onset,
+          context.divisions,
+          note_duration(note),
+        ));
+      },
+    );
+    let p1 = || String::from("P1");
+    let p2 = || String::from("P2");
+    let voice = |voice: &str| Some(String::from(voice));
+    assert_eq!(
+      onsets,
+      vec![
+        (p1(), voice("1"), Some(1), 0, 2, 2),
+        (p1(), voice("1"), Some(1), 0, 2, 2),
+        (p1(), voice("1"), Some(1), 2, 2, 2),
+        (p1(), voice("2"), Some(2), 1, 2, 3),
+        (p1(), voice("1"), None, 0, 2, 4),
+        (p2(), None, None, 0, 1, 1),
+        (p2(), None, None, 0, 1, 1),
+      ]
+    );
+  }
+
+  #[test]
+  fn visit_enter_and_leave() {
+    #[derive(Default)]
+    struct Recorder(Vec<String>, u32);
+    impl Visitor for Recorder {
+      fn visit_pitch(&mut self, _element: &Pitch, _context: &VisitContext) {
+        self.0.push(String::from("enter pitch"));
+      }
+
+      fn leave_pitch(&mut self, _element: &Pitch, _context: &VisitContext) {
+        self.0.push(String::from("leave pitch"));
+      }
+
+      fn visit_step(&mut self, step: &Step, _context: &VisitContext) {
+        self.0.push(format!("step {:?}", step.content));
+      }
+
+      fn visit_octave(&mut self, _element: &Octave, _context: &VisitContext) {
+        self.0.push(String::from("octave"));
+      }
+
+      fn leave_duration(&mut self, duration: &Duration, _context: &VisitContext) {
+        self.1 = *duration.content;
+      }
+    }
+
+    let note = parse_from_xml_str::<Note>(
+      "<note><pitch><step>D</step><octave>5</octave></pitch><duration>3</duration><type>quarter</type></note>",
+    )
+    .unwrap();
+    let mut recorder = Recorder::default();
+    visit(&note, &mut recorder);
+    assert_eq!(recorder.0, vec!["enter pitch", "step D", "octave", "leave pitch"]);
+    assert_eq!(recorder.1, 3);
+  }
+
+  #[test]
+  fn visit_mut_rests() {
+    struct Rests;
+    impl VisitorMut for Rests {
+      fn visit_note_mut(&mut self, note: &mut Note, context: &VisitContext) {
+        if context.part_id.as_deref() == Some("P2") {
+          if let NoteType::Normal(info) = &mut note.content.info {
+            info.audible = AudibleType::Rest(Rest {
+              attributes: RestAttributes::default(),
+              content: RestContents {
+                display_step: None,
+                display_octave: None,
+              },
+            });
+          }
+        }
+      }
+
+      fn visit_step_mut(&mut self, _element: &mut Step, context: &VisitContext) {
+        assert_ne!(context.part_id.as_deref(), Some("P2"));
+      }
+    }
+
+    let mut score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    visit_mut(&mut score, &mut Rests);
+    let mut steps = 0;
+    for_each(&score, |_: &Step, _| steps += 1);
+    assert_eq!(steps, 4);
+  }
+
+  #[test]
+  fn visit_mut_transpose() {
+    let mut score = parse_from_xml_str::<ScorePartwise>(SCORE).unwrap();
+    for_each_mut(&mut score, |octave: &mut Octave, context| {
+      if context.part_id.as_deref() == Some("P2") {
+        octave.content.0 += 1;
+      }
+    });
+    for_each_mut(&mut score, |note: &mut Note, _| {
+      note.content.staff = None;
+    });
+    let mut octaves = Vec::new();
+    for_each(&score, |octave: &Octave, context| {
+      octaves.push((*octave.content, context.staff))
+    });
+    assert_eq!(
+      octaves,
+      vec![(4, None), (4, None), (4, None), (3, None), (5, None), (5, None)]
+    );
+  }
+
+  #[test]
+  fn visit_timewise() {
+    let score: ScoreTimewise = parse_score_timewise_from_data(SCORE.as_bytes().to_vec()).unwrap();
+    let mut onsets = Vec::new();
+    for_each(&score, |_: &Note, context| {
+      onsets.push((
+        context.measure_number.clone().unwrap(),
+        context.part_id.clone().unwrap(),
+        context.onset,
+        context.divisions,
+      ));
+    });
+    assert_eq!(onsets.len(), 7);
+    assert_eq!(onsets[3], (String::from("1"), String::from("P1"), 1, 2));
+    assert_eq!(onsets[4], (String::from("1"), String::from("P2"), 0, 1));
+    assert_eq!(onsets[5], (String::from("2"), String::from("P1"), 0, 2));
+  }
+}
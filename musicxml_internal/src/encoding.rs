@@ -0,0 +1,205 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Characters represented by bytes `0x80` through `0x9F` in Windows-1252.
+///
+/// Bytes that are undefined in Windows-1252 map to the C1 control character with the same value.
+const WINDOWS_1252_HIGH: [char; 32] = [
+  '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}', '\u{02C6}',
+  '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}', '\u{0090}', '\u{2018}',
+  '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}', '\u{02DC}', '\u{2122}', '\u{0161}',
+  '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+  Utf8,
+  Utf16Le,
+  Utf16Be,
+  Latin1,
+  Windows1252,
+}
+
+fn detect_byte_order_mark(bytes: &[u8]) -> Option<(Encoding, usize)> {
+  match bytes {
+    [0xEF, 0xBB, 0xBF, ..] => Some((Encoding::Utf8, 3)),
+    [0xFF, 0xFE, ..] => Some((Encoding::Utf16Le, 2)),
+    [0xFE, 0xFF, ..] => Some((Encoding::Utf16Be, 2)),
+    [b'<', 0, b'?', 0, ..] => Some((Encoding::Utf16Le, 0)),
+    [0, b'<', 0, b'?', ..] => Some((Encoding::Utf16Be, 0)),
+    _ => None,
+  }
+}
+
+fn declared_encoding(bytes: &[u8]) -> Option<String> {
+  let declaration = bytes.strip_prefix(b"<?xml")?;
+  let declaration = &declaration[..declaration.windows(2).position(|window| window == b"?>")?];
+  let position = declaration.windows(8).position(|window| window == b"encoding")?;
+  let value = declaration[position + 8..]
+    .trim_ascii_start()
+    .strip_prefix(b"=")?
+    .trim_ascii_start();
+  let (&quote, value) = value.split_first()?;
+  if quote != b'"' && quote != b'\'' {
+    return None;
+  }
+  let value = &value[..value.iter().position(|&byte| byte == quote)?];
+  Some(value.iter().map(|&byte| char::from(byte)).collect())
+}
+
+fn encoding_from_name(name: &str) -> Result<Encoding, String> {
+  match name.to_ascii_lowercase().as_str() {
+    "utf-8" | "utf8" | "us-ascii" | "ascii" => Ok(Encoding::Utf8),
+    "utf-16le" => Ok(Encoding::Utf16Le),
+    "utf-16" | "utf-16be" => Ok(Encoding::Utf16Be),
+    "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "latin1" | "latin-1" | "l1" | "cp819" => Ok(Encoding::Latin1),
+    "windows-1252" | "cp1252" | "x-cp1252" => Ok(Encoding::Windows1252),
+    _ => Err(format!("Unsupported character encoding \"{name}\" in MusicXML content")),
+  }
+}
+
+fn decode(bytes: &[u8], encoding: Encoding) -> Result<String, String> {
+  match encoding {
+    Encoding::Utf8 => {
+      String::from_utf8(bytes.to_vec()).map_err(|_| String::from("Invalid UTF-8 data in MusicXML content"))
+    }
+    Encoding::Utf16Le | Encoding::Utf16Be => {
+      let chunks = bytes.chunks_exact(2);
+      if !chunks.remainder().is_empty() {
+        return Err(String::from("Truncated UTF-16 data in MusicXML content"));
+      }
+      let convert = if encoding == Encoding::Utf16Le {
+        u16::from_le_bytes
+      } else {
+        u16::from_be_bytes
+      };
+      let units: Vec<u16> = chunks.map(|bytes| convert([bytes[0], bytes[1]])).collect();
+      String::from_utf16(&units).map_err(|_| String::from("Invalid UTF-16 data in MusicXML content"))
+    }
+    Encoding::Latin1 => Ok(bytes.iter().map(|&byte| char::from(byte)).collect()),
+    Encoding::Windows1252 => Ok(
+      bytes
+        .iter()
+        .map(|&byte| match byte {
+          0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+          _ => char::from(byte),
+        })
+        .collect(),
+    ),
+  }
+}
+
+/// Decodes the raw bytes of an XML document into a string.
+///
+/// A byte order mark takes precedence, followed by UTF-16 content detected from the `<?` that begins its XML
+/// declaration, and then the `encoding` named in the XML declaration. Documents with none of these are decoded as
+/// UTF-8. UTF-8, UTF-16LE, UTF-16BE, ISO-8859-1 and Windows-1252 are supported, and any byte order mark is removed
+/// from the decoded string.
+///
+/// # Errors
+///
+/// If the declared encoding is not supported, a UTF-16 encoding is declared by content that is not encoded as
+/// UTF-16, or the bytes are not valid in the detected encoding, an error message will be returned.
+pub fn bytes_to_string(bytes: &[u8]) -> Result<String, String> {
+  if let Some((encoding, length)) = detect_byte_order_mark(bytes) {
+    decode(&bytes[length..], encoding)
+  } else if let Some(name) = declared_encoding(bytes) {
+    match encoding_from_name(&name)? {
+      // UTF-16 content without a byte order mark is detected from its first characters, so a UTF-16 declaration
+      // that can be read as single bytes contradicts the actual encoding of the document
+      Encoding::Utf16Le | Encoding::Utf16Be => Err(format!(
+        "MusicXML content declares the \"{name}\" character encoding but is not encoded as UTF-16"
+      )),
+      encoding => decode(bytes, encoding),
+    }
+  } else {
+    decode(bytes, Encoding::Utf8)
+  }
+}
+
+#[cfg(test)]
+mod encoding_tests {
+  use super::*;
+  use alloc::vec;
+
+  fn utf16(text: &str, little_endian: bool) -> Vec<u8> {
+    text
+      .encode_utf16()
+      .flat_map(|unit| {
+        if little_endian {
+          unit.to_le_bytes()
+        } else {
+          unit.to_be_bytes()
+        }
+      })
+      .collect()
+  }
+
+  #[test]
+  fn decode_utf8() {
+    assert_eq!(bytes_to_string(b"<a>Caf\xC3\xA9</a>").unwrap(), "<a>Café</a>");
+    assert_eq!(
+      bytes_to_string(b"\xEF\xBB\xBF<a>Caf\xC3\xA9</a>").unwrap(),
+      "<a>Café</a>"
+    );
+    assert_eq!(
+      bytes_to_string(b"<?xml version=\"1.0\" encoding='utf-8'?><a>\xE2\x99\xAD</a>").unwrap(),
+      "<?xml version=\"1.0\" encoding='utf-8'?><a>♭</a>"
+    );
+    assert!(bytes_to_string(b"<a>Caf\xE9</a>").is_err());
+    assert!(bytes_to_string(b"").unwrap().is_empty());
+  }
+
+  #[test]
+  fn decode_utf16() {
+    let text = "<?xml version=\"1.0\" encoding=\"UTF-16\"?><a>E♭ 𝄞</a>";
+    for little_endian in [true, false] {
+      let mut bytes = if little_endian {
+        vec![0xFF, 0xFE]
+      } else {
+        vec![0xFE, 0xFF]
+      };
+      bytes.extend(utf16(text, little_endian));
+      assert_eq!(bytes_to_string(&bytes).unwrap(), text);
+      assert_eq!(bytes_to_string(&utf16(text, little_endian)).unwrap(), text);
+      bytes.pop();
+      assert!(bytes_to_string(&bytes).is_err());
+    }
+  }
+
+  #[test]
+  fn decode_latin1() {
+    let bytes = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><creator>Fr\xE9d\xE9ric Fran\xE7ois Chopin</creator>";
+    assert_eq!(
+      bytes_to_string(bytes).unwrap(),
+      "<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><creator>Frédéric François Chopin</creator>"
+    );
+    assert_eq!(
+      bytes_to_string(b"<?xml version='1.0' encoding = 'latin1' ?><a>\x80\xFF</a>").unwrap(),
+      "<?xml version='1.0' encoding = 'latin1' ?><a>\u{80}ÿ</a>"
+    );
+  }
+
+  #[test]
+  fn decode_windows_1252() {
+    let bytes = b"<?xml version=\"1.0\" encoding=\"windows-1252\"?><rights>\xA9 \x93Fine\x94 \x96 \x80\x81</rights>";
+    assert_eq!(
+      bytes_to_string(bytes).unwrap(),
+      "<?xml version=\"1.0\" encoding=\"windows-1252\"?><rights>© “Fine” – €\u{81}</rights>"
+    );
+  }
+
+  #[test]
+  fn decode_unsupported() {
+    assert_eq!(
+      bytes_to_string(b"<?xml version=\"1.0\" encoding=\"Shift_JIS\"?><a/>").unwrap_err(),
+      "Unsupported character encoding \"Shift_JIS\" in MusicXML content"
+    );
+    assert_eq!(
+      bytes_to_string(b"<?xml version=\"1.0\" encoding=\"UTF-16\"?><a/>").unwrap_err(),
+      "MusicXML content declares the \"UTF-16\" character encoding but is not encoded as UTF-16"
+    );
+    assert!(bytes_to_string(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?><a>\xFF</a>").is_err());
+  }
+}
@@ -2,31 +2,44 @@
 
 extern crate alloc;
 
-use alloc::borrow::ToOwned;
+mod encoding;
+
+use alloc::borrow::Cow;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
-pub fn bytes_to_string(bytes: &[u8]) -> Result<String, String> {
-  String::from_utf8(bytes.to_owned()).or_else(|_| {
-    let convert = if bytes[0] == 0xFF && bytes[1] == 0xFE {
-      u16::from_le_bytes
-    } else {
-      u16::from_be_bytes
-    };
-    let u16_bytes: Vec<u16> = bytes
-      .chunks_exact(2)
-      .map(|bytes| convert([bytes[0], bytes[1]]))
-      .collect();
-    String::from_utf16(&u16_bytes).map_err(|_| String::from("Invalid UTF-8 or UTF-16 data in MusicXML content"))
-  })
-}
-
-#[derive(Debug, Default, PartialEq, Eq)]
-pub struct XmlElement {
-  pub name: String,
-  pub attributes: Vec<(String, String)>,
-  pub elements: Vec<XmlElement>,
-  pub text: String,
+pub use encoding::bytes_to_string;
+
+/// Name and value of a single XML attribute.
+pub type XmlAttribute<'a> = (Cow<'a, str>, Cow<'a, str>);
+
+/// Intermediate representation of an XML element and all of its children.
+///
+/// Elements parsed from a document borrow their names, attributes and text from the input string wherever possible,
+/// while elements created during serialization own their contents or borrow static strings.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct XmlElement<'a> {
+  pub name: Cow<'a, str>,
+  pub attributes: Vec<XmlAttribute<'a>>,
+  pub elements: Vec<XmlElement<'a>>,
+  pub text: Cow<'a, str>,
+}
+
+impl XmlElement<'_> {
+  /// Converts the element into one that owns all of its contents and no longer borrows from its source.
+  #[must_use]
+  pub fn into_owned(self) -> XmlElement<'static> {
+    XmlElement {
+      name: Cow::Owned(self.name.into_owned()),
+      attributes: self
+        .attributes
+        .into_iter()
+        .map(|(key, value)| (Cow::Owned(key.into_owned()), Cow::Owned(value.into_owned())))
+        .collect(),
+      elements: self.elements.into_iter().map(XmlElement::into_owned).collect(),
+      text: Cow::Owned(self.text.into_owned()),
+    }
+  }
 }
 
 #[allow(clippy::missing_errors_doc)]
@@ -36,17 +49,17 @@ pub trait DatatypeDeserializer: Sized {
 
 #[allow(clippy::missing_errors_doc)]
 pub trait AttributeDeserializer: Sized {
-  fn deserialize(attributes: &[(String, String)]) -> Result<Self, String>;
+  fn deserialize(attributes: &[XmlAttribute<'_>]) -> Result<Self, String>;
 }
 
 #[allow(clippy::missing_errors_doc)]
 pub trait ContentDeserializer: Sized {
-  fn deserialize(elements: &[XmlElement]) -> Result<Self, String>;
+  fn deserialize(elements: &[XmlElement<'_>]) -> Result<Self, String>;
 }
 
 #[allow(clippy::missing_errors_doc)]
 pub trait ElementDeserializer: Sized {
-  fn deserialize(element: &XmlElement) -> Result<Self, String>;
+  fn deserialize(element: &XmlElement<'_>) -> Result<Self, String>;
 }
 
 pub trait DatatypeSerializer {
@@ -54,15 +67,15 @@ pub trait DatatypeSerializer {
 }
 
 pub trait AttributeSerializer {
-  fn serialize(element: &Self) -> Vec<(String, String)>;
+  fn serialize(element: &Self) -> Vec<XmlAttribute<'static>>;
 }
 
 pub trait ContentSerializer {
-  fn serialize(element: &Self) -> Vec<XmlElement>;
+  fn serialize(element: &Self) -> Vec<XmlElement<'static>>;
 }
 
 pub trait ElementSerializer {
-  fn serialize(element: &Self) -> XmlElement;
+  fn serialize(element: &Self) -> XmlElement<'static>;
 }
 
 impl DatatypeDeserializer for String {
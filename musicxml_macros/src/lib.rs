@@ -3,7 +3,7 @@
 extern crate alloc;
 
 use alloc::string::{String, ToString};
-use alloc::{vec, vec::Vec};
+use alloc::{format, vec, vec::Vec};
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -191,7 +191,7 @@ fn deserialize_attribute_named_struct(element_type: &syn::Ident, fields: &syn::F
               if let syn::GenericArgument::Type(syn::Type::Path(option_path)) = details.args.first().unwrap() {
                 deserialized_fields.push(quote! {
                   #field_name: match attributes.iter().find(|&el| el.0 == #field_name_string) {
-                    Some(attr) => Some(#option_path::deserialize(attr.1.as_str())?),
+                    Some(attr) => Some(#option_path::deserialize(attr.1.as_ref())?),
                     None => None,
                   }
                 });
@@ -201,7 +201,7 @@ fn deserialize_attribute_named_struct(element_type: &syn::Ident, fields: &syn::F
           _ => {
             deserialized_fields.push(quote! {
               #field_name: match attributes.iter().find(|&el| el.0 == #field_name_string) {
-                Some(attr) => #type_path::deserialize(attr.1.as_str())?,
+                Some(attr) => #type_path::deserialize(attr.1.as_ref())?,
                 None => Err(format!("Missing required attribute for '{}': {}", #element_type_string, #field_name_string))?,
               }
             });
@@ -215,7 +215,7 @@ fn deserialize_attribute_named_struct(element_type: &syn::Ident, fields: &syn::F
   // Generate the actual deserialization function
   TokenStream::from(quote! {
     impl AttributeDeserializer for #element_type {
-      fn deserialize(attributes: &[(String, String)]) -> Result<#element_type, String> {
+      fn deserialize(attributes: &[XmlAttribute<'_>]) -> Result<#element_type, String> {
         Ok(#element_type { #(#deserialized_fields),* })
       }
     }
@@ -247,14 +247,14 @@ fn serialize_attribute_named_struct(element_type: &syn::Ident, fields: &syn::Fie
               if let syn::GenericArgument::Type(syn::Type::Path(option_path)) = details.args.first().unwrap() {
                 serialized_fields.push(quote! {
                   if let Some(data) = &element.#field_name {
-                    attributes.push((String::from(#field_name_string), #option_path::serialize(data)));
+                    attributes.push((#field_name_string.into(), #option_path::serialize(data).into()));
                   }
                 });
               }
             }
           }
           _ => {
-            serialized_fields.push(quote! { attributes.push((String::from(#field_name_string), #type_path::serialize(&element.#field_name))); });
+            serialized_fields.push(quote! { attributes.push((#field_name_string.into(), #type_path::serialize(&element.#field_name).into())); });
           }
         }
       }
@@ -265,8 +265,8 @@ fn serialize_attribute_named_struct(element_type: &syn::Ident, fields: &syn::Fie
   // Generate the actual serialization function
   TokenStream::from(quote! {
     impl AttributeSerializer for #element_type {
-      fn serialize(element: &#element_type) -> Vec<(String, String)> {
-        let mut attributes: Vec<(String, String)> = Vec::new();
+      fn serialize(element: &#element_type) -> Vec<XmlAttribute<'static>> {
+        let mut attributes: Vec<XmlAttribute<'static>> = Vec::new();
         #(#serialized_fields)*;
         attributes
       }
@@ -351,7 +351,7 @@ fn deserialize_content_named_struct(element_type: &syn::Ident, fields: &syn::Fie
   // Generate the actual deserialization function
   TokenStream::from(quote! {
     impl ContentDeserializer for #element_type {
-      fn deserialize(elements: &[XmlElement]) -> Result<#element_type, String> {
+      fn deserialize(elements: &[XmlElement<'_>]) -> Result<#element_type, String> {
         Ok(#element_type { #(#deserialized_fields),* })
       }
     }
@@ -403,8 +403,8 @@ fn serialize_content_named_struct(element_type: &syn::Ident, fields: &syn::Field
   // Generate the actual serialization function
   TokenStream::from(quote! {
     impl ContentSerializer for #element_type {
-      fn serialize(element: &Self) -> Vec<XmlElement> {
-        let mut elements: Vec<XmlElement> = Vec::new();
+      fn serialize(element: &Self) -> Vec<XmlElement<'static>> {
+        let mut elements: Vec<XmlElement<'static>> = Vec::new();
         #(#serialized_fields)*;
         elements
       }
@@ -485,7 +485,7 @@ fn deserialize_element_enum(element_type: &syn::Ident, data: &syn::DataEnum) ->
   // Generate the actual deserialization function
   TokenStream::from(quote! {
     impl ElementDeserializer for #element_type {
-      fn deserialize(element: &XmlElement) -> Result<#element_type, String> {
+      fn deserialize(element: &XmlElement<'_>) -> Result<#element_type, String> {
         match element.name.as_ref() { #(#enum_arms),* }
       }
     }
@@ -526,7 +526,7 @@ fn serialize_element_enum(element_type: &syn::Ident, data: &syn::DataEnum) -> To
     enum_arms.push(quote! {
       #element_type::#variant_type(var) => {
         let mut result = #variant_type::serialize(var);
-        result.name = String::from(#variant_type_string);
+        result.name = #variant_type_string.into();
         result
       }
     });
@@ -535,7 +535,7 @@ fn serialize_element_enum(element_type: &syn::Ident, data: &syn::DataEnum) -> To
   // Generate the actual serialization function
   TokenStream::from(quote! {
     impl ElementSerializer for #element_type {
-      fn serialize(element: &Self) -> XmlElement {
+      fn serialize(element: &Self) -> XmlElement<'static> {
         match element { #(#enum_arms),* }
       }
     }
@@ -569,7 +569,7 @@ fn deserialize_element_named_struct(element_type: &syn::Ident, fields: &syn::Fie
             } else if field.attrs.iter().any(|attr| attr.path().is_ident("flatten")) {
               deserialized_fields.push(quote! { #field_name: #type_path::deserialize(&element.elements)? });
             } else {
-              deserialized_fields.push(quote! { #field_name: #type_path::deserialize(element.text.as_str())? });
+              deserialized_fields.push(quote! { #field_name: #type_path::deserialize(element.text.as_ref())? });
             }
           }
         }
@@ -581,7 +581,7 @@ fn deserialize_element_named_struct(element_type: &syn::Ident, fields: &syn::Fie
   // Generate the actual deserialization function
   TokenStream::from(quote! {
     impl ElementDeserializer for #element_type {
-      fn deserialize(element: &XmlElement) -> Result<#element_type, String> {
+      fn deserialize(element: &XmlElement<'_>) -> Result<#element_type, String> {
         Ok(#element_type { #(#deserialized_fields),* })
       }
     }
@@ -621,7 +621,7 @@ fn serialize_element_named_struct(
             } else if field.attrs.iter().any(|attr| attr.path().is_ident("flatten")) {
               serialized_fields.push(quote! { elements: #type_path::serialize(&element.content) });
             } else {
-              serialized_fields.push(quote! { text: #type_path::serialize(&element.content) });
+              serialized_fields.push(quote! { text: #type_path::serialize(&element.content).into() });
             }
           }
         }
@@ -634,9 +634,9 @@ fn serialize_element_named_struct(
   // Generate the actual serialization function
   TokenStream::from(quote! {
     impl ElementSerializer for #element_type {
-      fn serialize(element: &#element_type) -> XmlElement {
+      fn serialize(element: &#element_type) -> XmlElement<'static> {
         XmlElement {
-          name: String::from(#element_type_name),
+          name: #element_type_name.into(),
           #(#serialized_fields),*
         }
       }
@@ -683,3 +683,291 @@ pub fn serialize_element(tokens: TokenStream) -> TokenStream {
     syn::Data::Union(_) => panic!("Unions are unsupported in MusicXML elements"),
   }
 }
+
+// --------------------------------------------------------------------------------------------------------------------
+// WALK FUNCTIONALITY -------------------------------------------------------------------------------------------------
+// --------------------------------------------------------------------------------------------------------------------
+
+fn walked_element_name(attrs: &[syn::Attribute], ident: &syn::Ident) -> String {
+  match attrs.iter().find(|&attr| attr.path().is_ident("rename")) {
+    Some(attr) => match attr.meta.require_list() {
+      Ok(list) => {
+        match list.parse_args_with(syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated) {
+          Ok(names) => names.first().unwrap().value(),
+          Err(_) => attr.parse_args::<syn::LitStr>().unwrap().value(),
+        }
+      }
+      Err(_) => attr.parse_args::<syn::LitStr>().unwrap().value(),
+    },
+    None => ident.to_string().to_lowercase(),
+  }
+}
+
+fn snake_case(ident: &syn::Ident) -> String {
+  let mut name = String::new();
+  for (index, ch) in ident.to_string().chars().enumerate() {
+    if ch.is_ascii_uppercase() && index > 0 {
+      name.push('_');
+    }
+    name.push(ch.to_ascii_lowercase());
+  }
+  name
+}
+
+#[allow(clippy::panic, clippy::missing_panics_doc)]
+fn walk_enum(element_type: &syn::Ident, data: &syn::DataEnum, renamed: bool) -> TokenStream {
+  let mut enum_arms: Vec<proc_macro2::TokenStream> = Vec::new();
+  let mut enum_arms_mut: Vec<proc_macro2::TokenStream> = Vec::new();
+
+  // Walk the contents of every variant without reporting the enum itself
+  for variant in &data.variants {
+    let variant_type = &variant.ident;
+    match &variant.fields {
+      syn::Fields::Unit => {
+        enum_arms.push(quote! { #element_type::#variant_type => {} });
+        enum_arms_mut.push(quote! { #element_type::#variant_type => {} });
+      }
+      syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+        if renamed {
+          // Element enumerations determine the names of the elements they contain
+          let variant_name = walked_element_name(&variant.attrs, variant_type);
+          enum_arms.push(quote! {
+            #element_type::#variant_type(data) => crate::visit::Walk::walk_renamed(data, #variant_name, walker)
+          });
+          enum_arms_mut.push(quote! {
+            #element_type::#variant_type(data) => crate::visit::Walk::walk_renamed_mut(data, #variant_name, walker)
+          });
+        } else {
+          enum_arms.push(quote! { #element_type::#variant_type(data) => crate::visit::Walk::walk(data, walker) });
+          enum_arms_mut
+            .push(quote! { #element_type::#variant_type(data) => crate::visit::Walk::walk_mut(data, walker) });
+        }
+      }
+      _ => panic!("Only unit and single-field tuple variants can be walked in MusicXML enums"),
+    }
+  }
+
+  // Generate the actual walking functions
+  TokenStream::from(quote! {
+    impl crate::visit::Walk for #element_type {
+      fn walk<'a>(&'a self, walker: &mut dyn crate::visit::Walker<'a>) {
+        match self { #(#enum_arms),* }
+      }
+
+      fn walk_mut(&mut self, walker: &mut dyn crate::visit::WalkerMut) {
+        match self { #(#enum_arms_mut),* }
+      }
+    }
+  })
+}
+
+fn walk_content_named_struct(element_type: &syn::Ident, fields: &syn::FieldsNamed) -> TokenStream {
+  let mut walked_fields: Vec<&syn::Ident> = Vec::new();
+
+  // Every field of a content structure contains elements, except for empty placeholders
+  for field in &fields.named {
+    if !matches!(field.ty, syn::Type::Tuple(_)) {
+      walked_fields.push(field.ident.as_ref().unwrap());
+    }
+  }
+
+  // Generate the actual walking functions
+  TokenStream::from(quote! {
+    impl crate::visit::Walk for #element_type {
+      fn walk<'a>(&'a self, walker: &mut dyn crate::visit::Walker<'a>) {
+        #(crate::visit::Walk::walk(&self.#walked_fields, walker);)*
+      }
+
+      fn walk_mut(&mut self, walker: &mut dyn crate::visit::WalkerMut) {
+        #(crate::visit::Walk::walk_mut(&mut self.#walked_fields, walker);)*
+      }
+    }
+  })
+}
+
+fn walk_element_named_struct(element_type: &syn::Ident, element_name: &str, fields: &syn::FieldsNamed) -> TokenStream {
+  let mut walked_fields: Vec<&syn::Ident> = Vec::new();
+  let mut attributes = quote! { ::alloc::vec::Vec::new() };
+  let mut text = quote! { ::alloc::string::String::new() };
+
+  // Only element lists and flattened contents contain child elements, while any other content is text
+  for field in &fields.named {
+    if let syn::Type::Path(type_path) = &field.ty {
+      let field_name = field.ident.as_ref().unwrap();
+      let is_list = type_path.path.segments.first().unwrap().ident == "Vec";
+      let is_flattened = field.attrs.iter().any(|attr| attr.path().is_ident("flatten"));
+      if field_name == "attributes" {
+        attributes = quote! { AttributeSerializer::serialize(&self.attributes) };
+      } else if is_list || is_flattened {
+        walked_fields.push(field_name);
+      } else {
+        text = quote! { DatatypeSerializer::serialize(&self.#field_name) };
+      }
+    }
+  }
+
+  // Dispatch to the visitor methods named after the element type
+  let visit = quote::format_ident!("visit_{}", snake_case(element_type));
+  let leave = quote::format_ident!("leave_{}", snake_case(element_type));
+  let visit_mut = quote::format_ident!("visit_{}_mut", snake_case(element_type));
+  let leave_mut = quote::format_ident!("leave_{}_mut", snake_case(element_type));
+
+  // Generate the actual walking functions
+  TokenStream::from(quote! {
+    impl crate::visit::Walk for #element_type {
+      fn walk<'a>(&'a self, walker: &mut dyn crate::visit::Walker<'a>) {
+        crate::visit::Walk::walk_renamed(self, #element_name, walker);
+      }
+
+      fn walk_mut(&mut self, walker: &mut dyn crate::visit::WalkerMut) {
+        crate::visit::Walk::walk_renamed_mut(self, #element_name, walker);
+      }
+
+      fn walk_renamed<'a>(&'a self, name: &'static str, walker: &mut dyn crate::visit::Walker<'a>) {
+        walker.enter(name, self);
+        #(crate::visit::Walk::walk(&self.#walked_fields, walker);)*
+        walker.leave(name, self);
+      }
+
+      fn walk_renamed_mut(&mut self, name: &'static str, walker: &mut dyn crate::visit::WalkerMut) {
+        walker.enter(name, self);
+        #(crate::visit::Walk::walk_mut(&mut self.#walked_fields, walker);)*
+        walker.leave(name, self);
+      }
+    }
+
+    impl crate::visit::WalkElement for #element_type {
+      fn attributes(&self) -> ::alloc::vec::Vec<XmlAttribute<'static>> {
+        #attributes
+      }
+
+      fn text(&self) -> ::alloc::string::String {
+        #text
+      }
+
+      fn visit_with(&self, visitor: &mut dyn crate::visit::Visitor, context: &crate::visit::VisitContext) {
+        visitor.#visit(self, context);
+      }
+
+      fn leave_with(&self, visitor: &mut dyn crate::visit::Visitor, context: &crate::visit::VisitContext) {
+        visitor.#leave(self, context);
+      }
+
+      fn visit_with_mut(&mut self, visitor: &mut dyn crate::visit::VisitorMut, context: &crate::visit::VisitContext) {
+        visitor.#visit_mut(self, context);
+      }
+
+      fn leave_with_mut(&mut self, visitor: &mut dyn crate::visit::VisitorMut, context: &crate::visit::VisitContext) {
+        visitor.#leave_mut(self, context);
+      }
+    }
+  })
+}
+
+#[allow(clippy::panic, clippy::missing_panics_doc)]
+#[proc_macro_derive(ContentWalk)]
+pub fn walk_content(tokens: TokenStream) -> TokenStream {
+  let ast: syn::DeriveInput = syn::parse(tokens).unwrap();
+  match &ast.data {
+    syn::Data::Struct(data) => match &data.fields {
+      syn::Fields::Named(named_fields) => walk_content_named_struct(&ast.ident, named_fields),
+      _ => panic!("Unit and tuple structs are not supported in MusicXML content"),
+    },
+    syn::Data::Enum(data) => walk_enum(&ast.ident, data, false),
+    syn::Data::Union(_) => panic!("Unions are unsupported in MusicXML content"),
+  }
+}
+
+#[allow(clippy::panic, clippy::missing_panics_doc)]
+#[proc_macro_derive(ElementWalk, attributes(rename, flatten))]
+pub fn walk_element(tokens: TokenStream) -> TokenStream {
+  let ast: syn::DeriveInput = syn::parse(tokens).unwrap();
+  match &ast.data {
+    syn::Data::Struct(data) => match &data.fields {
+      syn::Fields::Named(named_fields) => {
+        walk_element_named_struct(&ast.ident, &walked_element_name(&ast.attrs, &ast.ident), named_fields)
+      }
+      _ => panic!("Unit and tuple structs are not supported in MusicXML elements"),
+    },
+    syn::Data::Enum(data) => walk_enum(&ast.ident, data, true),
+    syn::Data::Union(_) => panic!("Unions are unsupported in MusicXML elements"),
+  }
+}
+
+struct ElementVisitors {
+  visitor_attrs: Vec<syn::Attribute>,
+  visitor: syn::Ident,
+  visitor_mut_attrs: Vec<syn::Attribute>,
+  visitor_mut: syn::Ident,
+  elements: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]>,
+}
+
+impl syn::parse::Parse for ElementVisitors {
+  fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+    let visitor_attrs = input.call(syn::Attribute::parse_outer)?;
+    let visitor = input.parse()?;
+    input.parse::<syn::Token![;]>()?;
+    let visitor_mut_attrs = input.call(syn::Attribute::parse_outer)?;
+    let visitor_mut = input.parse()?;
+    input.parse::<syn::Token![;]>()?;
+    Ok(ElementVisitors {
+      visitor_attrs,
+      visitor,
+      visitor_mut_attrs,
+      visitor_mut,
+      elements: syn::punctuated::Punctuated::parse_terminated(input)?,
+    })
+  }
+}
+
+/// Generates a visitor trait and a mutable visitor trait with a default no-op method for entering and leaving each
+/// of the listed element types.
+///
+/// The invocation names the immutable trait followed by the mutable trait, each preceded by its documentation and
+/// terminated by a semicolon, and then lists every element type deriving `ElementWalk`.
+#[proc_macro]
+pub fn element_visitors(tokens: TokenStream) -> TokenStream {
+  let input = syn::parse_macro_input!(tokens as ElementVisitors);
+  let mut methods: Vec<proc_macro2::TokenStream> = Vec::new();
+  let mut methods_mut: Vec<proc_macro2::TokenStream> = Vec::new();
+
+  // Generate a pair of methods for every element type
+  for element_type in &input.elements {
+    let name = snake_case(element_type);
+    let visit = quote::format_ident!("visit_{}", name);
+    let leave = quote::format_ident!("leave_{}", name);
+    let visit_mut = quote::format_ident!("visit_{}_mut", name);
+    let leave_mut = quote::format_ident!("leave_{}_mut", name);
+    let visit_doc = format!(" Called for each [{element_type}] element before any of its children are visited.");
+    let leave_doc = format!(" Called for each [{element_type}] element after all of its children have been visited.");
+    methods.push(quote! {
+      #[doc = #visit_doc]
+      fn #visit(&mut self, _element: &#element_type, _context: &VisitContext) {}
+
+      #[doc = #leave_doc]
+      fn #leave(&mut self, _element: &#element_type, _context: &VisitContext) {}
+    });
+    methods_mut.push(quote! {
+      #[doc = #visit_doc]
+      fn #visit_mut(&mut self, _element: &mut #element_type, _context: &VisitContext) {}
+
+      #[doc = #leave_doc]
+      fn #leave_mut(&mut self, _element: &mut #element_type, _context: &VisitContext) {}
+    });
+  }
+
+  // Generate the actual visitor traits
+  let (visitor_attrs, visitor) = (&input.visitor_attrs, &input.visitor);
+  let (visitor_mut_attrs, visitor_mut) = (&input.visitor_mut_attrs, &input.visitor_mut);
+  TokenStream::from(quote! {
+    #(#visitor_attrs)*
+    pub trait #visitor {
+      #(#methods)*
+    }
+
+    #(#visitor_mut_attrs)*
+    pub trait #visitor_mut {
+      #(#methods_mut)*
+    }
+  })
+}